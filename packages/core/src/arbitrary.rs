@@ -0,0 +1,103 @@
+use float16::f16;
+use float8::F8E4M3;
+use crate::Value;
+
+/// How deep the arrays of arbitrary [`Value`]s nest.
+///
+/// Every generated value is valid: strings and arrays are never empty and
+/// nesting stays far below the decoder's limit, so whatever a test builds
+/// from them can be serialized and read back.
+pub const MAX_ARBITRARY_DEPTH: u32 = 3;
+
+/// Longest generated string, in characters, and array, in items.
+const MAX_ARBITRARY_LEN: usize = 8;
+
+#[cfg(feature = "proptest")]
+mod proptest_impl {
+    use proptest::prelude::*;
+    use proptest::collection::vec;
+    use super::*;
+
+    /// Any non-array value.
+    fn leaf() -> BoxedStrategy<Value> {
+        prop_oneof![
+            any::<u8>().prop_map(Value::from),
+            any::<u16>().prop_map(Value::from),
+            any::<u32>().prop_map(Value::from),
+            any::<u64>().prop_map(Value::from),
+            any::<i8>().prop_map(Value::from),
+            any::<i16>().prop_map(Value::from),
+            any::<i32>().prop_map(Value::from),
+            any::<i64>().prop_map(Value::from),
+            any::<u8>().prop_map(|bits| Value::from(F8E4M3::from_bits(bits))),
+            any::<u16>().prop_map(|bits| Value::from(f16::from_bits(bits))),
+            any::<f32>().prop_map(Value::from),
+            any::<f64>().prop_map(Value::from),
+            any::<bool>().prop_map(Value::from),
+            vec(any::<char>(), 1..=MAX_ARBITRARY_LEN)
+                .prop_map(|chars| Value::try_from(chars.into_iter().collect::<String>()).expect("strings are not empty")),
+        ].boxed()
+    }
+
+    impl Arbitrary for Value {
+        type Parameters = ();
+        type Strategy = BoxedStrategy<Value>;
+
+        fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+            leaf().prop_recursive(MAX_ARBITRARY_DEPTH, 64, MAX_ARBITRARY_LEN as u32, |inner| {
+                vec(inner, 1..=MAX_ARBITRARY_LEN)
+                    .prop_map(|items| Value::try_from(items).expect("arrays are not empty"))
+            }).boxed()
+        }
+    }
+}
+
+#[cfg(feature = "quickcheck")]
+mod quickcheck_impl {
+    use quickcheck::{Arbitrary, Gen};
+    use super::*;
+
+    /// A value whose arrays nest at most `depth` more levels.
+    fn value(g: &mut Gen, depth: u32) -> Value {
+        let kinds = if depth == 0 { 14 } else { 15 };
+
+        match u8::arbitrary(g) % kinds {
+            0 => Value::from(u8::arbitrary(g)),
+            1 => Value::from(u16::arbitrary(g)),
+            2 => Value::from(u32::arbitrary(g)),
+            3 => Value::from(u64::arbitrary(g)),
+            4 => Value::from(i8::arbitrary(g)),
+            5 => Value::from(i16::arbitrary(g)),
+            6 => Value::from(i32::arbitrary(g)),
+            7 => Value::from(i64::arbitrary(g)),
+            8 => Value::from(F8E4M3::from_bits(u8::arbitrary(g))),
+            9 => Value::from(f16::from_bits(u16::arbitrary(g))),
+            10 => Value::from(f32::arbitrary(g)),
+            11 => Value::from(f64::arbitrary(g)),
+            12 => Value::from(bool::arbitrary(g)),
+            13 => {
+                let len = 1 + usize::arbitrary(g) % MAX_ARBITRARY_LEN;
+                let string: String = (0..len).map(|_| char::arbitrary(g)).collect();
+                Value::try_from(string).expect("strings are not empty")
+            }
+            _ => {
+                let len = 1 + usize::arbitrary(g) % MAX_ARBITRARY_LEN;
+                let items: Vec<Value> = (0..len).map(|_| value(g, depth - 1)).collect();
+                Value::try_from(items).expect("arrays are not empty")
+            }
+        }
+    }
+
+    impl Arbitrary for Value {
+        fn arbitrary(g: &mut Gen) -> Self {
+            value(g, MAX_ARBITRARY_DEPTH)
+        }
+
+        fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+            match TryInto::<Vec<Value>>::try_into(self.clone()) {
+                Ok(items) => Box::new(items.into_iter()),
+                Err(_) => quickcheck::empty_shrinker(),
+            }
+        }
+    }
+}
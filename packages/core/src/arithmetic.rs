@@ -0,0 +1,120 @@
+use float8::F8E4M3;
+use float16::f16;
+use crate::compare::Number;
+use crate::constants::error::{ErrorMessage, ARITHMETIC_OVERFLOW, NOT_A_NUMBER};
+use crate::constants::length::ByteLength;
+use crate::constants::types::Type;
+use crate::Value;
+
+/// Widths a result may be promoted through, narrowest first.
+const WIDTHS: [ByteLength; 4] = [ByteLength::One, ByteLength::Two, ByteLength::Four, ByteLength::Eight];
+
+/// Encodes `result` as an integer at least `min_width` wide, signed when
+/// `signed` is set or `result` is negative, promoted to the narrowest wider
+/// width that holds it.
+fn integer(result: i128, signed: bool, min_width: ByteLength) -> Result<Value, ErrorMessage> {
+    let signed = signed || result < 0;
+
+    for width in WIDTHS.into_iter().filter(|width| *width >= min_width) {
+        let value = match (signed, width) {
+            (false, ByteLength::One) => u8::try_from(result).ok().map(Value::from),
+            (false, ByteLength::Two) => u16::try_from(result).ok().map(Value::from),
+            (false, ByteLength::Four) => u32::try_from(result).ok().map(Value::from),
+            (false, _) => u64::try_from(result).ok().map(Value::from),
+            (true, ByteLength::One) => i8::try_from(result).ok().map(Value::from),
+            (true, ByteLength::Two) => i16::try_from(result).ok().map(Value::from),
+            (true, ByteLength::Four) => i32::try_from(result).ok().map(Value::from),
+            (true, _) => i64::try_from(result).ok().map(Value::from),
+        };
+
+        if let Some(value) = value {
+            return Ok(value);
+        }
+    }
+
+    Err(ErrorMessage(ARITHMETIC_OVERFLOW))
+}
+
+/// Encodes `result` as a float at least `min_width` wide, promoted to the
+/// narrowest wider width where it does not overflow.
+fn float(result: f64, min_width: ByteLength) -> Value {
+    WIDTHS.into_iter()
+        .filter(|width| *width >= min_width)
+        .map(|width| match width {
+            ByteLength::One => Value::from(F8E4M3::from_f64(result)),
+            ByteLength::Two => Value::from(f16::from_f64(result)),
+            ByteLength::Four => Value::from(result as f32),
+            _ => Value::from(result),
+        })
+        .find(|value| !result.is_finite() || matches!(value.as_number(), Some(Number::Float(v)) if v.is_finite()))
+        .unwrap_or_else(|| Value::from(result))
+}
+
+impl Number {
+    fn as_i128(self) -> i128 {
+        match self {
+            Number::Unsigned(v) => v as i128,
+            Number::Signed(v) => v as i128,
+            Number::Float(v) => v as i128,
+        }
+    }
+
+    fn as_f64(self) -> f64 {
+        match self {
+            Number::Unsigned(v) => v as f64,
+            Number::Signed(v) => v as f64,
+            Number::Float(v) => v,
+        }
+    }
+}
+
+impl Value {
+    /// Adds `by` to a numeric value in place, keeping its type and width when
+    /// the result fits.
+    ///
+    /// Otherwise integers are promoted to the narrowest wider width that holds
+    /// the result, and unsigned integers going below zero become signed, so
+    /// counters never wrap. Floats are promoted when the result would overflow
+    /// their width.
+    ///
+    /// # Errors
+    /// Returns `NOT_A_NUMBER` if the value is not a number, or
+    /// `ARITHMETIC_OVERFLOW` if an integer result does not fit in 64 bits; the
+    /// value is left unchanged.
+    ///
+    /// # Example
+    /// ```
+    /// use yad_core::Value;
+    ///
+    /// let mut counter = Value::from(255u8);
+    /// counter.increment(1).unwrap();
+    /// assert_eq!(counter, Value::from(256u16));
+    /// ```
+    pub fn increment(&mut self, by: i64) -> Result<(), ErrorMessage> {
+        *self = match self.as_number().ok_or(ErrorMessage(NOT_A_NUMBER))? {
+            Number::Float(v) => float(v + by as f64, self.length),
+            number => integer(number.as_i128() + by as i128, self.r#type == Type::Int, self.length)?,
+        };
+        Ok(())
+    }
+
+    /// Adds two numeric values of any type and width.
+    ///
+    /// The result is as wide as the wider operand, and promoted further like
+    /// [`Value::increment`] when it does not fit. It is a float when either
+    /// operand is, and signed when either operand is or the sum is negative.
+    ///
+    /// # Errors
+    /// Returns `NOT_A_NUMBER` if either value is not a number, or
+    /// `ARITHMETIC_OVERFLOW` if an integer sum does not fit in 64 bits.
+    pub fn checked_add(&self, other: &Value) -> Result<Value, ErrorMessage> {
+        let a = self.as_number().ok_or(ErrorMessage(NOT_A_NUMBER))?;
+        let b = other.as_number().ok_or(ErrorMessage(NOT_A_NUMBER))?;
+        let width = self.length.max(other.length);
+
+        match (a, b) {
+            (Number::Float(_), _) | (_, Number::Float(_)) => Ok(float(a.as_f64() + b.as_f64(), width)),
+            _ => integer(a.as_i128() + b.as_i128(), self.r#type == Type::Int || other.r#type == Type::Int, width),
+        }
+    }
+}
@@ -0,0 +1,122 @@
+use crate::constants::error::{
+    ErrorMessage, INDEX_OUT_OF_BOUNDS, NOT_AN_ARRAY, NOT_ENOUGH_BYTES, VEC_MAX_LENGTH_EXCEEDED, VEC_OF_LENGTH_ZERO,
+};
+use crate::constants::types::Type;
+use crate::{set_len_descriptor, Value};
+
+/// In-place array operations.
+///
+/// They splice the encoded bytes and rewrite the count descriptor rather than
+/// decoding and re-encoding every element: only the elements in front of the
+/// index are walked, by their length descriptors. The count descriptor is
+/// kept as narrow as the count allows, so the result equals the array built
+/// from the same elements with `Value::try_from(Vec<Value>)`.
+impl Value {
+    /// Returns the element count and the offset of the first element.
+    fn array_header(&self) -> Result<(usize, usize), ErrorMessage> {
+        if self.r#type != Type::Array {
+            return Err(ErrorMessage(NOT_AN_ARRAY));
+        }
+
+        let width = self.length.as_byte_count() as usize;
+        let count = self.bytes.get(1..1 + width).ok_or(ErrorMessage(NOT_ENOUGH_BYTES))?;
+        let count = count.iter().fold(0u64, |count, byte| count << 8 | *byte as u64);
+        Ok((count as usize, 1 + width))
+    }
+
+    /// Returns the byte range of element `index`, which must be below the count.
+    fn element_range(&self, index: usize) -> Result<(usize, usize), ErrorMessage> {
+        let (count, mut start) = self.array_header()?;
+        if index >= count {
+            return Err(ErrorMessage(INDEX_OUT_OF_BOUNDS));
+        }
+
+        for _ in 0..index {
+            start += Value::encoded_len(&self.bytes[start..])?;
+        }
+        Ok((start, start + Value::encoded_len(&self.bytes[start..])?))
+    }
+
+    /// Returns the number of elements of an array.
+    ///
+    /// # Errors
+    /// Returns `NOT_AN_ARRAY` if the value is not an array.
+    pub fn array_len(&self) -> Result<usize, ErrorMessage> {
+        Ok(self.array_header()?.0)
+    }
+
+    /// Returns element `index` of an array, decoding only that element.
+    ///
+    /// # Errors
+    /// Returns `NOT_AN_ARRAY` if the value is not an array, or
+    /// `INDEX_OUT_OF_BOUNDS` if it has no element `index`.
+    pub fn array_get(&self, index: usize) -> Result<Value, ErrorMessage> {
+        let (start, end) = self.element_range(index)?;
+        Value::decode_in_place(&self.bytes[start..end])
+    }
+
+    /// Appends `item` to an array.
+    ///
+    /// # Example
+    /// ```
+    /// use yad_core::Value;
+    ///
+    /// let mut array = Value::try_from(vec![1u8, 2]).unwrap();
+    /// array.array_push(&Value::from(3u8)).unwrap();
+    /// assert_eq!(array, Value::try_from(vec![1u8, 2, 3]).unwrap());
+    /// ```
+    ///
+    /// # Errors
+    /// Returns `NOT_AN_ARRAY` if the value is not an array.
+    pub fn array_push(&mut self, item: &Value) -> Result<(), ErrorMessage> {
+        let (count, _) = self.array_header()?;
+        set_len_descriptor(self, count + 1, VEC_OF_LENGTH_ZERO, VEC_MAX_LENGTH_EXCEEDED)?;
+        self.bytes.extend_from_slice(&item.bytes);
+        Ok(())
+    }
+
+    /// Inserts `item` at `index` of an array, shifting the elements after it.
+    ///
+    /// # Errors
+    /// Returns `NOT_AN_ARRAY` if the value is not an array, or
+    /// `INDEX_OUT_OF_BOUNDS` if `index` is greater than its length.
+    pub fn array_insert(&mut self, index: usize, item: &Value) -> Result<(), ErrorMessage> {
+        let (count, _) = self.array_header()?;
+        if index == count {
+            return self.array_push(item);
+        }
+
+        let (start, _) = self.element_range(index)?;
+        self.bytes.insert_many(start, item.bytes.iter().copied());
+        set_len_descriptor(self, count + 1, VEC_OF_LENGTH_ZERO, VEC_MAX_LENGTH_EXCEEDED)
+    }
+
+    /// Removes and returns element `index` of an array.
+    ///
+    /// # Errors
+    /// Returns `NOT_AN_ARRAY` if the value is not an array,
+    /// `INDEX_OUT_OF_BOUNDS` if it has no element `index`, or
+    /// `VEC_OF_LENGTH_ZERO` if it is the only element, as arrays cannot be
+    /// empty; the array is left unchanged.
+    pub fn array_remove(&mut self, index: usize) -> Result<Value, ErrorMessage> {
+        let (count, _) = self.array_header()?;
+        let (start, end) = self.element_range(index)?;
+        if count == 1 {
+            return Err(ErrorMessage(VEC_OF_LENGTH_ZERO));
+        }
+
+        let item = Value::decode_in_place(&self.bytes[start..end])?;
+        self.bytes.drain(start..end);
+        set_len_descriptor(self, count - 1, VEC_OF_LENGTH_ZERO, VEC_MAX_LENGTH_EXCEEDED)?;
+        Ok(item)
+    }
+
+    /// Removes and returns the last element of an array.
+    ///
+    /// # Errors
+    /// Returns the same errors as [`Value::array_remove`].
+    pub fn array_pop(&mut self) -> Result<Value, ErrorMessage> {
+        let (count, _) = self.array_header()?;
+        self.array_remove(count.saturating_sub(1))
+    }
+}
@@ -0,0 +1,340 @@
+use std::cmp::Ordering;
+use std::fmt;
+use std::str::FromStr;
+use crate::constants::error::{
+    ErrorMessage, BIG_INTEGER_OUT_OF_RANGE, INVALID_BIG_INTEGER_TEXT, MALFORMED_BIG_INTEGER, NOT_A_BIG_INTEGER,
+};
+use crate::Value;
+
+/// Extension tag of big integer values (see
+/// [`crate::extension::RESERVED_EXTENSION_TAGS`]).
+///
+/// The data is the integer as a big-endian two's complement number, in the
+/// fewest bytes holding it.
+pub const BIG_INTEGER_EXTENSION_TAG: u8 = 0x01;
+
+/// An integer of any size, for values past the 64 bits of the integer types.
+///
+/// Enable the `bigint` feature to convert to and from `num_bigint::BigInt`
+/// and `num_bigint::BigUint`.
+///
+/// # Example
+/// ```
+/// use yad_core::bigint::BigInteger;
+/// use yad_core::Value;
+///
+/// let big: BigInteger = "-340282366920938463463374607431768211456".parse().unwrap();
+/// let value = Value::from(big.clone());
+/// assert_eq!(value.to_string(), "-340282366920938463463374607431768211456");
+/// assert_eq!(TryInto::<BigInteger>::try_into(value), Ok(big));
+/// ```
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct BigInteger {
+    /// Big-endian two's complement, at least one byte and without redundant
+    /// sign bytes, so each integer has a single representation.
+    bytes: Vec<u8>,
+}
+
+impl BigInteger {
+    /// Reads a big-endian two's complement integer of any length; an empty
+    /// slice is zero.
+    pub fn from_signed_bytes_be(bytes: &[u8]) -> Self {
+        let fill = if bytes.first().is_some_and(|b| b & 0x80 != 0) { 0xFF } else { 0x00 };
+
+        // Drop sign-extension bytes, keeping the sign bit of the first byte left.
+        let mut start = 0;
+        while start + 1 < bytes.len() && bytes[start] == fill && (bytes[start + 1] ^ fill) & 0x80 == 0 {
+            start += 1;
+        }
+
+        let bytes = if bytes.is_empty() { vec![0] } else { bytes[start..].to_vec() };
+        Self { bytes }
+    }
+
+    /// Returns the integer as big-endian two's complement, in the fewest bytes holding it.
+    pub fn to_signed_bytes_be(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    /// Returns `true` if the integer is below zero.
+    pub fn is_negative(&self) -> bool {
+        self.bytes[0] & 0x80 != 0
+    }
+
+    /// Builds an integer from its sign and big-endian magnitude.
+    pub(crate) fn from_magnitude(negative: bool, magnitude: &[u8]) -> Self {
+        let mut bytes = Vec::with_capacity(magnitude.len() + 1);
+        bytes.push(0);
+        bytes.extend_from_slice(magnitude);
+        if negative {
+            negate(&mut bytes);
+        }
+        Self::from_signed_bytes_be(&bytes)
+    }
+
+    /// Returns the big-endian magnitude of the integer.
+    fn magnitude(&self) -> Vec<u8> {
+        let mut bytes = self.bytes.clone();
+        if self.is_negative() {
+            negate(&mut bytes);
+        }
+        bytes
+    }
+}
+
+/// Negates a big-endian two's complement number in place.
+fn negate(bytes: &mut [u8]) {
+    let mut carry = true;
+    for byte in bytes.iter_mut().rev() {
+        (*byte, carry) = (!*byte).overflowing_add(carry as u8);
+    }
+}
+
+impl Ord for BigInteger {
+    /// Orders integers by value.
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self.is_negative(), other.is_negative()) {
+            (true, false) => Ordering::Less,
+            (false, true) => Ordering::Greater,
+            // Without redundant sign bytes, longer means further from zero;
+            // bytes of the same length and sign compare as unsigned.
+            (negative, _) => match self.bytes.len().cmp(&other.bytes.len()) {
+                Ordering::Equal => self.bytes.cmp(&other.bytes),
+                ordering if negative => ordering.reverse(),
+                ordering => ordering,
+            },
+        }
+    }
+}
+
+impl PartialOrd for BigInteger {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl fmt::Display for BigInteger {
+    /// Writes the integer in base 10, with a leading `-` when negative.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        const CHUNK: u64 = 1_000_000_000;
+
+        // Divide the magnitude by 10^9 until nothing is left, collecting the
+        // remainders as groups of nine digits, lowest first.
+        let mut magnitude = self.magnitude();
+        let mut groups = vec![];
+        while magnitude.iter().any(|b| *b != 0) {
+            let mut remainder = 0u64;
+            for byte in magnitude.iter_mut() {
+                let current = (remainder << 8) | *byte as u64;
+                *byte = (current / CHUNK) as u8;
+                remainder = current % CHUNK;
+            }
+            groups.push(remainder);
+        }
+
+        let sign = if self.is_negative() { "-" } else { "" };
+        let mut groups = groups.iter().rev();
+        let mut text = format!("{}{}", sign, groups.next().copied().unwrap_or(0));
+        groups.for_each(|group| text.push_str(&format!("{:09}", group)));
+        f.write_str(&text)
+    }
+}
+
+impl FromStr for BigInteger {
+    type Err = ErrorMessage;
+
+    /// Parses `[+-]digits` in base 10.
+    ///
+    /// # Errors
+    /// Returns `INVALID_BIG_INTEGER_TEXT` for anything else.
+    fn from_str(text: &str) -> Result<Self, ErrorMessage> {
+        let (negative, digits) = match text.strip_prefix('-') {
+            Some(rest) => (true, rest),
+            None => (false, text.strip_prefix('+').unwrap_or(text)),
+        };
+        if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(ErrorMessage(INVALID_BIG_INTEGER_TEXT));
+        }
+
+        // Multiply the magnitude by ten and add each digit.
+        let mut magnitude = vec![0u8];
+        for digit in digits.bytes() {
+            let mut carry = (digit - b'0') as u16;
+            for byte in magnitude.iter_mut().rev() {
+                let current = *byte as u16 * 10 + carry;
+                *byte = current as u8;
+                carry = current >> 8;
+            }
+            if carry != 0 {
+                magnitude.insert(0, carry as u8);
+            }
+        }
+
+        Ok(Self::from_magnitude(negative, &magnitude))
+    }
+}
+
+macro_rules! impl_big_integer_from {
+    ($($t:ty),*) => {$(
+        impl From<$t> for BigInteger {
+            fn from(n: $t) -> Self {
+                Self::from_signed_bytes_be(&(n as i128).to_be_bytes())
+            }
+        }
+    )*};
+}
+
+impl_big_integer_from!(u8, u16, u32, u64, i8, i16, i32, i64, i128);
+
+impl From<u128> for BigInteger {
+    fn from(n: u128) -> Self {
+        Self::from_magnitude(false, &n.to_be_bytes())
+    }
+}
+
+impl TryFrom<&BigInteger> for i128 {
+    type Error = ErrorMessage;
+
+    /// Fails with `BIG_INTEGER_OUT_OF_RANGE` if the integer takes more than 16 bytes.
+    fn try_from(big: &BigInteger) -> Result<Self, ErrorMessage> {
+        if big.bytes.len() > 16 {
+            return Err(ErrorMessage(BIG_INTEGER_OUT_OF_RANGE));
+        }
+
+        let fill = if big.is_negative() { 0xFF } else { 0x00 };
+        let mut bytes = [fill; 16];
+        bytes[16 - big.bytes.len()..].copy_from_slice(&big.bytes);
+        Ok(i128::from_be_bytes(bytes))
+    }
+}
+
+impl TryFrom<&BigInteger> for u128 {
+    type Error = ErrorMessage;
+
+    /// Fails with `BIG_INTEGER_OUT_OF_RANGE` for negative integers and those
+    /// past `u128::MAX`.
+    fn try_from(big: &BigInteger) -> Result<Self, ErrorMessage> {
+        let magnitude = big.magnitude();
+        let magnitude = magnitude.strip_prefix(&[0]).unwrap_or(&magnitude);
+        if big.is_negative() || magnitude.len() > 16 {
+            return Err(ErrorMessage(BIG_INTEGER_OUT_OF_RANGE));
+        }
+
+        let mut bytes = [0; 16];
+        bytes[16 - magnitude.len()..].copy_from_slice(magnitude);
+        Ok(u128::from_be_bytes(bytes))
+    }
+}
+
+impl From<BigInteger> for Value {
+    /// Encodes the integer as an extension value tagged [`BIG_INTEGER_EXTENSION_TAG`].
+    fn from(big: BigInteger) -> Self {
+        Value::from_extension(BIG_INTEGER_EXTENSION_TAG, &big.bytes)
+            .expect("big integers shorter than 2^64 bytes")
+    }
+}
+
+impl TryInto<BigInteger> for Value {
+    type Error = ErrorMessage;
+
+    /// Reads a big integer value.
+    ///
+    /// # Errors
+    /// Returns `NOT_A_BIG_INTEGER` for other values, or `MALFORMED_BIG_INTEGER` if it has no data.
+    fn try_into(self) -> Result<BigInteger, Self::Error> {
+        self.as_big_integer()
+    }
+}
+
+impl Value {
+    /// Returns `true` for big integer values (see [`BigInteger`]).
+    pub fn is_big_integer(&self) -> bool {
+        self.extension_tag() == Ok(BIG_INTEGER_EXTENSION_TAG)
+    }
+
+    /// Reads a big integer value without consuming it. Redundant sign bytes
+    /// written by other encoders are accepted.
+    ///
+    /// # Errors
+    /// Returns `NOT_A_BIG_INTEGER` for other values, or `MALFORMED_BIG_INTEGER` if it has no data.
+    pub fn as_big_integer(&self) -> Result<BigInteger, ErrorMessage> {
+        match self.extension_payload() {
+            Ok((BIG_INTEGER_EXTENSION_TAG, [])) => Err(ErrorMessage(MALFORMED_BIG_INTEGER)),
+            Ok((BIG_INTEGER_EXTENSION_TAG, data)) => Ok(BigInteger::from_signed_bytes_be(data)),
+            _ => Err(ErrorMessage(NOT_A_BIG_INTEGER)),
+        }
+    }
+}
+
+#[cfg(feature = "bigint")]
+mod num_bigint_impl {
+    use num_bigint::{BigInt, BigUint};
+    use super::*;
+
+    impl From<BigInt> for BigInteger {
+        fn from(big: BigInt) -> Self {
+            Self::from_signed_bytes_be(&big.to_signed_bytes_be())
+        }
+    }
+
+    impl From<BigUint> for BigInteger {
+        fn from(big: BigUint) -> Self {
+            Self::from_magnitude(false, &big.to_bytes_be())
+        }
+    }
+
+    impl From<BigInteger> for BigInt {
+        fn from(big: BigInteger) -> Self {
+            BigInt::from_signed_bytes_be(&big.bytes)
+        }
+    }
+
+    impl TryFrom<BigInteger> for BigUint {
+        type Error = ErrorMessage;
+
+        /// Fails with `BIG_INTEGER_OUT_OF_RANGE` for negative integers.
+        fn try_from(big: BigInteger) -> Result<Self, ErrorMessage> {
+            if big.is_negative() {
+                return Err(ErrorMessage(BIG_INTEGER_OUT_OF_RANGE));
+            }
+            Ok(BigUint::from_bytes_be(&big.bytes))
+        }
+    }
+
+    impl From<BigInt> for Value {
+        fn from(big: BigInt) -> Self {
+            Value::from(BigInteger::from(big))
+        }
+    }
+
+    impl From<BigUint> for Value {
+        fn from(big: BigUint) -> Self {
+            Value::from(BigInteger::from(big))
+        }
+    }
+
+    impl TryInto<BigInt> for Value {
+        type Error = ErrorMessage;
+
+        /// Reads a big integer value.
+        ///
+        /// # Errors
+        /// Returns the errors of [`Value::as_big_integer`].
+        fn try_into(self) -> Result<BigInt, Self::Error> {
+            self.as_big_integer().map(BigInt::from)
+        }
+    }
+
+    impl TryInto<BigUint> for Value {
+        type Error = ErrorMessage;
+
+        /// Reads a big integer value.
+        ///
+        /// # Errors
+        /// Returns the errors of [`Value::as_big_integer`], or
+        /// `BIG_INTEGER_OUT_OF_RANGE` if it is negative.
+        fn try_into(self) -> Result<BigUint, Self::Error> {
+            BigUint::try_from(self.as_big_integer()?)
+        }
+    }
+}
@@ -0,0 +1,47 @@
+use crate::constants::error::ErrorMessage;
+use crate::constants::types::Type;
+use crate::Value;
+
+/// The byte order of number payloads in an encoded value.
+///
+/// `Value` always holds big-endian payloads; [`Value::with_byte_order`]
+/// converts them for files written in another order. Length descriptors of
+/// strings and arrays are big-endian in every order.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum ByteOrder {
+    /// Most significant byte first, as every YAD value is encoded by default.
+    #[default]
+    Big,
+    /// Least significant byte first.
+    Little,
+}
+
+impl Value {
+    /// Converts the number payloads of this value, including numbers nested
+    /// in arrays, between big-endian and `order`. The conversion is its own
+    /// inverse: it both encodes a value for a little-endian file and decodes
+    /// one read from it.
+    ///
+    /// # Errors
+    /// Returns a decoding error if an array is malformed.
+    pub fn with_byte_order(mut self, order: ByteOrder) -> Result<Self, ErrorMessage> {
+        if order == ByteOrder::Big {
+            return Ok(self);
+        }
+
+        match self.r#type {
+            Type::Uint | Type::Int | Type::Float => {
+                self.bytes[1..].reverse();
+                Ok(self)
+            }
+            Type::Array => {
+                let items: Vec<Value> = self.try_into()?;
+                let items = items.into_iter()
+                    .map(|item| item.with_byte_order(order))
+                    .collect::<Result<Vec<_>, _>>()?;
+                Value::try_from(items)
+            }
+            _ => Ok(self),
+        }
+    }
+}
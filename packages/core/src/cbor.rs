@@ -0,0 +1,187 @@
+use ciborium::value::Integer;
+use float16::f16;
+use float8::F8E4M3;
+use crate::constants::error::{
+    CBOR_BYTES_NOT_SUPPORTED,
+    CBOR_INTEGER_OUT_OF_RANGE,
+    CBOR_MAP_NOT_SUPPORTED,
+    CBOR_NULL_NOT_SUPPORTED,
+    CBOR_UNKNOWN_TAG,
+    ErrorMessage,
+};
+use crate::constants::length::ByteLength;
+use crate::constants::types::Type;
+use crate::bigint::BigInteger;
+use crate::decimal::Decimal;
+use crate::Value;
+
+/// Tag of a big-endian IEEE 754 binary16 typed array (RFC 8746), used with a
+/// single element so `f16` values keep their width.
+pub const CBOR_TAG_F16: u64 = 80;
+
+/// Tag of a one-byte F8E4M3 float, which CBOR has no native type for.
+pub const CBOR_TAG_F8E4M3: u64 = 0x5941_4438;
+
+/// Tag of an extension value, over its tag byte followed by its data.
+pub const CBOR_TAG_EXTENSION: u64 = 0x5941_4445;
+
+/// Tag of a non-negative bignum (RFC 8949), over its big-endian magnitude.
+pub const CBOR_TAG_POSITIVE_BIGNUM: u64 = 2;
+
+/// Tag of a negative bignum (RFC 8949), over the big-endian magnitude of `-1 - n`.
+pub const CBOR_TAG_NEGATIVE_BIGNUM: u64 = 3;
+
+/// Tag of a decimal fraction (RFC 8949), an array of a base-10 exponent and
+/// an integer mantissa.
+pub const CBOR_TAG_DECIMAL_FRACTION: u64 = 4;
+
+/// Returns the smallest unsigned, or for negative numbers signed, `Value` holding `n`.
+fn integer_value(n: i128) -> Result<Value, ErrorMessage> {
+    if let Ok(n) = u64::try_from(n) {
+        return Ok(match n {
+            n if n <= u8::MAX as u64 => Value::from(n as u8),
+            n if n <= u16::MAX as u64 => Value::from(n as u16),
+            n if n <= u32::MAX as u64 => Value::from(n as u32),
+            n => Value::from(n),
+        });
+    }
+
+    let n = i64::try_from(n).map_err(|_| ErrorMessage(CBOR_INTEGER_OUT_OF_RANGE))?;
+    Ok(match n {
+        n if n >= i8::MIN as i64 => Value::from(n as i8),
+        n if n >= i16::MIN as i64 => Value::from(n as i16),
+        n if n >= i32::MIN as i64 => Value::from(n as i32),
+        n => Value::from(n),
+    })
+}
+
+/// Decodes the payload of a tagged float, bignum or extension written by [`Value::to_cbor`].
+fn tagged_value(tag: u64, inner: &ciborium::Value) -> Result<Value, ErrorMessage> {
+    let bytes = inner.as_bytes().ok_or(ErrorMessage(CBOR_UNKNOWN_TAG))?;
+
+    match (tag, bytes.as_slice()) {
+        (CBOR_TAG_F16, [a, b]) => Ok(Value::from(f16::from_be_bytes([*a, *b]))),
+        (CBOR_TAG_F8E4M3, [a]) => Ok(Value::from(F8E4M3::from_bits(*a))),
+        (CBOR_TAG_EXTENSION, [tag, data @ ..]) => Value::from_extension(*tag, data),
+        (CBOR_TAG_POSITIVE_BIGNUM, magnitude) => Ok(Value::from(BigInteger::from_magnitude(false, magnitude))),
+        (CBOR_TAG_NEGATIVE_BIGNUM, magnitude) => {
+            // `-1 - n` is `!n` in two's complement.
+            let bytes: Vec<u8> = std::iter::once(0).chain(magnitude.iter().copied()).map(|b| !b).collect();
+            Ok(Value::from(BigInteger::from_signed_bytes_be(&bytes)))
+        }
+        _ => Err(ErrorMessage(CBOR_UNKNOWN_TAG)),
+    }
+}
+
+/// Writes a big integer as a bignum.
+fn bignum(big: &BigInteger) -> ciborium::Value {
+    let (tag, bytes): (u64, Vec<u8>) = match big.is_negative() {
+        true => (CBOR_TAG_NEGATIVE_BIGNUM, big.to_signed_bytes_be().iter().map(|b| !b).collect()),
+        false => (CBOR_TAG_POSITIVE_BIGNUM, big.to_signed_bytes_be().to_vec()),
+    };
+    let start = bytes.iter().position(|b| *b != 0).unwrap_or(bytes.len());
+    ciborium::Value::Tag(tag, Box::new(ciborium::Value::Bytes(bytes[start..].to_vec())))
+}
+
+/// Writes a decimal as a decimal fraction, or `None` if its mantissa does
+/// not fit a CBOR integer.
+fn decimal_fraction(decimal: Decimal) -> Option<ciborium::Value> {
+    let mantissa = Integer::try_from(decimal.mantissa).ok()?;
+    let exponent = Integer::from(-(decimal.scale as i16));
+    Some(ciborium::Value::Tag(
+        CBOR_TAG_DECIMAL_FRACTION,
+        Box::new(ciborium::Value::Array(vec![ciborium::Value::Integer(exponent), ciborium::Value::Integer(mantissa)])),
+    ))
+}
+
+/// Reads a decimal fraction whose exponent is between `-255` and `0`.
+fn decimal_value(inner: &ciborium::Value) -> Result<Value, ErrorMessage> {
+    let [ciborium::Value::Integer(exponent), ciborium::Value::Integer(mantissa)] = inner.as_array().map(Vec::as_slice).unwrap_or_default() else {
+        return Err(ErrorMessage(CBOR_UNKNOWN_TAG));
+    };
+
+    let scale = u8::try_from(-i128::from(*exponent)).map_err(|_| ErrorMessage(CBOR_UNKNOWN_TAG))?;
+    let mantissa = i128::from(*mantissa);
+    Ok(Value::from(Decimal::new(mantissa, scale)))
+}
+
+impl Value {
+    /// Converts the value into a `ciborium::Value`.
+    ///
+    /// - Integers become CBOR integers and `f32` / `f64` become CBOR floats;
+    ///   their YAD width is not kept.
+    /// - `f16` and F8E4M3 floats are tagged ([`CBOR_TAG_F16`], [`CBOR_TAG_F8E4M3`])
+    ///   over their big-endian bytes, so they come back with the same width.
+    /// - Booleans, strings and arrays map to their CBOR counterparts.
+    /// - Decimals become decimal fractions ([`CBOR_TAG_DECIMAL_FRACTION`]) when
+    ///   their mantissa fits a CBOR integer.
+    /// - Big integers become bignums ([`CBOR_TAG_POSITIVE_BIGNUM`] and
+    ///   [`CBOR_TAG_NEGATIVE_BIGNUM`]).
+    /// - Other extension values are tagged ([`CBOR_TAG_EXTENSION`]) over their tag and data.
+    ///
+    /// # Errors
+    /// Returns an error if the value is malformed.
+    pub fn to_cbor(&self) -> Result<ciborium::Value, ErrorMessage> {
+        let payload = self.isolate_value_bytes();
+
+        Ok(match (self.r#type, self.length) {
+            (Type::Uint, _) => ciborium::Value::Integer(Integer::from(self.as_u64_lossless()?)),
+            (Type::Int, _) => ciborium::Value::Integer(Integer::from(self.as_i64_lossless()?)),
+            (Type::Float, ByteLength::One) => {
+                ciborium::Value::Tag(CBOR_TAG_F8E4M3, Box::new(ciborium::Value::Bytes(payload.to_vec())))
+            }
+            (Type::Float, ByteLength::Two) => {
+                ciborium::Value::Tag(CBOR_TAG_F16, Box::new(ciborium::Value::Bytes(payload.to_vec())))
+            }
+            (Type::Float, _) => ciborium::Value::Float(self.as_f64_lossy()?),
+            (Type::String, _) => ciborium::Value::Text(self.clone().try_into()?),
+            (Type::Array, _) => {
+                let items: Vec<Value> = self.clone().try_into()?;
+                ciborium::Value::Array(items.iter().map(Value::to_cbor).collect::<Result<_, _>>()?)
+            }
+            (Type::Bool | Type::True | Type::False, _) => ciborium::Value::Bool(self.r#type != Type::False),
+            (Type::Extension, _) if let Ok(big) = self.as_big_integer() => bignum(&big),
+            (Type::Extension, _) if let Some(fraction) = self.as_decimal().ok().and_then(decimal_fraction) => fraction,
+            (Type::Extension, _) => {
+                ciborium::Value::Tag(CBOR_TAG_EXTENSION, Box::new(ciborium::Value::Bytes(payload.to_vec())))
+            }
+        })
+    }
+
+    /// Converts a `ciborium::Value` into a `Value`.
+    ///
+    /// - Integers take the smallest width holding them, unsigned when non-negative.
+    /// - Untagged floats become `f64`; tagged `f16` / F8E4M3 floats keep their width.
+    /// - Decimal fractions become decimals and bignums big integers.
+    /// - Booleans, text and arrays map to their YAD counterparts.
+    ///
+    /// # Errors
+    /// - `CBOR_NULL_NOT_SUPPORTED`, `CBOR_BYTES_NOT_SUPPORTED` and `CBOR_MAP_NOT_SUPPORTED`
+    ///   for CBOR types YAD values have no equivalent of; maps convert to documents instead.
+    /// - `CBOR_UNKNOWN_TAG` for tags other than the two float tags, the
+    ///   extension tag, bignums and decimal fractions with an exponent in `-255..=0`.
+    /// - `CBOR_INTEGER_OUT_OF_RANGE` for integers outside `i64::MIN..=u64::MAX`.
+    /// - `STRING_OF_LENGTH_ZERO` / `VEC_OF_LENGTH_ZERO` for empty text and arrays.
+    pub fn from_cbor(cbor: &ciborium::Value) -> Result<Self, ErrorMessage> {
+        match cbor {
+            ciborium::Value::Integer(n) => integer_value(i128::from(*n)),
+            ciborium::Value::Float(n) => Ok(Value::from(*n)),
+            ciborium::Value::Bool(b) => Ok(Value::from(*b)),
+            ciborium::Value::Text(s) => Value::try_from(s.as_str()),
+            ciborium::Value::Array(items) => {
+                let mut values = Vec::with_capacity(items.len());
+                for item in items {
+                    values.push(Self::from_cbor(item)?);
+                }
+                Value::try_from(values)
+            }
+            ciborium::Value::Tag(CBOR_TAG_DECIMAL_FRACTION, inner) => decimal_value(inner),
+            ciborium::Value::Tag(tag, inner) => tagged_value(*tag, inner),
+            ciborium::Value::Null => Err(ErrorMessage(CBOR_NULL_NOT_SUPPORTED)),
+            ciborium::Value::Bytes(_) => Err(ErrorMessage(CBOR_BYTES_NOT_SUPPORTED)),
+            ciborium::Value::Map(_) => Err(ErrorMessage(CBOR_MAP_NOT_SUPPORTED)),
+            _ => Err(ErrorMessage(CBOR_UNKNOWN_TAG)),
+        }
+    }
+
+}
@@ -0,0 +1,239 @@
+use std::cmp::Ordering;
+use std::hash::{Hash, Hasher};
+use float8::F8E4M3;
+use float16::f16;
+use crate::constants::length::ByteLength;
+use crate::constants::types::Type;
+use crate::Value;
+
+/// A decoded number used for width-independent comparisons.
+#[derive(Copy, Clone, Debug)]
+pub(crate) enum Number {
+    Unsigned(u64),
+    Signed(i64),
+    Float(f64),
+}
+
+impl Number {
+    /// Compares two numbers by their mathematical value.
+    ///
+    /// Integers are compared exactly. As soon as a float is involved both sides
+    /// are compared as `f64`, falling back to a total order for `NaN`.
+    fn cmp(self, other: Self) -> Ordering {
+        let as_i128 = |n: Self| match n {
+            Number::Unsigned(v) => Some(v as i128),
+            Number::Signed(v) => Some(v as i128),
+            Number::Float(_) => None,
+        };
+        let as_f64 = |n: Self| match n {
+            Number::Unsigned(v) => v as f64,
+            Number::Signed(v) => v as f64,
+            Number::Float(v) => v,
+        };
+
+        match (as_i128(self), as_i128(other)) {
+            (Some(a), Some(b)) => a.cmp(&b),
+            _ => {
+                let (a, b) = (as_f64(self), as_f64(other));
+                a.partial_cmp(&b).unwrap_or_else(|| a.total_cmp(&b))
+            }
+        }
+    }
+}
+
+/// Rank of each kind of value when comparing values of different kinds.
+fn kind_rank(r#type: Type) -> u8 {
+    match r#type {
+        Type::Bool | Type::True | Type::False => 0,
+        Type::Uint | Type::Int | Type::Float => 1,
+        Type::String => 2,
+        Type::Array => 3,
+        Type::Extension => 4,
+    }
+}
+
+impl Value {
+    /// Decodes a numeric value of any width, or `None` if this is not a valid number.
+    pub(crate) fn as_number(&self) -> Option<Number> {
+        let value = self.clone();
+        Some(match (self.r#type, self.length) {
+            (Type::Uint, ByteLength::One) => Number::Unsigned(TryInto::<u8>::try_into(value).ok()? as u64),
+            (Type::Uint, ByteLength::Two) => Number::Unsigned(TryInto::<u16>::try_into(value).ok()? as u64),
+            (Type::Uint, ByteLength::Four) => Number::Unsigned(TryInto::<u32>::try_into(value).ok()? as u64),
+            (Type::Uint, ByteLength::Eight) => Number::Unsigned(TryInto::<u64>::try_into(value).ok()?),
+            (Type::Int, ByteLength::One) => Number::Signed(TryInto::<i8>::try_into(value).ok()? as i64),
+            (Type::Int, ByteLength::Two) => Number::Signed(TryInto::<i16>::try_into(value).ok()? as i64),
+            (Type::Int, ByteLength::Four) => Number::Signed(TryInto::<i32>::try_into(value).ok()? as i64),
+            (Type::Int, ByteLength::Eight) => Number::Signed(TryInto::<i64>::try_into(value).ok()?),
+            (Type::Float, ByteLength::One) => Number::Float(TryInto::<F8E4M3>::try_into(value).ok()?.to_f64()),
+            (Type::Float, ByteLength::Two) => Number::Float(TryInto::<f16>::try_into(value).ok()?.to_f64()),
+            (Type::Float, ByteLength::Four) => Number::Float(TryInto::<f32>::try_into(value).ok()? as f64),
+            (Type::Float, ByteLength::Eight) => Number::Float(TryInto::<f64>::try_into(value).ok()?),
+            _ => return None,
+        })
+    }
+
+    /// Compares two values by what they represent rather than by how they are encoded.
+    ///
+    /// The derived `Ord`/`Eq` implementations compare the raw encoded bytes, so
+    /// `5u8` and `5u16` differ and signed or floating point values do not sort
+    /// numerically. This comparison:
+    /// - Orders numbers of any type and width by their numeric value.
+    /// - Orders strings lexicographically and arrays element by element.
+    /// - Orders decimals by value, whatever their scale, big integers by value,
+    ///   and other extension values by their encoded bytes, as only their
+    ///   codec knows what they mean.
+    /// - Orders values of different kinds as booleans < numbers < strings < arrays < extensions.
+    ///
+    /// Values that cannot be decoded fall back to the byte order.
+    pub fn semantic_cmp(&self, other: &Value) -> Ordering {
+        let (rank, other_rank) = (kind_rank(self.r#type), kind_rank(other.r#type));
+        if rank != other_rank {
+            return rank.cmp(&other_rank);
+        }
+
+        match self.r#type {
+            Type::Uint | Type::Int | Type::Float => match (self.as_number(), other.as_number()) {
+                (Some(a), Some(b)) => a.cmp(b),
+                _ => self.cmp(other),
+            },
+            Type::Bool | Type::True | Type::False => {
+                (self.r#type != Type::False).cmp(&(other.r#type != Type::False))
+            }
+            Type::String => {
+                let a: Result<String, _> = self.clone().try_into();
+                let b: Result<String, _> = other.clone().try_into();
+                match (a, b) {
+                    (Ok(a), Ok(b)) => a.cmp(&b),
+                    _ => self.cmp(other),
+                }
+            }
+            Type::Array => {
+                let a: Result<Vec<Value>, _> = self.clone().try_into();
+                let b: Result<Vec<Value>, _> = other.clone().try_into();
+                match (a, b) {
+                    (Ok(a), Ok(b)) => a.iter()
+                        .zip(b.iter())
+                        .map(|(x, y)| x.semantic_cmp(y))
+                        .find(|o| o.is_ne())
+                        .unwrap_or_else(|| a.len().cmp(&b.len())),
+                    _ => self.cmp(other),
+                }
+            }
+            Type::Extension => match (self.as_decimal(), other.as_decimal()) {
+                (Ok(a), Ok(b)) => a.cmp_value(&b),
+                _ => match (self.as_big_integer(), other.as_big_integer()) {
+                    (Ok(a), Ok(b)) => a.cmp(&b),
+                    _ => self.cmp(other),
+                },
+            },
+        }
+    }
+
+    /// Returns `true` if both values represent the same data, regardless of encoding width.
+    ///
+    /// Use `==` to compare the encoded bytes instead.
+    pub fn semantic_eq(&self, other: &Value) -> bool {
+        self.semantic_cmp(other).is_eq()
+    }
+
+    /// Feeds the value to `state` so that values which are [`Value::semantic_eq`]
+    /// hash the same, regardless of encoding width.
+    ///
+    /// Numbers are hashed through their `f64` value, the precision at which
+    /// integers and floats are compared, so large integers that differ may
+    /// collide. Values that cannot be decoded hash their bytes.
+    pub fn semantic_hash<H: Hasher>(&self, state: &mut H) {
+        kind_rank(self.r#type).hash(state);
+
+        match self.r#type {
+            Type::Uint | Type::Int | Type::Float => match self.as_number() {
+                Some(number) => {
+                    let v = match number {
+                        Number::Unsigned(v) => v as f64,
+                        Number::Signed(v) => v as f64,
+                        Number::Float(v) => v,
+                    };
+                    // `0.0` and `-0.0` compare equal.
+                    (if v == 0.0 { 0.0 } else { v }).to_bits().hash(state);
+                }
+                None => self.bytes.hash(state),
+            },
+            Type::Bool | Type::True | Type::False => (self.r#type != Type::False).hash(state),
+            Type::String => match TryInto::<String>::try_into(self.clone()) {
+                Ok(string) => string.hash(state),
+                Err(_) => self.bytes.hash(state),
+            },
+            Type::Array => match TryInto::<Vec<Value>>::try_into(self.clone()) {
+                Ok(items) => {
+                    items.len().hash(state);
+                    items.iter().for_each(|item| item.semantic_hash(state));
+                }
+                Err(_) => self.bytes.hash(state),
+            },
+            Type::Extension => match (self.as_decimal(), self.as_big_integer()) {
+                (Ok(decimal), _) => decimal.normalized().hash(state),
+                (_, Ok(big)) => big.hash(state),
+                _ => self.bytes.hash(state),
+            },
+        }
+    }
+}
+
+impl Hash for Value {
+    /// Hashes the value with [`Value::semantic_hash`], which agrees with both
+    /// `==` (equal bytes) and [`Value::semantic_eq`].
+    ///
+    /// A `HashSet<Value>` still deduplicates by `==`, so `5u8` and `5u16` are
+    /// kept apart; use a `HashSet<SemanticValue>` to merge them.
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.semantic_hash(state);
+    }
+}
+
+/// A value compared, ordered and hashed by what it represents, with
+/// [`Value::semantic_eq`], [`Value::semantic_cmp`] and [`Value::semantic_hash`].
+///
+/// Use it to deduplicate or sort values regardless of encoding width:
+/// ```
+/// use std::collections::HashSet;
+/// use yad_core::compare::SemanticValue;
+/// use yad_core::Value;
+///
+/// let set: HashSet<SemanticValue> = [Value::from(5u8), Value::from(5u16)].into_iter().map(SemanticValue).collect();
+/// assert_eq!(set.len(), 1);
+/// ```
+#[derive(Clone, Debug)]
+pub struct SemanticValue(pub Value);
+
+impl PartialEq for SemanticValue {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.semantic_eq(&other.0)
+    }
+}
+
+impl Eq for SemanticValue {}
+
+impl Hash for SemanticValue {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.semantic_hash(state);
+    }
+}
+
+impl PartialOrd for SemanticValue {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for SemanticValue {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.semantic_cmp(&other.0)
+    }
+}
+
+impl From<Value> for SemanticValue {
+    fn from(value: Value) -> Self {
+        Self(value)
+    }
+}
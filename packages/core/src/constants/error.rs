@@ -36,11 +36,53 @@ pub const NOT_A_FLOAT64_VALUE: &'static str = "You cannot convert something that
 pub const NOT_A_STRING_VALUE: &'static str = "You cannot convert something that is not value of string to string.";
 pub const NOT_A_BOOL_VALUE: &'static str = "You cannot convert something that is not value of boolean to boolean.";
 pub const NOT_AN_ARRAY_VALUE: &'static str = "You cannot convert something that is not value of array to array.";
-pub const STRING_MAX_LENGTH_EXCEEDED: &'static str = "Your string exceeds the limit of 2^64 − 1 characters.";
+pub const STRING_MAX_LENGTH_EXCEEDED: &'static str = "Your string exceeds the limit of 2^64 − 1 bytes.";
+pub const INVALID_STRING_RANGE: &str = "The provided range is past the end of the string or splits a character.";
 pub const STRING_OF_LENGTH_ZERO: &'static str = "Your string must have at least one character.";
 pub const VEC_MAX_LENGTH_EXCEEDED: &'static str = "Your vector exceeds the limit of 2^64 − 1 items.";
+pub const INDEX_OUT_OF_BOUNDS: &str = "The provided index is past the end of the array.";
 pub const VEC_OF_LENGTH_ZERO: &'static str = "Your vector must have at least one item.";
+pub const MALFORMED_VARINT: &str = "The provided varint is longer than 64 bits.";
+pub const MALFORMED_DELTA_ARRAY: &str = "The provided delta array has an invalid header or a count its array header cannot hold.";
+pub const ARITHMETIC_OVERFLOW: &str = "The result of the operation does not fit in a 64-bit number.";
+pub const NOT_SORTED: &str = "The provided values are not sorted in ascending order.";
+pub const ALLOCATION_LIMIT_EXCEEDED: &str = "Decoding the provided bytes would allocate more than the allowed maximum.";
 pub const NESTING_TOO_DEEP: &'static str = "The provided YAD array exceeds the maximum allowed nesting depth.";
+pub const JSON_NULL_NOT_SUPPORTED: &str = "JSON null has no equivalent YAD value.";
+pub const JSON_OBJECT_NOT_SUPPORTED: &str = "JSON objects cannot be converted to a single YAD value.";
+pub const JSON_NUMBER_NOT_REPRESENTABLE: &str = "The provided JSON number cannot be represented with the selected number policy.";
+pub const NOT_COERCIBLE_TO_U64: &str = "The provided value is neither a non-negative integer nor a string holding one.";
+pub const NOT_A_FLOAT: &str = "You cannot widen something that is not a float to f64.";
+pub const NAN_NOT_ALLOWED: &str = "NaN is not allowed by the selected non-finite float policy.";
+pub const INFINITY_NOT_ALLOWED: &str = "Infinite floats are not allowed by the selected non-finite float policy.";
+pub const CBOR_NULL_NOT_SUPPORTED: &str = "CBOR null has no equivalent YAD value.";
+pub const CBOR_BYTES_NOT_SUPPORTED: &str = "CBOR byte strings have no equivalent YAD value.";
+pub const CBOR_MAP_NOT_SUPPORTED: &str = "CBOR maps cannot be converted to a single YAD value.";
+pub const CBOR_UNKNOWN_TAG: &str = "The provided CBOR tag has no equivalent YAD value.";
+pub const CBOR_INTEGER_OUT_OF_RANGE: &str = "The provided CBOR integer does not fit in 64 bits.";
+pub const NOT_AN_EXTENSION: &str = "You cannot read an extension tag or data out of something that is not an extension.";
+pub const EXTENSION_OF_LENGTH_ZERO: &str = "An extension value must hold at least its tag byte.";
+pub const EXTENSION_MAX_LENGTH_EXCEEDED: &str = "The provided extension data is larger than the maximum allowed.";
+pub const EXTENSION_TAG_RESERVED: &str = "Extension tags below 0x80 are reserved for the format.";
+pub const EXTENSION_TAG_TAKEN: &str = "The provided extension tag already has a codec.";
+pub const EXTENSION_TARGET_TAKEN: &str = "A codec for the provided type is already registered under another tag.";
+pub const UNKNOWN_EXTENSION_TAG: &str = "No codec is registered for the extension tag of the provided value.";
+pub const UNKNOWN_EXTENSION_TARGET: &str = "No codec is registered for the provided type.";
+pub const EXTENSION_TARGET_MISMATCH: &str = "The codec of the provided extension tag does not decode to the requested type.";
+pub const NOT_A_DECIMAL: &str = "You cannot read a decimal out of something that is not a decimal.";
+pub const MALFORMED_DECIMAL: &str = "The provided decimal value has malformed data.";
+pub const INVALID_DECIMAL_TEXT: &str = "The provided text is not a decimal number.";
+pub const DECIMAL_OUT_OF_RANGE: &str = "The provided decimal does not fit the requested representation.";
+pub const NOT_A_BIG_INTEGER: &str = "You cannot read a big integer out of something that is not a big integer.";
+pub const MALFORMED_BIG_INTEGER: &str = "The provided big integer value has no data.";
+pub const INVALID_BIG_INTEGER_TEXT: &str = "The provided text is not an integer.";
+pub const NOT_A_SINGLE_CHAR: &str = "The provided string does not hold exactly one character.";
+pub const NOT_ASCII: &str = "The provided byte is not an ASCII character.";
+pub const BIG_INTEGER_OUT_OF_RANGE: &str = "The provided big integer does not fit the requested representation.";
+pub const PROTOBUF_NULL_NOT_SUPPORTED: &str = "Protobuf null has no equivalent YAD value.";
+pub const PROTOBUF_STRUCT_NOT_SUPPORTED: &str = "Protobuf structs cannot be converted to a single YAD value.";
+pub const PROTOBUF_KIND_MISSING: &str = "The provided protobuf value has no kind set.";
+pub const PROTOBUF_NUMBER_NOT_REPRESENTABLE: &str = "The integer cannot be represented exactly as a protobuf number.";
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct ErrorMessage(pub &'static str);
@@ -49,4 +91,11 @@ impl From<&'static str> for ErrorMessage {
     fn from(s: &'static str) -> Self {
         ErrorMessage(s)
     }
-}
\ No newline at end of file
+}
+/// Lets infallible conversions (`From<T> for Value`) be used wherever a
+/// `TryInto<Value>` whose error converts into `ErrorMessage` is accepted.
+impl From<std::convert::Infallible> for ErrorMessage {
+    fn from(never: std::convert::Infallible) -> Self {
+        match never {}
+    }
+}
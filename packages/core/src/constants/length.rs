@@ -1,3 +1,4 @@
+use std::fmt::{Display, Formatter};
 use crate::constants::error::{ErrorMessage, FAILED_TRANSFORMING_AN_U8_TO_VALID_LENGTH};
 
 /// Indicates an 0-bit length.
@@ -59,3 +60,9 @@ impl From<ByteLength> for usize {
     }
 }
 
+impl Display for ByteLength {
+    /// Writes the width in bits: `0`, `8`, `16`, `32` or `64`.
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_byte_count() as u32 * 8)
+    }
+}
@@ -1,41 +1,58 @@
+#![cfg_attr(feature = "prefixed-symbols", allow(unused_attributes))]
+use std::fmt::{Display, Formatter};
 use crate::constants::error::{ErrorMessage, FAILED_TRANSFORMING_AN_U8_TO_VALID_TYPE};
 
 /// > **NEEDS A LENGTH BYTE**
 ///
 /// Indicates an unsigned integer
 #[unsafe(no_mangle)]
+#[cfg_attr(feature = "prefixed-symbols", unsafe(export_name = "YAD_UNSIGNED_INTEGER_TYPE"))]
 pub static UNSIGNED_INTEGER_TYPE: u8 = 0x10;
 /// > **NEEDS A LENGTH BYTE**
 ///
 /// Indicates a signed integer
 #[unsafe(no_mangle)]
+#[cfg_attr(feature = "prefixed-symbols", unsafe(export_name = "YAD_SIGNED_INTEGER_TYPE"))]
 pub static SIGNED_INTEGER_TYPE: u8 = 0x20;
 /// > **NEEDS A LENGTH BYTE**
 ///
 /// Indicates a floating point number
 #[unsafe(no_mangle)]
+#[cfg_attr(feature = "prefixed-symbols", unsafe(export_name = "YAD_FLOATING_POINT_TYPE"))]
 pub static FLOATING_POINT_TYPE: u8 = 0x30;
 /// > **NEEDS A LENGTH BYTE**
 ///
 /// Indicates a floating point number
 #[unsafe(no_mangle)]
+#[cfg_attr(feature = "prefixed-symbols", unsafe(export_name = "YAD_STRING_TYPE"))]
 pub static STRING_TYPE: u8 = 0x40;
 /// > **NEEDS A LENGTH BYTE**
 ///
 /// Indicates a floating point number
 #[unsafe(no_mangle)]
+#[cfg_attr(feature = "prefixed-symbols", unsafe(export_name = "YAD_ARRAY_TYPE"))]
 pub static ARRAY_TYPE: u8 = 0x50;
 /// This is a Boolean unifier.
 ///
 /// Any value between `0x81` and `0x8F` is considered `true`, however each write will be truncated to `0x81`.
 #[unsafe(no_mangle)]
+#[cfg_attr(feature = "prefixed-symbols", unsafe(export_name = "YAD_BOOLEAN_TYPE"))]
 pub static BOOLEAN_TYPE: u8 = 0x8F;
 /// This is a Boolean with a value of `false`.
 #[unsafe(no_mangle)]
+#[cfg_attr(feature = "prefixed-symbols", unsafe(export_name = "YAD_FALSE_BOOLEAN_TYPE"))]
 pub static FALSE_BOOLEAN_TYPE: u8 = 0x80;
 /// This is a Boolean with a value of `true`.
 #[unsafe(no_mangle)]
+#[cfg_attr(feature = "prefixed-symbols", unsafe(export_name = "YAD_TRUE_BOOLEAN_TYPE"))]
 pub static TRUE_BOOLEAN_TYPE: u8 = 0x81;
+/// > **NEEDS A LENGTH BYTE**
+///
+/// Indicates an extension value: a tag byte naming an application-defined
+/// kind, followed by data only its codec understands (see `extension`).
+#[unsafe(no_mangle)]
+#[cfg_attr(feature = "prefixed-symbols", unsafe(export_name = "YAD_EXTENSION_TYPE"))]
+pub static EXTENSION_TYPE: u8 = 0xA0;
 
 #[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Debug)]
 #[repr(u8)]
@@ -47,7 +64,8 @@ pub enum Type {
     Array = ARRAY_TYPE,
     Bool = BOOLEAN_TYPE,
     False = FALSE_BOOLEAN_TYPE,
-    True = TRUE_BOOLEAN_TYPE
+    True = TRUE_BOOLEAN_TYPE,
+    Extension = EXTENSION_TYPE
 }
 
 impl TryFrom<u8> for Type {
@@ -62,6 +80,7 @@ impl TryFrom<u8> for Type {
             v if v & 0xF0 == BOOLEAN_TYPE => Ok(Type::Bool),
             v if v == FALSE_BOOLEAN_TYPE => Ok(Type::False),
             v if v == TRUE_BOOLEAN_TYPE => Ok(Type::True),
+            v if v & 0xF0 == EXTENSION_TYPE => Ok(Type::Extension),
             _ => Err(ErrorMessage(FAILED_TRANSFORMING_AN_U8_TO_VALID_TYPE)),
         }
     }
@@ -71,4 +90,19 @@ impl From<Type> for u8 {
     fn from(t: Type) -> u8 {
         t as u8
     }
-}
\ No newline at end of file
+}
+
+impl Display for Type {
+    /// Writes the name of the type: `uint`, `int`, `float`, `string`, `array`, `bool` or `extension`.
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Type::Uint => "uint",
+            Type::Int => "int",
+            Type::Float => "float",
+            Type::String => "string",
+            Type::Array => "array",
+            Type::Bool | Type::False | Type::True => "bool",
+            Type::Extension => "extension",
+        })
+    }
+}
@@ -0,0 +1,238 @@
+use std::cmp::Ordering;
+use std::fmt;
+use std::str::FromStr;
+use crate::constants::error::{
+    ErrorMessage, DECIMAL_OUT_OF_RANGE, INVALID_DECIMAL_TEXT, MALFORMED_DECIMAL, NOT_A_DECIMAL,
+};
+use crate::Value;
+
+/// Extension tag of decimal values, the first of the tags reserved for the
+/// format (see [`crate::extension::RESERVED_EXTENSION_TAGS`]).
+///
+/// The data is the scale, one byte, followed by the mantissa as a big-endian
+/// two's complement integer of 1 to 16 bytes, in the fewest bytes holding it.
+pub const DECIMAL_EXTENSION_TAG: u8 = 0x00;
+
+/// Longest mantissa, in bytes.
+const MAX_MANTISSA_BYTES: usize = 16;
+
+/// A fixed-point number, `mantissa × 10^-scale`, for values such as money
+/// that floats cannot hold exactly.
+///
+/// The scale is kept as written: `1.50` and `1.5` are different decimals that
+/// both round-trip unchanged. Use [`Decimal::cmp_value`] or
+/// [`Decimal::normalized`] to compare them by value.
+///
+/// # Example
+/// ```
+/// use yad_core::decimal::Decimal;
+/// use yad_core::Value;
+///
+/// let price: Decimal = "19.90".parse().unwrap();
+/// assert_eq!(price, Decimal::new(1990, 2));
+///
+/// let value = Value::from(price);
+/// assert_eq!(value.to_string(), "19.90");
+/// assert_eq!(TryInto::<Decimal>::try_into(value), Ok(price));
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Decimal {
+    /// The digits of the number, without the decimal point.
+    pub mantissa: i128,
+    /// How many of the digits are after the decimal point.
+    pub scale: u8,
+}
+
+impl Decimal {
+    /// Creates `mantissa × 10^-scale`.
+    pub fn new(mantissa: i128, scale: u8) -> Self {
+        Self { mantissa, scale }
+    }
+
+    /// Returns the same number without trailing zeros after the decimal point
+    /// (`1.500` becomes `1.5`, `2.0` becomes `2`).
+    pub fn normalized(&self) -> Self {
+        let mut decimal = *self;
+        while decimal.scale > 0 && decimal.mantissa % 10 == 0 {
+            decimal.mantissa /= 10;
+            decimal.scale -= 1;
+        }
+        decimal
+    }
+
+    /// Compares two decimals by the number they represent, whatever their scale.
+    pub fn cmp_value(&self, other: &Decimal) -> Ordering {
+        let (a, b) = (self.normalized(), other.normalized());
+        if let ordering @ (Ordering::Less | Ordering::Greater) = a.mantissa.signum().cmp(&b.mantissa.signum()) {
+            return ordering;
+        }
+
+        let scale = a.scale.max(b.scale);
+        let widen = |d: Decimal| 10i128.checked_pow((scale - d.scale) as u32).and_then(|p| d.mantissa.checked_mul(p));
+
+        // Both have the same sign, and the one at the common scale always
+        // fits: a mantissa that overflows when widened is the larger in magnitude.
+        match (widen(a), widen(b)) {
+            (Some(a), Some(b)) => a.cmp(&b),
+            (None, _) => if a.mantissa < 0 { Ordering::Less } else { Ordering::Greater },
+            (_, None) => if b.mantissa < 0 { Ordering::Greater } else { Ordering::Less },
+        }
+    }
+
+    /// Writes the data of the extension value holding this decimal.
+    pub(crate) fn to_extension_data(self) -> Vec<u8> {
+        let bytes = self.mantissa.to_be_bytes();
+        let fill = if self.mantissa < 0 { 0xFF } else { 0x00 };
+
+        // Drop sign-extension bytes, keeping the sign bit of the first byte left.
+        let mut start = 0;
+        while start < MAX_MANTISSA_BYTES - 1 && bytes[start] == fill && (bytes[start + 1] ^ fill) & 0x80 == 0 {
+            start += 1;
+        }
+
+        let mut data = Vec::with_capacity(1 + MAX_MANTISSA_BYTES - start);
+        data.push(self.scale);
+        data.extend_from_slice(&bytes[start..]);
+        data
+    }
+
+    /// Reads the data of an extension value tagged [`DECIMAL_EXTENSION_TAG`].
+    pub(crate) fn from_extension_data(data: &[u8]) -> Result<Self, ErrorMessage> {
+        let [scale, mantissa @ ..] = data else { return Err(ErrorMessage(MALFORMED_DECIMAL)) };
+        if mantissa.is_empty() || mantissa.len() > MAX_MANTISSA_BYTES {
+            return Err(ErrorMessage(MALFORMED_DECIMAL));
+        }
+
+        let fill = if mantissa[0] & 0x80 != 0 { 0xFF } else { 0x00 };
+        let mut bytes = [fill; MAX_MANTISSA_BYTES];
+        bytes[MAX_MANTISSA_BYTES - mantissa.len()..].copy_from_slice(mantissa);
+
+        Ok(Self { mantissa: i128::from_be_bytes(bytes), scale: *scale })
+    }
+}
+
+impl fmt::Display for Decimal {
+    /// Writes every digit, keeping trailing zeros: `Decimal::new(-5, 3)` is `-0.005`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let sign = if self.mantissa < 0 { "-" } else { "" };
+        let digits = self.mantissa.unsigned_abs().to_string();
+        let scale = self.scale as usize;
+
+        if scale == 0 {
+            return write!(f, "{}{}", sign, digits);
+        }
+
+        let digits = format!("{:0>width$}", digits, width = scale + 1);
+        let (integer, fraction) = digits.split_at(digits.len() - scale);
+        write!(f, "{}{}.{}", sign, integer, fraction)
+    }
+}
+
+impl FromStr for Decimal {
+    type Err = ErrorMessage;
+
+    /// Parses `[+-]digits[.digits]`; the scale is the number of digits after the point.
+    ///
+    /// # Errors
+    /// - `INVALID_DECIMAL_TEXT` for anything else, such as exponents or a bare point.
+    /// - `DECIMAL_OUT_OF_RANGE` if the digits do not fit in an `i128` or there
+    ///   are more than 255 of them after the point.
+    fn from_str(text: &str) -> Result<Self, ErrorMessage> {
+        let (sign, unsigned) = match text.strip_prefix('-') {
+            Some(rest) => ("-", rest),
+            None => ("", text.strip_prefix('+').unwrap_or(text)),
+        };
+        let (integer, fraction) = unsigned.split_once('.').unwrap_or((unsigned, ""));
+
+        let is_digits = |part: &str| !part.is_empty() && part.bytes().all(|b| b.is_ascii_digit());
+        if !is_digits(integer) || (unsigned.contains('.') && !is_digits(fraction)) {
+            return Err(ErrorMessage(INVALID_DECIMAL_TEXT));
+        }
+
+        let scale = u8::try_from(fraction.len()).map_err(|_| ErrorMessage(DECIMAL_OUT_OF_RANGE))?;
+        let mantissa = format!("{}{}{}", sign, integer, fraction).parse().map_err(|_| ErrorMessage(DECIMAL_OUT_OF_RANGE))?;
+
+        Ok(Self { mantissa, scale })
+    }
+}
+
+impl From<Decimal> for Value {
+    /// Encodes the decimal as an extension value tagged [`DECIMAL_EXTENSION_TAG`].
+    fn from(decimal: Decimal) -> Self {
+        Value::from_extension(DECIMAL_EXTENSION_TAG, &decimal.to_extension_data())
+            .expect("decimal data is at most 17 bytes")
+    }
+}
+
+impl TryInto<Decimal> for Value {
+    type Error = ErrorMessage;
+
+    /// Reads a decimal value.
+    ///
+    /// # Errors
+    /// Returns `NOT_A_DECIMAL` for other values, or `MALFORMED_DECIMAL` if the data is invalid.
+    fn try_into(self) -> Result<Decimal, Self::Error> {
+        self.as_decimal()
+    }
+}
+
+impl Value {
+    /// Returns `true` for decimal values (see [`Decimal`]).
+    pub fn is_decimal(&self) -> bool {
+        self.extension_tag() == Ok(DECIMAL_EXTENSION_TAG)
+    }
+
+    /// Reads a decimal value without consuming it.
+    ///
+    /// # Errors
+    /// Returns `NOT_A_DECIMAL` for other values, or `MALFORMED_DECIMAL` if the data is invalid.
+    pub fn as_decimal(&self) -> Result<Decimal, ErrorMessage> {
+        match self.extension_payload() {
+            Ok((DECIMAL_EXTENSION_TAG, data)) => Decimal::from_extension_data(data),
+            _ => Err(ErrorMessage(NOT_A_DECIMAL)),
+        }
+    }
+}
+
+#[cfg(feature = "decimal")]
+mod rust_decimal_impl {
+    use super::*;
+
+    impl From<rust_decimal::Decimal> for Decimal {
+        fn from(decimal: rust_decimal::Decimal) -> Self {
+            // `rust_decimal` scales never exceed 28.
+            Self { mantissa: decimal.mantissa(), scale: decimal.scale() as u8 }
+        }
+    }
+
+    impl TryFrom<Decimal> for rust_decimal::Decimal {
+        type Error = ErrorMessage;
+
+        /// Fails with `DECIMAL_OUT_OF_RANGE` if the mantissa does not fit in
+        /// 96 bits or the scale is above 28.
+        fn try_from(decimal: Decimal) -> Result<Self, ErrorMessage> {
+            rust_decimal::Decimal::try_from_i128_with_scale(decimal.mantissa, decimal.scale as u32)
+                .map_err(|_| ErrorMessage(DECIMAL_OUT_OF_RANGE))
+        }
+    }
+
+    impl From<rust_decimal::Decimal> for Value {
+        /// Encodes the decimal with its scale, so it reads back unchanged.
+        fn from(decimal: rust_decimal::Decimal) -> Self {
+            Value::from(Decimal::from(decimal))
+        }
+    }
+
+    impl TryInto<rust_decimal::Decimal> for Value {
+        type Error = ErrorMessage;
+
+        /// Reads a decimal value.
+        ///
+        /// # Errors
+        /// Returns the errors of [`Value::as_decimal`], or `DECIMAL_OUT_OF_RANGE`
+        /// if it does not fit a `rust_decimal::Decimal`.
+        fn try_into(self) -> Result<rust_decimal::Decimal, Self::Error> {
+            rust_decimal::Decimal::try_from(self.as_decimal()?)
+        }
+    }
+}
@@ -0,0 +1,259 @@
+use crate::byte_order::ByteOrder;
+use crate::constants::error::{ErrorMessage, MALFORMED_DELTA_ARRAY, NESTING_TOO_DEEP, NOT_ENOUGH_BYTES, NOT_SORTED};
+use crate::constants::length::ByteLength;
+use crate::constants::types::Type;
+use crate::varint::{read_varint, write_varint};
+use crate::{Reader, Value, MAX_NESTING_DEPTH};
+
+/// Header of a delta-encoded array. Its low nibble names the width of the
+/// unsigned integers the array holds.
+///
+/// # Binary Layout
+/// ```text
+/// +-------------------------------+--------------+---------------+---------------+------------------------+
+/// | DELTA_ARRAY_HEADER | ByteLength | Array header | Count varint  | First varint  | Zig-zag delta varints  |
+/// +-------------------------------+--------------+---------------+---------------+------------------------+
+/// ```
+/// The array header is the one of the plain array, so the array is rebuilt
+/// byte for byte; each delta is the difference to the previous item, wrapping
+/// at 64 bits.
+pub const DELTA_ARRAY_HEADER: u8 = 0x90;
+
+/// Maps a signed integer to an unsigned one so that small magnitudes of
+/// either sign stay small: 0, -1, 1, -2, ... become 0, 1, 2, 3, ...
+pub fn zigzag_encode(value: i64) -> u64 {
+    ((value << 1) ^ (value >> 63)) as u64
+}
+
+/// Inverse of [`zigzag_encode`].
+pub fn zigzag_decode(value: u64) -> i64 {
+    (value >> 1) as i64 ^ -((value & 1) as i64)
+}
+
+impl Value {
+    /// Builds an array of unsigned integers from sorted `values`, all of the
+    /// width of the largest one, so a serializer with delta arrays enabled
+    /// can write it as its first value followed by the differences between
+    /// neighbours (see [`transcode_to_delta`]).
+    ///
+    /// # Errors
+    /// Returns `NOT_SORTED` if a value is smaller than the one before it, or
+    /// `VEC_OF_LENGTH_ZERO` if `values` is empty.
+    pub fn from_sorted_u64s(values: &[u64]) -> Result<Self, ErrorMessage> {
+        if values.windows(2).any(|pair| pair[0] > pair[1]) {
+            return Err(ErrorMessage(NOT_SORTED));
+        }
+
+        let max = values.last().copied().unwrap_or_default();
+        let items: Vec<Value> = values.iter().map(|&value| match max {
+            m if m <= u8::MAX as u64 => Value::from(value as u8),
+            m if m <= u16::MAX as u64 => Value::from(value as u16),
+            m if m <= u32::MAX as u64 => Value::from(value as u32),
+            _ => Value::from(value),
+        }).collect();
+
+        Value::try_from(items)
+    }
+}
+
+/// Reads a number payload in `order`.
+fn read_payload(bytes: &[u8], order: ByteOrder) -> u64 {
+    let fold = |value: u64, byte: &u8| (value << 8) | *byte as u64;
+    match order {
+        ByteOrder::Big => bytes.iter().fold(0, fold),
+        ByteOrder::Little => bytes.iter().rev().fold(0, fold),
+    }
+}
+
+/// Appends the low `width` bytes of `value` in `order`.
+fn write_payload(value: u64, width: usize, order: ByteOrder, out: &mut Vec<u8>) {
+    let bytes = value.to_be_bytes();
+    let payload = &bytes[8 - width..];
+    match order {
+        ByteOrder::Big => out.extend_from_slice(payload),
+        ByteOrder::Little => out.extend(payload.iter().rev()),
+    }
+}
+
+/// Returns the delta encoding of the array at the start of `bytes`, if it
+/// holds at least two unsigned integers of one width and the encoding is
+/// shorter than `plain_len`, the size of the array as it is.
+fn try_delta(bytes: &[u8], plain_len: usize, order: ByteOrder) -> Result<Option<Vec<u8>>, ErrorMessage> {
+    let mut reader = Reader::new(bytes);
+    let header = reader.take(1)?[0];
+    let count = reader.take(ByteLength::try_from(header)?.as_byte_count() as usize)?;
+    let count = read_payload(count, ByteOrder::Big);
+
+    if count < 2 {
+        return Ok(None);
+    }
+
+    let Some(item_header) = reader.peek() else { return Ok(None) };
+    if !matches!(Type::try_from(item_header), Ok(Type::Uint)) {
+        return Ok(None);
+    }
+    let width = ByteLength::try_from(item_header)?.as_byte_count() as usize;
+    if width == 0 {
+        return Ok(None);
+    }
+
+    let mut out = vec![DELTA_ARRAY_HEADER | (item_header & 0x0F), header];
+    write_varint(count, &mut out);
+
+    let mut previous = 0u64;
+    for i in 0..count {
+        if reader.peek() != Some(item_header) {
+            return Ok(None);
+        }
+        reader.advance(1)?;
+        let value = read_payload(reader.take(width)?, order);
+
+        if i == 0 {
+            write_varint(value, &mut out);
+        } else {
+            write_varint(zigzag_encode(value.wrapping_sub(previous) as i64), &mut out);
+        }
+        previous = value;
+
+        if out.len() >= plain_len {
+            return Ok(None);
+        }
+    }
+
+    Ok(Some(out))
+}
+
+/// Copies the value at the reader's position to `out`, rewriting arrays of
+/// unsigned integers of one width (nested ones included) as delta arrays
+/// (see [`DELTA_ARRAY_HEADER`]) wherever that makes them shorter, as it
+/// does for arrays built by [`Value::from_sorted_u64s`] from closely spaced
+/// values. Other values are copied as they are.
+///
+/// `order` is the byte order of the number payloads, so deltas are taken
+/// between the numbers rather than their bytes.
+///
+/// # Errors
+/// Returns an error if the value is malformed or truncated.
+pub fn transcode_to_delta(reader: &mut Reader, out: &mut Vec<u8>, order: ByteOrder) -> Result<(), ErrorMessage> {
+    to_delta(reader, out, order, 0)
+}
+
+fn to_delta(reader: &mut Reader, out: &mut Vec<u8>, order: ByteOrder, depth: usize) -> Result<(), ErrorMessage> {
+    if depth > MAX_NESTING_DEPTH {
+        return Err(ErrorMessage(NESTING_TOO_DEEP));
+    }
+
+    let header = reader.peek().ok_or(ErrorMessage(NOT_ENOUGH_BYTES))?;
+    if Type::try_from(header)? != Type::Array {
+        out.extend_from_slice(reader.skip_value()?);
+        return Ok(());
+    }
+
+    let plain_len = Value::encoded_len(reader.remaining())?;
+    if let Some(delta) = try_delta(&reader.remaining()[..plain_len], plain_len, order)? {
+        out.extend_from_slice(&delta);
+        return reader.advance(plain_len);
+    }
+
+    let descriptor = 1 + ByteLength::try_from(header)?.as_byte_count() as usize;
+    let descriptor = reader.take(descriptor)?;
+    out.extend_from_slice(descriptor);
+
+    for _ in 0..read_payload(&descriptor[1..], ByteOrder::Big) {
+        to_delta(reader, out, order, depth + 1)?;
+    }
+
+    Ok(())
+}
+
+/// Copies a value written by [`transcode_to_delta`] at the reader's position
+/// to `out`, rebuilding its delta arrays as the plain arrays they were.
+///
+/// # Errors
+/// Returns `MALFORMED_DELTA_ARRAY` if a delta array is malformed, or another
+/// error if the value is malformed or truncated.
+pub fn transcode_from_delta(reader: &mut Reader, out: &mut Vec<u8>, order: ByteOrder) -> Result<(), ErrorMessage> {
+    from_delta(reader, out, order, 0)
+}
+
+fn from_delta(reader: &mut Reader, out: &mut Vec<u8>, order: ByteOrder, depth: usize) -> Result<(), ErrorMessage> {
+    if depth > MAX_NESTING_DEPTH {
+        return Err(ErrorMessage(NESTING_TOO_DEEP));
+    }
+
+    let header = reader.peek().ok_or(ErrorMessage(NOT_ENOUGH_BYTES))?;
+
+    if header & 0xF0 == DELTA_ARRAY_HEADER {
+        return expand_delta(reader, out, order);
+    }
+
+    if Type::try_from(header)? != Type::Array {
+        out.extend_from_slice(reader.skip_value()?);
+        return Ok(());
+    }
+
+    let descriptor = 1 + ByteLength::try_from(header)?.as_byte_count() as usize;
+    let descriptor = reader.take(descriptor)?;
+    out.extend_from_slice(descriptor);
+
+    for _ in 0..read_payload(&descriptor[1..], ByteOrder::Big) {
+        from_delta(reader, out, order, depth + 1)?;
+    }
+
+    Ok(())
+}
+
+fn expand_delta(reader: &mut Reader, out: &mut Vec<u8>, order: ByteOrder) -> Result<(), ErrorMessage> {
+    let malformed = |_| ErrorMessage(MALFORMED_DELTA_ARRAY);
+    let header = reader.take(1)?[0];
+    let width = ByteLength::try_from(header).map_err(malformed)?;
+    let array_header = reader.take(1)?[0];
+    let count_width = ByteLength::try_from(array_header).map_err(malformed)?;
+
+    if width == ByteLength::Zero || Type::try_from(array_header).ok() != Some(Type::Array) {
+        return Err(ErrorMessage(MALFORMED_DELTA_ARRAY));
+    }
+
+    let (count, consumed) = read_varint(reader.remaining())?;
+    reader.advance(consumed)?;
+
+    if count_width.as_byte_count() < 8 && count >> (8 * count_width.as_byte_count()) != 0 {
+        return Err(ErrorMessage(MALFORMED_DELTA_ARRAY));
+    }
+
+    out.push(array_header);
+    write_payload(count, count_width.as_byte_count() as usize, ByteOrder::Big, out);
+
+    let item_header = u8::from(Type::Uint) | u8::from(width);
+    let width = width.as_byte_count() as usize;
+    let mut value = 0u64;
+
+    for i in 0..count {
+        let (next, consumed) = read_varint(reader.remaining())?;
+        reader.advance(consumed)?;
+
+        value = if i == 0 { next } else { value.wrapping_add(zigzag_decode(next) as u64) };
+
+        out.push(item_header);
+        write_payload(value, width, order, out);
+    }
+
+    Ok(())
+}
+
+/// Returns the size of the delta array at the start of `bytes`, without rebuilding it.
+///
+/// # Errors
+/// Returns `NOT_ENOUGH_BYTES` if it is truncated.
+pub fn delta_encoded_len(bytes: &[u8]) -> Result<usize, ErrorMessage> {
+    let mut pos = 2;
+    let (count, consumed) = read_varint(bytes.get(pos..).ok_or(ErrorMessage(NOT_ENOUGH_BYTES))?)?;
+    pos += consumed;
+
+    for _ in 0..count {
+        let (_, consumed) = read_varint(bytes.get(pos..).ok_or(ErrorMessage(NOT_ENOUGH_BYTES))?)?;
+        pos += consumed;
+    }
+
+    Ok(pos)
+}
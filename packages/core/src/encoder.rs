@@ -0,0 +1,111 @@
+use crate::constants::error::{ErrorMessage, VEC_MAX_LENGTH_EXCEEDED, VEC_OF_LENGTH_ZERO};
+use crate::constants::types::Type;
+use crate::{match_len_min_bytes, Value, ValueBytes};
+
+/// Room left in front of the payload for the largest array header: one
+/// header byte and an eight-byte count.
+const HEADER_ROOM: usize = 9;
+
+/// Encodes an array one element at a time.
+///
+/// `Value::try_from(Vec<T>)` needs every element, and so the count, up front.
+/// An encoder appends each element's bytes to a single buffer as it arrives
+/// and writes the header and count in front of them on [`ArrayEncoder::finish`],
+/// so arrays can be built from iterators of unknown length without holding
+/// the elements themselves.
+///
+/// # Example
+/// ```text
+/// let mut encoder = ArrayEncoder::new();
+/// for reading in sensor.readings() {
+///     encoder.push(reading?);
+/// }
+/// let value = encoder.finish()?;
+/// ```
+#[derive(Clone, Debug)]
+pub struct ArrayEncoder {
+    buffer: Vec<u8>,
+    count: usize,
+}
+
+impl ArrayEncoder {
+    /// Creates an encoder with no elements.
+    pub fn new() -> Self {
+        Self::with_capacity(0)
+    }
+
+    /// Creates an encoder whose buffer holds `bytes` payload bytes before it reallocates.
+    pub fn with_capacity(bytes: usize) -> Self {
+        let mut buffer = Vec::with_capacity(HEADER_ROOM + bytes);
+        buffer.resize(HEADER_ROOM, 0);
+        Self { buffer, count: 0 }
+    }
+
+    /// Appends an element.
+    pub fn push<T: Into<Value>>(&mut self, item: T) {
+        self.push_value(&item.into());
+    }
+
+    /// Appends an already encoded element without taking it.
+    pub fn push_value(&mut self, item: &Value) {
+        self.buffer.extend_from_slice(item.bytes.as_slice());
+        self.count += 1;
+    }
+
+    /// Returns the number of elements pushed so far.
+    pub fn len(&self) -> usize {
+        self.count
+    }
+
+    /// Returns `true` if no element was pushed.
+    pub fn is_empty(&self) -> bool {
+        self.count == 0
+    }
+
+    /// Returns the number of payload bytes buffered so far.
+    pub fn payload_len(&self) -> usize {
+        self.buffer.len() - HEADER_ROOM
+    }
+
+    /// Writes the header and count in front of the buffered elements and
+    /// returns the array. The buffer becomes the value's bytes, shifted once
+    /// over the unused header room.
+    ///
+    /// # Errors
+    /// Returns `VEC_OF_LENGTH_ZERO` if no element was pushed.
+    pub fn finish(mut self) -> Result<Value, ErrorMessage> {
+        let length = match_len_min_bytes(self.count, VEC_OF_LENGTH_ZERO, VEC_MAX_LENGTH_EXCEEDED)?;
+        let count = (self.count as u64).to_be_bytes();
+        let count = &count[8 - length.as_byte_count() as usize..];
+        let start = HEADER_ROOM - 1 - count.len();
+
+        self.buffer[start] = u8::from(Type::Array) | u8::from(length);
+        self.buffer[start + 1..HEADER_ROOM].copy_from_slice(count);
+        self.buffer.drain(..start);
+
+        Ok(Value { r#type: Type::Array, length, bytes: ValueBytes::from_vec(self.buffer) })
+    }
+}
+
+impl Default for ArrayEncoder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Into<Value>> Extend<T> for ArrayEncoder {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for item in iter {
+            self.push(item);
+        }
+    }
+}
+
+impl<T: Into<Value>> FromIterator<T> for ArrayEncoder {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut encoder = Self::new();
+        encoder.extend(iter);
+        encoder
+    }
+}
+
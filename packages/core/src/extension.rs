@@ -0,0 +1,276 @@
+use std::any::{Any, TypeId};
+use std::collections::BTreeMap;
+use std::ops::RangeInclusive;
+use smallvec::smallvec;
+use crate::constants::error::{
+    ErrorMessage,
+    EXTENSION_MAX_LENGTH_EXCEEDED,
+    EXTENSION_OF_LENGTH_ZERO,
+    EXTENSION_TAG_RESERVED,
+    EXTENSION_TAG_TAKEN,
+    EXTENSION_TARGET_MISMATCH,
+    EXTENSION_TARGET_TAKEN,
+    NOT_AN_EXTENSION,
+    UNKNOWN_EXTENSION_TAG,
+    UNKNOWN_EXTENSION_TARGET,
+};
+use crate::constants::types::Type;
+use crate::{extend_bytes_with_len_bytes, match_len_min_bytes, Value, ValueBytes};
+
+/// Extension tags kept for kinds the format itself may define; applications
+/// register codecs for the tags above (`0x80..=0xFF`).
+pub const RESERVED_EXTENSION_TAGS: RangeInclusive<u8> = 0x00..=0x7F;
+
+/// Extension values: a header of type [`Type::Extension`], a length
+/// descriptor counting the bytes after it, a tag byte and the data.
+///
+/// The format only knows the tag and the length, so any reader can skip or
+/// copy extension values; turning the data into something meaningful is up
+/// to the [`ValueCodec`] registered for the tag.
+impl Value {
+    /// Encodes `data` as an extension value of kind `tag`. `data` may be empty.
+    ///
+    /// # Example
+    /// ```
+    /// use yad_core::Value;
+    ///
+    /// let value = Value::from_extension(0x81, &[0x0A, 0x0B]).unwrap();
+    /// assert_eq!(value.bytes.as_slice(), &[0xA1, 0x03, 0x81, 0x0A, 0x0B]);
+    /// assert_eq!(value.extension_tag(), Ok(0x81));
+    /// assert_eq!(value.extension_data(), Ok(&[0x0A, 0x0B][..]));
+    /// ```
+    ///
+    /// # Errors
+    /// Returns `EXTENSION_MAX_LENGTH_EXCEEDED` if the length does not fit in 64 bits.
+    pub fn from_extension(tag: u8, data: &[u8]) -> Result<Self, ErrorMessage> {
+        let r#type = Type::Extension;
+        let len = data.len().checked_add(1).ok_or(ErrorMessage(EXTENSION_MAX_LENGTH_EXCEEDED))?;
+        let length = match_len_min_bytes(len, EXTENSION_OF_LENGTH_ZERO, EXTENSION_MAX_LENGTH_EXCEEDED)?;
+
+        let mut bytes: ValueBytes = smallvec![u8::from(r#type) | u8::from(length)];
+        extend_bytes_with_len_bytes(len, &mut bytes, EXTENSION_OF_LENGTH_ZERO, EXTENSION_MAX_LENGTH_EXCEEDED)?;
+        bytes.push(tag);
+        bytes.extend_from_slice(data);
+
+        Ok(Self { r#type, length, bytes })
+    }
+
+    /// Returns the tag and data of an extension value.
+    pub(crate) fn extension_payload(&self) -> Result<(u8, &[u8]), ErrorMessage> {
+        if self.r#type != Type::Extension {
+            return Err(ErrorMessage(NOT_AN_EXTENSION));
+        }
+
+        match self.isolate_value_bytes() {
+            [tag, data @ ..] => Ok((*tag, data)),
+            [] => Err(ErrorMessage(EXTENSION_OF_LENGTH_ZERO)),
+        }
+    }
+
+    /// Returns the tag naming the kind of an extension value.
+    ///
+    /// # Errors
+    /// Returns `NOT_AN_EXTENSION` for other types.
+    pub fn extension_tag(&self) -> Result<u8, ErrorMessage> {
+        self.extension_payload().map(|(tag, _)| tag)
+    }
+
+    /// Returns the data of an extension value, without its tag.
+    ///
+    /// # Errors
+    /// Returns `NOT_AN_EXTENSION` for other types.
+    pub fn extension_data(&self) -> Result<&[u8], ErrorMessage> {
+        self.extension_payload().map(|(_, data)| data)
+    }
+}
+
+/// Encodes and decodes an application-defined kind of value, such as a
+/// decimal or a geo-point, to and from the data of an extension value.
+///
+/// # Example
+/// ```
+/// use yad_core::constants::error::ErrorMessage;
+/// use yad_core::extension::{ValueCodec, ValueCodecRegistry};
+///
+/// #[derive(Debug, PartialEq)]
+/// struct Point { lat: f32, lon: f32 }
+///
+/// struct PointCodec;
+///
+/// impl ValueCodec for PointCodec {
+///     type Target = Point;
+///
+///     fn name(&self) -> &str { "geo-point" }
+///
+///     fn encode(&self, point: &Point) -> Result<Vec<u8>, ErrorMessage> {
+///         Ok([point.lat.to_be_bytes(), point.lon.to_be_bytes()].concat())
+///     }
+///
+///     fn decode(&self, data: &[u8]) -> Result<Point, ErrorMessage> {
+///         let data: [u8; 8] = data.try_into().map_err(|_| ErrorMessage("A geo-point takes 8 bytes."))?;
+///         Ok(Point {
+///             lat: f32::from_be_bytes(data[..4].try_into().unwrap()),
+///             lon: f32::from_be_bytes(data[4..].try_into().unwrap()),
+///         })
+///     }
+/// }
+///
+/// let mut registry = ValueCodecRegistry::new();
+/// registry.register(0x80, PointCodec).unwrap();
+///
+/// let value = registry.encode(&Point { lat: 40.4, lon: -3.7 }).unwrap();
+/// assert_eq!(registry.decode::<Point>(&value), Ok(Point { lat: 40.4, lon: -3.7 }));
+/// ```
+pub trait ValueCodec: Send + Sync + 'static {
+    /// The Rust type values of this kind are read into.
+    type Target: 'static;
+
+    /// Returns a short name of the kind, for diagnostics.
+    fn name(&self) -> &str;
+
+    /// Writes `value` as extension data.
+    fn encode(&self, value: &Self::Target) -> Result<Vec<u8>, ErrorMessage>;
+
+    /// Reads extension data written by [`ValueCodec::encode`].
+    fn decode(&self, data: &[u8]) -> Result<Self::Target, ErrorMessage>;
+}
+
+/// A [`ValueCodec`] with its target type erased, so codecs of different
+/// types can share a registry.
+trait ErasedCodec: Send + Sync {
+    fn name(&self) -> &str;
+    fn target(&self) -> TypeId;
+    fn encode(&self, value: &dyn Any) -> Result<Vec<u8>, ErrorMessage>;
+    fn decode(&self, data: &[u8]) -> Result<Box<dyn Any>, ErrorMessage>;
+}
+
+impl<C: ValueCodec> ErasedCodec for C {
+    fn name(&self) -> &str {
+        ValueCodec::name(self)
+    }
+
+    fn target(&self) -> TypeId {
+        TypeId::of::<C::Target>()
+    }
+
+    fn encode(&self, value: &dyn Any) -> Result<Vec<u8>, ErrorMessage> {
+        let value = value.downcast_ref::<C::Target>().ok_or(ErrorMessage(EXTENSION_TARGET_MISMATCH))?;
+        ValueCodec::encode(self, value)
+    }
+
+    fn decode(&self, data: &[u8]) -> Result<Box<dyn Any>, ErrorMessage> {
+        ValueCodec::decode(self, data).map(|value| Box::new(value) as Box<dyn Any>)
+    }
+}
+
+/// The codecs an application knows, by extension tag.
+///
+/// Each tag and each target type has at most one codec, so values can be
+/// encoded by their type alone. Tags in [`RESERVED_EXTENSION_TAGS`] cannot be
+/// registered.
+#[derive(Default)]
+pub struct ValueCodecRegistry {
+    codecs: BTreeMap<u8, Box<dyn ErasedCodec>>,
+}
+
+impl ValueCodecRegistry {
+    /// Creates a registry without codecs.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers `codec` for the extension values tagged `tag`.
+    ///
+    /// # Errors
+    /// - `EXTENSION_TAG_RESERVED` for tags in [`RESERVED_EXTENSION_TAGS`].
+    /// - `EXTENSION_TAG_TAKEN` if `tag` already has a codec.
+    /// - `EXTENSION_TARGET_TAKEN` if another tag has a codec of the same target type.
+    pub fn register<C: ValueCodec>(&mut self, tag: u8, codec: C) -> Result<(), ErrorMessage> {
+        if RESERVED_EXTENSION_TAGS.contains(&tag) {
+            return Err(ErrorMessage(EXTENSION_TAG_RESERVED));
+        }
+        if self.codecs.contains_key(&tag) {
+            return Err(ErrorMessage(EXTENSION_TAG_TAKEN));
+        }
+        if self.tag_of(TypeId::of::<C::Target>()).is_some() {
+            return Err(ErrorMessage(EXTENSION_TARGET_TAKEN));
+        }
+
+        self.codecs.insert(tag, Box::new(codec));
+        Ok(())
+    }
+
+    /// Returns the name of the codec registered for `tag`, if any.
+    pub fn name(&self, tag: u8) -> Option<&str> {
+        self.codecs.get(&tag).map(|codec| codec.name())
+    }
+
+    /// Returns every registered tag, in ascending order.
+    pub fn tags(&self) -> impl Iterator<Item = u8> + '_ {
+        self.codecs.keys().copied()
+    }
+
+    /// Returns the tag whose codec reads into `target`.
+    fn tag_of(&self, target: TypeId) -> Option<u8> {
+        self.codecs.iter().find(|(_, codec)| codec.target() == target).map(|(tag, _)| *tag)
+    }
+
+    /// Encodes `value` as an extension value, with the tag of the codec
+    /// registered for `T`.
+    ///
+    /// # Errors
+    /// Returns `UNKNOWN_EXTENSION_TARGET` if no codec reads into `T`, or the
+    /// error of the codec.
+    pub fn encode<T: 'static>(&self, value: &T) -> Result<Value, ErrorMessage> {
+        let tag = self.tag_of(TypeId::of::<T>()).ok_or(ErrorMessage(UNKNOWN_EXTENSION_TARGET))?;
+        let data = self.codecs[&tag].encode(value)?;
+        Value::from_extension(tag, &data)
+    }
+
+    /// Decodes an extension value into a `T`, with the codec registered for its tag.
+    ///
+    /// # Errors
+    /// - `NOT_AN_EXTENSION` for other types.
+    /// - `UNKNOWN_EXTENSION_TAG` if no codec is registered for the tag of `value`.
+    /// - `EXTENSION_TARGET_MISMATCH` if that codec does not read into `T`.
+    /// - The error of the codec.
+    pub fn decode<T: 'static>(&self, value: &Value) -> Result<T, ErrorMessage> {
+        let (tag, data) = value.extension_payload()?;
+        let codec = self.codecs.get(&tag).ok_or(ErrorMessage(UNKNOWN_EXTENSION_TAG))?;
+        if codec.target() != TypeId::of::<T>() {
+            return Err(ErrorMessage(EXTENSION_TARGET_MISMATCH));
+        }
+
+        codec.decode(data)?.downcast::<T>().map(|value| *value).map_err(|_| ErrorMessage(EXTENSION_TARGET_MISMATCH))
+    }
+
+    /// Checks that every extension value in `value`, including array items,
+    /// has a registered codec that can decode it. Decimals and big integers
+    /// are checked by the format itself; other values are accepted.
+    ///
+    /// # Errors
+    /// Returns `UNKNOWN_EXTENSION_TAG`, the error of a codec, or the error of
+    /// an array that does not decode.
+    pub fn validate(&self, value: &Value) -> Result<(), ErrorMessage> {
+        match value.r#type {
+            Type::Extension if value.is_decimal() => value.as_decimal().map(|_| ()),
+            Type::Extension if value.is_big_integer() => value.as_big_integer().map(|_| ()),
+            Type::Extension => {
+                let (tag, data) = value.extension_payload()?;
+                let codec = self.codecs.get(&tag).ok_or(ErrorMessage(UNKNOWN_EXTENSION_TAG))?;
+                codec.decode(data).map(|_| ())
+            }
+            Type::Array => {
+                let items: Vec<Value> = value.clone().try_into()?;
+                items.iter().try_for_each(|item| self.validate(item))
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+impl std::fmt::Debug for ValueCodecRegistry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_map().entries(self.codecs.iter().map(|(tag, codec)| (tag, codec.name()))).finish()
+    }
+}
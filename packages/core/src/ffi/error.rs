@@ -0,0 +1,195 @@
+use std::cell::RefCell;
+use std::ffi::{c_char, CString};
+use std::panic::AssertUnwindSafe;
+use crate::constants::error::{ErrorMessage, ALLOCATION_LIMIT_EXCEEDED};
+
+/// Category of the last error reported by an FFI function.
+///
+/// Returned inside [`YadError`] by [`yad_last_error`]. `Ok` means no error has
+/// been recorded on the calling thread since the last [`yad_clear_last_error`].
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum YadErrorCode {
+    /// No error recorded.
+    Ok = 0,
+    /// A required pointer argument was null.
+    NullPointer = 1,
+    /// A C string argument was not valid UTF-8.
+    InvalidUtf8 = 2,
+    /// The input bytes could not be decoded.
+    Decode = 3,
+    /// The object could not be encoded.
+    Encode = 4,
+    /// The requested row, key or element does not exist.
+    NotFound = 5,
+    /// An index was out of bounds.
+    OutOfBounds = 6,
+    /// A `Value` holds a different type than the one requested.
+    Conversion = 7,
+    /// The output buffer was too small; the output was truncated.
+    BufferTooSmall = 8,
+    /// A panic was caught at the FFI boundary.
+    Panic = 9,
+    /// Decoding the input would allocate more than the caller's limit.
+    LimitExceeded = 10,
+}
+
+/// The last error reported on the calling thread, as returned by [`yad_last_error`].
+///
+/// # Fields
+/// - `code`: Category of the error, [`YadErrorCode::Ok`] if none.
+/// - `message`: Null-terminated description, or null if `code` is `Ok`.
+///   Owned by the library; valid until the next error is recorded or cleared on the same thread.
+#[repr(C)]
+pub struct YadError {
+    pub code: YadErrorCode,
+    pub message: *const c_char,
+}
+
+thread_local! {
+    static LAST_ERROR: RefCell<Option<(YadErrorCode, CString)>> = const { RefCell::new(None) };
+}
+
+/// Records the last error of the calling thread.
+///
+/// Called by every FFI function (in this crate and in `serde_yad`) right before
+/// it returns its failure value (null, `0` or `false`).
+pub fn set_last_error<M: Into<String>>(code: YadErrorCode, message: M) {
+    let message = CString::new(message.into().replace('\0', "\\0"))
+        .unwrap_or_default();
+    LAST_ERROR.with(|last| *last.borrow_mut() = Some((code, message)));
+}
+
+/// Records a null pointer error for `function`.
+///
+/// # Arguments
+/// - `function`: Name of the exported function.
+/// - `arguments`: The pointer argument(s) that may not be null, e.g. `` "`row` or `key`" ``.
+pub fn set_null_pointer_error(function: &str, arguments: &str) {
+    set_last_error(YadErrorCode::NullPointer, format!("{function}: {arguments} must not be null"));
+}
+
+/// Records an [`ErrorMessage`] returned by the Rust API as the last error of `function`.
+pub fn set_error_message(function: &str, code: YadErrorCode, error: ErrorMessage) {
+    set_last_error(code, format!("{function}: {}", error.0));
+}
+
+/// Records an [`ErrorMessage`] returned by a decoder as the last error of
+/// `function`: [`YadErrorCode::LimitExceeded`] for `ALLOCATION_LIMIT_EXCEEDED`,
+/// [`YadErrorCode::Decode`] for anything else.
+pub fn set_decode_error(function: &str, error: ErrorMessage) {
+    let code = match error.0 {
+        ALLOCATION_LIMIT_EXCEEDED => YadErrorCode::LimitExceeded,
+        _ => YadErrorCode::Decode,
+    };
+    set_error_message(function, code, error);
+}
+
+/// What an FFI function returns when it fails: null for pointers, `0` for
+/// numbers and `false` for booleans. [`catch_panic`] returns it when the body
+/// of the function panics.
+pub trait FailureValue {
+    fn failure() -> Self;
+}
+
+impl<T> FailureValue for *mut T {
+    fn failure() -> Self {
+        std::ptr::null_mut()
+    }
+}
+
+impl<T> FailureValue for *const T {
+    fn failure() -> Self {
+        std::ptr::null()
+    }
+}
+
+impl FailureValue for bool {
+    fn failure() -> Self {
+        false
+    }
+}
+
+impl FailureValue for u8 {
+    fn failure() -> Self {
+        0
+    }
+}
+
+impl FailureValue for u32 {
+    fn failure() -> Self {
+        0
+    }
+}
+
+impl FailureValue for usize {
+    fn failure() -> Self {
+        0
+    }
+}
+
+impl FailureValue for () {
+    fn failure() -> Self {}
+}
+
+impl FailureValue for YadError {
+    fn failure() -> Self {
+        YadError { code: YadErrorCode::Panic, message: std::ptr::null() }
+    }
+}
+
+/// Runs the body of the FFI function `function`, so a panic never unwinds
+/// across the C boundary, which is undefined behavior.
+///
+/// Every exported function wraps its body in it. A panic is recorded as a
+/// [`YadErrorCode::Panic`] error carrying the panic message, and the
+/// function returns its [`FailureValue`].
+pub fn catch_panic<T: FailureValue>(function: &str, body: impl FnOnce() -> T) -> T {
+    match std::panic::catch_unwind(AssertUnwindSafe(body)) {
+        Ok(value) => value,
+        Err(payload) => {
+            let reason = payload.downcast_ref::<&str>().copied()
+                .or_else(|| payload.downcast_ref::<String>().map(String::as_str))
+                .unwrap_or("unknown panic");
+            set_last_error(YadErrorCode::Panic, format!("{function}: panicked: {reason}"));
+            T::failure()
+        }
+    }
+}
+
+/// Returns the last error reported on the calling thread.
+///
+/// Functions only record errors, they never clear them: check the result of a
+/// call first (null, `0` or `false`) and only then ask for the reason.
+///
+/// # Returns
+/// - A [`YadError`] whose `message` stays valid until the next error is recorded or cleared on this thread.
+/// - `{ YadErrorCode::Ok, null }` if no error has been recorded.
+///
+/// # Example
+/// ```c
+/// Value* val = value_from_buffer(buf, len);
+/// if (val == NULL) {
+///     YadError err = yad_last_error();
+///     fprintf(stderr, "%d: %s\n", err.code, err.message);
+/// }
+/// ```
+#[unsafe(no_mangle)]
+#[cfg_attr(feature = "prefixed-symbols", unsafe(export_name = "yad_last_error"))]
+pub extern "C" fn yad_last_error() -> YadError {
+    catch_panic("yad_last_error", || {
+        LAST_ERROR.with(|last| match &*last.borrow() {
+            Some((code, message)) => YadError { code: *code, message: message.as_ptr() },
+            None => YadError { code: YadErrorCode::Ok, message: std::ptr::null() },
+        })
+    })
+}
+
+/// Clears the last error of the calling thread.
+#[unsafe(no_mangle)]
+#[cfg_attr(feature = "prefixed-symbols", unsafe(export_name = "yad_clear_last_error"))]
+pub extern "C" fn yad_clear_last_error() {
+    catch_panic("yad_clear_last_error", || {
+        LAST_ERROR.with(|last| *last.borrow_mut() = None);
+    })
+}
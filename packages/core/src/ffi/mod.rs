@@ -1,28 +1,101 @@
+#![cfg_attr(feature = "prefixed-symbols", allow(unused_attributes))]
+pub mod error;
 pub mod value;
 use crate::Value;
+use crate::ffi::error::{catch_panic, set_last_error, set_null_pointer_error, FailureValue, YadErrorCode};
 
-/// Frees a heap-allocated buffer previously returned from Rust.
+/// Version of the C ABI: the exported functions, their signatures and the
+/// `#[repr(C)]` types. It is bumped whenever one of them changes in a way
+/// that breaks callers built against an older header, and never otherwise.
+pub const YAD_ABI_VERSION: u32 = 1;
+
+/// Returns the [`YAD_ABI_VERSION`] the library was built with.
+///
+/// A shared library can be swapped under an application; compare this with
+/// the `YAD_ABI_VERSION` of the header the application was compiled against
+/// before making any other call.
+///
+/// # Example
+/// ```c
+/// if (yad_abi_version() != YAD_ABI_VERSION) {
+///     fprintf(stderr, "incompatible libyad\n");
+///     exit(1);
+/// }
+/// ```
+#[unsafe(no_mangle)]
+#[cfg_attr(feature = "prefixed-symbols", unsafe(export_name = "yad_abi_version"))]
+pub extern "C" fn yad_abi_version() -> u32 {
+    catch_panic("yad_abi_version", || YAD_ABI_VERSION)
+}
+
+/// A C-compatible byte buffer owned by Rust.
+///
+/// Returned by the `*_as_buffer` functions (`value_as_buffer`, `key_as_buffer`,
+/// `row_as_buffer`, `yad_as_buffer`). It carries its length, so callers never
+/// have to guess how many bytes were written, and its capacity, so
+/// [`byte_buffer_free`] can release the exact allocation.
+///
+/// # Fields
+/// - `ptr`: Pointer to the first byte. Null when the buffer is empty or the call failed.
+/// - `len`: Number of valid bytes.
+/// - `cap`: Allocated capacity. Do not modify.
+///
+/// # Safety
+/// The buffer must be released with [`byte_buffer_free`] exactly once, with all
+/// three fields unchanged.
+#[repr(C)]
+pub struct ByteBuffer {
+    pub ptr: *mut u8,
+    pub len: usize,
+    pub cap: usize,
+}
+
+impl ByteBuffer {
+    /// An empty buffer with a null pointer, returned on failure.
+    pub fn null() -> Self {
+        Self { ptr: std::ptr::null_mut(), len: 0, cap: 0 }
+    }
+
+    /// Hands the bytes of `vec` over to the caller.
+    pub fn from_vec(vec: Vec<u8>) -> Self {
+        if vec.capacity() == 0 {
+            return Self::null();
+        }
+
+        let mut vec = std::mem::ManuallyDrop::new(vec);
+        Self { ptr: vec.as_mut_ptr(), len: vec.len(), cap: vec.capacity() }
+    }
+}
+
+impl FailureValue for ByteBuffer {
+    fn failure() -> Self {
+        Self::null()
+    }
+}
+
+/// Frees a [`ByteBuffer`] returned by one of the `*_as_buffer` functions.
 ///
 /// # Parameters
-/// - `ptr`: Pointer to the buffer to free.
+/// - `buf`: The buffer, passed by value with its fields unchanged.
 ///
 /// # Safety
-/// - `ptr` must be a pointer previously returned from Rust (e.g., a buffer from `yad_as_buffer` or `row_as_buffer`) or null.
-/// - After calling this function, `ptr` must not be used again to avoid undefined behavior.
+/// - `buf` must come from this library and must not be freed twice.
+/// - A buffer with a null `ptr` is ignored.
 ///
 /// # Example
 /// ```c
-/// const uint8_t* buf = yad_as_buffer(yad);
-/// // ... use the buffer ...
-/// free_buffer((uint8_t*)buf);
+/// ByteBuffer buf = yad_as_buffer(yad);
+/// fwrite(buf.ptr, 1, buf.len, file);
+/// byte_buffer_free(buf);
 /// ```
 #[unsafe(no_mangle)]
-pub extern "C" fn free_buffer(ptr: *mut u8) {
-    if !ptr.is_null() {
-        unsafe {
-            let _ = Box::from_raw(ptr);
+#[cfg_attr(feature = "prefixed-symbols", unsafe(export_name = "yad_byte_buffer_free"))]
+pub extern "C" fn byte_buffer_free(buf: ByteBuffer) {
+    catch_panic("byte_buffer_free", || {
+        if !buf.ptr.is_null() {
+            unsafe { drop(Vec::from_raw_parts(buf.ptr, buf.len, buf.cap)) }
         }
-    }
+    })
 }
 
 /// A C-compatible wrapper around a Rust `Vec<Value>`
@@ -54,15 +127,18 @@ pub struct CArray {
 /// # Safety
 /// - The returned pointer must eventually be freed using `free_c_array` to prevent memory leaks.
 #[unsafe(no_mangle)]
+#[cfg_attr(feature = "prefixed-symbols", unsafe(export_name = "yad_c_array_new"))]
 pub extern "C" fn c_array_new() -> *mut CArray {
-    let mut vec: Vec<Value> = Vec::new();
-    let arr = CArray {
-        ptr: vec.as_mut_ptr(),
-        len: vec.len(),
-        cap: vec.capacity(),
-    };
-    std::mem::forget(vec); // Prevent Rust from deallocating
-    Box::into_raw(Box::new(arr))
+    catch_panic("c_array_new", || {
+        let mut vec: Vec<Value> = Vec::new();
+        let arr = CArray {
+            ptr: vec.as_mut_ptr(),
+            len: vec.len(),
+            cap: vec.capacity(),
+        };
+        std::mem::forget(vec); // Prevent Rust from deallocating
+        Box::into_raw(Box::new(arr))
+    })
 }
 
 /// Inserts a `Value` into the `CArray` at the specified index.
@@ -79,31 +155,36 @@ pub extern "C" fn c_array_new() -> *mut CArray {
 /// # Safety
 /// - Both `arr` and `value` must be valid, non-null pointers.
 #[unsafe(no_mangle)]
+#[cfg_attr(feature = "prefixed-symbols", unsafe(export_name = "yad_c_array_insert"))]
 pub extern "C" fn c_array_insert(arr: *mut CArray, index: usize, value: *mut Value) -> bool {
-    if arr.is_null() || value.is_null() {
-        return false;
-    }
-    unsafe {
-        let mut vec = Vec::from_raw_parts((*arr).ptr, (*arr).len, (*arr).cap);
-        if index > vec.len() {
+    catch_panic("c_array_insert", || {
+        if arr.is_null() || value.is_null() {
+            set_null_pointer_error("c_array_insert", "`arr` or `value`");
+            return false;
+        }
+        unsafe {
+            let mut vec = Vec::from_raw_parts((*arr).ptr, (*arr).len, (*arr).cap);
+            if index > vec.len() {
+                let arr_mut = &mut *arr;
+                arr_mut.ptr = vec.as_mut_ptr();
+                arr_mut.len = vec.len();
+                arr_mut.cap = vec.capacity();
+                std::mem::forget(vec);
+                set_last_error(YadErrorCode::OutOfBounds, format!("c_array_insert: index {index} is past the end of the array"));
+                return false;
+            }
+
+            let val: Value = *Box::from_raw(value);
+            vec.insert(index, val);
+
             let arr_mut = &mut *arr;
             arr_mut.ptr = vec.as_mut_ptr();
             arr_mut.len = vec.len();
             arr_mut.cap = vec.capacity();
             std::mem::forget(vec);
-            return false;
         }
-
-        let val: Value = *Box::from_raw(value);
-        vec.insert(index, val);
-
-        let arr_mut = &mut *arr;
-        arr_mut.ptr = vec.as_mut_ptr();
-        arr_mut.len = vec.len();
-        arr_mut.cap = vec.capacity();
-        std::mem::forget(vec);
-    }
-    true
+        true
+    })
 }
 
 /// Pushes a new `Value` into a `CArray`.
@@ -141,38 +222,43 @@ pub extern "C" fn c_array_insert(arr: *mut CArray, index: usize, value: *mut Val
 /// bool ok = c_array_push(arr, val);
 /// ```
 #[unsafe(no_mangle)]
+#[cfg_attr(feature = "prefixed-symbols", unsafe(export_name = "yad_c_array_push"))]
 pub extern "C" fn c_array_push(arr: *mut CArray, value: *mut Value) -> bool {
-    if arr.is_null() || value.is_null() {
-        return false;
-    }
-    unsafe {
-        // Prevent exceeding maximum addressable size
-        if (*arr).cap >= isize::MAX as usize {
+    catch_panic("c_array_push", || {
+        if arr.is_null() || value.is_null() {
+            set_null_pointer_error("c_array_push", "`arr` or `value`");
             return false;
         }
+        unsafe {
+            // Prevent exceeding maximum addressable size
+            if (*arr).cap >= isize::MAX as usize {
+                set_last_error(YadErrorCode::OutOfBounds, "c_array_push: the array is at its maximum capacity");
+                return false;
+            }
 
-        // Rebuild Vec<Value> from raw parts
-        let mut vec = Vec::from_raw_parts((*arr).ptr, (*arr).len, (*arr).cap);
+            // Rebuild Vec<Value> from raw parts
+            let mut vec = Vec::from_raw_parts((*arr).ptr, (*arr).len, (*arr).cap);
 
-        // Take ownership of the Value pointer
-        let val: Value = *Box::from_raw(value);
+            // Take ownership of the Value pointer
+            let val: Value = *Box::from_raw(value);
 
-        // Grow capacity if full
-        if vec.len() == vec.capacity() {
-            vec.reserve(1);
-        }
-        vec.push(val);
+            // Grow capacity if full
+            if vec.len() == vec.capacity() {
+                vec.reserve(1);
+            }
+            vec.push(val);
 
-        // Update array metadata
-        let arr_mut = &mut *arr;
-        arr_mut.ptr = vec.as_mut_ptr();
-        arr_mut.len = vec.len();
-        arr_mut.cap = vec.capacity();
+            // Update array metadata
+            let arr_mut = &mut *arr;
+            arr_mut.ptr = vec.as_mut_ptr();
+            arr_mut.len = vec.len();
+            arr_mut.cap = vec.capacity();
 
-        // Prevent Vec from freeing its buffer
-        std::mem::forget(vec);
-    }
-    true
+            // Prevent Vec from freeing its buffer
+            std::mem::forget(vec);
+        }
+        true
+    })
 }
 
 
@@ -209,30 +295,31 @@ pub extern "C" fn c_array_push(arr: *mut CArray, value: *mut Value) -> bool {
 /// # Notes
 /// - The implementation now avoids temporary ownership of the buffer by using
 ///   `slice::from_raw_parts` instead of `Vec::from_raw_parts`.
-/// - The body is wrapped in `catch_unwind` to prevent panics from propagating
-///   across the FFI boundary.
 #[unsafe(no_mangle)]
+#[cfg_attr(feature = "prefixed-symbols", unsafe(export_name = "yad_c_array_get"))]
 pub extern "C" fn c_array_get(arr: *mut CArray, index: usize) -> *mut Value {
-    if arr.is_null() {
-        return std::ptr::null_mut();
-    }
+    catch_panic("c_array_get", || {
+        if arr.is_null() {
+            set_null_pointer_error("c_array_get", "`arr`");
+            return std::ptr::null_mut();
+        }
 
-    let result = std::panic::catch_unwind(|| unsafe {
-        let arr_ref = &*arr;
+        unsafe {
+            let arr_ref = &*arr;
 
-        debug_assert!(arr_ref.len <= arr_ref.cap);
-        debug_assert!(arr_ref.ptr.is_null() == (arr_ref.len == 0));
+            debug_assert!(arr_ref.len <= arr_ref.cap);
+            debug_assert!(arr_ref.ptr.is_null() == (arr_ref.len == 0));
 
-        let slice = std::slice::from_raw_parts(arr_ref.ptr, arr_ref.len);
+            let slice = std::slice::from_raw_parts(arr_ref.ptr, arr_ref.len);
 
-        if let Some(v) = slice.get(index) {
-            Box::into_raw(Box::new(v.clone()))
-        } else {
-            std::ptr::null_mut()
+            if let Some(v) = slice.get(index) {
+                Box::into_raw(Box::new(v.clone()))
+            } else {
+                set_last_error(YadErrorCode::OutOfBounds, format!("c_array_get: index {index} is out of bounds"));
+                std::ptr::null_mut()
+            }
         }
-    });
-
-    result.unwrap_or_else(|_| std::ptr::null_mut())
+    })
 }
 
 /// Removes a value from the `CArray` at the specified index.
@@ -250,33 +337,38 @@ pub extern "C" fn c_array_get(arr: *mut CArray, index: usize) -> *mut Value {
 /// - `arr` must be a valid pointer.
 /// - `out` can be null if the removed value does not need to be retrieved.
 #[unsafe(no_mangle)]
+#[cfg_attr(feature = "prefixed-symbols", unsafe(export_name = "yad_c_array_remove"))]
 pub extern "C" fn c_array_remove(arr: *mut CArray, index: usize, out: *mut Value) -> bool {
-    if arr.is_null() {
-        return false;
-    }
-    unsafe {
-        let mut vec = Vec::from_raw_parts((*arr).ptr, (*arr).len, (*arr).cap);
-        if index >= vec.len() {
+    catch_panic("c_array_remove", || {
+        if arr.is_null() {
+            set_null_pointer_error("c_array_remove", "`arr`");
+            return false;
+        }
+        unsafe {
+            let mut vec = Vec::from_raw_parts((*arr).ptr, (*arr).len, (*arr).cap);
+            if index >= vec.len() {
+                let arr_mut = &mut *arr;
+                arr_mut.ptr = vec.as_mut_ptr();
+                arr_mut.len = vec.len();
+                arr_mut.cap = vec.capacity();
+                std::mem::forget(vec);
+                set_last_error(YadErrorCode::OutOfBounds, format!("c_array_remove: index {index} is out of bounds"));
+                return false;
+            }
+
+            let removed = vec.remove(index);
+            if !out.is_null() {
+                std::ptr::write(out, removed.clone());
+            }
+
             let arr_mut = &mut *arr;
             arr_mut.ptr = vec.as_mut_ptr();
             arr_mut.len = vec.len();
             arr_mut.cap = vec.capacity();
             std::mem::forget(vec);
-            return false;
         }
-
-        let removed = vec.remove(index);
-        if !out.is_null() {
-            std::ptr::write(out, removed.clone());
-        }
-
-        let arr_mut = &mut *arr;
-        arr_mut.ptr = vec.as_mut_ptr();
-        arr_mut.len = vec.len();
-        arr_mut.cap = vec.capacity();
-        std::mem::forget(vec);
-    }
-    true
+        true
+    })
 }
 
 /// Returns the number of elements in the `CArray`.
@@ -287,11 +379,15 @@ pub extern "C" fn c_array_remove(arr: *mut CArray, index: usize, out: *mut Value
 /// # Returns
 /// - Length of the array, or 0 if the pointer is null.
 #[unsafe(no_mangle)]
+#[cfg_attr(feature = "prefixed-symbols", unsafe(export_name = "yad_c_array_length"))]
 pub extern "C" fn c_array_length(arr: *mut CArray) -> usize {
-    if arr.is_null() {
-        return 0;
-    }
-    unsafe { (*arr).len }
+    catch_panic("c_array_length", || {
+        if arr.is_null() {
+            set_null_pointer_error("c_array_length", "`arr`");
+            return 0;
+        }
+        unsafe { (*arr).len }
+    })
 }
 
 /// Returns the capacity of the `CArray`.
@@ -302,11 +398,15 @@ pub extern "C" fn c_array_length(arr: *mut CArray) -> usize {
 /// # Returns
 /// - Capacity of the array, or 0 if the pointer is null.
 #[unsafe(no_mangle)]
+#[cfg_attr(feature = "prefixed-symbols", unsafe(export_name = "yad_c_array_capacity"))]
 pub extern "C" fn c_array_capacity(arr: *mut CArray) -> usize {
-    if arr.is_null() {
-        return 0;
-    }
-    unsafe { (*arr).cap }
+    catch_panic("c_array_capacity", || {
+        if arr.is_null() {
+            set_null_pointer_error("c_array_capacity", "`arr`");
+            return 0;
+        }
+        unsafe { (*arr).cap }
+    })
 }
 
 /// Returns a raw pointer to the internal buffer of the CArray and its length.
@@ -322,15 +422,19 @@ pub extern "C" fn c_array_capacity(arr: *mut CArray) -> usize {
 /// - The caller does not own the Values; they are still owned by the CArray.
 /// - Thread-safety: the CArray must not be mutated concurrently.
 #[unsafe(no_mangle)]
+#[cfg_attr(feature = "prefixed-symbols", unsafe(export_name = "yad_c_array_as_ptr"))]
 pub extern "C" fn c_array_as_ptr(arr: *mut CArray, out_len: *mut usize) -> *mut *mut Value {
-    if arr.is_null() || out_len.is_null() {
-        return std::ptr::null_mut();
-    }
+    catch_panic("c_array_as_ptr", || {
+        if arr.is_null() || out_len.is_null() {
+            set_null_pointer_error("c_array_as_ptr", "`arr` or `out_len`");
+            return std::ptr::null_mut();
+        }
 
-    unsafe {
-        *out_len = (*arr).len;
-        (*arr).ptr as *mut *mut Value
-    }
+        unsafe {
+            *out_len = (*arr).len;
+            (*arr).ptr as *mut *mut Value
+        }
+    })
 }
 
 /// Frees a `CArray` and its underlying memory.
@@ -342,15 +446,18 @@ pub extern "C" fn c_array_as_ptr(arr: *mut CArray, out_len: *mut usize) -> *mut
 /// - `arr` must be a pointer previously returned by `c_array_new`.
 /// - After calling this function, `arr` must not be used again.
 #[unsafe(no_mangle)]
+#[cfg_attr(feature = "prefixed-symbols", unsafe(export_name = "yad_free_c_array"))]
 pub extern "C" fn free_c_array(arr: *mut CArray) {
-    if arr.is_null() {
-        return;
-    }
-    unsafe {
-        let c_arr = Box::from_raw(arr);
+    catch_panic("free_c_array", || {
+        if arr.is_null() {
+            return;
+        }
+        unsafe {
+            let c_arr = Box::from_raw(arr);
 
-        if !c_arr.ptr.is_null() {
-            drop(Vec::from_raw_parts(c_arr.ptr, c_arr.len, c_arr.cap));
+            if !c_arr.ptr.is_null() {
+                drop(Vec::from_raw_parts(c_arr.ptr, c_arr.len, c_arr.cap));
+            }
         }
-    }
+    })
 }
\ No newline at end of file
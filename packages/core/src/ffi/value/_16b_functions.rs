@@ -1,5 +1,6 @@
 use float16::f16;
 use crate::Value;
+use crate::ffi::error::{catch_panic, set_last_error, set_null_pointer_error, YadErrorCode};
 
 /// Creates a new [`Value`] containing an unsigned 16-bit integer (`u16`)
 /// and returns a raw pointer suitable for FFI.
@@ -15,8 +16,11 @@ use crate::Value;
 /// - The pointer is allocated on the heap and must be manually deallocated.
 /// - Must not be dereferenced after being freed.
 #[unsafe(no_mangle)]
+#[cfg_attr(feature = "prefixed-symbols", unsafe(export_name = "yad_value_from_uint_16"))]
 pub extern "C" fn value_from_uint_16(val: u16) -> *mut Value {
-    Box::into_raw(Box::new(Value::from(val)))
+    catch_panic("value_from_uint_16", || {
+        Box::into_raw(Box::new(Value::from(val)))
+    })
 }
 
 /// Creates a new [`Value`] containing a signed 16-bit integer (`i16`)
@@ -33,8 +37,11 @@ pub extern "C" fn value_from_uint_16(val: u16) -> *mut Value {
 /// - The pointer must be properly deallocated.
 /// - Must not be dereferenced after being freed.
 #[unsafe(no_mangle)]
+#[cfg_attr(feature = "prefixed-symbols", unsafe(export_name = "yad_value_from_int_16"))]
 pub extern "C" fn value_from_int_16(val: i16) -> *mut Value {
-    Box::into_raw(Box::new(Value::from(val)))
+    catch_panic("value_from_int_16", || {
+        Box::into_raw(Box::new(Value::from(val)))
+    })
 }
 
 /// Creates a new [`Value`] representing a 16-bit floating point (`f16`)
@@ -52,8 +59,11 @@ pub extern "C" fn value_from_int_16(val: i16) -> *mut Value {
 /// - Must not be dereferenced after being freed.
 /// - Precision loss may occur during the conversion from `f32` to `f16`.
 #[unsafe(no_mangle)]
+#[cfg_attr(feature = "prefixed-symbols", unsafe(export_name = "yad_value_as_f16_from_float"))]
 pub extern "C" fn value_as_f16_from_float(val: f32) -> *mut Value {
-    Box::into_raw(Box::new(Value::from(f16::from_f32(val))))
+    catch_panic("value_as_f16_from_float", || {
+        Box::into_raw(Box::new(Value::from(f16::from_f32(val))))
+    })
 }
 
 /// Extracts a 16-bit floating point value (`f16`) from a [`Value`] and
@@ -71,18 +81,23 @@ pub extern "C" fn value_as_f16_from_float(val: f32) -> *mut Value {
 /// - Both `value` and `out` must be valid, non-null pointers.
 /// - `out` must point to a valid writable memory location.
 #[unsafe(no_mangle)]
+#[cfg_attr(feature = "prefixed-symbols", unsafe(export_name = "yad_float_from_f16_value"))]
 pub extern "C" fn float_from_f16_value(value: *mut Value, out: *mut f32) -> bool {
-    if value.is_null() {
-        return false;
-    }
-    unsafe {
-        if let Ok(num) = <Value as TryInto<f16>>::try_into((&*value).to_owned()) {
-            *out = num.to_f32();
-            true
-        } else {
-            false
+    catch_panic("float_from_f16_value", || {
+        if value.is_null() || out.is_null() {
+            set_null_pointer_error("float_from_f16_value", "`value` or `out`");
+            return false;
         }
-    }
+        unsafe {
+            if let Ok(num) = <Value as TryInto<f16>>::try_into((&*value).to_owned()) {
+                *out = num.to_f32();
+                true
+            } else {
+                set_last_error(YadErrorCode::Conversion, "float_from_f16_value: `value` does not hold a `f16`");
+                false
+            }
+        }
+    })
 }
 
 /// Extracts an unsigned 16-bit integer (`u16`) from a [`Value`] and writes
@@ -100,18 +115,23 @@ pub extern "C" fn float_from_f16_value(value: *mut Value, out: *mut f32) -> bool
 /// - Both `value` and `out` must be valid, non-null pointers.
 /// - `out` must point to a valid writable memory location.
 #[unsafe(no_mangle)]
+#[cfg_attr(feature = "prefixed-symbols", unsafe(export_name = "yad_uint16_from_value"))]
 pub extern "C" fn uint16_from_value(value: *mut Value, out: *mut u16) -> bool {
-    if value.is_null() {
-        return false;
-    }
-    unsafe {
-        if let Ok(num) = (&*value).try_into() {
-            *out = num;
-            true
-        } else {
-            false
+    catch_panic("uint16_from_value", || {
+        if value.is_null() || out.is_null() {
+            set_null_pointer_error("uint16_from_value", "`value` or `out`");
+            return false;
+        }
+        unsafe {
+            if let Ok(num) = (&*value).try_into() {
+                *out = num;
+                true
+            } else {
+                set_last_error(YadErrorCode::Conversion, "uint16_from_value: `value` does not hold a `u16`");
+                false
+            }
         }
-    }
+    })
 }
 
 /// Extracts a signed 16-bit integer (`i16`) from a [`Value`] and writes
@@ -129,16 +149,21 @@ pub extern "C" fn uint16_from_value(value: *mut Value, out: *mut u16) -> bool {
 /// - Both `value` and `out` must be valid, non-null pointers.
 /// - `out` must point to a valid writable memory location.
 #[unsafe(no_mangle)]
+#[cfg_attr(feature = "prefixed-symbols", unsafe(export_name = "yad_int16_from_value"))]
 pub extern "C" fn int16_from_value(value: *mut Value, out: *mut i16) -> bool {
-    if value.is_null() {
-        return false;
-    }
-    unsafe {
-        if let Ok(num) = (&*value).try_into() {
-            *out = num;
-            true
-        } else {
-            false
+    catch_panic("int16_from_value", || {
+        if value.is_null() || out.is_null() {
+            set_null_pointer_error("int16_from_value", "`value` or `out`");
+            return false;
+        }
+        unsafe {
+            if let Ok(num) = (&*value).try_into() {
+                *out = num;
+                true
+            } else {
+                set_last_error(YadErrorCode::Conversion, "int16_from_value: `value` does not hold a `i16`");
+                false
+            }
         }
-    }
+    })
 }
@@ -1,4 +1,5 @@
 use crate::Value;
+use crate::ffi::error::{catch_panic, set_last_error, set_null_pointer_error, YadErrorCode};
 
 /// Creates a new [`Value`] containing an unsigned 32-bit integer (`u32`)
 /// and returns a raw pointer suitable for FFI.
@@ -14,8 +15,11 @@ use crate::Value;
 /// - The pointer is heap-allocated and must be manually deallocated.
 /// - Must not be dereferenced after being freed.
 #[unsafe(no_mangle)]
+#[cfg_attr(feature = "prefixed-symbols", unsafe(export_name = "yad_value_from_uint_32"))]
 pub extern "C" fn value_from_uint_32(val: u32) -> *mut Value {
-    Box::into_raw(Box::new(Value::from(val)))
+    catch_panic("value_from_uint_32", || {
+        Box::into_raw(Box::new(Value::from(val)))
+    })
 }
 
 /// Creates a new [`Value`] containing a signed 32-bit integer (`i32`)
@@ -32,8 +36,11 @@ pub extern "C" fn value_from_uint_32(val: u32) -> *mut Value {
 /// - The pointer must be manually deallocated to prevent memory leaks.
 /// - Must not be used after being freed.
 #[unsafe(no_mangle)]
+#[cfg_attr(feature = "prefixed-symbols", unsafe(export_name = "yad_value_from_int_32"))]
 pub extern "C" fn value_from_int_32(val: i32) -> *mut Value {
-    Box::into_raw(Box::new(Value::from(val)))
+    catch_panic("value_from_int_32", || {
+        Box::into_raw(Box::new(Value::from(val)))
+    })
 }
 
 /// Creates a new [`Value`] containing a 32-bit floating point (`f32`)
@@ -50,8 +57,11 @@ pub extern "C" fn value_from_int_32(val: i32) -> *mut Value {
 /// - The pointer is heap-allocated and must be manually freed.
 /// - Must not be dereferenced after being freed.
 #[unsafe(no_mangle)]
+#[cfg_attr(feature = "prefixed-symbols", unsafe(export_name = "yad_value_from_float"))]
 pub extern "C" fn value_from_float(val: f32) -> *mut Value {
-    Box::into_raw(Box::new(Value::from(val)))
+    catch_panic("value_from_float", || {
+        Box::into_raw(Box::new(Value::from(val)))
+    })
 }
 
 /// Extracts a 32-bit floating point (`f32`) from a [`Value`] and writes it
@@ -69,18 +79,23 @@ pub extern "C" fn value_from_float(val: f32) -> *mut Value {
 /// - Both `value` and `out` must be valid, non-null pointers.
 /// - `out` must point to a valid writable memory location.
 #[unsafe(no_mangle)]
+#[cfg_attr(feature = "prefixed-symbols", unsafe(export_name = "yad_float_from_value"))]
 pub extern "C" fn float_from_value(value: *mut Value, out: *mut f32) -> bool {
-    if value.is_null() {
-        return false;
-    }
-    unsafe {
-        if let Ok(num) = (&*value).try_into() {
-            *out = num;
-            true
-        } else {
-            false
+    catch_panic("float_from_value", || {
+        if value.is_null() || out.is_null() {
+            set_null_pointer_error("float_from_value", "`value` or `out`");
+            return false;
         }
-    }
+        unsafe {
+            if let Ok(num) = (&*value).try_into() {
+                *out = num;
+                true
+            } else {
+                set_last_error(YadErrorCode::Conversion, "float_from_value: `value` does not hold a `f32`");
+                false
+            }
+        }
+    })
 }
 
 /// Extracts an unsigned 32-bit integer (`u32`) from a [`Value`] and writes
@@ -98,18 +113,23 @@ pub extern "C" fn float_from_value(value: *mut Value, out: *mut f32) -> bool {
 /// - Both `value` and `out` must be valid, non-null pointers.
 /// - `out` must point to a valid writable memory location.
 #[unsafe(no_mangle)]
+#[cfg_attr(feature = "prefixed-symbols", unsafe(export_name = "yad_uint32_from_value"))]
 pub extern "C" fn uint32_from_value(value: *mut Value, out: *mut u32) -> bool {
-    if value.is_null() {
-        return false;
-    }
-    unsafe {
-        if let Ok(num) = (&*value).try_into() {
-            *out = num;
-            true
-        } else {
-            false
+    catch_panic("uint32_from_value", || {
+        if value.is_null() || out.is_null() {
+            set_null_pointer_error("uint32_from_value", "`value` or `out`");
+            return false;
+        }
+        unsafe {
+            if let Ok(num) = (&*value).try_into() {
+                *out = num;
+                true
+            } else {
+                set_last_error(YadErrorCode::Conversion, "uint32_from_value: `value` does not hold a `u32`");
+                false
+            }
         }
-    }
+    })
 }
 
 /// Extracts a signed 32-bit integer (`i32`) from a [`Value`] and writes
@@ -127,16 +147,21 @@ pub extern "C" fn uint32_from_value(value: *mut Value, out: *mut u32) -> bool {
 /// - Both `value` and `out` must be valid, non-null pointers.
 /// - `out` must point to a valid writable memory location.
 #[unsafe(no_mangle)]
+#[cfg_attr(feature = "prefixed-symbols", unsafe(export_name = "yad_int32_from_value"))]
 pub extern "C" fn int32_from_value(value: *mut Value, out: *mut i32) -> bool {
-    if value.is_null() {
-        return false;
-    }
-    unsafe {
-        if let Ok(num) = (&*value).try_into() {
-            *out = num;
-            true
-        } else {
-            false
+    catch_panic("int32_from_value", || {
+        if value.is_null() || out.is_null() {
+            set_null_pointer_error("int32_from_value", "`value` or `out`");
+            return false;
+        }
+        unsafe {
+            if let Ok(num) = (&*value).try_into() {
+                *out = num;
+                true
+            } else {
+                set_last_error(YadErrorCode::Conversion, "int32_from_value: `value` does not hold a `i32`");
+                false
+            }
         }
-    }
+    })
 }
@@ -1,4 +1,5 @@
 use crate::Value;
+use crate::ffi::error::{catch_panic, set_last_error, set_null_pointer_error, YadErrorCode};
 
 /// Creates a new [`Value`] containing an unsigned 64-bit integer (`u64`)
 /// and returns a raw pointer suitable for FFI.
@@ -14,8 +15,11 @@ use crate::Value;
 /// - The pointer is heap-allocated and must be manually deallocated.
 /// - Must not be dereferenced after being freed.
 #[unsafe(no_mangle)]
+#[cfg_attr(feature = "prefixed-symbols", unsafe(export_name = "yad_value_from_uint_64"))]
 pub extern "C" fn value_from_uint_64(val: u64) -> *mut Value {
-    Box::into_raw(Box::new(Value::from(val)))
+    catch_panic("value_from_uint_64", || {
+        Box::into_raw(Box::new(Value::from(val)))
+    })
 }
 
 /// Creates a new [`Value`] containing a signed 64-bit integer (`i64`)
@@ -32,8 +36,11 @@ pub extern "C" fn value_from_uint_64(val: u64) -> *mut Value {
 /// - The pointer must be manually managed by the caller.
 /// - Must not be dereferenced after being freed.
 #[unsafe(no_mangle)]
+#[cfg_attr(feature = "prefixed-symbols", unsafe(export_name = "yad_value_from_int_64"))]
 pub extern "C" fn value_from_int_64(val: i64) -> *mut Value {
-    Box::into_raw(Box::new(Value::from(val)))
+    catch_panic("value_from_int_64", || {
+        Box::into_raw(Box::new(Value::from(val)))
+    })
 }
 
 /// Creates a new [`Value`] containing a 64-bit floating point (`f64`)
@@ -50,8 +57,11 @@ pub extern "C" fn value_from_int_64(val: i64) -> *mut Value {
 /// - The pointer is heap-allocated and must be manually freed.
 /// - Must not be dereferenced after being freed.
 #[unsafe(no_mangle)]
+#[cfg_attr(feature = "prefixed-symbols", unsafe(export_name = "yad_value_from_double"))]
 pub extern "C" fn value_from_double(val: f64) -> *mut Value {
-    Box::into_raw(Box::new(Value::from(val)))
+    catch_panic("value_from_double", || {
+        Box::into_raw(Box::new(Value::from(val)))
+    })
 }
 
 /// Extracts a 64-bit floating point (`f64`) from a [`Value`] and writes it
@@ -69,18 +79,23 @@ pub extern "C" fn value_from_double(val: f64) -> *mut Value {
 /// - Both `value` and `out` must be valid, non-null pointers.
 /// - `out` must point to a valid writable memory location.
 #[unsafe(no_mangle)]
+#[cfg_attr(feature = "prefixed-symbols", unsafe(export_name = "yad_double_from_value"))]
 pub extern "C" fn double_from_value(value: *mut Value, out: *mut f64) -> bool {
-    if value.is_null() {
-        return false;
-    }
-    unsafe {
-        if let Ok(num) = (&*value).try_into() {
-            *out = num;
-            true
-        } else {
-            false
+    catch_panic("double_from_value", || {
+        if value.is_null() || out.is_null() {
+            set_null_pointer_error("double_from_value", "`value` or `out`");
+            return false;
         }
-    }
+        unsafe {
+            if let Ok(num) = (&*value).try_into() {
+                *out = num;
+                true
+            } else {
+                set_last_error(YadErrorCode::Conversion, "double_from_value: `value` does not hold a `f64`");
+                false
+            }
+        }
+    })
 }
 
 /// Extracts an unsigned 64-bit integer (`u64`) from a [`Value`] and writes
@@ -98,18 +113,23 @@ pub extern "C" fn double_from_value(value: *mut Value, out: *mut f64) -> bool {
 /// - Both `value` and `out` must be valid, non-null pointers.
 /// - `out` must point to a valid writable memory location.
 #[unsafe(no_mangle)]
+#[cfg_attr(feature = "prefixed-symbols", unsafe(export_name = "yad_uint64_from_value"))]
 pub extern "C" fn uint64_from_value(value: *mut Value, out: *mut u64) -> bool {
-    if value.is_null() {
-        return false;
-    }
-    unsafe {
-        if let Ok(num) = (&*value).try_into() {
-            *out = num;
-            true
-        } else {
-            false
+    catch_panic("uint64_from_value", || {
+        if value.is_null() || out.is_null() {
+            set_null_pointer_error("uint64_from_value", "`value` or `out`");
+            return false;
+        }
+        unsafe {
+            if let Ok(num) = (&*value).try_into() {
+                *out = num;
+                true
+            } else {
+                set_last_error(YadErrorCode::Conversion, "uint64_from_value: `value` does not hold a `u64`");
+                false
+            }
         }
-    }
+    })
 }
 
 /// Extracts a signed 64-bit integer (`i64`) from a [`Value`] and writes
@@ -127,16 +147,21 @@ pub extern "C" fn uint64_from_value(value: *mut Value, out: *mut u64) -> bool {
 /// - Both `value` and `out` must be valid, non-null pointers.
 /// - `out` must point to a valid writable memory location.
 #[unsafe(no_mangle)]
+#[cfg_attr(feature = "prefixed-symbols", unsafe(export_name = "yad_int64_from_value"))]
 pub extern "C" fn int64_from_value(value: *mut Value, out: *mut i64) -> bool {
-    if value.is_null() {
-        return false;
-    }
-    unsafe {
-        if let Ok(num) = (&*value).try_into() {
-            *out = num;
-            true
-        } else {
-            false
+    catch_panic("int64_from_value", || {
+        if value.is_null() || out.is_null() {
+            set_null_pointer_error("int64_from_value", "`value` or `out`");
+            return false;
+        }
+        unsafe {
+            if let Ok(num) = (&*value).try_into() {
+                *out = num;
+                true
+            } else {
+                set_last_error(YadErrorCode::Conversion, "int64_from_value: `value` does not hold a `i64`");
+                false
+            }
         }
-    }
+    })
 }
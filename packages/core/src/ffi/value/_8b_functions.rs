@@ -1,5 +1,6 @@
 use float8::F8E4M3;
 use crate::Value;
+use crate::ffi::error::{catch_panic, set_last_error, set_null_pointer_error, YadErrorCode};
 
 /// Converts an 8-bit unsigned integer (`u8`) into a heap-allocated [`Value`] pointer
 /// suitable for FFI usage.
@@ -14,8 +15,11 @@ use crate::Value;
 /// - The caller is responsible for freeing the returned pointer to avoid memory leaks.
 /// - The pointer is valid for FFI usage but must not be dereferenced without validation.
 #[unsafe(no_mangle)]
+#[cfg_attr(feature = "prefixed-symbols", unsafe(export_name = "yad_value_from_uint_8"))]
 pub extern "C" fn value_from_uint_8(val: u8) -> *mut Value {
-    Box::into_raw(Box::new(Value::from(val)))
+    catch_panic("value_from_uint_8", || {
+        Box::into_raw(Box::new(Value::from(val)))
+    })
 }
 
 /// Converts an 8-bit signed integer (`i8`) into a heap-allocated [`Value`] pointer
@@ -30,8 +34,11 @@ pub extern "C" fn value_from_uint_8(val: u8) -> *mut Value {
 /// # Safety
 /// - The caller must free the returned pointer to avoid memory leaks.
 #[unsafe(no_mangle)]
+#[cfg_attr(feature = "prefixed-symbols", unsafe(export_name = "yad_value_from_int_8"))]
 pub extern "C" fn value_from_int_8(val: i8) -> *mut Value {
-    Box::into_raw(Box::new(Value::from(val)))
+    catch_panic("value_from_int_8", || {
+        Box::into_raw(Box::new(Value::from(val)))
+    })
 }
 
 /// Converts a 32-bit floating point number (`f32`) into a compact [`F8E4M3`] format,
@@ -47,8 +54,11 @@ pub extern "C" fn value_from_int_8(val: i8) -> *mut Value {
 /// - The caller must free the pointer to avoid memory leaks.
 /// - Precision may be lost due to the reduced bit representation of `F8E4M3`.
 #[unsafe(no_mangle)]
+#[cfg_attr(feature = "prefixed-symbols", unsafe(export_name = "yad_value_as_f8_from_float"))]
 pub extern "C" fn value_as_f8_from_float(val: f32) -> *mut Value {
-    Box::into_raw(Box::new(Value::from(F8E4M3::from_f32(val))))
+    catch_panic("value_as_f8_from_float", || {
+        Box::into_raw(Box::new(Value::from(F8E4M3::from_f32(val))))
+    })
 }
 
 /// Attempts to extract a `u8` from a [`Value`] pointer and writes it to the provided output pointer.
@@ -65,18 +75,23 @@ pub extern "C" fn value_as_f8_from_float(val: f32) -> *mut Value {
 /// - Both pointers must be valid and non-null.
 /// - Dereferencing a null pointer is undefined behavior.
 #[unsafe(no_mangle)]
+#[cfg_attr(feature = "prefixed-symbols", unsafe(export_name = "yad_uint8_from_value"))]
 pub extern "C" fn uint8_from_value(value: *mut Value, out: *mut u8) -> bool {
-    if value.is_null() {
-        return false;
-    }
-    unsafe {
-        if let Ok(num) = (&*value).try_into() {
-            *out = num;
-            true
-        } else {
-            false
+    catch_panic("uint8_from_value", || {
+        if value.is_null() || out.is_null() {
+            set_null_pointer_error("uint8_from_value", "`value` or `out`");
+            return false;
         }
-    }
+        unsafe {
+            if let Ok(num) = (&*value).try_into() {
+                *out = num;
+                true
+            } else {
+                set_last_error(YadErrorCode::Conversion, "uint8_from_value: `value` does not hold a `u8`");
+                false
+            }
+        }
+    })
 }
 
 /// Attempts to extract an `i8` from a [`Value`] pointer and writes it to the provided output pointer.
@@ -93,18 +108,23 @@ pub extern "C" fn uint8_from_value(value: *mut Value, out: *mut u8) -> bool {
 /// - Both pointers must be valid and non-null.
 /// - Dereferencing a null pointer is undefined behavior.
 #[unsafe(no_mangle)]
+#[cfg_attr(feature = "prefixed-symbols", unsafe(export_name = "yad_int8_from_value"))]
 pub extern "C" fn int8_from_value(value: *mut Value, out: *mut i8) -> bool {
-    if value.is_null() {
-        return false;
-    }
-    unsafe {
-        if let Ok(num) = (&*value).try_into() {
-            *out = num;
-            true
-        } else {
-            false
+    catch_panic("int8_from_value", || {
+        if value.is_null() || out.is_null() {
+            set_null_pointer_error("int8_from_value", "`value` or `out`");
+            return false;
+        }
+        unsafe {
+            if let Ok(num) = (&*value).try_into() {
+                *out = num;
+                true
+            } else {
+                set_last_error(YadErrorCode::Conversion, "int8_from_value: `value` does not hold a `i8`");
+                false
+            }
         }
-    }
+    })
 }
 
 /// Attempts to extract an [`F8E4M3`] floating point from a [`Value`] pointer,
@@ -123,16 +143,21 @@ pub extern "C" fn int8_from_value(value: *mut Value, out: *mut i8) -> bool {
 /// - Dereferencing invalid pointers is undefined behavior.
 /// - Precision may be lost due to the limited bits of the `F8E4M3` format.
 #[unsafe(no_mangle)]
+#[cfg_attr(feature = "prefixed-symbols", unsafe(export_name = "yad_float_from_f8_value"))]
 pub extern "C" fn float_from_f8_value(value: *mut Value, out: *mut f32) -> bool {
-    if value.is_null() {
-        return false;
-    }
-    unsafe {
-        if let Ok(num) = <Value as TryInto<F8E4M3>>::try_into((&*value).to_owned()) {
-            *out = num.to_f32();
-            true
-        } else {
-            false
+    catch_panic("float_from_f8_value", || {
+        if value.is_null() || out.is_null() {
+            set_null_pointer_error("float_from_f8_value", "`value` or `out`");
+            return false;
+        }
+        unsafe {
+            if let Ok(num) = <Value as TryInto<F8E4M3>>::try_into((&*value).to_owned()) {
+                *out = num.to_f32();
+                true
+            } else {
+                set_last_error(YadErrorCode::Conversion, "float_from_f8_value: `value` does not hold a `F8E4M3`");
+                false
+            }
         }
-    }
+    })
 }
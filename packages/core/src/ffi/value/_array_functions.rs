@@ -1,5 +1,6 @@
-use crate::Value;
+use crate::{ArrayEncoder, Value};
 use crate::ffi::CArray;
+use crate::ffi::error::{catch_panic, set_error_message, set_last_error, set_null_pointer_error, YadErrorCode};
 
 /// Converts a C-compatible array (`CArray`) into a heap-allocated [`Value`].
 ///
@@ -15,21 +16,28 @@ use crate::ffi::CArray;
 /// - The caller must free the returned pointer with `value_free` to avoid memory leaks.
 /// - Ownership of the array memory is transferred temporarily; the original `CArray` should not be used after this call.
 #[unsafe(no_mangle)]
+#[cfg_attr(feature = "prefixed-symbols", unsafe(export_name = "yad_value_from_c_array"))]
 pub extern "C" fn value_from_c_array(c_arr: *mut CArray) -> *mut Value {
-    if c_arr.is_null() {
-        return std::ptr::null_mut();
-    }
+    catch_panic("value_from_c_array", || {
+        if c_arr.is_null() {
+            set_null_pointer_error("value_from_c_array", "`c_arr`");
+            return std::ptr::null_mut();
+        }
 
-    unsafe {
-        // Reconstruct Vec<Value> from raw parts
-        let vec = Vec::from_raw_parts((*c_arr).ptr, (*c_arr).len, (*c_arr).cap);
+        unsafe {
+            // Reconstruct Vec<Value> from raw parts
+            let vec = Vec::from_raw_parts((*c_arr).ptr, (*c_arr).len, (*c_arr).cap);
 
-        // Convert Vec<Value> into Value
-        match Value::try_from(vec) {
-            Ok(v) => Box::into_raw(Box::new(v)),
-            Err(_) => std::ptr::null_mut(),
+            // Convert Vec<Value> into Value
+            match Value::try_from(vec) {
+                Ok(v) => Box::into_raw(Box::new(v)),
+                Err(e) => {
+                    set_error_message("value_from_c_array", YadErrorCode::Encode, e);
+                    std::ptr::null_mut()
+                }
+            }
         }
-    }
+    })
 }
 
 /// Converts a heap-allocated [`Value`] containing an array into a C-compatible `CArray`.
@@ -46,19 +54,259 @@ pub extern "C" fn value_from_c_array(c_arr: *mut CArray) -> *mut Value {
 /// - The returned `CArray` must be freed with `free_c_array` to avoid memory leaks.
 /// - Memory inside the original [`Value`] remains managed by Rust; this exposes the array contents as a `CArray`.
 #[unsafe(no_mangle)]
+#[cfg_attr(feature = "prefixed-symbols", unsafe(export_name = "yad_c_array_from_value"))]
 pub extern "C" fn c_array_from_value(val: *mut Value) -> *mut CArray {
-    if val.is_null() {
+    catch_panic("c_array_from_value", || {
+        if val.is_null() {
+            set_null_pointer_error("c_array_from_value", "`val`");
+            return std::ptr::null_mut();
+        }
+
+        unsafe {
+            match <Value as TryInto<Vec<Value>>>::try_into((&*val).clone()) {
+                Ok(mut arr) => Box::into_raw(Box::new(CArray {
+                    ptr: arr.as_mut_ptr(),
+                    len: arr.len(),
+                    cap: arr.capacity(),
+                })),
+                Err(_) => {
+                    set_last_error(YadErrorCode::Conversion, "c_array_from_value: `val` does not hold an array");
+                    std::ptr::null_mut()
+                }
+            }
+        }
+    })
+}
+
+/// Builds an array [`Value`] from `len` elements read from `ptr`, encoding
+/// them straight into the array without an intermediate `CArray`.
+///
+/// # Safety
+/// - `ptr` must point to `len` initialized elements, or be null if `len` is `0`.
+unsafe fn array_from_buffer<T: Copy + Into<Value>>(function: &str, ptr: *const T, len: usize) -> *mut Value {
+    if ptr.is_null() && len != 0 {
+        set_null_pointer_error(function, "`ptr` (of non-zero `len`)");
         return std::ptr::null_mut();
     }
 
-    unsafe {
-        match <Value as TryInto<Vec<Value>>>::try_into((&*val).clone()) {
-            Ok(mut arr) => Box::into_raw(Box::new(CArray {
-                ptr: arr.as_mut_ptr(),
-                len: arr.len(),
-                cap: arr.capacity(),
-            })),
-            Err(_) => std::ptr::null_mut(),
+    let items: &[T] = if len == 0 { &[] } else { unsafe { std::slice::from_raw_parts(ptr, len) } };
+    let mut encoder = ArrayEncoder::with_capacity(len * (1 + size_of::<T>()));
+    encoder.extend(items.iter().copied());
+
+    match encoder.finish() {
+        Ok(value) => Box::into_raw(Box::new(value)),
+        Err(e) => {
+            set_error_message(function, YadErrorCode::Encode, e);
+            std::ptr::null_mut()
         }
     }
 }
+
+/// Creates an array [`Value`] in one call from a contiguous buffer of `u8`,
+/// each element becoming an unsigned 8-bit integer.
+///
+/// # Parameters
+/// - `ptr`: Pointer to the first element.
+/// - `len`: Number of elements.
+///
+/// # Returns
+/// - A raw pointer to a heap-allocated array [`Value`]; free it with `value_free`.
+/// - Returns `null` if `ptr` is null or `len` is zero.
+///
+/// # Safety
+/// - `ptr` must point to at least `len` elements. The buffer is only read.
+#[unsafe(no_mangle)]
+#[cfg_attr(feature = "prefixed-symbols", unsafe(export_name = "yad_value_from_uint8_array"))]
+pub unsafe extern "C" fn value_from_uint8_array(ptr: *const u8, len: usize) -> *mut Value {
+    catch_panic("value_from_uint8_array", || unsafe { array_from_buffer("value_from_uint8_array", ptr, len) })
+}
+
+/// Creates an array [`Value`] in one call from a contiguous buffer of `i8`,
+/// each element becoming a signed 8-bit integer.
+///
+/// # Parameters
+/// - `ptr`: Pointer to the first element.
+/// - `len`: Number of elements.
+///
+/// # Returns
+/// - A raw pointer to a heap-allocated array [`Value`]; free it with `value_free`.
+/// - Returns `null` if `ptr` is null or `len` is zero.
+///
+/// # Safety
+/// - `ptr` must point to at least `len` elements. The buffer is only read.
+#[unsafe(no_mangle)]
+#[cfg_attr(feature = "prefixed-symbols", unsafe(export_name = "yad_value_from_int8_array"))]
+pub unsafe extern "C" fn value_from_int8_array(ptr: *const i8, len: usize) -> *mut Value {
+    catch_panic("value_from_int8_array", || unsafe { array_from_buffer("value_from_int8_array", ptr, len) })
+}
+
+/// Creates an array [`Value`] in one call from a contiguous buffer of `u16`,
+/// each element becoming an unsigned 16-bit integer.
+///
+/// # Parameters
+/// - `ptr`: Pointer to the first element.
+/// - `len`: Number of elements.
+///
+/// # Returns
+/// - A raw pointer to a heap-allocated array [`Value`]; free it with `value_free`.
+/// - Returns `null` if `ptr` is null or `len` is zero.
+///
+/// # Safety
+/// - `ptr` must point to at least `len` elements. The buffer is only read.
+#[unsafe(no_mangle)]
+#[cfg_attr(feature = "prefixed-symbols", unsafe(export_name = "yad_value_from_uint16_array"))]
+pub unsafe extern "C" fn value_from_uint16_array(ptr: *const u16, len: usize) -> *mut Value {
+    catch_panic("value_from_uint16_array", || unsafe { array_from_buffer("value_from_uint16_array", ptr, len) })
+}
+
+/// Creates an array [`Value`] in one call from a contiguous buffer of `i16`,
+/// each element becoming a signed 16-bit integer.
+///
+/// # Parameters
+/// - `ptr`: Pointer to the first element.
+/// - `len`: Number of elements.
+///
+/// # Returns
+/// - A raw pointer to a heap-allocated array [`Value`]; free it with `value_free`.
+/// - Returns `null` if `ptr` is null or `len` is zero.
+///
+/// # Safety
+/// - `ptr` must point to at least `len` elements. The buffer is only read.
+#[unsafe(no_mangle)]
+#[cfg_attr(feature = "prefixed-symbols", unsafe(export_name = "yad_value_from_int16_array"))]
+pub unsafe extern "C" fn value_from_int16_array(ptr: *const i16, len: usize) -> *mut Value {
+    catch_panic("value_from_int16_array", || unsafe { array_from_buffer("value_from_int16_array", ptr, len) })
+}
+
+/// Creates an array [`Value`] in one call from a contiguous buffer of `u32`,
+/// each element becoming an unsigned 32-bit integer.
+///
+/// # Parameters
+/// - `ptr`: Pointer to the first element.
+/// - `len`: Number of elements.
+///
+/// # Returns
+/// - A raw pointer to a heap-allocated array [`Value`]; free it with `value_free`.
+/// - Returns `null` if `ptr` is null or `len` is zero.
+///
+/// # Safety
+/// - `ptr` must point to at least `len` elements. The buffer is only read.
+#[unsafe(no_mangle)]
+#[cfg_attr(feature = "prefixed-symbols", unsafe(export_name = "yad_value_from_uint32_array"))]
+pub unsafe extern "C" fn value_from_uint32_array(ptr: *const u32, len: usize) -> *mut Value {
+    catch_panic("value_from_uint32_array", || unsafe { array_from_buffer("value_from_uint32_array", ptr, len) })
+}
+
+/// Creates an array [`Value`] in one call from a contiguous buffer of `i32`,
+/// each element becoming a signed 32-bit integer.
+///
+/// # Parameters
+/// - `ptr`: Pointer to the first element.
+/// - `len`: Number of elements.
+///
+/// # Returns
+/// - A raw pointer to a heap-allocated array [`Value`]; free it with `value_free`.
+/// - Returns `null` if `ptr` is null or `len` is zero.
+///
+/// # Safety
+/// - `ptr` must point to at least `len` elements. The buffer is only read.
+#[unsafe(no_mangle)]
+#[cfg_attr(feature = "prefixed-symbols", unsafe(export_name = "yad_value_from_int32_array"))]
+pub unsafe extern "C" fn value_from_int32_array(ptr: *const i32, len: usize) -> *mut Value {
+    catch_panic("value_from_int32_array", || unsafe { array_from_buffer("value_from_int32_array", ptr, len) })
+}
+
+/// Creates an array [`Value`] in one call from a contiguous buffer of `u64`,
+/// each element becoming an unsigned 64-bit integer.
+///
+/// # Parameters
+/// - `ptr`: Pointer to the first element.
+/// - `len`: Number of elements.
+///
+/// # Returns
+/// - A raw pointer to a heap-allocated array [`Value`]; free it with `value_free`.
+/// - Returns `null` if `ptr` is null or `len` is zero.
+///
+/// # Safety
+/// - `ptr` must point to at least `len` elements. The buffer is only read.
+#[unsafe(no_mangle)]
+#[cfg_attr(feature = "prefixed-symbols", unsafe(export_name = "yad_value_from_uint64_array"))]
+pub unsafe extern "C" fn value_from_uint64_array(ptr: *const u64, len: usize) -> *mut Value {
+    catch_panic("value_from_uint64_array", || unsafe { array_from_buffer("value_from_uint64_array", ptr, len) })
+}
+
+/// Creates an array [`Value`] in one call from a contiguous buffer of `i64`,
+/// each element becoming a signed 64-bit integer.
+///
+/// # Parameters
+/// - `ptr`: Pointer to the first element.
+/// - `len`: Number of elements.
+///
+/// # Returns
+/// - A raw pointer to a heap-allocated array [`Value`]; free it with `value_free`.
+/// - Returns `null` if `ptr` is null or `len` is zero.
+///
+/// # Safety
+/// - `ptr` must point to at least `len` elements. The buffer is only read.
+#[unsafe(no_mangle)]
+#[cfg_attr(feature = "prefixed-symbols", unsafe(export_name = "yad_value_from_int64_array"))]
+pub unsafe extern "C" fn value_from_int64_array(ptr: *const i64, len: usize) -> *mut Value {
+    catch_panic("value_from_int64_array", || unsafe { array_from_buffer("value_from_int64_array", ptr, len) })
+}
+
+/// Creates an array [`Value`] in one call from a contiguous buffer of `f32`,
+/// each element becoming a 32-bit float.
+///
+/// # Parameters
+/// - `ptr`: Pointer to the first element.
+/// - `len`: Number of elements.
+///
+/// # Returns
+/// - A raw pointer to a heap-allocated array [`Value`]; free it with `value_free`.
+/// - Returns `null` if `ptr` is null or `len` is zero.
+///
+/// # Safety
+/// - `ptr` must point to at least `len` elements. The buffer is only read.
+#[unsafe(no_mangle)]
+#[cfg_attr(feature = "prefixed-symbols", unsafe(export_name = "yad_value_from_float_array"))]
+pub unsafe extern "C" fn value_from_float_array(ptr: *const f32, len: usize) -> *mut Value {
+    catch_panic("value_from_float_array", || unsafe { array_from_buffer("value_from_float_array", ptr, len) })
+}
+
+/// Creates an array [`Value`] in one call from a contiguous buffer of `f64`,
+/// each element becoming a 64-bit float.
+///
+/// # Parameters
+/// - `ptr`: Pointer to the first element.
+/// - `len`: Number of elements.
+///
+/// # Returns
+/// - A raw pointer to a heap-allocated array [`Value`]; free it with `value_free`.
+/// - Returns `null` if `ptr` is null or `len` is zero.
+///
+/// # Safety
+/// - `ptr` must point to at least `len` elements. The buffer is only read.
+#[unsafe(no_mangle)]
+#[cfg_attr(feature = "prefixed-symbols", unsafe(export_name = "yad_value_from_double_array"))]
+pub unsafe extern "C" fn value_from_double_array(ptr: *const f64, len: usize) -> *mut Value {
+    catch_panic("value_from_double_array", || unsafe { array_from_buffer("value_from_double_array", ptr, len) })
+}
+
+/// Creates an array [`Value`] in one call from a contiguous buffer of `bool`,
+/// each element becoming a boolean.
+///
+/// # Parameters
+/// - `ptr`: Pointer to the first element.
+/// - `len`: Number of elements.
+///
+/// # Returns
+/// - A raw pointer to a heap-allocated array [`Value`]; free it with `value_free`.
+/// - Returns `null` if `ptr` is null or `len` is zero.
+///
+/// # Safety
+/// - `ptr` must point to at least `len` elements. The buffer is only read.
+#[unsafe(no_mangle)]
+#[cfg_attr(feature = "prefixed-symbols", unsafe(export_name = "yad_value_from_bool_array"))]
+pub unsafe extern "C" fn value_from_bool_array(ptr: *const bool, len: usize) -> *mut Value {
+    catch_panic("value_from_bool_array", || unsafe { array_from_buffer("value_from_bool_array", ptr, len) })
+}
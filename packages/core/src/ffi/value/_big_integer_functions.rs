@@ -0,0 +1,121 @@
+use std::ffi::{c_char, CStr, CString};
+use crate::Value;
+use crate::bigint::BigInteger;
+use crate::ffi::ByteBuffer;
+use crate::ffi::error::{catch_panic, set_error_message, set_last_error, set_null_pointer_error, YadErrorCode};
+
+/// Creates a heap-allocated big integer [`Value`] from `len` bytes of
+/// big-endian two's complement read from `data`.
+///
+/// # Parameters
+/// - `data`: Pointer to the bytes, or null if `len` is `0`.
+/// - `len`: Number of bytes; `0` is zero.
+///
+/// # Returns
+/// - A raw pointer to a heap-allocated [`Value`]; free it with `value_free`.
+/// - Returns `null` if `data` is null while `len` is not zero.
+///
+/// # Safety
+/// - `data` must point to at least `len` bytes. The buffer is only read.
+#[unsafe(no_mangle)]
+#[cfg_attr(feature = "prefixed-symbols", unsafe(export_name = "yad_value_from_big_integer_bytes"))]
+pub unsafe extern "C" fn value_from_big_integer_bytes(data: *const u8, len: usize) -> *mut Value {
+    catch_panic("value_from_big_integer_bytes", || {
+        if data.is_null() && len != 0 {
+            set_null_pointer_error("value_from_big_integer_bytes", "`data` (of non-zero `len`)");
+            return std::ptr::null_mut();
+        }
+
+        let data: &[u8] = if len == 0 { &[] } else { unsafe { std::slice::from_raw_parts(data, len) } };
+        Box::into_raw(Box::new(Value::from(BigInteger::from_signed_bytes_be(data))))
+    })
+}
+
+/// Returns a big integer [`Value`] as big-endian two's complement, in the
+/// fewest bytes holding it.
+///
+/// # Returns
+/// - A [`ByteBuffer`] holding the bytes; free it with `byte_buffer_free`.
+/// - An empty buffer with a null `ptr` if `value` is null or not a big integer.
+///
+/// # Safety
+/// - `value` must be a valid pointer or null.
+#[unsafe(no_mangle)]
+#[cfg_attr(feature = "prefixed-symbols", unsafe(export_name = "yad_big_integer_bytes_from_value"))]
+pub unsafe extern "C" fn big_integer_bytes_from_value(value: *mut Value) -> ByteBuffer {
+    catch_panic("big_integer_bytes_from_value", || {
+        if value.is_null() {
+            set_null_pointer_error("big_integer_bytes_from_value", "`value`");
+            return ByteBuffer::null();
+        }
+
+        match unsafe { &*value }.as_big_integer() {
+            Ok(big) => ByteBuffer::from_vec(big.to_signed_bytes_be().to_vec()),
+            Err(e) => {
+                set_error_message("big_integer_bytes_from_value", YadErrorCode::Conversion, e);
+                ByteBuffer::null()
+            }
+        }
+    })
+}
+
+/// Creates a heap-allocated big integer [`Value`] from its base 10 text, such
+/// as `"-340282366920938463463374607431768211456"`.
+///
+/// # Returns
+/// - A raw pointer to a heap-allocated [`Value`]; free it with `value_free`.
+/// - Returns `null` if the pointer is null or the text is not an integer.
+///
+/// # Safety
+/// - `c_string` must point to a valid null-terminated C string or be null.
+#[unsafe(no_mangle)]
+#[cfg_attr(feature = "prefixed-symbols", unsafe(export_name = "yad_value_from_big_integer_cstring"))]
+pub unsafe extern "C" fn value_from_big_integer_cstring(c_string: *const c_char) -> *mut Value {
+    catch_panic("value_from_big_integer_cstring", || {
+        if c_string.is_null() {
+            set_null_pointer_error("value_from_big_integer_cstring", "`c_string`");
+            return std::ptr::null_mut();
+        }
+
+        let Ok(text) = unsafe { CStr::from_ptr(c_string) }.to_str() else {
+            set_last_error(YadErrorCode::InvalidUtf8, "value_from_big_integer_cstring: `c_string` is not valid UTF-8");
+            return std::ptr::null_mut();
+        };
+
+        match text.parse::<BigInteger>() {
+            Ok(big) => Box::into_raw(Box::new(Value::from(big))),
+            Err(e) => {
+                set_error_message("value_from_big_integer_cstring", YadErrorCode::Encode, e);
+                std::ptr::null_mut()
+            }
+        }
+    })
+}
+
+/// Writes a big integer [`Value`] in base 10.
+///
+/// # Returns
+/// - Pointer to a null-terminated C string; free it with `cstring_free`.
+/// - Returns `null` if `value` is null or not a big integer.
+///
+/// # Safety
+/// - `value` must be a valid pointer or null.
+#[unsafe(no_mangle)]
+#[cfg_attr(feature = "prefixed-symbols", unsafe(export_name = "yad_cstring_from_big_integer_value"))]
+pub unsafe extern "C" fn cstring_from_big_integer_value(value: *mut Value) -> *const c_char {
+    catch_panic("cstring_from_big_integer_value", || {
+        if value.is_null() {
+            set_null_pointer_error("cstring_from_big_integer_value", "`value`");
+            return std::ptr::null();
+        }
+
+        match unsafe { &*value }.as_big_integer() {
+            // Digits never hold a null byte.
+            Ok(big) => CString::new(big.to_string()).map_or(std::ptr::null(), |cstr| cstr.into_raw() as *const c_char),
+            Err(e) => {
+                set_error_message("cstring_from_big_integer_value", YadErrorCode::Conversion, e);
+                std::ptr::null()
+            }
+        }
+    })
+}
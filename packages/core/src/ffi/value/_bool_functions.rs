@@ -1,4 +1,5 @@
 use crate::Value;
+use crate::ffi::error::{catch_panic, set_last_error, set_null_pointer_error, YadErrorCode};
 
 /// Creates a heap-allocated [`Value`] from a Rust boolean.
 ///
@@ -13,8 +14,11 @@ use crate::Value;
 /// - The returned pointer must eventually be freed with `value_free` to avoid memory leaks.
 /// - Pointer must not be dereferenced after being freed.
 #[unsafe(no_mangle)]
+#[cfg_attr(feature = "prefixed-symbols", unsafe(export_name = "yad_value_from_bool"))]
 pub extern "C" fn value_from_bool(val: bool) -> *mut Value {
-    Box::into_raw(Box::new(Value::from(val)))
+    catch_panic("value_from_bool", || {
+        Box::into_raw(Box::new(Value::from(val)))
+    })
 }
 
 /// Extracts a Rust boolean from a heap-allocated [`Value`].
@@ -31,16 +35,21 @@ pub extern "C" fn value_from_bool(val: bool) -> *mut Value {
 /// - Both `value` and `out` must be valid, non-null pointers.
 /// - Caller must ensure that `out` points to a valid writable memory location.
 #[unsafe(no_mangle)]
+#[cfg_attr(feature = "prefixed-symbols", unsafe(export_name = "yad_bool_from_value"))]
 pub extern "C" fn bool_from_value(value: *mut Value, out: *mut bool) -> bool {
-    if value.is_null() {
-        return false;
-    }
-    unsafe {
-        if let Ok(b) = (&*value).clone().try_into() {
-            *out = b;
-            true
-        } else {
-            false
+    catch_panic("bool_from_value", || {
+        if value.is_null() || out.is_null() {
+            set_null_pointer_error("bool_from_value", "`value` or `out`");
+            return false;
         }
-    }
+        unsafe {
+            if let Ok(b) = (&*value).clone().try_into() {
+                *out = b;
+                true
+            } else {
+                set_last_error(YadErrorCode::Conversion, "bool_from_value: `value` does not hold a `bool`");
+                false
+            }
+        }
+    })
 }
@@ -0,0 +1,126 @@
+use std::ffi::{c_char, CStr, CString};
+use crate::Value;
+use crate::decimal::Decimal;
+use crate::ffi::error::{catch_panic, set_error_message, set_last_error, set_null_pointer_error, YadErrorCode};
+
+/// Creates a heap-allocated decimal [`Value`], `mantissa × 10^-scale`.
+///
+/// Mantissas wider than 64 bits can be written with [`value_from_decimal_cstring`].
+///
+/// # Returns
+/// - A raw pointer to a heap-allocated [`Value`]; free it with `value_free`.
+#[unsafe(no_mangle)]
+#[cfg_attr(feature = "prefixed-symbols", unsafe(export_name = "yad_value_from_decimal"))]
+pub extern "C" fn value_from_decimal(mantissa: i64, scale: u8) -> *mut Value {
+    catch_panic("value_from_decimal", || {
+        Box::into_raw(Box::new(Value::from(Decimal::new(mantissa as i128, scale))))
+    })
+}
+
+/// Extracts the mantissa and scale of a decimal [`Value`].
+///
+/// # Parameters
+/// - `value`: Pointer to a [`Value`] expected to be a decimal.
+/// - `out_mantissa`: Pointer to an `i64` where the mantissa will be written.
+/// - `out_scale`: Pointer to a `u8` where the scale will be written.
+///
+/// # Returns
+/// - `true` if extraction succeeded.
+/// - `false` if a pointer is null, `value` is not a decimal or its mantissa
+///   does not fit in 64 bits; read it with [`cstring_from_decimal_value`] then.
+///
+/// # Safety
+/// - `value`, `out_mantissa` and `out_scale` must be valid, non-null pointers.
+#[unsafe(no_mangle)]
+#[cfg_attr(feature = "prefixed-symbols", unsafe(export_name = "yad_decimal_from_value"))]
+pub unsafe extern "C" fn decimal_from_value(value: *mut Value, out_mantissa: *mut i64, out_scale: *mut u8) -> bool {
+    catch_panic("decimal_from_value", || {
+        if value.is_null() || out_mantissa.is_null() || out_scale.is_null() {
+            set_null_pointer_error("decimal_from_value", "`value`, `out_mantissa` or `out_scale`");
+            return false;
+        }
+
+        unsafe {
+            match (&*value).as_decimal() {
+                Ok(decimal) => match i64::try_from(decimal.mantissa) {
+                    Ok(mantissa) => {
+                        *out_mantissa = mantissa;
+                        *out_scale = decimal.scale;
+                        true
+                    }
+                    Err(_) => {
+                        set_last_error(YadErrorCode::Conversion, "decimal_from_value: the mantissa does not fit in 64 bits");
+                        false
+                    }
+                },
+                Err(e) => {
+                    set_error_message("decimal_from_value", YadErrorCode::Conversion, e);
+                    false
+                }
+            }
+        }
+    })
+}
+
+/// Creates a heap-allocated decimal [`Value`] from its text, such as `"-19.90"`.
+///
+/// # Parameters
+/// - `c_string`: Pointer to a null-terminated C string of the form `[+-]digits[.digits]`.
+///
+/// # Returns
+/// - A raw pointer to a heap-allocated [`Value`]; free it with `value_free`.
+/// - Returns `null` if the pointer is null or the text is not a decimal.
+///
+/// # Safety
+/// - `c_string` must point to a valid null-terminated C string or be null.
+#[unsafe(no_mangle)]
+#[cfg_attr(feature = "prefixed-symbols", unsafe(export_name = "yad_value_from_decimal_cstring"))]
+pub unsafe extern "C" fn value_from_decimal_cstring(c_string: *const c_char) -> *mut Value {
+    catch_panic("value_from_decimal_cstring", || {
+        if c_string.is_null() {
+            set_null_pointer_error("value_from_decimal_cstring", "`c_string`");
+            return std::ptr::null_mut();
+        }
+
+        let Ok(text) = unsafe { CStr::from_ptr(c_string) }.to_str() else {
+            set_last_error(YadErrorCode::InvalidUtf8, "value_from_decimal_cstring: `c_string` is not valid UTF-8");
+            return std::ptr::null_mut();
+        };
+
+        match text.parse::<Decimal>() {
+            Ok(decimal) => Box::into_raw(Box::new(Value::from(decimal))),
+            Err(e) => {
+                set_error_message("value_from_decimal_cstring", YadErrorCode::Encode, e);
+                std::ptr::null_mut()
+            }
+        }
+    })
+}
+
+/// Writes a decimal [`Value`] as text, keeping its scale (`"19.90"`).
+///
+/// # Returns
+/// - Pointer to a null-terminated C string; free it with `cstring_free`.
+/// - Returns `null` if `value` is null or not a decimal.
+///
+/// # Safety
+/// - `value` must be a valid pointer or null.
+#[unsafe(no_mangle)]
+#[cfg_attr(feature = "prefixed-symbols", unsafe(export_name = "yad_cstring_from_decimal_value"))]
+pub unsafe extern "C" fn cstring_from_decimal_value(value: *mut Value) -> *const c_char {
+    catch_panic("cstring_from_decimal_value", || {
+        if value.is_null() {
+            set_null_pointer_error("cstring_from_decimal_value", "`value`");
+            return std::ptr::null();
+        }
+
+        match unsafe { &*value }.as_decimal() {
+            // Decimal text never holds a null byte.
+            Ok(decimal) => CString::new(decimal.to_string()).map_or(std::ptr::null(), |cstr| cstr.into_raw() as *const c_char),
+            Err(e) => {
+                set_error_message("cstring_from_decimal_value", YadErrorCode::Conversion, e);
+                std::ptr::null()
+            }
+        }
+    })
+}
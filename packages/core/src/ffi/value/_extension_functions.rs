@@ -0,0 +1,106 @@
+use crate::Value;
+use crate::ffi::ByteBuffer;
+use crate::ffi::error::{catch_panic, set_error_message, set_null_pointer_error, YadErrorCode};
+
+/// Creates a heap-allocated extension [`Value`] of kind `tag` holding `len`
+/// bytes of data read from `data`.
+///
+/// # Parameters
+/// - `tag`: The tag naming the kind of the value.
+/// - `data`: Pointer to the data, or null if `len` is `0`.
+/// - `len`: Length of the data in bytes; may be `0`.
+///
+/// # Returns
+/// - A raw pointer to a heap-allocated [`Value`]; free it with `value_free`.
+/// - Returns `null` if `data` is null while `len` is not zero.
+///
+/// # Safety
+/// - `data` must point to at least `len` bytes. The buffer is only read.
+#[unsafe(no_mangle)]
+#[cfg_attr(feature = "prefixed-symbols", unsafe(export_name = "yad_value_from_extension"))]
+pub unsafe extern "C" fn value_from_extension(tag: u8, data: *const u8, len: usize) -> *mut Value {
+    catch_panic("value_from_extension", || {
+        if data.is_null() && len != 0 {
+            set_null_pointer_error("value_from_extension", "`data` (of non-zero `len`)");
+            return std::ptr::null_mut();
+        }
+
+        let data: &[u8] = if len == 0 { &[] } else { unsafe { std::slice::from_raw_parts(data, len) } };
+        match Value::from_extension(tag, data) {
+            Ok(value) => Box::into_raw(Box::new(value)),
+            Err(e) => {
+                set_error_message("value_from_extension", YadErrorCode::Encode, e);
+                std::ptr::null_mut()
+            }
+        }
+    })
+}
+
+/// Extracts the tag of an extension [`Value`].
+///
+/// # Parameters
+/// - `value`: Pointer to a [`Value`] expected to be an extension value.
+/// - `out`: Pointer to a `u8` where the tag will be written.
+///
+/// # Returns
+/// - `true` if extraction succeeded.
+/// - `false` if a pointer is null or `value` is not an extension value.
+///
+/// # Safety
+/// - Both `value` and `out` must be valid, non-null pointers.
+#[unsafe(no_mangle)]
+#[cfg_attr(feature = "prefixed-symbols", unsafe(export_name = "yad_extension_tag_from_value"))]
+pub unsafe extern "C" fn extension_tag_from_value(value: *mut Value, out: *mut u8) -> bool {
+    catch_panic("extension_tag_from_value", || {
+        if value.is_null() || out.is_null() {
+            set_null_pointer_error("extension_tag_from_value", "`value` or `out`");
+            return false;
+        }
+
+        unsafe {
+            match (&*value).extension_tag() {
+                Ok(tag) => {
+                    *out = tag;
+                    true
+                }
+                Err(e) => {
+                    set_error_message("extension_tag_from_value", YadErrorCode::Conversion, e);
+                    false
+                }
+            }
+        }
+    })
+}
+
+/// Returns a copy of the data of an extension [`Value`], without its tag.
+///
+/// # Parameters
+/// - `value`: Pointer to a [`Value`] expected to be an extension value.
+///
+/// # Returns
+/// - A [`ByteBuffer`] holding the data; free it with `byte_buffer_free`.
+/// - An empty buffer with a null `ptr` if the value has no data, or if
+///   `value` is null or not an extension value; `yad_last_error` tells them apart.
+///
+/// # Safety
+/// - `value` must be a valid pointer or null.
+#[unsafe(no_mangle)]
+#[cfg_attr(feature = "prefixed-symbols", unsafe(export_name = "yad_extension_data_from_value"))]
+pub unsafe extern "C" fn extension_data_from_value(value: *mut Value) -> ByteBuffer {
+    catch_panic("extension_data_from_value", || {
+        if value.is_null() {
+            set_null_pointer_error("extension_data_from_value", "`value`");
+            return ByteBuffer::null();
+        }
+
+        unsafe {
+            match (&*value).extension_data() {
+                Ok(data) => ByteBuffer::from_vec(data.to_vec()),
+                Err(e) => {
+                    set_error_message("extension_data_from_value", YadErrorCode::Conversion, e);
+                    ByteBuffer::null()
+                }
+            }
+        }
+    })
+}
@@ -1,5 +1,6 @@
 use std::ffi::{c_char, CStr, CString};
 use crate::Value;
+use crate::ffi::error::{catch_panic, set_error_message, set_last_error, set_null_pointer_error, YadErrorCode};
 
 /// Creates a heap-allocated [`Value`] from a C string (`*const c_char`).
 ///
@@ -15,22 +16,31 @@ use crate::Value;
 /// - The returned pointer must eventually be freed with `value_free` to avoid memory leaks.
 /// - Pointer must not be dereferenced after being freed.
 #[unsafe(no_mangle)]
+#[cfg_attr(feature = "prefixed-symbols", unsafe(export_name = "yad_value_from_cstring"))]
 pub extern "C" fn value_from_cstring(c_string: *const c_char) -> *mut Value {
-    if c_string.is_null() {
-        return std::ptr::null_mut();
-    }
+    catch_panic("value_from_cstring", || {
+        if c_string.is_null() {
+            set_null_pointer_error("value_from_cstring", "`c_string`");
+            return std::ptr::null_mut();
+        }
 
-    let c_str = unsafe { CStr::from_ptr(c_string) };
-    let c_str = match c_str.to_str() {
-        Ok(s) => s,
-        Err(_) => return std::ptr::null_mut(),
-    };
+        let c_str = unsafe { CStr::from_ptr(c_string) };
+        let c_str = match c_str.to_str() {
+            Ok(s) => s,
+            Err(_) => {
+                set_last_error(YadErrorCode::InvalidUtf8, "value_from_cstring: `c_string` is not valid UTF-8");
+                return std::ptr::null_mut();
+            }
+        };
 
-    if let Ok(val) = Value::try_from(c_str) {
-        Box::into_raw(Box::new(val))
-    } else {
-        std::ptr::null_mut()
-    }
+        match Value::try_from(c_str) {
+            Ok(val) => Box::into_raw(Box::new(val)),
+            Err(e) => {
+                set_error_message("value_from_cstring", YadErrorCode::Encode, e);
+                std::ptr::null_mut()
+            }
+        }
+    })
 }
 
 /// Converts a [`Value`] containing a Rust string into a C string (`*const c_char`).
@@ -46,20 +56,30 @@ pub extern "C" fn value_from_cstring(c_string: *const c_char) -> *mut Value {
 /// - The returned C string must be freed using [`cstring_free`] when no longer needed.
 /// - Pointer must not be used after being freed.
 #[unsafe(no_mangle)]
+#[cfg_attr(feature = "prefixed-symbols", unsafe(export_name = "yad_cstring_from_value"))]
 pub extern "C" fn cstring_from_value(value: *mut Value) -> *const c_char {
-    if value.is_null() {
-        return std::ptr::null();
-    }
+    catch_panic("cstring_from_value", || {
+        if value.is_null() {
+            set_null_pointer_error("cstring_from_value", "`value`");
+            return std::ptr::null();
+        }
 
-    unsafe {
-        match (&*value).clone().try_into() {
-            Ok(string) => match CString::new::<String>(string) {
-                Ok(cstr) => cstr.into_raw() as *const c_char,
-                Err(_) => std::ptr::null(),
-            },
-            Err(_) => std::ptr::null(),
+        unsafe {
+            match (&*value).clone().try_into() {
+                Ok(string) => match CString::new::<String>(string) {
+                    Ok(cstr) => cstr.into_raw() as *const c_char,
+                    Err(_) => {
+                        set_last_error(YadErrorCode::Conversion, "cstring_from_value: the string contains a null byte");
+                        std::ptr::null()
+                    }
+                },
+                Err(_) => {
+                    set_last_error(YadErrorCode::Conversion, "cstring_from_value: `value` does not hold a string");
+                    std::ptr::null()
+                }
+            }
         }
-    }
+    })
 }
 
 /// Frees a C string previously allocated by [`cstring_from_value`].
@@ -71,15 +91,18 @@ pub extern "C" fn cstring_from_value(value: *mut Value) -> *const c_char {
 /// - `cstr` must be a valid pointer returned by [`cstring_from_value`].
 /// - After calling this function, `cstr` must not be used again.
 #[unsafe(no_mangle)]
+#[cfg_attr(feature = "prefixed-symbols", unsafe(export_name = "yad_cstring_free"))]
 pub extern "C" fn cstring_free(cstr: *mut c_char) {
-    if cstr.is_null() {
-        return;
-    }
+    catch_panic("cstring_free", || {
+        if cstr.is_null() {
+            return;
+        }
 
-    unsafe {
-        // Reconstruct CString to drop it and free memory
-        drop(CString::from_raw(cstr))
-    }
+        unsafe {
+            // Reconstruct CString to drop it and free memory
+            drop(CString::from_raw(cstr))
+        }
+    })
 }
 
 /// Returns the length of a string contained within a [`Value`] as a C-compatible size.
@@ -95,11 +118,18 @@ pub extern "C" fn cstring_free(cstr: *mut c_char) {
 /// - `value` must be a valid pointer or null.
 /// - The memory pointed to by `value` must remain valid for the duration of the call.
 #[unsafe(no_mangle)]
+#[cfg_attr(feature = "prefixed-symbols", unsafe(export_name = "yad_cstring_len_from_value"))]
 pub extern "C" fn cstring_len_from_value(value: *mut Value) -> usize {
-    if value.is_null() {
-        return 0;
-    }
+    catch_panic("cstring_len_from_value", || {
+        if value.is_null() {
+            set_null_pointer_error("cstring_len_from_value", "`value`");
+            return 0;
+        }
 
-    let string: Result<String, _> = unsafe { (&*value).clone().try_into() };
-    string.map(|s| s.len()).unwrap_or(0)
+        let string: Result<String, _> = unsafe { (&*value).clone().try_into() };
+        string.map(|s| s.len()).unwrap_or_else(|_| {
+            set_last_error(YadErrorCode::Conversion, "cstring_len_from_value: `value` does not hold a string");
+            0
+        })
+    })
 }
@@ -1,4 +1,6 @@
 use crate::Value;
+use crate::ffi::ByteBuffer;
+use crate::ffi::error::{catch_panic, set_decode_error, set_error_message, set_null_pointer_error, YadErrorCode};
 
 /// Submodules providing specialized functions for different types of `Value`.
 ///
@@ -10,6 +12,9 @@ use crate::Value;
 /// - `_bool_functions`: Functions for boolean values
 /// - `_string_functions`: Functions for string values
 /// - `_array_functions`: Functions for array values
+/// - `_extension_functions`: Functions for extension values
+/// - `_decimal_functions`: Functions for decimal values
+/// - `_big_integer_functions`: Functions for big integer values
 mod _8b_functions;
 pub use _8b_functions::*;
 mod _32b_functions;
@@ -24,6 +29,12 @@ mod _string_functions;
 pub use _string_functions::*;
 mod _array_functions;
 pub use _array_functions::*;
+mod _extension_functions;
+pub use _extension_functions::*;
+mod _decimal_functions;
+pub use _decimal_functions::*;
+mod _big_integer_functions;
+pub use _big_integer_functions::*;
 
 /// Frees a `Value` previously allocated on the heap.
 ///
@@ -34,10 +45,13 @@ pub use _array_functions::*;
 /// - `val` must be a pointer previously returned from a creation function.
 /// - After calling this function, the pointer must not be used again.
 #[unsafe(no_mangle)]
+#[cfg_attr(feature = "prefixed-symbols", unsafe(export_name = "yad_value_free"))]
 pub extern "C" fn value_free(val: *mut Value) {
-    if !val.is_null() {
-        unsafe { drop(Box::from_raw(val)) }
-    }
+    catch_panic("value_free", || {
+        if !val.is_null() {
+            unsafe { drop(Box::from_raw(val)) }
+        }
+    })
 }
 
 /// Decodes a buffer of bytes into a heap-allocated `Value`.
@@ -56,23 +70,67 @@ pub extern "C" fn value_free(val: *mut Value) {
 /// - Returns `null` if `buff` is null, `len` is zero, or decoding fails.
 ///
 /// # Notes
-/// - This function creates a temporary Rust `Vec<u8>` from the raw buffer to pass to `Value::decode`.
+/// - The raw buffer is decoded in place with `Value::decode_in_place`; only the decoded value is copied.
 /// - Ownership of the returned `Value` is fully transferred; Rust will not automatically free it.
 /// - The caller must ensure proper deallocation to avoid memory leaks.
 #[unsafe(no_mangle)]
+#[cfg_attr(feature = "prefixed-symbols", unsafe(export_name = "yad_value_from_buffer"))]
 pub extern "C" fn value_from_buffer(buff: *const u8, len: usize) -> *mut Value {
-    if buff.is_null() || len == 0 {
-        return std::ptr::null_mut()
-    }
-
-    unsafe {
-        let slice = std::slice::from_raw_parts(buff, len);
-        let vec = slice.to_vec();
-        match Value::decode(vec) {
-            Ok(val) => Box::into_raw(Box::new(val)),
-            Err(_) => std::ptr::null_mut(),
+    catch_panic("value_from_buffer", || {
+        if buff.is_null() || len == 0 {
+            set_null_pointer_error("value_from_buffer", "`buff` (of non-zero `len`)");
+            return std::ptr::null_mut()
+        }
+
+        unsafe {
+            let slice = std::slice::from_raw_parts(buff, len);
+            match Value::decode_in_place(slice) {
+                Ok(val) => Box::into_raw(Box::new(val)),
+                Err(e) => {
+                    set_error_message("value_from_buffer", YadErrorCode::Decode, e);
+                    std::ptr::null_mut()
+                }
+            }
         }
-    }
+    })
+}
+
+/// Decodes a buffer of bytes into a heap-allocated `Value`, like
+/// [`value_from_buffer`], unless the value would take more than `max_alloc` bytes.
+///
+/// # Safety
+/// - The caller must ensure that `buff` points to a valid memory region of at least `len` bytes.
+/// - The returned pointer must be freed with `value_free`.
+///
+/// # Parameters
+/// - `buff`: Pointer to a contiguous buffer of `u8` containing the encoded value.
+/// - `len`: Length of the buffer in bytes.
+/// - `max_alloc`: Largest encoded value, in bytes, the call may allocate.
+///
+/// # Returns
+/// - A raw pointer to a heap-allocated `Value` on success.
+/// - Returns `null` if `buff` is null, `len` is zero, or decoding fails. A value
+///   over the limit is reported as [`YadErrorCode::LimitExceeded`] before anything is allocated.
+#[unsafe(no_mangle)]
+#[cfg_attr(feature = "prefixed-symbols", unsafe(export_name = "yad_value_from_buffer_limited"))]
+pub unsafe extern "C" fn value_from_buffer_limited(buff: *const u8, len: usize, max_alloc: usize) -> *mut Value {
+    catch_panic("value_from_buffer_limited", || {
+        if buff.is_null() || len == 0 {
+            set_null_pointer_error("value_from_buffer_limited", "`buff` (of non-zero `len`)");
+            return std::ptr::null_mut()
+        }
+
+        unsafe {
+            let slice = std::slice::from_raw_parts(buff, len);
+            match Value::decode_limited(slice, max_alloc) {
+                Ok(val) => Box::into_raw(Box::new(val)),
+                Err(e) => {
+                    set_decode_error("value_from_buffer_limited", e);
+                    std::ptr::null_mut()
+                }
+            }
+        }
+    })
 }
 
 /// Returns the type of the `Value`.
@@ -87,14 +145,18 @@ pub extern "C" fn value_from_buffer(buff: *const u8, len: usize) -> *mut Value {
 /// # Safety
 /// - `val` must be a valid pointer or null.
 #[unsafe(no_mangle)]
+#[cfg_attr(feature = "prefixed-symbols", unsafe(export_name = "yad_value_type"))]
 pub extern "C" fn value_type(val: *mut Value) -> u8 {
-    if val.is_null() {
-        return 0
-    }
+    catch_panic("value_type", || {
+        if val.is_null() {
+            set_null_pointer_error("value_type", "`val`");
+            return 0
+        }
 
-    unsafe {
-        (*val).r#type as u8
-    }
+        unsafe {
+            (*val).r#type as u8
+        }
+    })
 }
 
 /// Returns the length of the `Value` in bytes (or its logical length).
@@ -109,14 +171,18 @@ pub extern "C" fn value_type(val: *mut Value) -> u8 {
 /// # Safety
 /// - `val` must be a valid pointer or null.
 #[unsafe(no_mangle)]
+#[cfg_attr(feature = "prefixed-symbols", unsafe(export_name = "yad_value_len"))]
 pub extern "C" fn value_len(val: *mut Value) -> u8 {
-    if val.is_null() {
-        return 0
-    }
+    catch_panic("value_len", || {
+        if val.is_null() {
+            set_null_pointer_error("value_len", "`val`");
+            return 0
+        }
 
-    unsafe {
-        (&*val).length as u8
-    }
+        unsafe {
+            (&*val).length as u8
+        }
+    })
 }
 
 /// Returns a raw pointer to the underlying bytes of the `Value`.
@@ -131,14 +197,18 @@ pub extern "C" fn value_len(val: *mut Value) -> u8 {
 /// - The returned pointer is valid as long as the `Value` is alive.
 /// - Modifying the memory through this pointer may cause undefined behavior.
 #[unsafe(no_mangle)]
+#[cfg_attr(feature = "prefixed-symbols", unsafe(export_name = "yad_value_raw_bytes"))]
 pub extern "C" fn value_raw_bytes(val: *mut Value) -> *const u8 {
-    if val.is_null() {
-        return std::ptr::null()
-    }
+    catch_panic("value_raw_bytes", || {
+        if val.is_null() {
+            set_null_pointer_error("value_raw_bytes", "`val`");
+            return std::ptr::null()
+        }
 
-    unsafe {
-        (&*val).bytes.as_ptr()
-    }
+        unsafe {
+            (&*val).bytes.as_ptr()
+        }
+    })
 }
 
 /// Returns the length of the raw byte buffer of the `Value`.
@@ -152,12 +222,42 @@ pub extern "C" fn value_raw_bytes(val: *mut Value) -> *const u8 {
 /// # Safety
 /// - `val` must be a valid pointer or null.
 #[unsafe(no_mangle)]
+#[cfg_attr(feature = "prefixed-symbols", unsafe(export_name = "yad_value_raw_bytes_length"))]
 pub extern "C" fn value_raw_bytes_length(val: *mut Value) -> usize {
-    if val.is_null() {
-        return 0
-    }
+    catch_panic("value_raw_bytes_length", || {
+        if val.is_null() {
+            set_null_pointer_error("value_raw_bytes_length", "`val`");
+            return 0
+        }
+
+        unsafe {
+            (&*val).bytes.len()
+        }
+    })
+}
+
+/// Returns a copy of the encoded bytes of the `Value`.
+///
+/// # Parameters
+/// - `val`: Pointer to a `Value`.
+///
+/// # Returns
+/// - A [`ByteBuffer`] holding the encoded value; free it with `byte_buffer_free`.
+/// - An empty buffer with a null `ptr` if `val` is null.
+///
+/// # Safety
+/// - `val` must be a valid pointer or null.
+#[unsafe(no_mangle)]
+#[cfg_attr(feature = "prefixed-symbols", unsafe(export_name = "yad_value_as_buffer"))]
+pub unsafe extern "C" fn value_as_buffer(val: *mut Value) -> ByteBuffer {
+    catch_panic("value_as_buffer", || {
+        if val.is_null() {
+            set_null_pointer_error("value_as_buffer", "`val`");
+            return ByteBuffer::null()
+        }
 
-    unsafe {
-        (&*val).bytes.len()
-    }
+        unsafe {
+            ByteBuffer::from_vec((&*val).bytes.to_vec())
+        }
+    })
 }
@@ -0,0 +1,84 @@
+use float16::f16;
+use float8::F8E4M3;
+use crate::constants::error::{ErrorMessage, INFINITY_NOT_ALLOWED, NAN_NOT_ALLOWED};
+use crate::constants::length::ByteLength;
+use crate::constants::types::Type;
+use crate::Value;
+
+/// How non-finite floats (NaN, +Inf and -Inf) are handled.
+///
+/// Applied with [`Value::with_non_finite_policy`], and by `serde_yad` when
+/// serializing or decoding documents with a policy other than `Allow`.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum NonFinitePolicy {
+    /// Keep non-finite floats as they are.
+    #[default]
+    Allow,
+    /// Fail with `NAN_NOT_ALLOWED` or `INFINITY_NOT_ALLOWED`.
+    Reject,
+    /// Keep the float width but rewrite NaN (any payload or sign) as the
+    /// width's canonical quiet NaN, and saturate ±Inf to the width's largest
+    /// finite value of the same sign.
+    Canonicalize,
+}
+
+impl Value {
+    /// Builds an `f64` float `Value`, applying `policy` to non-finite input.
+    ///
+    /// # Errors
+    /// Returns `NAN_NOT_ALLOWED` or `INFINITY_NOT_ALLOWED` under [`NonFinitePolicy::Reject`].
+    pub fn from_f64_with_policy(value: f64, policy: NonFinitePolicy) -> Result<Self, ErrorMessage> {
+        Value::from(value).with_non_finite_policy(policy)
+    }
+
+    /// Applies `policy` to this value.
+    ///
+    /// Floats of every width are checked, as are floats nested in arrays (at any
+    /// depth). Other values, and every value under [`NonFinitePolicy::Allow`],
+    /// are returned unchanged.
+    ///
+    /// # Errors
+    /// Returns `NAN_NOT_ALLOWED` or `INFINITY_NOT_ALLOWED` under
+    /// [`NonFinitePolicy::Reject`], or a decoding error if an array is malformed.
+    pub fn with_non_finite_policy(self, policy: NonFinitePolicy) -> Result<Self, ErrorMessage> {
+        if policy == NonFinitePolicy::Allow {
+            return Ok(self);
+        }
+
+        match self.r#type {
+            Type::Float => {
+                let value = self.as_f64_lossy()?;
+                if value.is_finite() {
+                    return Ok(self);
+                }
+
+                if policy == NonFinitePolicy::Reject {
+                    let error = if value.is_nan() { NAN_NOT_ALLOWED } else { INFINITY_NOT_ALLOWED };
+                    return Err(ErrorMessage(error));
+                }
+
+                Ok(canonical_non_finite(self.length, value))
+            }
+            Type::Array => {
+                let items: Vec<Value> = self.try_into()?;
+                let items = items.into_iter()
+                    .map(|item| item.with_non_finite_policy(policy))
+                    .collect::<Result<Vec<_>, _>>()?;
+                Value::try_from(items)
+            }
+            _ => Ok(self),
+        }
+    }
+}
+
+/// Returns the canonical replacement of the non-finite `value` in the float width `length`.
+fn canonical_non_finite(length: ByteLength, value: f64) -> Value {
+    let positive = value.is_sign_positive();
+
+    match length {
+        ByteLength::One => Value::from(if value.is_nan() { F8E4M3::NAN } else if positive { F8E4M3::MAX } else { F8E4M3::MIN }),
+        ByteLength::Two => Value::from(if value.is_nan() { f16::NAN } else if positive { f16::MAX } else { f16::MIN }),
+        ByteLength::Four => Value::from(if value.is_nan() { f32::NAN } else if positive { f32::MAX } else { f32::MIN }),
+        _ => Value::from(if value.is_nan() { f64::NAN } else if positive { f64::MAX } else { f64::MIN }),
+    }
+}
@@ -0,0 +1,160 @@
+use std::fmt::Write;
+use crate::constants::length::ByteLength;
+use crate::constants::types::Type;
+use crate::Value;
+
+/// Bytes shown per line before a segment wraps onto a continuation line.
+const BYTES_PER_LINE: usize = 8;
+
+/// Width of the hex column, wide enough for [`BYTES_PER_LINE`] bytes plus nesting.
+const HEX_COLUMN: usize = 32;
+
+/// Appends one annotated segment to `out`, wrapping long segments.
+fn write_segment(out: &mut String, offset: usize, depth: usize, bytes: &[u8], label: &str) {
+    for (i, chunk) in bytes.chunks(BYTES_PER_LINE).enumerate() {
+        let hex: Vec<String> = chunk.iter().map(|b| format!("{:02X}", b)).collect();
+        let hex = format!("{}{}", "  ".repeat(depth), hex.join(" "));
+        let label = if i == 0 { label } else { "..." };
+
+        let _ = writeln!(out, "{:08X}  {:<width$}  {}", offset + i * BYTES_PER_LINE, hex, label, width = HEX_COLUMN);
+    }
+}
+
+/// Returns the name of a type as shown in the dump (`uint`, `string`, ...).
+fn type_name(r#type: Type) -> &'static str {
+    match r#type {
+        Type::Uint => "uint",
+        Type::Int => "int",
+        Type::Float => "float",
+        Type::String => "string",
+        Type::Array => "array",
+        Type::Bool | Type::True | Type::False => "bool",
+        Type::Extension => "extension",
+    }
+}
+
+/// Reads a big-endian length descriptor.
+fn read_length(bytes: &[u8]) -> usize {
+    bytes.iter().fold(0usize, |acc, b| acc.wrapping_shl(8) | *b as usize)
+}
+
+/// Dumps the encoded value at `pos`, returning the position after it.
+///
+/// Malformed input is dumped as a single `invalid` segment running to the end.
+fn dump(out: &mut String, bytes: &[u8], pos: usize, depth: usize) -> usize {
+    let invalid = |out: &mut String, pos: usize, reason: &str| {
+        write_segment(out, pos, depth, &bytes[pos..], &format!("invalid: {}", reason));
+        bytes.len()
+    };
+
+    let Some(&header) = bytes.get(pos) else { return pos };
+    let (Ok(r#type), Ok(length)) = (Type::try_from(header), ByteLength::try_from(header)) else {
+        return invalid(out, pos, "unknown header");
+    };
+    let width = length.as_byte_count() as usize;
+
+    match r#type {
+        Type::Bool | Type::True | Type::False => {
+            write_segment(out, pos, depth, &bytes[pos..=pos], &format!("header: bool = {}", r#type != Type::False));
+            pos + 1
+        }
+        Type::Uint | Type::Int | Type::Float => {
+            let end = pos + 1 + width;
+            if bytes.len() < end {
+                return invalid(out, pos, "truncated number");
+            }
+
+            write_segment(out, pos, depth, &bytes[pos..=pos], &format!("header: {}{}", type_name(r#type), width * 8));
+            let payload = match Value::decode_in_place(&bytes[pos..end]) {
+                Ok(value) => format!("payload: {}", value),
+                Err(_) => String::from("payload"),
+            };
+            write_segment(out, pos + 1, depth, &bytes[pos + 1..end], &payload);
+            end
+        }
+        Type::Extension => {
+            let start = pos + 1 + width;
+            if width == 0 || bytes.len() < start {
+                return invalid(out, pos, "truncated length");
+            }
+
+            let count = read_length(&bytes[pos + 1..start]);
+            let Some(end) = start.checked_add(count).filter(|end| count > 0 && *end <= bytes.len()) else {
+                return invalid(out, pos, "truncated extension");
+            };
+
+            write_segment(out, pos, depth, &bytes[pos..=pos], &format!("header: extension, {}-byte length", width));
+            write_segment(out, pos + 1, depth, &bytes[pos + 1..start], &format!("length: {} byte(s)", count));
+            write_segment(out, start, depth, &bytes[start..=start], &format!("tag: {:#04x}", bytes[start]));
+            if end > start + 1 {
+                let value = Value::decode_in_place(&bytes[pos..end]);
+                let label = match value {
+                    Ok(value) if value.as_decimal().is_ok() => format!("data: decimal {}", value),
+                    Ok(value) if value.as_big_integer().is_ok() => format!("data: big integer {}", value),
+                    _ => String::from("data"),
+                };
+                write_segment(out, start + 1, depth, &bytes[start + 1..end], &label);
+            }
+            end
+        }
+        Type::String | Type::Array => {
+            let start = pos + 1 + width;
+            if width == 0 || bytes.len() < start {
+                return invalid(out, pos, "truncated length");
+            }
+
+            let count = read_length(&bytes[pos + 1..start]);
+            let unit = if r#type == Type::String { "byte" } else { "item" };
+
+            write_segment(out, pos, depth, &bytes[pos..=pos], &format!("header: {}, {}-byte length", type_name(r#type), width));
+            write_segment(out, pos + 1, depth, &bytes[pos + 1..start], &format!("length: {} {}(s)", count, unit));
+
+            if r#type == Type::String {
+                let Some(end) = start.checked_add(count).filter(|end| *end <= bytes.len()) else {
+                    return invalid(out, start, "truncated string");
+                };
+                let text = String::from_utf8_lossy(&bytes[start..end]);
+                write_segment(out, start, depth, &bytes[start..end], &format!("payload: {:?}", text));
+                return end;
+            }
+
+            let mut pos = start;
+            for _ in 0..count {
+                if pos >= bytes.len() {
+                    let _ = writeln!(out, "{:08X}  {:<width$}  invalid: truncated array", pos, "", width = HEX_COLUMN);
+                    return pos;
+                }
+                pos = dump(out, bytes, pos, depth + 1);
+            }
+            pos
+        }
+    }
+}
+
+/// Dumps the encoded bytes of `value`, one segment per line, annotated with
+/// header, length and payload boundaries.
+///
+/// Each line shows the offset, the bytes (indented per array nesting level)
+/// and what they encode. Bytes that cannot be decoded are shown as `invalid`
+/// instead of stopping the dump, so malformed values can be inspected too.
+///
+/// # Example
+/// ```text
+/// 00000000  51                                header: array, 1-byte length
+/// 00000001  02                                length: 2 item(s)
+/// 00000002    11                              header: uint8
+/// 00000003    C8                              payload: 200
+/// 00000004    41                              header: string, 1-byte length
+/// 00000005    02                              length: 2 byte(s)
+/// 00000006    68 69                           payload: "hi"
+/// ```
+pub fn hexdump(value: &Value) -> String {
+    let mut out = String::new();
+    let end = dump(&mut out, &value.bytes, 0, 0);
+
+    if end < value.bytes.len() {
+        write_segment(&mut out, end, 0, &value.bytes[end..], "trailing bytes");
+    }
+
+    out
+}
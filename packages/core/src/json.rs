@@ -0,0 +1,165 @@
+use crate::constants::error::{
+    ErrorMessage,
+    JSON_NULL_NOT_SUPPORTED,
+    JSON_NUMBER_NOT_REPRESENTABLE,
+    JSON_OBJECT_NOT_SUPPORTED,
+};
+use crate::Value;
+
+/// Controls which integer width is chosen when converting a JSON integer.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum IntegerWidth {
+    /// Use the smallest of 8, 16, 32 or 64 bits that holds the number.
+    Smallest,
+    /// Always use 64 bits.
+    Fixed64,
+}
+
+/// Controls which floating point width is chosen when converting a JSON float.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum FloatWidth {
+    /// Always use 64 bits.
+    F64,
+    /// Use 32 bits when the number survives the round trip unchanged, 64 bits otherwise.
+    Smallest,
+}
+
+/// Width inference rules used by [`Value::from_json_value`].
+///
+/// JSON only has one number type, while YAD distinguishes signed, unsigned and
+/// floating point numbers across several widths. The policy decides which one
+/// each JSON number becomes.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct NumberPolicy {
+    /// Width selection for integers.
+    pub integer_width: IntegerWidth,
+    /// Encode non-negative integers as unsigned. When `false` every integer is signed.
+    pub prefer_unsigned: bool,
+    /// Width selection for floats.
+    pub float_width: FloatWidth,
+    /// Encode floats without a fractional part (e.g. `3.0`) as integers.
+    pub integral_floats_as_integers: bool,
+}
+
+impl Default for NumberPolicy {
+    /// Smallest widths, unsigned for non-negative integers, 64-bit floats kept as floats.
+    fn default() -> Self {
+        Self {
+            integer_width: IntegerWidth::Smallest,
+            prefer_unsigned: true,
+            float_width: FloatWidth::F64,
+            integral_floats_as_integers: false,
+        }
+    }
+}
+
+/// Encodes a non-negative integer as an unsigned `Value` following `width`.
+fn unsigned_value(n: u64, width: IntegerWidth) -> Value {
+    match width {
+        IntegerWidth::Fixed64 => Value::from(n),
+        IntegerWidth::Smallest => {
+            if let Ok(v) = u8::try_from(n) {
+                Value::from(v)
+            } else if let Ok(v) = u16::try_from(n) {
+                Value::from(v)
+            } else if let Ok(v) = u32::try_from(n) {
+                Value::from(v)
+            } else {
+                Value::from(n)
+            }
+        }
+    }
+}
+
+/// Encodes an integer as a signed `Value` following `width`.
+fn signed_value(n: i64, width: IntegerWidth) -> Value {
+    match width {
+        IntegerWidth::Fixed64 => Value::from(n),
+        IntegerWidth::Smallest => {
+            if let Ok(v) = i8::try_from(n) {
+                Value::from(v)
+            } else if let Ok(v) = i16::try_from(n) {
+                Value::from(v)
+            } else if let Ok(v) = i32::try_from(n) {
+                Value::from(v)
+            } else {
+                Value::from(n)
+            }
+        }
+    }
+}
+
+/// Encodes a float as a `Value` following `width`.
+fn float_value(n: f64, width: FloatWidth) -> Value {
+    match width {
+        FloatWidth::F64 => Value::from(n),
+        FloatWidth::Smallest => {
+            let narrowed = n as f32;
+            if narrowed as f64 == n {
+                Value::from(narrowed)
+            } else {
+                Value::from(n)
+            }
+        }
+    }
+}
+
+/// Converts a JSON number following `policy`.
+fn number_value(number: &serde_json::Number, policy: NumberPolicy) -> Result<Value, ErrorMessage> {
+    if let Some(n) = number.as_u64() {
+        if policy.prefer_unsigned {
+            return Ok(unsigned_value(n, policy.integer_width));
+        }
+
+        let n = i64::try_from(n).map_err(|_| ErrorMessage(JSON_NUMBER_NOT_REPRESENTABLE))?;
+        return Ok(signed_value(n, policy.integer_width));
+    }
+
+    if let Some(n) = number.as_i64() {
+        return Ok(signed_value(n, policy.integer_width));
+    }
+
+    let n = number.as_f64().ok_or(ErrorMessage(JSON_NUMBER_NOT_REPRESENTABLE))?;
+
+    // `u64::MAX as f64` and `i64::MAX as f64` round up to 2^64 and 2^63,
+    // which do not fit, so the upper bounds are exclusive.
+    if policy.integral_floats_as_integers && n.fract() == 0.0 {
+        if policy.prefer_unsigned && (0.0..18446744073709551616.0).contains(&n) {
+            return Ok(unsigned_value(n as u64, policy.integer_width));
+        }
+        if (i64::MIN as f64..9223372036854775808.0).contains(&n) {
+            return Ok(signed_value(n as i64, policy.integer_width));
+        }
+    }
+
+    Ok(float_value(n, policy.float_width))
+}
+
+impl Value {
+    /// Converts a `serde_json::Value` into a `Value`.
+    ///
+    /// - Booleans, strings and arrays map to their YAD counterparts.
+    /// - Numbers are encoded following `policy`.
+    ///
+    /// # Errors
+    /// - `JSON_NULL_NOT_SUPPORTED` for `null`, which YAD has no type for.
+    /// - `JSON_OBJECT_NOT_SUPPORTED` for objects; those map to rows, not values.
+    /// - `STRING_OF_LENGTH_ZERO` / `VEC_OF_LENGTH_ZERO` for empty strings and arrays.
+    /// - `JSON_NUMBER_NOT_REPRESENTABLE` if the number does not fit the policy.
+    pub fn from_json_value(json: &serde_json::Value, policy: NumberPolicy) -> Result<Self, ErrorMessage> {
+        match json {
+            serde_json::Value::Null => Err(ErrorMessage(JSON_NULL_NOT_SUPPORTED)),
+            serde_json::Value::Bool(b) => Ok(Value::from(*b)),
+            serde_json::Value::Number(n) => number_value(n, policy),
+            serde_json::Value::String(s) => Value::try_from(s.as_str()),
+            serde_json::Value::Array(items) => {
+                let mut values = Vec::with_capacity(items.len());
+                for item in items {
+                    values.push(Self::from_json_value(item, policy)?);
+                }
+                Value::try_from(values)
+            }
+            serde_json::Value::Object(_) => Err(ErrorMessage(JSON_OBJECT_NOT_SUPPORTED)),
+        }
+    }
+}
@@ -0,0 +1,106 @@
+use crate::constants::length::ByteLength;
+use crate::constants::types::Type;
+use crate::bigint::BIG_INTEGER_EXTENSION_TAG;
+use crate::decimal::DECIMAL_EXTENSION_TAG;
+use crate::Value;
+
+/// What a [`Value`] holds, without its encoding width or boolean state.
+///
+/// [`Type`] mirrors the header byte, where booleans have three variants;
+/// `ValueKind` is what generic code usually wants to match on.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum ValueKind {
+    Uint,
+    Int,
+    Float,
+    String,
+    Array,
+    Bool,
+    Decimal,
+    BigInteger,
+    Extension,
+}
+
+impl From<Type> for ValueKind {
+    fn from(r#type: Type) -> Self {
+        match r#type {
+            Type::Uint => ValueKind::Uint,
+            Type::Int => ValueKind::Int,
+            Type::Float => ValueKind::Float,
+            Type::String => ValueKind::String,
+            Type::Array => ValueKind::Array,
+            Type::Bool | Type::True | Type::False => ValueKind::Bool,
+            Type::Extension => ValueKind::Extension,
+        }
+    }
+}
+
+impl Value {
+    /// Returns what the value holds. Decimals and big integers are extension
+    /// values to [`Type`], but [`ValueKind::Decimal`] and
+    /// [`ValueKind::BigInteger`] here.
+    pub fn kind(&self) -> ValueKind {
+        match self.extension_tag() {
+            Ok(DECIMAL_EXTENSION_TAG) => ValueKind::Decimal,
+            Ok(BIG_INTEGER_EXTENSION_TAG) => ValueKind::BigInteger,
+            _ => ValueKind::from(self.r#type),
+        }
+    }
+
+    /// Returns `true` for unsigned integers of any width.
+    pub fn is_uint(&self) -> bool {
+        self.kind() == ValueKind::Uint
+    }
+
+    /// Returns `true` for signed integers of any width.
+    pub fn is_int(&self) -> bool {
+        self.kind() == ValueKind::Int
+    }
+
+    /// Returns `true` for floats of any width.
+    pub fn is_float(&self) -> bool {
+        self.kind() == ValueKind::Float
+    }
+
+    /// Returns `true` for integers and floats of any width.
+    pub fn is_number(&self) -> bool {
+        matches!(self.kind(), ValueKind::Uint | ValueKind::Int | ValueKind::Float)
+    }
+
+    /// Returns `true` for strings.
+    pub fn is_string(&self) -> bool {
+        self.kind() == ValueKind::String
+    }
+
+    /// Returns `true` for booleans.
+    pub fn is_bool(&self) -> bool {
+        self.kind() == ValueKind::Bool
+    }
+
+    /// Returns `true` for application-defined extension values (see
+    /// [`Value::from_extension`]); decimals and big integers are not.
+    pub fn is_extension(&self) -> bool {
+        self.kind() == ValueKind::Extension
+    }
+
+    /// Returns `true` for values holding other values, which are arrays.
+    pub fn is_container(&self) -> bool {
+        self.kind() == ValueKind::Array
+    }
+
+    /// Returns the width of a number in bits (8, 16, 32 or 64), or `None` for
+    /// other kinds.
+    pub fn numeric_bit_width(&self) -> Option<u8> {
+        match self.length {
+            ByteLength::Zero => None,
+            length if self.is_number() => Some(length.as_byte_count() * 8),
+            _ => None,
+        }
+    }
+
+    /// Returns the number of elements of an array, or `None` for other kinds
+    /// (see [`Value::array_len`]).
+    pub fn element_count(&self) -> Option<usize> {
+        self.array_len().ok()
+    }
+}
@@ -1,9 +1,13 @@
 use std::fmt;
 use crate::constants::error::{
     ErrorMessage,
+    ALLOCATION_LIMIT_EXCEEDED,
+    EXTENSION_OF_LENGTH_ZERO,
     MALFORMED_UTF8,
     NOT_AN_ARRAY,
     NOT_A_BOOL,
+    NOT_A_FLOAT,
+    NOT_COERCIBLE_TO_U64,
     NOT_A_FLOAT16,
     NOT_A_FLOAT32,
     NOT_A_FLOAT32_VALUE,
@@ -32,7 +36,6 @@ use crate::constants::error::{
     NESTING_TOO_DEEP,
     STRING_MAX_LENGTH_EXCEEDED,
     STRING_OF_LENGTH_ZERO,
-    UNKNOWN,
     VEC_MAX_LENGTH_EXCEEDED,
     VEC_OF_LENGTH_ZERO,
 };
@@ -40,9 +43,49 @@ use crate::constants::length::ByteLength;
 use crate::constants::types::{Type, FLOATING_POINT_TYPE};
 use float8::F8E4M3;
 use float16::f16;
+use smallvec::{smallvec, SmallVec};
 
 pub mod constants;
+#[cfg(feature = "ffi")]
 pub mod ffi;
+pub mod compare;
+pub mod kind;
+pub mod visit;
+pub mod arithmetic;
+mod array;
+mod string;
+pub mod extension;
+pub mod decimal;
+pub mod bigint;
+pub mod float;
+pub mod byte_order;
+pub mod varint;
+pub mod delta;
+mod hexdump;
+mod reader;
+mod encoder;
+#[cfg(feature = "json")]
+pub mod json;
+#[cfg(feature = "cbor")]
+pub mod cbor;
+#[cfg(feature = "protobuf")]
+pub mod protobuf;
+#[cfg(feature = "serde")]
+mod serde_impl;
+#[cfg(any(feature = "proptest", feature = "quickcheck"))]
+pub mod arbitrary;
+
+pub use hexdump::hexdump;
+pub use reader::Reader;
+pub use encoder::ArrayEncoder;
+
+/// Inline capacity of [`ValueBytes`]: every number (header plus at most eight
+/// payload bytes) and short strings fit without a heap allocation.
+pub const VALUE_INLINE_CAPACITY: usize = 16;
+
+/// Storage for the encoded bytes of a [`Value`]. Stays inline up to
+/// [`VALUE_INLINE_CAPACITY`] bytes and spills to the heap beyond that.
+pub type ValueBytes = SmallVec<[u8; VALUE_INLINE_CAPACITY]>;
 
 // [FIX #2] Maximum nesting depth for arrays to prevent stack overflow via
 // deeply nested malicious inputs. Adjust if legitimate use cases require deeper nesting.
@@ -50,7 +93,8 @@ const MAX_NESTING_DEPTH: usize = 64;
 
 // [FIX #1] Maximum number of elements to pre-allocate in a Vec when decoding
 // arrays. Prevents OOM attacks where the count field is large but the actual
-// payload is small. The Vec will still grow beyond this if needed.
+// payload is small. The Vec will still grow beyond this, one decoded element at a
+// time, so its size follows the input actually read.
 const MAX_PREALLOC_ELEMENTS: usize = 4096;
 
 /// Choose the smallest `ByteLength` that can represent `len`.
@@ -81,7 +125,7 @@ fn match_len_min_bytes(
 /// `len` encoded in big-endian using that width.
 fn extend_bytes_with_len_bytes(
     len: usize,
-    bytes: &mut Vec<u8>,
+    bytes: &mut ValueBytes,
     len_zero_error: &'static str,
     exceded_max_len_error: &'static str,
 ) -> Result<(), ErrorMessage> {
@@ -96,6 +140,124 @@ fn extend_bytes_with_len_bytes(
     Ok(())
 }
 
+/// Rewrites the header and length descriptor of a string or array `value`
+/// for `len`, in the narrowest width that holds it, leaving the payload as is.
+fn set_len_descriptor(
+    value: &mut Value,
+    len: usize,
+    len_zero_error: &'static str,
+    exceded_max_len_error: &'static str,
+) -> Result<(), ErrorMessage> {
+    let length = match_len_min_bytes(len, len_zero_error, exceded_max_len_error)?;
+    let mut header: ValueBytes = smallvec![u8::from(value.r#type) | u8::from(length)];
+    extend_bytes_with_len_bytes(len, &mut header, len_zero_error, exceded_max_len_error)?;
+
+    value.bytes.drain(..1 + value.length.as_byte_count() as usize);
+    value.bytes.insert_many(0, header);
+    value.length = length;
+    Ok(())
+}
+
+/// Returns the number of bytes taken by the encoded value at the start of `bytes`.
+// [FIX #2] Added `depth` parameter to consumed_for_value to enforce
+// MAX_NESTING_DEPTH and prevent stack overflows from deeply nested arrays.
+fn consumed_for_value(bytes: &[u8], depth: usize) -> Result<usize, ErrorMessage> {
+    if bytes.is_empty() {
+        return Err(ErrorMessage(NOT_ENOUGH_BYTES));
+    }
+
+    // [FIX #2] Reject inputs that exceed the maximum allowed nesting depth.
+    if depth > MAX_NESTING_DEPTH {
+        return Err(ErrorMessage(NESTING_TOO_DEEP));
+    }
+
+    let first = bytes[0];
+    let r#type = Type::try_from(first)?;
+    let bl = ByteLength::try_from(first)?;
+    let len_field_size = bl.as_byte_count() as usize;
+
+    // Booleans keep their value, not a length, in the low nibble.
+    let is_bool = matches!(r#type, Type::Bool | Type::True | Type::False);
+
+    if !is_bool && bytes.len() < 1 + len_field_size {
+        return Err(ErrorMessage(NOT_ENOUGH_BYTES));
+    }
+
+    match r#type {
+        Type::Uint | Type::Int | Type::Float => {
+            let total = 1 + len_field_size;
+            if bytes.len() < total {
+                return Err(ErrorMessage(NOT_ENOUGH_BYTES));
+            }
+            Ok(total)
+        }
+        Type::Bool | Type::True | Type::False => Ok(1),
+        Type::String | Type::Extension => {
+            let zero_error = if r#type == Type::String { STRING_OF_LENGTH_ZERO } else { EXTENSION_OF_LENGTH_ZERO };
+            let str_len = match bl {
+                ByteLength::Zero => Err(ErrorMessage(zero_error))?,
+                ByteLength::One => *bytes.get(1).ok_or(ErrorMessage(NOT_ENOUGH_BYTES))? as usize,
+                ByteLength::Two => {
+                    let s = bytes.get(1..=2).ok_or(ErrorMessage(NOT_ENOUGH_BYTES))?;
+                    u16::from_be_bytes(s.try_into().map_err(|_| ErrorMessage(NOT_ENOUGH_BYTES))?) as usize
+                }
+                ByteLength::Four => {
+                    let s = bytes.get(1..=4).ok_or(ErrorMessage(NOT_ENOUGH_BYTES))?;
+                    u32::from_be_bytes(s.try_into().map_err(|_| ErrorMessage(NOT_ENOUGH_BYTES))?) as usize
+                }
+                ByteLength::Eight => {
+                    let s = bytes.get(1..=8).ok_or(ErrorMessage(NOT_ENOUGH_BYTES))?;
+                    let v = u64::from_be_bytes(s.try_into().map_err(|_| ErrorMessage(NOT_ENOUGH_BYTES))?);
+                    if v as usize > usize::MAX { Err(ErrorMessage(VEC_MAX_LENGTH_EXCEEDED))? }
+                    v as usize
+                }
+            };
+            if r#type == Type::Extension && str_len == 0 {
+                return Err(ErrorMessage(EXTENSION_OF_LENGTH_ZERO));
+            }
+            let total = 1 + len_field_size + str_len;
+            if bytes.len() < total {
+                return Err(ErrorMessage(NOT_ENOUGH_BYTES));
+            }
+            Ok(total)
+        }
+        Type::Array => {
+            let count = match bl {
+                ByteLength::Zero => Err(ErrorMessage(VEC_OF_LENGTH_ZERO))?,
+                ByteLength::One => *bytes.get(1).ok_or(ErrorMessage(NOT_ENOUGH_BYTES))? as usize,
+                ByteLength::Two => {
+                    let s = bytes.get(1..=2).ok_or(ErrorMessage(NOT_ENOUGH_BYTES))?;
+                    u16::from_be_bytes(s.try_into().map_err(|_| ErrorMessage(NOT_ENOUGH_BYTES))?) as usize
+                }
+                ByteLength::Four => {
+                    let s = bytes.get(1..=4).ok_or(ErrorMessage(NOT_ENOUGH_BYTES))?;
+                    u32::from_be_bytes(s.try_into().map_err(|_| ErrorMessage(NOT_ENOUGH_BYTES))?) as usize
+                }
+                ByteLength::Eight => {
+                    let s = bytes.get(1..=8).ok_or(ErrorMessage(NOT_ENOUGH_BYTES))?;
+                    let v = u64::from_be_bytes(s.try_into().map_err(|_| ErrorMessage(NOT_ENOUGH_BYTES))?);
+                    if v as usize > usize::MAX { Err(ErrorMessage(VEC_MAX_LENGTH_EXCEEDED))? }
+                    v as usize
+                }
+            };
+
+            let mut pos = 1 + len_field_size;
+            for _ in 0..count {
+                if pos >= bytes.len() {
+                    return Err(ErrorMessage(NOT_ENOUGH_BYTES));
+                }
+                // [FIX #2] Pass depth + 1 to enforce nesting limit recursively.
+                let consumed = consumed_for_value(&bytes[pos..], depth + 1)?;
+                pos = pos.checked_add(consumed).ok_or(ErrorMessage(VEC_MAX_LENGTH_EXCEEDED))?;
+            }
+            if bytes.len() < pos {
+                return Err(ErrorMessage(NOT_ENOUGH_BYTES));
+            }
+            Ok(pos)
+        }
+    }
+}
+
 /// Represents a single value encoded in YAD's binary format.
 ///
 /// A `Value` is the in-memory representation of one encoded item. It stores:
@@ -109,6 +271,10 @@ fn extend_bytes_with_len_bytes(
 /// - Conversions (`TryInto` / `From`) rely on `r#type` and `length` matching expected values.
 /// - For nested `Array` values decoded via `TryInto<Vec<Value>>`, `bytes` always includes the
 ///   full encoding (header + length descriptor + payload) to preserve the invariant.
+///
+/// # Equality
+/// The derived `Eq`/`Ord` compare the encoded bytes. Use `Value::semantic_eq` and
+/// `Value::semantic_cmp` to compare what the values represent.
 #[derive(Clone, Ord, PartialOrd, Eq, PartialEq, Debug)]
 pub struct Value {
     /// Encoded type tag (header's type section). Use `Type::try_from(u8)` to obtain.
@@ -119,7 +285,7 @@ pub struct Value {
     /// For arrays/strings: header + length descriptor + payload.
     /// For numbers: header + numeric bytes.
     /// For booleans: header only (1 byte).
-    pub bytes: Vec<u8>,
+    pub bytes: ValueBytes,
 }
 
 impl Value {
@@ -136,184 +302,48 @@ impl Value {
     /// # Nesting limit
     /// Array decoding is bounded by `MAX_NESTING_DEPTH` to prevent stack overflows.
     pub fn decode(vec: Vec<u8>) -> Result<Self, ErrorMessage> {
-        if vec.len() < 1 {
-            Err(ErrorMessage(NOT_ENOUGH_BYTES))?
-        }
-
-        // [FIX #2] Added `depth` parameter to consumed_for_value to enforce
-        // MAX_NESTING_DEPTH and prevent stack overflows from deeply nested arrays.
-        fn consumed_for_value(bytes: &[u8], depth: usize) -> Result<usize, ErrorMessage> {
-            if bytes.is_empty() {
-                return Err(ErrorMessage(NOT_ENOUGH_BYTES));
-            }
-
-            // [FIX #2] Reject inputs that exceed the maximum allowed nesting depth.
-            if depth > MAX_NESTING_DEPTH {
-                return Err(ErrorMessage(NESTING_TOO_DEEP));
-            }
-
-            let first = bytes[0];
-            let r#type = Type::try_from(first)?;
-            let bl = ByteLength::try_from(first)?;
-            let len_field_size = usize::from(bl);
-
-            if bytes.len() < 1 + len_field_size {
-                return Err(ErrorMessage(NOT_ENOUGH_BYTES));
-            }
+        Self::decode_in_place(&vec)
+    }
 
-            match r#type {
-                Type::Uint | Type::Int | Type::Float => {
-                    let total = 1 + len_field_size;
-                    if bytes.len() < total {
-                        return Err(ErrorMessage(NOT_ENOUGH_BYTES));
-                    }
-                    Ok(total)
-                }
-                Type::Bool | Type::True | Type::False => Ok(1),
-                Type::String => {
-                    let str_len = match bl {
-                        ByteLength::Zero => Err(ErrorMessage(STRING_OF_LENGTH_ZERO))?,
-                        ByteLength::One => *bytes.get(1).ok_or(ErrorMessage(NOT_ENOUGH_BYTES))? as usize,
-                        ByteLength::Two => {
-                            let s = bytes.get(1..=2).ok_or(ErrorMessage(NOT_ENOUGH_BYTES))?;
-                            u16::from_be_bytes(s.try_into().map_err(|_| ErrorMessage(NOT_ENOUGH_BYTES))?) as usize
-                        }
-                        ByteLength::Four => {
-                            let s = bytes.get(1..=4).ok_or(ErrorMessage(NOT_ENOUGH_BYTES))?;
-                            u32::from_be_bytes(s.try_into().map_err(|_| ErrorMessage(NOT_ENOUGH_BYTES))?) as usize
-                        }
-                        ByteLength::Eight => {
-                            let s = bytes.get(1..=8).ok_or(ErrorMessage(NOT_ENOUGH_BYTES))?;
-                            let v = u64::from_be_bytes(s.try_into().map_err(|_| ErrorMessage(NOT_ENOUGH_BYTES))?);
-                            if v as usize > usize::MAX { Err(ErrorMessage(VEC_MAX_LENGTH_EXCEEDED))? }
-                            v as usize
-                        }
-                    };
-                    let total = 1 + len_field_size + str_len;
-                    if bytes.len() < total {
-                        return Err(ErrorMessage(NOT_ENOUGH_BYTES));
-                    }
-                    Ok(total)
-                }
-                Type::Array => {
-                    let count = match bl {
-                        ByteLength::Zero => Err(ErrorMessage(VEC_OF_LENGTH_ZERO))?,
-                        ByteLength::One => *bytes.get(1).ok_or(ErrorMessage(NOT_ENOUGH_BYTES))? as usize,
-                        ByteLength::Two => {
-                            let s = bytes.get(1..=2).ok_or(ErrorMessage(NOT_ENOUGH_BYTES))?;
-                            u16::from_be_bytes(s.try_into().map_err(|_| ErrorMessage(NOT_ENOUGH_BYTES))?) as usize
-                        }
-                        ByteLength::Four => {
-                            let s = bytes.get(1..=4).ok_or(ErrorMessage(NOT_ENOUGH_BYTES))?;
-                            u32::from_be_bytes(s.try_into().map_err(|_| ErrorMessage(NOT_ENOUGH_BYTES))?) as usize
-                        }
-                        ByteLength::Eight => {
-                            let s = bytes.get(1..=8).ok_or(ErrorMessage(NOT_ENOUGH_BYTES))?;
-                            let v = u64::from_be_bytes(s.try_into().map_err(|_| ErrorMessage(NOT_ENOUGH_BYTES))?);
-                            if v as usize > usize::MAX { Err(ErrorMessage(VEC_MAX_LENGTH_EXCEEDED))? }
-                            v as usize
-                        }
-                    };
+    /// Decode a single top-level `Value` from the start of `bytes` without taking
+    /// ownership of, or copying, the input.
+    ///
+    /// Behaves like [`Value::decode`]: trailing bytes after the first value are
+    /// ignored. Nested array elements are decoded straight from `bytes` by a
+    /// [`Reader`], so large arrays are not split into one temporary `Vec` per element.
+    ///
+    /// # Errors
+    /// Returns the same errors as [`Value::decode`].
+    pub fn decode_in_place(bytes: &[u8]) -> Result<Self, ErrorMessage> {
+        Reader::new(bytes).read_value()
+    }
 
-                    let mut pos = 1 + len_field_size;
-                    for _ in 0..count {
-                        if pos >= bytes.len() {
-                            return Err(ErrorMessage(NOT_ENOUGH_BYTES));
-                        }
-                        // [FIX #2] Pass depth + 1 to enforce nesting limit recursively.
-                        let consumed = consumed_for_value(&bytes[pos..], depth + 1)?;
-                        pos = pos.checked_add(consumed).ok_or_else(|| ErrorMessage(VEC_MAX_LENGTH_EXCEEDED))?;
-                    }
-                    if bytes.len() < pos {
-                        return Err(ErrorMessage(NOT_ENOUGH_BYTES));
-                    }
-                    Ok(pos)
-                }
-            }
+    /// Decode a single top-level `Value` like [`Value::decode_in_place`], unless
+    /// it would allocate more than `max_alloc` bytes.
+    ///
+    /// The size of the value is read from its length descriptors before any
+    /// byte is copied, so untrusted input cannot make the decoder allocate
+    /// past the limit.
+    ///
+    /// # Errors
+    /// Returns `ALLOCATION_LIMIT_EXCEEDED` if the encoded value is larger than
+    /// `max_alloc`, or the same errors as [`Value::decode`].
+    pub fn decode_limited(bytes: &[u8], max_alloc: usize) -> Result<Self, ErrorMessage> {
+        if Self::encoded_len(bytes)? > max_alloc {
+            return Err(ErrorMessage(ALLOCATION_LIMIT_EXCEEDED));
         }
+        Self::decode_in_place(bytes)
+    }
 
-        let first = *vec.get(0).ok_or(ErrorMessage(NOT_ENOUGH_BYTES))?;
-        let r#type = Type::try_from(first)?;
-        let bl = ByteLength::try_from(first)?;
-        let len_field_size = usize::from(bl);
-
-        match r#type {
-            Type::Uint | Type::Int | Type::Float => {
-                let total = 1 + len_field_size;
-                if vec.len() < total { Err(ErrorMessage(NOT_ENOUGH_BYTES))? }
-                let chunk = vec[..total].to_vec();
-                Self::from_number(chunk)
-            }
-
-            Type::String => {
-                let str_len = match bl {
-                    ByteLength::Zero => Err(ErrorMessage(STRING_OF_LENGTH_ZERO))?,
-                    ByteLength::One => *vec.get(1).ok_or(ErrorMessage(NOT_ENOUGH_BYTES))? as usize,
-                    ByteLength::Two => {
-                        let s = vec.get(1..=2).ok_or(ErrorMessage(NOT_ENOUGH_BYTES))?;
-                        u16::from_be_bytes(s.try_into().map_err(|_| ErrorMessage(NOT_ENOUGH_BYTES))?) as usize
-                    }
-                    ByteLength::Four => {
-                        let s = vec.get(1..=4).ok_or(ErrorMessage(NOT_ENOUGH_BYTES))?;
-                        u32::from_be_bytes(s.try_into().map_err(|_| ErrorMessage(NOT_ENOUGH_BYTES))?) as usize
-                    }
-                    ByteLength::Eight => {
-                        let s = vec.get(1..=8).ok_or(ErrorMessage(NOT_ENOUGH_BYTES))?;
-                        let v = u64::from_be_bytes(s.try_into().map_err(|_| ErrorMessage(NOT_ENOUGH_BYTES))?);
-                        if v as usize > usize::MAX { Err(ErrorMessage(VEC_MAX_LENGTH_EXCEEDED))? }
-                        v as usize
-                    }
-                };
-
-                let total = 1 + len_field_size + str_len;
-                if vec.len() < total { Err(ErrorMessage(NOT_ENOUGH_BYTES))? }
-
-                let payload = &vec[(1 + len_field_size)..total];
-                let s = String::from_bytes(payload)?;
-                Self::try_from(s).map_err(|_e| ErrorMessage(UNKNOWN))
-            }
-
-            Type::Array => {
-                let count = match bl {
-                    ByteLength::Zero => Err(ErrorMessage(VEC_OF_LENGTH_ZERO))?,
-                    ByteLength::One => *vec.get(1).ok_or(ErrorMessage(NOT_ENOUGH_BYTES))? as usize,
-                    ByteLength::Two => {
-                        let s = vec.get(1..=2).ok_or(ErrorMessage(NOT_ENOUGH_BYTES))?;
-                        u16::from_be_bytes(s.try_into().map_err(|_| ErrorMessage(NOT_ENOUGH_BYTES))?) as usize
-                    }
-                    ByteLength::Four => {
-                        let s = vec.get(1..=4).ok_or(ErrorMessage(NOT_ENOUGH_BYTES))?;
-                        u32::from_be_bytes(s.try_into().map_err(|_| ErrorMessage(NOT_ENOUGH_BYTES))?) as usize
-                    }
-                    ByteLength::Eight => {
-                        let s = vec.get(1..=8).ok_or(ErrorMessage(NOT_ENOUGH_BYTES))?;
-                        let v = u64::from_be_bytes(s.try_into().map_err(|_| ErrorMessage(NOT_ENOUGH_BYTES))?);
-                        if v as usize > usize::MAX { Err(ErrorMessage(VEC_MAX_LENGTH_EXCEEDED))? }
-                        v as usize
-                    }
-                };
-
-                // [FIX #1] Cap pre-allocation to MAX_PREALLOC_ELEMENTS to prevent OOM
-                // when a malicious input declares a huge count but has few actual elements.
-                let mut elements: Vec<Self> = Vec::with_capacity(count.min(MAX_PREALLOC_ELEMENTS));
-                let mut pos = 1 + len_field_size;
-                for _ in 0..count {
-                    if pos >= vec.len() { Err(ErrorMessage(NOT_ENOUGH_BYTES))? }
-                    // [FIX #2] Start at depth 1 since we are already inside one array.
-                    let consumed = consumed_for_value(&vec[pos..], 1)?;
-                    let chunk = vec[pos..pos + consumed].to_vec();
-                    let element = Self::decode(chunk)?;
-                    elements.push(element);
-                    pos += consumed;
-                }
-
-                Self::try_from(elements).map_err(|_e| ErrorMessage(UNKNOWN))
-            }
-
-            Type::Bool | Type::False | Type::True => {
-                Self::try_from(r#type != Type::False).map_err(|_e| ErrorMessage(UNKNOWN))
-            }
-        }
+    /// Returns the size of the encoded value at the start of `bytes`, without decoding it.
+    ///
+    /// Validates headers and length descriptors (including those of nested array
+    /// elements) but not the payload, so it can be used to seek past values.
+    ///
+    /// # Errors
+    /// Returns the same length errors as [`Value::decode`].
+    pub fn encoded_len(bytes: &[u8]) -> Result<usize, ErrorMessage> {
+        consumed_for_value(bytes, 0)
     }
 
     /// Build a `Value` representing a numeric encoded chunk.
@@ -326,13 +356,13 @@ impl Value {
     /// Returns `NOT_ENOUGH_BYTES` if the slice is too short, or `NOT_A_NUMBER` if
     /// the header type nibble does not correspond to a numeric type.
     pub fn from_number(vec: Vec<u8>) -> Result<Self, ErrorMessage> {
-        if vec.len() < 1 {
-            Err(ErrorMessage(NOT_ENOUGH_BYTES))?
-        }
+        Self::number_from_slice(&vec)
+    }
 
-        // [FIX #5] Replaced vec.remove(0) (O(n), shifts all elements) with direct
-        // index access. The original Vec is no longer mutated unnecessarily.
-        let chunk_a = vec[0];
+    /// Slice-based body of [`Value::from_number`]; copies only the header and
+    /// the declared payload width out of `bytes`.
+    pub(crate) fn number_from_slice(bytes: &[u8]) -> Result<Self, ErrorMessage> {
+        let chunk_a = *bytes.first().ok_or(ErrorMessage(NOT_ENOUGH_BYTES))?;
 
         if chunk_a & 0xF0 > FLOATING_POINT_TYPE {
             Err(ErrorMessage(NOT_A_NUMBER))?;
@@ -340,22 +370,14 @@ impl Value {
 
         let format = Type::try_from(chunk_a)?;
         let byte_length = ByteLength::try_from(chunk_a)?;
+        let total = 1 + byte_length.as_byte_count() as usize;
 
-        // [FIX #5] Use a slice starting at index 1 instead of draining the original Vec.
-        let payload = &vec[1..];
-
-        if payload.len() < u8::from(byte_length) as usize {
-            Err(ErrorMessage(NOT_ENOUGH_BYTES))?
-        }
-
-        let mut bytes = Vec::with_capacity(1 + byte_length as usize);
-        bytes.push(chunk_a);
-        bytes.extend_from_slice(&payload[..byte_length as usize]);
+        let bytes = bytes.get(..total).ok_or(ErrorMessage(NOT_ENOUGH_BYTES))?;
 
         Ok(Self {
             r#type: format,
             length: byte_length,
-            bytes,
+            bytes: ValueBytes::from_slice(bytes),
         })
     }
 
@@ -372,6 +394,149 @@ impl Value {
 
         &self.bytes[start..]
     }
+
+    /// Encodes `value` as a string. The length descriptor holds the UTF-8
+    /// byte length (`str::len`), never the number of characters.
+    ///
+    /// # Errors
+    /// Returns `STRING_OF_LENGTH_ZERO` for `""`.
+    pub fn from_str_bytes(value: &str) -> Result<Self, ErrorMessage> {
+        let r#type = Type::String;
+        let byte_length = match_len_min_bytes(value.len(), STRING_OF_LENGTH_ZERO, STRING_MAX_LENGTH_EXCEEDED)?;
+
+        let mut bytes: ValueBytes = smallvec![u8::from(r#type) | u8::from(byte_length)];
+        extend_bytes_with_len_bytes(value.len(), &mut bytes, STRING_OF_LENGTH_ZERO, STRING_MAX_LENGTH_EXCEEDED)?;
+        bytes.extend_from_slice(value.as_bytes());
+
+        Ok(Self { r#type, length: byte_length, bytes })
+    }
+
+    /// Encodes a sequence of characters as a string. Each character takes
+    /// its UTF-8 width (one to four bytes), and the length descriptor holds
+    /// the resulting byte length, like [`Value::from_str_bytes`].
+    ///
+    /// # Errors
+    /// Returns `STRING_OF_LENGTH_ZERO` for an empty sequence.
+    pub fn from_str_chars<I: IntoIterator<Item = char>>(chars: I) -> Result<Self, ErrorMessage> {
+        Self::from_str_bytes(&chars.into_iter().collect::<String>())
+    }
+
+    /// Returns the number of characters (Unicode scalar values) of a string
+    /// value, which differs from its encoded byte length for non-ASCII text.
+    ///
+    /// # Errors
+    /// Returns `NOT_A_STRING` for other types, or `MALFORMED_UTF8` if the payload is not UTF-8.
+    pub fn char_len(&self) -> Result<usize, ErrorMessage> {
+        if self.r#type != Type::String {
+            return Err(ErrorMessage(NOT_A_STRING));
+        }
+
+        std::str::from_utf8(self.isolate_value_bytes())
+            .map(|string| string.chars().count())
+            .map_err(|_| ErrorMessage(MALFORMED_UTF8))
+    }
+
+    /// Encodes `value` in the narrowest float width that round-trips within `tolerance`.
+    ///
+    /// Tries F8E4M3, then f16, then f32, and falls back to f64. A width is
+    /// chosen when widening the narrowed value back to `f64` lands within
+    /// `tolerance` (absolute) of `value`. A tolerance of `0.0` (or a negative or
+    /// NaN one) only accepts exact round-trips; infinities and NaN keep a width
+    /// that can represent them.
+    ///
+    /// # Example
+    /// ```text
+    /// Value::from_float_auto(0.5, 0.0)     // F8E4M3, 2 bytes
+    /// Value::from_float_auto(21.37, 0.01)  // f16, 3 bytes
+    /// Value::from_float_auto(0.1, 0.0)     // f64, 9 bytes
+    /// ```
+    pub fn from_float_auto(value: f64, tolerance: f64) -> Self {
+        let tolerance = if tolerance > 0.0 { tolerance } else { 0.0 };
+        let fits = |back: f64| {
+            back == value
+                || (back.is_nan() && value.is_nan())
+                || (value.is_finite() && (back - value).abs() <= tolerance)
+        };
+
+        let f8 = F8E4M3::from_f64(value);
+        if fits(f8.to_f64()) {
+            return Self::from(f8);
+        }
+
+        let half = f16::from_f64(value);
+        if fits(half.to_f64()) {
+            return Self::from(half);
+        }
+
+        let single = value as f32;
+        if fits(single as f64) {
+            return Self::from(single);
+        }
+
+        Self::from(value)
+    }
+
+    /// Widens a float of any width (F8E4M3, f16, f32 or f64) to `f64`.
+    ///
+    /// Lossless for every width; "lossy" refers to the value having possibly
+    /// been narrowed when it was stored (see [`Value::from_float_auto`]).
+    ///
+    /// # Errors
+    /// Returns `NOT_A_FLOAT` if the value is not a float.
+    pub fn as_f64_lossy(&self) -> Result<f64, ErrorMessage> {
+        let value = self.clone();
+        match (self.r#type, self.length) {
+            (Type::Float, ByteLength::One) => Ok(TryInto::<F8E4M3>::try_into(value)?.to_f64()),
+            (Type::Float, ByteLength::Two) => Ok(TryInto::<f16>::try_into(value)?.to_f64()),
+            (Type::Float, ByteLength::Four) => Ok(TryInto::<f32>::try_into(value)? as f64),
+            (Type::Float, ByteLength::Eight) => TryInto::<f64>::try_into(value),
+            _ => Err(ErrorMessage(NOT_A_FLOAT)),
+        }
+    }
+
+    /// Reads an unsigned integer out of loosely-typed data: unsigned and
+    /// non-negative signed integers of any width, and strings holding a
+    /// decimal number (surrounding whitespace and a leading `+` are accepted).
+    ///
+    /// Floats are not coerced, even integral ones, so no fraction is dropped silently.
+    ///
+    /// # Errors
+    /// Returns `NOT_COERCIBLE_TO_U64` for negative integers, other types, and
+    /// strings that do not hold a number fitting in a `u64`.
+    pub fn coerce_u64(&self) -> Result<u64, ErrorMessage> {
+        let not_coercible = || ErrorMessage(NOT_COERCIBLE_TO_U64);
+
+        match self.r#type {
+            Type::Uint | Type::Int => match self.as_number() {
+                Some(compare::Number::Unsigned(v)) => Ok(v),
+                Some(compare::Number::Signed(v)) => u64::try_from(v).map_err(|_| not_coercible()),
+                _ => Err(not_coercible()),
+            },
+            Type::String => {
+                let text = std::str::from_utf8(self.isolate_value_bytes()).map_err(|_| not_coercible())?;
+                text.trim().parse().map_err(|_| not_coercible())
+            }
+            _ => Err(not_coercible()),
+        }
+    }
+
+    /// Renders any value as text for loosely-typed consumers: strings as
+    /// their text, with invalid UTF-8 replaced by `U+FFFD`, and every other
+    /// value as its `Display` output (see [`Value::try_display`]).
+    ///
+    /// # Example
+    /// ```
+    /// use yad_core::Value;
+    ///
+    /// assert_eq!(Value::from(7u16).as_string_lossy(), "7");
+    /// assert_eq!(Value::try_from("seven").unwrap().as_string_lossy(), "seven");
+    /// ```
+    pub fn as_string_lossy(&self) -> String {
+        match self.r#type {
+            Type::String => String::from_utf8_lossy(self.isolate_value_bytes()).into_owned(),
+            _ => self.to_string(),
+        }
+    }
 }
 
 /// Trait used to decode primitive types from a byte slice according to YAD semantics.
@@ -408,7 +573,7 @@ macro_rules! impl_from_num {
 
                 let num_as_be = value.to_be_bytes();
 
-                let mut bytes = vec![u8::from(r#type) | u8::from(length)];
+                let mut bytes: ValueBytes = smallvec![u8::from(r#type) | u8::from(length)];
                 bytes.extend_from_slice(&num_as_be);
 
                 Self { r#type, length, bytes }
@@ -443,7 +608,7 @@ impl From<F8E4M3> for Value {
 
         let num_as_be = value.to_bits();
 
-        let mut bytes = vec![u8::from(r#type) | u8::from(length)];
+        let mut bytes: ValueBytes = smallvec![u8::from(r#type) | u8::from(length)];
         bytes.push(num_as_be);
 
         Self { r#type, length, bytes }
@@ -457,7 +622,7 @@ impl From<f16> for Value {
 
         let num_as_be = value.to_be_bytes();
 
-        let mut bytes = vec![u8::from(r#type) | u8::from(length)];
+        let mut bytes: ValueBytes = smallvec![u8::from(r#type) | u8::from(length)];
         bytes.extend_from_slice(&num_as_be);
 
         Self { r#type, length, bytes }
@@ -467,52 +632,64 @@ impl From<f16> for Value {
 impl TryFrom<String> for Value {
     type Error = ErrorMessage;
     fn try_from(value: String) -> Result<Self, Self::Error> {
-        let r#type = Type::String;
-        let byte_length = match_len_min_bytes(value.len(), STRING_OF_LENGTH_ZERO, STRING_MAX_LENGTH_EXCEEDED)?;
-
-        let mut bytes = vec![u8::from(r#type) | u8::from(byte_length)];
-        extend_bytes_with_len_bytes(value.len(), &mut bytes, STRING_OF_LENGTH_ZERO, STRING_MAX_LENGTH_EXCEEDED)?;
-        bytes.extend_from_slice(&value.as_bytes());
-
-        Ok(Self { r#type, length: byte_length, bytes })
+        Self::from_str_bytes(&value)
     }
 }
 
 impl TryFrom<&str> for Value {
     type Error = ErrorMessage;
     fn try_from(value: &str) -> Result<Self, Self::Error> {
-        let r#type = Type::String;
-        let byte_length = match_len_min_bytes(value.len(), STRING_OF_LENGTH_ZERO, STRING_MAX_LENGTH_EXCEEDED)?;
-
-        let mut bytes = vec![u8::from(r#type) | u8::from(byte_length)];
-        extend_bytes_with_len_bytes(value.len(), &mut bytes, STRING_OF_LENGTH_ZERO, STRING_MAX_LENGTH_EXCEEDED)?;
-        bytes.extend_from_slice(&value.as_bytes());
+        Self::from_str_bytes(value)
+    }
+}
 
-        Ok(Self { r#type, length: byte_length, bytes })
+impl From<char> for Value {
+    /// Encodes the character as a string of its UTF-8 width, one to four bytes.
+    fn from(value: char) -> Self {
+        Self::from_str_bytes(value.encode_utf8(&mut [0; 4])).expect("a character is never empty")
     }
 }
 
-impl TryFrom<Vec<Value>> for Value {
-    type Error = ErrorMessage;
-    fn try_from(value: Vec<Value>) -> Result<Self, Self::Error> {
-        let r#type = Type::Array;
-        let byte_length = match_len_min_bytes(value.len(), VEC_OF_LENGTH_ZERO, VEC_MAX_LENGTH_EXCEEDED)?;
+/// Encodes `items` as an array, converting each element into a `Value`.
+fn array_from_items<I>(items: I) -> Result<Value, ErrorMessage>
+where
+    I: ExactSizeIterator,
+    I::Item: Into<Value>,
+{
+    let r#type = Type::Array;
+    let len = items.len();
+    let byte_length = match_len_min_bytes(len, VEC_OF_LENGTH_ZERO, VEC_MAX_LENGTH_EXCEEDED)?;
+
+    let mut bytes: ValueBytes = smallvec![u8::from(r#type) | u8::from(byte_length)];
+    extend_bytes_with_len_bytes(len, &mut bytes, VEC_OF_LENGTH_ZERO, VEC_MAX_LENGTH_EXCEEDED)?;
+
+    for i in items {
+        bytes.extend_from_slice(i.into().bytes.as_slice());
+    }
 
-        let mut bytes = vec![u8::from(r#type) | u8::from(byte_length)];
-        extend_bytes_with_len_bytes(value.len(), &mut bytes, VEC_OF_LENGTH_ZERO, VEC_MAX_LENGTH_EXCEEDED)?;
+    Ok(Value { r#type, length: byte_length, bytes })
+}
 
-        for i in value {
-            bytes.extend_from_slice(i.bytes.as_slice());
-        }
+/// Encodes a `Vec` of anything convertible to `Value` (including `Vec<Value>`) as an array.
+impl<T: Into<Value>> TryFrom<Vec<T>> for Value {
+    type Error = ErrorMessage;
+    fn try_from(value: Vec<T>) -> Result<Self, Self::Error> {
+        array_from_items(value.into_iter())
+    }
+}
 
-        Ok(Self { r#type, length: byte_length, bytes })
+/// Encodes a slice of anything convertible to `Value` as an array, cloning each element.
+impl<T: Clone + Into<Value>> TryFrom<&[T]> for Value {
+    type Error = ErrorMessage;
+    fn try_from(value: &[T]) -> Result<Self, Self::Error> {
+        array_from_items(value.iter().cloned())
     }
 }
 
 impl From<bool> for Value {
     fn from(value: bool) -> Self {
         let r#type = if value { Type::True } else { Type::False };
-        Self { r#type, length: ByteLength::Zero, bytes: vec![u8::from(r#type)] }
+        Self { r#type, length: ByteLength::Zero, bytes: smallvec![u8::from(r#type)] }
     }
 }
 
@@ -628,8 +805,16 @@ impl TryInto<String> for Value {
             Err(ErrorMessage(NOT_A_STRING))?;
         }
 
-        let bytes = self.isolate_value_bytes();
-        Ok(String::from_bytes(bytes).map_err(|_e| ErrorMessage(NOT_A_STRING))?)
+        String::from_bytes(self.isolate_value_bytes())
+    }
+}
+
+impl TryInto<char> for Value {
+    type Error = ErrorMessage;
+
+    /// Reads a string value holding exactly one character (see [`Value::as_char`]).
+    fn try_into(self) -> Result<char, Self::Error> {
+        self.as_char()
     }
 }
 
@@ -718,10 +903,11 @@ impl TryInto<Vec<Value>> for Value {
                         }
                         // [FIX #2] Pass depth + 1 on each recursive call.
                         let used = consumed_for_value(&bytes[pos..], depth + 1)?;
-                        pos = pos.checked_add(used).ok_or_else(|| ErrorMessage(VEC_MAX_LENGTH_EXCEEDED))?;
+                        pos = pos.checked_add(used).ok_or(ErrorMessage(VEC_MAX_LENGTH_EXCEEDED))?;
                     }
                     Ok(pos)
                 }
+                Type::Extension => crate::consumed_for_value(bytes, depth),
             }
         }
 
@@ -741,7 +927,7 @@ impl TryInto<Vec<Value>> for Value {
                         return Err(ErrorMessage(NOT_ENOUGH_BYTES));
                     }
                     let (chunk, rest) = bytes.split_at(size);
-                    result.push(Value::from_number(chunk.to_vec())?);
+                    result.push(Value::number_from_slice(chunk)?);
                     bytes = rest;
                 }
                 Type::String => {
@@ -778,10 +964,16 @@ impl TryInto<Vec<Value>> for Value {
                     result.push(Value {
                         r#type: Type::Array,
                         length: len_type,
-                        bytes: chunk.to_vec(),
+                        bytes: ValueBytes::from_slice(chunk),
                     });
                     bytes = rest;
                 }
+                Type::Extension => {
+                    let used = consumed_for_value(bytes, 1)?;
+                    let (chunk, rest) = bytes.split_at(used);
+                    result.push(Value::decode_in_place(chunk)?);
+                    bytes = rest;
+                }
             }
         }
 
@@ -809,6 +1001,107 @@ impl TryInto<bool> for Value {
     }
 }
 
+/// Macro implementing `TryInto<Vec<$t>>` for `Value`: decodes the array,
+/// then converts every element with `TryInto<$t>`, failing on the first
+/// element of another type or width.
+macro_rules! impl_try_into_vec {
+    ($($t:ty),* $(,)?) => {
+        $(
+            impl TryInto<Vec<$t>> for Value {
+                type Error = ErrorMessage;
+
+                fn try_into(self) -> Result<Vec<$t>, Self::Error> {
+                    let items: Vec<Value> = self.try_into()?;
+                    items.into_iter().map(TryInto::<$t>::try_into).collect()
+                }
+            }
+        )*
+    };
+}
+
+impl_try_into_vec!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize, f32, f64, f16, F8E4M3, bool, String);
+
+
+impl Value {
+    /// Renders the value like its `Display` implementation, failing on the
+    /// first payload that does not decode instead of writing a placeholder.
+    ///
+    /// # Errors
+    /// Returns the error of the first value, or nested array item, whose
+    /// payload cannot be decoded as its type.
+    pub fn try_display(&self) -> Result<String, ErrorMessage> {
+        self.render(&mut |_, error| Err(error))
+    }
+
+    /// Widens an unsigned integer of any width to `u64`.
+    #[cfg(any(feature = "cbor", feature = "protobuf"))]
+    pub(crate) fn as_u64_lossless(&self) -> Result<u64, ErrorMessage> {
+        let value = self.clone();
+        Ok(match self.length {
+            ByteLength::One => TryInto::<u8>::try_into(value)? as u64,
+            ByteLength::Two => TryInto::<u16>::try_into(value)? as u64,
+            ByteLength::Four => TryInto::<u32>::try_into(value)? as u64,
+            _ => TryInto::<u64>::try_into(value)?,
+        })
+    }
+
+    /// Widens a signed integer of any width to `i64`.
+    #[cfg(any(feature = "cbor", feature = "protobuf"))]
+    pub(crate) fn as_i64_lossless(&self) -> Result<i64, ErrorMessage> {
+        let value = self.clone();
+        Ok(match self.length {
+            ByteLength::One => TryInto::<i8>::try_into(value)? as i64,
+            ByteLength::Two => TryInto::<i16>::try_into(value)? as i64,
+            ByteLength::Four => TryInto::<i32>::try_into(value)? as i64,
+            _ => TryInto::<i64>::try_into(value)?,
+        })
+    }
+
+    /// Renders the value, replacing each payload that does not decode with
+    /// whatever `invalid` returns for it.
+    fn render(&self, invalid: &mut impl FnMut(&Value, ErrorMessage) -> Result<String, ErrorMessage>) -> Result<String, ErrorMessage> {
+        fn text<T: fmt::Display>(value: &Value) -> Result<String, ErrorMessage>
+        where
+            Value: TryInto<T, Error = ErrorMessage>,
+        {
+            value.clone().try_into().map(|v: T| v.to_string())
+        }
+
+        let rendered = match (self.r#type, self.length) {
+            (Type::Uint, ByteLength::One) => text::<u8>(self),
+            (Type::Uint, ByteLength::Two) => text::<u16>(self),
+            (Type::Uint, ByteLength::Four) => text::<u32>(self),
+            (Type::Uint, ByteLength::Eight) => text::<u64>(self),
+            (Type::Int, ByteLength::One) => text::<i8>(self),
+            (Type::Int, ByteLength::Two) => text::<i16>(self),
+            (Type::Int, ByteLength::Four) => text::<i32>(self),
+            (Type::Int, ByteLength::Eight) => text::<i64>(self),
+            (Type::Float, ByteLength::One) => text::<F8E4M3>(self),
+            (Type::Float, ByteLength::Two) => text::<f16>(self),
+            (Type::Float, ByteLength::Four) => text::<f32>(self),
+            (Type::Float, ByteLength::Eight) => text::<f64>(self),
+            (Type::Uint | Type::Int | Type::Float, ByteLength::Zero) => Ok(format!("{:?}", self.bytes)),
+            (Type::String, _) => text::<String>(self),
+            (Type::Array, _) => match TryInto::<Vec<Value>>::try_into(self.clone()) {
+                Ok(items) => {
+                    let items = items.iter().map(|item| item.render(invalid)).collect::<Result<Vec<_>, _>>()?;
+                    return Ok(format!("[{}]", items.join(", ")));
+                }
+                Err(error) => Err(error),
+            },
+            (Type::Bool | Type::True | Type::False, _) => text::<bool>(self),
+            (Type::Extension, _) if self.is_decimal() => self.as_decimal().map(|decimal| decimal.to_string()),
+            (Type::Extension, _) if self.is_big_integer() => self.as_big_integer().map(|big| big.to_string()),
+            (Type::Extension, _) => self.extension_payload().map(|(tag, data)| {
+                let hex: String = data.iter().map(|b| format!("{:02X}", b)).collect();
+                format!("ext.{:02X}.{}", tag, hex)
+            }),
+        };
+
+        rendered.or_else(|error| invalid(self, error))
+    }
+}
+
 impl fmt::Display for Value {
     /// Produce a human-readable representation of a `Value`.
     ///
@@ -816,85 +1109,22 @@ impl fmt::Display for Value {
     /// - Strings are printed as plain UTF-8 text.
     /// - Arrays are displayed as `[a, b, c]` using recursive formatting.
     /// - Booleans are printed as `true` or `false`.
+    /// - Decimals are printed with every digit of their scale, such as `19.90`.
+    /// - Big integers are printed in base 10.
+    /// - Other extension values are printed as `ext.<tag>.<data>`, both in
+    ///   hex, such as `ext.81.0A0B`.
+    /// - Payloads that do not decode are printed as a placeholder such as
+    ///   `<invalid uint32: reason>` instead of failing; use
+    ///   [`Value::try_display`] to get the error.
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        match self.r#type {
-            Type::Uint => match self.length {
-                ByteLength::One => {
-                    let v: u8 = self.clone().try_into().map_err(|_| fmt::Error)?;
-                    write!(f, "{}", v)
-                }
-                ByteLength::Two => {
-                    let v: u16 = self.clone().try_into().map_err(|_| fmt::Error)?;
-                    write!(f, "{}", v)
-                }
-                ByteLength::Four => {
-                    let v: u32 = self.clone().try_into().map_err(|_| fmt::Error)?;
-                    write!(f, "{}", v)
-                }
-                ByteLength::Eight => {
-                    let v: u64 = self.clone().try_into().map_err(|_| fmt::Error)?;
-                    write!(f, "{}", v)
-                }
-                _ => write!(f, "{:?}", self.bytes),
-            },
-            Type::Int => match self.length {
-                ByteLength::One => {
-                    let v: i8 = self.clone().try_into().map_err(|_| fmt::Error)?;
-                    write!(f, "{}", v)
-                }
-                ByteLength::Two => {
-                    let v: i16 = self.clone().try_into().map_err(|_| fmt::Error)?;
-                    write!(f, "{}", v)
-                }
-                ByteLength::Four => {
-                    let v: i32 = self.clone().try_into().map_err(|_| fmt::Error)?;
-                    write!(f, "{}", v)
-                }
-                ByteLength::Eight => {
-                    let v: i64 = self.clone().try_into().map_err(|_| fmt::Error)?;
-                    write!(f, "{}", v)
-                }
-                _ => write!(f, "{:?}", self.bytes),
-            },
-            Type::Float => match self.length {
-                ByteLength::One => {
-                    let v: F8E4M3 = self.clone().try_into().map_err(|_| fmt::Error)?;
-                    write!(f, "{}", v)
-                }
-                ByteLength::Two => {
-                    let v: f16 = self.clone().try_into().map_err(|_| fmt::Error)?;
-                    write!(f, "{}", v)
-                }
-                ByteLength::Four => {
-                    let v: f32 = self.clone().try_into().map_err(|_| fmt::Error)?;
-                    write!(f, "{}", v)
-                }
-                ByteLength::Eight => {
-                    let v: f64 = self.clone().try_into().map_err(|_| fmt::Error)?;
-                    write!(f, "{}", v)
-                }
-                _ => write!(f, "{:?}", self.bytes),
-            },
-            Type::String => {
-                let s: String = self.clone().try_into().map_err(|_| fmt::Error)?;
-                write!(f, "{}", s)
-            }
-            Type::Array => {
-                let arr: Vec<Value> = self.clone().try_into().map_err(|_| fmt::Error)?;
-                let mut string = String::from("[");
-                for (i, item) in arr.iter().enumerate() {
-                    string.push_str(&format!("{}", item));
-                    if i < arr.len() - 1 {
-                        string.push_str(", ");
-                    }
-                }
-                string.push(']');
-                write!(f, "{}", string)
-            }
-            Type::Bool | Type::True | Type::False => {
-                let b: bool = self.clone().try_into().map_err(|_| fmt::Error)?;
-                write!(f, "{}", b)
-            }
+        let rendered = self.render(&mut |value, error| Ok(match value.r#type {
+            Type::Uint | Type::Int | Type::Float => format!("<invalid {}{}: {}>", value.r#type, value.length, error.0),
+            r#type => format!("<invalid {}: {}>", r#type, error.0),
+        }));
+
+        match rendered {
+            Ok(text) => f.write_str(&text),
+            Err(_) => Err(fmt::Error),
         }
     }
-}
\ No newline at end of file
+}
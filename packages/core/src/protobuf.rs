@@ -0,0 +1,107 @@
+use prost_types::value::Kind;
+use prost_types::ListValue;
+use crate::constants::error::{
+    ErrorMessage,
+    PROTOBUF_KIND_MISSING,
+    PROTOBUF_NULL_NOT_SUPPORTED,
+    PROTOBUF_NUMBER_NOT_REPRESENTABLE,
+    PROTOBUF_STRUCT_NOT_SUPPORTED,
+};
+use crate::constants::types::Type;
+use crate::Value;
+
+/// Largest integer magnitude a protobuf number (an `f64`) holds exactly, 2^53.
+pub const MAX_EXACT_PROTOBUF_INTEGER: u64 = 1 << 53;
+
+/// Returns the smallest unsigned, or for negative numbers signed, `Value`
+/// holding `n`, which is at most [`MAX_EXACT_PROTOBUF_INTEGER`] in magnitude.
+fn integer_value(n: i64) -> Value {
+    if let Ok(n) = u64::try_from(n) {
+        return match n {
+            n if n <= u8::MAX as u64 => Value::from(n as u8),
+            n if n <= u16::MAX as u64 => Value::from(n as u16),
+            n if n <= u32::MAX as u64 => Value::from(n as u32),
+            n => Value::from(n),
+        };
+    }
+
+    match n {
+        n if n >= i8::MIN as i64 => Value::from(n as i8),
+        n if n >= i16::MIN as i64 => Value::from(n as i16),
+        n if n >= i32::MIN as i64 => Value::from(n as i32),
+        n => Value::from(n),
+    }
+}
+
+impl Value {
+    /// Converts the value into a `prost_types::Value`, for embedding it in
+    /// protobuf messages as structured data rather than a bytes blob.
+    ///
+    /// - Integers and floats become numbers; their YAD width is not kept.
+    /// - Booleans, strings and arrays map to their protobuf counterparts.
+    /// - Decimals, big integers and other extension values become strings of
+    ///   their text, such as `"19.90"`, and come back as strings.
+    ///
+    /// # Errors
+    /// - `PROTOBUF_NUMBER_NOT_REPRESENTABLE` for integers past
+    ///   [`MAX_EXACT_PROTOBUF_INTEGER`] in magnitude, which an `f64` would round.
+    /// - Otherwise, an error if the value is malformed.
+    pub fn to_protobuf(&self) -> Result<prost_types::Value, ErrorMessage> {
+        let not_representable = || ErrorMessage(PROTOBUF_NUMBER_NOT_REPRESENTABLE);
+
+        let kind = match self.r#type {
+            Type::Uint => match self.as_u64_lossless()? {
+                n if n <= MAX_EXACT_PROTOBUF_INTEGER => Kind::NumberValue(n as f64),
+                _ => return Err(not_representable()),
+            },
+            Type::Int => match self.as_i64_lossless()? {
+                n if n.unsigned_abs() <= MAX_EXACT_PROTOBUF_INTEGER => Kind::NumberValue(n as f64),
+                _ => return Err(not_representable()),
+            },
+            Type::Float => Kind::NumberValue(self.as_f64_lossy()?),
+            Type::String => Kind::StringValue(self.clone().try_into()?),
+            Type::Array => {
+                let items: Vec<Value> = self.clone().try_into()?;
+                let values = items.iter().map(Value::to_protobuf).collect::<Result<_, _>>()?;
+                Kind::ListValue(ListValue { values })
+            }
+            Type::Bool | Type::True | Type::False => Kind::BoolValue(self.r#type != Type::False),
+            Type::Extension => Kind::StringValue(self.try_display()?),
+        };
+
+        Ok(prost_types::Value { kind: Some(kind) })
+    }
+
+    /// Converts a `prost_types::Value` into a `Value`.
+    ///
+    /// - Numbers without a fractional part, up to [`MAX_EXACT_PROTOBUF_INTEGER`]
+    ///   in magnitude, take the smallest integer width holding them, unsigned
+    ///   when non-negative; other numbers become `f64`.
+    /// - Booleans, strings and lists map to their YAD counterparts.
+    ///
+    /// # Errors
+    /// - `PROTOBUF_NULL_NOT_SUPPORTED` for null, which YAD has no type for.
+    /// - `PROTOBUF_STRUCT_NOT_SUPPORTED` for structs; those map to rows, not values.
+    /// - `PROTOBUF_KIND_MISSING` for values without a kind.
+    /// - `STRING_OF_LENGTH_ZERO` / `VEC_OF_LENGTH_ZERO` for empty strings and lists.
+    pub fn from_protobuf(protobuf: &prost_types::Value) -> Result<Self, ErrorMessage> {
+        match &protobuf.kind {
+            Some(Kind::NumberValue(n)) if n.fract() == 0.0 && n.abs() <= MAX_EXACT_PROTOBUF_INTEGER as f64 => {
+                Ok(integer_value(*n as i64))
+            }
+            Some(Kind::NumberValue(n)) => Ok(Value::from(*n)),
+            Some(Kind::BoolValue(b)) => Ok(Value::from(*b)),
+            Some(Kind::StringValue(s)) => Value::try_from(s.as_str()),
+            Some(Kind::ListValue(list)) => {
+                let mut values = Vec::with_capacity(list.values.len());
+                for item in &list.values {
+                    values.push(Self::from_protobuf(item)?);
+                }
+                Value::try_from(values)
+            }
+            Some(Kind::NullValue(_)) => Err(ErrorMessage(PROTOBUF_NULL_NOT_SUPPORTED)),
+            Some(Kind::StructValue(_)) => Err(ErrorMessage(PROTOBUF_STRUCT_NOT_SUPPORTED)),
+            None => Err(ErrorMessage(PROTOBUF_KIND_MISSING)),
+        }
+    }
+}
@@ -0,0 +1,181 @@
+use crate::constants::error::{
+    EXTENSION_OF_LENGTH_ZERO,
+    ErrorMessage,
+    NESTING_TOO_DEEP,
+    NOT_ENOUGH_BYTES,
+    STRING_OF_LENGTH_ZERO,
+    UNKNOWN,
+    VEC_MAX_LENGTH_EXCEEDED,
+    VEC_OF_LENGTH_ZERO,
+};
+use crate::constants::length::ByteLength;
+use crate::constants::types::Type;
+use crate::{FromYADNotation, Value, MAX_NESTING_DEPTH, MAX_PREALLOC_ELEMENTS};
+
+/// A cursor over borrowed encoded bytes.
+///
+/// Reading moves an offset forward instead of removing the consumed bytes, so
+/// walking a buffer is linear no matter how many items it holds. [`Value`]
+/// decoding and the row and key readers of `serde_yad` share it.
+#[derive(Clone, Copy, Debug)]
+pub struct Reader<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+    max_depth: Option<usize>,
+}
+
+impl<'a> Reader<'a> {
+    /// Creates a reader positioned at the start of `bytes`.
+    pub fn new(bytes: &'a [u8]) -> Self {
+        Self::at(bytes, 0)
+    }
+
+    /// Creates a reader positioned at `pos`, clamped to the end of `bytes`.
+    pub fn at(bytes: &'a [u8], pos: usize) -> Self {
+        Self { bytes, pos: pos.min(bytes.len()), max_depth: None }
+    }
+
+    /// Rejects decoded values whose arrays nest deeper than `max_depth` with
+    /// `NESTING_TOO_DEEP`, as soon as the offending array header is read:
+    /// `0` rejects arrays and `1` allows arrays of scalars only. The decoder's
+    /// own bound, `MAX_NESTING_DEPTH`, still applies.
+    pub fn with_max_depth(self, max_depth: usize) -> Self {
+        Self { max_depth: Some(max_depth), ..self }
+    }
+
+    /// Returns the current offset into the underlying bytes.
+    pub fn position(&self) -> usize {
+        self.pos
+    }
+
+    /// Returns `true` once every byte has been consumed.
+    pub fn is_empty(&self) -> bool {
+        self.pos >= self.bytes.len()
+    }
+
+    /// Returns the bytes not consumed yet.
+    pub fn remaining(&self) -> &'a [u8] {
+        &self.bytes[self.pos..]
+    }
+
+    /// Returns the next byte without consuming it.
+    pub fn peek(&self) -> Option<u8> {
+        self.bytes.get(self.pos).copied()
+    }
+
+    /// Skips `n` bytes.
+    ///
+    /// # Errors
+    /// Returns `NOT_ENOUGH_BYTES` if fewer than `n` bytes remain; the position is then unchanged.
+    pub fn advance(&mut self, n: usize) -> Result<(), ErrorMessage> {
+        self.take(n).map(|_| ())
+    }
+
+    /// Consumes and returns the next `n` bytes.
+    ///
+    /// # Errors
+    /// Returns `NOT_ENOUGH_BYTES` if fewer than `n` bytes remain; the position is then unchanged.
+    pub fn take(&mut self, n: usize) -> Result<&'a [u8], ErrorMessage> {
+        let end = self.pos.checked_add(n).ok_or(ErrorMessage(NOT_ENOUGH_BYTES))?;
+        let bytes = self.bytes.get(self.pos..end).ok_or(ErrorMessage(NOT_ENOUGH_BYTES))?;
+        self.pos = end;
+        Ok(bytes)
+    }
+
+    /// Decodes the value at the current position and moves past it.
+    ///
+    /// # Errors
+    /// Returns the same errors as [`Value::decode`]; the position is then unchanged.
+    pub fn read_value(&mut self) -> Result<Value, ErrorMessage> {
+        let start = self.pos;
+        self.value_at_depth(0).inspect_err(|_| self.pos = start)
+    }
+
+    /// Moves past the value at the current position without decoding it,
+    /// returning its encoded bytes (see [`Value::encoded_len`]).
+    ///
+    /// # Errors
+    /// Returns the same errors as [`Value::encoded_len`]; the position is then unchanged.
+    pub fn skip_value(&mut self) -> Result<&'a [u8], ErrorMessage> {
+        let len = Value::encoded_len(self.remaining())?;
+        self.take(len)
+    }
+
+    /// Reads the big-endian length descriptor of width `bl`.
+    ///
+    /// `zero_error` is returned when the header declares no descriptor, which
+    /// is invalid for strings and arrays.
+    fn read_len(&mut self, bl: ByteLength, zero_error: &'static str) -> Result<usize, ErrorMessage> {
+        let size = bl.as_byte_count() as usize;
+        if size == 0 {
+            return Err(ErrorMessage(zero_error));
+        }
+
+        let len = self.take(size)?.iter().fold(0u64, |acc, b| acc << 8 | *b as u64);
+
+        usize::try_from(len).map_err(|_| ErrorMessage(VEC_MAX_LENGTH_EXCEEDED))
+    }
+
+    /// Decodes one value, with `depth` bounding array recursion by `MAX_NESTING_DEPTH`.
+    ///
+    /// Array elements are decoded in place at a moving offset instead of being
+    /// copied out first.
+    fn value_at_depth(&mut self, depth: usize) -> Result<Value, ErrorMessage> {
+        if depth > MAX_NESTING_DEPTH {
+            return Err(ErrorMessage(NESTING_TOO_DEEP));
+        }
+
+        let first = self.peek().ok_or(ErrorMessage(NOT_ENOUGH_BYTES))?;
+        let r#type = Type::try_from(first)?;
+        let bl = ByteLength::try_from(first)?;
+
+        match r#type {
+            Type::Uint | Type::Int | Type::Float => {
+                let value = Value::number_from_slice(self.remaining())?;
+                self.pos += value.bytes.len();
+                Ok(value)
+            }
+
+            Type::String => {
+                self.pos += 1;
+                let len = self.read_len(bl, STRING_OF_LENGTH_ZERO)?;
+                let s = String::from_bytes(self.take(len)?)?;
+                Value::try_from(s).map_err(|_e| ErrorMessage(UNKNOWN))
+            }
+
+            Type::Array => {
+                if self.max_depth.is_some_and(|max| depth >= max) {
+                    return Err(ErrorMessage(NESTING_TOO_DEEP));
+                }
+
+                self.pos += 1;
+                let count = self.read_len(bl, VEC_OF_LENGTH_ZERO)?;
+
+                // [FIX #1] Cap pre-allocation to MAX_PREALLOC_ELEMENTS to prevent OOM
+                // when a malicious input declares a huge count but has few actual elements.
+                // Every element takes at least one byte, so the rest of the input caps it too.
+                let capacity = count.min(self.remaining().len()).min(MAX_PREALLOC_ELEMENTS);
+                let mut elements: Vec<Value> = Vec::with_capacity(capacity);
+                for _ in 0..count {
+                    elements.push(self.value_at_depth(depth + 1)?);
+                }
+
+                Value::try_from(elements).map_err(|_e| ErrorMessage(UNKNOWN))
+            }
+
+            Type::Bool | Type::False | Type::True => {
+                self.pos += 1;
+                Ok(Value::from(r#type != Type::False))
+            }
+
+            Type::Extension => {
+                self.pos += 1;
+                let len = self.read_len(bl, EXTENSION_OF_LENGTH_ZERO)?;
+                match self.take(len)? {
+                    [tag, data @ ..] => Value::from_extension(*tag, data),
+                    [] => Err(ErrorMessage(EXTENSION_OF_LENGTH_ZERO)),
+                }
+            }
+        }
+    }
+}
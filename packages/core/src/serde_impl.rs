@@ -0,0 +1,131 @@
+use serde::de::Error as _;
+use serde::ser::Error as _;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use float16::f16;
+use float8::F8E4M3;
+use crate::constants::error::ErrorMessage;
+use crate::constants::length::ByteLength;
+use crate::constants::types::Type;
+use crate::bigint::BigInteger;
+use crate::decimal::Decimal;
+use crate::Value;
+
+/// How a [`Value`] is written by serde: a single-entry map from its type and
+/// width, as printed by their `Display` implementations, to its content.
+///
+/// `f16` and F8E4M3 floats are written as `f32`, which holds them exactly.
+/// Decimals and big integers are written as text, such as
+/// `{"decimal": "19.90"}`, so no digit is lost.
+#[derive(Serialize, Deserialize)]
+enum Tagged {
+    #[serde(rename = "uint8")]
+    Uint8(u8),
+    #[serde(rename = "uint16")]
+    Uint16(u16),
+    #[serde(rename = "uint32")]
+    Uint32(u32),
+    #[serde(rename = "uint64")]
+    Uint64(u64),
+    #[serde(rename = "int8")]
+    Int8(i8),
+    #[serde(rename = "int16")]
+    Int16(i16),
+    #[serde(rename = "int32")]
+    Int32(i32),
+    #[serde(rename = "int64")]
+    Int64(i64),
+    #[serde(rename = "float8")]
+    Float8(f32),
+    #[serde(rename = "float16")]
+    Float16(f32),
+    #[serde(rename = "float32")]
+    Float32(f32),
+    #[serde(rename = "float64")]
+    Float64(f64),
+    #[serde(rename = "string")]
+    String(String),
+    #[serde(rename = "array")]
+    Array(Vec<Value>),
+    #[serde(rename = "bool")]
+    Bool(bool),
+    #[serde(rename = "decimal")]
+    Decimal(String),
+    #[serde(rename = "bigint")]
+    BigInteger(String),
+    #[serde(rename = "extension")]
+    Extension { tag: u8, data: Vec<u8> },
+}
+
+impl TryFrom<&Value> for Tagged {
+    type Error = ErrorMessage;
+
+    fn try_from(value: &Value) -> Result<Self, ErrorMessage> {
+        let value = value.clone();
+        Ok(match (value.r#type, value.length) {
+            (Type::Uint, ByteLength::One) => Tagged::Uint8(value.try_into()?),
+            (Type::Uint, ByteLength::Two) => Tagged::Uint16(value.try_into()?),
+            (Type::Uint, ByteLength::Four) => Tagged::Uint32(value.try_into()?),
+            (Type::Uint, _) => Tagged::Uint64(value.try_into()?),
+            (Type::Int, ByteLength::One) => Tagged::Int8(value.try_into()?),
+            (Type::Int, ByteLength::Two) => Tagged::Int16(value.try_into()?),
+            (Type::Int, ByteLength::Four) => Tagged::Int32(value.try_into()?),
+            (Type::Int, _) => Tagged::Int64(value.try_into()?),
+            (Type::Float, ByteLength::One) => Tagged::Float8(TryInto::<F8E4M3>::try_into(value)?.to_f32()),
+            (Type::Float, ByteLength::Two) => Tagged::Float16(TryInto::<f16>::try_into(value)?.to_f32()),
+            (Type::Float, ByteLength::Four) => Tagged::Float32(value.try_into()?),
+            (Type::Float, _) => Tagged::Float64(value.try_into()?),
+            (Type::String, _) => Tagged::String(value.try_into()?),
+            (Type::Array, _) => Tagged::Array(value.try_into()?),
+            (Type::Bool | Type::True | Type::False, _) => Tagged::Bool(value.try_into()?),
+            (Type::Extension, _) if value.is_big_integer() => Tagged::BigInteger(value.as_big_integer()?.to_string()),
+            (Type::Extension, _) if value.is_decimal() => Tagged::Decimal(value.as_decimal()?.to_string()),
+            (Type::Extension, _) => Tagged::Extension { tag: value.extension_tag()?, data: value.extension_data()?.to_vec() },
+        })
+    }
+}
+
+impl TryFrom<Tagged> for Value {
+    type Error = ErrorMessage;
+
+    fn try_from(tagged: Tagged) -> Result<Self, ErrorMessage> {
+        Ok(match tagged {
+            Tagged::Uint8(v) => Value::from(v),
+            Tagged::Uint16(v) => Value::from(v),
+            Tagged::Uint32(v) => Value::from(v),
+            Tagged::Uint64(v) => Value::from(v),
+            Tagged::Int8(v) => Value::from(v),
+            Tagged::Int16(v) => Value::from(v),
+            Tagged::Int32(v) => Value::from(v),
+            Tagged::Int64(v) => Value::from(v),
+            Tagged::Float8(v) => Value::from(F8E4M3::from_f32(v)),
+            Tagged::Float16(v) => Value::from(f16::from_f32(v)),
+            Tagged::Float32(v) => Value::from(v),
+            Tagged::Float64(v) => Value::from(v),
+            Tagged::String(v) => Value::try_from(v)?,
+            Tagged::Array(v) => Value::try_from(v)?,
+            Tagged::Bool(v) => Value::from(v),
+            Tagged::Decimal(v) => Value::from(v.parse::<Decimal>()?),
+            Tagged::BigInteger(v) => Value::from(v.parse::<BigInteger>()?),
+            Tagged::Extension { tag, data } => Value::from_extension(tag, &data)?,
+        })
+    }
+}
+
+impl Serialize for Value {
+    /// Writes the value as a single-entry map from its type and width to its
+    /// content, such as `{"uint32": 7}` or `{"array": [{"bool": true}]}`, so
+    /// it reads back with the same encoding.
+    ///
+    /// Fails if the payload does not decode as its type.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        Tagged::try_from(self).map_err(|error| S::Error::custom(error.0))?.serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Value {
+    /// Reads a value written by its `Serialize` implementation. Empty strings
+    /// and arrays are rejected, as they are everywhere else.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Value::try_from(Tagged::deserialize(deserializer)?).map_err(|error| D::Error::custom(error.0))
+    }
+}
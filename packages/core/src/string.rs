@@ -0,0 +1,120 @@
+use std::ops::{Bound, RangeBounds};
+use crate::constants::error::{
+    ErrorMessage, INVALID_STRING_RANGE, MALFORMED_UTF8, NOT_ASCII, NOT_A_SINGLE_CHAR, NOT_A_STRING,
+    STRING_MAX_LENGTH_EXCEEDED, STRING_OF_LENGTH_ZERO,
+};
+use crate::constants::types::Type;
+use crate::{set_len_descriptor, Value};
+
+/// String operations on the encoded buffer.
+///
+/// Lengths and ranges count UTF-8 bytes, like the length descriptor; use
+/// [`Value::char_len`] to count characters.
+impl Value {
+    /// Returns the UTF-8 text of a string value without copying it.
+    fn string_payload(&self) -> Result<&str, ErrorMessage> {
+        if self.r#type != Type::String {
+            return Err(ErrorMessage(NOT_A_STRING));
+        }
+
+        std::str::from_utf8(self.isolate_value_bytes()).map_err(|_| ErrorMessage(MALFORMED_UTF8))
+    }
+
+    /// Returns the text of a string value without copying it.
+    ///
+    /// # Errors
+    /// Returns `NOT_A_STRING` for other types, or `MALFORMED_UTF8` if the payload is not UTF-8.
+    pub fn as_str(&self) -> Result<&str, ErrorMessage> {
+        self.string_payload()
+    }
+
+    /// Returns the length of a string value in UTF-8 bytes, as held by its
+    /// length descriptor.
+    ///
+    /// # Errors
+    /// Returns `NOT_A_STRING` for other types.
+    pub fn string_len(&self) -> Result<usize, ErrorMessage> {
+        if self.r#type != Type::String {
+            return Err(ErrorMessage(NOT_A_STRING));
+        }
+
+        Ok(self.isolate_value_bytes().len())
+    }
+
+    /// Appends `suffix` to a string value in place, widening its length
+    /// descriptor when the new length needs more bytes.
+    ///
+    /// # Example
+    /// ```
+    /// use yad_core::Value;
+    ///
+    /// let mut greeting = Value::try_from("hello").unwrap();
+    /// greeting.string_append(", world").unwrap();
+    /// assert_eq!(greeting, Value::try_from("hello, world").unwrap());
+    /// ```
+    ///
+    /// # Errors
+    /// Returns `NOT_A_STRING` for other types.
+    pub fn string_append(&mut self, suffix: &str) -> Result<(), ErrorMessage> {
+        let len = self.string_len()? + suffix.len();
+        set_len_descriptor(self, len, STRING_OF_LENGTH_ZERO, STRING_MAX_LENGTH_EXCEEDED)?;
+        self.bytes.extend_from_slice(suffix.as_bytes());
+        Ok(())
+    }
+
+    /// Returns the bytes `range` of a string value as a new string value.
+    ///
+    /// # Errors
+    /// Returns `NOT_A_STRING` for other types, `MALFORMED_UTF8` if the payload
+    /// is not UTF-8, `INVALID_STRING_RANGE` if `range` goes past the end or
+    /// splits a character, or `STRING_OF_LENGTH_ZERO` if it is empty.
+    pub fn string_slice(&self, range: impl RangeBounds<usize>) -> Result<Value, ErrorMessage> {
+        let range = (range.start_bound().cloned(), range.end_bound().cloned());
+        let slice = self.string_payload()?.get::<(Bound<usize>, Bound<usize>)>(range)
+            .ok_or(ErrorMessage(INVALID_STRING_RANGE))?;
+        Value::from_str_bytes(slice)
+    }
+
+    /// Encodes `byte` as an unsigned 8-bit integer, like `Value::from(byte)`.
+    ///
+    /// A byte literal such as `b'a'` is a `u8`, so `Value::from(b'a')` is the
+    /// number 97; this name says so, and [`Value::from_ascii`] is its
+    /// character counterpart.
+    pub fn from_byte(byte: u8) -> Value {
+        Value::from(byte)
+    }
+
+    /// Encodes the ASCII character `byte` as a one-byte string, so `b'a'`
+    /// becomes `"a"` rather than the number 97.
+    ///
+    /// # Errors
+    /// Returns `NOT_ASCII` for bytes above `0x7F`.
+    pub fn from_ascii(byte: u8) -> Result<Value, ErrorMessage> {
+        if !byte.is_ascii() {
+            return Err(ErrorMessage(NOT_ASCII));
+        }
+        Ok(Value::from(byte as char))
+    }
+
+    /// Returns the character of a string value holding exactly one.
+    ///
+    /// # Example
+    /// ```
+    /// use yad_core::Value;
+    ///
+    /// let grade = Value::from('é');
+    /// assert_eq!(grade.string_len(), Ok(2));
+    /// assert_eq!(grade.as_char(), Ok('é'));
+    /// ```
+    ///
+    /// # Errors
+    /// Returns `NOT_A_STRING` for other types, `MALFORMED_UTF8` if the payload
+    /// is not UTF-8, or `NOT_A_SINGLE_CHAR` if it holds more than one character.
+    pub fn as_char(&self) -> Result<char, ErrorMessage> {
+        let mut chars = self.string_payload()?.chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => Ok(c),
+            _ => Err(ErrorMessage(NOT_A_SINGLE_CHAR)),
+        }
+    }
+}
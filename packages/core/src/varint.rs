@@ -0,0 +1,133 @@
+use crate::constants::error::{ErrorMessage, MALFORMED_VARINT, NESTING_TOO_DEEP, NOT_ENOUGH_BYTES};
+use crate::constants::length::ByteLength;
+use crate::constants::types::Type;
+use crate::delta::{delta_encoded_len, DELTA_ARRAY_HEADER};
+use crate::{Reader, MAX_NESTING_DEPTH};
+
+/// Appends `value` as an unsigned LEB128 varint: seven bits per byte, least
+/// significant group first, the high bit set on every byte but the last.
+pub fn write_varint(mut value: u64, out: &mut Vec<u8>) {
+    while value >= 0x80 {
+        out.push(value as u8 | 0x80);
+        value >>= 7;
+    }
+    out.push(value as u8);
+}
+
+/// Reads an unsigned LEB128 varint at the start of `bytes`.
+///
+/// # Returns
+/// - `Ok((u64, usize))`: The value and the number of bytes it took.
+///
+/// # Errors
+/// Returns `NOT_ENOUGH_BYTES` if the varint is truncated, or `MALFORMED_VARINT`
+/// if it does not fit in 64 bits.
+pub fn read_varint(bytes: &[u8]) -> Result<(u64, usize), ErrorMessage> {
+    let mut value: u64 = 0;
+
+    for (i, byte) in bytes.iter().enumerate() {
+        let group = (byte & 0x7F) as u64;
+        if i == 9 && *byte > 1 {
+            return Err(ErrorMessage(MALFORMED_VARINT));
+        }
+
+        value |= group << (7 * i);
+        if byte & 0x80 == 0 {
+            return Ok((value, i + 1));
+        }
+    }
+
+    Err(ErrorMessage(NOT_ENOUGH_BYTES))
+}
+
+/// Returns the width of the smallest fixed length descriptor holding `len`.
+fn min_length(len: u64) -> ByteLength {
+    match len {
+        l if l <= u8::MAX as u64 => ByteLength::One,
+        l if l <= u16::MAX as u64 => ByteLength::Two,
+        l if l <= u32::MAX as u64 => ByteLength::Four,
+        _ => ByteLength::Eight,
+    }
+}
+
+/// Appends `len` as a big-endian descriptor of width `length`.
+fn write_fixed(len: u64, length: ByteLength, out: &mut Vec<u8>) {
+    let bytes = len.to_be_bytes();
+    out.extend_from_slice(&bytes[8 - length.as_byte_count() as usize..]);
+}
+
+/// Reads a big-endian descriptor of width `length`.
+fn read_fixed(reader: &mut Reader, length: ByteLength) -> Result<u64, ErrorMessage> {
+    Ok(reader.take(length.as_byte_count() as usize)?
+        .iter()
+        .fold(0u64, |len, byte| len << 8 | *byte as u64))
+}
+
+/// Copies the value at the reader's position to `out`, rewriting the length
+/// descriptors of strings and arrays (nested ones included) as varints.
+///
+/// The header byte is kept, so its low nibble still names the width of the
+/// fixed descriptor the value had; numbers and booleans are copied as they are.
+///
+/// # Errors
+/// Returns an error if the value is malformed or truncated.
+pub fn transcode_to_varint(reader: &mut Reader, out: &mut Vec<u8>) -> Result<(), ErrorMessage> {
+    transcode(reader, out, true, 0)
+}
+
+/// Copies a value written by [`transcode_to_varint`] at the reader's position
+/// to `out`, rewriting its varint length descriptors as the smallest fixed ones.
+///
+/// # Errors
+/// Returns an error if the value is malformed or truncated.
+pub fn transcode_from_varint(reader: &mut Reader, out: &mut Vec<u8>) -> Result<(), ErrorMessage> {
+    transcode(reader, out, false, 0)
+}
+
+fn transcode(reader: &mut Reader, out: &mut Vec<u8>, to_varint: bool, depth: usize) -> Result<(), ErrorMessage> {
+    if depth > MAX_NESTING_DEPTH {
+        return Err(ErrorMessage(NESTING_TOO_DEEP));
+    }
+
+    let header = reader.peek().ok_or(ErrorMessage(NOT_ENOUGH_BYTES))?;
+
+    // Delta arrays already use varints and are the same in both encodings.
+    if header & 0xF0 == DELTA_ARRAY_HEADER {
+        let len = delta_encoded_len(reader.remaining())?;
+        out.extend_from_slice(reader.take(len)?);
+        return Ok(());
+    }
+
+    let r#type = Type::try_from(header)?;
+
+    if !matches!(r#type, Type::String | Type::Array) {
+        out.extend_from_slice(reader.skip_value()?);
+        return Ok(());
+    }
+    reader.advance(1)?;
+
+    let len = if to_varint {
+        let len = read_fixed(reader, ByteLength::try_from(header)?)?;
+        out.push(header);
+        write_varint(len, out);
+        len
+    } else {
+        let (len, consumed) = read_varint(reader.remaining())?;
+        reader.advance(consumed)?;
+        let length = min_length(len);
+        out.push(header & 0xF0 | u8::from(length));
+        write_fixed(len, length, out);
+        len
+    };
+
+    if r#type == Type::String {
+        let len = usize::try_from(len).map_err(|_| ErrorMessage(NOT_ENOUGH_BYTES))?;
+        out.extend_from_slice(reader.take(len)?);
+    } else {
+        for _ in 0..len {
+            transcode(reader, out, to_varint, depth + 1)?;
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,159 @@
+use crate::bigint::BigInteger;
+use crate::compare::Number;
+use crate::constants::error::{ErrorMessage, NESTING_TOO_DEEP, NOT_AN_ARRAY, NOT_A_NUMBER, NOT_ENOUGH_BYTES};
+use crate::constants::length::ByteLength;
+use crate::constants::types::Type;
+use crate::decimal::Decimal;
+use crate::kind::ValueKind;
+use crate::{Value, MAX_NESTING_DEPTH};
+
+/// Where a visited value sits inside the value passed to [`Value::walk`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Location<'a> {
+    /// Index of the value in each enclosing array, outermost first. Empty for
+    /// the walked value itself.
+    pub path: &'a [usize],
+    /// Offset of the value's header byte in the encoded bytes of the walked value.
+    pub offset: usize,
+}
+
+impl Location<'_> {
+    /// Returns how many arrays enclose the value.
+    pub fn depth(&self) -> usize {
+        self.path.len()
+    }
+}
+
+/// Callbacks invoked by [`Value::walk`], one per value kind.
+///
+/// Every method does nothing by default, so visitors only implement the kinds
+/// they care about.
+pub trait ValueVisitor {
+    /// Called for unsigned integers of any width.
+    fn visit_uint(&mut self, _at: Location<'_>, _value: u64) {}
+
+    /// Called for signed integers of any width.
+    fn visit_int(&mut self, _at: Location<'_>, _value: i64) {}
+
+    /// Called for floats of any width, widened to `f64`.
+    fn visit_float(&mut self, _at: Location<'_>, _value: f64) {}
+
+    /// Called for strings.
+    fn visit_string(&mut self, _at: Location<'_>, _value: &str) {}
+
+    /// Called for booleans.
+    fn visit_bool(&mut self, _at: Location<'_>, _value: bool) {}
+
+    /// Called for decimals (see [`Decimal`]).
+    fn visit_decimal(&mut self, _at: Location<'_>, _value: Decimal) {}
+
+    /// Called for big integers (see [`BigInteger`]).
+    fn visit_big_integer(&mut self, _at: Location<'_>, _value: BigInteger) {}
+
+    /// Called for other extension values with their tag and data.
+    fn visit_extension(&mut self, _at: Location<'_>, _tag: u8, _data: &[u8]) {}
+
+    /// Called before the elements of an array holding `len` of them. Returning
+    /// `false` skips the elements; [`ValueVisitor::leave_array`] is still called.
+    fn enter_array(&mut self, _at: Location<'_>, _len: usize) -> bool {
+        true
+    }
+
+    /// Called after the elements of an array.
+    fn leave_array(&mut self, _at: Location<'_>, _len: usize) {}
+}
+
+impl Value {
+    /// Traverses the value depth-first, calling `visitor` for it and, for
+    /// arrays, for every element in order.
+    ///
+    /// Elements are read from the encoded bytes by their length descriptors, so
+    /// nested arrays are not decoded into one `Value` per element. Each callback
+    /// receives a [`Location`] with the element indices leading to the value
+    /// and its byte offset in `self.bytes`.
+    ///
+    /// # Example
+    /// ```
+    /// use yad_core::Value;
+    /// use yad_core::visit::{Location, ValueVisitor};
+    ///
+    /// struct Sum(u64);
+    ///
+    /// impl ValueVisitor for Sum {
+    ///     fn visit_uint(&mut self, _at: Location<'_>, value: u64) {
+    ///         self.0 += value;
+    ///     }
+    /// }
+    ///
+    /// let inner = Value::try_from(vec![2u8, 3]).unwrap();
+    /// let value = Value::try_from(vec![Value::from(1u8), inner]).unwrap();
+    ///
+    /// let mut sum = Sum(0);
+    /// value.walk(&mut sum).unwrap();
+    /// assert_eq!(sum.0, 6);
+    /// ```
+    ///
+    /// # Errors
+    /// Returns the error of the first value whose encoding or payload does not
+    /// decode, or `NESTING_TOO_DEEP` if arrays are nested beyond
+    /// `MAX_NESTING_DEPTH`. Callbacks made before the error are not undone.
+    pub fn walk(&self, visitor: &mut impl ValueVisitor) -> Result<(), ErrorMessage> {
+        walk_at(&self.bytes, 0, &mut Vec::new(), visitor).map(|_| ())
+    }
+}
+
+/// Visits the value starting at `bytes[offset]` and returns its encoded size.
+fn walk_at(bytes: &[u8], offset: usize, path: &mut Vec<usize>, visitor: &mut impl ValueVisitor) -> Result<usize, ErrorMessage> {
+    if path.len() > MAX_NESTING_DEPTH {
+        return Err(ErrorMessage(NESTING_TOO_DEEP));
+    }
+
+    let encoded = bytes.get(offset..).ok_or(ErrorMessage(NOT_ENOUGH_BYTES))?;
+    if Type::try_from(*encoded.first().ok_or(ErrorMessage(NOT_ENOUGH_BYTES))?)? == Type::Array {
+        return walk_array(bytes, offset, path, visitor);
+    }
+
+    let value = Value::decode_in_place(encoded)?;
+    let at = Location { path, offset };
+    match value.kind() {
+        ValueKind::Uint | ValueKind::Int | ValueKind::Float => match value.as_number() {
+            Some(Number::Unsigned(number)) => visitor.visit_uint(at, number),
+            Some(Number::Signed(number)) => visitor.visit_int(at, number),
+            Some(Number::Float(number)) => visitor.visit_float(at, number),
+            None => return Err(ErrorMessage(NOT_A_NUMBER)),
+        },
+        ValueKind::String => visitor.visit_string(at, value.as_str()?),
+        ValueKind::Bool => visitor.visit_bool(at, value.clone().try_into()?),
+        ValueKind::Decimal => visitor.visit_decimal(at, value.as_decimal()?),
+        ValueKind::BigInteger => visitor.visit_big_integer(at, value.as_big_integer()?),
+        ValueKind::Extension => {
+            let (tag, data) = value.extension_payload()?;
+            visitor.visit_extension(at, tag, data);
+        }
+        ValueKind::Array => return Err(ErrorMessage(NOT_AN_ARRAY)),
+    }
+    Ok(value.bytes.len())
+}
+
+/// Visits the array starting at `bytes[offset]` and returns its encoded size.
+fn walk_array(bytes: &[u8], offset: usize, path: &mut Vec<usize>, visitor: &mut impl ValueVisitor) -> Result<usize, ErrorMessage> {
+    let encoded = &bytes[offset..];
+    let width = ByteLength::try_from(encoded[0])?.as_byte_count() as usize;
+    let count = encoded.get(1..1 + width).ok_or(ErrorMessage(NOT_ENOUGH_BYTES))?;
+    let len = count.iter().fold(0u64, |count, byte| count << 8 | *byte as u64) as usize;
+
+    if !visitor.enter_array(Location { path, offset }, len) {
+        visitor.leave_array(Location { path, offset }, len);
+        return Value::encoded_len(encoded);
+    }
+
+    let mut end = offset + 1 + width;
+    for index in 0..len {
+        path.push(index);
+        end += walk_at(bytes, end, path, visitor)?;
+        path.pop();
+    }
+
+    visitor.leave_array(Location { path, offset }, len);
+    Ok(end - offset)
+}
@@ -1,8 +1,22 @@
 import test from 'ava'
 
-import { plus100 } from '../index'
+import { Yad } from '../index'
 
-test('sync function from native code', (t) => {
-  const fixture = 42
-  t.is(plus100(fixture), fixture + 100)
+test('round-trips a document through a Buffer', (t) => {
+  const yad = new Yad()
+  yad.setRow('johan', { age: 17, name: 'Johan', tags: ['a', 1, true] })
+  yad.setKey('ana', 'score', 9.5)
+
+  const copy = Yad.deserialize(yad.serialize())
+
+  t.deepEqual(copy.toObject(), {
+    ana: { score: 9.5 },
+    johan: { age: 17, name: 'Johan', tags: ['a', 1, true] },
+  })
+  t.is(copy.getKey('johan', 'age'), 17)
+  t.is(copy.getRow('missing'), null)
+})
+
+test('reports malformed input as an Error', (t) => {
+  t.throws(() => Yad.deserialize(Buffer.from([1, 2])), { message: /malformed version header/ })
 })
@@ -1,3 +1,65 @@
 /* auto-generated by NAPI-RS */
 /* eslint-disable */
-export declare function plus100(input: number): number
+export declare class Value {
+  type: number
+  byteLength: number
+  bytes: Array<number>
+  static fromU8(num: number): Value
+}
+
+export declare const enum ByteLength {
+  One = 1,
+  Two = 2,
+  Four = 3,
+  Eight = 4
+}
+
+export declare const enum Type {
+  Uint = 16,
+  Int = 32,
+  Float = 48,
+  String = 64
+}
+
+/** A value stored in a key. */
+export type YadValue = number | string | boolean | Array<YadValue>
+
+/**
+ * A YAD document.
+ *
+ * Mirrors the `yad_*`, `row_*` and `key_*` C functions, with memory managed
+ * by the JavaScript garbage collector.
+ */
+export declare class Yad {
+  /** Creates an empty document of the current format version. */
+  constructor()
+  /** Decodes a document from a `Buffer` or `Uint8Array`. */
+  static deserialize(bytes: Uint8Array): Yad
+  /** Encodes the document into a `Buffer`. */
+  serialize(): Buffer
+  /** The format version, as `major.minor.patch-beta`. */
+  get version(): string
+  /** Returns the names of every row, in name order. */
+  rowNames(): Array<string>
+  /** Returns the number of rows. */
+  rowCount(): number
+  /** Returns a row as an object mapping key names to values, or `null`. */
+  getRow(name: string): Record<string, YadValue> | null
+  /**
+   * Inserts or replaces a row from an object mapping key names to values.
+   *
+   * Integers become the smallest fitting unsigned or signed type, other
+   * numbers 64-bit floats. `null` and nested objects are rejected.
+   */
+  setRow(name: string, keys: Record<string, YadValue>): void
+  /** Removes a row, returning `true` if it existed. */
+  removeRow(name: string): boolean
+  /** Returns the value of a key, or `null` if the row or key does not exist. */
+  getKey(row: string, key: string): YadValue | null
+  /** Sets the value of a key, creating the row if needed. */
+  setKey(row: string, key: string, value: YadValue): void
+  /** Removes a key, returning `true` if it existed. */
+  removeKey(row: string, key: string): boolean
+  /** Returns the whole document as an object mapping row names to row objects. */
+  toObject(): Record<string, Record<string, YadValue>>
+}
@@ -393,4 +393,7 @@ if (!nativeBinding) {
 }
 
 module.exports = nativeBinding
-module.exports.plus100 = nativeBinding.plus100
+module.exports.ByteLength = nativeBinding.ByteLength
+module.exports.Type = nativeBinding.Type
+module.exports.Value = nativeBinding.Value
+module.exports.Yad = nativeBinding.Yad
@@ -1 +1,2 @@
-mod value;
\ No newline at end of file
+mod value;
+mod yad;
@@ -1,21 +1,27 @@
 use napi_derive::napi;
-use napi::bindgen_prelude::*;
-use yad_core::core::Value as CoreValue;
-use yad_core::constants::types;
+use yad_core::Value as CoreValue;
 
 #[napi(js_name = "Value")]
 pub struct JsValue {
   pub r#type: u8,
   pub byte_length: u8,
-  pub bytes: Vec<u8>
+  pub bytes: Vec<u8>,
+}
+
+impl From<CoreValue> for JsValue {
+  fn from(value: CoreValue) -> Self {
+    Self {
+      r#type: u8::from(value.r#type),
+      byte_length: u8::from(value.length),
+      bytes: value.bytes.into_vec(),
+    }
+  }
 }
 
 #[napi]
 impl JsValue {
   #[napi]
   pub fn from_u8(num: u8) -> Self {
-    Self {
-      r#type:
-    }
+    CoreValue::from(num).into()
   }
-}
\ No newline at end of file
+}
@@ -0,0 +1,216 @@
+use float16::f16;
+use float8::F8E4M3;
+use napi::bindgen_prelude::*;
+use napi_derive::napi;
+use serde_json::{Map, Number, Value as Json};
+use serde_yad::key::Key;
+use serde_yad::row::Row;
+use serde_yad::{CURRENT_VERSION, YAD};
+use yad_core::constants::error::ErrorMessage;
+use yad_core::constants::length::ByteLength;
+use yad_core::constants::types::Type;
+use yad_core::json::NumberPolicy;
+use yad_core::Value;
+
+/// Converts a crate error into a JavaScript `Error`.
+fn js_error(error: ErrorMessage) -> Error {
+  Error::from_reason(error.0)
+}
+
+/// Number rules for values coming from JavaScript, where every number is a double.
+fn number_policy() -> NumberPolicy {
+  NumberPolicy {
+    integral_floats_as_integers: true,
+    ..NumberPolicy::default()
+  }
+}
+
+/// Converts a value into JSON, which napi hands to JavaScript as plain values.
+///
+/// 64-bit integers outside the safe integer range lose precision, as any
+/// JavaScript `number` would. Decimals and big integers become strings, such as `"19.90"`, so no
+/// digit is lost; other extension values become `{ tag, data }` objects.
+fn value_to_json(value: &Value) -> std::result::Result<Json, ErrorMessage> {
+  let v = value.clone();
+  let float = |f: f64| Number::from_f64(f).map(Json::Number).unwrap_or(Json::Null);
+
+  Ok(match (value.r#type, value.length) {
+    (Type::Uint, ByteLength::One) => TryInto::<u8>::try_into(v)?.into(),
+    (Type::Uint, ByteLength::Two) => TryInto::<u16>::try_into(v)?.into(),
+    (Type::Uint, ByteLength::Four) => TryInto::<u32>::try_into(v)?.into(),
+    (Type::Uint, _) => TryInto::<u64>::try_into(v)?.into(),
+    (Type::Int, ByteLength::One) => TryInto::<i8>::try_into(v)?.into(),
+    (Type::Int, ByteLength::Two) => TryInto::<i16>::try_into(v)?.into(),
+    (Type::Int, ByteLength::Four) => TryInto::<i32>::try_into(v)?.into(),
+    (Type::Int, _) => TryInto::<i64>::try_into(v)?.into(),
+    (Type::Float, ByteLength::One) => float(TryInto::<F8E4M3>::try_into(v)?.to_f64()),
+    (Type::Float, ByteLength::Two) => float(TryInto::<f16>::try_into(v)?.to_f64()),
+    (Type::Float, ByteLength::Four) => float(TryInto::<f32>::try_into(v)? as f64),
+    (Type::Float, _) => float(TryInto::<f64>::try_into(v)?),
+    (Type::String, _) => TryInto::<String>::try_into(v)?.into(),
+    (Type::Bool | Type::True | Type::False, _) => TryInto::<bool>::try_into(v)?.into(),
+    (Type::Array, _) => {
+      let items: Vec<Value> = v.try_into()?;
+      Json::Array(items.iter().map(value_to_json).collect::<std::result::Result<_, _>>()?)
+    }
+    (Type::Extension, _) if value.is_decimal() => value.as_decimal()?.to_string().into(),
+    (Type::Extension, _) if value.is_big_integer() => value.as_big_integer()?.to_string().into(),
+    (Type::Extension, _) => serde_json::json!({ "tag": value.extension_tag()?, "data": value.extension_data()? }),
+  })
+}
+
+/// Converts a row into an object mapping key names to values.
+fn row_to_json(row: &Row) -> std::result::Result<Json, ErrorMessage> {
+  let mut object = Map::new();
+  for (name, key) in &row.keys {
+    object.insert(name.clone(), value_to_json(&key.value)?);
+  }
+  Ok(Json::Object(object))
+}
+
+/// Converts a JavaScript value into a YAD value.
+fn json_to_value(json: &Json) -> Result<Value> {
+  Value::from_json_value(json, number_policy()).map_err(js_error)
+}
+
+/// A YAD document.
+///
+/// Mirrors the `yad_*`, `row_*` and `key_*` C functions, with memory managed
+/// by the JavaScript garbage collector.
+#[napi]
+pub struct Yad {
+  inner: YAD,
+}
+
+#[napi]
+impl Yad {
+  /// Creates an empty document of the current format version.
+  #[napi(constructor)]
+  pub fn new() -> Self {
+    Self {
+      inner: YAD::new_empty(CURRENT_VERSION),
+    }
+  }
+
+  /// Decodes a document from a `Buffer` or `Uint8Array`.
+  #[napi(factory)]
+  pub fn deserialize(bytes: &[u8]) -> Result<Self> {
+    YAD::deserialize(bytes.to_vec())
+      .map(|inner| Self { inner })
+      .map_err(js_error)
+  }
+
+  /// Encodes the document into a `Buffer`.
+  #[napi]
+  pub fn serialize(&self) -> Result<Buffer> {
+    self.inner.serialize().map(Buffer::from).map_err(js_error)
+  }
+
+  /// The format version, as `major.minor.patch-beta`.
+  #[napi(getter)]
+  pub fn version(&self) -> String {
+    self.inner.version.to_string()
+  }
+
+  /// Returns the names of every row, in name order.
+  #[napi]
+  pub fn row_names(&self) -> Vec<String> {
+    self.inner.rows.keys().cloned().collect()
+  }
+
+  /// Returns the number of rows.
+  #[napi]
+  pub fn row_count(&self) -> u32 {
+    self.inner.rows.len() as u32
+  }
+
+  /// Returns a row as an object mapping key names to values, or `null`.
+  #[napi]
+  pub fn get_row(&self, name: String) -> Result<Option<Json>> {
+    self
+      .inner
+      .rows
+      .get(&name)
+      .map(row_to_json)
+      .transpose()
+      .map_err(js_error)
+  }
+
+  /// Inserts or replaces a row from an object mapping key names to values.
+  ///
+  /// Integers become the smallest fitting unsigned or signed type, other
+  /// numbers 64-bit floats. `null` and nested objects are rejected.
+  #[napi]
+  pub fn set_row(&mut self, name: String, keys: Map<String, Json>) -> Result<()> {
+    let keys = keys
+      .iter()
+      .map(|(key, value)| Ok(Key::new(key, json_to_value(value)?)))
+      .collect::<Result<Vec<Key>>>()?;
+
+    self.inner.insert_row(name, keys);
+    Ok(())
+  }
+
+  /// Removes a row, returning `true` if it existed.
+  #[napi]
+  pub fn remove_row(&mut self, name: String) -> bool {
+    self.inner.remove_row(name).is_some()
+  }
+
+  /// Returns the value of a key, or `null` if the row or key does not exist.
+  #[napi]
+  pub fn get_key(&self, row: String, key: String) -> Result<Option<Json>> {
+    self
+      .inner
+      .rows
+      .get(&row)
+      .and_then(|r| r.keys.get(&key))
+      .map(|k| value_to_json(&k.value))
+      .transpose()
+      .map_err(js_error)
+  }
+
+  /// Sets the value of a key, creating the row if needed.
+  #[napi]
+  pub fn set_key(&mut self, row: String, key: String, value: Json) -> Result<()> {
+    let value = json_to_value(&value)?;
+
+    match self.inner.rows.get_mut(&row) {
+      Some(existing) => existing.insert_key(key, value).map_err(js_error)?,
+      None => self.inner.insert_row(row, vec![Key::new(key, value)]),
+    }
+
+    self.inner.rebuild_indexes();
+    Ok(())
+  }
+
+  /// Removes a key, returning `true` if it existed.
+  #[napi]
+  pub fn remove_key(&mut self, row: String, key: String) -> bool {
+    let removed = self
+      .inner
+      .rows
+      .get_mut(&row)
+      .and_then(|r| r.remove_key(key))
+      .is_some();
+
+    self.inner.rebuild_indexes();
+    removed
+  }
+
+  /// Returns the whole document as an object mapping row names to row objects.
+  #[napi]
+  pub fn to_object(&self) -> Result<Json> {
+    let mut object = Map::new();
+    for (name, row) in &self.inner.rows {
+      object.insert(name.clone(), row_to_json(row).map_err(js_error)?);
+    }
+    Ok(Json::Object(object))
+  }
+}
+
+impl Default for Yad {
+  fn default() -> Self {
+    Self::new()
+  }
+}
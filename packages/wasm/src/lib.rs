@@ -0,0 +1,142 @@
+use float16::f16;
+use float8::F8E4M3;
+use js_sys::{Array, BigInt, Object, Reflect, Uint8Array};
+use serde_yad::row::Row;
+use serde_yad::YAD;
+use wasm_bindgen::prelude::*;
+use yad_core::constants::error::ErrorMessage;
+use yad_core::constants::length::ByteLength;
+use yad_core::constants::types::Type;
+use yad_core::Value;
+
+/// Converts a crate error into a JavaScript `Error`.
+fn js_error(error: ErrorMessage) -> JsError {
+    JsError::new(error.0)
+}
+
+/// Converts a value into its JavaScript counterpart.
+///
+/// - 64-bit integers become `BigInt`s, every other number a `number`.
+/// - Strings and booleans map directly; arrays become `Array`s.
+/// - Decimals become strings, such as `"19.90"`, so no digit is lost; big
+///   integers become `BigInt`s.
+/// - Other extension values become `{ tag, data }` objects, `data` a `Uint8Array`.
+fn value_to_js(value: &Value) -> Result<JsValue, ErrorMessage> {
+    let v = value.clone();
+
+    Ok(match (value.r#type, value.length) {
+        (Type::Uint, ByteLength::Eight) => BigInt::from(TryInto::<u64>::try_into(v)?).into(),
+        (Type::Int, ByteLength::Eight) => BigInt::from(TryInto::<i64>::try_into(v)?).into(),
+        (Type::Uint, ByteLength::One) => TryInto::<u8>::try_into(v)?.into(),
+        (Type::Uint, ByteLength::Two) => TryInto::<u16>::try_into(v)?.into(),
+        (Type::Uint, _) => TryInto::<u32>::try_into(v)?.into(),
+        (Type::Int, ByteLength::One) => TryInto::<i8>::try_into(v)?.into(),
+        (Type::Int, ByteLength::Two) => TryInto::<i16>::try_into(v)?.into(),
+        (Type::Int, _) => TryInto::<i32>::try_into(v)?.into(),
+        (Type::Float, ByteLength::One) => TryInto::<F8E4M3>::try_into(v)?.to_f64().into(),
+        (Type::Float, ByteLength::Two) => TryInto::<f16>::try_into(v)?.to_f64().into(),
+        (Type::Float, ByteLength::Four) => TryInto::<f32>::try_into(v)?.into(),
+        (Type::Float, _) => TryInto::<f64>::try_into(v)?.into(),
+        (Type::String, _) => TryInto::<String>::try_into(v)?.into(),
+        (Type::Bool | Type::True | Type::False, _) => TryInto::<bool>::try_into(v)?.into(),
+        (Type::Array, _) => {
+            let items: Vec<Value> = v.try_into()?;
+            let array = Array::new();
+            for item in &items {
+                array.push(&value_to_js(item)?);
+            }
+            array.into()
+        }
+        (Type::Extension, _) if value.is_decimal() => value.as_decimal()?.to_string().into(),
+        (Type::Extension, _) if value.is_big_integer() => {
+            let digits = JsValue::from_str(&value.as_big_integer()?.to_string());
+            // Base 10 digits always parse as a `BigInt`.
+            BigInt::new(&digits).map(JsValue::from).unwrap_or(digits)
+        }
+        (Type::Extension, _) => {
+            let object = Object::new();
+            // Setting a property on a fresh plain object cannot fail.
+            let _ = Reflect::set(&object, &JsValue::from_str("tag"), &value.extension_tag()?.into());
+            let _ = Reflect::set(&object, &JsValue::from_str("data"), &Uint8Array::from(value.extension_data()?));
+            object.into()
+        }
+    })
+}
+
+/// Converts a row into a plain object mapping key names to values.
+fn row_to_js(row: &Row) -> Result<JsValue, ErrorMessage> {
+    let object = Object::new();
+
+    for (name, key) in &row.keys {
+        // Setting a property on a fresh plain object cannot fail.
+        let _ = Reflect::set(&object, &JsValue::from_str(name), &value_to_js(&key.value)?);
+    }
+
+    Ok(object.into())
+}
+
+/// A decoded YAD document.
+#[wasm_bindgen]
+pub struct YadDocument {
+    inner: YAD,
+}
+
+/// Decodes a YAD file from its bytes (e.g. a `Uint8Array` read with `fetch`).
+///
+/// # Errors
+/// Throws an `Error` with the decoder message if the bytes are malformed.
+#[wasm_bindgen]
+pub fn decode(bytes: &[u8]) -> Result<YadDocument, JsError> {
+    YAD::deserialize(bytes.to_vec())
+        .map(|inner| YadDocument { inner })
+        .map_err(js_error)
+}
+
+#[wasm_bindgen]
+impl YadDocument {
+    /// Serializes the document, returning a `Uint8Array`.
+    pub fn serialize(&self) -> Result<Vec<u8>, JsError> {
+        self.inner.serialize().map_err(js_error)
+    }
+
+    /// Returns the format version as `major.minor.patch-beta`.
+    pub fn version(&self) -> String {
+        self.inner.version.to_string()
+    }
+
+    /// Returns the names of every row, in name order.
+    #[wasm_bindgen(js_name = rowNames)]
+    pub fn row_names(&self) -> Vec<String> {
+        self.inner.rows.keys().cloned().collect()
+    }
+
+    /// Returns a row as a plain object, or `undefined` if it does not exist.
+    #[wasm_bindgen(js_name = getRow)]
+    pub fn get_row(&self, name: &str) -> Result<JsValue, JsError> {
+        match self.inner.rows.get(name) {
+            Some(row) => row_to_js(row).map_err(js_error),
+            None => Ok(JsValue::UNDEFINED),
+        }
+    }
+
+    /// Returns the value of a key, or `undefined` if the row or key does not exist.
+    #[wasm_bindgen(js_name = getKey)]
+    pub fn get_key(&self, row: &str, key: &str) -> Result<JsValue, JsError> {
+        match self.inner.rows.get(row).and_then(|r| r.keys.get(key)) {
+            Some(key) => value_to_js(&key.value).map_err(js_error),
+            None => Ok(JsValue::UNDEFINED),
+        }
+    }
+
+    /// Returns the whole document as an object mapping row names to row objects.
+    #[wasm_bindgen(js_name = toObject)]
+    pub fn to_object(&self) -> Result<JsValue, JsError> {
+        let object = Object::new();
+
+        for (name, row) in &self.inner.rows {
+            let _ = Reflect::set(&object, &JsValue::from_str(name), &row_to_js(row).map_err(js_error)?);
+        }
+
+        Ok(object.into())
+    }
+}
@@ -0,0 +1,43 @@
+//! Guards against quadratic decoding of wide rows: decode time should grow
+//! linearly with the number of keys.
+//!
+//! Run with `cargo bench --bench decode`.
+
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use serde_yad::key::Key;
+use serde_yad::row::Row;
+use serde_yad::{CURRENT_VERSION, Value, YAD};
+
+/// A single row holding `keys` keys of mixed types.
+fn wide_row(keys: usize) -> Row {
+    Row::new("wide", (0..keys)
+        .map(|k| match k % 3 {
+            0 => Key::new(format!("uint_{k}"), Value::from(k as u32)),
+            1 => Key::new(format!("text_{k}"), Value::try_from(format!("value {k}")).unwrap()),
+            _ => Key::new(format!("list_{k}"), Value::try_from(vec![Value::from(true), Value::from(k as u16)]).unwrap()),
+        })
+        .collect())
+}
+
+fn decode(c: &mut Criterion) {
+    let mut group = c.benchmark_group("decode_wide_row");
+
+    for keys in [1_000, 4_000, 16_000] {
+        let row = wide_row(keys);
+        let row_bytes = row.serialize().unwrap();
+        let yad_bytes = YAD::new(CURRENT_VERSION, vec![row]).serialize().unwrap();
+
+        group.throughput(Throughput::Elements(keys as u64));
+        group.bench_with_input(BenchmarkId::new("row", keys), &row_bytes, |b, bytes| {
+            b.iter(|| Row::deserialize(black_box(bytes.clone())).unwrap())
+        });
+        group.bench_with_input(BenchmarkId::new("document", keys), &yad_bytes, |b, bytes| {
+            b.iter(|| YAD::deserialize(black_box(bytes.clone())).unwrap())
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, decode);
+criterion_main!(benches);
@@ -0,0 +1,58 @@
+//! Compares the single-buffer serializer with the previous nested-buffer one.
+//!
+//! Run with `cargo bench --bench serialize`.
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use serde_yad::key::Key;
+use serde_yad::row::Row;
+use serde_yad::{CURRENT_VERSION, Value, YAD};
+
+/// A document of `rows` rows holding ten keys of mixed types each.
+fn document(rows: usize) -> YAD {
+    let rows = (0..rows)
+        .map(|r| {
+            Row::new(format!("row_{r}"), (0..10)
+                .map(|k| match k % 4 {
+                    0 => Key::new(format!("uint_{k}"), Value::from(r as u32)),
+                    1 => Key::new(format!("float_{k}"), Value::from(r as f64 / 3.0)),
+                    2 => Key::new(format!("text_{k}"), Value::try_from(format!("value {r} {k}")).unwrap()),
+                    _ => Key::new(format!("list_{k}"), Value::try_from(vec![Value::from(true), Value::from(k as u8)]).unwrap()),
+                })
+                .collect())
+        })
+        .collect();
+
+    YAD::new(CURRENT_VERSION, rows)
+}
+
+/// The serializer before the single-buffer write path: one `Vec` per key and
+/// per row, each copied into its parent.
+fn serialize_nested(yad: &YAD) -> Vec<u8> {
+    let mut bytes = vec![0x59, 0x41, 0x44, 0x00];
+    bytes.extend_from_slice(&yad.version.serialize());
+
+    for row in yad.rows.values() {
+        let mut row_bytes = Row::new_empty(&row.name).serialize().unwrap();
+        row_bytes.pop();
+        for key in row.keys.values() {
+            row_bytes.extend_from_slice(key.serialize().unwrap().as_slice());
+        }
+        row_bytes.push(0xF2);
+        bytes.extend_from_slice(&row_bytes);
+    }
+
+    bytes
+}
+
+fn serialize(c: &mut Criterion) {
+    let yad = document(1_000);
+    assert_eq!(serialize_nested(&yad), yad.serialize().unwrap());
+
+    let mut group = c.benchmark_group("serialize_1000_rows");
+    group.bench_function("nested_buffers", |b| b.iter(|| serialize_nested(black_box(&yad))));
+    group.bench_function("single_buffer", |b| b.iter(|| black_box(&yad).serialize().unwrap()));
+    group.finish();
+}
+
+criterion_group!(benches, serialize);
+criterion_main!(benches);
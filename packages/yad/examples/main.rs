@@ -36,7 +36,7 @@ fn create_a_new_yad() -> YAD {
 /// # Panics
 /// If serialization fails or if the file cannot be written.
 fn write_a_new_yad(yad: &YAD, path_buf: &PathBuf) {
-    std::fs::write(path_buf, yad.serialize().unwrap()).unwrap();
+    yad.save_atomic(path_buf).unwrap();
 }
 
 /// Reads a `.yad` file from disk and deserializes it into a [`YAD`] document.
@@ -0,0 +1,28 @@
+use std::path::PathBuf;
+use serde_yad::recover;
+
+/// Salvages the intact rows of a corrupted `.yad` file into a new one,
+/// reporting every region that could not be read.
+///
+/// Usage: `cargo run --example repair -- <damaged.yad> <repaired.yad>`
+///
+/// # Panics
+/// Panics if the input cannot be read or the output cannot be written.
+fn main() {
+    let mut args = std::env::args().skip(1);
+    let (Some(input), Some(output)) = (args.next().map(PathBuf::from), args.next().map(PathBuf::from)) else {
+        eprintln!("usage: repair <damaged.yad> <repaired.yad>");
+        std::process::exit(2);
+    };
+
+    let report = recover::scan(&std::fs::read(&input).unwrap());
+    let place = if report.offsets_in_input { "bytes" } else { "body bytes" };
+
+    for region in &report.damaged {
+        println!("damaged {} {}..{}: {}", place, region.range.start, region.range.end, region.error.0);
+    }
+    println!("salvaged {} rows, {} damaged regions", report.rows.len(), report.damaged.len());
+
+    report.into_yad().save_atomic(&output).unwrap();
+    println!("wrote {}", output.display());
+}
@@ -0,0 +1,8 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use serde_yad::row::Row;
+
+fuzz_target!(|data: &[u8]| {
+    let _ = Row::deserialize(data.to_vec());
+});
@@ -0,0 +1,10 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use yad_core::Value;
+
+fuzz_target!(|data: &[u8]| {
+    if let Ok(value) = Value::decode(data.to_vec()) {
+        assert_eq!(Value::decode(value.bytes.to_vec()), Ok(value));
+    }
+});
@@ -0,0 +1,10 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use serde_yad::options::DecodeOptions;
+use serde_yad::YAD;
+
+fuzz_target!(|data: &[u8]| {
+    let _ = YAD::deserialize(data.to_vec());
+    let _ = YAD::deserialize_with_options(data.to_vec(), &DecodeOptions::limited(1 << 20));
+});
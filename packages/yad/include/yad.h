@@ -0,0 +1,1791 @@
+#ifndef YAD_H
+#define YAD_H
+
+/* Generated by cbindgen from serde_yad and yad_core. Do not edit by hand. */
+
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+
+// Version of the C ABI this header describes; compare it with `yad_abi_version()`.
+#define YAD_ABI_VERSION 1
+
+// Category of the last error reported by an FFI function.
+//
+// Returned inside [`YadError`] by [`yad_last_error`]. `Ok` means no error has
+// been recorded on the calling thread since the last [`yad_clear_last_error`].
+typedef enum {
+  // No error recorded.
+  YadErrorCode_Ok = 0,
+  // A required pointer argument was null.
+  YadErrorCode_NullPointer = 1,
+  // A C string argument was not valid UTF-8.
+  YadErrorCode_InvalidUtf8 = 2,
+  // The input bytes could not be decoded.
+  YadErrorCode_Decode = 3,
+  // The object could not be encoded.
+  YadErrorCode_Encode = 4,
+  // The requested row, key or element does not exist.
+  YadErrorCode_NotFound = 5,
+  // An index was out of bounds.
+  YadErrorCode_OutOfBounds = 6,
+  // A `Value` holds a different type than the one requested.
+  YadErrorCode_Conversion = 7,
+  // The output buffer was too small; the output was truncated.
+  YadErrorCode_BufferTooSmall = 8,
+  // A panic was caught at the FFI boundary.
+  YadErrorCode_Panic = 9,
+  // Decoding the input would allocate more than the caller's limit.
+  YadErrorCode_LimitExceeded = 10,
+} YadErrorCode;
+
+// Represents a **key-value pair** inside a row structure.
+//
+// A [`Key`] stores both:
+// - A unique string identifier (`name`) within its parent row.
+// - An associated [`Value`] representing the stored data.
+//
+// Keys can be serialized and deserialized into/from a custom
+// **binary format** defined by the YAD protocol.
+//
+// A key holds one value, and may hold a short ordered list of further
+// values pushed with [`Key::push_value`]; [`Key::values`] walks all of them.
+//
+// # Binary Layout
+// ```text
+// +---------------+----------------------+-----------------+------------------+---------------------+---------------+
+// | Start Header  | Encoded Key Name     | [Metadata]      | Encoded Value    | [Further Values...] | End Header    |
+// +---------------+----------------------+-----------------+------------------+---------------------+---------------+
+// ```
+// Further values follow the first one back to back. Single-valued keys are
+// encoded exactly as before multi-value keys existed.
+//
+// # Fields
+// - `name`: Unique identifier of the key within its parent row.
+// - `value`: Data associated with the key (its first value).
+// - `meta`: Optional metadata, written only when not empty (see [`Meta`]).
+typedef struct Key Key;
+
+// Represents a **row structure** in the YAD binary format.
+//
+// A [`Row`] acts as a container object that groups multiple [`Key`] instances
+// under a single unique name. Rows serve as high-level organizational units
+// when encoding or decoding structured YAD data.
+//
+// # Binary Layout
+// ```text
+// +---------------+---------------------+-----------------+-------------------+---------------+
+// | Start Header  | Encoded Row Name    | [Metadata]      | Encoded Keys...   | End Header    |
+// +---------------+---------------------+-----------------+-------------------+---------------+
+// ```
+//
+// # Fields
+// - `name`: A unique string identifier for the row.
+// - `keys`: A [`BTreeMap`] mapping key names to their associated [`Key`] objects.
+// - `meta`: Optional metadata, written only when not empty (see [`Meta`]).
+typedef struct Row Row;
+
+// Iterates the keys of a [`Row`]. Created by `row_key_cursor_new`.
+typedef struct RowKeyCursor RowKeyCursor;
+
+// Represents a single value encoded in YAD's binary format.
+//
+// A `Value` is the in-memory representation of one encoded item. It stores:
+// - `r#type`: a `Type` discriminant indicating how to interpret `bytes`.
+// - `length`: a `ByteLength` value used for numbers/collections where applicable.
+// - `bytes`: the raw encoded bytes for the whole encoded value (header + length + payload).
+//
+// # Invariants
+// - `bytes[0]` is always the header byte (type | byte-length metadata).
+// - `isolate_value_bytes()` returns only the payload bytes (not the header or length descriptor).
+// - Conversions (`TryInto` / `From`) rely on `r#type` and `length` matching expected values.
+// - For nested `Array` values decoded via `TryInto<Vec<Value>>`, `bytes` always includes the
+//   full encoding (header + length descriptor + payload) to preserve the invariant.
+//
+// # Equality
+// The derived `Eq`/`Ord` compare the encoded bytes. Use `Value::semantic_eq` and
+// `Value::semantic_cmp` to compare what the values represent.
+typedef struct Value Value;
+
+// Represents a semantic version of the YAD file format.
+//
+// Versioning uses: major, minor, patch, and beta (pre-release).
+typedef struct Version Version;
+
+// Represents a full YAD document containing a version and multiple rows.
+//
+// Two documents are equal when their versions and rows are; secondary
+// indexes and observers are not compared.
+typedef struct YAD YAD;
+
+// Iterates the rows of a [`YAD`] document. Created by `yad_row_cursor_new`.
+typedef struct YadRowCursor YadRowCursor;
+
+// A C-compatible byte buffer owned by Rust.
+//
+// Returned by the `*_as_buffer` functions (`value_as_buffer`, `key_as_buffer`,
+// `row_as_buffer`, `yad_as_buffer`). It carries its length, so callers never
+// have to guess how many bytes were written, and its capacity, so
+// [`byte_buffer_free`] can release the exact allocation.
+//
+// # Fields
+// - `ptr`: Pointer to the first byte. Null when the buffer is empty or the call failed.
+// - `len`: Number of valid bytes.
+// - `cap`: Allocated capacity. Do not modify.
+//
+// # Safety
+// The buffer must be released with [`byte_buffer_free`] exactly once, with all
+// three fields unchanged.
+typedef struct {
+  uint8_t *ptr;
+  size_t len;
+  size_t cap;
+} ByteBuffer;
+
+// A C-compatible wrapper around a Rust `Vec<Value>`
+//
+// This struct is intended to be used across FFI boundaries.
+// The layout is compatible with C (`#[repr(C)]`), and it exposes
+// a raw pointer, length, and capacity.
+//
+// # Fields
+// - `ptr`: Pointer to the first element in the array. Can be null if empty.
+// - `len`: Number of valid elements in the array.
+// - `cap`: Allocated capacity of the array.
+//
+// # Safety
+// Accessing `ptr` directly is unsafe. Always use the provided FFI functions
+// to manipulate the array safely.
+typedef struct {
+  Value *ptr;
+  size_t len;
+  size_t cap;
+} CArray;
+
+// The last error reported on the calling thread, as returned by [`yad_last_error`].
+//
+// # Fields
+// - `code`: Category of the error, [`YadErrorCode::Ok`] if none.
+// - `message`: Null-terminated description, or null if `code` is `Ok`.
+//   Owned by the library; valid until the next error is recorded or cleared on the same thread.
+typedef struct {
+  YadErrorCode code;
+  const char *message;
+} YadError;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+// # Version FFI (C ABI)
+//
+// Provides functions to create, serialize, and deserialize `Version` objects
+// for use from C code. All functions use `#[unsafe(no_mangle)]`.
+// Creates a new [`Version`] from individual fields.
+//
+// # Parameters
+// - `major`: Major version number (breaking changes)
+// - `minor`: Minor version number (new features)
+// - `patch`: Patch version number (bug fixes)
+// - `beta`: Beta/pre-release identifier (0 = stable)
+//
+// # Returns
+// - Pointer to a newly allocated [`Version`]. Must be freed using `version_free`.
+Version *version_new(uint8_t major, uint8_t minor, uint8_t patch, uint8_t beta);
+
+// Frees a [`Version`] previously allocated by `version_new`.
+//
+// # Safety
+// - `version` must be a valid pointer returned by `version_new`.
+void version_free(Version *version);
+
+// Serializes a [`Version`] into a 5-byte array.
+//
+// # Safety
+// - `version` must be a valid pointer to a [`Version`].
+// - `out_bytes` must point to at least 5 writable bytes.
+void version_serialize(const Version *version, uint8_t *out_bytes);
+
+// Deserializes a [`Version`] from a 5-byte buffer.
+//
+// # Safety
+// - `bytes` must point to at least 5 bytes.
+// - Returns null pointer on failure.
+// - Allocated memory must be freed with `version_free`.
+Version *version_deserialize(const uint8_t *bytes);
+
+// # YAD FFI (C ABI)
+//
+// Provides functions to create, manipulate, serialize, and deserialize `YAD` documents
+// for use from C code.
+// Creates a new empty [`YAD`] document with the specified version.
+//
+// # Safety
+// - `version` must be a valid pointer to a [`Version`].
+// - Returns a pointer to a new [`YAD`] object. Must be freed with `yad_free`.
+YAD *yad_new_empty(const Version *version);
+
+// Creates a new [`YAD`] document from version and an array of [`Row`] pointers.
+//
+// # Safety
+// - `version` must be a valid pointer to [`Version`].
+// - `rows` is an array of `*mut Row` of length `rows_len`.
+// - Null pointers inside `rows` are ignored.
+// - Returns a pointer to a new [`YAD`] object. Must be freed with `yad_free`.
+YAD *yad_new(const Version *version, Row *const *rows, size_t rows_len);
+
+// Frees a [`YAD`] object previously allocated.
+//
+// # Safety
+// - `yad` must be a valid pointer returned by `yad_new` or `yad_new_empty`.
+void yad_free(YAD *yad);
+
+// Inserts a [`Row`] into the [`YAD`] document.
+//
+// # Safety
+// - `yad` must be a valid pointer to a [`YAD`].
+// - `row` must be a valid pointer to a [`Row`].
+void yad_insert_row(YAD *yad, Row *row);
+
+// Removes a [`Row`] from the [`YAD`] document by name.
+//
+// # Safety
+// - `yad` must be a valid pointer to a [`YAD`].
+// - `name` must be a null-terminated C string.
+//
+// # Returns
+// - Pointer to removed [`Row`] or null if not found.
+// - Caller must free with `row_free`.
+Row *yad_remove_row(YAD *yad, const int8_t *name);
+
+// Serializes a [`YAD`] document into a byte buffer.
+//
+// # Safety
+// - `yad` must be a valid pointer to a [`YAD`].
+// - `out_bytes` must point to a valid buffer of at least `max_len` bytes.
+// - Returns the number of bytes written.
+size_t yad_serialize(const YAD *yad, uint8_t *out_bytes, size_t max_len);
+
+// Serializes a [`YAD`] into a newly allocated [`ByteBuffer`].
+//
+// Unlike `yad_serialize`, the output is never truncated and its length is
+// reported in the buffer itself.
+//
+// # Safety
+// - `yad` must be a valid pointer to a [`YAD`].
+//
+// # Returns
+// - A [`ByteBuffer`] with the serialized document; free it with `byte_buffer_free`.
+// - An empty buffer with a null `ptr` on error (see `yad_last_error`).
+ByteBuffer yad_as_buffer(const YAD *yad);
+
+// Deserializes a [`YAD`] document from a byte buffer.
+//
+// # Safety
+// - `bytes` must point to a valid buffer of length `len`.
+// - Returns null on failure. Allocated memory must be freed with `yad_free`.
+YAD *yad_deserialize(const uint8_t *bytes, size_t len);
+
+// Deserializes a [`YAD`] document from a byte buffer, like [`yad_deserialize`],
+// unless decoding it would allocate more than `max_alloc` bytes.
+//
+// Use it for untrusted input: the length is checked before the buffer is
+// copied, and the body again once its encodings are expanded (see
+// [`DecodeOptions::max_size`]).
+//
+// # Safety
+// - `buff` must point to a valid buffer of length `len`.
+// - Returns null on failure, with [`YadErrorCode::LimitExceeded`] recorded if
+//   the document is over the limit. Allocated memory must be freed with `yad_free`.
+YAD *yad_from_buffer_limited(const uint8_t *buff, size_t len, size_t max_alloc);
+
+// # YAD FFI – Row Accessors
+//
+// These functions provide C-compatible access to rows within a YAD document.
+// Retrieves a [`Row`] from a [`YAD`] by name.
+//
+// # Safety
+// - `yad` must be a valid pointer to a [`YAD`].
+// - `name` must be a null-terminated C string.
+// - Returns a pointer to a cloned [`Row`], or null if not found.
+// - Caller must free the returned row using `row_free`.
+Row *yad_get_row(const YAD *yad, const int8_t *name);
+
+// Removes a [`Row`] from a [`YAD`] by name.
+//
+// # Safety
+// - `yad` must be a valid pointer to a [`YAD`].
+// - `name` must be a null-terminated C string.
+// - Returns a pointer to the removed [`Row`] or null if not found.
+// - Caller must free the returned row using `row_free`.
+Row *yad_remove_row_by_name(YAD *yad, const int8_t *name);
+
+// Sets or replaces a [`Row`] in the [`YAD`] document.
+//
+// # Safety
+// - `yad` must be a valid pointer to a [`YAD`].
+// - `row` must be a valid pointer to a [`Row`].
+// - The row will be cloned into the document; original memory must still be freed separately if needed.
+void yad_set_row(YAD *yad,
+                 Row *row);
+
+// # YAD FFI – Row Utilities
+//
+// Additional helper functions for row management in a C-compatible manner.
+// Returns the number of rows in the YAD document.
+//
+// # Safety
+// - `yad` must be a valid pointer to a [`YAD`] document.
+size_t yad_row_count(const YAD *yad);
+
+// Returns a copy of the [`Row`] at position `index` of the [`YAD`] document.
+//
+// Rows are ordered by name, the same order as `yad_row_names` and
+// `yad_row_cursor_next`. Each call walks the document from the start; use a
+// row cursor to enumerate every row.
+//
+// # Safety
+// - `yad` must be a valid pointer to a [`YAD`] document.
+//
+// # Returns
+// - Pointer to a cloned [`Row`], or null if `index` is out of bounds.
+// - Caller must free the returned row using `row_free`.
+Row *yad_row_at(const YAD *yad, size_t index);
+
+// Returns a heap-allocated array of C strings representing the row names.
+//
+// # Safety
+// - `yad` must be a valid pointer to a [`YAD`] document.
+// - Returns a pointer to an array of `*const i8` (C strings).
+// - Caller is responsible for freeing each string with `CString::from_raw`
+//   and the array itself with `Box::from_raw`.
+int8_t **yad_row_names(const YAD *yad);
+
+// Frees the array of C strings returned by [`yad_row_names`].
+//
+// # Safety
+// - `names` must be a pointer returned by [`yad_row_names`].
+// - `count` must be the number of elements in the array.
+void yad_row_names_free(int8_t **names, size_t count);
+
+// Frees a C string returned by one of the `*_get_name_cstr` functions.
+//
+// # Safety
+// - `string` must be a pointer returned by this library and not freed before.
+// - Passing a null pointer is safe and does nothing.
+void yad_string_free(int8_t *string);
+
+// Creates a cursor over the rows of `yad`, positioned before the first row.
+//
+// # Safety
+// - `yad` must be a valid pointer to a [`YAD`] and must outlive the cursor.
+//
+// # Returns
+// - Pointer to a new cursor, or null if `yad` is null. Free it with `yad_row_cursor_free`.
+YadRowCursor *yad_row_cursor_new(const YAD *yad);
+
+// Advances the cursor and returns a copy of the next [`Row`].
+//
+// # Safety
+// - `cursor` must be a valid pointer returned by `yad_row_cursor_new`.
+//
+// # Returns
+// - Pointer to a cloned [`Row`], to be freed with `row_free`.
+// - Null once every row has been returned (no error is recorded), or if `cursor` is null.
+Row *yad_row_cursor_next(YadRowCursor *cursor);
+
+// Frees a cursor created by `yad_row_cursor_new`.
+//
+// # Safety
+// - `cursor` must be a valid pointer returned by `yad_row_cursor_new`, or null.
+void yad_row_cursor_free(YadRowCursor *cursor);
+
+// Creates a cursor over the keys of `row`, positioned before the first key.
+//
+// # Safety
+// - `row` must be a valid pointer to a [`Row`] and must outlive the cursor.
+//
+// # Returns
+// - Pointer to a new cursor, or null if `row` is null. Free it with `row_key_cursor_free`.
+RowKeyCursor *row_key_cursor_new(const Row *row);
+
+// Advances the cursor and returns a copy of the next [`Key`].
+//
+// # Safety
+// - `cursor` must be a valid pointer returned by `row_key_cursor_new`.
+//
+// # Returns
+// - Pointer to a cloned [`Key`], to be freed with `key_free`.
+// - Null once every key has been returned (no error is recorded), or if `cursor` is null.
+Key *row_key_cursor_next(RowKeyCursor *cursor);
+
+// Frees a cursor created by `row_key_cursor_new`.
+//
+// # Safety
+// - `cursor` must be a valid pointer returned by `row_key_cursor_new`, or null.
+void row_key_cursor_free(RowKeyCursor *cursor);
+
+// # Key FFI (C ABI)
+//
+// These functions provide a pure C-compatible interface for
+// creating, manipulating, and serializing [`Key`] objects.
+//
+// All functions use `#[unsafe(no_mangle)]` to export symbols
+// compatible with a C toolchain. All memory allocations must
+// be freed using the corresponding `_free` functions to prevent
+// leaks.
+// Creates a new [`Key`] instance from a C string and a [`Value`].
+//
+// # Safety
+// - `name` must be a valid null-terminated C string.
+// - `value` must be a valid pointer to a [`Value`] object.
+// - Returns a null pointer on error.
+//
+// # Parameters
+// - `name`: C string pointer representing the key name.
+// - `value`: Pointer to a [`Value`] object.
+//
+// # Returns
+// - Pointer to a heap-allocated [`Key`] object. Must be freed with `key_free`.
+Key *key_new(const int8_t *name, const Value *value);
+
+// Frees a [`Key`] previously allocated by `key_new`.
+//
+// # Safety
+// - `key` must be a valid pointer returned by `key_new`.
+// - Passing a null pointer is safe and has no effect.
+void key_free(Key *key);
+
+// Serializes a [`Key`] to an external byte buffer.
+//
+// Copies at most `max_len` bytes into `out_bytes`.
+//
+// # Safety
+// - `key` must be a valid pointer to a [`Key`].
+// - `out_bytes` must point to a valid writable buffer of at least `max_len` bytes.
+//
+// # Returns
+// - Number of bytes written to the buffer.
+size_t key_serialize(const Key *key, uint8_t *out_bytes, size_t max_len);
+
+// Serializes a [`Key`] into a newly allocated [`ByteBuffer`].
+//
+// Unlike `key_serialize`, the output is never truncated and its length is
+// reported in the buffer itself.
+//
+// # Safety
+// - `key` must be a valid pointer to a [`Key`].
+//
+// # Returns
+// - A [`ByteBuffer`] with the serialized key; free it with `byte_buffer_free`.
+// - An empty buffer with a null `ptr` on error (see `yad_last_error`).
+ByteBuffer key_as_buffer(const Key *key);
+
+// Deserializes a [`Key`] from a byte buffer.
+//
+// # Safety
+// - `bytes` must point to a valid buffer of length `len`.
+// - Returns null pointer if deserialization fails.
+//
+// # Parameters
+// - `bytes`: Pointer to serialized key bytes.
+// - `len`: Number of bytes in the buffer.
+//
+// # Returns
+// - Pointer to a newly allocated [`Key`], or null on error.
+Key *key_deserialize(const uint8_t *bytes, size_t len);
+
+// Returns a pointer to the UTF-8 bytes of the name of the [`Key`].
+//
+// The name is **not** null-terminated: read exactly `key_get_name_len`
+// bytes, or use `key_get_name_cstr` for a C string.
+//
+// # Safety
+// - `key` must be a valid pointer to a [`Key`].
+// - Returned pointer is valid as long as the `Key` is alive and unchanged.
+// - Do **not** free the returned pointer.
+//
+// # Returns
+// - Pointer to the first byte of the key's name.
+const int8_t *key_get_name(const Key *key);
+
+// Returns the length in bytes of the name of the [`Key`], without a terminator.
+//
+// # Safety
+// - `key` must be a valid pointer to a [`Key`].
+//
+// # Returns
+// - The number of bytes `key_get_name` points to, or `0` if `key` is null.
+size_t key_get_name_len(const Key *key);
+
+// Returns a copy of the name of the [`Key`] as a null-terminated C string.
+//
+// # Safety
+// - `key` must be a valid pointer to a [`Key`].
+//
+// # Returns
+// - A C string owned by the caller, who must free it with `yad_string_free`.
+// - Null if `key` is null or the name contains a null byte.
+int8_t *key_get_name_cstr(const Key *key);
+
+// Updates the [`Value`] of the given [`Key`].
+//
+// # Safety
+// - Both `key` and `value` must be valid pointers.
+// - `key` must be mutable.
+//
+// # Parameters
+// - `key`: Pointer to the [`Key`] to update.
+// - `value`: Pointer to the new [`Value`].
+void key_set_value(Key *key, const Value *value);
+
+// Returns a pointer to the [`Value`] of the given [`Key`].
+//
+// # Safety
+// - `key` must be a valid pointer to a [`Key`].
+// - Pointer is valid as long as `Key` is alive.
+//
+// # Returns
+// - Pointer to the internal [`Value`].
+const Value *key_get_value(const Key *key);
+
+// # Row FFI (C ABI)
+//
+// These functions provide a pure C-compatible interface for
+// creating, manipulating, and serializing [`Row`] objects.
+//
+// All functions use `#[unsafe(no_mangle)]` to export symbols
+// compatible with a C toolchain. Memory allocated by these
+// functions must be freed using `row_free` to avoid leaks.
+// Creates a new [`Row`] from a C string and a vector of [`Key`] pointers.
+//
+// # Safety
+// - `name` must be a valid null-terminated C string.
+// - `keys` is a pointer to an array of [`Key`] pointers of length `keys_len`.
+// - Any null pointer in `keys` array is ignored.
+// - Returns a null pointer on error.
+//
+// # Parameters
+// - `name`: C string representing the row name.
+// - `keys`: Pointer to an array of [`Key`] pointers.
+// - `keys_len`: Length of the `keys` array.
+//
+// # Returns
+// - Pointer to a heap-allocated [`Row`], must be freed with `row_free`.
+Row *row_new(const int8_t *name, Key *const *keys, size_t keys_len);
+
+// Frees a [`Row`] previously allocated by `row_new`.
+//
+// # Safety
+// - `row` must be a valid pointer returned by `row_new`.
+// - Passing a null pointer is safe and does nothing.
+void row_free(Row *row);
+
+// Inserts a [`Key`] into the [`Row`].
+//
+// If a key with the same name exists, it will be replaced.
+//
+// # Safety
+// - `row` must be a valid pointer to a [`Row`].
+// - `key` must be a valid pointer to a [`Key`].
+void row_insert_key(Row *row, Key *key);
+
+// Removes a [`Key`] from the [`Row`] by name.
+//
+// # Safety
+// - `row` must be a valid pointer to a [`Row`].
+// - `name` must be a valid null-terminated C string.
+//
+// # Returns
+// - Pointer to the removed [`Key`], or null if not found.
+// - Caller is responsible for freeing the returned key using `key_free`.
+Key *row_remove_key(Row *row, const int8_t *name);
+
+// Serializes a [`Row`] into an external byte buffer.
+//
+// Copies at most `max_len` bytes into `out_bytes`.
+//
+// # Safety
+// - `row` must be a valid pointer to a [`Row`].
+// - `out_bytes` must point to a valid writable buffer of at least `max_len` bytes.
+//
+// # Returns
+// - Number of bytes written to the buffer.
+size_t row_serialize(const Row *row, uint8_t *out_bytes, size_t max_len);
+
+// Serializes a [`Row`] into a newly allocated [`ByteBuffer`].
+//
+// Unlike `row_serialize`, the output is never truncated and its length is
+// reported in the buffer itself.
+//
+// # Safety
+// - `row` must be a valid pointer to a [`Row`].
+//
+// # Returns
+// - A [`ByteBuffer`] with the serialized row; free it with `byte_buffer_free`.
+// - An empty buffer with a null `ptr` on error (see `yad_last_error`).
+ByteBuffer row_as_buffer(const Row *row);
+
+// Deserializes a [`Row`] from a byte buffer.
+//
+// # Safety
+// - `bytes` must point to a valid buffer of length `len`.
+// - Returns null pointer if deserialization fails.
+//
+// # Parameters
+// - `bytes`: Pointer to serialized row bytes.
+// - `len`: Number of bytes in the buffer.
+//
+// # Returns
+// - Pointer to a newly allocated [`Row`], or null on error.
+Row *row_deserialize(const uint8_t *bytes, size_t len);
+
+// Returns the number of keys in the [`Row`].
+//
+// # Safety
+// - `row` must be a valid pointer to a [`Row`].
+size_t row_key_count(const Row *row);
+
+// Returns a copy of the [`Key`] at position `index` of the [`Row`].
+//
+// Keys are ordered by name, the same order as `row_key_cursor_next`. Each call
+// walks the row from the start; use a key cursor to enumerate every key.
+//
+// # Safety
+// - `row` must be a valid pointer to a [`Row`].
+//
+// # Returns
+// - Pointer to a cloned [`Key`], or null if `index` is out of bounds.
+// - Caller must free the returned key using `key_free`.
+Key *row_key_at(const Row *row, size_t index);
+
+// Returns the length in bytes of the name of the [`Row`], without a terminator.
+//
+// # Safety
+// - `row` must be a valid pointer to a [`Row`].
+//
+// # Returns
+// - The length of the row's name, or `0` if `row` is null.
+size_t row_get_name_len(const Row *row);
+
+// Returns a copy of the name of the [`Row`] as a null-terminated C string.
+//
+// # Safety
+// - `row` must be a valid pointer to a [`Row`].
+//
+// # Returns
+// - A C string owned by the caller, who must free it with `yad_string_free`.
+// - Null if `row` is null or the name contains a null byte.
+int8_t *row_get_name_cstr(const Row *row);
+
+// Returns the [`YAD_ABI_VERSION`] the library was built with.
+//
+// A shared library can be swapped under an application; compare this with
+// the `YAD_ABI_VERSION` of the header the application was compiled against
+// before making any other call.
+//
+// # Example
+// ```c
+// if (yad_abi_version() != YAD_ABI_VERSION) {
+//     fprintf(stderr, "incompatible libyad\n");
+//     exit(1);
+// }
+// ```
+uint32_t yad_abi_version(void);
+
+// Frees a [`ByteBuffer`] returned by one of the `*_as_buffer` functions.
+//
+// # Parameters
+// - `buf`: The buffer, passed by value with its fields unchanged.
+//
+// # Safety
+// - `buf` must come from this library and must not be freed twice.
+// - A buffer with a null `ptr` is ignored.
+//
+// # Example
+// ```c
+// ByteBuffer buf = yad_as_buffer(yad);
+// fwrite(buf.ptr, 1, buf.len, file);
+// byte_buffer_free(buf);
+// ```
+void byte_buffer_free(ByteBuffer buf);
+
+// Creates a new empty `CArray`.
+//
+// # Returns
+// - A raw pointer to a heap-allocated `CArray`.
+//
+// # Safety
+// - The returned pointer must eventually be freed using `free_c_array` to prevent memory leaks.
+CArray *c_array_new(void);
+
+// Inserts a `Value` into the `CArray` at the specified index.
+//
+// # Parameters
+// - `arr`: Pointer to the `CArray`.
+// - `index`: The position to insert the value.
+// - `value`: Pointer to the `Value` to insert.
+//
+// # Returns
+// - `true` if insertion succeeded.
+// - `false` if the array pointer or value pointer is null, or if the index is out of bounds.
+//
+// # Safety
+// - Both `arr` and `value` must be valid, non-null pointers.
+bool c_array_insert(CArray *arr, size_t index, Value *value);
+
+// Pushes a new `Value` into a `CArray`.
+//
+// # Parameters
+// - `arr`: A raw pointer to a mutable [`CArray`].
+// - `value`: A raw pointer to a heap-allocated [`Value`].
+//
+// # Returns
+// - `true` if the operation succeeded.
+// - `false` if:
+//   - Either pointer is null.
+//   - The array has reached `isize::MAX` capacity (overflow safeguard).
+//
+// # Behavior
+// - Takes ownership of the `value` pointer (frees the original `Box`).
+// - Converts the internal raw pointer of the `CArray` into a temporary [`Vec<Value>`].
+// - If the vector is at capacity, calls [`Vec::reserve`] to allocate more space.
+// - Pushes the new value into the vector.
+// - Updates the `ptr`, `len`, and `cap` fields of the `CArray` with the new vector state.
+// - Calls [`std::mem::forget`] to prevent the temporary `Vec` from freeing its buffer,
+//   since ownership is transferred back to the `CArray`.
+//
+// # Safety
+// - `arr` must be a valid, non-null pointer to a properly initialized `CArray`.
+// - `value` must be a valid, non-null pointer to a heap-allocated `Value`.
+// - After this call, the caller must not use the original `value` pointer again,
+//   as its ownership has been transferred.
+// - Misuse can lead to undefined behavior, memory leaks, or double frees.
+//
+// # Example (pseudo-usage in C/FFI)
+// ```c
+// CArray* arr = c_array_new();
+// Value* val = value_from_int(42);
+// bool ok = c_array_push(arr, val);
+// ```
+bool c_array_push(CArray *arr, Value *value);
+
+// Returns a heap-allocated clone of the `Value` stored in the `CArray` at `index`.
+//
+// This function does **not** expose a direct pointer into the internal buffer. Instead,
+// it clones the element and returns ownership of a newly allocated `Value*` to the caller.
+// The caller is responsible for destroying that `Value*` with this library's designated
+// deallocator (e.g., `value_free`).
+//
+// # Parameters
+// - `arr`: Pointer to a valid `CArray` whose internal buffer was created by this library.
+// - `index`: Zero-based index of the element to retrieve.
+//
+// # Returns
+// - On success: a non-null `*mut Value` pointing to a freshly allocated clone of the element.
+// - On failure (null `arr`, out-of-bounds index, or panic during clone): `null_mut()`.
+//
+// # Complexity
+// - O(1) for indexing, plus the cost of `Value::clone()`.
+//
+// # Ownership & Lifetime
+// - The returned pointer owns its storage and must be freed by the caller using the
+//   appropriate destructor from this library.
+// - This function does **not** transfer or modify ownership of the underlying `CArray` buffer.
+//
+// # Safety
+// - `arr` must be a non-null pointer to a well-formed `CArray` with fields (`ptr`, `len`, `cap`)
+//   describing a buffer allocated by the same Rust allocator.
+// - No other thread may mutate the `CArray` concurrently while this function executes.
+// - `Value` must implement `Clone` and cloning must not unwind across the FFI boundary.
+// - Invoking this function with an invalid `arr` or corrupted invariants constitutes undefined behavior.
+//
+// # Notes
+// - The implementation now avoids temporary ownership of the buffer by using
+//   `slice::from_raw_parts` instead of `Vec::from_raw_parts`.
+Value *c_array_get(CArray *arr,
+                   size_t index);
+
+// Removes a value from the `CArray` at the specified index.
+//
+// # Parameters
+// - `arr`: Pointer to the `CArray`.
+// - `index`: Index of the element to remove.
+// - `out`: Optional pointer to a `Value` where the removed element will be written.
+//
+// # Returns
+// - `true` if removal succeeded.
+// - `false` if the index is out of bounds or `arr` is null.
+//
+// # Safety
+// - `arr` must be a valid pointer.
+// - `out` can be null if the removed value does not need to be retrieved.
+bool c_array_remove(CArray *arr, size_t index, Value *out);
+
+// Returns the number of elements in the `CArray`.
+//
+// # Parameters
+// - `arr`: Pointer to the `CArray`.
+//
+// # Returns
+// - Length of the array, or 0 if the pointer is null.
+size_t c_array_length(CArray *arr);
+
+// Returns the capacity of the `CArray`.
+//
+// # Parameters
+// - `arr`: Pointer to the `CArray`.
+//
+// # Returns
+// - Capacity of the array, or 0 if the pointer is null.
+size_t c_array_capacity(CArray *arr);
+
+// Returns a raw pointer to the internal buffer of the CArray and its length.
+//
+// # Parameters
+// - `arr`: Pointer to a valid CArray.
+//
+// # Returns
+// - Tuple `(ptr, len)` as `(Value**, usize)`; returns `(null_mut(), 0)` if `arr` is null.
+//
+// # Safety
+// - The caller must not mutate the returned pointers.
+// - The caller does not own the Values; they are still owned by the CArray.
+// - Thread-safety: the CArray must not be mutated concurrently.
+Value **c_array_as_ptr(CArray *arr, size_t *out_len);
+
+// Frees a `CArray` and its underlying memory.
+//
+// # Parameters
+// - `arr`: Pointer to the `CArray` to free.
+//
+// # Safety
+// - `arr` must be a pointer previously returned by `c_array_new`.
+// - After calling this function, `arr` must not be used again.
+void free_c_array(CArray *arr);
+
+// Returns the last error reported on the calling thread.
+//
+// Functions only record errors, they never clear them: check the result of a
+// call first (null, `0` or `false`) and only then ask for the reason.
+//
+// # Returns
+// - A [`YadError`] whose `message` stays valid until the next error is recorded or cleared on this thread.
+// - `{ YadErrorCode::Ok, null }` if no error has been recorded.
+//
+// # Example
+// ```c
+// Value* val = value_from_buffer(buf, len);
+// if (val == NULL) {
+//     YadError err = yad_last_error();
+//     fprintf(stderr, "%d: %s\n", err.code, err.message);
+// }
+// ```
+YadError yad_last_error(void);
+
+// Clears the last error of the calling thread.
+void yad_clear_last_error(void);
+
+// Frees a `Value` previously allocated on the heap.
+//
+// # Parameters
+// - `val`: Pointer to a `Value`.
+//
+// # Safety
+// - `val` must be a pointer previously returned from a creation function.
+// - After calling this function, the pointer must not be used again.
+void value_free(Value *val);
+
+// Decodes a buffer of bytes into a heap-allocated `Value`.
+//
+// # Safety
+// - The caller must ensure that `buff` points to a valid memory region of at least `len` bytes.
+// - The returned pointer transfers ownership of the `Value` to the caller.
+//   The caller is responsible for freeing it with `Box::from_raw` or a dedicated free function.
+//
+// # Parameters
+// - `buff`: Pointer to a contiguous buffer of `u8` containing the encoded value.
+// - `len`: Length of the buffer in bytes.
+//
+// # Returns
+// - A raw pointer to a heap-allocated `Value` on success.
+// - Returns `null` if `buff` is null, `len` is zero, or decoding fails.
+//
+// # Notes
+// - The raw buffer is decoded in place with `Value::decode_in_place`; only the decoded value is copied.
+// - Ownership of the returned `Value` is fully transferred; Rust will not automatically free it.
+// - The caller must ensure proper deallocation to avoid memory leaks.
+Value *value_from_buffer(const uint8_t *buff,
+                         size_t len);
+
+// Decodes a buffer of bytes into a heap-allocated `Value`, like
+// [`value_from_buffer`], unless the value would take more than `max_alloc` bytes.
+//
+// # Safety
+// - The caller must ensure that `buff` points to a valid memory region of at least `len` bytes.
+// - The returned pointer must be freed with `value_free`.
+//
+// # Parameters
+// - `buff`: Pointer to a contiguous buffer of `u8` containing the encoded value.
+// - `len`: Length of the buffer in bytes.
+// - `max_alloc`: Largest encoded value, in bytes, the call may allocate.
+//
+// # Returns
+// - A raw pointer to a heap-allocated `Value` on success.
+// - Returns `null` if `buff` is null, `len` is zero, or decoding fails. A value
+//   over the limit is reported as [`YadErrorCode::LimitExceeded`] before anything is allocated.
+Value *value_from_buffer_limited(const uint8_t *buff, size_t len, size_t max_alloc);
+
+// Returns the type of the `Value`.
+//
+// # Parameters
+// - `val`: Pointer to a `Value`.
+//
+// # Returns
+// - A `u8` representing the type.
+// - Returns `0` if `val` is null.
+//
+// # Safety
+// - `val` must be a valid pointer or null.
+uint8_t value_type(Value *val);
+
+// Returns the length of the `Value` in bytes (or its logical length).
+//
+// # Parameters
+// - `val`: Pointer to a `Value`.
+//
+// # Returns
+// - Length as `u8`.
+// - Returns `0` if `val` is null.
+//
+// # Safety
+// - `val` must be a valid pointer or null.
+uint8_t value_len(Value *val);
+
+// Returns a raw pointer to the underlying bytes of the `Value`.
+//
+// # Parameters
+// - `val`: Pointer to a `Value`.
+//
+// # Returns
+// - Pointer to the first byte of the value, or null if `val` is null.
+//
+// # Safety
+// - The returned pointer is valid as long as the `Value` is alive.
+// - Modifying the memory through this pointer may cause undefined behavior.
+const uint8_t *value_raw_bytes(Value *val);
+
+// Returns the length of the raw byte buffer of the `Value`.
+//
+// # Parameters
+// - `val`: Pointer to a `Value`.
+//
+// # Returns
+// - Number of bytes in the `Value`, or `0` if `val` is null.
+//
+// # Safety
+// - `val` must be a valid pointer or null.
+size_t value_raw_bytes_length(Value *val);
+
+// Returns a copy of the encoded bytes of the `Value`.
+//
+// # Parameters
+// - `val`: Pointer to a `Value`.
+//
+// # Returns
+// - A [`ByteBuffer`] holding the encoded value; free it with `byte_buffer_free`.
+// - An empty buffer with a null `ptr` if `val` is null.
+//
+// # Safety
+// - `val` must be a valid pointer or null.
+ByteBuffer value_as_buffer(Value *val);
+
+// Converts an 8-bit unsigned integer (`u8`) into a heap-allocated [`Value`] pointer
+// suitable for FFI usage.
+//
+// # Parameters
+// - `val`: The 8-bit unsigned integer to wrap.
+//
+// # Returns
+// A raw pointer to a [`Value`] containing the provided `u8`.
+//
+// # Safety
+// - The caller is responsible for freeing the returned pointer to avoid memory leaks.
+// - The pointer is valid for FFI usage but must not be dereferenced without validation.
+Value *value_from_uint_8(uint8_t val);
+
+// Converts an 8-bit signed integer (`i8`) into a heap-allocated [`Value`] pointer
+// suitable for FFI usage.
+//
+// # Parameters
+// - `val`: The 8-bit signed integer to wrap.
+//
+// # Returns
+// A raw pointer to a [`Value`] containing the provided `i8`.
+//
+// # Safety
+// - The caller must free the returned pointer to avoid memory leaks.
+Value *value_from_int_8(int8_t val);
+
+// Converts a 32-bit floating point number (`f32`) into a compact [`F8E4M3`] format,
+// then wraps it in a heap-allocated [`Value`] pointer for FFI.
+//
+// # Parameters
+// - `val`: The `f32` value to convert.
+//
+// # Returns
+// A raw pointer to a [`Value`] containing the `F8E4M3` representation.
+//
+// # Safety
+// - The caller must free the pointer to avoid memory leaks.
+// - Precision may be lost due to the reduced bit representation of `F8E4M3`.
+Value *value_as_f8_from_float(float val);
+
+// Attempts to extract a `u8` from a [`Value`] pointer and writes it to the provided output pointer.
+//
+// # Parameters
+// - `value`: Pointer to the [`Value`] to extract from.
+// - `out`: Pointer to a `u8` where the result will be written.
+//
+// # Returns
+// - `true` if extraction succeeded.
+// - `false` if either pointer is null or the conversion failed.
+//
+// # Safety
+// - Both pointers must be valid and non-null.
+// - Dereferencing a null pointer is undefined behavior.
+bool uint8_from_value(Value *value, uint8_t *out);
+
+// Attempts to extract an `i8` from a [`Value`] pointer and writes it to the provided output pointer.
+//
+// # Parameters
+// - `value`: Pointer to the [`Value`] to extract from.
+// - `out`: Pointer to an `i8` where the result will be written.
+//
+// # Returns
+// - `true` if extraction succeeded.
+// - `false` if either pointer is null or the conversion failed.
+//
+// # Safety
+// - Both pointers must be valid and non-null.
+// - Dereferencing a null pointer is undefined behavior.
+bool int8_from_value(Value *value,
+                     int8_t *out);
+
+// Attempts to extract an [`F8E4M3`] floating point from a [`Value`] pointer,
+// convert it to `f32`, and write it to the provided output pointer.
+//
+// # Parameters
+// - `value`: Pointer to the [`Value`] to extract from.
+// - `out`: Pointer to a `f32` where the result will be written.
+//
+// # Returns
+// - `true` if extraction and conversion succeeded.
+// - `false` if either pointer is null or the conversion failed.
+//
+// # Safety
+// - Both pointers must be valid and non-null.
+// - Dereferencing invalid pointers is undefined behavior.
+// - Precision may be lost due to the limited bits of the `F8E4M3` format.
+bool float_from_f8_value(Value *value, float *out);
+
+// Creates a new [`Value`] containing an unsigned 32-bit integer (`u32`)
+// and returns a raw pointer suitable for FFI.
+//
+// # Parameters
+// - `val`: The `u32` value to wrap.
+//
+// # Returns
+// A raw pointer to a heap-allocated [`Value`] containing `val`.
+// Caller is responsible for freeing this pointer to prevent memory leaks.
+//
+// # Safety
+// - The pointer is heap-allocated and must be manually deallocated.
+// - Must not be dereferenced after being freed.
+Value *value_from_uint_32(uint32_t val);
+
+// Creates a new [`Value`] containing a signed 32-bit integer (`i32`)
+// and returns a raw pointer suitable for FFI.
+//
+// # Parameters
+// - `val`: The `i32` value to wrap.
+//
+// # Returns
+// A raw pointer to a heap-allocated [`Value`] containing `val`.
+// Caller is responsible for freeing this pointer.
+//
+// # Safety
+// - The pointer must be manually deallocated to prevent memory leaks.
+// - Must not be used after being freed.
+Value *value_from_int_32(int32_t val);
+
+// Creates a new [`Value`] containing a 32-bit floating point (`f32`)
+// and returns a raw pointer suitable for FFI.
+//
+// # Parameters
+// - `val`: The `f32` value to wrap.
+//
+// # Returns
+// A raw pointer to a heap-allocated [`Value`] containing `val`.
+// Caller is responsible for freeing this pointer.
+//
+// # Safety
+// - The pointer is heap-allocated and must be manually freed.
+// - Must not be dereferenced after being freed.
+Value *value_from_float(float val);
+
+// Extracts a 32-bit floating point (`f32`) from a [`Value`] and writes it
+// into the provided pointer.
+//
+// # Parameters
+// - `value`: Pointer to a [`Value`] expected to contain an `f32`.
+// - `out`: Pointer to a `f32` where the extracted value will be stored.
+//
+// # Returns
+// - `true` if extraction succeeds.
+// - `false` if `value` is null or does not contain a valid `f32`.
+//
+// # Safety
+// - Both `value` and `out` must be valid, non-null pointers.
+// - `out` must point to a valid writable memory location.
+bool float_from_value(Value *value, float *out);
+
+// Extracts an unsigned 32-bit integer (`u32`) from a [`Value`] and writes
+// it into the provided pointer.
+//
+// # Parameters
+// - `value`: Pointer to a [`Value`] expected to contain a `u32`.
+// - `out`: Pointer to a `u32` where the extracted value will be stored.
+//
+// # Returns
+// - `true` if extraction succeeds.
+// - `false` if `value` is null or does not contain a valid `u32`.
+//
+// # Safety
+// - Both `value` and `out` must be valid, non-null pointers.
+// - `out` must point to a valid writable memory location.
+bool uint32_from_value(Value *value, uint32_t *out);
+
+// Extracts a signed 32-bit integer (`i32`) from a [`Value`] and writes
+// it into the provided pointer.
+//
+// # Parameters
+// - `value`: Pointer to a [`Value`] expected to contain an `i32`.
+// - `out`: Pointer to an `i32` where the extracted value will be stored.
+//
+// # Returns
+// - `true` if extraction succeeds.
+// - `false` if `value` is null or does not contain a valid `i32`.
+//
+// # Safety
+// - Both `value` and `out` must be valid, non-null pointers.
+// - `out` must point to a valid writable memory location.
+bool int32_from_value(Value *value, int32_t *out);
+
+// Creates a new [`Value`] containing an unsigned 16-bit integer (`u16`)
+// and returns a raw pointer suitable for FFI.
+//
+// # Parameters
+// - `val`: The `u16` value to wrap.
+//
+// # Returns
+// A raw pointer to a heap-allocated [`Value`] containing `val`.
+// The caller is responsible for freeing this pointer to prevent memory leaks.
+//
+// # Safety
+// - The pointer is allocated on the heap and must be manually deallocated.
+// - Must not be dereferenced after being freed.
+Value *value_from_uint_16(uint16_t val);
+
+// Creates a new [`Value`] containing a signed 16-bit integer (`i16`)
+// and returns a raw pointer suitable for FFI.
+//
+// # Parameters
+// - `val`: The `i16` value to wrap.
+//
+// # Returns
+// A raw pointer to a heap-allocated [`Value`] containing `val`.
+// The caller is responsible for freeing this pointer to prevent memory leaks.
+//
+// # Safety
+// - The pointer must be properly deallocated.
+// - Must not be dereferenced after being freed.
+Value *value_from_int_16(int16_t val);
+
+// Creates a new [`Value`] representing a 16-bit floating point (`f16`)
+// by converting a 32-bit float (`f32`) to `f16`.
+//
+// # Parameters
+// - `val`: The `f32` value to convert.
+//
+// # Returns
+// A raw pointer to a heap-allocated [`Value`] containing the `f16`.
+// The caller is responsible for freeing this pointer.
+//
+// # Safety
+// - The pointer must be manually deallocated.
+// - Must not be dereferenced after being freed.
+// - Precision loss may occur during the conversion from `f32` to `f16`.
+Value *value_as_f16_from_float(float val);
+
+// Extracts a 16-bit floating point value (`f16`) from a [`Value`] and
+// writes it as a 32-bit float (`f32`) into the provided pointer.
+//
+// # Parameters
+// - `value`: Pointer to a [`Value`] expected to contain an `f16`.
+// - `out`: Pointer to a `f32` where the result will be stored.
+//
+// # Returns
+// - `true` if extraction and conversion succeed.
+// - `false` if `value` is null or does not contain a valid `f16`.
+//
+// # Safety
+// - Both `value` and `out` must be valid, non-null pointers.
+// - `out` must point to a valid writable memory location.
+bool float_from_f16_value(Value *value, float *out);
+
+// Extracts an unsigned 16-bit integer (`u16`) from a [`Value`] and writes
+// it into the provided pointer.
+//
+// # Parameters
+// - `value`: Pointer to a [`Value`] expected to contain a `u16`.
+// - `out`: Pointer to a `u16` where the result will be stored.
+//
+// # Returns
+// - `true` if extraction succeeds.
+// - `false` if `value` is null or does not contain a valid `u16`.
+//
+// # Safety
+// - Both `value` and `out` must be valid, non-null pointers.
+// - `out` must point to a valid writable memory location.
+bool uint16_from_value(Value *value, uint16_t *out);
+
+// Extracts a signed 16-bit integer (`i16`) from a [`Value`] and writes
+// it into the provided pointer.
+//
+// # Parameters
+// - `value`: Pointer to a [`Value`] expected to contain an `i16`.
+// - `out`: Pointer to an `i16` where the result will be stored.
+//
+// # Returns
+// - `true` if extraction succeeds.
+// - `false` if `value` is null or does not contain a valid `i16`.
+//
+// # Safety
+// - Both `value` and `out` must be valid, non-null pointers.
+// - `out` must point to a valid writable memory location.
+bool int16_from_value(Value *value, int16_t *out);
+
+// Creates a new [`Value`] containing an unsigned 64-bit integer (`u64`)
+// and returns a raw pointer suitable for FFI.
+//
+// # Parameters
+// - `val`: The `u64` value to wrap.
+//
+// # Returns
+// A raw pointer to a heap-allocated [`Value`] containing `val`.
+// Caller is responsible for freeing this pointer to avoid memory leaks.
+//
+// # Safety
+// - The pointer is heap-allocated and must be manually deallocated.
+// - Must not be dereferenced after being freed.
+Value *value_from_uint_64(uint64_t val);
+
+// Creates a new [`Value`] containing a signed 64-bit integer (`i64`)
+// and returns a raw pointer suitable for FFI.
+//
+// # Parameters
+// - `val`: The `i64` value to wrap.
+//
+// # Returns
+// A raw pointer to a heap-allocated [`Value`] containing `val`.
+// Caller is responsible for freeing this pointer.
+//
+// # Safety
+// - The pointer must be manually managed by the caller.
+// - Must not be dereferenced after being freed.
+Value *value_from_int_64(int64_t val);
+
+// Creates a new [`Value`] containing a 64-bit floating point (`f64`)
+// and returns a raw pointer suitable for FFI.
+//
+// # Parameters
+// - `val`: The `f64` value to wrap.
+//
+// # Returns
+// A raw pointer to a heap-allocated [`Value`] containing `val`.
+// Caller is responsible for freeing this pointer.
+//
+// # Safety
+// - The pointer is heap-allocated and must be manually freed.
+// - Must not be dereferenced after being freed.
+Value *value_from_double(double val);
+
+// Extracts a 64-bit floating point (`f64`) from a [`Value`] and writes it
+// into the provided pointer.
+//
+// # Parameters
+// - `value`: Pointer to a [`Value`] expected to contain an `f64`.
+// - `out`: Pointer to a `f64` where the extracted value will be stored.
+//
+// # Returns
+// - `true` if extraction succeeds.
+// - `false` if `value` is null or does not contain a valid `f64`.
+//
+// # Safety
+// - Both `value` and `out` must be valid, non-null pointers.
+// - `out` must point to a valid writable memory location.
+bool double_from_value(Value *value, double *out);
+
+// Extracts an unsigned 64-bit integer (`u64`) from a [`Value`] and writes
+// it into the provided pointer.
+//
+// # Parameters
+// - `value`: Pointer to a [`Value`] expected to contain a `u64`.
+// - `out`: Pointer to a `u64` where the extracted value will be stored.
+//
+// # Returns
+// - `true` if extraction succeeds.
+// - `false` if `value` is null or does not contain a valid `u64`.
+//
+// # Safety
+// - Both `value` and `out` must be valid, non-null pointers.
+// - `out` must point to a valid writable memory location.
+bool uint64_from_value(Value *value, uint64_t *out);
+
+// Extracts a signed 64-bit integer (`i64`) from a [`Value`] and writes
+// it into the provided pointer.
+//
+// # Parameters
+// - `value`: Pointer to a [`Value`] expected to contain an `i64`.
+// - `out`: Pointer to an `i64` where the extracted value will be stored.
+//
+// # Returns
+// - `true` if extraction succeeds.
+// - `false` if `value` is null or does not contain a valid `i64`.
+//
+// # Safety
+// - Both `value` and `out` must be valid, non-null pointers.
+// - `out` must point to a valid writable memory location.
+bool int64_from_value(Value *value, int64_t *out);
+
+// Creates a heap-allocated [`Value`] from a Rust boolean.
+//
+// # Parameters
+// - `val`: Rust `bool` to wrap inside a [`Value`].
+//
+// # Returns
+// - Pointer to a heap-allocated [`Value`] representing the boolean.
+// - Never returns null in normal operation (conversion cannot fail for bools).
+//
+// # Safety
+// - The returned pointer must eventually be freed with `value_free` to avoid memory leaks.
+// - Pointer must not be dereferenced after being freed.
+Value *value_from_bool(bool val);
+
+// Extracts a Rust boolean from a heap-allocated [`Value`].
+//
+// # Parameters
+// - `value`: Pointer to a [`Value`] expected to contain a boolean.
+// - `out`: Pointer to a `bool` where the result will be written.
+//
+// # Returns
+// - `true` if extraction succeeded.
+// - `false` if `value` is null or does not contain a valid boolean.
+//
+// # Safety
+// - Both `value` and `out` must be valid, non-null pointers.
+// - Caller must ensure that `out` points to a valid writable memory location.
+bool bool_from_value(Value *value, bool *out);
+
+// Creates a heap-allocated [`Value`] from a C string (`*const c_char`).
+//
+// # Parameters
+// - `c_string`: Pointer to a null-terminated C string.
+//
+// # Returns
+// - Pointer to a heap-allocated [`Value`] containing the string.
+// - Returns `null` if the input pointer is null or the conversion fails.
+//
+// # Safety
+// - `c_string` must point to a valid null-terminated C string or be null.
+// - The returned pointer must eventually be freed with `value_free` to avoid memory leaks.
+// - Pointer must not be dereferenced after being freed.
+Value *value_from_cstring(const char *c_string);
+
+// Converts a [`Value`] containing a Rust string into a C string (`*const c_char`).
+//
+// # Parameters
+// - `value`: Pointer to a [`Value`] expected to contain a string.
+//
+// # Returns
+// - Pointer to a null-terminated C string allocated on the heap.
+// - Returns `null` if `value` is null or conversion fails.
+//
+// # Safety
+// - The returned C string must be freed using [`cstring_free`] when no longer needed.
+// - Pointer must not be used after being freed.
+const char *cstring_from_value(Value *value);
+
+// Frees a C string previously allocated by [`cstring_from_value`].
+//
+// # Parameters
+// - `cstr`: Pointer to a heap-allocated C string.
+//
+// # Safety
+// - `cstr` must be a valid pointer returned by [`cstring_from_value`].
+// - After calling this function, `cstr` must not be used again.
+void cstring_free(char *cstr);
+
+// Returns the length of a string contained within a [`Value`] as a C-compatible size.
+//
+// # Parameters
+// - `value`: Pointer to a [`Value`] expected to contain a string. Can be null.
+//
+// # Returns
+// - `usize`: Length of the string contained in the `Value`.
+// - Returns `0` if `value` is null or not a string.
+//
+// # Safety
+// - `value` must be a valid pointer or null.
+// - The memory pointed to by `value` must remain valid for the duration of the call.
+size_t cstring_len_from_value(Value *value);
+
+// Converts a C-compatible array (`CArray`) into a heap-allocated [`Value`].
+//
+// # Parameters
+// - `c_arr`: Pointer to a `CArray` containing array elements.
+//
+// # Returns
+// - A raw pointer to a heap-allocated [`Value`] containing the array data.
+// - Returns `null` if the input pointer is null or the conversion fails.
+//
+// # Safety
+// - `c_arr` must be a valid pointer or null.
+// - The caller must free the returned pointer with `value_free` to avoid memory leaks.
+// - Ownership of the array memory is transferred temporarily; the original `CArray` should not be used after this call.
+Value *value_from_c_array(CArray *c_arr);
+
+// Converts a heap-allocated [`Value`] containing an array into a C-compatible `CArray`.
+//
+// # Parameters
+// - `val`: Pointer to a [`Value`] expected to contain an array.
+//
+// # Returns
+// - A raw pointer to a heap-allocated `CArray` containing the array data.
+// - Returns `null` if the input pointer is null or the [`Value`] is not an array.
+//
+// # Safety
+// - `val` must be a valid pointer or null.
+// - The returned `CArray` must be freed with `free_c_array` to avoid memory leaks.
+// - Memory inside the original [`Value`] remains managed by Rust; this exposes the array contents as a `CArray`.
+CArray *c_array_from_value(Value *val);
+
+// Creates an array [`Value`] in one call from a contiguous buffer of `u8`,
+// each element becoming an unsigned 8-bit integer.
+//
+// # Parameters
+// - `ptr`: Pointer to the first element.
+// - `len`: Number of elements.
+//
+// # Returns
+// - A raw pointer to a heap-allocated array [`Value`]; free it with `value_free`.
+// - Returns `null` if `ptr` is null or `len` is zero.
+//
+// # Safety
+// - `ptr` must point to at least `len` elements. The buffer is only read.
+Value *value_from_uint8_array(const uint8_t *ptr, size_t len);
+
+// Creates an array [`Value`] in one call from a contiguous buffer of `i8`,
+// each element becoming a signed 8-bit integer.
+//
+// # Parameters
+// - `ptr`: Pointer to the first element.
+// - `len`: Number of elements.
+//
+// # Returns
+// - A raw pointer to a heap-allocated array [`Value`]; free it with `value_free`.
+// - Returns `null` if `ptr` is null or `len` is zero.
+//
+// # Safety
+// - `ptr` must point to at least `len` elements. The buffer is only read.
+Value *value_from_int8_array(const int8_t *ptr, size_t len);
+
+// Creates an array [`Value`] in one call from a contiguous buffer of `u16`,
+// each element becoming an unsigned 16-bit integer.
+//
+// # Parameters
+// - `ptr`: Pointer to the first element.
+// - `len`: Number of elements.
+//
+// # Returns
+// - A raw pointer to a heap-allocated array [`Value`]; free it with `value_free`.
+// - Returns `null` if `ptr` is null or `len` is zero.
+//
+// # Safety
+// - `ptr` must point to at least `len` elements. The buffer is only read.
+Value *value_from_uint16_array(const uint16_t *ptr, size_t len);
+
+// Creates an array [`Value`] in one call from a contiguous buffer of `i16`,
+// each element becoming a signed 16-bit integer.
+//
+// # Parameters
+// - `ptr`: Pointer to the first element.
+// - `len`: Number of elements.
+//
+// # Returns
+// - A raw pointer to a heap-allocated array [`Value`]; free it with `value_free`.
+// - Returns `null` if `ptr` is null or `len` is zero.
+//
+// # Safety
+// - `ptr` must point to at least `len` elements. The buffer is only read.
+Value *value_from_int16_array(const int16_t *ptr, size_t len);
+
+// Creates an array [`Value`] in one call from a contiguous buffer of `u32`,
+// each element becoming an unsigned 32-bit integer.
+//
+// # Parameters
+// - `ptr`: Pointer to the first element.
+// - `len`: Number of elements.
+//
+// # Returns
+// - A raw pointer to a heap-allocated array [`Value`]; free it with `value_free`.
+// - Returns `null` if `ptr` is null or `len` is zero.
+//
+// # Safety
+// - `ptr` must point to at least `len` elements. The buffer is only read.
+Value *value_from_uint32_array(const uint32_t *ptr, size_t len);
+
+// Creates an array [`Value`] in one call from a contiguous buffer of `i32`,
+// each element becoming a signed 32-bit integer.
+//
+// # Parameters
+// - `ptr`: Pointer to the first element.
+// - `len`: Number of elements.
+//
+// # Returns
+// - A raw pointer to a heap-allocated array [`Value`]; free it with `value_free`.
+// - Returns `null` if `ptr` is null or `len` is zero.
+//
+// # Safety
+// - `ptr` must point to at least `len` elements. The buffer is only read.
+Value *value_from_int32_array(const int32_t *ptr, size_t len);
+
+// Creates an array [`Value`] in one call from a contiguous buffer of `u64`,
+// each element becoming an unsigned 64-bit integer.
+//
+// # Parameters
+// - `ptr`: Pointer to the first element.
+// - `len`: Number of elements.
+//
+// # Returns
+// - A raw pointer to a heap-allocated array [`Value`]; free it with `value_free`.
+// - Returns `null` if `ptr` is null or `len` is zero.
+//
+// # Safety
+// - `ptr` must point to at least `len` elements. The buffer is only read.
+Value *value_from_uint64_array(const uint64_t *ptr, size_t len);
+
+// Creates an array [`Value`] in one call from a contiguous buffer of `i64`,
+// each element becoming a signed 64-bit integer.
+//
+// # Parameters
+// - `ptr`: Pointer to the first element.
+// - `len`: Number of elements.
+//
+// # Returns
+// - A raw pointer to a heap-allocated array [`Value`]; free it with `value_free`.
+// - Returns `null` if `ptr` is null or `len` is zero.
+//
+// # Safety
+// - `ptr` must point to at least `len` elements. The buffer is only read.
+Value *value_from_int64_array(const int64_t *ptr, size_t len);
+
+// Creates an array [`Value`] in one call from a contiguous buffer of `f32`,
+// each element becoming a 32-bit float.
+//
+// # Parameters
+// - `ptr`: Pointer to the first element.
+// - `len`: Number of elements.
+//
+// # Returns
+// - A raw pointer to a heap-allocated array [`Value`]; free it with `value_free`.
+// - Returns `null` if `ptr` is null or `len` is zero.
+//
+// # Safety
+// - `ptr` must point to at least `len` elements. The buffer is only read.
+Value *value_from_float_array(const float *ptr, size_t len);
+
+// Creates an array [`Value`] in one call from a contiguous buffer of `f64`,
+// each element becoming a 64-bit float.
+//
+// # Parameters
+// - `ptr`: Pointer to the first element.
+// - `len`: Number of elements.
+//
+// # Returns
+// - A raw pointer to a heap-allocated array [`Value`]; free it with `value_free`.
+// - Returns `null` if `ptr` is null or `len` is zero.
+//
+// # Safety
+// - `ptr` must point to at least `len` elements. The buffer is only read.
+Value *value_from_double_array(const double *ptr, size_t len);
+
+// Creates an array [`Value`] in one call from a contiguous buffer of `bool`,
+// each element becoming a boolean.
+//
+// # Parameters
+// - `ptr`: Pointer to the first element.
+// - `len`: Number of elements.
+//
+// # Returns
+// - A raw pointer to a heap-allocated array [`Value`]; free it with `value_free`.
+// - Returns `null` if `ptr` is null or `len` is zero.
+//
+// # Safety
+// - `ptr` must point to at least `len` elements. The buffer is only read.
+Value *value_from_bool_array(const bool *ptr, size_t len);
+
+// Creates a heap-allocated extension [`Value`] of kind `tag` holding `len`
+// bytes of data read from `data`.
+//
+// # Parameters
+// - `tag`: The tag naming the kind of the value.
+// - `data`: Pointer to the data, or null if `len` is `0`.
+// - `len`: Length of the data in bytes; may be `0`.
+//
+// # Returns
+// - A raw pointer to a heap-allocated [`Value`]; free it with `value_free`.
+// - Returns `null` if `data` is null while `len` is not zero.
+//
+// # Safety
+// - `data` must point to at least `len` bytes. The buffer is only read.
+Value *value_from_extension(uint8_t tag, const uint8_t *data, size_t len);
+
+// Extracts the tag of an extension [`Value`].
+//
+// # Parameters
+// - `value`: Pointer to a [`Value`] expected to be an extension value.
+// - `out`: Pointer to a `u8` where the tag will be written.
+//
+// # Returns
+// - `true` if extraction succeeded.
+// - `false` if a pointer is null or `value` is not an extension value.
+//
+// # Safety
+// - Both `value` and `out` must be valid, non-null pointers.
+bool extension_tag_from_value(Value *value, uint8_t *out);
+
+// Returns a copy of the data of an extension [`Value`], without its tag.
+//
+// # Parameters
+// - `value`: Pointer to a [`Value`] expected to be an extension value.
+//
+// # Returns
+// - A [`ByteBuffer`] holding the data; free it with `byte_buffer_free`.
+// - An empty buffer with a null `ptr` if the value has no data, or if
+//   `value` is null or not an extension value; `yad_last_error` tells them apart.
+//
+// # Safety
+// - `value` must be a valid pointer or null.
+ByteBuffer extension_data_from_value(Value *value);
+
+// Creates a heap-allocated decimal [`Value`], `mantissa × 10^-scale`.
+//
+// Mantissas wider than 64 bits can be written with [`value_from_decimal_cstring`].
+//
+// # Returns
+// - A raw pointer to a heap-allocated [`Value`]; free it with `value_free`.
+Value *value_from_decimal(int64_t mantissa, uint8_t scale);
+
+// Extracts the mantissa and scale of a decimal [`Value`].
+//
+// # Parameters
+// - `value`: Pointer to a [`Value`] expected to be a decimal.
+// - `out_mantissa`: Pointer to an `i64` where the mantissa will be written.
+// - `out_scale`: Pointer to a `u8` where the scale will be written.
+//
+// # Returns
+// - `true` if extraction succeeded.
+// - `false` if a pointer is null, `value` is not a decimal or its mantissa
+//   does not fit in 64 bits; read it with [`cstring_from_decimal_value`] then.
+//
+// # Safety
+// - `value`, `out_mantissa` and `out_scale` must be valid, non-null pointers.
+bool decimal_from_value(Value *value, int64_t *out_mantissa, uint8_t *out_scale);
+
+// Creates a heap-allocated decimal [`Value`] from its text, such as `"-19.90"`.
+//
+// # Parameters
+// - `c_string`: Pointer to a null-terminated C string of the form `[+-]digits[.digits]`.
+//
+// # Returns
+// - A raw pointer to a heap-allocated [`Value`]; free it with `value_free`.
+// - Returns `null` if the pointer is null or the text is not a decimal.
+//
+// # Safety
+// - `c_string` must point to a valid null-terminated C string or be null.
+Value *value_from_decimal_cstring(const char *c_string);
+
+// Writes a decimal [`Value`] as text, keeping its scale (`"19.90"`).
+//
+// # Returns
+// - Pointer to a null-terminated C string; free it with `cstring_free`.
+// - Returns `null` if `value` is null or not a decimal.
+//
+// # Safety
+// - `value` must be a valid pointer or null.
+const char *cstring_from_decimal_value(Value *value);
+
+// Creates a heap-allocated big integer [`Value`] from `len` bytes of
+// big-endian two's complement read from `data`.
+//
+// # Parameters
+// - `data`: Pointer to the bytes, or null if `len` is `0`.
+// - `len`: Number of bytes; `0` is zero.
+//
+// # Returns
+// - A raw pointer to a heap-allocated [`Value`]; free it with `value_free`.
+// - Returns `null` if `data` is null while `len` is not zero.
+//
+// # Safety
+// - `data` must point to at least `len` bytes. The buffer is only read.
+Value *value_from_big_integer_bytes(const uint8_t *data, size_t len);
+
+// Returns a big integer [`Value`] as big-endian two's complement, in the
+// fewest bytes holding it.
+//
+// # Returns
+// - A [`ByteBuffer`] holding the bytes; free it with `byte_buffer_free`.
+// - An empty buffer with a null `ptr` if `value` is null or not a big integer.
+//
+// # Safety
+// - `value` must be a valid pointer or null.
+ByteBuffer big_integer_bytes_from_value(Value *value);
+
+// Creates a heap-allocated big integer [`Value`] from its base 10 text, such
+// as `"-340282366920938463463374607431768211456"`.
+//
+// # Returns
+// - A raw pointer to a heap-allocated [`Value`]; free it with `value_free`.
+// - Returns `null` if the pointer is null or the text is not an integer.
+//
+// # Safety
+// - `c_string` must point to a valid null-terminated C string or be null.
+Value *value_from_big_integer_cstring(const char *c_string);
+
+// Writes a big integer [`Value`] in base 10.
+//
+// # Returns
+// - Pointer to a null-terminated C string; free it with `cstring_free`.
+// - Returns `null` if `value` is null or not a big integer.
+//
+// # Safety
+// - `value` must be a valid pointer or null.
+const char *cstring_from_big_integer_value(Value *value);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
+
+#endif  /* YAD_H */
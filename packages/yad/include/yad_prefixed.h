@@ -0,0 +1,111 @@
+#ifndef YAD_PREFIXED_H
+
+/* Maps the names declared by yad.h onto the symbols exported with the
+ * `prefixed-symbols` feature. Include this header instead of yad.h when
+ * linking such a build; names already starting with `yad_` are unchanged.
+ * Keep in sync with the `export_name` attributes (checked by tests/ffi_abi.rs). */
+
+#define big_integer_bytes_from_value   yad_big_integer_bytes_from_value
+#define bool_from_value                yad_bool_from_value
+#define byte_buffer_free               yad_byte_buffer_free
+#define c_array_as_ptr                 yad_c_array_as_ptr
+#define c_array_capacity               yad_c_array_capacity
+#define c_array_from_value             yad_c_array_from_value
+#define c_array_get                    yad_c_array_get
+#define c_array_insert                 yad_c_array_insert
+#define c_array_length                 yad_c_array_length
+#define c_array_new                    yad_c_array_new
+#define c_array_push                   yad_c_array_push
+#define c_array_remove                 yad_c_array_remove
+#define cstring_free                   yad_cstring_free
+#define cstring_from_big_integer_value yad_cstring_from_big_integer_value
+#define cstring_from_decimal_value     yad_cstring_from_decimal_value
+#define cstring_from_value             yad_cstring_from_value
+#define cstring_len_from_value         yad_cstring_len_from_value
+#define decimal_from_value             yad_decimal_from_value
+#define double_from_value              yad_double_from_value
+#define extension_data_from_value      yad_extension_data_from_value
+#define extension_tag_from_value       yad_extension_tag_from_value
+#define float_from_f16_value           yad_float_from_f16_value
+#define float_from_f8_value            yad_float_from_f8_value
+#define float_from_value               yad_float_from_value
+#define free_c_array                   yad_free_c_array
+#define int16_from_value               yad_int16_from_value
+#define int32_from_value               yad_int32_from_value
+#define int64_from_value               yad_int64_from_value
+#define int8_from_value                yad_int8_from_value
+#define key_as_buffer                  yad_key_as_buffer
+#define key_deserialize                yad_key_deserialize
+#define key_free                       yad_key_free
+#define key_get_name                   yad_key_get_name
+#define key_get_name_cstr              yad_key_get_name_cstr
+#define key_get_name_len               yad_key_get_name_len
+#define key_get_value                  yad_key_get_value
+#define key_new                        yad_key_new
+#define key_serialize                  yad_key_serialize
+#define key_set_value                  yad_key_set_value
+#define row_as_buffer                  yad_row_as_buffer
+#define row_deserialize                yad_row_deserialize
+#define row_free                       yad_row_free
+#define row_get_name_cstr              yad_row_get_name_cstr
+#define row_get_name_len               yad_row_get_name_len
+#define row_insert_key                 yad_row_insert_key
+#define row_key_at                     yad_row_key_at
+#define row_key_count                  yad_row_key_count
+#define row_key_cursor_free            yad_row_key_cursor_free
+#define row_key_cursor_new             yad_row_key_cursor_new
+#define row_key_cursor_next            yad_row_key_cursor_next
+#define row_new                        yad_row_new
+#define row_remove_key                 yad_row_remove_key
+#define row_serialize                  yad_row_serialize
+#define uint16_from_value              yad_uint16_from_value
+#define uint32_from_value              yad_uint32_from_value
+#define uint64_from_value              yad_uint64_from_value
+#define uint8_from_value               yad_uint8_from_value
+#define value_as_buffer                yad_value_as_buffer
+#define value_as_f16_from_float        yad_value_as_f16_from_float
+#define value_as_f8_from_float         yad_value_as_f8_from_float
+#define value_free                     yad_value_free
+#define value_from_big_integer_bytes   yad_value_from_big_integer_bytes
+#define value_from_big_integer_cstring yad_value_from_big_integer_cstring
+#define value_from_bool                yad_value_from_bool
+#define value_from_bool_array          yad_value_from_bool_array
+#define value_from_buffer              yad_value_from_buffer
+#define value_from_buffer_limited      yad_value_from_buffer_limited
+#define value_from_c_array             yad_value_from_c_array
+#define value_from_cstring             yad_value_from_cstring
+#define value_from_decimal             yad_value_from_decimal
+#define value_from_decimal_cstring     yad_value_from_decimal_cstring
+#define value_from_double              yad_value_from_double
+#define value_from_double_array        yad_value_from_double_array
+#define value_from_extension           yad_value_from_extension
+#define value_from_float               yad_value_from_float
+#define value_from_float_array         yad_value_from_float_array
+#define value_from_int16_array         yad_value_from_int16_array
+#define value_from_int32_array         yad_value_from_int32_array
+#define value_from_int64_array         yad_value_from_int64_array
+#define value_from_int8_array          yad_value_from_int8_array
+#define value_from_int_16              yad_value_from_int_16
+#define value_from_int_32              yad_value_from_int_32
+#define value_from_int_64              yad_value_from_int_64
+#define value_from_int_8               yad_value_from_int_8
+#define value_from_uint16_array        yad_value_from_uint16_array
+#define value_from_uint32_array        yad_value_from_uint32_array
+#define value_from_uint64_array        yad_value_from_uint64_array
+#define value_from_uint8_array         yad_value_from_uint8_array
+#define value_from_uint_16             yad_value_from_uint_16
+#define value_from_uint_32             yad_value_from_uint_32
+#define value_from_uint_64             yad_value_from_uint_64
+#define value_from_uint_8              yad_value_from_uint_8
+#define value_len                      yad_value_len
+#define value_raw_bytes                yad_value_raw_bytes
+#define value_raw_bytes_length         yad_value_raw_bytes_length
+#define value_type                     yad_value_type
+#define version_deserialize            yad_version_deserialize
+#define version_free                   yad_version_free
+#define version_new                    yad_version_new
+#define version_serialize              yad_version_serialize
+
+#include "yad.h"
+
+#endif /* YAD_PREFIXED_H */
@@ -0,0 +1,103 @@
+use crate::key::Key;
+use crate::row::Row;
+use crate::{CURRENT_VERSION, YAD};
+
+/// Most keys in an arbitrary [`Row`], and rows in an arbitrary [`YAD`].
+const MAX_ARBITRARY_ITEMS: usize = 8;
+
+/// Longest arbitrary row or key name, in characters.
+const MAX_ARBITRARY_NAME: usize = 12;
+
+#[cfg(feature = "proptest")]
+mod proptest_impl {
+    use proptest::prelude::*;
+    use proptest::collection::vec;
+    use yad_core::Value;
+    use super::*;
+
+    /// A non-empty name.
+    fn name() -> impl Strategy<Value = String> {
+        vec(any::<char>(), 1..=MAX_ARBITRARY_NAME).prop_map(String::from_iter)
+    }
+
+    impl Arbitrary for Key {
+        type Parameters = ();
+        type Strategy = BoxedStrategy<Key>;
+
+        fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+            (name(), any::<Value>()).prop_map(|(name, value)| Key::new(name, value)).boxed()
+        }
+    }
+
+    impl Arbitrary for Row {
+        type Parameters = ();
+        type Strategy = BoxedStrategy<Row>;
+
+        fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+            (name(), vec(any::<Key>(), 0..=MAX_ARBITRARY_ITEMS)).prop_map(|(name, keys)| Row::new(name, keys)).boxed()
+        }
+    }
+
+    impl Arbitrary for YAD {
+        type Parameters = ();
+        type Strategy = BoxedStrategy<YAD>;
+
+        fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+            vec(any::<Row>(), 0..=MAX_ARBITRARY_ITEMS).prop_map(|rows| YAD::new(CURRENT_VERSION, rows)).boxed()
+        }
+    }
+}
+
+#[cfg(feature = "quickcheck")]
+mod quickcheck_impl {
+    use quickcheck::{Arbitrary, Gen};
+    use yad_core::Value;
+    use super::*;
+
+    /// A non-empty name.
+    fn name(g: &mut Gen) -> String {
+        let len = 1 + usize::arbitrary(g) % MAX_ARBITRARY_NAME;
+        (0..len).map(|_| char::arbitrary(g)).collect()
+    }
+
+    /// Up to [`MAX_ARBITRARY_ITEMS`] arbitrary items.
+    fn items<T: Arbitrary>(g: &mut Gen) -> Vec<T> {
+        let len = usize::arbitrary(g) % (MAX_ARBITRARY_ITEMS + 1);
+        (0..len).map(|_| T::arbitrary(g)).collect()
+    }
+
+    impl Arbitrary for Key {
+        fn arbitrary(g: &mut Gen) -> Self {
+            Key::new(name(g), Value::arbitrary(g))
+        }
+    }
+
+    impl Arbitrary for Row {
+        fn arbitrary(g: &mut Gen) -> Self {
+            Row::new(name(g), items(g))
+        }
+
+        fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+            let name = self.name.clone();
+            let keys: Vec<Key> = self.keys.values().cloned().collect();
+            Box::new((0..keys.len()).map(move |skip| {
+                let keys = keys.iter().enumerate().filter(|(i, _)| *i != skip).map(|(_, key)| key.clone()).collect();
+                Row::new(name.clone(), keys)
+            }))
+        }
+    }
+
+    impl Arbitrary for YAD {
+        fn arbitrary(g: &mut Gen) -> Self {
+            YAD::new(CURRENT_VERSION, items(g))
+        }
+
+        fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+            let rows: Vec<Row> = self.rows.values().cloned().collect();
+            Box::new((0..rows.len()).map(move |skip| {
+                let rows = rows.iter().enumerate().filter(|(i, _)| *i != skip).map(|(_, row)| row.clone()).collect();
+                YAD::new(CURRENT_VERSION, rows)
+            }))
+        }
+    }
+}
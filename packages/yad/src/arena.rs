@@ -0,0 +1,185 @@
+use bumpalo::collections::Vec as BumpVec;
+use yad_core::constants::error::ErrorMessage;
+use yad_core::byte_order::ByteOrder;
+use yad_core::{Reader, Value};
+use crate::constants::{KEY_START_HEADER, ROW_END_HEADER, ROW_NAME_HEADER, ROW_START_HEADER};
+use crate::borrowed::{read_key_ref, read_meta, KeyRef, Values};
+use crate::error::{MALFORMED_ROW_NAME_VECTOR, MALFORMED_ROW_VECTOR};
+use crate::compression::MAX_INFLATED_SIZE;
+use crate::header::{body_start, decode_body, Header};
+use crate::key::Key;
+use crate::meta::Meta;
+use crate::row::Row;
+use crate::{decode_name_ref, Version, YAD};
+
+/// The arena type taken by [`YAD::deserialize_in`], re-exported so callers
+/// do not need a matching `bumpalo` dependency.
+pub use bumpalo::Bump;
+
+/// A key read by [`YAD::deserialize_in`]. Its name, metadata and value point
+/// into the arena copy of the input and are only decoded on request.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct ArenaKey<'a> {
+    /// The key name.
+    pub name: &'a str,
+    /// The raw metadata block, empty when the key has none.
+    pub meta: &'a [u8],
+    /// The encoded value (header, length descriptor and payload) as stored;
+    /// the first one for multi-value keys.
+    pub value: &'a [u8],
+    more: &'a [u8],
+    order: ByteOrder,
+}
+
+impl<'a> ArenaKey<'a> {
+    /// Iterates over every encoded value of the key in order, starting with [`ArenaKey::value`].
+    pub fn values(&self) -> Values<'a> {
+        Values::new(self.value, self.more, self.order)
+    }
+
+    /// Decodes the value, converting numbers of little-endian documents.
+    pub fn value(&self) -> Result<Value, ErrorMessage> {
+        Value::decode_in_place(self.value)?.with_byte_order(self.order)
+    }
+
+    /// Decodes the metadata block.
+    pub fn meta(&self) -> Result<Meta, ErrorMessage> {
+        Meta::decode(self.meta).map(|(meta, _)| meta)
+    }
+
+    /// Copies the key out of the arena.
+    pub fn to_owned(&self) -> Result<Key, ErrorMessage> {
+        let mut key = Key::new(self.name, self.value()?);
+        key.meta = self.meta()?;
+
+        for value in self.values().skip(1) {
+            key.push_value(value.to_owned()?);
+        }
+
+        Ok(key)
+    }
+}
+
+/// A row read by [`YAD::deserialize_in`], with its keys in file order.
+#[derive(Debug)]
+pub struct ArenaRow<'a> {
+    /// The row name.
+    pub name: &'a str,
+    /// The raw metadata block, empty when the row has none.
+    pub meta: &'a [u8],
+    /// The keys, allocated in the arena.
+    pub keys: BumpVec<'a, ArenaKey<'a>>,
+}
+
+impl<'a> ArenaRow<'a> {
+    /// Returns the last key named `name`, which is the one [`Row`] would keep.
+    pub fn get(&self, name: &str) -> Option<&ArenaKey<'a>> {
+        self.keys.iter().rev().find(|key| key.name == name)
+    }
+
+    /// Decodes the metadata block.
+    pub fn meta(&self) -> Result<Meta, ErrorMessage> {
+        Meta::decode(self.meta).map(|(meta, _)| meta)
+    }
+
+    /// Copies the row and its keys out of the arena.
+    pub fn to_owned(&self) -> Result<Row, ErrorMessage> {
+        let mut row = Row::new_empty(self.name);
+        row.meta = self.meta()?;
+
+        for key in &self.keys {
+            let key = key.to_owned()?;
+            row.keys.insert(key.name.clone(), key);
+        }
+
+        Ok(row)
+    }
+}
+
+/// A document read by [`YAD::deserialize_in`].
+#[derive(Debug)]
+pub struct ArenaYad<'a> {
+    /// The format version.
+    pub version: Version,
+    /// The rows in file order, allocated in the arena.
+    pub rows: BumpVec<'a, ArenaRow<'a>>,
+}
+
+impl<'a> ArenaYad<'a> {
+    /// Returns the last row named `name`, which is the one [`YAD`] would keep.
+    pub fn get(&self, name: &str) -> Option<&ArenaRow<'a>> {
+        self.rows.iter().rev().find(|row| row.name == name)
+    }
+
+    /// Copies the document out of the arena.
+    pub fn to_owned(&self) -> Result<YAD, ErrorMessage> {
+        let rows = self.rows.iter().map(ArenaRow::to_owned).collect::<Result<Vec<_>, _>>()?;
+        Ok(YAD::new(self.version.clone(), rows))
+    }
+}
+
+/// Reads the row at the reader's position (on its start header).
+fn read_row<'a>(reader: &mut Reader<'a>, order: ByteOrder, bump: &'a Bump) -> Result<ArenaRow<'a>, ErrorMessage> {
+    reader.advance(1)?;
+
+    let (name, consumed) = decode_name_ref(reader.remaining(), ROW_NAME_HEADER)
+        .ok_or(ErrorMessage(MALFORMED_ROW_NAME_VECTOR))?;
+    reader.advance(consumed)?;
+
+    let meta = read_meta(reader)?;
+    let mut keys = BumpVec::new_in(bump);
+
+    loop {
+        match reader.peek() {
+            Some(ROW_END_HEADER) => break,
+            Some(KEY_START_HEADER) => {
+                let KeyRef { name, meta, value, more } = read_key_ref(reader, order)?;
+                keys.push(ArenaKey { name, meta, value: value.as_bytes(), more, order });
+            }
+            _ => return Err(ErrorMessage(MALFORMED_ROW_VECTOR)),
+        }
+    }
+    reader.advance(1)?;
+
+    Ok(ArenaRow { name, meta, keys })
+}
+
+impl YAD {
+    /// Deserializes a document into `bump` for read-only use.
+    ///
+    /// The rows of the input are copied into the arena once, with varint
+    /// length descriptors rewritten as fixed ones, delta arrays as plain ones
+    /// and dictionary references as the key names they point to; names, metadata and
+    /// encoded values are slices of that copy and the row and key lists are
+    /// arena vectors, so nothing is allocated per name or value. Values are
+    /// validated by their length descriptors only and decoded on access.
+    ///
+    /// Like [`YAD::deserialize_projected`], the walk is strict: bytes that do
+    /// not frame a row or a key are an error. A signature section is skipped
+    /// without being checked; encrypted documents are rejected with
+    /// `ENCRYPTED_DOCUMENT`.
+    ///
+    /// # Example
+    /// ```text
+    /// let bump = Bump::new();
+    /// let doc = YAD::deserialize_in(&bytes, &bump)?;
+    /// let age = doc.get("johan").and_then(|row| row.get("age"));
+    /// ```
+    pub fn deserialize_in<'a>(bytes: &[u8], bump: &'a Bump) -> Result<ArenaYad<'a>, ErrorMessage> {
+        let (Header { version, flags }, pos) = body_start(bytes)?;
+
+        let body: &'a [u8] = bump.alloc_slice_copy(&decode_body(bytes.get(pos..).unwrap_or_default(), flags, Some(MAX_INFLATED_SIZE))?);
+
+        let mut reader = Reader::new(body);
+        let mut rows = BumpVec::new_in(bump);
+
+        while let Some(b) = reader.peek() {
+            if b != ROW_START_HEADER {
+                return Err(ErrorMessage(MALFORMED_ROW_VECTOR));
+            }
+            rows.push(read_row(&mut reader, flags.byte_order(), bump)?);
+        }
+
+        Ok(ArenaYad { version, rows })
+    }
+}
@@ -0,0 +1,167 @@
+use std::fmt::{Display, Formatter};
+use std::sync::Arc;
+use arrow_array::{
+    ArrayRef, BooleanArray, Decimal128Array, Float32Array, Float64Array, Int16Array, Int32Array, Int64Array,
+    Int8Array, LargeStringArray, RecordBatch, RecordBatchOptions, StringArray, UInt16Array, UInt32Array,
+    UInt64Array, UInt8Array,
+};
+use arrow_schema::{DataType, Field, SchemaRef};
+use yad_core::constants::error::ErrorMessage;
+use yad_core::Value;
+use crate::error::{ARROW_MISSING_VALUE, ARROW_UNSUPPORTED_TYPE, ARROW_VALUE_MISMATCH};
+use crate::row::Row;
+use crate::YAD;
+
+/// Name of the Arrow field that receives the row names in
+/// [`YAD::to_arrow_recordbatch`], rather than the values of a key.
+pub const ROW_NAME_FIELD: &str = "_row";
+
+/// An error exporting a document to Arrow, with the field and, when the
+/// error comes from a value, the row it was found in.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ArrowError {
+    /// What went wrong.
+    pub error: ErrorMessage,
+    /// Name of the row, or `None` when the field itself cannot be exported.
+    pub row: Option<String>,
+    /// Name of the Arrow field.
+    pub field: String,
+}
+
+impl Display for ArrowError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match &self.row {
+            Some(row) => write!(f, "{} (field `{}` in row `{}`)", self.error.0, self.field, row),
+            None => write!(f, "{} (field `{}`)", self.error.0, self.field),
+        }
+    }
+}
+
+impl From<ArrowError> for ErrorMessage {
+    fn from(error: ArrowError) -> Self {
+        error.error
+    }
+}
+
+/// Reads the value of `field` in every row, converted with `read`; rows
+/// without the key give `None` if the field is nullable.
+fn column<'a, T>(
+    rows: &[&'a Row],
+    field: &Field,
+    read: impl Fn(&'a Value) -> Result<T, ErrorMessage>,
+) -> Result<Vec<Option<T>>, ArrowError> {
+    let error = |error: &'static str, row: &Row| ArrowError {
+        error: ErrorMessage(error),
+        row: Some(row.name.clone()),
+        field: field.name().clone(),
+    };
+
+    rows.iter()
+        .map(|row| match row.get(field.name()) {
+            Some(value) => read(value).map(Some).map_err(|_| error(ARROW_VALUE_MISMATCH, row)),
+            None if field.is_nullable() => Ok(None),
+            None => Err(error(ARROW_MISSING_VALUE, row)),
+        })
+        .collect()
+}
+
+/// Reads a decimal value as a mantissa at `scale` with at most `precision` digits.
+fn decimal_mantissa(value: &Value, precision: u8, scale: i8) -> Result<i128, ErrorMessage> {
+    let mismatch = || ErrorMessage(ARROW_VALUE_MISMATCH);
+    let decimal = value.as_decimal()?;
+
+    // Widening the scale is exact; narrowing it would drop digits.
+    let widen = u32::try_from(scale as i16 - decimal.scale as i16).map_err(|_| mismatch())?;
+    let mantissa = 10i128.checked_pow(widen).and_then(|p| decimal.mantissa.checked_mul(p)).ok_or_else(mismatch)?;
+
+    match 10i128.checked_pow(precision as u32) {
+        Some(limit) if mantissa.abs() >= limit => Err(mismatch()),
+        _ => Ok(mantissa),
+    }
+}
+
+/// Builds the Arrow array of `field` from the values of its key in `rows`.
+fn array(rows: &[&Row], field: &Field) -> Result<ArrayRef, ArrowError> {
+    macro_rules! primitive {
+        ($array:ty, $t:ty) => {
+            Arc::new(<$array>::from(column(rows, field, |value| TryInto::<$t>::try_into(value.clone()))?))
+        };
+    }
+
+    Ok(match field.data_type() {
+        DataType::Utf8 if field.name() == ROW_NAME_FIELD => {
+            Arc::new(StringArray::from_iter_values(rows.iter().map(|row| row.name.as_str())))
+        }
+        DataType::Boolean => primitive!(BooleanArray, bool),
+        DataType::UInt8 => primitive!(UInt8Array, u8),
+        DataType::UInt16 => primitive!(UInt16Array, u16),
+        DataType::UInt32 => primitive!(UInt32Array, u32),
+        DataType::UInt64 => primitive!(UInt64Array, u64),
+        DataType::Int8 => primitive!(Int8Array, i8),
+        DataType::Int16 => primitive!(Int16Array, i16),
+        DataType::Int32 => primitive!(Int32Array, i32),
+        DataType::Int64 => primitive!(Int64Array, i64),
+        DataType::Float32 => primitive!(Float32Array, f32),
+        DataType::Float64 => primitive!(Float64Array, f64),
+        DataType::Utf8 => Arc::new(StringArray::from(column(rows, field, Value::as_str)?)),
+        DataType::LargeUtf8 => Arc::new(LargeStringArray::from(column(rows, field, Value::as_str)?)),
+        DataType::Decimal128(precision, scale) => Arc::new(
+            Decimal128Array::from(column(rows, field, |value| decimal_mantissa(value, *precision, *scale))?)
+                .with_precision_and_scale(*precision, *scale)
+                .map_err(|_| ArrowError { error: ErrorMessage(ARROW_UNSUPPORTED_TYPE), row: None, field: field.name().clone() })?,
+        ),
+        _ => return Err(ArrowError { error: ErrorMessage(ARROW_UNSUPPORTED_TYPE), row: None, field: field.name().clone() }),
+    })
+}
+
+impl YAD {
+    /// Exports the rows as an Arrow record batch, one record per row in
+    /// name order, for writing Parquet or querying with DataFusion.
+    ///
+    /// Each field of `schema` is filled with the values of the key of the
+    /// same name; rows without it give nulls in nullable fields. A `Utf8`
+    /// field named [`ROW_NAME_FIELD`] receives the row names instead.
+    ///
+    /// Values must have the type of their field, as with `TryInto`: `UInt16`
+    /// fields take `uint16` values only, `Utf8` and `LargeUtf8` fields take
+    /// strings, and `Decimal128` fields take decimals, which are rescaled
+    /// when that keeps every digit. Booleans, integers and `Float32`/`Float64`
+    /// are the other supported data types. Multi-value keys give their first
+    /// value.
+    ///
+    /// # Example
+    /// ```
+    /// use std::sync::Arc;
+    /// use arrow_schema::{DataType, Field, Schema};
+    /// use serde_yad::{row::Row, key::Key, arrow::ROW_NAME_FIELD, YAD, CURRENT_VERSION};
+    ///
+    /// let yad = YAD::new(CURRENT_VERSION, vec![
+    ///     Row::new("ana", vec![Key::new("age", 30u8)]),
+    ///     Row::new("johan", vec![Key::new("age", 17u8)]),
+    /// ]);
+    /// let schema = Arc::new(Schema::new(vec![
+    ///     Field::new(ROW_NAME_FIELD, DataType::Utf8, false),
+    ///     Field::new("age", DataType::UInt8, false),
+    /// ]));
+    ///
+    /// let batch = yad.to_arrow_recordbatch(schema).unwrap();
+    /// assert_eq!(batch.num_rows(), 2);
+    /// assert_eq!(batch.num_columns(), 2);
+    /// ```
+    ///
+    /// # Errors
+    /// - `ARROW_UNSUPPORTED_TYPE` for fields of other data types.
+    /// - `ARROW_MISSING_VALUE` if a row has no key for a non-nullable field.
+    /// - `ARROW_VALUE_MISMATCH` if a value does not have the type of its
+    ///   field, or a decimal does not fit its precision and scale.
+    pub fn to_arrow_recordbatch(&self, schema: SchemaRef) -> Result<RecordBatch, ArrowError> {
+        let rows: Vec<&Row> = self.rows.values().collect();
+        let columns = schema.fields().iter()
+            .map(|field| array(&rows, field))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let options = RecordBatchOptions::new().with_row_count(Some(rows.len()));
+        Ok(RecordBatch::try_new_with_options(schema, columns, &options)
+            .expect("arrays built to match the schema"))
+    }
+}
@@ -0,0 +1,122 @@
+use std::path::PathBuf;
+use std::sync::{Arc, Condvar, Mutex, PoisonError};
+use std::thread::JoinHandle;
+use std::time::Duration;
+use yad_core::constants::error::ErrorMessage;
+use crate::shared::{SharedYad, Snapshot};
+
+/// State shared between a [`YadAutoSaver`] and its thread.
+#[derive(Default)]
+struct Progress {
+    stopping: bool,
+    saves: usize,
+    last_error: Option<ErrorMessage>,
+}
+
+/// Saves a [`SharedYad`] to a file in the background, every `interval`,
+/// for applications that use a document as their data store.
+///
+/// Each save serializes a [`Snapshot`], so writers are only held up while
+/// it is taken, and replaces the file atomically (see [`YadAutoSaver::spawn`]).
+/// Intervals without writes are skipped. The thread saves one last time and
+/// ends when the saver is stopped or dropped.
+///
+/// Holding on to the last saved snapshot means the first write after each
+/// save copies the document once, as with any live snapshot.
+///
+/// # Example
+/// ```text
+/// let shared = SharedYad::new(YAD::deserialize(fs::read("data.yad")?)?);
+/// let saver = YadAutoSaver::spawn(shared.clone(), "data.yad", Duration::from_secs(5));
+/// shared.write().insert_row("johan", vec![]);
+/// saver.stop()?;   // saves "johan" before returning
+/// ```
+pub struct YadAutoSaver {
+    progress: Arc<(Mutex<Progress>, Condvar)>,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl YadAutoSaver {
+    /// Starts a thread saving `shared` to `path` every `interval`.
+    ///
+    /// Saves go through [`crate::YAD::save_atomic`], so a crash at any point
+    /// leaves a complete document: the previous save or this one.
+    ///
+    /// A failed save is kept in [`YadAutoSaver::last_error`] and retried at
+    /// the next interval.
+    pub fn spawn<P: Into<PathBuf>>(shared: SharedYad, path: P, interval: Duration) -> Self {
+        let path = path.into();
+        let progress = Arc::new((Mutex::new(Progress::default()), Condvar::new()));
+        let thread_progress = progress.clone();
+
+        let thread = std::thread::spawn(move || {
+            let (lock, wake) = &*thread_progress;
+            let mut saved: Option<Snapshot> = None;
+
+            loop {
+                let progress = lock.lock().unwrap_or_else(PoisonError::into_inner);
+                let (progress, _) = wake.wait_timeout_while(progress, interval, |p| !p.stopping)
+                    .unwrap_or_else(PoisonError::into_inner);
+                let stopping = progress.stopping;
+                drop(progress);
+
+                let snapshot = shared.snapshot();
+                if !saved.as_ref().is_some_and(|saved| saved.same_as(&snapshot)) {
+                    let result = snapshot.save_atomic(&path);
+
+                    let mut progress = lock.lock().unwrap_or_else(PoisonError::into_inner);
+                    match result {
+                        Ok(()) => {
+                            progress.saves += 1;
+                            progress.last_error = None;
+                            saved = Some(snapshot);
+                        }
+                        Err(error) => progress.last_error = Some(error),
+                    }
+                }
+
+                if stopping {
+                    return;
+                }
+            }
+        });
+
+        Self { progress, thread: Some(thread) }
+    }
+
+    /// The number of saves written so far.
+    pub fn saves(&self) -> usize {
+        self.progress.0.lock().unwrap_or_else(PoisonError::into_inner).saves
+    }
+
+    /// The error of the last save, if it failed; cleared by the next successful one.
+    pub fn last_error(&self) -> Option<ErrorMessage> {
+        self.progress.0.lock().unwrap_or_else(PoisonError::into_inner).last_error
+    }
+
+    /// Saves one last time if the document changed, and stops the thread.
+    ///
+    /// # Errors
+    /// Returns the error of the last save if it failed, such as
+    /// `SAVE_IO_FAILED` if the file could not be written.
+    pub fn stop(mut self) -> Result<(), ErrorMessage> {
+        self.finish();
+        self.last_error().map_or(Ok(()), Err)
+    }
+
+    /// Wakes the thread for its last save and waits for it to end.
+    fn finish(&mut self) {
+        let Some(thread) = self.thread.take() else { return };
+
+        let (lock, wake) = &*self.progress;
+        lock.lock().unwrap_or_else(PoisonError::into_inner).stopping = true;
+        wake.notify_all();
+        let _ = thread.join();
+    }
+}
+
+impl Drop for YadAutoSaver {
+    fn drop(&mut self) {
+        self.finish();
+    }
+}
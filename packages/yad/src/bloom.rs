@@ -0,0 +1,147 @@
+use yad_core::constants::error::ErrorMessage;
+use yad_core::Value;
+use crate::constants::{BLOOM_HEADER, SCHEMA_HEADER, SIGNATURE_HEADER, SIGNATURE_LENGTH};
+use crate::error::MALFORMED_BLOOM_FILTER;
+use crate::header::Header;
+use crate::schema::Schema;
+
+/// Bits set aside per row name, for about one false positive in a hundred lookups.
+pub const BITS_PER_NAME: usize = 10;
+
+/// Hash functions per name, the best number for [`BITS_PER_NAME`].
+pub const HASHES_PER_NAME: u8 = 7;
+
+/// 64-bit FNV-1a, a hash that is the same on every platform and Rust version.
+fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xCBF2_9CE4_8422_2325, |hash, byte| (hash ^ *byte as u64).wrapping_mul(0x0100_0000_01B3))
+}
+
+/// A bloom filter over row names, written into a serialized document with
+/// [`crate::options::SerializeOptions::bloom_filter`], so readers can tell
+/// that a row is missing without walking the rows.
+///
+/// [`BloomFilter::might_contain`] never answers `false` for a name that was
+/// inserted; it answers `true` for about one missing name in a hundred.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BloomFilter {
+    bits: Vec<u64>,
+    hashes: u8,
+}
+
+impl BloomFilter {
+    /// Creates an empty filter sized for `names` names.
+    pub fn with_capacity(names: usize) -> Self {
+        let words = (names * BITS_PER_NAME).div_ceil(64).max(1);
+        Self { bits: vec![0; words], hashes: HASHES_PER_NAME }
+    }
+
+    /// Builds a filter holding every name of `names`.
+    pub fn from_names<'a, I: ExactSizeIterator<Item = &'a str>>(names: I) -> Self {
+        let mut filter = Self::with_capacity(names.len());
+        names.for_each(|name| filter.insert(name));
+        filter
+    }
+
+    /// The bit positions of `name`, by double hashing the halves of its hash.
+    fn positions(&self, name: &str) -> impl Iterator<Item = usize> {
+        let hash = fnv1a(name.as_bytes());
+        let (first, step) = (hash & 0xFFFF_FFFF, (hash >> 32) | 1);
+        let len = self.bits.len() as u64 * 64;
+        (0..self.hashes as u64).map(move |i| (first.wrapping_add(i.wrapping_mul(step)) % len) as usize)
+    }
+
+    /// Adds `name` to the filter.
+    pub fn insert(&mut self, name: &str) {
+        let positions: Vec<usize> = self.positions(name).collect();
+        for position in positions {
+            self.bits[position / 64] |= 1 << (position % 64);
+        }
+    }
+
+    /// Returns `false` if `name` was never inserted, and `true` if it
+    /// probably was.
+    pub fn might_contain(&self, name: &str) -> bool {
+        self.positions(name).all(|position| self.bits[position / 64] & (1 << (position % 64)) != 0)
+    }
+
+    /// Serializes the filter as a bloom filter section.
+    ///
+    /// # Layout
+    /// `BLOOM_HEADER` followed by an array value holding the number of hash
+    /// functions (u8) and then the bits as u64 words, lowest bits first.
+    ///
+    /// # Errors
+    /// Returns an error if the array cannot be encoded.
+    pub fn serialize(&self) -> Result<Vec<u8>, ErrorMessage> {
+        let mut items = Vec::with_capacity(1 + self.bits.len());
+        items.push(Value::from(self.hashes));
+        items.extend(self.bits.iter().map(|word| Value::from(*word)));
+
+        let mut bytes = vec![BLOOM_HEADER];
+        bytes.extend_from_slice(Value::try_from(items)?.bytes.as_slice());
+        Ok(bytes)
+    }
+
+    /// Deserializes a bloom filter section starting with `BLOOM_HEADER`.
+    ///
+    /// # Returns
+    /// - `Ok((BloomFilter, usize))`: The filter and the number of bytes consumed.
+    /// - `Err(ErrorMessage)`: `MALFORMED_BLOOM_FILTER` if the section is malformed.
+    pub fn deserialize(bytes: &[u8]) -> Result<(Self, usize), ErrorMessage> {
+        let malformed = || ErrorMessage(MALFORMED_BLOOM_FILTER);
+        if bytes.first() != Some(&BLOOM_HEADER) {
+            return Err(malformed());
+        }
+
+        let section = Value::decode_in_place(&bytes[1..]).map_err(|_| malformed())?;
+        let consumed = 1 + section.bytes.len();
+        let items: Vec<Value> = section.try_into().map_err(|_| malformed())?;
+        let (hashes, words) = items.split_first().ok_or_else(malformed)?;
+
+        let hashes: u8 = hashes.clone().try_into().map_err(|_| malformed())?;
+        let bits = words.iter()
+            .map(|word| word.clone().try_into().map_err(|_| malformed()))
+            .collect::<Result<Vec<u64>, _>>()?;
+        if hashes == 0 || bits.is_empty() {
+            return Err(malformed());
+        }
+
+        Ok((Self { bits, hashes }, consumed))
+    }
+
+    /// Reads the bloom filter embedded in a serialized document, if any.
+    ///
+    /// # Arguments
+    /// - `bytes`: The whole serialized document, starting with its header.
+    ///
+    /// # Returns
+    /// - `Ok(Some(BloomFilter))`: If the document embeds a bloom filter section.
+    /// - `Ok(None)`: If it does not.
+    pub fn read_embedded(bytes: &[u8]) -> Result<Option<Self>, ErrorMessage> {
+        let (_, mut pos) = Header::read(bytes)?;
+
+        if bytes.get(pos) == Some(&SIGNATURE_HEADER) {
+            pos += 1 + SIGNATURE_LENGTH;
+        }
+
+        if bytes.get(pos) == Some(&SCHEMA_HEADER) {
+            pos += Schema::deserialize(&bytes[pos..])?.1;
+        }
+
+        match bytes.get(pos) {
+            Some(&BLOOM_HEADER) => Ok(Some(Self::deserialize(&bytes[pos..])?.0)),
+            _ => Ok(None),
+        }
+    }
+}
+
+/// Removes a leading bloom filter section from a document body (the bytes
+/// after the version header and the schema section).
+pub(crate) fn strip_bloom_section(bytes: &mut Vec<u8>) -> Result<(), ErrorMessage> {
+    if bytes.first() == Some(&BLOOM_HEADER) {
+        let (_, consumed) = BloomFilter::deserialize(bytes)?;
+        bytes.drain(..consumed);
+    }
+
+    Ok(())
+}
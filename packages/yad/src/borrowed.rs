@@ -0,0 +1,440 @@
+use yad_core::constants::error::{ErrorMessage, MALFORMED_UTF8, NOT_A_STRING};
+use yad_core::constants::length::ByteLength;
+use yad_core::constants::types::Type;
+use yad_core::byte_order::ByteOrder;
+use yad_core::{Reader, Value};
+use crate::constants::{KEY_END_HEADER, KEY_NAME_HEADER, KEY_START_HEADER, ROW_END_HEADER, ROW_NAME_HEADER, ROW_START_HEADER};
+use crate::error::{MALFORMED_KEY_NAME_VECTOR, MALFORMED_KEY_VECTOR, MALFORMED_ROW_NAME_VECTOR, MALFORMED_ROW_VECTOR, VARINT_LENGTHS_NOT_BORROWABLE, DICTIONARY_NOT_BORROWABLE, DELTA_ARRAYS_NOT_BORROWABLE, COMPRESSED_NOT_BORROWABLE};
+use crate::bloom::BloomFilter;
+use crate::header::{body_start, Header, HeaderFlags};
+use crate::key::Key;
+use crate::meta::Meta;
+use crate::row::Row;
+use crate::{decode_name_ref, Version, YAD};
+
+/// An encoded value borrowed from the input buffer.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct ValueRef<'a> {
+    bytes: &'a [u8],
+    order: ByteOrder,
+}
+
+impl<'a> ValueRef<'a> {
+    /// The value's type, read from its header.
+    pub fn r#type(&self) -> Result<Type, ErrorMessage> {
+        Type::try_from(self.bytes[0])
+    }
+
+    /// The value's length descriptor width, read from its header.
+    pub fn length(&self) -> Result<ByteLength, ErrorMessage> {
+        ByteLength::try_from(self.bytes[0])
+    }
+
+    /// The full encoding: header, length descriptor and payload, as stored;
+    /// number payloads are in the document's [`YadRef::byte_order`].
+    pub fn as_bytes(&self) -> &'a [u8] {
+        self.bytes
+    }
+
+    /// Returns a string value without copying it.
+    ///
+    /// # Errors
+    /// Returns `NOT_A_STRING` for other types, or `MALFORMED_UTF8` if the payload is not UTF-8.
+    pub fn as_str(&self) -> Result<&'a str, ErrorMessage> {
+        if self.r#type()? != Type::String {
+            return Err(ErrorMessage(NOT_A_STRING));
+        }
+
+        let start = 1 + self.length()?.as_byte_count() as usize;
+        std::str::from_utf8(&self.bytes[start..]).map_err(|_| ErrorMessage(MALFORMED_UTF8))
+    }
+
+    /// Decodes the value, converting numbers of little-endian documents.
+    pub fn to_owned(&self) -> Result<Value, ErrorMessage> {
+        Value::decode_in_place(self.bytes)?.with_byte_order(self.order)
+    }
+}
+
+/// A key borrowed from the input buffer.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct KeyRef<'a> {
+    /// The key name.
+    pub name: &'a str,
+    /// The raw metadata block, empty when the key has none.
+    pub meta: &'a [u8],
+    /// The encoded value; the first one for multi-value keys.
+    pub value: ValueRef<'a>,
+    pub(crate) more: &'a [u8],
+}
+
+impl<'a> KeyRef<'a> {
+    /// Iterates over every value of the key in order, starting with [`KeyRef::value`].
+    pub fn values(&self) -> Values<'a> {
+        Values::new(self.value.bytes, self.more, self.value.order)
+    }
+
+    /// Decodes the metadata block.
+    pub fn meta(&self) -> Result<Meta, ErrorMessage> {
+        Meta::decode(self.meta).map(|(meta, _)| meta)
+    }
+
+    /// Copies the key out of the input buffer.
+    pub fn to_owned(&self) -> Result<Key, ErrorMessage> {
+        let mut key = Key::new(self.name, self.value.to_owned()?);
+        key.meta = self.meta()?;
+
+        for value in self.values().skip(1) {
+            key.push_value(value.to_owned()?);
+        }
+
+        Ok(key)
+    }
+}
+
+/// A row borrowed from the input buffer. Its keys are walked on each call to
+/// [`RowRef::keys`] rather than collected.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct RowRef<'a> {
+    /// The row name.
+    pub name: &'a str,
+    /// The raw metadata block, empty when the row has none.
+    pub meta: &'a [u8],
+    keys: &'a [u8],
+    order: ByteOrder,
+    now: Option<u64>,
+}
+
+impl<'a> RowRef<'a> {
+    /// Iterates over the keys in file order, skipping expired ones when the
+    /// document is read with [`YadRef::expiring_at`].
+    pub fn keys(&self) -> Keys<'a> {
+        Keys { reader: Reader::new(self.keys), order: self.order, now: self.now, failed: false }
+    }
+
+    /// Returns the last key named `name`, which is the one [`Row`] would keep.
+    pub fn get(&self, name: &str) -> Result<Option<KeyRef<'a>>, ErrorMessage> {
+        let mut found = None;
+        for key in self.keys() {
+            let key = key?;
+            if key.name == name {
+                found = Some(key);
+            }
+        }
+        Ok(found)
+    }
+
+    /// Decodes the metadata block.
+    pub fn meta(&self) -> Result<Meta, ErrorMessage> {
+        Meta::decode(self.meta).map(|(meta, _)| meta)
+    }
+
+    /// Copies the row and its keys out of the input buffer.
+    pub fn to_owned(&self) -> Result<Row, ErrorMessage> {
+        let mut row = Row::new_empty(self.name);
+        row.meta = self.meta()?;
+
+        for key in self.keys() {
+            let key = key?.to_owned()?;
+            row.keys.insert(key.name.clone(), key);
+        }
+
+        Ok(row)
+    }
+}
+
+/// A document borrowed from the input buffer. Only the header is read up
+/// front; rows are walked on each call to [`YadRef::rows`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct YadRef<'a> {
+    /// The format version.
+    pub version: Version,
+    body: &'a [u8],
+    order: ByteOrder,
+    bloom: Option<BloomFilter>,
+    now: Option<u64>,
+}
+
+impl<'a> YadRef<'a> {
+    /// Reads the header of a serialized document and borrows the rest.
+    ///
+    /// A signature section is skipped without being checked; encrypted
+    /// documents are rejected with `ENCRYPTED_DOCUMENT`, documents with
+    /// varint length descriptors with `VARINT_LENGTHS_NOT_BORROWABLE`,
+    /// documents with a key name dictionary with `DICTIONARY_NOT_BORROWABLE`,
+    /// documents with delta arrays with `DELTA_ARRAYS_NOT_BORROWABLE`, and
+    /// compressed documents with `COMPRESSED_NOT_BORROWABLE`. A checksum
+    /// section is skipped without being checked.
+    ///
+    /// # Example
+    /// ```text
+    /// let doc = YadRef::new(&bytes)?;
+    /// if let Some(row) = doc.row("johan")? {
+    ///     let name: Option<&str> = row.get("name")?.map(|key| key.value.as_str()).transpose()?;
+    /// }
+    /// ```
+    pub fn new(bytes: &'a [u8]) -> Result<Self, ErrorMessage> {
+        let (Header { version, flags }, pos) = body_start(bytes)?;
+
+        if flags.contains(HeaderFlags::VARINT_LENGTHS) {
+            return Err(ErrorMessage(VARINT_LENGTHS_NOT_BORROWABLE));
+        }
+
+        if flags.contains(HeaderFlags::DICTIONARY) {
+            return Err(ErrorMessage(DICTIONARY_NOT_BORROWABLE));
+        }
+
+        if flags.contains(HeaderFlags::DELTA_ARRAYS) {
+            return Err(ErrorMessage(DELTA_ARRAYS_NOT_BORROWABLE));
+        }
+
+        if flags.contains(HeaderFlags::COMPRESSED) {
+            return Err(ErrorMessage(COMPRESSED_NOT_BORROWABLE));
+        }
+
+        let bloom = BloomFilter::read_embedded(bytes)?;
+
+        Ok(Self { version, body: bytes.get(pos..).unwrap_or_default(), order: flags.byte_order(), bloom, now: None })
+    }
+
+    /// Reads the document as of `now`: rows and keys whose expiry timestamp
+    /// ([`Meta::expires`]) is not after `now` are skipped by every lookup and
+    /// walk, as if [`YAD::purge_expired`] had removed them.
+    ///
+    /// # Example
+    /// ```text
+    /// let doc = YadRef::new(&bytes)?.expiring_at(now);
+    /// let session = doc.row("session")?;   // `None` once expired
+    /// ```
+    pub fn expiring_at(mut self, now: u64) -> Self {
+        self.now = Some(now);
+        self
+    }
+
+    /// The byte order of number payloads, read from the header flags.
+    pub fn byte_order(&self) -> ByteOrder {
+        self.order
+    }
+
+    /// Iterates over the rows in file order, skipping expired ones when read
+    /// with [`YadRef::expiring_at`]. The walk is strict: bytes that do not
+    /// frame a row are an error, after which the iterator ends.
+    pub fn rows(&self) -> Rows<'a> {
+        Rows { reader: Reader::new(self.body), order: self.order, now: self.now, failed: false }
+    }
+
+    /// The bloom filter over the row names, when the document embeds one.
+    pub fn bloom_filter(&self) -> Option<&BloomFilter> {
+        self.bloom.as_ref()
+    }
+
+    /// Returns the last row named `name`, which is the one [`YAD`] would keep.
+    ///
+    /// When the document embeds a bloom filter, missing rows are usually
+    /// answered without walking the rows.
+    pub fn row(&self, name: &str) -> Result<Option<RowRef<'a>>, ErrorMessage> {
+        if self.bloom.as_ref().is_some_and(|bloom| !bloom.might_contain(name)) {
+            return Ok(None);
+        }
+
+        let mut found = None;
+        for row in self.rows() {
+            let row = row?;
+            if row.name == name {
+                found = Some(row);
+            }
+        }
+        Ok(found)
+    }
+
+    /// Returns `true` if the document has a row named `name`.
+    pub fn contains_row(&self, name: &str) -> Result<bool, ErrorMessage> {
+        if self.bloom.as_ref().is_some_and(|bloom| !bloom.might_contain(name)) {
+            return Ok(false);
+        }
+
+        for row in self.rows() {
+            if row?.name == name {
+                return Ok(true);
+            }
+        }
+        Ok(false)
+    }
+
+    /// Copies the whole document out of the input buffer.
+    pub fn to_owned(&self) -> Result<YAD, ErrorMessage> {
+        let rows = self.rows()
+            .map(|row| row?.to_owned())
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(YAD::new(self.version.clone(), rows))
+    }
+}
+
+/// Iterator over the values of a [`KeyRef`].
+#[derive(Clone, Debug)]
+pub struct Values<'a> {
+    first: Option<ValueRef<'a>>,
+    more: Reader<'a>,
+    order: ByteOrder,
+}
+
+impl<'a> Values<'a> {
+    /// Iterates over `first` followed by the values encoded back to back in `more`,
+    /// which must already have been walked by [`read_values`]. Numbers are in `order`.
+    pub(crate) fn new(first: &'a [u8], more: &'a [u8], order: ByteOrder) -> Self {
+        Values { first: Some(ValueRef { bytes: first, order }), more: Reader::new(more), order }
+    }
+}
+
+impl<'a> Iterator for Values<'a> {
+    type Item = ValueRef<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(first) = self.first.take() {
+            return Some(first);
+        }
+
+        let order = self.order;
+        self.more.skip_value().ok().map(|bytes| ValueRef { bytes, order })
+    }
+}
+
+/// Iterator over the rows of a [`YadRef`].
+#[derive(Clone, Debug)]
+pub struct Rows<'a> {
+    reader: Reader<'a>,
+    order: ByteOrder,
+    now: Option<u64>,
+    failed: bool,
+}
+
+impl<'a> Iterator for Rows<'a> {
+    type Item = Result<RowRef<'a>, ErrorMessage>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while !self.failed && !self.reader.is_empty() {
+            let row = read_row_ref(&mut self.reader, self.order)
+                .and_then(|row| Ok((!is_expired(row.meta, self.now)?).then_some(RowRef { now: self.now, ..row })));
+            self.failed = row.is_err();
+
+            if let Some(row) = row.transpose() {
+                return Some(row);
+            }
+        }
+
+        None
+    }
+}
+
+/// Iterator over the keys of a [`RowRef`].
+#[derive(Clone, Debug)]
+pub struct Keys<'a> {
+    reader: Reader<'a>,
+    order: ByteOrder,
+    now: Option<u64>,
+    failed: bool,
+}
+
+impl<'a> Iterator for Keys<'a> {
+    type Item = Result<KeyRef<'a>, ErrorMessage>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while !self.failed && !self.reader.is_empty() {
+            let key = read_key_ref(&mut self.reader, self.order)
+                .and_then(|key| Ok((!is_expired(key.meta, self.now)?).then_some(key)));
+            self.failed = key.is_err();
+
+            if let Some(key) = key.transpose() {
+                return Some(key);
+            }
+        }
+
+        None
+    }
+}
+
+/// Returns `true` if the raw metadata block `meta` holds an expiry
+/// timestamp not after `now`; always `false` without `now`.
+fn is_expired(meta: &[u8], now: Option<u64>) -> Result<bool, ErrorMessage> {
+    match now {
+        Some(now) if !meta.is_empty() => Ok(Meta::decode(meta)?.0.is_expired(now)),
+        _ => Ok(false),
+    }
+}
+
+/// Moves past a metadata block, if there is one, returning its raw bytes.
+pub(crate) fn read_meta<'a>(reader: &mut Reader<'a>) -> Result<&'a [u8], ErrorMessage> {
+    let (_, consumed) = Meta::decode(reader.remaining())?;
+    reader.take(consumed)
+}
+
+/// Moves past the values of a key, stopping on its `key_end` header, and
+/// returns the first value with the bytes of any further ones.
+pub(crate) fn read_values<'a>(reader: &mut Reader<'a>, key_end: u8) -> Result<(&'a [u8], &'a [u8]), ErrorMessage> {
+    let first = reader.skip_value()?;
+    let more = reader.remaining();
+    let start = reader.position();
+
+    loop {
+        match reader.peek() {
+            Some(b) if b == key_end => break,
+            Some(_) => {
+                reader.skip_value()?;
+            }
+            None => return Err(ErrorMessage(MALFORMED_KEY_VECTOR)),
+        }
+    }
+
+    Ok((first, &more[..reader.position() - start]))
+}
+
+/// Reads the key at the reader's position (on its start header) without
+/// decoding its values, whose numbers are in `order`.
+pub(crate) fn read_key_ref<'a>(reader: &mut Reader<'a>, order: ByteOrder) -> Result<KeyRef<'a>, ErrorMessage> {
+    if reader.peek() != Some(KEY_START_HEADER) {
+        return Err(ErrorMessage(MALFORMED_KEY_VECTOR));
+    }
+    reader.advance(1)?;
+
+    let (name, consumed) = decode_name_ref(reader.remaining(), KEY_NAME_HEADER)
+        .ok_or(ErrorMessage(MALFORMED_KEY_NAME_VECTOR))?;
+    reader.advance(consumed)?;
+
+    let meta = read_meta(reader)?;
+    let (value, more) = read_values(reader, KEY_END_HEADER)?;
+    reader.advance(1)?;
+
+    Ok(KeyRef { name, meta, value: ValueRef { bytes: value, order }, more })
+}
+
+/// Reads the row at the reader's position (on its start header), walking
+/// its keys only to find where it ends.
+fn read_row_ref<'a>(reader: &mut Reader<'a>, order: ByteOrder) -> Result<RowRef<'a>, ErrorMessage> {
+    if reader.peek() != Some(ROW_START_HEADER) {
+        return Err(ErrorMessage(MALFORMED_ROW_VECTOR));
+    }
+    reader.advance(1)?;
+
+    let (name, consumed) = decode_name_ref(reader.remaining(), ROW_NAME_HEADER)
+        .ok_or(ErrorMessage(MALFORMED_ROW_NAME_VECTOR))?;
+    reader.advance(consumed)?;
+
+    let meta = read_meta(reader)?;
+    let keys = reader.remaining();
+    let start = reader.position();
+
+    loop {
+        match reader.peek() {
+            Some(ROW_END_HEADER) => break,
+            Some(KEY_START_HEADER) => {
+                read_key_ref(reader, order)?;
+            }
+            _ => return Err(ErrorMessage(MALFORMED_ROW_VECTOR)),
+        }
+    }
+
+    let keys = &keys[..reader.position() - start];
+    reader.advance(1)?;
+
+    Ok(RowRef { name, meta, keys, order, now: None })
+}
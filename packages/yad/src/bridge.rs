@@ -0,0 +1,505 @@
+use std::fmt::{Display, Formatter};
+use float16::f16;
+use float8::F8E4M3;
+use serde::de::{self, DeserializeOwned, DeserializeSeed, IntoDeserializer, MapAccess, SeqAccess, Visitor};
+use serde::ser::{self, Impossible, Serialize};
+use yad_core::constants::error::ErrorMessage;
+use yad_core::constants::length::ByteLength;
+use yad_core::constants::types::Type;
+use yad_core::Value;
+use crate::error::{SERDE_MISMATCH, SERDE_NOT_A_STRUCT, SERDE_UNSUPPORTED_TYPE};
+use crate::key::Key;
+use crate::row::Row;
+
+/// An error mapping a row to or from a Rust type with [`Row::deserialize_into`]
+/// or [`Row::from_serialize`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BridgeError {
+    /// What went wrong.
+    pub error: ErrorMessage,
+    /// Where, or serde's own message such as ``missing field `age` ``; empty
+    /// when `error` says it all.
+    pub detail: String,
+}
+
+impl BridgeError {
+    fn new(error: &'static str) -> Self {
+        Self { error: ErrorMessage(error), detail: String::new() }
+    }
+
+    /// Names the key the error was found in, unless it already says where.
+    fn in_key(mut self, name: &str) -> Self {
+        if self.detail.is_empty() {
+            self.detail = format!("key `{}`", name);
+        }
+        self
+    }
+}
+
+impl From<ErrorMessage> for BridgeError {
+    fn from(error: ErrorMessage) -> Self {
+        Self { error, detail: String::new() }
+    }
+}
+
+impl From<BridgeError> for ErrorMessage {
+    fn from(error: BridgeError) -> Self {
+        error.error
+    }
+}
+
+impl Display for BridgeError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self.detail.is_empty() {
+            true => write!(f, "{}", self.error.0),
+            false => write!(f, "{} ({})", self.error.0, self.detail),
+        }
+    }
+}
+
+impl std::error::Error for BridgeError {}
+
+impl ser::Error for BridgeError {
+    fn custom<T: Display>(msg: T) -> Self {
+        Self { error: ErrorMessage(SERDE_MISMATCH), detail: msg.to_string() }
+    }
+}
+
+impl de::Error for BridgeError {
+    fn custom<T: Display>(msg: T) -> Self {
+        Self { error: ErrorMessage(SERDE_MISMATCH), detail: msg.to_string() }
+    }
+}
+
+/// Maps rows to and from Rust types through serde, without a YAD-specific
+/// derive: each field is a key.
+///
+/// - Numbers keep their width, so a `u16` field is a `uint16` key; strings,
+///   booleans and characters map directly, and sequences become arrays.
+/// - `None` fields and unit values are left out of the row, and missing keys
+///   read as `None`.
+/// - Unit enum variants are strings holding the variant name.
+/// - Decimals and big integers read as strings; other extension values as bytes.
+/// - Keys with several values read as sequences.
+/// - Nested structs and maps, enum variants with data and empty strings or
+///   sequences have no key form and fail.
+///
+/// # Example
+/// ```
+/// use serde::{Deserialize, Serialize};
+/// use serde_yad::row::Row;
+///
+/// #[derive(Debug, PartialEq, Serialize, Deserialize)]
+/// struct User { age: u8, name: String, email: Option<String> }
+///
+/// let user = User { age: 17, name: "Johan".into(), email: None };
+/// let row = Row::from_serialize("johan", &user).unwrap();
+/// assert_eq!(row.get_u8("age"), Ok(17));
+/// assert!(row.get("email").is_none());
+/// assert_eq!(row.deserialize_into::<User>(), Ok(user));
+/// ```
+impl Row {
+    /// Builds a row named `name` whose keys are the fields of `value`.
+    ///
+    /// # Errors
+    /// - `SERDE_NOT_A_STRUCT` if `value` is not a struct or a map with string keys.
+    /// - `SERDE_UNSUPPORTED_TYPE` for fields rows cannot hold.
+    /// - The error of the field, such as `STRING_OF_LENGTH_ZERO`.
+    pub fn from_serialize<S: ToString, T: Serialize + ?Sized>(name: S, value: &T) -> Result<Row, BridgeError> {
+        Ok(Row::new(name, value.serialize(RowSerializer)?))
+    }
+
+    /// Reads the keys of the row into a `T`.
+    ///
+    /// # Errors
+    /// Returns `SERDE_MISMATCH` with serde's message when the keys do not
+    /// match `T`, or the error of a value that does not convert, naming its key.
+    pub fn deserialize_into<T: DeserializeOwned>(&self) -> Result<T, BridgeError> {
+        T::deserialize(RowDeserializer(self))
+    }
+}
+
+/// Writes a struct or map as the keys of a row.
+struct RowSerializer;
+
+/// Writes the fields of a struct or the entries of a map as keys.
+struct KeysSerializer {
+    keys: Vec<Key>,
+    name: Option<String>,
+}
+
+impl KeysSerializer {
+    fn push<T: Serialize + ?Sized>(&mut self, name: &str, value: &T) -> Result<(), BridgeError> {
+        if let Some(value) = value.serialize(ValueSerializer).map_err(|e| e.in_key(name))? {
+            self.keys.push(Key::new(name, value));
+        }
+        Ok(())
+    }
+}
+
+/// Rejects everything but structs and maps.
+macro_rules! not_a_struct {
+    ($($method:ident($($arg:ty),*)),* $(,)?) => {$(
+        fn $method(self, $(_: $arg),*) -> Result<Self::Ok, BridgeError> {
+            Err(BridgeError::new(SERDE_NOT_A_STRUCT))
+        }
+    )*};
+}
+
+impl ser::Serializer for RowSerializer {
+    type Ok = Vec<Key>;
+    type Error = BridgeError;
+    type SerializeSeq = Impossible<Vec<Key>, BridgeError>;
+    type SerializeTuple = Impossible<Vec<Key>, BridgeError>;
+    type SerializeTupleStruct = Impossible<Vec<Key>, BridgeError>;
+    type SerializeTupleVariant = Impossible<Vec<Key>, BridgeError>;
+    type SerializeMap = KeysSerializer;
+    type SerializeStruct = KeysSerializer;
+    type SerializeStructVariant = Impossible<Vec<Key>, BridgeError>;
+
+    not_a_struct! {
+        serialize_bool(bool), serialize_i8(i8), serialize_i16(i16), serialize_i32(i32), serialize_i64(i64),
+        serialize_u8(u8), serialize_u16(u16), serialize_u32(u32), serialize_u64(u64),
+        serialize_f32(f32), serialize_f64(f64), serialize_char(char), serialize_str(&str), serialize_bytes(&[u8]),
+        serialize_none(), serialize_unit(), serialize_unit_struct(&'static str),
+        serialize_unit_variant(&'static str, u32, &'static str),
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(self, value: &T) -> Result<Vec<Key>, BridgeError> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(self, _: &'static str, value: &T) -> Result<Vec<Key>, BridgeError> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(self, _: &'static str, _: u32, _: &'static str, _: &T) -> Result<Vec<Key>, BridgeError> {
+        Err(BridgeError::new(SERDE_NOT_A_STRUCT))
+    }
+
+    fn serialize_seq(self, _: Option<usize>) -> Result<Self::SerializeSeq, BridgeError> {
+        Err(BridgeError::new(SERDE_NOT_A_STRUCT))
+    }
+
+    fn serialize_tuple(self, _: usize) -> Result<Self::SerializeTuple, BridgeError> {
+        Err(BridgeError::new(SERDE_NOT_A_STRUCT))
+    }
+
+    fn serialize_tuple_struct(self, _: &'static str, _: usize) -> Result<Self::SerializeTupleStruct, BridgeError> {
+        Err(BridgeError::new(SERDE_NOT_A_STRUCT))
+    }
+
+    fn serialize_tuple_variant(self, _: &'static str, _: u32, _: &'static str, _: usize) -> Result<Self::SerializeTupleVariant, BridgeError> {
+        Err(BridgeError::new(SERDE_NOT_A_STRUCT))
+    }
+
+    fn serialize_map(self, len: Option<usize>) -> Result<KeysSerializer, BridgeError> {
+        Ok(KeysSerializer { keys: Vec::with_capacity(len.unwrap_or(0)), name: None })
+    }
+
+    fn serialize_struct(self, _: &'static str, len: usize) -> Result<KeysSerializer, BridgeError> {
+        Ok(KeysSerializer { keys: Vec::with_capacity(len), name: None })
+    }
+
+    fn serialize_struct_variant(self, _: &'static str, _: u32, _: &'static str, _: usize) -> Result<Self::SerializeStructVariant, BridgeError> {
+        Err(BridgeError::new(SERDE_NOT_A_STRUCT))
+    }
+}
+
+impl ser::SerializeMap for KeysSerializer {
+    type Ok = Vec<Key>;
+    type Error = BridgeError;
+
+    fn serialize_key<T: Serialize + ?Sized>(&mut self, key: &T) -> Result<(), BridgeError> {
+        let name = key.serialize(ValueSerializer)?.ok_or(BridgeError::new(SERDE_NOT_A_STRUCT))?;
+        self.name = Some(name.as_str().map_err(|_| BridgeError::new(SERDE_NOT_A_STRUCT))?.to_string());
+        Ok(())
+    }
+
+    fn serialize_value<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), BridgeError> {
+        let name = self.name.take().ok_or(BridgeError::new(SERDE_NOT_A_STRUCT))?;
+        self.push(&name, value)
+    }
+
+    fn end(self) -> Result<Vec<Key>, BridgeError> {
+        Ok(self.keys)
+    }
+}
+
+impl ser::SerializeStruct for KeysSerializer {
+    type Ok = Vec<Key>;
+    type Error = BridgeError;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, name: &'static str, value: &T) -> Result<(), BridgeError> {
+        self.push(name, value)
+    }
+
+    fn end(self) -> Result<Vec<Key>, BridgeError> {
+        Ok(self.keys)
+    }
+}
+
+/// Writes a field as a value, or `None` for fields left out of the row.
+struct ValueSerializer;
+
+/// Writes a sequence as an array.
+struct ArraySerializer {
+    items: Vec<Value>,
+}
+
+impl ArraySerializer {
+    fn push<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), BridgeError> {
+        // Arrays have no empty slots.
+        let item = value.serialize(ValueSerializer)?.ok_or(BridgeError::new(SERDE_UNSUPPORTED_TYPE))?;
+        self.items.push(item);
+        Ok(())
+    }
+
+    fn end(self) -> Result<Option<Value>, BridgeError> {
+        Ok(Some(Value::try_from(self.items)?))
+    }
+}
+
+/// Writes a value of a type with a `From` conversion into [`Value`].
+macro_rules! serialize_into_value {
+    ($($method:ident($t:ty)),* $(,)?) => {$(
+        fn $method(self, value: $t) -> Result<Option<Value>, BridgeError> {
+            Ok(Some(Value::from(value)))
+        }
+    )*};
+}
+
+impl ser::Serializer for ValueSerializer {
+    type Ok = Option<Value>;
+    type Error = BridgeError;
+    type SerializeSeq = ArraySerializer;
+    type SerializeTuple = ArraySerializer;
+    type SerializeTupleStruct = ArraySerializer;
+    type SerializeTupleVariant = Impossible<Option<Value>, BridgeError>;
+    type SerializeMap = Impossible<Option<Value>, BridgeError>;
+    type SerializeStruct = Impossible<Option<Value>, BridgeError>;
+    type SerializeStructVariant = Impossible<Option<Value>, BridgeError>;
+
+    serialize_into_value! {
+        serialize_bool(bool), serialize_i8(i8), serialize_i16(i16), serialize_i32(i32), serialize_i64(i64),
+        serialize_u8(u8), serialize_u16(u16), serialize_u32(u32), serialize_u64(u64),
+        serialize_f32(f32), serialize_f64(f64), serialize_char(char),
+    }
+
+    fn serialize_str(self, value: &str) -> Result<Option<Value>, BridgeError> {
+        Ok(Some(Value::try_from(value)?))
+    }
+
+    fn serialize_bytes(self, value: &[u8]) -> Result<Option<Value>, BridgeError> {
+        Ok(Some(Value::try_from(value)?))
+    }
+
+    fn serialize_none(self) -> Result<Option<Value>, BridgeError> {
+        Ok(None)
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(self, value: &T) -> Result<Option<Value>, BridgeError> {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<Option<Value>, BridgeError> {
+        Ok(None)
+    }
+
+    fn serialize_unit_struct(self, _: &'static str) -> Result<Option<Value>, BridgeError> {
+        Ok(None)
+    }
+
+    fn serialize_unit_variant(self, _: &'static str, _: u32, variant: &'static str) -> Result<Option<Value>, BridgeError> {
+        self.serialize_str(variant)
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(self, _: &'static str, value: &T) -> Result<Option<Value>, BridgeError> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(self, _: &'static str, _: u32, _: &'static str, _: &T) -> Result<Option<Value>, BridgeError> {
+        Err(BridgeError::new(SERDE_UNSUPPORTED_TYPE))
+    }
+
+    fn serialize_seq(self, len: Option<usize>) -> Result<ArraySerializer, BridgeError> {
+        Ok(ArraySerializer { items: Vec::with_capacity(len.unwrap_or(0)) })
+    }
+
+    fn serialize_tuple(self, len: usize) -> Result<ArraySerializer, BridgeError> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_struct(self, _: &'static str, len: usize) -> Result<ArraySerializer, BridgeError> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_variant(self, _: &'static str, _: u32, _: &'static str, _: usize) -> Result<Self::SerializeTupleVariant, BridgeError> {
+        Err(BridgeError::new(SERDE_UNSUPPORTED_TYPE))
+    }
+
+    fn serialize_map(self, _: Option<usize>) -> Result<Self::SerializeMap, BridgeError> {
+        Err(BridgeError::new(SERDE_UNSUPPORTED_TYPE))
+    }
+
+    fn serialize_struct(self, _: &'static str, _: usize) -> Result<Self::SerializeStruct, BridgeError> {
+        Err(BridgeError::new(SERDE_UNSUPPORTED_TYPE))
+    }
+
+    fn serialize_struct_variant(self, _: &'static str, _: u32, _: &'static str, _: usize) -> Result<Self::SerializeStructVariant, BridgeError> {
+        Err(BridgeError::new(SERDE_UNSUPPORTED_TYPE))
+    }
+}
+
+impl ser::SerializeSeq for ArraySerializer {
+    type Ok = Option<Value>;
+    type Error = BridgeError;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), BridgeError> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<Option<Value>, BridgeError> {
+        ArraySerializer::end(self)
+    }
+}
+
+impl ser::SerializeTuple for ArraySerializer {
+    type Ok = Option<Value>;
+    type Error = BridgeError;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), BridgeError> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<Option<Value>, BridgeError> {
+        ArraySerializer::end(self)
+    }
+}
+
+impl ser::SerializeTupleStruct for ArraySerializer {
+    type Ok = Option<Value>;
+    type Error = BridgeError;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), BridgeError> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<Option<Value>, BridgeError> {
+        ArraySerializer::end(self)
+    }
+}
+
+/// Reads the keys of a row as a map.
+struct RowDeserializer<'a>(&'a Row);
+
+/// Walks the keys of a row, name then value.
+struct KeysAccess<'a> {
+    keys: std::collections::btree_map::Values<'a, String, Key>,
+    current: Option<&'a Key>,
+}
+
+impl<'de> de::Deserializer<'de> for RowDeserializer<'_> {
+    type Error = BridgeError;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, BridgeError> {
+        visitor.visit_map(KeysAccess { keys: self.0.keys.values(), current: None })
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, BridgeError> {
+        visitor.visit_some(self)
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(self, _: &'static str, visitor: V) -> Result<V::Value, BridgeError> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    serde::forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string bytes byte_buf unit unit_struct
+        seq tuple tuple_struct map struct enum identifier ignored_any
+    }
+}
+
+impl<'de> MapAccess<'de> for KeysAccess<'_> {
+    type Error = BridgeError;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(&mut self, seed: K) -> Result<Option<K::Value>, BridgeError> {
+        self.current = self.keys.next();
+        match self.current {
+            Some(key) => seed.deserialize(key.name.as_str().into_deserializer()).map(Some),
+            None => Ok(None),
+        }
+    }
+
+    fn next_value_seed<V: DeserializeSeed<'de>>(&mut self, seed: V) -> Result<V::Value, BridgeError> {
+        let key = self.current.take().ok_or(BridgeError::new(SERDE_MISMATCH))?;
+        let value = match key.values().count() {
+            1 => key.value.clone(),
+            _ => Value::try_from(key.values().cloned().collect::<Vec<Value>>())?,
+        };
+        seed.deserialize(ValueDeserializer(value)).map_err(|e| e.in_key(&key.name))
+    }
+}
+
+/// Reads a value as what it holds.
+struct ValueDeserializer(Value);
+
+/// Walks the items of an array.
+struct ItemsAccess(std::vec::IntoIter<Value>);
+
+impl<'de> de::Deserializer<'de> for ValueDeserializer {
+    type Error = BridgeError;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, BridgeError> {
+        let value = self.0;
+        match (value.r#type, value.length) {
+            (Type::Uint, ByteLength::One) => visitor.visit_u8(value.try_into()?),
+            (Type::Uint, ByteLength::Two) => visitor.visit_u16(value.try_into()?),
+            (Type::Uint, ByteLength::Four) => visitor.visit_u32(value.try_into()?),
+            (Type::Uint, _) => visitor.visit_u64(value.try_into()?),
+            (Type::Int, ByteLength::One) => visitor.visit_i8(value.try_into()?),
+            (Type::Int, ByteLength::Two) => visitor.visit_i16(value.try_into()?),
+            (Type::Int, ByteLength::Four) => visitor.visit_i32(value.try_into()?),
+            (Type::Int, _) => visitor.visit_i64(value.try_into()?),
+            (Type::Float, ByteLength::One) => visitor.visit_f32(TryInto::<F8E4M3>::try_into(value)?.to_f32()),
+            (Type::Float, ByteLength::Two) => visitor.visit_f32(TryInto::<f16>::try_into(value)?.to_f32()),
+            (Type::Float, ByteLength::Four) => visitor.visit_f32(value.try_into()?),
+            (Type::Float, _) => visitor.visit_f64(value.try_into()?),
+            (Type::String, _) => visitor.visit_string(value.try_into()?),
+            (Type::Bool | Type::True | Type::False, _) => visitor.visit_bool(value.try_into()?),
+            (Type::Array, _) => visitor.visit_seq(ItemsAccess(TryInto::<Vec<Value>>::try_into(value)?.into_iter())),
+            (Type::Extension, _) if value.is_decimal() || value.is_big_integer() => visitor.visit_string(value.try_display()?),
+            (Type::Extension, _) => visitor.visit_byte_buf(value.extension_data()?.to_vec()),
+        }
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, BridgeError> {
+        visitor.visit_some(self)
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(self, _: &'static str, visitor: V) -> Result<V::Value, BridgeError> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(self, _: &'static str, _: &'static [&'static str], visitor: V) -> Result<V::Value, BridgeError> {
+        let variant: String = self.0.try_into()?;
+        visitor.visit_enum(variant.into_deserializer())
+    }
+
+    serde::forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string bytes byte_buf unit unit_struct
+        seq tuple tuple_struct map struct identifier ignored_any
+    }
+}
+
+impl<'de> SeqAccess<'de> for ItemsAccess {
+    type Error = BridgeError;
+
+    fn next_element_seed<T: DeserializeSeed<'de>>(&mut self, seed: T) -> Result<Option<T::Value>, BridgeError> {
+        self.0.next().map(|item| seed.deserialize(ValueDeserializer(item))).transpose()
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.0.len())
+    }
+}
@@ -0,0 +1,76 @@
+use yad_core::constants::error::ErrorMessage;
+use yad_core::Value;
+use crate::row::Row;
+use crate::{Version, YAD};
+
+/// Builds a [`Row`] key by key; handed to the closure of [`YadBuilder::row`].
+///
+/// Values are converted as they are added. The first conversion that fails
+/// is kept and returned by [`YadBuilder::build`].
+#[derive(Debug)]
+pub struct RowBuilder {
+    row: Row,
+    error: Option<ErrorMessage>,
+}
+
+impl RowBuilder {
+    /// Adds a key, replacing any earlier key of the same name.
+    ///
+    /// `value` is anything convertible to a [`Value`]: numbers, `bool`, `&str`,
+    /// `String`, `Vec<Value>` or a `Value`.
+    pub fn key<S: ToString, V>(mut self, name: S, value: V) -> Self
+    where
+        V: TryInto<Value>,
+        V::Error: Into<ErrorMessage>,
+    {
+        if let Err(error) = self.row.insert_key(name, value) {
+            self.error.get_or_insert(error);
+        }
+
+        self
+    }
+}
+
+/// Builds a [`YAD`] document fluently.
+///
+/// # Example
+/// ```text
+/// let yad = YadBuilder::new(CURRENT_VERSION)
+///     .row("johan", |r| r.key("name", "Johan").key("age", 17u8))
+///     .build()?;
+/// ```
+#[derive(Debug)]
+pub struct YadBuilder {
+    yad: YAD,
+    error: Option<ErrorMessage>,
+}
+
+impl YadBuilder {
+    /// Starts an empty document of the given version.
+    pub fn new(version: Version) -> Self {
+        Self { yad: YAD::new_empty(version), error: None }
+    }
+
+    /// Adds a row filled in by `keys`, replacing any earlier row of the same name.
+    pub fn row<S: ToString>(mut self, name: S, keys: impl FnOnce(RowBuilder) -> RowBuilder) -> Self {
+        let builder = keys(RowBuilder { row: Row::new_empty(name), error: None });
+
+        if let Some(error) = builder.error {
+            self.error.get_or_insert(error);
+        }
+        self.yad.rows.insert(builder.row.name.clone(), builder.row);
+
+        self
+    }
+
+    /// Finishes the document.
+    ///
+    /// # Errors
+    /// Returns the first value conversion that failed, such as an empty string.
+    pub fn build(self) -> Result<YAD, ErrorMessage> {
+        match self.error {
+            Some(error) => Err(error),
+            None => Ok(self.yad),
+        }
+    }
+}
@@ -0,0 +1,183 @@
+use std::collections::{BTreeMap, HashMap};
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+use yad_core::constants::error::ErrorMessage;
+use crate::borrowed::YadRef;
+use crate::row::Row;
+
+/// Bounds of the row cache of a [`CachedYadRef`]. The cache evicts the least
+/// recently used rows as soon as either bound is exceeded.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct CacheConfig {
+    /// Most rows kept decoded.
+    pub max_rows: usize,
+    /// Most bytes kept decoded, counted as the encoded size of each row
+    /// ([`Row::encoded_len`]). Rows larger than this are never cached.
+    pub max_bytes: usize,
+}
+
+impl Default for CacheConfig {
+    /// 1024 rows and 64 MiB.
+    fn default() -> Self {
+        Self { max_rows: 1024, max_bytes: 64 * 1024 * 1024 }
+    }
+}
+
+/// A cached row with its size and the tick of its last use.
+struct Entry {
+    row: Arc<Row>,
+    bytes: usize,
+    used: u64,
+}
+
+/// The rows of a [`CachedYadRef`] with their order of use.
+#[derive(Default)]
+struct Lru {
+    entries: HashMap<String, Entry>,
+    /// Tick of last use → row name, oldest first.
+    order: BTreeMap<u64, String>,
+    tick: u64,
+    bytes: usize,
+    hits: u64,
+    misses: u64,
+}
+
+impl Lru {
+    /// Marks the row `name` as used now and returns it, if cached.
+    fn get(&mut self, name: &str) -> Option<Arc<Row>> {
+        self.tick += 1;
+        let entry = self.entries.get_mut(name)?;
+        self.order.remove(&entry.used);
+        self.order.insert(self.tick, name.to_string());
+        entry.used = self.tick;
+        Some(entry.row.clone())
+    }
+
+    /// Caches `row`, evicting the least recently used rows to stay within `config`.
+    fn insert(&mut self, row: Arc<Row>, config: &CacheConfig) {
+        // Another thread may have decoded the same row meanwhile.
+        if let Some(old) = self.entries.remove(&row.name) {
+            self.order.remove(&old.used);
+            self.bytes -= old.bytes;
+        }
+
+        let bytes = row.encoded_len();
+        if bytes > config.max_bytes || config.max_rows == 0 {
+            return;
+        }
+
+        while self.entries.len() >= config.max_rows || self.bytes + bytes > config.max_bytes {
+            let Some((_, oldest)) = self.order.pop_first() else { break };
+            if let Some(entry) = self.entries.remove(&oldest) {
+                self.bytes -= entry.bytes;
+            }
+        }
+
+        self.tick += 1;
+        self.bytes += bytes;
+        self.order.insert(self.tick, row.name.clone());
+        self.entries.insert(row.name.clone(), Entry { row, bytes, used: self.tick });
+    }
+}
+
+/// A [`YadRef`] that keeps recently read rows decoded, so repeated access to
+/// hot rows does not decode them again while memory stays bounded by a
+/// [`CacheConfig`].
+///
+/// The input can be any byte slice, such as a memory-mapped file. Rows are
+/// handed out as `Arc<Row>`, and the cache can be shared between threads.
+///
+/// # Example
+/// ```
+/// use serde_yad::borrowed::YadRef;
+/// use serde_yad::cache::{CacheConfig, CachedYadRef};
+/// use serde_yad::{row::Row, key::Key, YAD, CURRENT_VERSION};
+///
+/// let bytes = YAD::new(CURRENT_VERSION, vec![Row::new("johan", vec![Key::new("age", 17u8)])]).serialize().unwrap();
+/// let cached = CachedYadRef::new(YadRef::new(&bytes).unwrap(), CacheConfig { max_rows: 100, max_bytes: 1 << 20 });
+///
+/// let row = cached.row("johan").unwrap().unwrap();
+/// assert_eq!(row.name, "johan");
+/// cached.row("johan").unwrap();
+/// assert_eq!((cached.hits(), cached.misses()), (1, 1));
+/// ```
+pub struct CachedYadRef<'a> {
+    yad: YadRef<'a>,
+    config: CacheConfig,
+    lru: Mutex<Lru>,
+}
+
+impl<'a> CachedYadRef<'a> {
+    /// Wraps `yad` with an empty cache bounded by `config`.
+    pub fn new(yad: YadRef<'a>, config: CacheConfig) -> Self {
+        Self { yad, config, lru: Mutex::new(Lru::default()) }
+    }
+
+    /// The underlying document.
+    pub fn yad(&self) -> &YadRef<'a> {
+        &self.yad
+    }
+
+    /// The bounds of the cache.
+    pub fn config(&self) -> CacheConfig {
+        self.config
+    }
+
+    /// Locks the cache.
+    fn lru(&self) -> MutexGuard<'_, Lru> {
+        self.lru.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Returns the row `name`, decoding it with [`YadRef::row`] unless it is cached.
+    ///
+    /// # Returns
+    /// - `Ok(Some(Arc<Row>))`: The decoded row.
+    /// - `Ok(None)`: If the document has no such row; this is not cached.
+    ///
+    /// # Errors
+    /// Returns the errors of [`YadRef::row`] and [`crate::borrowed::RowRef::to_owned`].
+    pub fn row(&self, name: &str) -> Result<Option<Arc<Row>>, ErrorMessage> {
+        {
+            let mut lru = self.lru();
+            if let Some(row) = lru.get(name) {
+                lru.hits += 1;
+                return Ok(Some(row));
+            }
+            lru.misses += 1;
+        }
+
+        // Decode without holding the lock, so other rows stay readable.
+        let Some(row) = self.yad.row(name)? else { return Ok(None) };
+        let row = Arc::new(row.to_owned()?);
+
+        self.lru().insert(row.clone(), &self.config);
+        Ok(Some(row))
+    }
+
+    /// The number of rows currently cached.
+    pub fn cached_rows(&self) -> usize {
+        self.lru().entries.len()
+    }
+
+    /// The encoded size of the rows currently cached.
+    pub fn cached_bytes(&self) -> usize {
+        self.lru().bytes
+    }
+
+    /// The number of lookups answered from the cache.
+    pub fn hits(&self) -> u64 {
+        self.lru().hits
+    }
+
+    /// The number of lookups that had to read the document.
+    pub fn misses(&self) -> u64 {
+        self.lru().misses
+    }
+
+    /// Drops every cached row; the hit and miss counts are kept.
+    pub fn clear(&self) {
+        let mut lru = self.lru();
+        lru.entries.clear();
+        lru.order.clear();
+        lru.bytes = 0;
+    }
+}
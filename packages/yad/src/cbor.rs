@@ -0,0 +1,79 @@
+use yad_core::constants::error::ErrorMessage;
+use yad_core::Value;
+use crate::error::{CBOR_NAME_NOT_TEXT, CBOR_ROW_NOT_A_MAP, MALFORMED_CBOR};
+use crate::key::Key;
+use crate::row::Row;
+use crate::{CURRENT_VERSION, YAD};
+
+/// Returns the text of a CBOR map key, which must be a row or key name.
+fn name(cbor: &ciborium::Value) -> Result<&str, ErrorMessage> {
+    cbor.as_text().ok_or(ErrorMessage(CBOR_NAME_NOT_TEXT))
+}
+
+impl YAD {
+    /// Converts the document into a CBOR map of rows, each a map of keys to
+    /// values (see [`Value::to_cbor`]).
+    ///
+    /// The version is not written; metadata is dropped.
+    ///
+    /// # Errors
+    /// Returns an error if a value is malformed.
+    pub fn to_cbor(&self) -> Result<ciborium::Value, ErrorMessage> {
+        let mut rows = Vec::with_capacity(self.rows.len());
+
+        for row in self.rows.values() {
+            let mut keys = Vec::with_capacity(row.keys.len());
+            for key in row.keys.values() {
+                keys.push((ciborium::Value::Text(key.name.clone()), key.value.to_cbor()?));
+            }
+            rows.push((ciborium::Value::Text(row.name.clone()), ciborium::Value::Map(keys)));
+        }
+
+        Ok(ciborium::Value::Map(rows))
+    }
+
+    /// Builds a document of the [`CURRENT_VERSION`] from a CBOR map shaped
+    /// like the output of [`YAD::to_cbor`].
+    ///
+    /// # Errors
+    /// - `CBOR_ROW_NOT_A_MAP` if the document or one of its rows is not a map.
+    /// - `CBOR_NAME_NOT_TEXT` if a row or key name is not text.
+    /// - Any error of [`Value::from_cbor`] on a value.
+    pub fn from_cbor(cbor: &ciborium::Value) -> Result<Self, ErrorMessage> {
+        let rows = cbor.as_map().ok_or(ErrorMessage(CBOR_ROW_NOT_A_MAP))?;
+        let mut yad = Self::new_empty(CURRENT_VERSION);
+
+        for (row_name, keys) in rows {
+            let keys = keys.as_map().ok_or(ErrorMessage(CBOR_ROW_NOT_A_MAP))?;
+            let mut row = Row::new_empty(name(row_name)?);
+
+            for (key_name, value) in keys {
+                let key = Key::new(name(key_name)?, Value::from_cbor(value)?);
+                row.keys.insert(key.name.clone(), key);
+            }
+
+            yad.rows.insert(row.name.clone(), row);
+        }
+
+        Ok(yad)
+    }
+
+    /// Encodes [`YAD::to_cbor`] as CBOR bytes.
+    ///
+    /// # Errors
+    /// Returns an error if a value is malformed.
+    pub fn to_cbor_bytes(&self) -> Result<Vec<u8>, ErrorMessage> {
+        let mut bytes = vec![];
+        ciborium::into_writer(&self.to_cbor()?, &mut bytes).map_err(|_| ErrorMessage(MALFORMED_CBOR))?;
+        Ok(bytes)
+    }
+
+    /// Decodes CBOR bytes and converts them with [`YAD::from_cbor`].
+    ///
+    /// # Errors
+    /// Returns `MALFORMED_CBOR` if the bytes are not valid CBOR, or any error of [`YAD::from_cbor`].
+    pub fn from_cbor_bytes(bytes: &[u8]) -> Result<Self, ErrorMessage> {
+        let cbor: ciborium::Value = ciborium::from_reader(bytes).map_err(|_| ErrorMessage(MALFORMED_CBOR))?;
+        Self::from_cbor(&cbor)
+    }
+}
@@ -0,0 +1,56 @@
+use yad_core::constants::error::ErrorMessage;
+use crate::constants::CHECKSUM_HEADER;
+use crate::error::MALFORMED_CHECKSUM;
+
+/// Length of the checksum section: its header and the big-endian CRC-32.
+pub(crate) const CHECKSUM_SECTION_LENGTH: usize = 5;
+
+/// The CRC-32 lookup table (IEEE 802.3, reflected polynomial `0xEDB88320`).
+const TABLE: [u32; 256] = {
+    let mut table = [0u32; 256];
+    let mut i = 0;
+    while i < 256 {
+        let mut crc = i as u32;
+        let mut bit = 0;
+        while bit < 8 {
+            crc = if crc & 1 == 1 { (crc >> 1) ^ 0xEDB8_8320 } else { crc >> 1 };
+            bit += 1;
+        }
+        table[i] = crc;
+        i += 1;
+    }
+    table
+};
+
+/// The CRC-32 of `bytes`, as computed by zlib and most archivers.
+pub(crate) fn crc32(bytes: &[u8]) -> u32 {
+    !bytes.iter().fold(!0u32, |crc, byte| TABLE[((crc ^ *byte as u32) & 0xFF) as usize] ^ (crc >> 8))
+}
+
+/// Returns the checksum section covering `body`, the bytes written after it.
+pub(crate) fn checksum_section(body: &[u8]) -> [u8; CHECKSUM_SECTION_LENGTH] {
+    let [a, b, c, d] = crc32(body).to_be_bytes();
+    [CHECKSUM_HEADER, a, b, c, d]
+}
+
+/// Removes a leading checksum section from a document body (the bytes after
+/// the bloom filter section), checking it against the bytes after it.
+///
+/// # Returns
+/// Whether the bytes after the section match it; `true` without a section.
+///
+/// # Errors
+/// Returns `MALFORMED_CHECKSUM` if the section is truncated.
+pub(crate) fn strip_checksum_section(bytes: &mut Vec<u8>) -> Result<bool, ErrorMessage> {
+    if bytes.first() != Some(&CHECKSUM_HEADER) {
+        return Ok(true);
+    }
+
+    let expected: [u8; 4] = bytes.get(1..CHECKSUM_SECTION_LENGTH)
+        .and_then(|s| s.try_into().ok())
+        .ok_or(ErrorMessage(MALFORMED_CHECKSUM))?;
+
+    let matches = crc32(&bytes[CHECKSUM_SECTION_LENGTH..]) == u32::from_be_bytes(expected);
+    bytes.drain(..CHECKSUM_SECTION_LENGTH);
+    Ok(matches)
+}
@@ -0,0 +1,269 @@
+use std::fmt::{Display, Formatter};
+use bytes::{Buf, BufMut, BytesMut};
+use tokio_util::codec::{Decoder, Encoder};
+use yad_core::constants::error::{ErrorMessage, NOT_ENOUGH_BYTES};
+use yad_core::varint::{read_varint, write_varint};
+use yad_core::Value;
+use crate::error::{FRAME_TOO_LARGE, STREAM_IO_FAILED};
+use crate::YAD;
+
+/// Largest frame the codecs accept unless configured otherwise, 8 MiB.
+pub const DEFAULT_MAX_FRAME_LENGTH: usize = 8 * 1024 * 1024;
+
+/// An error framing YAD data over a stream with [`YadCodec`] or [`ValueCodec`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CodecError {
+    /// What went wrong: `STREAM_IO_FAILED` if the transport failed, or why
+    /// a frame could not be encoded or decoded.
+    pub error: ErrorMessage,
+    /// The message of the I/O error; empty when `error` says it all.
+    pub detail: String,
+}
+
+impl Display for CodecError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self.detail.is_empty() {
+            true => write!(f, "{}", self.error.0),
+            false => write!(f, "{} ({})", self.error.0, self.detail),
+        }
+    }
+}
+
+impl std::error::Error for CodecError {}
+
+impl From<ErrorMessage> for CodecError {
+    fn from(error: ErrorMessage) -> Self {
+        Self { error, detail: String::new() }
+    }
+}
+
+impl From<std::io::Error> for CodecError {
+    fn from(error: std::io::Error) -> Self {
+        Self { error: ErrorMessage(STREAM_IO_FAILED), detail: error.to_string() }
+    }
+}
+
+impl From<CodecError> for ErrorMessage {
+    fn from(error: CodecError) -> Self {
+        error.error
+    }
+}
+
+/// Frames whole documents over a byte stream, for use with
+/// `tokio_util::codec::Framed` on a TCP connection.
+///
+/// Each frame is the length of the document as an unsigned LEB128 varint
+/// followed by the bytes of [`YAD::serialize`]. Partial reads are left in the
+/// buffer until the whole frame has arrived.
+///
+/// # Example
+/// ```
+/// use bytes::BytesMut;
+/// use tokio_util::codec::{Decoder, Encoder};
+/// use serde_yad::codec::YadCodec;
+/// use serde_yad::{row::Row, key::Key, YAD, CURRENT_VERSION};
+///
+/// let yad = YAD::new(CURRENT_VERSION, vec![Row::new("johan", vec![Key::new("age", 17u8)])]);
+/// let mut codec = YadCodec::new();
+/// let mut buffer = BytesMut::new();
+/// codec.encode(&yad, &mut buffer).unwrap();
+///
+/// let mut partial = buffer.split_to(4);
+/// assert_eq!(codec.decode(&mut partial), Ok(None));
+/// partial.unsplit(buffer);
+/// assert_eq!(codec.decode(&mut partial), Ok(Some(yad)));
+/// ```
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct YadCodec {
+    max_frame_length: usize,
+}
+
+impl YadCodec {
+    /// Creates a codec accepting documents up to [`DEFAULT_MAX_FRAME_LENGTH`] bytes.
+    pub fn new() -> Self {
+        Self { max_frame_length: DEFAULT_MAX_FRAME_LENGTH }
+    }
+
+    /// Sets the largest document, in bytes, the codec encodes or decodes.
+    pub fn with_max_frame_length(mut self, max_frame_length: usize) -> Self {
+        self.max_frame_length = max_frame_length;
+        self
+    }
+
+    /// The largest document, in bytes, the codec encodes or decodes.
+    pub fn max_frame_length(&self) -> usize {
+        self.max_frame_length
+    }
+}
+
+impl Default for YadCodec {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Decoder for YadCodec {
+    type Item = YAD;
+    type Error = CodecError;
+
+    /// Decodes the next document once its whole frame is buffered.
+    ///
+    /// # Errors
+    /// - `FRAME_TOO_LARGE` if the frame declares more than the maximum length,
+    ///   before any of it is buffered.
+    /// - `MALFORMED_VARINT` for a malformed length, or any error of
+    ///   [`YAD::deserialize`] on the document.
+    fn decode(&mut self, src: &mut BytesMut) -> Result<Option<YAD>, CodecError> {
+        let (length, prefix) = match read_varint(src) {
+            Ok(read) => read,
+            Err(ErrorMessage(NOT_ENOUGH_BYTES)) => return Ok(None),
+            Err(error) => return Err(error.into()),
+        };
+
+        let length = usize::try_from(length).unwrap_or(usize::MAX);
+        if length > self.max_frame_length {
+            return Err(ErrorMessage(FRAME_TOO_LARGE).into());
+        }
+        if src.len() - prefix < length {
+            src.reserve(prefix + length - src.len());
+            return Ok(None);
+        }
+
+        src.advance(prefix);
+        let frame = src.split_to(length);
+        Ok(Some(YAD::deserialize(frame.to_vec())?))
+    }
+}
+
+impl Encoder<&YAD> for YadCodec {
+    type Error = CodecError;
+
+    /// Writes the document as a length-prefixed frame.
+    ///
+    /// # Errors
+    /// Returns `FRAME_TOO_LARGE` if the document is larger than the maximum
+    /// length, or any error of [`YAD::serialize`].
+    fn encode(&mut self, yad: &YAD, dst: &mut BytesMut) -> Result<(), CodecError> {
+        let bytes = yad.serialize()?;
+        if bytes.len() > self.max_frame_length {
+            return Err(ErrorMessage(FRAME_TOO_LARGE).into());
+        }
+
+        let mut prefix = Vec::with_capacity(10);
+        write_varint(bytes.len() as u64, &mut prefix);
+        dst.reserve(prefix.len() + bytes.len());
+        dst.put_slice(&prefix);
+        dst.put_slice(&bytes);
+        Ok(())
+    }
+}
+
+impl Encoder<YAD> for YadCodec {
+    type Error = CodecError;
+
+    fn encode(&mut self, yad: YAD, dst: &mut BytesMut) -> Result<(), CodecError> {
+        self.encode(&yad, dst)
+    }
+}
+
+/// Frames single values over a byte stream, for use with
+/// `tokio_util::codec::Framed`.
+///
+/// Values carry their own length, so frames are their encoded bytes with no
+/// prefix; the decoder waits until [`Value::encoded_len`] finds a whole value.
+///
+/// # Example
+/// ```
+/// use bytes::BytesMut;
+/// use tokio_util::codec::{Decoder, Encoder};
+/// use serde_yad::codec::ValueCodec;
+/// use serde_yad::Value;
+///
+/// let mut codec = ValueCodec::new();
+/// let mut buffer = BytesMut::new();
+/// codec.encode(Value::try_from("hello").unwrap(), &mut buffer).unwrap();
+/// codec.encode(Value::from(7u8), &mut buffer).unwrap();
+///
+/// assert_eq!(codec.decode(&mut buffer), Ok(Some(Value::try_from("hello").unwrap())));
+/// assert_eq!(codec.decode(&mut buffer), Ok(Some(Value::from(7u8))));
+/// assert_eq!(codec.decode(&mut buffer), Ok(None));
+/// ```
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct ValueCodec {
+    max_frame_length: usize,
+}
+
+impl ValueCodec {
+    /// Creates a codec accepting values up to [`DEFAULT_MAX_FRAME_LENGTH`] bytes.
+    pub fn new() -> Self {
+        Self { max_frame_length: DEFAULT_MAX_FRAME_LENGTH }
+    }
+
+    /// Sets the largest encoded value, in bytes, the codec encodes or decodes.
+    pub fn with_max_frame_length(mut self, max_frame_length: usize) -> Self {
+        self.max_frame_length = max_frame_length;
+        self
+    }
+
+    /// The largest encoded value, in bytes, the codec encodes or decodes.
+    pub fn max_frame_length(&self) -> usize {
+        self.max_frame_length
+    }
+}
+
+impl Default for ValueCodec {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Decoder for ValueCodec {
+    type Item = Value;
+    type Error = CodecError;
+
+    /// Decodes the next value once it is wholly buffered.
+    ///
+    /// # Errors
+    /// - `FRAME_TOO_LARGE` if a value is larger than the maximum length, or
+    ///   more than that many bytes are buffered without completing one.
+    /// - Any error of [`Value::decode`] on the value.
+    fn decode(&mut self, src: &mut BytesMut) -> Result<Option<Value>, CodecError> {
+        let length = match Value::encoded_len(src) {
+            Ok(length) => length,
+            Err(ErrorMessage(NOT_ENOUGH_BYTES)) if src.len() <= self.max_frame_length => return Ok(None),
+            Err(ErrorMessage(NOT_ENOUGH_BYTES)) => return Err(ErrorMessage(FRAME_TOO_LARGE).into()),
+            Err(error) => return Err(error.into()),
+        };
+        if length > self.max_frame_length {
+            return Err(ErrorMessage(FRAME_TOO_LARGE).into());
+        }
+
+        let frame = src.split_to(length);
+        Ok(Some(Value::decode_in_place(&frame)?))
+    }
+}
+
+impl Encoder<&Value> for ValueCodec {
+    type Error = CodecError;
+
+    /// Writes the encoded bytes of the value.
+    ///
+    /// # Errors
+    /// Returns `FRAME_TOO_LARGE` if the value is larger than the maximum length.
+    fn encode(&mut self, value: &Value, dst: &mut BytesMut) -> Result<(), CodecError> {
+        if value.bytes.len() > self.max_frame_length {
+            return Err(ErrorMessage(FRAME_TOO_LARGE).into());
+        }
+
+        dst.put_slice(&value.bytes);
+        Ok(())
+    }
+}
+
+impl Encoder<Value> for ValueCodec {
+    type Error = CodecError;
+
+    fn encode(&mut self, value: Value, dst: &mut BytesMut) -> Result<(), CodecError> {
+        self.encode(&value, dst)
+    }
+}
@@ -0,0 +1,55 @@
+use yad_core::constants::error::ErrorMessage;
+#[cfg(feature = "compression")]
+use crate::error::MALFORMED_COMPRESSED_BODY;
+#[cfg(not(feature = "compression"))]
+use crate::error::COMPRESSION_NOT_SUPPORTED;
+
+/// Largest body a compressed document is inflated to by the readers that
+/// take no [`crate::options::DecodeOptions::max_size`], such as
+/// [`crate::YAD::deserialize_projected`], so a small input cannot exhaust memory.
+pub(crate) const MAX_INFLATED_SIZE: usize = 1 << 30;
+
+/// Compresses a document body (its dictionary section and rows) with raw deflate.
+#[cfg(feature = "compression")]
+pub(crate) fn deflate(body: &[u8]) -> Result<Vec<u8>, ErrorMessage> {
+    use std::io::Write;
+    use flate2::write::DeflateEncoder;
+
+    let mut encoder = DeflateEncoder::new(Vec::with_capacity(body.len() / 2), flate2::Compression::default());
+    encoder.write_all(body).map_err(|_| ErrorMessage(MALFORMED_COMPRESSED_BODY))?;
+    encoder.finish().map_err(|_| ErrorMessage(MALFORMED_COMPRESSED_BODY))
+}
+
+/// Decompresses a body written by [`deflate`], stopping with
+/// `ALLOCATION_LIMIT_EXCEEDED` once it grows past `limit` bytes.
+#[cfg(feature = "compression")]
+pub(crate) fn inflate(body: &[u8], limit: Option<usize>) -> Result<Vec<u8>, ErrorMessage> {
+    use std::io::Read;
+    use flate2::read::DeflateDecoder;
+    use yad_core::constants::error::ALLOCATION_LIMIT_EXCEEDED;
+
+    let limit = limit.unwrap_or(usize::MAX);
+    let mut inflated = vec![];
+    DeflateDecoder::new(body)
+        .take((limit as u64).saturating_add(1))
+        .read_to_end(&mut inflated)
+        .map_err(|_| ErrorMessage(MALFORMED_COMPRESSED_BODY))?;
+
+    if inflated.len() > limit {
+        return Err(ErrorMessage(ALLOCATION_LIMIT_EXCEEDED));
+    }
+
+    Ok(inflated)
+}
+
+/// Compression needs the `compression` feature.
+#[cfg(not(feature = "compression"))]
+pub(crate) fn deflate(_: &[u8]) -> Result<Vec<u8>, ErrorMessage> {
+    Err(ErrorMessage(COMPRESSION_NOT_SUPPORTED))
+}
+
+/// Compressed documents need the `compression` feature.
+#[cfg(not(feature = "compression"))]
+pub(crate) fn inflate(_: &[u8], _: Option<usize>) -> Result<Vec<u8>, ErrorMessage> {
+    Err(ErrorMessage(COMPRESSION_NOT_SUPPORTED))
+}
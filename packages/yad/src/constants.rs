@@ -7,4 +7,36 @@ pub const ROW_END_HEADER: u8 = 0xF2;   // Marks the end of a row.
 
 pub const KEY_START_HEADER: u8 = 0xF3; // Marks the start of a key.
 pub const KEY_NAME_HEADER: u8 = 0x70;  // Marks the beginning of a key's name.
+pub const KEY_NAME_REF_HEADER: u8 = 0x80; // Replaces a key's name with an index into the dictionary section.
 pub const KEY_END_HEADER: u8 = 0xF4;   // Marks the end of a key.
+
+pub const SCHEMA_HEADER: u8 = 0xF6; // Marks an embedded schema section, right after the version.
+
+pub const BLOOM_HEADER: u8 = 0xFC; // Marks a bloom filter section over the row names, right after the schema section.
+
+pub const PATCH_HEADER: u8 = 0xF5;     // Marks the start of a patch.
+pub const PATCH_ADD_ROW: u8 = 0xE1;    // Adds a row; followed by its name and key operations.
+pub const PATCH_REMOVE_ROW: u8 = 0xE2; // Removes a row; followed by its name.
+pub const PATCH_CHANGE_ROW: u8 = 0xE3; // Changes a row; followed by its name and key operations.
+pub const PATCH_SET_KEY: u8 = 0xE4;    // Sets a key; followed by its name and value.
+pub const PATCH_REMOVE_KEY: u8 = 0xE5; // Removes a key; followed by its name.
+pub const PATCH_ROW_END: u8 = 0xE6;    // Ends the key operations of a row.
+pub const PATCH_SET_ROW_META: u8 = 0xE7; // Sets a changed row's metadata; followed by its metadata block, absent when cleared.
+
+pub const CHECKSUM_HEADER: u8 = 0xFE; // Marks a checksum section, right after the bloom filter section; followed by the CRC-32 of the bytes after it.
+
+pub const TOMBSTONE_HEADER: u8 = 0xFD; // Marks a removed row in a log, between its records; followed by the row name.
+
+pub const ENCRYPTED_HEADER: u8 = 0xF7; // Marks an encrypted body, right after the version; followed by the cipher id.
+
+pub const SIGNATURE_HEADER: u8 = 0xF8; // Marks a signature section, right after the version; followed by the signature.
+pub const SIGNATURE_LENGTH: usize = 64; // Length of the Ed25519 signature following SIGNATURE_HEADER.
+
+pub const META_HEADER: u8 = 0xF9; // Marks a row or key metadata block, right after the name.
+
+pub const FLAGS_HEADER: u8 = 0xFA; // Marks the header flags section, right after the version; followed by the flags.
+pub const FLAGS_RESERVED_LENGTH: usize = 2; // Reserved bytes following the u16 flags, written as zero.
+
+pub const DICTIONARY_HEADER: u8 = 0xFB; // Marks the dictionary section, right before the rows; followed by the key names it holds.
+
+pub const MAGIC: [u8; 4] = [0x59, 0x41, 0x44, 0x00]; // "YAD\0", written before the version header.
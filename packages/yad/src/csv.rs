@@ -0,0 +1,161 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::io::{Read, Write};
+use float16::f16;
+use float8::F8E4M3;
+use yad_core::constants::error::ErrorMessage;
+use yad_core::constants::length::ByteLength;
+use yad_core::constants::types::Type;
+use yad_core::bigint::BigInteger;
+use yad_core::decimal::Decimal;
+use yad_core::Value;
+use crate::error::{CSV_INVALID_VALUE, CSV_NESTED_VALUE, CSV_UNKNOWN_COLUMN, MALFORMED_CSV, ROW_NAME_OF_LENGTH_ZERO};
+use crate::key::Key;
+use crate::row::Row;
+use crate::schema::{FieldType, Schema};
+use crate::{CURRENT_VERSION, YAD};
+
+/// Title written above the row name column by [`YAD::to_csv`].
+pub const CSV_ROW_COLUMN: &str = "row";
+
+/// Column name → value type, used by [`YAD::from_csv`] to type the cells.
+pub type ColumnTypes = BTreeMap<String, FieldType>;
+
+impl Schema {
+    /// Merges the key types of every row into column types for [`YAD::from_csv`].
+    ///
+    /// When rows disagree on a key's type, the first row in name order wins.
+    pub fn columns(&self) -> ColumnTypes {
+        let mut columns = ColumnTypes::new();
+        for keys in self.rows.values() {
+            for (name, field) in keys {
+                columns.entry(name.clone()).or_insert(*field);
+            }
+        }
+        columns
+    }
+}
+
+/// Writes a flat value as a CSV cell.
+fn cell(value: &Value) -> Result<String, ErrorMessage> {
+    match value.r#type {
+        Type::Array => Err(ErrorMessage(CSV_NESTED_VALUE)),
+        Type::String => value.clone().try_into(),
+        _ => Ok(format!("{}", value)),
+    }
+}
+
+/// Parses a CSV cell as a value of type `field`.
+fn parse_cell(text: &str, field: FieldType) -> Option<Value> {
+    Some(match (field.r#type, field.length) {
+        (Type::Uint, ByteLength::One) => Value::from(text.parse::<u8>().ok()?),
+        (Type::Uint, ByteLength::Two) => Value::from(text.parse::<u16>().ok()?),
+        (Type::Uint, ByteLength::Four) => Value::from(text.parse::<u32>().ok()?),
+        (Type::Uint, _) => Value::from(text.parse::<u64>().ok()?),
+        (Type::Int, ByteLength::One) => Value::from(text.parse::<i8>().ok()?),
+        (Type::Int, ByteLength::Two) => Value::from(text.parse::<i16>().ok()?),
+        (Type::Int, ByteLength::Four) => Value::from(text.parse::<i32>().ok()?),
+        (Type::Int, _) => Value::from(text.parse::<i64>().ok()?),
+        (Type::Float, ByteLength::One) => Value::from(F8E4M3::from_f64(text.parse().ok()?)),
+        (Type::Float, ByteLength::Two) => Value::from(f16::from_f64(text.parse().ok()?)),
+        (Type::Float, ByteLength::Four) => Value::from(text.parse::<f32>().ok()?),
+        (Type::Float, _) => Value::from(text.parse::<f64>().ok()?),
+        (Type::Bool | Type::True | Type::False, _) => Value::from(text.parse::<bool>().ok()?),
+        (Type::String, _) => Value::try_from(text).ok()?,
+        // Cells only hold the digits, so decimals without a fraction read back
+        // as big integers.
+        (Type::Extension, _) => match crate::text::extension_value(text) {
+            Some(value) => value,
+            None if !text.contains('.') => Value::from(text.parse::<BigInteger>().ok()?),
+            None => Value::from(text.parse::<Decimal>().ok()?),
+        },
+        (Type::Array, _) => return None,
+    })
+}
+
+impl YAD {
+    /// Writes the document as CSV: one record per row, one column per key.
+    ///
+    /// The first column, titled [`CSV_ROW_COLUMN`], holds the row names; the
+    /// other columns are every key name found in the document, in name order.
+    /// Keys a row does not have are left empty. Numbers and booleans are
+    /// written as text; their width is restored on import by [`YAD::from_csv`].
+    ///
+    /// # Errors
+    /// - `CSV_NESTED_VALUE` if a value is an array or a key holds several
+    ///   values; only flat documents can be exported.
+    /// - `MALFORMED_CSV` if writing to `writer` fails.
+    pub fn to_csv<W: Write>(&self, writer: W) -> Result<(), ErrorMessage> {
+        let columns: BTreeSet<&String> = self.rows.values().flat_map(|row| row.keys.keys()).collect();
+        let mut csv = csv::Writer::from_writer(writer);
+
+        let header = std::iter::once(CSV_ROW_COLUMN).chain(columns.iter().map(|c| c.as_str()));
+        csv.write_record(header).map_err(|_| ErrorMessage(MALFORMED_CSV))?;
+
+        for row in self.rows.values() {
+            let mut record = vec![row.name.clone()];
+            for column in &columns {
+                record.push(match row.keys.get(*column) {
+                    Some(key) if key.is_multi_valued() => return Err(ErrorMessage(CSV_NESTED_VALUE)),
+                    Some(key) => cell(&key.value)?,
+                    None => String::new(),
+                });
+            }
+            csv.write_record(&record).map_err(|_| ErrorMessage(MALFORMED_CSV))?;
+        }
+
+        csv.flush().map_err(|_| ErrorMessage(MALFORMED_CSV))
+    }
+
+    /// Reads CSV written like [`YAD::to_csv`] into a document of the [`CURRENT_VERSION`].
+    ///
+    /// The first column holds the row names, whatever its title; every other
+    /// column is a key, typed by `columns` (see [`Schema::columns`]). Empty
+    /// cells leave the key out of the row.
+    ///
+    /// # Example
+    /// ```text
+    /// let columns = Schema::infer(&yad).columns();
+    /// let mut csv = vec![];
+    /// yad.to_csv(&mut csv)?;
+    /// assert_eq!(YAD::from_csv(csv.as_slice(), &columns)?, yad);
+    /// ```
+    ///
+    /// # Errors
+    /// - `MALFORMED_CSV` if the input is not valid CSV or has no header.
+    /// - `CSV_UNKNOWN_COLUMN` if a column has no type in `columns`.
+    /// - `CSV_INVALID_VALUE` if a cell does not parse as its column type.
+    /// - `ROW_NAME_OF_LENGTH_ZERO` if a row name is empty.
+    pub fn from_csv<R: Read>(reader: R, columns: &ColumnTypes) -> Result<Self, ErrorMessage> {
+        let mut csv = csv::Reader::from_reader(reader);
+
+        let header = csv.headers().map_err(|_| ErrorMessage(MALFORMED_CSV))?.clone();
+        let fields = header.iter().skip(1)
+            .map(|name| columns.get(name).map(|field| (name, *field)).ok_or(ErrorMessage(CSV_UNKNOWN_COLUMN)))
+            .collect::<Result<Vec<(&str, FieldType)>, ErrorMessage>>()?;
+
+        let mut yad = Self::new_empty(CURRENT_VERSION);
+
+        for record in csv.records() {
+            let record = record.map_err(|_| ErrorMessage(MALFORMED_CSV))?;
+            let name = record.get(0).ok_or(ErrorMessage(MALFORMED_CSV))?;
+
+            if name.is_empty() {
+                return Err(ErrorMessage(ROW_NAME_OF_LENGTH_ZERO));
+            }
+
+            let mut row = Row::new_empty(name);
+
+            for ((key, field), text) in fields.iter().zip(record.iter().skip(1)) {
+                if text.is_empty() {
+                    continue;
+                }
+                let value = parse_cell(text, *field).ok_or(ErrorMessage(CSV_INVALID_VALUE))?;
+                row.keys.insert(key.to_string(), Key::new(key, value));
+            }
+
+            yad.rows.insert(row.name.clone(), row);
+        }
+
+        Ok(yad)
+    }
+}
@@ -0,0 +1,210 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, MutexGuard, PoisonError};
+use yad_core::constants::error::ErrorMessage;
+use crate::error::{DATABASE_IO_FAILED, DOCUMENT_EXISTS, DOCUMENT_NOT_FOUND, INVALID_DOCUMENT_NAME};
+use crate::shard::SHARD_EXTENSION;
+use crate::shared::SharedYad;
+use crate::{CURRENT_VERSION, YAD};
+
+/// A directory of named documents, each stored as `<name>.yad`, so data can
+/// be organized into collections rather than one large file.
+///
+/// Documents are loaded on first use and cached as [`SharedYad`] handles:
+/// every caller asking for a name gets the same document, with its own
+/// lock, so writers to different documents never wait for each other.
+/// Changes stay in memory until [`YadDatabase::save`], [`YadDatabase::save_all`]
+/// or [`YadDatabase::close`] writes them with [`YAD::save_atomic`].
+///
+/// The database can be shared between threads, for example in an `Arc`.
+///
+/// # Example
+/// ```text
+/// let db = YadDatabase::open("data")?;
+/// let users = db.create("users")?;
+/// users.write().insert_row("johan", vec![Key::new("age", 17u8)]);
+/// db.save("users")?;
+/// assert_eq!(db.list()?, ["users"]);
+/// ```
+pub struct YadDatabase {
+    dir: PathBuf,
+    cache: Mutex<BTreeMap<String, SharedYad>>,
+}
+
+/// Checks that `name` can be used as a file name inside the database directory.
+fn check_name(name: &str) -> Result<(), ErrorMessage> {
+    let invalid = name.is_empty()
+        || name.starts_with('.')
+        || name.contains(['/', '\\', '\0']);
+
+    match invalid {
+        true => Err(ErrorMessage(INVALID_DOCUMENT_NAME)),
+        false => Ok(()),
+    }
+}
+
+impl YadDatabase {
+    /// Opens the database in `dir`, creating the directory if it does not exist.
+    /// No document is read until it is asked for.
+    ///
+    /// # Errors
+    /// Returns `DATABASE_IO_FAILED` if the directory cannot be created.
+    pub fn open<P: AsRef<Path>>(dir: P) -> Result<Self, ErrorMessage> {
+        let dir = dir.as_ref().to_path_buf();
+        fs::create_dir_all(&dir).map_err(|_| ErrorMessage(DATABASE_IO_FAILED))?;
+        Ok(Self { dir, cache: Mutex::new(BTreeMap::new()) })
+    }
+
+    /// The directory holding the documents.
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// Locks the cache of loaded documents.
+    fn cache(&self) -> MutexGuard<'_, BTreeMap<String, SharedYad>> {
+        self.cache.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Returns the file of the document `name`.
+    fn path(&self, name: &str) -> Result<PathBuf, ErrorMessage> {
+        check_name(name)?;
+        Ok(self.dir.join(format!("{}.{}", name, SHARD_EXTENSION)))
+    }
+
+    /// Returns the names of the documents, loaded or on disk, in order.
+    ///
+    /// # Errors
+    /// Returns `DATABASE_IO_FAILED` if the directory cannot be read.
+    pub fn list(&self) -> Result<Vec<String>, ErrorMessage> {
+        let mut names: Vec<String> = self.cache().keys().cloned().collect();
+
+        for entry in fs::read_dir(&self.dir).map_err(|_| ErrorMessage(DATABASE_IO_FAILED))? {
+            let path = entry.map_err(|_| ErrorMessage(DATABASE_IO_FAILED))?.path();
+            if !path.is_file() || path.extension().is_none_or(|ext| ext != SHARD_EXTENSION) {
+                continue;
+            }
+            if let Some(name) = path.file_stem().and_then(|stem| stem.to_str()).filter(|name| check_name(name).is_ok()) {
+                names.push(name.to_string());
+            }
+        }
+
+        names.sort();
+        names.dedup();
+        Ok(names)
+    }
+
+    /// Returns `true` if there is a document `name`, loaded or on disk.
+    pub fn contains(&self, name: &str) -> bool {
+        self.cache().contains_key(name) || self.path(name).is_ok_and(|path| path.is_file())
+    }
+
+    /// Creates the empty document `name` of the [`CURRENT_VERSION`] and writes
+    /// it to disk.
+    ///
+    /// # Errors
+    /// - `INVALID_DOCUMENT_NAME` for names that are not plain file names.
+    /// - `DOCUMENT_EXISTS` if the document already exists.
+    /// - `SAVE_IO_FAILED` if it cannot be written.
+    pub fn create(&self, name: &str) -> Result<SharedYad, ErrorMessage> {
+        let path = self.path(name)?;
+        let mut cache = self.cache();
+        if cache.contains_key(name) || path.exists() {
+            return Err(ErrorMessage(DOCUMENT_EXISTS));
+        }
+
+        let yad = YAD::new_empty(CURRENT_VERSION);
+        yad.save_atomic(&path)?;
+
+        let shared = SharedYad::new(yad);
+        cache.insert(name.to_string(), shared.clone());
+        Ok(shared)
+    }
+
+    /// Returns the document `name`, reading it from disk the first time.
+    ///
+    /// # Errors
+    /// - `INVALID_DOCUMENT_NAME` for names that are not plain file names.
+    /// - `DOCUMENT_NOT_FOUND` if there is no such document.
+    /// - `DATABASE_IO_FAILED` if it cannot be read, or the error of
+    ///   [`YAD::deserialize`] if it is malformed.
+    pub fn get(&self, name: &str) -> Result<SharedYad, ErrorMessage> {
+        let path = self.path(name)?;
+        let mut cache = self.cache();
+        if let Some(shared) = cache.get(name) {
+            return Ok(shared.clone());
+        }
+
+        if !path.is_file() {
+            return Err(ErrorMessage(DOCUMENT_NOT_FOUND));
+        }
+        let bytes = fs::read(&path).map_err(|_| ErrorMessage(DATABASE_IO_FAILED))?;
+        let shared = SharedYad::new(YAD::deserialize(bytes)?);
+
+        cache.insert(name.to_string(), shared.clone());
+        Ok(shared)
+    }
+
+    /// Writes the document `name` to disk if it is loaded; documents that
+    /// were never loaded have nothing to save.
+    ///
+    /// # Errors
+    /// - `INVALID_DOCUMENT_NAME` for names that are not plain file names.
+    /// - `DOCUMENT_NOT_FOUND` if there is no such document.
+    /// - The errors of [`YAD::save_atomic`].
+    pub fn save(&self, name: &str) -> Result<(), ErrorMessage> {
+        let path = self.path(name)?;
+        let shared = self.cache().get(name).cloned();
+
+        match shared {
+            Some(shared) => shared.snapshot().save_atomic(&path),
+            None if path.is_file() => Ok(()),
+            None => Err(ErrorMessage(DOCUMENT_NOT_FOUND)),
+        }
+    }
+
+    /// Writes every loaded document to disk.
+    ///
+    /// # Errors
+    /// Returns the first error of [`YAD::save_atomic`]; the documents after it are not saved.
+    pub fn save_all(&self) -> Result<(), ErrorMessage> {
+        let loaded: Vec<(String, SharedYad)> = self.cache().iter()
+            .map(|(name, shared)| (name.clone(), shared.clone()))
+            .collect();
+
+        for (name, shared) in loaded {
+            shared.snapshot().save_atomic(self.path(&name)?)?;
+        }
+        Ok(())
+    }
+
+    /// Saves the document `name` and drops it from the cache; the next
+    /// [`YadDatabase::get`] reads it from disk again.
+    ///
+    /// # Errors
+    /// Returns the errors of [`YadDatabase::save`]; the document stays loaded on failure.
+    pub fn close(&self, name: &str) -> Result<(), ErrorMessage> {
+        self.save(name)?;
+        self.cache().remove(name);
+        Ok(())
+    }
+
+    /// Deletes the document `name` from disk and from the cache. Handles
+    /// obtained before keep working on a document that is no longer saved.
+    ///
+    /// # Errors
+    /// - `INVALID_DOCUMENT_NAME` for names that are not plain file names.
+    /// - `DOCUMENT_NOT_FOUND` if there is no such document.
+    /// - `DATABASE_IO_FAILED` if the file cannot be removed.
+    pub fn delete(&self, name: &str) -> Result<(), ErrorMessage> {
+        let path = self.path(name)?;
+        let mut cache = self.cache();
+        let loaded = cache.remove(name).is_some();
+
+        match path.is_file() {
+            true => fs::remove_file(&path).map_err(|_| ErrorMessage(DATABASE_IO_FAILED)),
+            false if loaded => Ok(()),
+            false => Err(ErrorMessage(DOCUMENT_NOT_FOUND)),
+        }
+    }
+}
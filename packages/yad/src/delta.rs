@@ -0,0 +1,16 @@
+use yad_core::byte_order::ByteOrder;
+use yad_core::constants::error::ErrorMessage;
+use yad_core::delta::{transcode_from_delta, transcode_to_delta};
+use crate::dictionary::{copy_key_name, rewrite_body};
+
+/// Rewrites the eligible arrays of a body of rows as delta arrays (see
+/// [`crate::options::SerializeOptions::delta_arrays`]). Key names may be
+/// dictionary references; `order` is the byte order of the number payloads.
+pub(crate) fn body_to_delta(body: &[u8], order: ByteOrder) -> Result<Vec<u8>, ErrorMessage> {
+    rewrite_body(body, copy_key_name, |reader, out| transcode_to_delta(reader, out, order))
+}
+
+/// Rebuilds the delta arrays of a body written by [`body_to_delta`].
+pub(crate) fn body_from_delta(body: &[u8], order: ByteOrder) -> Result<Vec<u8>, ErrorMessage> {
+    rewrite_body(body, copy_key_name, |reader, out| transcode_from_delta(reader, out, order))
+}
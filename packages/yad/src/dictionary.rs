@@ -0,0 +1,213 @@
+use std::collections::HashMap;
+use yad_core::constants::error::ErrorMessage;
+use yad_core::constants::length::ByteLength;
+use yad_core::{Reader, Value};
+use crate::constants::{DICTIONARY_HEADER, KEY_END_HEADER, KEY_NAME_HEADER, KEY_NAME_REF_HEADER, KEY_START_HEADER, ROW_END_HEADER, ROW_NAME_HEADER, ROW_START_HEADER};
+use crate::error::{MALFORMED_DICTIONARY, MALFORMED_KEY_NAME_VECTOR, MALFORMED_KEY_VECTOR, MALFORMED_ROW_NAME_VECTOR, MALFORMED_ROW_VECTOR};
+use crate::varint::copy_meta;
+use crate::{decode_name_ref, encode_name_into, length_descriptor, usize_from_slice_bytes};
+
+/// Appends a reference to entry `index` of the string table.
+///
+/// # Binary Layout
+/// ```text
+/// +----------------------------------+-------------------------+
+/// | KEY_NAME_REF_HEADER | ByteLength | Index (big-endian)      |
+/// +----------------------------------+-------------------------+
+/// ```
+pub(crate) fn encode_name_ref(index: usize, bytes: &mut Vec<u8>) {
+    let length = length_descriptor(index);
+    bytes.push(KEY_NAME_REF_HEADER | u8::from(length));
+
+    match length {
+        ByteLength::One => bytes.push(index as u8),
+        ByteLength::Two => bytes.extend_from_slice(&(index as u16).to_be_bytes()),
+        ByteLength::Four => bytes.extend_from_slice(&(index as u32).to_be_bytes()),
+        _ => bytes.extend_from_slice(&(index as u64).to_be_bytes()),
+    }
+}
+
+/// Decodes a reference written by [`encode_name_ref`].
+///
+/// # Returns
+/// - `Some((usize, usize))`: The table index and the number of bytes consumed.
+/// - `None`: If `bytes` does not start with a reference or it is truncated.
+pub(crate) fn decode_name_ref_index(bytes: &[u8]) -> Option<(usize, usize)> {
+    let first = *bytes.first()?;
+
+    if first & 0xF0 != KEY_NAME_REF_HEADER {
+        return None;
+    }
+
+    let byte_length = ByteLength::try_from(first).ok()?;
+    let index = usize_from_slice_bytes(bytes.get(1..)?, byte_length)?;
+
+    Some((index, 1 + byte_length.as_byte_count() as usize))
+}
+
+/// Copies a body of rows (fixed length descriptors) while `key_name` rewrites
+/// each key name and `value` each value at the reader's position; row names
+/// and metadata are copied as they are.
+pub(crate) fn rewrite_body<'a>(
+    body: &'a [u8],
+    mut key_name: impl FnMut(&mut Reader<'a>, &mut Vec<u8>) -> Result<(), ErrorMessage>,
+    mut value: impl FnMut(&mut Reader<'a>, &mut Vec<u8>) -> Result<(), ErrorMessage>,
+) -> Result<Vec<u8>, ErrorMessage> {
+    let mut reader = Reader::new(body);
+    let mut out = Vec::with_capacity(body.len());
+
+    while reader.peek().is_some() {
+        rewrite_row(&mut reader, &mut out, &mut key_name, &mut value)?;
+    }
+
+    Ok(out)
+}
+
+/// Copies the row at the reader's position like [`rewrite_body`] does for every row.
+pub(crate) fn rewrite_row<'a>(
+    reader: &mut Reader<'a>,
+    out: &mut Vec<u8>,
+    mut key_name: impl FnMut(&mut Reader<'a>, &mut Vec<u8>) -> Result<(), ErrorMessage>,
+    mut value: impl FnMut(&mut Reader<'a>, &mut Vec<u8>) -> Result<(), ErrorMessage>,
+) -> Result<(), ErrorMessage> {
+    if reader.peek() != Some(ROW_START_HEADER) {
+        return Err(ErrorMessage(MALFORMED_ROW_VECTOR));
+    }
+    out.push(ROW_START_HEADER);
+    reader.advance(1)?;
+
+    let (_, consumed) = decode_name_ref(reader.remaining(), ROW_NAME_HEADER).ok_or(ErrorMessage(MALFORMED_ROW_NAME_VECTOR))?;
+    out.extend_from_slice(reader.take(consumed)?);
+    copy_meta(reader, out)?;
+
+    loop {
+        match reader.peek() {
+            Some(ROW_END_HEADER) => break,
+            Some(KEY_START_HEADER) => {
+                out.push(KEY_START_HEADER);
+                reader.advance(1)?;
+
+                key_name(reader, out)?;
+                copy_meta(reader, out)?;
+
+                loop {
+                    match reader.peek() {
+                        Some(KEY_END_HEADER) => break,
+                        Some(_) => value(reader, out)?,
+                        None => return Err(ErrorMessage(MALFORMED_KEY_VECTOR)),
+                    }
+                }
+                out.push(KEY_END_HEADER);
+                reader.advance(1)?;
+            }
+            _ => return Err(ErrorMessage(MALFORMED_ROW_VECTOR)),
+        }
+    }
+    out.push(ROW_END_HEADER);
+    reader.advance(1)
+}
+
+/// Moves the key names used more than once in a body of rows into a string
+/// table and replaces them in `body` with references to it.
+///
+/// # Returns
+/// - `Ok(Some(Vec<u8>))`: The dictionary section to write before the body.
+/// - `Ok(None)`: If no key name repeats; `body` is left unchanged.
+pub(crate) fn body_to_dictionary(body: &mut Vec<u8>) -> Result<Option<Vec<u8>>, ErrorMessage> {
+    let mut counts: HashMap<&str, usize> = HashMap::new();
+    let mut names: Vec<&str> = vec![];
+
+    rewrite_body(body.as_slice(), |reader, _| {
+        let (name, consumed) = decode_name_ref(reader.remaining(), KEY_NAME_HEADER).ok_or(ErrorMessage(MALFORMED_KEY_NAME_VECTOR))?;
+        let count = counts.entry(name).or_insert(0);
+        if *count == 1 {
+            names.push(name);
+        }
+        *count += 1;
+        reader.advance(consumed)
+    }, copy_value)?;
+
+    if names.is_empty() {
+        return Ok(None);
+    }
+
+    let indexes: HashMap<&str, usize> = names.iter().enumerate().map(|(i, name)| (*name, i)).collect();
+    let table: Vec<Value> = names.iter().map(|name| Value::try_from(*name)).collect::<Result<_, _>>()?;
+
+    let mut section = vec![DICTIONARY_HEADER];
+    section.extend_from_slice(Value::try_from(table)?.bytes.as_slice());
+
+    let rows = rewrite_body(body.as_slice(), |reader, out| {
+        let (name, consumed) = decode_name_ref(reader.remaining(), KEY_NAME_HEADER).ok_or(ErrorMessage(MALFORMED_KEY_NAME_VECTOR))?;
+        match indexes.get(name) {
+            Some(index) => encode_name_ref(*index, out),
+            None => out.extend_from_slice(&reader.remaining()[..consumed]),
+        }
+        reader.advance(consumed)
+    }, copy_value)?;
+
+    *body = rows;
+
+    Ok(Some(section))
+}
+
+/// Splits the dictionary section at the start of `bytes` from the body after it.
+///
+/// # Errors
+/// Returns `MALFORMED_DICTIONARY` if there is no section or it is not an
+/// array of strings.
+pub(crate) fn read_dictionary(bytes: &[u8]) -> Result<(Vec<String>, &[u8]), ErrorMessage> {
+    if bytes.first() != Some(&DICTIONARY_HEADER) {
+        return Err(ErrorMessage(MALFORMED_DICTIONARY));
+    }
+
+    let section = Value::decode_in_place(&bytes[1..]).map_err(|_| ErrorMessage(MALFORMED_DICTIONARY))?;
+    let consumed = 1 + section.bytes.len();
+    let entries: Vec<Value> = section.try_into().map_err(|_| ErrorMessage(MALFORMED_DICTIONARY))?;
+    let names = entries.into_iter()
+        .map(|entry| entry.try_into().map_err(|_| ErrorMessage(MALFORMED_DICTIONARY)))
+        .collect::<Result<Vec<String>, _>>()?;
+
+    Ok((names, &bytes[consumed..]))
+}
+
+/// Replaces every reference of a body written by [`body_to_dictionary`]
+/// (without its dictionary section) with the name it points to.
+///
+/// # Errors
+/// Returns `MALFORMED_DICTIONARY` if a reference points past the table.
+pub(crate) fn body_from_dictionary(body: &[u8], names: &[String]) -> Result<Vec<u8>, ErrorMessage> {
+    rewrite_body(body, |reader, out| resolve_key_name(reader, out, names), copy_value)
+}
+
+/// Copies the key name at the reader's position, replacing a reference with
+/// the name it points to in `names`.
+///
+/// # Errors
+/// Returns `MALFORMED_DICTIONARY` if the reference points past `names`.
+pub(crate) fn resolve_key_name(reader: &mut Reader, out: &mut Vec<u8>, names: &[String]) -> Result<(), ErrorMessage> {
+    match decode_name_ref_index(reader.remaining()) {
+        Some((index, consumed)) => {
+            let name = names.get(index).ok_or(ErrorMessage(MALFORMED_DICTIONARY))?;
+            encode_name_into(name, KEY_NAME_HEADER, out)?;
+            reader.advance(consumed)
+        }
+        None => copy_key_name(reader, out),
+    }
+}
+
+/// Copies the key name or dictionary reference at the reader's position.
+pub(crate) fn copy_key_name(reader: &mut Reader, out: &mut Vec<u8>) -> Result<(), ErrorMessage> {
+    let consumed = match decode_name_ref_index(reader.remaining()) {
+        Some((_, consumed)) => consumed,
+        None => decode_name_ref(reader.remaining(), KEY_NAME_HEADER).ok_or(ErrorMessage(MALFORMED_KEY_NAME_VECTOR))?.1,
+    };
+    out.extend_from_slice(reader.take(consumed)?);
+    Ok(())
+}
+
+/// Copies the value at the reader's position as it is.
+pub(crate) fn copy_value(reader: &mut Reader, out: &mut Vec<u8>) -> Result<(), ErrorMessage> {
+    out.extend_from_slice(reader.skip_value()?);
+    Ok(())
+}
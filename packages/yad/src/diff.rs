@@ -0,0 +1,194 @@
+use crate::key::Key;
+use crate::meta::Meta;
+use crate::observe::ChangeEvent;
+use crate::row::Row;
+use crate::YAD;
+
+/// A key whose values or metadata differ between two versions of the same row.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct KeyChange {
+    /// Name of the changed key.
+    pub name: String,
+    /// Key held by the original document, with all of its values.
+    pub before: Key,
+    /// Key held by the compared document, with all of its values.
+    pub after: Key,
+}
+
+/// The metadata of a row that differs between two versions of the row.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MetaChange {
+    /// Metadata held by the original document.
+    pub before: Meta,
+    /// Metadata held by the compared document.
+    pub after: Meta,
+}
+
+/// Metadata and key-level differences of a row present in both documents.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RowDiff {
+    /// Name of the row.
+    pub name: String,
+    /// The row's metadata, when it differs.
+    pub changed_meta: Option<MetaChange>,
+    /// Keys only present in the compared document.
+    pub added_keys: Vec<Key>,
+    /// Keys only present in the original document.
+    pub removed_keys: Vec<Key>,
+    /// Keys present in both documents with different values or metadata.
+    pub changed_keys: Vec<KeyChange>,
+}
+
+impl RowDiff {
+    /// Compares the metadata of two rows with the same name, then their keys one by one.
+    ///
+    /// # Arguments
+    /// - `before`: The row from the original document.
+    /// - `after`: The row from the compared document.
+    pub fn between(before: &Row, after: &Row) -> Self {
+        let mut diff = Self {
+            name: after.name.clone(),
+            changed_meta: (before.meta != after.meta).then(|| MetaChange { before: before.meta.clone(), after: after.meta.clone() }),
+            added_keys: vec![],
+            removed_keys: vec![],
+            changed_keys: vec![],
+        };
+
+        for (name, key) in &before.keys {
+            match after.keys.get(name) {
+                None => diff.removed_keys.push(key.clone()),
+                Some(other) if other != key => diff.changed_keys.push(KeyChange {
+                    name: name.clone(),
+                    before: key.clone(),
+                    after: other.clone(),
+                }),
+                Some(_) => {}
+            }
+        }
+
+        for (name, key) in &after.keys {
+            if !before.keys.contains_key(name) {
+                diff.added_keys.push(key.clone());
+            }
+        }
+
+        diff
+    }
+
+    /// Returns `true` when both rows hold the same metadata, keys and values.
+    pub fn is_empty(&self) -> bool {
+        self.changed_meta.is_none() && self.added_keys.is_empty() && self.removed_keys.is_empty() && self.changed_keys.is_empty()
+    }
+}
+
+/// Structural differences between two [`YAD`] documents.
+///
+/// Produced by [`YAD::diff`]. Rows and keys are listed in name order, the same
+/// order in which they are serialized.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct YadDiff {
+    /// Rows only present in the compared document.
+    pub added_rows: Vec<Row>,
+    /// Rows only present in the original document.
+    pub removed_rows: Vec<Row>,
+    /// Rows present in both documents whose metadata or keys differ.
+    pub changed_rows: Vec<RowDiff>,
+}
+
+impl YadDiff {
+    /// Returns `true` when both documents hold the same rows, metadata and keys.
+    pub fn is_empty(&self) -> bool {
+        self.added_rows.is_empty() && self.removed_rows.is_empty() && self.changed_rows.is_empty()
+    }
+}
+
+/// Decides what [`YAD::merge`] does when both documents define the same key.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum MergeStrategy {
+    /// Keep the value, or row metadata entry, already stored in the receiving document.
+    KeepExisting,
+    /// Replace the value, or row metadata entry, with the one from the merged document.
+    Overwrite,
+    /// Replace whole rows with the ones from the merged document, dropping keys it lacks.
+    ReplaceRows,
+}
+
+impl YAD {
+    /// Compares this document with `other`.
+    ///
+    /// Versions are not compared; only rows, their metadata and keys are.
+    ///
+    /// # Returns
+    /// A [`YadDiff`] describing how to go from `self` to `other`.
+    pub fn diff(&self, other: &YAD) -> YadDiff {
+        let mut diff = YadDiff {
+            added_rows: vec![],
+            removed_rows: vec![],
+            changed_rows: vec![],
+        };
+
+        for (name, row) in &self.rows {
+            match other.rows.get(name) {
+                None => diff.removed_rows.push(row.clone()),
+                Some(other_row) => {
+                    let row_diff = RowDiff::between(row, other_row);
+                    if !row_diff.is_empty() {
+                        diff.changed_rows.push(row_diff);
+                    }
+                }
+            }
+        }
+
+        for (name, row) in &other.rows {
+            if !self.rows.contains_key(name) {
+                diff.added_rows.push(row.clone());
+            }
+        }
+
+        diff
+    }
+
+    /// Merges the rows of `other` into this document.
+    ///
+    /// Rows only present in `other` are always added and rows only present in
+    /// `self` are always kept. Conflicts on rows present in both are resolved
+    /// according to `strategy`. The version of `self` is kept.
+    ///
+    /// Observers see a `RowInserted` event for every added row and a
+    /// `RowUpdated` event for every row the merge changed.
+    pub fn merge(&mut self, other: YAD, strategy: MergeStrategy) {
+        for (name, row) in other.rows {
+            let Some(mut existing) = self.detach_row(&name) else {
+                self.adopt_row(row);
+                continue;
+            };
+
+            let changed = match strategy {
+                MergeStrategy::ReplaceRows => {
+                    existing = row;
+                    true
+                }
+                MergeStrategy::Overwrite => {
+                    existing.meta.entries.extend(row.meta.entries);
+                    existing.keys.extend(row.keys);
+                    true
+                }
+                MergeStrategy::KeepExisting => {
+                    let before = (existing.meta.entries.len(), existing.keys.len());
+                    for (tag, value) in row.meta.entries {
+                        existing.meta.entries.entry(tag).or_insert(value);
+                    }
+                    for (key_name, key) in row.keys {
+                        existing.keys.entry(key_name).or_insert(key);
+                    }
+                    (existing.meta.entries.len(), existing.keys.len()) != before
+                }
+            };
+
+            self.attach_row(existing);
+            if changed {
+                self.notify(|| ChangeEvent::RowUpdated { row: name });
+            }
+        }
+    }
+}
@@ -0,0 +1,136 @@
+use aes_gcm::Aes256Gcm;
+use chacha20poly1305::aead::{Aead, AeadCore, KeyInit, OsRng, Payload};
+use chacha20poly1305::ChaCha20Poly1305;
+use yad_core::constants::error::ErrorMessage;
+use crate::constants::ENCRYPTED_HEADER;
+use crate::error::{AUTHENTICATION_FAILED, MALFORMED_ENVELOPE, UNKNOWN_CIPHER};
+use crate::header::version_end;
+use crate::{Version, YAD};
+
+/// Size of the nonce stored in the envelope, shared by both ciphers.
+const NONCE_SIZE: usize = 12;
+
+/// Authenticated encryption algorithms supported by the envelope.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Cipher {
+    /// ChaCha20-Poly1305 (RFC 8439). Fast without hardware AES support.
+    ChaCha20Poly1305,
+    /// AES-256 in Galois/Counter Mode.
+    Aes256Gcm,
+}
+
+impl From<Cipher> for u8 {
+    fn from(cipher: Cipher) -> u8 {
+        match cipher {
+            Cipher::ChaCha20Poly1305 => 0x01,
+            Cipher::Aes256Gcm => 0x02,
+        }
+    }
+}
+
+impl TryFrom<u8> for Cipher {
+    type Error = ErrorMessage;
+
+    fn try_from(id: u8) -> Result<Self, Self::Error> {
+        match id {
+            0x01 => Ok(Cipher::ChaCha20Poly1305),
+            0x02 => Ok(Cipher::Aes256Gcm),
+            _ => Err(ErrorMessage(UNKNOWN_CIPHER)),
+        }
+    }
+}
+
+/// A 256-bit key together with the cipher it is used with.
+#[derive(Clone)]
+pub struct EncryptionKey {
+    /// The cipher written to (and expected in) the envelope.
+    pub cipher: Cipher,
+    /// The raw key bytes.
+    pub key: [u8; 32],
+}
+
+impl EncryptionKey {
+    /// Creates a key for the given cipher.
+    pub fn new(cipher: Cipher, key: [u8; 32]) -> Self {
+        Self { cipher, key }
+    }
+
+    /// Encrypts `plaintext`, authenticating `aad` alongside it.
+    fn seal(&self, nonce: &[u8], plaintext: &[u8], aad: &[u8]) -> Result<Vec<u8>, ErrorMessage> {
+        let payload = Payload { msg: plaintext, aad };
+        let sealed = match self.cipher {
+            Cipher::ChaCha20Poly1305 => ChaCha20Poly1305::new(&self.key.into()).encrypt(nonce.into(), payload),
+            Cipher::Aes256Gcm => Aes256Gcm::new(&self.key.into()).encrypt(nonce.into(), payload),
+        };
+        sealed.map_err(|_| ErrorMessage(AUTHENTICATION_FAILED))
+    }
+
+    /// Decrypts `ciphertext`, checking the tag over it and `aad`.
+    fn open(&self, nonce: &[u8], ciphertext: &[u8], aad: &[u8]) -> Result<Vec<u8>, ErrorMessage> {
+        let payload = Payload { msg: ciphertext, aad };
+        let opened = match self.cipher {
+            Cipher::ChaCha20Poly1305 => ChaCha20Poly1305::new(&self.key.into()).decrypt(nonce.into(), payload),
+            Cipher::Aes256Gcm => Aes256Gcm::new(&self.key.into()).decrypt(nonce.into(), payload),
+        };
+        opened.map_err(|_| ErrorMessage(AUTHENTICATION_FAILED))
+    }
+}
+
+impl YAD {
+    /// Serializes the document and encrypts everything after the version header.
+    ///
+    /// # Layout
+    /// `magic (4 bytes) | version (5 bytes) | ENCRYPTED_HEADER | cipher id | nonce (12 bytes) | ciphertext + tag`
+    ///
+    /// The magic number, version, header and cipher id stay readable and are authenticated
+    /// together with the ciphertext. A fresh random nonce is used for every call.
+    ///
+    /// # Errors
+    /// Returns the same errors as [`YAD::serialize`].
+    pub fn serialize_encrypted(&self, key: &EncryptionKey) -> Result<Vec<u8>, ErrorMessage> {
+        let plain = self.serialize()?;
+        let (version, body) = plain.split_at(version_end(&plain));
+
+        let mut bytes = version.to_vec();
+        bytes.push(ENCRYPTED_HEADER);
+        bytes.push(u8::from(key.cipher));
+
+        let nonce = ChaCha20Poly1305::generate_nonce(&mut OsRng);
+        let sealed = key.seal(&nonce, body, &bytes)?;
+
+        bytes.extend_from_slice(&nonce);
+        bytes.extend_from_slice(&sealed);
+
+        Ok(bytes)
+    }
+
+    /// Decrypts and deserializes a document written by [`YAD::serialize_encrypted`].
+    ///
+    /// # Errors
+    /// - `MALFORMED_ENVELOPE` if the bytes are not an encrypted document.
+    /// - `UNKNOWN_CIPHER` if the envelope names an unknown cipher, or not the cipher of `key`.
+    /// - `AUTHENTICATION_FAILED` if the key is wrong or the bytes were modified.
+    /// - Any error of [`YAD::deserialize`] on the decrypted document.
+    pub fn deserialize_encrypted(key: &EncryptionKey, bytes: &[u8]) -> Result<Self, ErrorMessage> {
+        let version = version_end(bytes);
+
+        if bytes.len() < version + 2 + NONCE_SIZE || bytes[version] != ENCRYPTED_HEADER {
+            return Err(ErrorMessage(MALFORMED_ENVELOPE));
+        }
+
+        Version::deserialize(bytes[version - 5..version].to_vec())?;
+
+        if Cipher::try_from(bytes[version + 1])? != key.cipher {
+            return Err(ErrorMessage(UNKNOWN_CIPHER));
+        }
+
+        let (header, rest) = bytes.split_at(version + 2);
+        let (nonce, sealed) = rest.split_at(NONCE_SIZE);
+        let body = key.open(nonce, sealed, header)?;
+
+        let mut plain = header[..version].to_vec();
+        plain.extend_from_slice(&body);
+
+        Self::deserialize(plain)
+    }
+}
@@ -23,3 +23,201 @@ pub const MALFORMED_ROW_NAME_VECTOR: &str = "The provided vector cannot be decod
 
 /// The row name must contain at least one character.
 pub const ROW_NAME_OF_LENGTH_ZERO: &str = "Row names must contain at least one character.";
+
+/// The given byte vector cannot be decoded as a valid patch.
+pub const MALFORMED_PATCH: &str = "The provided vector cannot be decoded as a valid patch.";
+
+/// The patch changes a row that does not exist in the target document.
+pub const PATCH_TARGET_ROW_MISSING: &str = "The patch changes a row that does not exist in the document.";
+
+/// The embedded schema section cannot be decoded.
+pub const MALFORMED_SCHEMA: &str = "The provided YAD file has a malformed schema section.";
+
+/// The bloom filter section cannot be decoded.
+pub const MALFORMED_BLOOM_FILTER: &str = "The provided YAD file has a malformed bloom filter section.";
+
+/// The document does not match the schema.
+pub const SCHEMA_MISMATCH: &str = "The document does not match the provided schema.";
+
+/// The row does not exist in the document.
+pub const ROW_NOT_FOUND: &str = "The row does not exist in the document.";
+
+/// The key does not exist in the row.
+pub const KEY_NOT_FOUND: &str = "The key does not exist in the row.";
+
+/// A rename targets a name already used by another row or key.
+pub const NAME_ALREADY_TAKEN: &str = "Another row or key already has the requested name.";
+
+/// Varint length descriptors must be rewritten before rows can be borrowed.
+pub const VARINT_LENGTHS_NOT_BORROWABLE: &str = "The provided YAD file uses varint lengths and cannot be read without copying it.";
+
+/// Dictionary references must be resolved before rows can be borrowed.
+pub const DICTIONARY_NOT_BORROWABLE: &str = "The provided YAD file uses a key name dictionary and cannot be read without copying it.";
+
+/// Delta arrays must be rebuilt before rows can be borrowed.
+pub const DELTA_ARRAYS_NOT_BORROWABLE: &str = "The provided YAD file uses delta arrays and cannot be read without copying it.";
+
+/// The dictionary section is missing or malformed, or a reference points past it.
+pub const MALFORMED_DICTIONARY: &str = "The provided YAD file has a malformed key name dictionary.";
+
+/// The document body is encrypted and must be read with the decryption API.
+pub const ENCRYPTED_DOCUMENT: &str = "The provided YAD file is encrypted.";
+
+/// The encryption envelope is truncated or does not start with the encrypted header.
+pub const MALFORMED_ENVELOPE: &str = "The provided YAD file has a malformed encryption envelope.";
+
+/// The envelope names a cipher this build does not know.
+pub const UNKNOWN_CIPHER: &str = "The provided YAD file is encrypted with an unknown cipher.";
+
+/// The key is wrong or the encrypted document was tampered with.
+pub const AUTHENTICATION_FAILED: &str = "The encrypted YAD file failed authentication.";
+
+/// The document has no signature section or it is truncated.
+pub const MISSING_SIGNATURE: &str = "The provided YAD file is not signed.";
+
+/// The signature does not match the document and public key.
+pub const INVALID_SIGNATURE: &str = "The signature of the YAD file is invalid.";
+
+/// A row or key metadata block is truncated or its entries overrun it.
+pub const MALFORMED_META: &str = "The provided YAD file has a malformed metadata block.";
+
+/// A metadata value is longer than 255 bytes.
+pub const META_VALUE_TOO_LONG: &str = "Metadata values cannot be longer than 255 bytes.";
+
+/// The header flags section is truncated.
+pub const MALFORMED_FLAGS: &str = "The provided YAD file has a malformed header flags section.";
+
+/// The file does not start with the YAD magic number.
+pub const MISSING_MAGIC: &str = "The provided file does not start with the YAD magic number.";
+
+/// The text contains a character that is not valid at its position.
+pub const TEXT_UNEXPECTED_CHARACTER: &str = "The YAD text contains an unexpected character.";
+
+/// A string in the text is not closed before the end of the input.
+pub const TEXT_UNTERMINATED_STRING: &str = "The YAD text contains an unterminated string.";
+
+/// A row or key name was expected in the text.
+pub const TEXT_EXPECTED_NAME: &str = "The YAD text is missing a row or key name.";
+
+/// A value was expected in the text.
+pub const TEXT_EXPECTED_VALUE: &str = "The YAD text is missing a value.";
+
+/// A number in the text is malformed or does not fit its width suffix.
+pub const TEXT_INVALID_NUMBER: &str = "The YAD text contains an invalid number.";
+
+/// The version declared in the text is not `major.minor.patch[-beta]`.
+pub const TEXT_INVALID_VERSION: &str = "The YAD text declares an invalid version.";
+
+/// An extension value in the text is not `ext.<tag>.<data>`, both in hex.
+pub const TEXT_INVALID_EXTENSION: &str = "The YAD text contains an invalid extension value.";
+
+/// A row or key name is declared twice in the text.
+pub const TEXT_DUPLICATE_NAME: &str = "The YAD text declares the same row or key twice.";
+
+/// The CBOR bytes cannot be decoded or encoded.
+pub const MALFORMED_CBOR: &str = "The provided CBOR data is malformed.";
+
+/// A CBOR document or row is not a map.
+pub const CBOR_ROW_NOT_A_MAP: &str = "CBOR documents and rows must be maps.";
+
+/// A CBOR row or key name is not a text string.
+pub const CBOR_NAME_NOT_TEXT: &str = "CBOR row and key names must be text strings.";
+
+/// The CSV input is malformed, or writing the CSV output failed.
+pub const MALFORMED_CSV: &str = "The provided CSV data is malformed or could not be written.";
+
+/// A CSV column has no type in the provided column types.
+pub const CSV_UNKNOWN_COLUMN: &str = "The CSV data has a column with no known type.";
+
+/// A CSV cell cannot be parsed as the type of its column.
+pub const CSV_INVALID_VALUE: &str = "The CSV data has a cell that does not match its column type.";
+
+/// Arrays and multi-value keys cannot be written to or read from CSV cells.
+pub const CSV_NESTED_VALUE: &str = "Only flat documents without arrays can be converted to CSV.";
+
+/// Two rows of a document, or two keys of a row, share a name.
+pub const DUPLICATE_NAME: &str = "The provided YAD file has two rows or two keys with the same name.";
+
+/// A shard file or directory could not be read or written.
+pub const SHARD_IO_FAILED: &str = "A shard file or directory could not be read or written.";
+
+/// A streamed document could not be read or written.
+pub const STREAM_IO_FAILED: &str = "A YAD stream could not be read or written.";
+
+/// A document could not be saved to its file.
+pub const SAVE_IO_FAILED: &str = "The YAD document could not be saved to its file.";
+
+/// A type converted to or from a row is not a struct or a map with string keys.
+pub const SERDE_NOT_A_STRUCT: &str = "Only structs and maps with string keys convert to and from rows.";
+
+/// A field holds something rows cannot, such as a nested struct or an enum variant with data.
+pub const SERDE_UNSUPPORTED_TYPE: &str = "Rows only hold numbers, strings, booleans, characters, unit enum variants and arrays of them.";
+
+/// A row does not match the type it is read into, or the type reported an error.
+pub const SERDE_MISMATCH: &str = "The row does not match the type it is converted to or from.";
+
+/// The YAML text cannot be parsed or written.
+pub const MALFORMED_YAML: &str = "The provided YAML data is malformed.";
+
+/// A YAML document or row is not a mapping.
+pub const YAML_ROW_NOT_A_MAPPING: &str = "YAML documents and rows must be mappings.";
+
+/// A YAML row or key name is not a string.
+pub const YAML_NAME_NOT_TEXT: &str = "YAML row and key names must be strings.";
+
+/// A YAML value has no YAD equivalent, or does not match its tag.
+pub const YAML_INVALID_VALUE: &str = "The YAML data has a null, a nested mapping or a value that does not match its tag.";
+
+/// An Arrow field has a data type that YAD values are not exported to.
+pub const ARROW_UNSUPPORTED_TYPE: &str = "The Arrow field has a data type YAD values cannot be exported to.";
+
+/// A row has no key for a non-nullable Arrow field.
+pub const ARROW_MISSING_VALUE: &str = "The row has no key for a non-nullable Arrow field.";
+
+/// A value does not have the type of its Arrow field, or does not fit it.
+pub const ARROW_VALUE_MISMATCH: &str = "The value does not match the data type of its Arrow field.";
+
+/// A row of a protobuf document is not a struct.
+pub const PROTOBUF_ROW_NOT_A_STRUCT: &str = "Rows of a protobuf document must be structs.";
+
+/// A network frame declares, or buffers, more bytes than the codec accepts.
+pub const FRAME_TOO_LARGE: &str = "The YAD frame is larger than the codec accepts.";
+
+/// A database directory or one of its documents could not be read, written or removed.
+pub const DATABASE_IO_FAILED: &str = "The YAD database directory or one of its documents could not be accessed.";
+
+/// A document name is empty, starts with a dot or holds a path separator.
+pub const INVALID_DOCUMENT_NAME: &str = "Document names must be non-empty, not start with a dot and hold no path separators.";
+
+/// A database has no document of the requested name.
+pub const DOCUMENT_NOT_FOUND: &str = "The database has no document of that name.";
+
+/// A database already has a document of the name being created.
+pub const DOCUMENT_EXISTS: &str = "The database already has a document of that name.";
+
+/// A log file has a malformed header or record.
+pub const MALFORMED_LOG: &str = "The provided YAD log has a malformed header or record.";
+
+/// A log file could not be opened, read or appended to.
+pub const LOG_IO_FAILED: &str = "The YAD log could not be read or written.";
+
+/// A log snapshot was requested at a sequence number it no longer, or does not yet, hold.
+pub const SNAPSHOT_UNAVAILABLE: &str = "The YAD log has no snapshot at that sequence number.";
+
+/// The checksum section is truncated.
+pub const MALFORMED_CHECKSUM: &str = "The provided YAD file has a malformed checksum section.";
+
+/// The bytes after the checksum section do not match it.
+pub const CHECKSUM_MISMATCH: &str = "The provided YAD file does not match its checksum.";
+
+/// A compressed body cannot be decompressed.
+pub const MALFORMED_COMPRESSED_BODY: &str = "The compressed body of the YAD file is malformed.";
+
+/// Compression was requested, or a compressed body read, without the `compression` feature.
+pub const COMPRESSION_NOT_SUPPORTED: &str = "Compressed YAD files need the `compression` feature.";
+
+/// A compressed body cannot be borrowed from the input.
+pub const COMPRESSED_NOT_BORROWABLE: &str = "The provided YAD file is compressed and cannot be read without copying it.";
+
+/// A strict decode found bytes outside of any row.
+pub const STRAY_BYTES: &str = "The provided YAD file has bytes outside of any row.";
@@ -0,0 +1,155 @@
+//! # Cursor FFI (C ABI)
+//!
+//! Cursors enumerate the rows of a [`YAD`] or the keys of a [`Row`] in name
+//! order, in O(n log n) overall instead of the O(n²) of `yad_row_at` /
+//! `row_key_at` loops.
+//!
+//! A cursor remembers the name of the last item it returned and resumes after
+//! it, so rows or keys may be inserted or removed between calls: items added
+//! after the cursor position are still visited, removed ones are skipped.
+//! The document or row must outlive the cursor.
+
+use crate::{Key, Row, YAD};
+use yad_core::ffi::error::{catch_panic, set_null_pointer_error};
+use std::ops::Bound;
+use std::ptr;
+
+/// Iterates the rows of a [`YAD`] document. Created by `yad_row_cursor_new`.
+pub struct YadRowCursor {
+    yad: *const YAD,
+    last: Option<String>,
+}
+
+/// Iterates the keys of a [`Row`]. Created by `row_key_cursor_new`.
+pub struct RowKeyCursor {
+    row: *const Row,
+    last: Option<String>,
+}
+
+/// Returns the bounds of the items following `last`.
+fn after(last: &Option<String>) -> (Bound<&str>, Bound<&str>) {
+    match last {
+        Some(name) => (Bound::Excluded(name.as_str()), Bound::Unbounded),
+        None => (Bound::Unbounded, Bound::Unbounded),
+    }
+}
+
+/// Creates a cursor over the rows of `yad`, positioned before the first row.
+///
+/// # Safety
+/// - `yad` must be a valid pointer to a [`YAD`] and must outlive the cursor.
+///
+/// # Returns
+/// - Pointer to a new cursor, or null if `yad` is null. Free it with `yad_row_cursor_free`.
+#[unsafe(no_mangle)]
+#[cfg_attr(feature = "prefixed-symbols", unsafe(export_name = "yad_row_cursor_new"))]
+pub extern "C" fn yad_row_cursor_new(yad: *const YAD) -> *mut YadRowCursor {
+    catch_panic("yad_row_cursor_new", || {
+        if yad.is_null() {
+            set_null_pointer_error("yad_row_cursor_new", "`yad`");
+            return ptr::null_mut();
+        }
+        Box::into_raw(Box::new(YadRowCursor { yad, last: None }))
+    })
+}
+
+/// Advances the cursor and returns a copy of the next [`Row`].
+///
+/// # Safety
+/// - `cursor` must be a valid pointer returned by `yad_row_cursor_new`.
+///
+/// # Returns
+/// - Pointer to a cloned [`Row`], to be freed with `row_free`.
+/// - Null once every row has been returned (no error is recorded), or if `cursor` is null.
+#[unsafe(no_mangle)]
+#[cfg_attr(feature = "prefixed-symbols", unsafe(export_name = "yad_row_cursor_next"))]
+pub extern "C" fn yad_row_cursor_next(cursor: *mut YadRowCursor) -> *mut Row {
+    catch_panic("yad_row_cursor_next", || {
+        unsafe {
+            if cursor.is_null() {
+                set_null_pointer_error("yad_row_cursor_next", "`cursor`");
+                return ptr::null_mut();
+            }
+            let cursor = &mut *cursor;
+            match (*cursor.yad).rows.range::<str, _>(after(&cursor.last)).next() {
+                Some((name, row)) => {
+                    cursor.last = Some(name.clone());
+                    Box::into_raw(Box::new(row.clone()))
+                }
+                None => ptr::null_mut(),
+            }
+        }
+    })
+}
+
+/// Frees a cursor created by `yad_row_cursor_new`.
+///
+/// # Safety
+/// - `cursor` must be a valid pointer returned by `yad_row_cursor_new`, or null.
+#[unsafe(no_mangle)]
+#[cfg_attr(feature = "prefixed-symbols", unsafe(export_name = "yad_row_cursor_free"))]
+pub extern "C" fn yad_row_cursor_free(cursor: *mut YadRowCursor) {
+    catch_panic("yad_row_cursor_free", || {
+        unsafe { if !cursor.is_null() { let _ = Box::from_raw(cursor); } }
+    })
+}
+
+/// Creates a cursor over the keys of `row`, positioned before the first key.
+///
+/// # Safety
+/// - `row` must be a valid pointer to a [`Row`] and must outlive the cursor.
+///
+/// # Returns
+/// - Pointer to a new cursor, or null if `row` is null. Free it with `row_key_cursor_free`.
+#[unsafe(no_mangle)]
+#[cfg_attr(feature = "prefixed-symbols", unsafe(export_name = "yad_row_key_cursor_new"))]
+pub extern "C" fn row_key_cursor_new(row: *const Row) -> *mut RowKeyCursor {
+    catch_panic("row_key_cursor_new", || {
+        if row.is_null() {
+            set_null_pointer_error("row_key_cursor_new", "`row`");
+            return ptr::null_mut();
+        }
+        Box::into_raw(Box::new(RowKeyCursor { row, last: None }))
+    })
+}
+
+/// Advances the cursor and returns a copy of the next [`Key`].
+///
+/// # Safety
+/// - `cursor` must be a valid pointer returned by `row_key_cursor_new`.
+///
+/// # Returns
+/// - Pointer to a cloned [`Key`], to be freed with `key_free`.
+/// - Null once every key has been returned (no error is recorded), or if `cursor` is null.
+#[unsafe(no_mangle)]
+#[cfg_attr(feature = "prefixed-symbols", unsafe(export_name = "yad_row_key_cursor_next"))]
+pub extern "C" fn row_key_cursor_next(cursor: *mut RowKeyCursor) -> *mut Key {
+    catch_panic("row_key_cursor_next", || {
+        unsafe {
+            if cursor.is_null() {
+                set_null_pointer_error("row_key_cursor_next", "`cursor`");
+                return ptr::null_mut();
+            }
+            let cursor = &mut *cursor;
+            match (*cursor.row).keys.range::<str, _>(after(&cursor.last)).next() {
+                Some((name, key)) => {
+                    cursor.last = Some(name.clone());
+                    Box::into_raw(Box::new(key.clone()))
+                }
+                None => ptr::null_mut(),
+            }
+        }
+    })
+}
+
+/// Frees a cursor created by `row_key_cursor_new`.
+///
+/// # Safety
+/// - `cursor` must be a valid pointer returned by `row_key_cursor_new`, or null.
+#[unsafe(no_mangle)]
+#[cfg_attr(feature = "prefixed-symbols", unsafe(export_name = "yad_row_key_cursor_free"))]
+pub extern "C" fn row_key_cursor_free(cursor: *mut RowKeyCursor) {
+    catch_panic("row_key_cursor_free", || {
+        unsafe { if !cursor.is_null() { let _ = Box::from_raw(cursor); } }
+    })
+}
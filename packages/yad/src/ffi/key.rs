@@ -1,7 +1,10 @@
 use crate::Key;
 use crate::Value;
+use yad_core::ffi::ByteBuffer;
+use yad_core::ffi::error::{catch_panic, set_error_message, set_last_error, set_null_pointer_error, YadErrorCode};
 use std::ffi::CStr;
 use std::ptr;
+use super::owned_cstring;
 
 /// # Key FFI (C ABI)
 ///
@@ -27,13 +30,25 @@ use std::ptr;
 /// # Returns
 /// - Pointer to a heap-allocated [`Key`] object. Must be freed with `key_free`.
 #[unsafe(no_mangle)]
+#[cfg_attr(feature = "prefixed-symbols", unsafe(export_name = "yad_key_new"))]
 pub extern "C" fn key_new(name: *const i8, value: *const Value) -> *mut Key {
-    unsafe {
-        if name.is_null() || value.is_null() { return ptr::null_mut(); }
-        let cstr = CStr::from_ptr(name);
-        let name_str = match cstr.to_str() { Ok(s) => s, Err(_) => return ptr::null_mut() };
-        Box::into_raw(Box::new(Key::new(name_str, (*value).clone())))
-    }
+    catch_panic("key_new", || {
+        unsafe {
+            if name.is_null() || value.is_null() {
+                set_null_pointer_error("key_new", "`name` or `value`");
+                return ptr::null_mut();
+            }
+            let cstr = CStr::from_ptr(name);
+            let name_str = match cstr.to_str() {
+                Ok(s) => s,
+                Err(_) => {
+                    set_last_error(YadErrorCode::InvalidUtf8, "key_new: `name` is not valid UTF-8");
+                    return ptr::null_mut();
+                }
+            };
+            Box::into_raw(Box::new(Key::new(name_str, (*value).clone())))
+        }
+    })
 }
 
 /// Frees a [`Key`] previously allocated by `key_new`.
@@ -42,8 +57,11 @@ pub extern "C" fn key_new(name: *const i8, value: *const Value) -> *mut Key {
 /// - `key` must be a valid pointer returned by `key_new`.
 /// - Passing a null pointer is safe and has no effect.
 #[unsafe(no_mangle)]
+#[cfg_attr(feature = "prefixed-symbols", unsafe(export_name = "yad_key_free"))]
 pub extern "C" fn key_free(key: *mut Key) {
-    unsafe { if !key.is_null() { let _ = Box::from_raw(key); } }
+    catch_panic("key_free", || {
+        unsafe { if !key.is_null() { let _ = Box::from_raw(key); } }
+    })
 }
 
 /// Serializes a [`Key`] to an external byte buffer.
@@ -57,19 +75,62 @@ pub extern "C" fn key_free(key: *mut Key) {
 /// # Returns
 /// - Number of bytes written to the buffer.
 #[unsafe(no_mangle)]
+#[cfg_attr(feature = "prefixed-symbols", unsafe(export_name = "yad_key_serialize"))]
 pub extern "C" fn key_serialize(key: *const Key, out_bytes: *mut u8, max_len: usize) -> usize {
-    unsafe {
-        if key.is_null() || out_bytes.is_null() { return 0; }
-        let key = &*key;
-        match key.serialize() {
-            Ok(vec) => {
-                let len = vec.len().min(max_len);
-                ptr::copy_nonoverlapping(vec.as_ptr(), out_bytes, len);
-                len
+    catch_panic("key_serialize", || {
+        unsafe {
+            if key.is_null() || out_bytes.is_null() {
+                set_null_pointer_error("key_serialize", "`key` or `out_bytes`");
+                return 0;
+            }
+            let key = &*key;
+            match key.serialize() {
+                Ok(vec) => {
+                    if vec.len() > max_len {
+                        set_last_error(YadErrorCode::BufferTooSmall, format!("key_serialize: {} bytes needed, `max_len` is {max_len}", vec.len()));
+                    }
+                    let len = vec.len().min(max_len);
+                    ptr::copy_nonoverlapping(vec.as_ptr(), out_bytes, len);
+                    len
+                }
+                Err(e) => {
+                    set_error_message("key_serialize", YadErrorCode::Encode, e);
+                    0
+                }
+            }
+        }
+    })
+}
+
+/// Serializes a [`Key`] into a newly allocated [`ByteBuffer`].
+///
+/// Unlike `key_serialize`, the output is never truncated and its length is
+/// reported in the buffer itself.
+///
+/// # Safety
+/// - `key` must be a valid pointer to a [`Key`].
+///
+/// # Returns
+/// - A [`ByteBuffer`] with the serialized key; free it with `byte_buffer_free`.
+/// - An empty buffer with a null `ptr` on error (see `yad_last_error`).
+#[unsafe(no_mangle)]
+#[cfg_attr(feature = "prefixed-symbols", unsafe(export_name = "yad_key_as_buffer"))]
+pub extern "C" fn key_as_buffer(key: *const Key) -> ByteBuffer {
+    catch_panic("key_as_buffer", || {
+        unsafe {
+            if key.is_null() {
+                set_null_pointer_error("key_as_buffer", "`key`");
+                return ByteBuffer::null();
+            }
+            match (*key).serialize() {
+                Ok(vec) => ByteBuffer::from_vec(vec),
+                Err(e) => {
+                    set_error_message("key_as_buffer", YadErrorCode::Encode, e);
+                    ByteBuffer::null()
+                }
             }
-            Err(_) => 0,
         }
-    }
+    })
 }
 
 /// Deserializes a [`Key`] from a byte buffer.
@@ -85,32 +146,93 @@ pub extern "C" fn key_serialize(key: *const Key, out_bytes: *mut u8, max_len: us
 /// # Returns
 /// - Pointer to a newly allocated [`Key`], or null on error.
 #[unsafe(no_mangle)]
+#[cfg_attr(feature = "prefixed-symbols", unsafe(export_name = "yad_key_deserialize"))]
 pub extern "C" fn key_deserialize(bytes: *const u8, len: usize) -> *mut Key {
-    unsafe {
-        if bytes.is_null() || len == 0 { return ptr::null_mut(); }
-        let vec = std::slice::from_raw_parts(bytes, len).to_vec();
-        match Key::deserialize(vec) {
-            Ok(k) => Box::into_raw(Box::new(k)),
-            Err(_) => ptr::null_mut(),
+    catch_panic("key_deserialize", || {
+        unsafe {
+            if bytes.is_null() || len == 0 {
+                set_null_pointer_error("key_deserialize", "`bytes` (of non-zero `len`)");
+                return ptr::null_mut();
+            }
+            let vec = std::slice::from_raw_parts(bytes, len).to_vec();
+            match Key::deserialize(vec) {
+                Ok(k) => Box::into_raw(Box::new(k)),
+                Err(e) => {
+                    set_error_message("key_deserialize", YadErrorCode::Decode, e);
+                    ptr::null_mut()
+                }
+            }
         }
-    }
+    })
 }
 
-/// Returns a pointer to the name of the [`Key`] as a C string.
+/// Returns a pointer to the UTF-8 bytes of the name of the [`Key`].
+///
+/// The name is **not** null-terminated: read exactly `key_get_name_len`
+/// bytes, or use `key_get_name_cstr` for a C string.
 ///
 /// # Safety
 /// - `key` must be a valid pointer to a [`Key`].
-/// - Returned pointer is valid as long as the `Key` is alive.
+/// - Returned pointer is valid as long as the `Key` is alive and unchanged.
 /// - Do **not** free the returned pointer.
 ///
 /// # Returns
-/// - `const char*` pointer to the key's name.
+/// - Pointer to the first byte of the key's name.
 #[unsafe(no_mangle)]
+#[cfg_attr(feature = "prefixed-symbols", unsafe(export_name = "yad_key_get_name"))]
 pub extern "C" fn key_get_name(key: *const Key) -> *const i8 {
-    unsafe {
-        if key.is_null() { return ptr::null(); }
-        (*key).name.as_ptr() as *const i8
-    }
+    catch_panic("key_get_name", || {
+        unsafe {
+            if key.is_null() {
+                set_null_pointer_error("key_get_name", "`key`");
+                return ptr::null();
+            }
+            (*key).name.as_ptr() as *const i8
+        }
+    })
+}
+
+/// Returns the length in bytes of the name of the [`Key`], without a terminator.
+///
+/// # Safety
+/// - `key` must be a valid pointer to a [`Key`].
+///
+/// # Returns
+/// - The number of bytes `key_get_name` points to, or `0` if `key` is null.
+#[unsafe(no_mangle)]
+#[cfg_attr(feature = "prefixed-symbols", unsafe(export_name = "yad_key_get_name_len"))]
+pub extern "C" fn key_get_name_len(key: *const Key) -> usize {
+    catch_panic("key_get_name_len", || {
+        unsafe {
+            if key.is_null() {
+                set_null_pointer_error("key_get_name_len", "`key`");
+                return 0;
+            }
+            (&*key).name.len()
+        }
+    })
+}
+
+/// Returns a copy of the name of the [`Key`] as a null-terminated C string.
+///
+/// # Safety
+/// - `key` must be a valid pointer to a [`Key`].
+///
+/// # Returns
+/// - A C string owned by the caller, who must free it with `yad_string_free`.
+/// - Null if `key` is null or the name contains a null byte.
+#[unsafe(no_mangle)]
+#[cfg_attr(feature = "prefixed-symbols", unsafe(export_name = "yad_key_get_name_cstr"))]
+pub extern "C" fn key_get_name_cstr(key: *const Key) -> *mut i8 {
+    catch_panic("key_get_name_cstr", || {
+        unsafe {
+            if key.is_null() {
+                set_null_pointer_error("key_get_name_cstr", "`key`");
+                return ptr::null_mut();
+            }
+            owned_cstring("key_get_name_cstr", &(*key).name)
+        }
+    })
 }
 
 /// Updates the [`Value`] of the given [`Key`].
@@ -123,11 +245,17 @@ pub extern "C" fn key_get_name(key: *const Key) -> *const i8 {
 /// - `key`: Pointer to the [`Key`] to update.
 /// - `value`: Pointer to the new [`Value`].
 #[unsafe(no_mangle)]
+#[cfg_attr(feature = "prefixed-symbols", unsafe(export_name = "yad_key_set_value"))]
 pub extern "C" fn key_set_value(key: *mut Key, value: *const Value) {
-    unsafe {
-        if key.is_null() || value.is_null() { return; }
-        (*key).set_value((*value).clone());
-    }
+    catch_panic("key_set_value", || {
+        unsafe {
+            if key.is_null() || value.is_null() {
+                set_null_pointer_error("key_set_value", "`key` or `value`");
+                return;
+            }
+            (*key).set_value((*value).clone());
+        }
+    })
 }
 
 /// Returns a pointer to the [`Value`] of the given [`Key`].
@@ -139,9 +267,15 @@ pub extern "C" fn key_set_value(key: *mut Key, value: *const Value) {
 /// # Returns
 /// - Pointer to the internal [`Value`].
 #[unsafe(no_mangle)]
+#[cfg_attr(feature = "prefixed-symbols", unsafe(export_name = "yad_key_get_value"))]
 pub extern "C" fn key_get_value(key: *const Key) -> *const Value {
-    unsafe {
-        if key.is_null() { return ptr::null(); }
-        &(*key).value
-    }
+    catch_panic("key_get_value", || {
+        unsafe {
+            if key.is_null() {
+                set_null_pointer_error("key_get_value", "`key`");
+                return ptr::null();
+            }
+            &(*key).value
+        }
+    })
 }
@@ -1,7 +1,12 @@
+#![cfg_attr(feature = "prefixed-symbols", allow(unused_attributes))]
+pub mod cursor;
 pub mod key;
 pub mod row;
 
 use crate::{YAD, Version, Row};
+use crate::options::DecodeOptions;
+use yad_core::ffi::ByteBuffer;
+use yad_core::ffi::error::{catch_panic, set_decode_error, set_error_message, set_last_error, set_null_pointer_error, YadErrorCode};
 use std::ffi::{CStr, CString};
 use std::ptr;
 
@@ -21,8 +26,11 @@ use std::ptr;
 /// # Returns
 /// - Pointer to a newly allocated [`Version`]. Must be freed using `version_free`.
 #[unsafe(no_mangle)]
+#[cfg_attr(feature = "prefixed-symbols", unsafe(export_name = "yad_version_new"))]
 pub extern "C" fn version_new(major: u8, minor: u8, patch: u8, beta: u8) -> *mut Version {
-    Box::into_raw(Box::new(Version { major, minor, patch, beta }))
+    catch_panic("version_new", || {
+        Box::into_raw(Box::new(Version { major, minor, patch, beta }))
+    })
 }
 
 /// Frees a [`Version`] previously allocated by `version_new`.
@@ -30,8 +38,11 @@ pub extern "C" fn version_new(major: u8, minor: u8, patch: u8, beta: u8) -> *mut
 /// # Safety
 /// - `version` must be a valid pointer returned by `version_new`.
 #[unsafe(no_mangle)]
+#[cfg_attr(feature = "prefixed-symbols", unsafe(export_name = "yad_version_free"))]
 pub extern "C" fn version_free(version: *mut Version) {
-    unsafe { if !version.is_null() { let _ = Box::from_raw(version); } }
+    catch_panic("version_free", || {
+        unsafe { if !version.is_null() { let _ = Box::from_raw(version); } }
+    })
 }
 
 /// Serializes a [`Version`] into a 5-byte array.
@@ -40,12 +51,18 @@ pub extern "C" fn version_free(version: *mut Version) {
 /// - `version` must be a valid pointer to a [`Version`].
 /// - `out_bytes` must point to at least 5 writable bytes.
 #[unsafe(no_mangle)]
+#[cfg_attr(feature = "prefixed-symbols", unsafe(export_name = "yad_version_serialize"))]
 pub extern "C" fn version_serialize(version: *const Version, out_bytes: *mut u8) {
-    unsafe {
-        if version.is_null() || out_bytes.is_null() { return; }
-        let bytes = (*version).serialize();
-        ptr::copy_nonoverlapping(bytes.as_ptr(), out_bytes, bytes.len());
-    }
+    catch_panic("version_serialize", || {
+        unsafe {
+            if version.is_null() || out_bytes.is_null() {
+                set_null_pointer_error("version_serialize", "`version` or `out_bytes`");
+                return;
+            }
+            let bytes = (*version).serialize();
+            ptr::copy_nonoverlapping(bytes.as_ptr(), out_bytes, bytes.len());
+        }
+    })
 }
 
 /// Deserializes a [`Version`] from a 5-byte buffer.
@@ -55,15 +72,24 @@ pub extern "C" fn version_serialize(version: *const Version, out_bytes: *mut u8)
 /// - Returns null pointer on failure.
 /// - Allocated memory must be freed with `version_free`.
 #[unsafe(no_mangle)]
+#[cfg_attr(feature = "prefixed-symbols", unsafe(export_name = "yad_version_deserialize"))]
 pub extern "C" fn version_deserialize(bytes: *const u8) -> *mut Version {
-    unsafe {
-        if bytes.is_null() { return ptr::null_mut(); }
-        let slice = std::slice::from_raw_parts(bytes, 5).to_vec();
-        match Version::deserialize(slice) {
-            Ok(ver) => Box::into_raw(Box::new(ver)),
-            Err(_) => ptr::null_mut(),
+    catch_panic("version_deserialize", || {
+        unsafe {
+            if bytes.is_null() {
+                set_null_pointer_error("version_deserialize", "`bytes`");
+                return ptr::null_mut();
+            }
+            let slice = std::slice::from_raw_parts(bytes, 5).to_vec();
+            match Version::deserialize(slice) {
+                Ok(ver) => Box::into_raw(Box::new(ver)),
+                Err(e) => {
+                    set_error_message("version_deserialize", YadErrorCode::Decode, e);
+                    ptr::null_mut()
+                }
+            }
         }
-    }
+    })
 }
 
 /// # YAD FFI (C ABI)
@@ -77,11 +103,17 @@ pub extern "C" fn version_deserialize(bytes: *const u8) -> *mut Version {
 /// - `version` must be a valid pointer to a [`Version`].
 /// - Returns a pointer to a new [`YAD`] object. Must be freed with `yad_free`.
 #[unsafe(no_mangle)]
+#[cfg_attr(feature = "prefixed-symbols", unsafe(export_name = "yad_new_empty"))]
 pub extern "C" fn yad_new_empty(version: *const Version) -> *mut YAD {
-    unsafe {
-        if version.is_null() { return ptr::null_mut(); }
-        Box::into_raw(Box::new(YAD::new_empty((*version).clone())))
-    }
+    catch_panic("yad_new_empty", || {
+        unsafe {
+            if version.is_null() {
+                set_null_pointer_error("yad_new_empty", "`version`");
+                return ptr::null_mut();
+            }
+            Box::into_raw(Box::new(YAD::new_empty((*version).clone())))
+        }
+    })
 }
 
 /// Creates a new [`YAD`] document from version and an array of [`Row`] pointers.
@@ -92,18 +124,24 @@ pub extern "C" fn yad_new_empty(version: *const Version) -> *mut YAD {
 /// - Null pointers inside `rows` are ignored.
 /// - Returns a pointer to a new [`YAD`] object. Must be freed with `yad_free`.
 #[unsafe(no_mangle)]
+#[cfg_attr(feature = "prefixed-symbols", unsafe(export_name = "yad_new"))]
 pub extern "C" fn yad_new(version: *const Version, rows: *const *mut Row, rows_len: usize) -> *mut YAD {
-    unsafe {
-        if version.is_null() { return ptr::null_mut(); }
-        let mut vec_rows = Vec::with_capacity(rows_len);
-        if !rows.is_null() {
-            for i in 0..rows_len {
-                let row_ptr = *rows.add(i);
-                if !row_ptr.is_null() { vec_rows.push((*row_ptr).clone()); }
+    catch_panic("yad_new", || {
+        unsafe {
+            if version.is_null() {
+                set_null_pointer_error("yad_new", "`version`");
+                return ptr::null_mut();
             }
+            let mut vec_rows = Vec::with_capacity(rows_len);
+            if !rows.is_null() {
+                for i in 0..rows_len {
+                    let row_ptr = *rows.add(i);
+                    if !row_ptr.is_null() { vec_rows.push((*row_ptr).clone()); }
+                }
+            }
+            Box::into_raw(Box::new(YAD::new((*version).clone(), vec_rows)))
         }
-        Box::into_raw(Box::new(YAD::new((*version).clone(), vec_rows)))
-    }
+    })
 }
 
 /// Frees a [`YAD`] object previously allocated.
@@ -111,23 +149,33 @@ pub extern "C" fn yad_new(version: *const Version, rows: *const *mut Row, rows_l
 /// # Safety
 /// - `yad` must be a valid pointer returned by `yad_new` or `yad_new_empty`.
 #[unsafe(no_mangle)]
+#[cfg_attr(feature = "prefixed-symbols", unsafe(export_name = "yad_free"))]
 pub extern "C" fn yad_free(yad: *mut YAD) {
-    unsafe { if !yad.is_null() { let _ = Box::from_raw(yad); } }
+    catch_panic("yad_free", || {
+        unsafe { if !yad.is_null() { let _ = Box::from_raw(yad); } }
+    })
 }
 
-/// Inserts a [`Row`] into the [`YAD`] document.
+/// Inserts a copy of a [`Row`] into the [`YAD`] document, replacing any row of
+/// the same name, like [`YAD::adopt_row`].
 ///
 /// # Safety
 /// - `yad` must be a valid pointer to a [`YAD`].
 /// - `row` must be a valid pointer to a [`Row`].
 #[unsafe(no_mangle)]
+#[cfg_attr(feature = "prefixed-symbols", unsafe(export_name = "yad_insert_row"))]
 pub extern "C" fn yad_insert_row(yad: *mut YAD, row: *mut Row) {
-    unsafe {
-        if yad.is_null() || row.is_null() { return; }
-        let yad = &mut *yad;
-        let row = &*row;
-        yad.rows.insert(row.name.clone(), row.clone());
-    }
+    catch_panic("yad_insert_row", || {
+        unsafe {
+            if yad.is_null() || row.is_null() {
+                set_null_pointer_error("yad_insert_row", "`yad` or `row`");
+                return;
+            }
+            let yad = &mut *yad;
+            let row = &*row;
+            yad.adopt_row(row.clone());
+        }
+    })
 }
 
 /// Removes a [`Row`] from the [`YAD`] document by name.
@@ -140,15 +188,30 @@ pub extern "C" fn yad_insert_row(yad: *mut YAD, row: *mut Row) {
 /// - Pointer to removed [`Row`] or null if not found.
 /// - Caller must free with `row_free`.
 #[unsafe(no_mangle)]
+#[cfg_attr(feature = "prefixed-symbols", unsafe(export_name = "yad_remove_row"))]
 pub extern "C" fn yad_remove_row(yad: *mut YAD, name: *const i8) -> *mut Row {
-    unsafe {
-        if yad.is_null() || name.is_null() { return ptr::null_mut(); }
-        let cstr = match CStr::from_ptr(name).to_str() { Ok(s) => s, Err(_) => return ptr::null_mut() };
-        match (*yad).rows.remove(cstr) {
-            Some(row) => Box::into_raw(Box::new(row)),
-            None => ptr::null_mut(),
+    catch_panic("yad_remove_row", || {
+        unsafe {
+            if yad.is_null() || name.is_null() {
+                set_null_pointer_error("yad_remove_row", "`yad` or `name`");
+                return ptr::null_mut();
+            }
+            let cstr = match CStr::from_ptr(name).to_str() {
+                Ok(s) => s,
+                Err(_) => {
+                    set_last_error(YadErrorCode::InvalidUtf8, "yad_remove_row: `name` is not valid UTF-8");
+                    return ptr::null_mut();
+                }
+            };
+            match (*yad).remove_row(cstr) {
+                Some(row) => Box::into_raw(Box::new(row)),
+                None => {
+                    set_last_error(YadErrorCode::NotFound, format!("yad_remove_row: no row named `{cstr}`"));
+                    ptr::null_mut()
+                }
+            }
         }
-    }
+    })
 }
 
 /// Serializes a [`YAD`] document into a byte buffer.
@@ -158,19 +221,62 @@ pub extern "C" fn yad_remove_row(yad: *mut YAD, name: *const i8) -> *mut Row {
 /// - `out_bytes` must point to a valid buffer of at least `max_len` bytes.
 /// - Returns the number of bytes written.
 #[unsafe(no_mangle)]
+#[cfg_attr(feature = "prefixed-symbols", unsafe(export_name = "yad_serialize"))]
 pub extern "C" fn yad_serialize(yad: *const YAD, out_bytes: *mut u8, max_len: usize) -> usize {
-    unsafe {
-        if yad.is_null() || out_bytes.is_null() { return 0; }
-        let yad = &*yad;
-        match yad.serialize() {
-            Ok(vec) => {
-                let len = vec.len().min(max_len);
-                ptr::copy_nonoverlapping(vec.as_ptr(), out_bytes, len);
-                len
+    catch_panic("yad_serialize", || {
+        unsafe {
+            if yad.is_null() || out_bytes.is_null() {
+                set_null_pointer_error("yad_serialize", "`yad` or `out_bytes`");
+                return 0;
+            }
+            let yad = &*yad;
+            match yad.serialize() {
+                Ok(vec) => {
+                    if vec.len() > max_len {
+                        set_last_error(YadErrorCode::BufferTooSmall, format!("yad_serialize: {} bytes needed, `max_len` is {max_len}", vec.len()));
+                    }
+                    let len = vec.len().min(max_len);
+                    ptr::copy_nonoverlapping(vec.as_ptr(), out_bytes, len);
+                    len
+                }
+                Err(e) => {
+                    set_error_message("yad_serialize", YadErrorCode::Encode, e);
+                    0
+                }
             }
-            Err(_) => 0,
         }
-    }
+    })
+}
+
+/// Serializes a [`YAD`] into a newly allocated [`ByteBuffer`].
+///
+/// Unlike `yad_serialize`, the output is never truncated and its length is
+/// reported in the buffer itself.
+///
+/// # Safety
+/// - `yad` must be a valid pointer to a [`YAD`].
+///
+/// # Returns
+/// - A [`ByteBuffer`] with the serialized document; free it with `byte_buffer_free`.
+/// - An empty buffer with a null `ptr` on error (see `yad_last_error`).
+#[unsafe(no_mangle)]
+#[cfg_attr(feature = "prefixed-symbols", unsafe(export_name = "yad_as_buffer"))]
+pub extern "C" fn yad_as_buffer(yad: *const YAD) -> ByteBuffer {
+    catch_panic("yad_as_buffer", || {
+        unsafe {
+            if yad.is_null() {
+                set_null_pointer_error("yad_as_buffer", "`yad`");
+                return ByteBuffer::null();
+            }
+            match (*yad).serialize() {
+                Ok(vec) => ByteBuffer::from_vec(vec),
+                Err(e) => {
+                    set_error_message("yad_as_buffer", YadErrorCode::Encode, e);
+                    ByteBuffer::null()
+                }
+            }
+        }
+    })
 }
 
 /// Deserializes a [`YAD`] document from a byte buffer.
@@ -179,15 +285,58 @@ pub extern "C" fn yad_serialize(yad: *const YAD, out_bytes: *mut u8, max_len: us
 /// - `bytes` must point to a valid buffer of length `len`.
 /// - Returns null on failure. Allocated memory must be freed with `yad_free`.
 #[unsafe(no_mangle)]
+#[cfg_attr(feature = "prefixed-symbols", unsafe(export_name = "yad_deserialize"))]
 pub extern "C" fn yad_deserialize(bytes: *const u8, len: usize) -> *mut YAD {
-    unsafe {
-        if bytes.is_null() || len == 0 { return ptr::null_mut(); }
-        let vec = std::slice::from_raw_parts(bytes, len).to_vec();
-        match YAD::deserialize(vec) {
-            Ok(yad) => Box::into_raw(Box::new(yad)),
-            Err(_) => ptr::null_mut(),
+    catch_panic("yad_deserialize", || {
+        unsafe {
+            if bytes.is_null() || len == 0 {
+                set_null_pointer_error("yad_deserialize", "`bytes` (of non-zero `len`)");
+                return ptr::null_mut();
+            }
+            let vec = std::slice::from_raw_parts(bytes, len).to_vec();
+            match YAD::deserialize(vec) {
+                Ok(yad) => Box::into_raw(Box::new(yad)),
+                Err(e) => {
+                    set_error_message("yad_deserialize", YadErrorCode::Decode, e);
+                    ptr::null_mut()
+                }
+            }
         }
-    }
+    })
+}
+
+/// Deserializes a [`YAD`] document from a byte buffer, like [`yad_deserialize`],
+/// unless decoding it would allocate more than `max_alloc` bytes.
+///
+/// Use it for untrusted input: the length is checked before the buffer is
+/// copied, and the body again once its encodings are expanded (see
+/// [`DecodeOptions::max_size`]).
+///
+/// # Safety
+/// - `buff` must point to a valid buffer of length `len`.
+/// - Returns null on failure, with [`YadErrorCode::LimitExceeded`] recorded if
+///   the document is over the limit. Allocated memory must be freed with `yad_free`.
+#[unsafe(no_mangle)]
+#[cfg_attr(feature = "prefixed-symbols", unsafe(export_name = "yad_from_buffer_limited"))]
+pub extern "C" fn yad_from_buffer_limited(buff: *const u8, len: usize, max_alloc: usize) -> *mut YAD {
+    catch_panic("yad_from_buffer_limited", || {
+        unsafe {
+            if buff.is_null() || len == 0 {
+                set_null_pointer_error("yad_from_buffer_limited", "`buff` (of non-zero `len`)");
+                return ptr::null_mut();
+            }
+            let options = DecodeOptions::limited(max_alloc);
+            let decoded = options.check_size(len)
+                .and_then(|_| YAD::deserialize_with_options(std::slice::from_raw_parts(buff, len).to_vec(), &options));
+            match decoded {
+                Ok(yad) => Box::into_raw(Box::new(yad)),
+                Err(e) => {
+                    set_decode_error("yad_from_buffer_limited", e);
+                    ptr::null_mut()
+                }
+            }
+        }
+    })
 }
 
 /// # YAD FFI – Row Accessors
@@ -202,15 +351,30 @@ pub extern "C" fn yad_deserialize(bytes: *const u8, len: usize) -> *mut YAD {
 /// - Returns a pointer to a cloned [`Row`], or null if not found.
 /// - Caller must free the returned row using `row_free`.
 #[unsafe(no_mangle)]
+#[cfg_attr(feature = "prefixed-symbols", unsafe(export_name = "yad_get_row"))]
 pub extern "C" fn yad_get_row(yad: *const YAD, name: *const i8) -> *mut Row {
-    unsafe {
-        if yad.is_null() || name.is_null() { return ptr::null_mut(); }
-        let cstr = match CStr::from_ptr(name).to_str() { Ok(s) => s, Err(_) => return ptr::null_mut() };
-        match (*yad).rows.get(cstr) {
-            Some(row) => Box::into_raw(Box::new(row.clone())),
-            None => ptr::null_mut(),
+    catch_panic("yad_get_row", || {
+        unsafe {
+            if yad.is_null() || name.is_null() {
+                set_null_pointer_error("yad_get_row", "`yad` or `name`");
+                return ptr::null_mut();
+            }
+            let cstr = match CStr::from_ptr(name).to_str() {
+                Ok(s) => s,
+                Err(_) => {
+                    set_last_error(YadErrorCode::InvalidUtf8, "yad_get_row: `name` is not valid UTF-8");
+                    return ptr::null_mut();
+                }
+            };
+            match (*yad).rows.get(cstr) {
+                Some(row) => Box::into_raw(Box::new(row.clone())),
+                None => {
+                    set_last_error(YadErrorCode::NotFound, format!("yad_get_row: no row named `{cstr}`"));
+                    ptr::null_mut()
+                }
+            }
         }
-    }
+    })
 }
 
 /// Removes a [`Row`] from a [`YAD`] by name.
@@ -221,15 +385,30 @@ pub extern "C" fn yad_get_row(yad: *const YAD, name: *const i8) -> *mut Row {
 /// - Returns a pointer to the removed [`Row`] or null if not found.
 /// - Caller must free the returned row using `row_free`.
 #[unsafe(no_mangle)]
+#[cfg_attr(feature = "prefixed-symbols", unsafe(export_name = "yad_remove_row_by_name"))]
 pub extern "C" fn yad_remove_row_by_name(yad: *mut YAD, name: *const i8) -> *mut Row {
-    unsafe {
-        if yad.is_null() || name.is_null() { return ptr::null_mut(); }
-        let cstr = match CStr::from_ptr(name).to_str() { Ok(s) => s, Err(_) => return ptr::null_mut() };
-        match (*yad).rows.remove(cstr) {
-            Some(row) => Box::into_raw(Box::new(row)),
-            None => ptr::null_mut(),
+    catch_panic("yad_remove_row_by_name", || {
+        unsafe {
+            if yad.is_null() || name.is_null() {
+                set_null_pointer_error("yad_remove_row_by_name", "`yad` or `name`");
+                return ptr::null_mut();
+            }
+            let cstr = match CStr::from_ptr(name).to_str() {
+                Ok(s) => s,
+                Err(_) => {
+                    set_last_error(YadErrorCode::InvalidUtf8, "yad_remove_row_by_name: `name` is not valid UTF-8");
+                    return ptr::null_mut();
+                }
+            };
+            match (*yad).remove_row(cstr) {
+                Some(row) => Box::into_raw(Box::new(row)),
+                None => {
+                    set_last_error(YadErrorCode::NotFound, format!("yad_remove_row_by_name: no row named `{cstr}`"));
+                    ptr::null_mut()
+                }
+            }
         }
-    }
+    })
 }
 
 /// Sets or replaces a [`Row`] in the [`YAD`] document.
@@ -239,13 +418,19 @@ pub extern "C" fn yad_remove_row_by_name(yad: *mut YAD, name: *const i8) -> *mut
 /// - `row` must be a valid pointer to a [`Row`].
 /// - The row will be cloned into the document; original memory must still be freed separately if needed.
 #[unsafe(no_mangle)]
+#[cfg_attr(feature = "prefixed-symbols", unsafe(export_name = "yad_set_row"))]
 pub extern "C" fn yad_set_row(yad: *mut YAD, row: *mut Row) {
-    unsafe {
-        if yad.is_null() || row.is_null() { return; }
-        let yad = &mut *yad;
-        let row = &*row;
-        yad.rows.insert(row.name.clone(), row.clone());
-    }
+    catch_panic("yad_set_row", || {
+        unsafe {
+            if yad.is_null() || row.is_null() {
+                set_null_pointer_error("yad_set_row", "`yad` or `row`");
+                return;
+            }
+            let yad = &mut *yad;
+            let row = &*row;
+            yad.adopt_row(row.clone());
+        }
+    })
 }
 
 /// # YAD FFI – Row Utilities
@@ -257,13 +442,49 @@ pub extern "C" fn yad_set_row(yad: *mut YAD, row: *mut Row) {
 /// # Safety
 /// - `yad` must be a valid pointer to a [`YAD`] document.
 #[unsafe(no_mangle)]
+#[cfg_attr(feature = "prefixed-symbols", unsafe(export_name = "yad_row_count"))]
 pub extern "C" fn yad_row_count(yad: *const YAD) -> usize {
-    unsafe {
-        if yad.is_null() {
-            return 0;
+    catch_panic("yad_row_count", || {
+        unsafe {
+            if yad.is_null() {
+                set_null_pointer_error("yad_row_count", "`yad`");
+                return 0;
+            }
+            (*yad).rows.len()
         }
-        (*yad).rows.len()
-    }
+    })
+}
+
+/// Returns a copy of the [`Row`] at position `index` of the [`YAD`] document.
+///
+/// Rows are ordered by name, the same order as `yad_row_names` and
+/// `yad_row_cursor_next`. Each call walks the document from the start; use a
+/// row cursor to enumerate every row.
+///
+/// # Safety
+/// - `yad` must be a valid pointer to a [`YAD`] document.
+///
+/// # Returns
+/// - Pointer to a cloned [`Row`], or null if `index` is out of bounds.
+/// - Caller must free the returned row using `row_free`.
+#[unsafe(no_mangle)]
+#[cfg_attr(feature = "prefixed-symbols", unsafe(export_name = "yad_row_at"))]
+pub extern "C" fn yad_row_at(yad: *const YAD, index: usize) -> *mut Row {
+    catch_panic("yad_row_at", || {
+        unsafe {
+            if yad.is_null() {
+                set_null_pointer_error("yad_row_at", "`yad`");
+                return ptr::null_mut();
+            }
+            match (*yad).rows.values().nth(index) {
+                Some(row) => Box::into_raw(Box::new(row.clone())),
+                None => {
+                    set_last_error(YadErrorCode::OutOfBounds, format!("yad_row_at: index {index} is out of bounds"));
+                    ptr::null_mut()
+                }
+            }
+        }
+    })
 }
 
 /// Returns a heap-allocated array of C strings representing the row names.
@@ -274,23 +495,27 @@ pub extern "C" fn yad_row_count(yad: *const YAD) -> usize {
 /// - Caller is responsible for freeing each string with `CString::from_raw`
 ///   and the array itself with `Box::from_raw`.
 #[unsafe(no_mangle)]
+#[cfg_attr(feature = "prefixed-symbols", unsafe(export_name = "yad_row_names"))]
 pub extern "C" fn yad_row_names(yad: *const YAD) -> *mut *mut i8 {
-    unsafe {
-        if yad.is_null() {
-            return ptr::null_mut();
-        }
+    catch_panic("yad_row_names", || {
+        unsafe {
+            if yad.is_null() {
+                set_null_pointer_error("yad_row_names", "`yad`");
+                return ptr::null_mut();
+            }
 
-        let yad = &*yad;
-        let mut cstrings: Vec<*mut i8> = Vec::with_capacity(yad.rows.len());
+            let yad = &*yad;
+            let mut cstrings: Vec<*mut i8> = Vec::with_capacity(yad.rows.len());
 
-        for row_name in yad.rows.keys() {
-            let cstr = CString::new(row_name.as_str()).unwrap_or_else(|_| CString::new("").unwrap());
-            cstrings.push(cstr.into_raw());
-        }
+            for row_name in yad.rows.keys() {
+                let cstr = CString::new(row_name.as_str()).unwrap_or_else(|_| CString::new("").unwrap());
+                cstrings.push(cstr.into_raw());
+            }
 
-        let ptr_array = cstrings.into_boxed_slice();
-        Box::into_raw(ptr_array) as *mut *mut i8
-    }
+            let ptr_array = cstrings.into_boxed_slice();
+            Box::into_raw(ptr_array) as *mut *mut i8
+        }
+    })
 }
 
 /// Frees the array of C strings returned by [`yad_row_names`].
@@ -299,19 +524,50 @@ pub extern "C" fn yad_row_names(yad: *const YAD) -> *mut *mut i8 {
 /// - `names` must be a pointer returned by [`yad_row_names`].
 /// - `count` must be the number of elements in the array.
 #[unsafe(no_mangle)]
+#[cfg_attr(feature = "prefixed-symbols", unsafe(export_name = "yad_row_names_free"))]
 pub extern "C" fn yad_row_names_free(names: *mut *mut i8, count: usize) {
-    unsafe {
-        if names.is_null() {
-            return;
-        }
+    catch_panic("yad_row_names_free", || {
+        unsafe {
+            if names.is_null() {
+                return;
+            }
 
-        let names_slice = std::slice::from_raw_parts_mut(names, count);
+            let names_slice = std::slice::from_raw_parts_mut(names, count);
 
-        for &mut name_ptr in names_slice {
-            if !name_ptr.is_null() {
-                // Reclaim CString memory
-                let _ = CString::from_raw(name_ptr);
+            for &mut name_ptr in names_slice {
+                if !name_ptr.is_null() {
+                    // Reclaim CString memory
+                    let _ = CString::from_raw(name_ptr);
+                }
             }
         }
+    })
+}
+
+/// Copies `name` into a C string owned by the caller, to be freed with
+/// [`yad_string_free`]. A name holding a null byte cannot be represented:
+/// it is reported as a conversion error of `function` and null is returned.
+pub(crate) fn owned_cstring(function: &str, name: &str) -> *mut i8 {
+    match CString::new(name) {
+        Ok(cstr) => cstr.into_raw(),
+        Err(_) => {
+            set_last_error(YadErrorCode::Conversion, format!("{function}: the name contains a null byte"));
+            ptr::null_mut()
+        }
     }
 }
+
+/// Frees a C string returned by one of the `*_get_name_cstr` functions.
+///
+/// # Safety
+/// - `string` must be a pointer returned by this library and not freed before.
+/// - Passing a null pointer is safe and does nothing.
+#[unsafe(no_mangle)]
+#[cfg_attr(feature = "prefixed-symbols", unsafe(export_name = "yad_string_free"))]
+pub extern "C" fn yad_string_free(string: *mut i8) {
+    catch_panic("yad_string_free", || {
+        if !string.is_null() {
+            unsafe { drop(CString::from_raw(string)) }
+        }
+    })
+}
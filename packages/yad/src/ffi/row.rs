@@ -1,6 +1,9 @@
 use crate::{Row, Key};
+use yad_core::ffi::ByteBuffer;
+use yad_core::ffi::error::{catch_panic, set_error_message, set_last_error, set_null_pointer_error, YadErrorCode};
 use std::ffi::CStr;
 use std::ptr;
+use super::owned_cstring;
 
 /// # Row FFI (C ABI)
 ///
@@ -27,23 +30,35 @@ use std::ptr;
 /// # Returns
 /// - Pointer to a heap-allocated [`Row`], must be freed with `row_free`.
 #[unsafe(no_mangle)]
+#[cfg_attr(feature = "prefixed-symbols", unsafe(export_name = "yad_row_new"))]
 pub extern "C" fn row_new(name: *const i8, keys: *const *mut Key, keys_len: usize) -> *mut Row {
-    unsafe {
-        if name.is_null() { return ptr::null_mut(); }
-        let cstr = match CStr::from_ptr(name).to_str() { Ok(s) => s, Err(_) => return ptr::null_mut() };
+    catch_panic("row_new", || {
+        unsafe {
+            if name.is_null() {
+                set_null_pointer_error("row_new", "`name`");
+                return ptr::null_mut();
+            }
+            let cstr = match CStr::from_ptr(name).to_str() {
+                Ok(s) => s,
+                Err(_) => {
+                    set_last_error(YadErrorCode::InvalidUtf8, "row_new: `name` is not valid UTF-8");
+                    return ptr::null_mut();
+                }
+            };
 
-        let mut keys_vec = Vec::with_capacity(keys_len);
-        if !keys.is_null() {
-            for i in 0..keys_len {
-                let key_ptr = *keys.add(i);
-                if !key_ptr.is_null() {
-                    keys_vec.push((*key_ptr).clone());
+            let mut keys_vec = Vec::with_capacity(keys_len);
+            if !keys.is_null() {
+                for i in 0..keys_len {
+                    let key_ptr = *keys.add(i);
+                    if !key_ptr.is_null() {
+                        keys_vec.push((*key_ptr).clone());
+                    }
                 }
             }
-        }
 
-        Box::into_raw(Box::new(Row::new(cstr, keys_vec)))
-    }
+            Box::into_raw(Box::new(Row::new(cstr, keys_vec)))
+        }
+    })
 }
 
 /// Frees a [`Row`] previously allocated by `row_new`.
@@ -52,8 +67,11 @@ pub extern "C" fn row_new(name: *const i8, keys: *const *mut Key, keys_len: usiz
 /// - `row` must be a valid pointer returned by `row_new`.
 /// - Passing a null pointer is safe and does nothing.
 #[unsafe(no_mangle)]
+#[cfg_attr(feature = "prefixed-symbols", unsafe(export_name = "yad_row_free"))]
 pub extern "C" fn row_free(row: *mut Row) {
-    unsafe { if !row.is_null() { let _ = Box::from_raw(row); } }
+    catch_panic("row_free", || {
+        unsafe { if !row.is_null() { let _ = Box::from_raw(row); } }
+    })
 }
 
 /// Inserts a [`Key`] into the [`Row`].
@@ -64,13 +82,19 @@ pub extern "C" fn row_free(row: *mut Row) {
 /// - `row` must be a valid pointer to a [`Row`].
 /// - `key` must be a valid pointer to a [`Key`].
 #[unsafe(no_mangle)]
+#[cfg_attr(feature = "prefixed-symbols", unsafe(export_name = "yad_row_insert_key"))]
 pub extern "C" fn row_insert_key(row: *mut Row, key: *mut Key) {
-    unsafe {
-        if row.is_null() || key.is_null() { return; }
-        let row = &mut *row;
-        let key = &*key;
-        row.keys.insert(key.name.clone(), key.clone());
-    }
+    catch_panic("row_insert_key", || {
+        unsafe {
+            if row.is_null() || key.is_null() {
+                set_null_pointer_error("row_insert_key", "`row` or `key`");
+                return;
+            }
+            let row = &mut *row;
+            let key = &*key;
+            row.keys.insert(key.name.clone(), key.clone());
+        }
+    })
 }
 
 /// Removes a [`Key`] from the [`Row`] by name.
@@ -83,15 +107,30 @@ pub extern "C" fn row_insert_key(row: *mut Row, key: *mut Key) {
 /// - Pointer to the removed [`Key`], or null if not found.
 /// - Caller is responsible for freeing the returned key using `key_free`.
 #[unsafe(no_mangle)]
+#[cfg_attr(feature = "prefixed-symbols", unsafe(export_name = "yad_row_remove_key"))]
 pub extern "C" fn row_remove_key(row: *mut Row, name: *const i8) -> *mut Key {
-    unsafe {
-        if row.is_null() || name.is_null() { return ptr::null_mut(); }
-        let cstr = match CStr::from_ptr(name).to_str() { Ok(s) => s, Err(_) => return ptr::null_mut() };
-        match (*row).keys.remove(cstr) {
-            Some(key) => Box::into_raw(Box::new(key)),
-            None => ptr::null_mut(),
+    catch_panic("row_remove_key", || {
+        unsafe {
+            if row.is_null() || name.is_null() {
+                set_null_pointer_error("row_remove_key", "`row` or `name`");
+                return ptr::null_mut();
+            }
+            let cstr = match CStr::from_ptr(name).to_str() {
+                Ok(s) => s,
+                Err(_) => {
+                    set_last_error(YadErrorCode::InvalidUtf8, "row_remove_key: `name` is not valid UTF-8");
+                    return ptr::null_mut();
+                }
+            };
+            match (*row).keys.remove(cstr) {
+                Some(key) => Box::into_raw(Box::new(key)),
+                None => {
+                    set_last_error(YadErrorCode::NotFound, format!("row_remove_key: no key named `{cstr}`"));
+                    ptr::null_mut()
+                }
+            }
         }
-    }
+    })
 }
 
 /// Serializes a [`Row`] into an external byte buffer.
@@ -105,19 +144,62 @@ pub extern "C" fn row_remove_key(row: *mut Row, name: *const i8) -> *mut Key {
 /// # Returns
 /// - Number of bytes written to the buffer.
 #[unsafe(no_mangle)]
+#[cfg_attr(feature = "prefixed-symbols", unsafe(export_name = "yad_row_serialize"))]
 pub extern "C" fn row_serialize(row: *const Row, out_bytes: *mut u8, max_len: usize) -> usize {
-    unsafe {
-        if row.is_null() || out_bytes.is_null() { return 0; }
-        let row = &*row;
-        match row.serialize() {
-            Ok(vec) => {
-                let len = vec.len().min(max_len);
-                ptr::copy_nonoverlapping(vec.as_ptr(), out_bytes, len);
-                len
-            }
-            Err(_) => 0,
+    catch_panic("row_serialize", || {
+        unsafe {
+            if row.is_null() || out_bytes.is_null() {
+                set_null_pointer_error("row_serialize", "`row` or `out_bytes`");
+                return 0;
+            }
+            let row = &*row;
+            match row.serialize() {
+                Ok(vec) => {
+                    if vec.len() > max_len {
+                        set_last_error(YadErrorCode::BufferTooSmall, format!("row_serialize: {} bytes needed, `max_len` is {max_len}", vec.len()));
+                    }
+                    let len = vec.len().min(max_len);
+                    ptr::copy_nonoverlapping(vec.as_ptr(), out_bytes, len);
+                    len
+                }
+                Err(e) => {
+                    set_error_message("row_serialize", YadErrorCode::Encode, e);
+                    0
+                }
+            }
         }
-    }
+    })
+}
+
+/// Serializes a [`Row`] into a newly allocated [`ByteBuffer`].
+///
+/// Unlike `row_serialize`, the output is never truncated and its length is
+/// reported in the buffer itself.
+///
+/// # Safety
+/// - `row` must be a valid pointer to a [`Row`].
+///
+/// # Returns
+/// - A [`ByteBuffer`] with the serialized row; free it with `byte_buffer_free`.
+/// - An empty buffer with a null `ptr` on error (see `yad_last_error`).
+#[unsafe(no_mangle)]
+#[cfg_attr(feature = "prefixed-symbols", unsafe(export_name = "yad_row_as_buffer"))]
+pub extern "C" fn row_as_buffer(row: *const Row) -> ByteBuffer {
+    catch_panic("row_as_buffer", || {
+        unsafe {
+            if row.is_null() {
+                set_null_pointer_error("row_as_buffer", "`row`");
+                return ByteBuffer::null();
+            }
+            match (*row).serialize() {
+                Ok(vec) => ByteBuffer::from_vec(vec),
+                Err(e) => {
+                    set_error_message("row_as_buffer", YadErrorCode::Encode, e);
+                    ByteBuffer::null()
+                }
+            }
+        }
+    })
 }
 
 /// Deserializes a [`Row`] from a byte buffer.
@@ -133,15 +215,24 @@ pub extern "C" fn row_serialize(row: *const Row, out_bytes: *mut u8, max_len: us
 /// # Returns
 /// - Pointer to a newly allocated [`Row`], or null on error.
 #[unsafe(no_mangle)]
+#[cfg_attr(feature = "prefixed-symbols", unsafe(export_name = "yad_row_deserialize"))]
 pub extern "C" fn row_deserialize(bytes: *const u8, len: usize) -> *mut Row {
-    unsafe {
-        if bytes.is_null() || len == 0 { return ptr::null_mut(); }
-        let vec = std::slice::from_raw_parts(bytes, len).to_vec();
-        match Row::deserialize(vec) {
-            Ok(row) => Box::into_raw(Box::new(row)),
-            Err(_) => ptr::null_mut(),
+    catch_panic("row_deserialize", || {
+        unsafe {
+            if bytes.is_null() || len == 0 {
+                set_null_pointer_error("row_deserialize", "`bytes` (of non-zero `len`)");
+                return ptr::null_mut();
+            }
+            let vec = std::slice::from_raw_parts(bytes, len).to_vec();
+            match Row::deserialize(vec) {
+                Ok(row) => Box::into_raw(Box::new(row)),
+                Err(e) => {
+                    set_error_message("row_deserialize", YadErrorCode::Decode, e);
+                    ptr::null_mut()
+                }
+            }
         }
-    }
+    })
 }
 
 /// Returns the number of keys in the [`Row`].
@@ -149,9 +240,89 @@ pub extern "C" fn row_deserialize(bytes: *const u8, len: usize) -> *mut Row {
 /// # Safety
 /// - `row` must be a valid pointer to a [`Row`].
 #[unsafe(no_mangle)]
+#[cfg_attr(feature = "prefixed-symbols", unsafe(export_name = "yad_row_key_count"))]
 pub extern "C" fn row_key_count(row: *const Row) -> usize {
-    unsafe {
-        if row.is_null() { return 0; }
-        (*row).keys.len()
-    }
+    catch_panic("row_key_count", || {
+        unsafe {
+            if row.is_null() {
+                set_null_pointer_error("row_key_count", "`row`");
+                return 0;
+            }
+            (*row).keys.len()
+        }
+    })
+}
+
+/// Returns a copy of the [`Key`] at position `index` of the [`Row`].
+///
+/// Keys are ordered by name, the same order as `row_key_cursor_next`. Each call
+/// walks the row from the start; use a key cursor to enumerate every key.
+///
+/// # Safety
+/// - `row` must be a valid pointer to a [`Row`].
+///
+/// # Returns
+/// - Pointer to a cloned [`Key`], or null if `index` is out of bounds.
+/// - Caller must free the returned key using `key_free`.
+#[unsafe(no_mangle)]
+#[cfg_attr(feature = "prefixed-symbols", unsafe(export_name = "yad_row_key_at"))]
+pub extern "C" fn row_key_at(row: *const Row, index: usize) -> *mut Key {
+    catch_panic("row_key_at", || {
+        unsafe {
+            if row.is_null() {
+                set_null_pointer_error("row_key_at", "`row`");
+                return ptr::null_mut();
+            }
+            match (*row).keys.values().nth(index) {
+                Some(key) => Box::into_raw(Box::new(key.clone())),
+                None => {
+                    set_last_error(YadErrorCode::OutOfBounds, format!("row_key_at: index {index} is out of bounds"));
+                    ptr::null_mut()
+                }
+            }
+        }
+    })
+}
+
+/// Returns the length in bytes of the name of the [`Row`], without a terminator.
+///
+/// # Safety
+/// - `row` must be a valid pointer to a [`Row`].
+///
+/// # Returns
+/// - The length of the row's name, or `0` if `row` is null.
+#[unsafe(no_mangle)]
+#[cfg_attr(feature = "prefixed-symbols", unsafe(export_name = "yad_row_get_name_len"))]
+pub extern "C" fn row_get_name_len(row: *const Row) -> usize {
+    catch_panic("row_get_name_len", || {
+        unsafe {
+            if row.is_null() {
+                set_null_pointer_error("row_get_name_len", "`row`");
+                return 0;
+            }
+            (&*row).name.len()
+        }
+    })
+}
+
+/// Returns a copy of the name of the [`Row`] as a null-terminated C string.
+///
+/// # Safety
+/// - `row` must be a valid pointer to a [`Row`].
+///
+/// # Returns
+/// - A C string owned by the caller, who must free it with `yad_string_free`.
+/// - Null if `row` is null or the name contains a null byte.
+#[unsafe(no_mangle)]
+#[cfg_attr(feature = "prefixed-symbols", unsafe(export_name = "yad_row_get_name_cstr"))]
+pub extern "C" fn row_get_name_cstr(row: *const Row) -> *mut i8 {
+    catch_panic("row_get_name_cstr", || {
+        unsafe {
+            if row.is_null() {
+                set_null_pointer_error("row_get_name_cstr", "`row`");
+                return ptr::null_mut();
+            }
+            owned_cstring("row_get_name_cstr", &(*row).name)
+        }
+    })
 }
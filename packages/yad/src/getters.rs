@@ -0,0 +1,139 @@
+use std::fmt::{Display, Formatter};
+use float16::f16;
+use float8::F8E4M3;
+use yad_core::constants::error::ErrorMessage;
+use yad_core::Value;
+use crate::error::KEY_NOT_FOUND;
+use crate::row::Row;
+
+/// An error reading a key of a row with a typed getter, with the names of both.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct KeyError {
+    /// What went wrong: `KEY_NOT_FOUND`, or why the value did not convert.
+    pub error: ErrorMessage,
+    /// Name of the row.
+    pub row: String,
+    /// Name of the key.
+    pub key: String,
+}
+
+impl Display for KeyError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} (key `{}` in row `{}`)", self.error.0, self.key, self.row)
+    }
+}
+
+impl From<KeyError> for ErrorMessage {
+    fn from(error: KeyError) -> Self {
+        error.error
+    }
+}
+
+/// Typed getters, which look a key up and convert its value in one step.
+///
+/// Conversions are those of `TryInto`, so numbers must have the width asked
+/// for: `get_u8` reads `uint8` values only. Multi-value keys give their
+/// first value.
+///
+/// # Example
+/// ```
+/// use serde_yad::{row::Row, key::Key};
+/// use yad_core::Value;
+///
+/// let row = Row::new("johan", vec![Key::new("age", 17u8), Key::new("name", Value::try_from("Johan").unwrap())]);
+/// assert_eq!(row.get_u8("age"), Ok(17));
+/// assert_eq!(row.get_str("name"), Ok("Johan"));
+/// assert_eq!(row.get_opt_u64("score"), Ok(None));
+/// assert_eq!(row.get_u16("age").unwrap_err().to_string(),
+///     "You cannot convert something that is not u16 to u16. (key `age` in row `johan`)");
+/// ```
+impl Row {
+    /// Wraps `error` with the names of this row and the key `name`.
+    fn key_error(&self, name: &str, error: ErrorMessage) -> KeyError {
+        KeyError { error, row: self.name.clone(), key: name.to_string() }
+    }
+
+    /// Returns the value of the key `name` converted into a `T`.
+    ///
+    /// # Errors
+    /// Returns `KEY_NOT_FOUND` if there is no such key, or the error of the conversion.
+    pub fn get_as<T>(&self, name: &str) -> Result<T, KeyError>
+    where
+        Value: TryInto<T, Error = ErrorMessage>,
+    {
+        self.get_opt_as(name)?.ok_or_else(|| self.key_error(name, ErrorMessage(KEY_NOT_FOUND)))
+    }
+
+    /// Returns the value of the key `name` converted into a `T`, or `None`
+    /// if there is no such key.
+    ///
+    /// # Errors
+    /// Returns the error of the conversion.
+    pub fn get_opt_as<T>(&self, name: &str) -> Result<Option<T>, KeyError>
+    where
+        Value: TryInto<T, Error = ErrorMessage>,
+    {
+        match self.get(name) {
+            Some(value) => value.clone().try_into().map(Some).map_err(|error| self.key_error(name, error)),
+            None => Ok(None),
+        }
+    }
+
+    /// Returns the text of the string key `name` without copying it.
+    ///
+    /// # Errors
+    /// Returns `KEY_NOT_FOUND` if there is no such key, or `NOT_A_STRING` if
+    /// its value is not a string.
+    pub fn get_str(&self, name: &str) -> Result<&str, KeyError> {
+        self.get_opt_str(name)?.ok_or_else(|| self.key_error(name, ErrorMessage(KEY_NOT_FOUND)))
+    }
+
+    /// Returns the text of the string key `name` without copying it, or
+    /// `None` if there is no such key.
+    ///
+    /// # Errors
+    /// Returns `NOT_A_STRING` if its value is not a string.
+    pub fn get_opt_str(&self, name: &str) -> Result<Option<&str>, KeyError> {
+        match self.get(name) {
+            Some(value) => value.as_str().map(Some).map_err(|error| self.key_error(name, error)),
+            None => Ok(None),
+        }
+    }
+}
+
+/// Implements a getter and an optional getter for `$t` over [`Row::get_as`]
+/// and [`Row::get_opt_as`].
+macro_rules! impl_typed_getters {
+    ($($get:ident, $get_opt:ident, $t:ty);* $(;)?) => {
+        impl Row {$(
+            #[doc = concat!("Returns the `", stringify!($t), "` value of the key `name` (see [`Row::get_as`]).")]
+            pub fn $get(&self, name: &str) -> Result<$t, KeyError> {
+                self.get_as(name)
+            }
+
+            #[doc = concat!("Returns the `", stringify!($t), "` value of the key `name`, or `None` if there is no such key (see [`Row::get_opt_as`]).")]
+            pub fn $get_opt(&self, name: &str) -> Result<Option<$t>, KeyError> {
+                self.get_opt_as(name)
+            }
+        )*}
+    };
+}
+
+impl_typed_getters! {
+    get_u8, get_opt_u8, u8;
+    get_u16, get_opt_u16, u16;
+    get_u32, get_opt_u32, u32;
+    get_u64, get_opt_u64, u64;
+    get_i8, get_opt_i8, i8;
+    get_i16, get_opt_i16, i16;
+    get_i32, get_opt_i32, i32;
+    get_i64, get_opt_i64, i64;
+    get_f8, get_opt_f8, F8E4M3;
+    get_f16, get_opt_f16, f16;
+    get_f32, get_opt_f32, f32;
+    get_f64, get_opt_f64, f64;
+    get_bool, get_opt_bool, bool;
+    get_char, get_opt_char, char;
+    get_string, get_opt_string, String;
+    get_array, get_opt_array, Vec<Value>;
+}
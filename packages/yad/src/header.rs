@@ -0,0 +1,323 @@
+use std::borrow::Cow;
+use std::fmt::{Debug, Formatter};
+use yad_core::constants::error::ErrorMessage;
+use crate::checksum::CHECKSUM_SECTION_LENGTH;
+use crate::compression::inflate;
+use crate::constants::{BLOOM_HEADER, CHECKSUM_HEADER, ENCRYPTED_HEADER, FLAGS_HEADER, FLAGS_RESERVED_LENGTH, MAGIC, SCHEMA_HEADER, SIGNATURE_HEADER, SIGNATURE_LENGTH};
+use crate::error::{ENCRYPTED_DOCUMENT, MALFORMED_BLOOM_FILTER, MALFORMED_FILE, MALFORMED_FLAGS, MALFORMED_SCHEMA, MALFORMED_VERSION_HEADER};
+use yad_core::byte_order::ByteOrder;
+use yad_core::delta::transcode_from_delta;
+use yad_core::{Reader, Value};
+use crate::delta::body_from_delta;
+use crate::dictionary::{body_from_dictionary, copy_key_name, copy_value, read_dictionary, resolve_key_name, rewrite_row};
+use crate::varint::{body_from_varint, transcode_row};
+use crate::Version;
+
+/// Capabilities a YAD file declares in its header.
+///
+/// Flags describe the file; they are written as given and, except for
+/// [`HeaderFlags::LITTLE_ENDIAN`], [`HeaderFlags::VARINT_LENGTHS`],
+/// [`HeaderFlags::DICTIONARY`] and [`HeaderFlags::DELTA_ARRAYS`], never change
+/// how it is decoded, so tools can sniff a file's properties with
+/// [`Header::read`] without parsing its rows.
+/// Bits without a constant are reserved for future capabilities and are
+/// preserved as read.
+///
+/// # Binary Layout
+/// Written right after the version, only when requested through
+/// [`crate::options::SerializeOptions::header_flags`]:
+/// ```text
+/// +--------------+-------------+--------------------+
+/// | FLAGS_HEADER | Flags u16   | Reserved (2 bytes) |
+/// +--------------+-------------+--------------------+
+/// ```
+#[derive(Copy, Clone, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct HeaderFlags(pub u16);
+
+impl HeaderFlags {
+    /// The body is compressed.
+    pub const COMPRESSED: Self = Self(0x0001);
+    /// The body is encrypted.
+    pub const ENCRYPTED: Self = Self(0x0002);
+    /// The file carries index data.
+    pub const INDEXED: Self = Self(0x0004);
+    /// The rows were written by [`crate::YAD::serialize_canonical`].
+    pub const CANONICAL: Self = Self(0x0008);
+    /// The file carries a checksum.
+    pub const CHECKSUMMED: Self = Self(0x0010);
+    /// Number payloads are little-endian (see [`ByteOrder`]). Set from
+    /// [`crate::options::SerializeOptions::byte_order`] and honoured by every reader.
+    pub const LITTLE_ENDIAN: Self = Self(0x0020);
+    /// Length descriptors are varints (see [`crate::options::LengthEncoding`]).
+    /// Set from [`crate::options::SerializeOptions::length_encoding`] and
+    /// honoured by every reader that copies the input.
+    pub const VARINT_LENGTHS: Self = Self(0x0040);
+    /// Repeated key names are written once in a dictionary section and
+    /// referenced by index. Set from [`crate::options::SerializeOptions::dictionary`]
+    /// and honoured by every reader that copies the input.
+    pub const DICTIONARY: Self = Self(0x0080);
+    /// Some arrays of unsigned integers are written as delta arrays (see
+    /// [`yad_core::delta::DELTA_ARRAY_HEADER`]). Set from
+    /// [`crate::options::SerializeOptions::delta_arrays`] and honoured by
+    /// every reader that copies the input.
+    pub const DELTA_ARRAYS: Self = Self(0x0100);
+
+    /// Returns the flags with no bit set.
+    pub fn empty() -> Self {
+        Self(0)
+    }
+
+    /// Returns the raw bitfield.
+    pub fn bits(&self) -> u16 {
+        self.0
+    }
+
+    /// Returns `true` if no bit is set.
+    pub fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    /// Returns `true` if every bit of `other` is set.
+    pub fn contains(&self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    /// Sets the bits of `other`.
+    pub fn insert(&mut self, other: Self) {
+        self.0 |= other.0;
+    }
+
+    /// Clears the bits of `other`.
+    pub fn remove(&mut self, other: Self) {
+        self.0 &= !other.0;
+    }
+
+    /// Returns the byte order of number payloads the flags declare.
+    pub fn byte_order(&self) -> ByteOrder {
+        if self.contains(Self::LITTLE_ENDIAN) { ByteOrder::Little } else { ByteOrder::Big }
+    }
+
+    /// Returns these flags with the bits of `other` set.
+    pub fn with(mut self, other: Self) -> Self {
+        self.insert(other);
+        self
+    }
+
+    /// Serializes the flags section.
+    pub fn serialize(&self) -> Vec<u8> {
+        let mut bytes = vec![FLAGS_HEADER];
+        bytes.extend_from_slice(&self.0.to_be_bytes());
+        bytes.extend_from_slice(&[0; FLAGS_RESERVED_LENGTH]);
+        bytes
+    }
+
+    /// Reads the flags section at the start of `bytes`, if there is one.
+    ///
+    /// # Returns
+    /// - `Ok((HeaderFlags, usize))`: The flags (empty when `bytes` does not
+    ///   start with [`FLAGS_HEADER`]) and the number of bytes consumed.
+    ///
+    /// # Errors
+    /// Returns `MALFORMED_FLAGS` if the section is truncated.
+    pub fn decode(bytes: &[u8]) -> Result<(Self, usize), ErrorMessage> {
+        if bytes.first() != Some(&FLAGS_HEADER) {
+            return Ok((Self::empty(), 0));
+        }
+
+        let flags: [u8; 2] = bytes.get(1..3)
+            .and_then(|s| s.try_into().ok())
+            .ok_or(ErrorMessage(MALFORMED_FLAGS))?;
+        let end = 3 + FLAGS_RESERVED_LENGTH;
+
+        if bytes.len() < end {
+            return Err(ErrorMessage(MALFORMED_FLAGS));
+        }
+
+        Ok((Self(u16::from_be_bytes(flags)), end))
+    }
+}
+
+impl Debug for HeaderFlags {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let names = [
+            (Self::COMPRESSED, "COMPRESSED"),
+            (Self::ENCRYPTED, "ENCRYPTED"),
+            (Self::INDEXED, "INDEXED"),
+            (Self::CANONICAL, "CANONICAL"),
+            (Self::CHECKSUMMED, "CHECKSUMMED"),
+            (Self::LITTLE_ENDIAN, "LITTLE_ENDIAN"),
+            (Self::VARINT_LENGTHS, "VARINT_LENGTHS"),
+            (Self::DICTIONARY, "DICTIONARY"),
+            (Self::DELTA_ARRAYS, "DELTA_ARRAYS"),
+        ];
+
+        let set: Vec<&str> = names.iter()
+            .filter(|(flag, _)| self.contains(*flag))
+            .map(|(_, name)| *name)
+            .collect();
+
+        write!(f, "HeaderFlags({:#06x}: {})", self.0, set.join(" | "))
+    }
+}
+
+/// Returns `true` if `bytes` starts with the YAD magic number ([`MAGIC`]).
+///
+/// Files written before the magic number was introduced start directly with
+/// the version header and are still accepted by every reader.
+pub fn has_magic(bytes: &[u8]) -> bool {
+    bytes.starts_with(&MAGIC)
+}
+
+/// Returns the position right after the version header: 5, or 9 when the
+/// file starts with the magic number.
+pub(crate) fn version_end(bytes: &[u8]) -> usize {
+    if has_magic(bytes) { MAGIC.len() + 5 } else { 5 }
+}
+
+/// The self-describing part of a YAD file: its version and header flags.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Header {
+    /// The format version.
+    pub version: Version,
+    /// The declared capabilities; empty when the file has no flags section.
+    pub flags: HeaderFlags,
+}
+
+impl Header {
+    /// Reads the header at the start of a serialized document without
+    /// touching the rest of it. The magic number is optional, so files written
+    /// before it was introduced are read too.
+    ///
+    /// # Returns
+    /// - `Ok((Header, usize))`: The header and the number of bytes it spans.
+    ///
+    /// # Errors
+    /// Returns an error if the version header or the flags section is malformed.
+    pub fn read(bytes: &[u8]) -> Result<(Self, usize), ErrorMessage> {
+        let end = version_end(bytes);
+        let version = bytes.get(end - 5..end).ok_or(ErrorMessage(MALFORMED_VERSION_HEADER))?;
+        let version = Version::deserialize(version.to_vec())?;
+        let (flags, consumed) = HeaderFlags::decode(&bytes[end..])?;
+
+        Ok((Self { version, flags }, end + consumed))
+    }
+}
+
+/// Reads the header and returns it with the position of the first row,
+/// skipping the signature, schema, bloom filter and checksum sections without checking them.
+///
+/// # Errors
+/// Returns `ENCRYPTED_DOCUMENT` for encrypted bodies, `MALFORMED_FILE` if a
+/// section runs past the end of `bytes`, or an error if the header or the
+/// schema section is malformed.
+pub(crate) fn body_start(bytes: &[u8]) -> Result<(Header, usize), ErrorMessage> {
+    let (header, mut pos) = Header::read(bytes)?;
+
+    if bytes.get(pos) == Some(&ENCRYPTED_HEADER) {
+        return Err(ErrorMessage(ENCRYPTED_DOCUMENT));
+    }
+
+    if bytes.get(pos) == Some(&SIGNATURE_HEADER) {
+        pos += 1 + SIGNATURE_LENGTH;
+    }
+
+    if bytes.get(pos) == Some(&SCHEMA_HEADER) {
+        let section = Value::encoded_len(&bytes[pos + 1..]).map_err(|_| ErrorMessage(MALFORMED_SCHEMA))?;
+        pos += 1 + section;
+    }
+
+    if bytes.get(pos) == Some(&BLOOM_HEADER) {
+        let section = Value::encoded_len(&bytes[pos + 1..]).map_err(|_| ErrorMessage(MALFORMED_BLOOM_FILTER))?;
+        pos += 1 + section;
+    }
+
+    if bytes.get(pos) == Some(&CHECKSUM_HEADER) {
+        pos += CHECKSUM_SECTION_LENGTH;
+    }
+
+    if pos > bytes.len() {
+        return Err(ErrorMessage(MALFORMED_FILE));
+    }
+
+    Ok((header, pos))
+}
+
+/// Undoes the body encodings the flags declare, compression, a dictionary
+/// section, varint length descriptors and delta arrays, so the rows can be walked with fixed
+/// descriptors, plain arrays and literal key names.
+///
+/// # Arguments
+/// - `body`: The bytes after the header sections, as returned by [`body_start`].
+/// - `max_size`: The largest size a compressed body may inflate to, or
+///   `ALLOCATION_LIMIT_EXCEEDED` is returned; `None` sets no limit.
+///
+/// # Returns
+/// - `Ok(Cow<[u8]>)`: `body` itself when no such flag is set, or the rewritten rows.
+pub(crate) fn decode_body(body: &[u8], flags: HeaderFlags, max_size: Option<usize>) -> Result<Cow<'_, [u8]>, ErrorMessage> {
+    if flags.contains(HeaderFlags::COMPRESSED) {
+        let mut flags = flags;
+        flags.remove(HeaderFlags::COMPRESSED);
+        let inflated = inflate(body, max_size)?;
+        return Ok(Cow::Owned(decode_body(&inflated, flags, max_size)?.into_owned()));
+    }
+
+    let (names, rows) = if flags.contains(HeaderFlags::DICTIONARY) {
+        let (names, rows) = read_dictionary(body)?;
+        (Some(names), rows)
+    } else {
+        (None, body)
+    };
+
+    let rows = if flags.contains(HeaderFlags::VARINT_LENGTHS) {
+        Cow::Owned(body_from_varint(rows)?)
+    } else {
+        Cow::Borrowed(rows)
+    };
+
+    let rows = if flags.contains(HeaderFlags::DELTA_ARRAYS) {
+        Cow::Owned(body_from_delta(&rows, flags.byte_order())?)
+    } else {
+        rows
+    };
+
+    match names {
+        Some(names) => Ok(Cow::Owned(body_from_dictionary(&rows, &names)?)),
+        None => Ok(rows),
+    }
+}
+
+/// Reads the row at the reader's position and undoes the body encodings the
+/// flags declare, like [`decode_body`] does for a whole body. `names` is the
+/// dictionary section of the document, read with [`read_dictionary`].
+///
+/// # Returns
+/// - `Ok(Vec<u8>)`: The row with fixed descriptors, plain arrays and literal key names.
+pub(crate) fn decode_row(reader: &mut Reader, flags: HeaderFlags, names: &[String]) -> Result<Vec<u8>, ErrorMessage> {
+    let mut row = vec![];
+    let order = flags.byte_order();
+    let delta = flags.contains(HeaderFlags::DELTA_ARRAYS);
+
+    if flags.contains(HeaderFlags::VARINT_LENGTHS) {
+        transcode_row(reader, &mut row, false)?;
+
+        if delta {
+            let mut plain = vec![];
+            rewrite_row(&mut Reader::new(&row), &mut plain, copy_key_name, |reader, out| transcode_from_delta(reader, out, order))?;
+            row = plain;
+        }
+    } else if delta {
+        // Fixed descriptors: the delta arrays are undone while reading the row,
+        // as `copy_value` cannot read past them.
+        rewrite_row(reader, &mut row, copy_key_name, |reader, out| transcode_from_delta(reader, out, order))?;
+    } else {
+        rewrite_row(reader, &mut row, copy_key_name, copy_value)?;
+    }
+
+    if flags.contains(HeaderFlags::DICTIONARY) {
+        let mut resolved = vec![];
+        rewrite_row(&mut Reader::new(&row), &mut resolved, |reader, out| resolve_key_name(reader, out, names), copy_value)?;
+        row = resolved;
+    }
+
+    Ok(row)
+}
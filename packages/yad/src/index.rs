@@ -0,0 +1,152 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::ops::{Bound, RangeBounds};
+use yad_core::Value;
+use crate::query::Predicate;
+use crate::row::Row;
+use crate::YAD;
+
+/// Index entries are keyed by [`SemanticValue`], so lookups ignore encoding width.
+pub use yad_core::compare::SemanticValue as IndexKey;
+
+/// A secondary index over one key name: value → names of the rows holding it.
+///
+/// Built with [`YAD::build_index`] and kept up to date by the document's
+/// mutating methods (see [`YAD::build_index`]).
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Index {
+    /// Indexed value → names of the rows whose key holds it.
+    pub entries: BTreeMap<IndexKey, BTreeSet<String>>,
+}
+
+impl Index {
+    /// Adds the value of `row`'s key `key`, if present.
+    fn insert(&mut self, key: &str, row: &Row) {
+        if let Some(k) = row.keys.get(key) {
+            self.entries.entry(IndexKey(k.value.clone())).or_default().insert(row.name.clone());
+        }
+    }
+
+    /// Removes the value of `row`'s key `key`, if present.
+    fn remove(&mut self, key: &str, row: &Row) {
+        let Some(k) = row.keys.get(key) else { return };
+        let value = IndexKey(k.value.clone());
+
+        if let Some(names) = self.entries.get_mut(&value) {
+            names.remove(&row.name);
+            if names.is_empty() {
+                self.entries.remove(&value);
+            }
+        }
+    }
+
+    /// Returns the names of the rows whose value falls in `range`, in value order.
+    pub fn range(&self, range: (Bound<IndexKey>, Bound<IndexKey>)) -> impl Iterator<Item = &String> {
+        let empty = match (&range.0, &range.1) {
+            (Bound::Included(s), Bound::Included(e)) => s > e,
+            (Bound::Included(s) | Bound::Excluded(s), Bound::Included(e) | Bound::Excluded(e)) => s >= e,
+            _ => false,
+        };
+
+        // `BTreeMap::range` panics on inverted ranges.
+        let entries = if empty { None } else { Some(self.entries.range(range)) };
+        entries.into_iter().flatten().flat_map(|(_, names)| names.iter())
+    }
+}
+
+/// Converts a borrowed range bound into an owned [`IndexKey`] bound.
+fn to_key_bound<V: Clone + Into<Value>>(bound: Bound<&V>) -> Bound<IndexKey> {
+    match bound {
+        Bound::Included(v) => Bound::Included(IndexKey(v.clone().into())),
+        Bound::Excluded(v) => Bound::Excluded(IndexKey(v.clone().into())),
+        Bound::Unbounded => Bound::Unbounded,
+    }
+}
+
+impl YAD {
+    /// Builds (or rebuilds) a secondary index on the key named `key`.
+    ///
+    /// Rows without that key are not indexed. Values are ordered with
+    /// [`Value::semantic_cmp`], so `5u8` and `5u32` are the same entry.
+    ///
+    /// The index is maintained on [`YAD::insert_row`], [`YAD::remove_row`],
+    /// [`YAD::merge`], [`YAD::apply_patch`], [`YAD::transform`] and
+    /// [`YAD::transaction`]. Changes made directly through `rows` or
+    /// [`YAD::get_rows_mut`] are not seen; call [`YAD::rebuild_indexes`] afterwards.
+    pub fn build_index(&mut self, key: &str) {
+        let mut index = Index::default();
+        for row in self.rows.values() {
+            index.insert(key, row);
+        }
+        self.indexes.insert(key.to_string(), index);
+    }
+
+    /// Drops the index on `key`, returning `true` if it existed.
+    pub fn drop_index(&mut self, key: &str) -> bool {
+        self.indexes.remove(key).is_some()
+    }
+
+    /// Returns the index on `key`, if one was built.
+    pub fn get_index(&self, key: &str) -> Option<&Index> {
+        self.indexes.get(key)
+    }
+
+    /// Rebuilds every index from the current rows.
+    pub fn rebuild_indexes(&mut self) {
+        let keys: Vec<String> = self.indexes.keys().cloned().collect();
+        for key in keys {
+            self.build_index(&key);
+        }
+    }
+
+    /// Returns the rows whose value at `key` falls in `range`.
+    ///
+    /// With an index on `key` the rows are returned in value order; otherwise
+    /// every row is scanned and they are returned in name order. Values of a
+    /// different kind than the bounds (e.g. strings when querying numbers) never
+    /// match; an unbounded range (`..`) matches every row holding `key`.
+    ///
+    /// # Example
+    /// ```text
+    /// yad.build_index("age");
+    /// let rows = yad.rows_where("age", 18u8..30);
+    /// ```
+    pub fn rows_where<V, R>(&self, key: &str, range: R) -> Vec<&Row>
+    where
+        V: Clone + Into<Value>,
+        R: RangeBounds<V>,
+    {
+        let bounds = (to_key_bound(range.start_bound()), to_key_bound(range.end_bound()));
+        let kind = match (&bounds.0, &bounds.1) {
+            (Bound::Included(b) | Bound::Excluded(b), _) | (_, Bound::Included(b) | Bound::Excluded(b)) => Some(b.0.clone()),
+            _ => None,
+        };
+        let same_kind = |value: &Value| kind.as_ref().is_none_or(|k| Predicate::same_kind(value, k));
+
+        if let Some(index) = self.indexes.get(key) {
+            return index.range(bounds)
+                .filter_map(|name| self.rows.get(name))
+                .filter(|row| row.keys.get(key).is_some_and(|k| same_kind(&k.value)))
+                .collect();
+        }
+
+        self.rows.values()
+            .filter(|row| row.keys.get(key).is_some_and(|k| {
+                same_kind(&k.value) && bounds.contains(&IndexKey(k.value.clone()))
+            }))
+            .collect()
+    }
+
+    /// Adds `row` to every index.
+    pub(crate) fn index_row(&mut self, row: &Row) {
+        for (key, index) in &mut self.indexes {
+            index.insert(key, row);
+        }
+    }
+
+    /// Removes `row` from every index.
+    pub(crate) fn unindex_row(&mut self, row: &Row) {
+        for (key, index) in &mut self.indexes {
+            index.remove(key, row);
+        }
+    }
+}
@@ -1,11 +1,12 @@
 use std::fmt::{Debug, Display, Formatter};
 use yad_core::constants::error::ErrorMessage;
-use yad_core::constants::length::ByteLength;
 use yad_core::constants::types::Type;
-use yad_core::Value;
+use yad_core::{Reader, Value};
 use crate::constants::{KEY_END_HEADER, KEY_NAME_HEADER, KEY_START_HEADER};
-use crate::{encode_name, usize_from_slice_bytes};
+use crate::borrowed::read_values;
+use crate::{decode_name_ref, encode_name_into, encoded_name_len};
 use crate::error::{MALFORMED_KEY_NAME_VECTOR, MALFORMED_KEY_VECTOR};
+use crate::meta::Meta;
 
 /// Represents a **key-value pair** inside a row structure.
 ///
@@ -16,22 +17,35 @@ use crate::error::{MALFORMED_KEY_NAME_VECTOR, MALFORMED_KEY_VECTOR};
 /// Keys can be serialized and deserialized into/from a custom
 /// **binary format** defined by the YAD protocol.
 ///
+/// A key holds one value, and may hold a short ordered list of further
+/// values pushed with [`Key::push_value`]; [`Key::values`] walks all of them.
+///
 /// # Binary Layout
 /// ```text
-/// +---------------+----------------------+------------------+---------------+
-/// | Start Header  | Encoded Key Name     | Encoded Value    | End Header    |
-/// +---------------+----------------------+------------------+---------------+
+/// +---------------+----------------------+-----------------+------------------+---------------------+---------------+
+/// | Start Header  | Encoded Key Name     | [Metadata]      | Encoded Value    | [Further Values...] | End Header    |
+/// +---------------+----------------------+-----------------+------------------+---------------------+---------------+
 /// ```
+/// Further values follow the first one back to back. Single-valued keys are
+/// encoded exactly as before multi-value keys existed.
 ///
 /// # Fields
 /// - `name`: Unique identifier of the key within its parent row.
-/// - `value`: Data associated with the key.
-#[derive(Clone, Ord, PartialOrd, Eq, PartialEq)]
+/// - `value`: Data associated with the key (its first value).
+/// - `meta`: Optional metadata, written only when not empty (see [`Meta`]).
+#[derive(Clone, Hash, Ord, PartialOrd, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Key {
     /// The unique name of the key within its parent row.
     pub name: String,
-    /// The value associated with this key.
+    /// The value associated with this key; the first one for multi-value keys.
     pub value: Value,
+    /// Optional metadata (timestamps, tags, ...).
+    #[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "Meta::is_empty"))]
+    pub meta: Meta,
+    /// Values pushed after `value`, in order.
+    #[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "Vec::is_empty"))]
+    more: Vec<Value>,
 }
 
 impl Key {
@@ -46,13 +60,68 @@ impl Key {
     ///
     /// # Returns
     /// A new [`Key`] instance with the provided name and value.
-    pub fn new<S: ToString>(name: S, value: Value) -> Self {
+    pub fn new<S: ToString, V: Into<Value>>(name: S, value: V) -> Self {
         Self {
             name: name.to_string(),
-            value,
+            value: value.into(),
+            meta: Meta::default(),
+            more: Vec::new(),
         }
     }
 
+    /// Appends a value after [`Key::value`] and any values pushed before it.
+    pub fn push_value<V: Into<Value>>(&mut self, value: V) {
+        self.more.push(value.into());
+    }
+
+    /// Iterates over every value of the key in order, starting with [`Key::value`].
+    pub fn values(&self) -> impl Iterator<Item = &Value> {
+        std::iter::once(&self.value).chain(&self.more)
+    }
+
+    /// Replaces every value of the key with `f` applied to it, stopping at the first error.
+    pub(crate) fn try_map_values<F>(&mut self, mut f: F) -> Result<(), ErrorMessage>
+    where
+        F: FnMut(Value) -> Result<Value, ErrorMessage>,
+    {
+        self.value = f(self.value.clone())?;
+        for value in &mut self.more {
+            *value = f(value.clone())?;
+        }
+        Ok(())
+    }
+
+    /// Returns `true` if values were pushed after [`Key::value`].
+    pub fn is_multi_valued(&self) -> bool {
+        !self.more.is_empty()
+    }
+
+    /// Creates a new [`Key`] from any value convertible to a [`Value`],
+    /// including fallible conversions such as `&str`, `String` and `Vec<Value>`.
+    ///
+    /// # Errors
+    /// Returns the conversion error, e.g. `STRING_OF_LENGTH_ZERO` for `""`.
+    pub fn try_new<S: ToString, V>(name: S, value: V) -> Result<Self, ErrorMessage>
+    where
+        V: TryInto<Value>,
+        V::Error: Into<ErrorMessage>,
+    {
+        Ok(Self::new(name, value.try_into().map_err(Into::into)?))
+    }
+
+    /// Returns the key's metadata.
+    pub fn meta(&self) -> &Meta {
+        &self.meta
+    }
+
+    /// Sets the raw metadata value of `tag` (see [`Meta::set`]).
+    ///
+    /// # Errors
+    /// Returns `META_VALUE_TOO_LONG` if `value` is longer than 255 bytes.
+    pub fn set_meta<V: Into<Vec<u8>>>(&mut self, tag: u8, value: V) -> Result<(), ErrorMessage> {
+        self.meta.set(tag, value)
+    }
+
     /// Updates the value stored in the key.
     ///
     /// # Arguments
@@ -61,6 +130,29 @@ impl Key {
         self.value = new_value;
     }
 
+    /// Replaces the value of the key with what `update` makes of it, leaving
+    /// it unchanged when `update` fails.
+    ///
+    /// # Example
+    /// ```
+    /// use serde_yad::key::Key;
+    /// use serde_yad::Value;
+    ///
+    /// let mut visits = Key::new("visits", 41u8);
+    /// visits.update_with(|value| value.checked_add(&Value::from(1u8))).unwrap();
+    /// assert_eq!(visits.value, Value::from(42u8));
+    /// ```
+    ///
+    /// # Errors
+    /// Returns the error of `update`.
+    pub fn update_with<F>(&mut self, update: F) -> Result<(), ErrorMessage>
+    where
+        F: FnOnce(&Value) -> Result<Value, ErrorMessage>,
+    {
+        self.value = update(&self.value)?;
+        Ok(())
+    }
+
     /// Checks if a byte matches the **key start header**.
     ///
     /// # Arguments
@@ -83,17 +175,6 @@ impl Key {
         KEY_END_HEADER == byte
     }
 
-    /// Checks if a byte matches the **key name header** pattern.
-    ///
-    /// # Arguments
-    /// - `byte`: The byte to check.
-    ///
-    /// # Returns
-    /// `true` if the byte matches the name header mask, otherwise `false`.
-    fn byte_is_key_name_header(byte: u8) -> bool {
-        KEY_NAME_HEADER == (byte & 0xF0)
-    }
-
     /// Validates that the first and last bytes of a byte vector
     /// correctly match the **key boundary headers**.
     ///
@@ -114,61 +195,49 @@ impl Key {
         Self::byte_is_key_start_header(*first) && Self::byte_is_key_end_header(*last)
     }
 
-    /// Extracts and decodes a key's name from its binary representation.
-    ///
-    /// # Arguments
-    /// - `bytes`: Byte vector containing the encoded key name.
-    ///
-    /// # Returns
-    /// - `Some(String)`: Successfully decoded UTF-8 key name.
-    /// - `None`: If validation or decoding fails.
-    fn find_and_decode_name_from_bytes(bytes: Vec<u8>) -> Option<String> {
-        if bytes.is_empty() {
-            return None;
-        }
-
-        let first = *bytes.get(0)?;
-
-        if !Self::byte_is_key_name_header(first) {
-            return None;
-        }
-
-        let byte_length = ByteLength::try_from(first).ok()?;
-        let be_length = usize_from_slice_bytes(&bytes[1..], byte_length)?;
-
-        let metadata_length = 1 + byte_length.as_byte_count() as usize;
-
-        if bytes.len() < metadata_length + be_length {
-            return None;
-        }
-
-        let string_bytes = &bytes[metadata_length..metadata_length + be_length];
-
-        // Attempt UTF-8 decoding
-        String::from_utf8(string_bytes.to_vec()).ok()
-    }
-
     /// Serializes the [`Key`] into its custom binary representation.
     ///
     /// The layout includes:
     /// - Start header
-    /// - Encoded name (with metadata)
-    /// - Encoded value
+    /// - Encoded name (with its length descriptor)
+    /// - Metadata block, if any
+    /// - Encoded value, then any further values
     /// - End header
     ///
     /// # Returns
     /// - `Ok(Vec<u8>)`: Binary representation of the key.
     /// - `Err(ErrorMessage)`: If name encoding or value serialization fails.
     pub fn serialize(&self) -> Result<Vec<u8>, ErrorMessage> {
-        let mut bytes: Vec<u8> = vec![KEY_START_HEADER];
+        let mut bytes: Vec<u8> = Vec::with_capacity(self.encoded_len());
 
-        bytes.extend_from_slice(encode_name(&self.name, KEY_NAME_HEADER)?.as_slice());
-        bytes.extend_from_slice(self.value.bytes.as_slice());
-        bytes.push(KEY_END_HEADER);
+        self.write_into(&mut bytes)?;
 
         Ok(bytes)
     }
 
+    /// Returns the number of bytes [`Key::serialize`] writes.
+    pub fn encoded_len(&self) -> usize {
+        2 + encoded_name_len(&self.name)
+            + self.meta.encoded_len()
+            + self.values().map(|value| value.bytes.len()).sum::<usize>()
+    }
+
+    /// Appends the serialized key to `bytes` (see [`Key::serialize`]).
+    ///
+    /// # Errors
+    /// Returns an error if the name cannot be encoded; `bytes` may then hold a partial key.
+    pub fn write_into(&self, bytes: &mut Vec<u8>) -> Result<(), ErrorMessage> {
+        bytes.push(KEY_START_HEADER);
+        encode_name_into(&self.name, KEY_NAME_HEADER, bytes)?;
+        self.meta.write_into(bytes);
+        for value in self.values() {
+            bytes.extend_from_slice(value.bytes.as_slice());
+        }
+        bytes.push(KEY_END_HEADER);
+
+        Ok(())
+    }
+
     /// Deserializes a [`Key`] from its custom binary representation.
     ///
     /// # Arguments
@@ -183,22 +252,59 @@ impl Key {
             return Err(ErrorMessage(MALFORMED_KEY_VECTOR));
         }
 
-        // Decode key name
-        let name = Self::find_and_decode_name_from_bytes(bytes[1..].to_vec())
-            .ok_or_else(|| ErrorMessage(MALFORMED_KEY_NAME_VECTOR))?;
+        let mut reader = Reader::new(&bytes);
+        let key = Self::read(&mut reader, |_| true)?.ok_or(ErrorMessage(MALFORMED_KEY_VECTOR))?;
 
-        // Calculate name metadata length
-        let name_metadata_length = 1 + ByteLength::One.as_byte_count() as usize + name.len();
+        if !reader.is_empty() {
+            return Err(ErrorMessage(MALFORMED_KEY_VECTOR));
+        }
 
-        // Extract value bytes
-        if bytes.len() < name_metadata_length + 2 {
+        Ok(key)
+    }
+
+    /// Reads the key at the reader's position (on its start header), using the
+    /// length descriptors of its name, metadata and value rather than scanning
+    /// for the end header, so values may contain any byte.
+    ///
+    /// Keys whose name `select` rejects are skipped by the length descriptors
+    /// of their values, which are never decoded, and read as `None`. On
+    /// success the reader is left after the key's end header.
+    ///
+    /// # Errors
+    /// Returns an error if the key is malformed or truncated.
+    pub(crate) fn read(reader: &mut Reader, select: impl FnOnce(&str) -> bool) -> Result<Option<Self>, ErrorMessage> {
+        if reader.peek() != Some(KEY_START_HEADER) {
             return Err(ErrorMessage(MALFORMED_KEY_VECTOR));
         }
+        reader.advance(1)?;
 
-        let value_bytes = &bytes[name_metadata_length + 1..bytes.len() - 1];
-        let value = Value::decode(value_bytes.to_vec())?;
+        let (name, consumed) = decode_name_ref(reader.remaining(), KEY_NAME_HEADER)
+            .ok_or(ErrorMessage(MALFORMED_KEY_NAME_VECTOR))?;
+        reader.advance(consumed)?;
 
-        Ok(Key { name, value })
+        let (meta, consumed) = Meta::decode(reader.remaining())?;
+        reader.advance(consumed)?;
+
+        if !select(name) {
+            read_values(reader, KEY_END_HEADER)?;
+            reader.advance(1)?;
+            return Ok(None);
+        }
+        let name = name.to_string();
+
+        let value = reader.read_value()?;
+        let mut more = Vec::new();
+
+        loop {
+            match reader.peek() {
+                Some(KEY_END_HEADER) => break,
+                Some(_) => more.push(reader.read_value()?),
+                None => return Err(ErrorMessage(MALFORMED_KEY_VECTOR)),
+            }
+        }
+        reader.advance(1)?;
+
+        Ok(Some(Key { name, value, meta, more }))
     }
 }
 
@@ -210,7 +316,8 @@ impl Display for Key {
     /// myKey = 42
     /// ```
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{} = {}", self.name, self.value)
+        let values: Vec<String> = self.values().map(|value| value.to_string()).collect();
+        write!(f, "{} = {}", self.name, values.join(", "))
     }
 }
 
@@ -219,12 +326,15 @@ impl Debug for Key {
     /// including its type and bit-length when applicable.
     ///
     /// # Formatting Rules
-    /// - `String` / `Array`: Displays the plain value.
+    /// - `String` / `Array` / `Extension`: Displays the plain value.
+    /// - Decimals: Displays `<value>d`; big integers `<value>n`.
     /// - `Bool` / `True` / `False`: Displays the boolean value.
     /// - `Float`: Displays `<value>f<bit-length>`.
     /// - `Uint`: Displays `<value>u<bit-length>`.
     /// - `Int`: Displays `<value>i<bit-length>`.
     ///
+    /// Multi-value keys list their values separated by `, `.
+    ///
     /// Example:
     /// ```text
     /// myKey = 123u32
@@ -232,13 +342,17 @@ impl Debug for Key {
     /// flag = true
     /// ```
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        let value_debug_format = match self.value.r#type {
-            Type::String | Type::Array => format!("{}", self.value),
-            Type::Bool | Type::True | Type::False => format!("{}", self.value),
-            Type::Float => format!("{}f{}", self.value, self.value.length.as_byte_count() * 8),
-            Type::Uint => format!("{}u{}", self.value, self.value.length.as_byte_count() * 8),
-            Type::Int => format!("{}i{}", self.value, self.value.length.as_byte_count() * 8),
-        };
-        write!(f, "{} = {}", self.name, value_debug_format)
+        let values: Vec<String> = self.values()
+            .map(|value| match value.r#type {
+                Type::Extension if value.is_decimal() => format!("{}d", value),
+                Type::Extension if value.is_big_integer() => format!("{}n", value),
+                Type::String | Type::Array | Type::Extension => format!("{}", value),
+                Type::Bool | Type::True | Type::False => format!("{}", value),
+                Type::Float => format!("{}f{}", value, value.length.as_byte_count() * 8),
+                Type::Uint => format!("{}u{}", value, value.length.as_byte_count() * 8),
+                Type::Int => format!("{}i{}", value, value.length.as_byte_count() * 8),
+            })
+            .collect();
+        write!(f, "{} = {}", self.name, values.join(", "))
     }
 }
@@ -2,24 +2,94 @@ pub mod constants;
 pub mod error;
 pub mod key;
 pub mod row;
+pub mod meta;
+pub mod header;
+pub mod pretty;
+pub mod text;
+pub mod diff;
+pub mod patch;
+pub mod schema;
+pub mod options;
+pub mod verbose;
+pub mod query;
+pub mod index;
+pub mod transform;
+pub mod redact;
+pub mod walk;
+pub mod projection;
+pub mod transaction;
+pub mod store;
+pub mod shared;
+pub mod save;
+pub mod autosave;
+pub mod database;
+pub mod log;
+pub mod borrowed;
+pub mod cache;
+pub mod bloom;
+pub mod builder;
+pub mod observe;
+mod varint;
+mod dictionary;
+mod delta;
+mod checksum;
+mod compression;
+pub mod shard;
+pub mod stream;
+pub mod recover;
+pub mod spec;
+pub mod getters;
+pub mod yad_testutil;
+#[cfg(feature = "encryption")]
+pub mod encryption;
+#[cfg(feature = "signing")]
+pub mod signature;
+#[cfg(feature = "cbor")]
+pub mod cbor;
+#[cfg(feature = "yaml")]
+pub mod yaml;
+#[cfg(feature = "arrow")]
+pub mod arrow;
+#[cfg(feature = "protobuf")]
+pub mod protobuf;
+#[cfg(feature = "tokio")]
+pub mod codec;
+#[cfg(feature = "csv")]
+pub mod csv;
+#[cfg(feature = "arena")]
+pub mod arena;
+#[cfg(feature = "normalize")]
+pub mod normalize;
+#[cfg(feature = "serde")]
+mod serde_impl;
+#[cfg(feature = "serde")]
+pub mod bridge;
+#[cfg(any(feature = "proptest", feature = "quickcheck"))]
+mod arbitrary;
+#[cfg(feature = "ffi")]
 pub mod ffi;
 
+use std::borrow::Cow;
 use std::collections::BTreeMap;
 use std::fmt::{Debug, Display, Formatter};
+use std::ops::{Index, IndexMut};
 use yad_core;
-use yad_core::constants::error::ErrorMessage;
+use yad_core::constants::error::{ErrorMessage, STRING_OF_LENGTH_ZERO};
 use yad_core::constants::length::ByteLength;
+use yad_core::Reader;
 pub use yad_core::Value;
 
-use crate::constants::{KEY_END_HEADER, KEY_START_HEADER, ROW_END_HEADER, ROW_START_HEADER, VERSION_HEADER};
+use crate::constants::{MAGIC, ROW_START_HEADER, VERSION_HEADER};
 use crate::error::{MALFORMED_FILE, MALFORMED_VERSION_HEADER};
 use crate::key::Key;
+use crate::options::DecodeOptions;
 use crate::row::Row;
 
 /// Encodes a string name into a serialized binary representation using a header byte.
 ///
 /// The header byte is combined with the length nibble to indicate the type of
-/// entity being encoded (row or key).
+/// entity being encoded (row or key). The length descriptor holds the UTF-8
+/// byte length of the name, never its number of characters.
 ///
 /// # Type Parameters
 /// - `S`: Any type implementing [`ToString`].
@@ -32,16 +102,86 @@ use crate::row::Row;
 /// - `Ok(Vec<u8>)`: The encoded byte vector.
 /// - `Err(ErrorMessage)`: If conversion fails.
 pub(crate) fn encode_name<S: ToString>(name: &S, header: u8) -> Result<Vec<u8>, ErrorMessage> {
-    let mut encoded_name = Value::try_from(name.to_string())?.bytes;
+    let name = name.to_string();
+    let mut encoded_name = Vec::with_capacity(encoded_name_len(&name));
 
-    if let Some(first_byte) = encoded_name.get_mut(0) {
-        let length_nibble = *first_byte & 0x0F;
-        *first_byte = header | length_nibble;
-    }
+    encode_name_into(&name, header, &mut encoded_name)?;
 
     Ok(encoded_name)
 }
 
+/// Returns the width of the smallest length descriptor that can hold `len`.
+pub(crate) fn length_descriptor(len: usize) -> ByteLength {
+    match len {
+        l if l <= u8::MAX as usize => ByteLength::One,
+        l if l <= u16::MAX as usize => ByteLength::Two,
+        l if l <= u32::MAX as usize => ByteLength::Four,
+        _ => ByteLength::Eight,
+    }
+}
+
+/// Returns the number of bytes [`encode_name`] writes for `name`.
+pub(crate) fn encoded_name_len(name: &str) -> usize {
+    1 + length_descriptor(name.len()).as_byte_count() as usize + name.len()
+}
+
+/// Appends the encoding of [`encode_name`] to `bytes`, without intermediate buffers.
+///
+/// # Errors
+/// Returns `STRING_OF_LENGTH_ZERO` if `name` is empty.
+pub(crate) fn encode_name_into(name: &str, header: u8, bytes: &mut Vec<u8>) -> Result<(), ErrorMessage> {
+    if name.is_empty() {
+        return Err(ErrorMessage(STRING_OF_LENGTH_ZERO));
+    }
+
+    let length = length_descriptor(name.len());
+    bytes.push(header | u8::from(length));
+
+    match length {
+        ByteLength::One => bytes.push(name.len() as u8),
+        ByteLength::Two => bytes.extend_from_slice(&(name.len() as u16).to_be_bytes()),
+        ByteLength::Four => bytes.extend_from_slice(&(name.len() as u32).to_be_bytes()),
+        _ => bytes.extend_from_slice(&(name.len() as u64).to_be_bytes()),
+    }
+
+    bytes.extend_from_slice(name.as_bytes());
+
+    Ok(())
+}
+
+/// Decodes a name written by [`encode_name`] whose header high nibble must equal `header`.
+///
+/// # Parameters
+/// - `bytes`: Slice starting at the name header byte.
+/// - `header`: The expected header (row or key name header).
+///
+/// # Returns
+/// - `Some((String, usize))`: The decoded name and the number of bytes consumed.
+/// - `None`: If the header does not match or the name is truncated or not UTF-8.
+///   The length descriptor is read as a byte length, so a descriptor that
+///   counts characters instead ends the name mid-character and is rejected.
+pub(crate) fn decode_name(bytes: &[u8], header: u8) -> Option<(String, usize)> {
+    decode_name_ref(bytes, header).map(|(name, end)| (name.to_string(), end))
+}
+
+/// Borrowing variant of [`decode_name`]: the name points into `bytes`.
+pub(crate) fn decode_name_ref(bytes: &[u8], header: u8) -> Option<(&str, usize)> {
+    let first = *bytes.first()?;
+
+    if first & 0xF0 != header {
+        return None;
+    }
+
+    let byte_length = ByteLength::try_from(first).ok()?;
+    let be_length = usize_from_slice_bytes(&bytes[1..], byte_length)?;
+    let metadata_length = 1 + byte_length.as_byte_count() as usize;
+    let end = metadata_length.checked_add(be_length)?;
+
+    let name = std::str::from_utf8(bytes.get(metadata_length..end)?).ok()?;
+
+    Some((name, end))
+}
+
 /// Interprets a byte slice as a big-endian unsigned integer of a given byte length.
 ///
 /// # Parameters
@@ -67,56 +207,11 @@ pub(crate) fn usize_from_slice_bytes(slice: &[u8], byte_length: ByteLength) -> O
     }
 }
 
-/// Generic function to segment a byte buffer into sub-slices bounded by `start` and `end` bytes.
-///
-/// # Parameters
-/// - `bytes`: Byte buffer to split.
-/// - `start`: Start marker byte.
-/// - `end`: End marker byte.
-///
-/// # Returns
-/// - `Vec<Vec<u8>>`: Each element is a sub-slice including start and end markers.
-///
-/// # Notes
-/// - Segments missing either marker are ignored.
-/// - Nested segments are **not supported**.
-pub(crate) fn segment<B: AsRef<Vec<u8>>>(bytes: B, start: &u8, end: &u8) -> Vec<Vec<u8>> {
-    let mut result = Vec::new();
-    let mut current = Vec::new();
-    let mut inside = false;
-
-    for b in bytes.as_ref() {
-        if b == start {
-            current.clear();
-            current.push(*b);
-            inside = true;
-        } else if b == end && inside {
-            current.push(*b);
-            result.push(current.clone());
-            current.clear();
-            inside = false;
-        } else if inside {
-            current.push(*b);
-        }
-    }
-
-    result
-}
-
-/// Segments a byte buffer into individual key byte sequences, including start and end markers.
-pub(crate) fn segment_keys<B: AsRef<Vec<u8>>>(bytes: B) -> Vec<Vec<u8>> {
-    segment(bytes, &KEY_START_HEADER, &KEY_END_HEADER)
-}
-
-/// Segments a byte buffer into individual row byte sequences, including start and end markers.
-pub(crate) fn segment_rows<B: AsRef<Vec<u8>>>(bytes: B) -> Vec<Vec<u8>> {
-    segment(bytes, &ROW_START_HEADER, &ROW_END_HEADER)
-}
-
 /// Represents a semantic version of the YAD file format.
 ///
 /// Versioning uses: major, minor, patch, and beta (pre-release).
 #[derive(Clone, Ord, PartialOrd, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Version {
     /// Major version (breaking changes)
     pub major: u8,
@@ -172,13 +267,49 @@ impl Version {
     }
 }
 
+/// The format version written by this crate.
+///
+/// Documents decoded from older files can be moved to it with [`YAD::upgrade`].
+pub const CURRENT_VERSION: Version = Version {
+    major: 1,
+    minor: 0,
+    patch: 0,
+    beta: 0,
+};
+
 /// Represents a full YAD document containing a version and multiple rows.
-#[derive(Eq, PartialEq)]
+///
+/// Two documents are equal when their versions and rows are; secondary
+/// indexes and observers are not compared.
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct YAD {
     /// Document version
     pub version: Version,
     /// Rows in the document, keyed by row name
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_impl::by_name"))]
     pub rows: BTreeMap<String, Row>,
+    /// Secondary indexes, keyed by key name (see [`YAD::build_index`])
+    #[cfg_attr(feature = "serde", serde(skip))]
+    indexes: BTreeMap<String, index::Index>,
+    /// Change observers (see [`YAD::on_change`])
+    #[cfg_attr(feature = "serde", serde(skip))]
+    observers: observe::Observers,
+}
+
+impl PartialEq for YAD {
+    fn eq(&self, other: &Self) -> bool {
+        self.version == other.version && self.rows == other.rows
+    }
+}
+
+impl Eq for YAD {}
+
+impl Default for YAD {
+    /// An empty document of the [`CURRENT_VERSION`].
+    fn default() -> Self {
+        Self::new_empty(CURRENT_VERSION)
+    }
 }
 
 impl YAD {
@@ -187,13 +318,15 @@ impl YAD {
         Self {
             version,
             rows: rows.into_iter().map(|r| (r.name.clone(), r)).collect(),
+            indexes: BTreeMap::new(),
+            observers: observe::Observers::default(),
         }
     }
 
     /// Constructs an empty YAD document for a given version.
     pub fn new_empty(version: Version) -> Self {
         Self {
-            version, rows: BTreeMap::new()
+            version, rows: BTreeMap::new(), indexes: BTreeMap::new(), observers: observe::Observers::default()
         }
     }
 
@@ -207,41 +340,428 @@ impl YAD {
         &mut self.rows
     }
 
-    /// Inserts a new row into the document.
+    /// Returns the row named `name`, if any. `yad["name"]` is the panicking form.
+    pub fn get_row(&self, name: &str) -> Option<&Row> {
+        self.rows.get(name)
+    }
+
+    /// Returns the row named `name` mutably, if any.
+    ///
+    /// Like [`YAD::get_rows_mut`], edits made through it do not update
+    /// secondary indexes. Use [`YAD::rename_row`] rather than assigning
+    /// [`Row::name`], which would leave the row under its old map key.
+    pub fn get_row_mut(&mut self, name: &str) -> Option<&mut Row> {
+        self.rows.get_mut(name)
+    }
+
+    /// Inserts a new row into the document, replacing any row with the same name.
     pub fn insert_row<S: ToString>(&mut self, name: S, keys: Vec<Key>) {
-        let rows = self.get_rows_mut();
-        rows.insert(name.to_string(), Row::new(name, keys));
+        self.adopt_row(Row::new(name, keys));
     }
 
     /// Removes a row by name, returning it if it existed.
     pub fn remove_row<S: ToString>(&mut self, name: S) -> Option<Row> {
-        let rows = self.get_rows_mut();
-        rows.remove(&name.to_string())
+        let row = self.detach_row(&name.to_string())?;
+        self.notify(|| observe::ChangeEvent::RowRemoved { row: row.name.clone() });
+        Some(row)
+    }
+
+    /// Removes a row and drops it from the secondary indexes, without notifying observers.
+    pub(crate) fn detach_row(&mut self, name: &str) -> Option<Row> {
+        let row = self.rows.remove(name)?;
+        self.unindex_row(&row);
+        Some(row)
+    }
+
+    /// Inserts a row under [`Row::name`] and adds it to the secondary indexes,
+    /// without notifying observers. Any row of the same name must have been detached.
+    pub(crate) fn attach_row(&mut self, row: Row) {
+        self.index_row(&row);
+        self.rows.insert(row.name.clone(), row);
+    }
+
+    /// Removes the row named `name` and hands it over, so it can be moved
+    /// into another document with [`YAD::adopt_row`] without copying its keys.
+    pub fn take_row(&mut self, name: &str) -> Option<Row> {
+        self.remove_row(name)
+    }
+
+    /// Moves `row` into the document under [`Row::name`], returning the row
+    /// it replaces, if any. Secondary indexes are kept in step.
+    pub fn adopt_row(&mut self, row: Row) -> Option<Row> {
+        let replaced = self.detach_row(&row.name);
+        let name = row.name.clone();
+        self.attach_row(row);
+
+        self.notify(|| match replaced {
+            Some(_) => observe::ChangeEvent::RowUpdated { row: name },
+            None => observe::ChangeEvent::RowInserted { row: name },
+        });
+
+        replaced
+    }
+
+    /// Moves every row of `other` into the document, replacing rows of the
+    /// same name. Rows are moved, not cloned; the version and secondary
+    /// indexes of `other` are dropped.
+    pub fn extend(&mut self, other: YAD) {
+        for (_, row) in other.rows {
+            self.adopt_row(row);
+        }
+    }
+
+    /// Renames the row `old` to `new`, updating both its map key and
+    /// [`Row::name`] and keeping secondary indexes in step. Renaming a row
+    /// to its own name does nothing.
+    ///
+    /// # Errors
+    /// - `ROW_NOT_FOUND` if there is no row named `old`.
+    /// - `NAME_ALREADY_TAKEN` if another row is already named `new`.
+    /// - `ROW_NAME_OF_LENGTH_ZERO` if `new` is empty.
+    ///
+    /// The document is left unchanged on error.
+    pub fn rename_row<S: ToString>(&mut self, old: &str, new: S) -> Result<(), ErrorMessage> {
+        let new = new.to_string();
+
+        if !self.rows.contains_key(old) {
+            return Err(ErrorMessage(error::ROW_NOT_FOUND));
+        }
+        if new.is_empty() {
+            return Err(ErrorMessage(error::ROW_NAME_OF_LENGTH_ZERO));
+        }
+        if new == old {
+            return Ok(());
+        }
+        if self.rows.contains_key(&new) {
+            return Err(ErrorMessage(error::NAME_ALREADY_TAKEN));
+        }
+
+        let mut row = self.detach_row(old).expect("checked above");
+        row.name = new.clone();
+        self.attach_row(row);
+
+        self.notify(|| observe::ChangeEvent::RowRenamed { old: old.to_string(), new });
+
+        Ok(())
+    }
+
+    /// Returns the number of bytes [`YAD::serialize`] writes.
+    pub fn encoded_len(&self) -> usize {
+        MAGIC.len() + 5 + self.rows.values().map(Row::encoded_len).sum::<usize>()
     }
 
-    /// Serializes the YAD document to bytes: version + rows.
+    /// Serializes the YAD document to bytes: magic number + version + rows.
+    ///
+    /// The output buffer is allocated once, sized by [`YAD::encoded_len`], and
+    /// every row and key is written straight into it.
     pub fn serialize(&self) -> Result<Vec<u8>, ErrorMessage> {
-        let mut bytes: Vec<u8> = vec![];
+        let mut bytes: Vec<u8> = Vec::with_capacity(self.encoded_len());
 
+        bytes.extend_from_slice(&MAGIC);
         bytes.extend_from_slice(&self.version.serialize());
 
-        for (_name, row) in &self.rows {
-            bytes.extend_from_slice(row.serialize()?.as_slice())
+        for row in self.rows.values() {
+            row.write_into(&mut bytes)?;
         }
 
         Ok(bytes)
     }
 
+    /// Serializes the YAD document to its canonical byte representation.
+    ///
+    /// # Stability guarantee
+    /// For documents holding the same rows, keys and values, the output is
+    /// byte-for-byte identical across runs, Rust versions and platforms, so it
+    /// can be signed, hashed or cached:
+    /// - Rows and keys are written in ascending byte order of their UTF-8
+    ///   names. Storage is a `BTreeMap`, never a hash map, so the order does not
+    ///   depend on hashing or insertion order.
+    /// - Every value is re-encoded through [`Value::decode`], which writes the
+    ///   smallest length descriptor for strings and arrays and a single
+    ///   `true`/`false` byte for booleans. Multi-value keys keep all their
+    ///   values, in order.
+    /// - Numbers are big-endian; optional sections (such as an embedded schema)
+    ///   and row or key metadata are never written.
+    ///
+    /// `usize` and `isize` convert to values of the platform pointer width, so
+    /// documents meant to be canonical across platforms should be built from
+    /// fixed-width integers.
+    ///
+    /// # Errors
+    /// Returns `ErrorMessage` if a name cannot be encoded or a value is malformed.
+    pub fn serialize_canonical(&self) -> Result<Vec<u8>, ErrorMessage> {
+        let mut bytes: Vec<u8> = MAGIC.to_vec();
+
+        bytes.extend_from_slice(&self.version.serialize());
+
+        for row in self.rows.values() {
+            let mut canonical = Row::new_empty(&row.name);
+
+            for key in row.keys.values() {
+                let mut canonical_key = Key::new(&key.name, Value::decode_in_place(&key.value.bytes)?);
+                for value in key.values().skip(1) {
+                    canonical_key.push_value(Value::decode_in_place(&value.bytes)?);
+                }
+                canonical.keys.insert(key.name.clone(), canonical_key);
+            }
+
+            canonical.write_into(&mut bytes)?;
+        }
+
+        Ok(bytes)
+    }
+
+    /// Replaces the document version with [`CURRENT_VERSION`].
+    ///
+    /// Used after reading a beta-era file so the next [`YAD::serialize`]
+    /// writes it with the current version.
+    pub fn upgrade(&mut self) {
+        self.version = CURRENT_VERSION;
+    }
+
     /// Deserializes a YAD document from bytes.
-    pub fn deserialize(mut bytes: Vec<u8>) -> Result<Self, ErrorMessage> {
+    ///
+    /// The [`MAGIC`] number is optional, so files written before it was
+    /// introduced are still read; anything else must start with the version
+    /// header. Use [`options::DecodeOptions::require_magic`] to reject files
+    /// without it.
+    ///
+    /// Beta-era files (version `< 1.0`) share the current row and key
+    /// framing and are read the same way. Header flags are skipped, except
+    /// that numbers of little-endian files, varint length descriptors,
+    /// dictionary references and delta arrays are converted back; use
+    /// [`header::Header::read`] to read them. An embedded schema section is
+    /// skipped; use [`schema::Schema::read_embedded`] to read it. A signature
+    /// section is skipped without being checked. Encrypted documents are
+    /// rejected with `ENCRYPTED_DOCUMENT`.
+    ///
+    /// When two rows, or two keys of a row, share a name the last one is kept;
+    /// use [`options::DecodeOptions::duplicates`] to choose another policy.
+    pub fn deserialize(bytes: Vec<u8>) -> Result<Self, ErrorMessage> {
+        Self::decode_with(bytes, &DecodeOptions::default())
+    }
+
+    /// Deserializes a document like [`YAD::deserialize`], resolving rows and
+    /// keys that share a name according to `options.duplicates`, skipping rows
+    /// and keys outside `options.projection` and enforcing `options.max_size`,
+    /// `options.max_depth` and `options.strict`. The non-finite float policy is
+    /// not applied.
+    pub(crate) fn decode_with(bytes: Vec<u8>, options: &DecodeOptions) -> Result<Self, ErrorMessage> {
+        let body = Body::read(bytes, options)?;
+        let (duplicates, projection) = (options.duplicates, &options.projection);
+
+        let mut yad = Self::new_empty(body.version);
+        let mut reader = options.reader(&body.bytes);
+
+        // Bytes between rows are skipped, unless strict; rows themselves are read by length.
+        while let Some(start) = reader.remaining().iter().position(|b| *b == ROW_START_HEADER) {
+            if options.strict && start > 0 {
+                return Err(ErrorMessage(error::STRAY_BYTES));
+            }
+
+            reader.advance(start)?;
+            let Some(mut row) = Row::read(&mut reader, duplicates, projection)? else { continue };
+            options::convert_byte_order(&mut row, body.flags.byte_order())?;
+            duplicates.insert(&mut yad.rows, row.name.clone(), row, |row, name| row.name = name.to_string())?;
+        }
+
+        if options.strict && !reader.is_empty() {
+            return Err(ErrorMessage(error::STRAY_BYTES));
+        }
+
+        Ok(yad)
+    }
+
+    /// Deserializes as much of a damaged document as possible, returning every
+    /// row decoded before the first error together with that error.
+    ///
+    /// Meant for recovery tooling: a file truncated or corrupted mid-row keeps
+    /// the rows before the damage, where [`YAD::deserialize`] fails as a whole.
+    /// When the headers themselves cannot be read, the document is empty.
+    ///
+    /// Rows are read only until the first error, so intact rows after a damaged
+    /// one are lost. Files using a dictionary, varint length descriptors or
+    /// delta arrays must have their whole body rewritten before any row can be
+    /// read; when that fails, no row is recovered.
+    ///
+    /// A body that does not match its checksum section is still read: the rows
+    /// are recovered as from any other damaged file, and `CHECKSUM_MISMATCH` is
+    /// reported, at offset `0` after every row, when no row fails to decode.
+    ///
+    /// # Example
+    /// ```
+    /// use serde_yad::{row::Row, key::Key, YAD, CURRENT_VERSION};
+    ///
+    /// let yad = YAD::new(CURRENT_VERSION, vec![
+    ///     Row::new("a", vec![Key::new("n", 1u8)]),
+    ///     Row::new("b", vec![Key::new("n", 2u8)]),
+    /// ]);
+    /// let mut bytes = yad.serialize().unwrap();
+    /// bytes.truncate(bytes.len() - 3);
+    ///
+    /// let (partial, error) = YAD::deserialize_partial(bytes);
+    /// assert!(partial.get_row("a").is_some() && partial.get_row("b").is_none());
+    /// assert!(error.is_some());
+    /// ```
+    pub fn deserialize_partial(bytes: Vec<u8>) -> (Self, Option<DecodeError>) {
+        let options = DecodeOptions::default();
+        let input_len = bytes.len();
+
+        let body = match Body::read_unverified(bytes, &options) {
+            Ok(body) => body,
+            Err(error) => return (Self::new_empty(CURRENT_VERSION), Some(DecodeError { error, offset: 0, rows: 0 })),
+        };
+        let version = body.version.clone();
+
+        let body_offset = if body.expanded { 0 } else { input_len - body.bytes.len() };
+        let mut yad = Self::new_empty(version);
+        let mut reader = Reader::new(&body.bytes);
+        let mut rows = 0;
+
+        while let Some(start) = reader.remaining().iter().position(|b| *b == ROW_START_HEADER) {
+            let offset = body_offset + reader.position() + start;
+            let row = reader.advance(start)
+                .and_then(|_| Row::read(&mut reader, options.duplicates, &options.projection))
+                .and_then(|row| {
+                    let Some(mut row) = row else { return Ok(()) };
+                    options::convert_byte_order(&mut row, body.flags.byte_order())?;
+                    options.duplicates.insert(&mut yad.rows, row.name.clone(), row, |row, name| row.name = name.to_string())
+                });
+
+            if let Err(error) = row {
+                return (yad, Some(DecodeError { error, offset, rows }));
+            }
+            rows += 1;
+        }
+
+        match body.checksum_matches {
+            true => (yad, None),
+            false => (yad, Some(DecodeError { error: ErrorMessage(error::CHECKSUM_MISMATCH), offset: 0, rows })),
+        }
+    }
+}
+
+/// The first error [`YAD::deserialize_partial`] found, with where it was found.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct DecodeError {
+    /// What went wrong.
+    pub error: ErrorMessage,
+    /// Offset of the row that could not be read, in the input; `0` when the
+    /// headers could not be read. For files whose body was rewritten (see
+    /// [`YAD::deserialize_partial`]) it is an offset into the rewritten body.
+    pub offset: usize,
+    /// How many rows were read before it.
+    pub rows: usize,
+}
+
+impl Display for DecodeError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} (offset {}, after {} rows)", self.error.0, self.offset, self.rows)
+    }
+}
+
+impl From<DecodeError> for ErrorMessage {
+    fn from(error: DecodeError) -> Self {
+        error.error
+    }
+}
+
+/// The rows of a document, after its headers and sections.
+struct Body {
+    version: Version,
+    flags: header::HeaderFlags,
+    bytes: Vec<u8>,
+    /// Whether `bytes` were decompressed or rewritten from the input by [`header::decode_body`].
+    expanded: bool,
+    /// Whether the checksum section, if any, matches the body. Only
+    /// [`Body::read_unverified`] returns bodies that do not.
+    checksum_matches: bool,
+}
+
+impl Body {
+    /// Reads the headers and sections of a document, enforcing `options.max_size`,
+    /// on the input and on the rewritten body.
+    ///
+    /// # Errors
+    /// Returns `CHECKSUM_MISMATCH` if the body does not match its checksum
+    /// section, or the errors of [`Body::read_unverified`].
+    fn read(bytes: Vec<u8>, options: &DecodeOptions) -> Result<Self, ErrorMessage> {
+        let body = Self::read_unverified(bytes, options)?;
+        if !body.checksum_matches {
+            return Err(ErrorMessage(error::CHECKSUM_MISMATCH));
+        }
+        Ok(body)
+    }
+
+    /// Reads a document like [`Body::read`], returning a body that does not
+    /// match its checksum section with `checksum_matches` unset, for the
+    /// recovery paths to read as many rows as they can from it.
+    fn read_unverified(mut bytes: Vec<u8>, options: &DecodeOptions) -> Result<Self, ErrorMessage> {
+        options.check_size(bytes.len())?;
+
+        if header::has_magic(&bytes) {
+            bytes.drain(..MAGIC.len());
+        }
+
+        if bytes.len() < 5 {
+            return Err(ErrorMessage(MALFORMED_VERSION_HEADER));
+        }
+
         let version = Version::deserialize(bytes.drain(..=4).collect())?;
-        let mut rows: Vec<Row> = vec![];
 
-        for row_bytes in segment_rows(bytes) {
-            rows.push(Row::deserialize(row_bytes)?)
+        let (flags, consumed) = header::HeaderFlags::decode(&bytes)?;
+        bytes.drain(..consumed);
+
+        if bytes.first() == Some(&constants::ENCRYPTED_HEADER) {
+            return Err(ErrorMessage(error::ENCRYPTED_DOCUMENT));
         }
 
-        Ok(Self::new(version, rows))
+        if bytes.first() == Some(&constants::SIGNATURE_HEADER) {
+            bytes.drain(..bytes.len().min(1 + constants::SIGNATURE_LENGTH));
+        }
+
+        schema::strip_schema_section(&mut bytes)?;
+        bloom::strip_bloom_section(&mut bytes)?;
+        let checksum_matches = checksum::strip_checksum_section(&mut bytes)?;
+
+        // Inflated here rather than in `decode_body`, so `max_size` bounds it.
+        let mut expanded = false;
+        let mut encodings = flags;
+        if flags.contains(header::HeaderFlags::COMPRESSED) {
+            bytes = compression::inflate(&bytes, options.max_size)?;
+            encodings.remove(header::HeaderFlags::COMPRESSED);
+            expanded = true;
+        }
+
+        if let Cow::Owned(body) = header::decode_body(&bytes, encodings, options.max_size)? {
+            options.check_size(body.len())?;
+            bytes = body;
+            expanded = true;
+        }
+
+        Ok(Self { version, flags, bytes, expanded, checksum_matches })
+    }
+}
+
+impl Index<&str> for YAD {
+    type Output = Row;
+
+    /// Returns the row named `name`.
+    ///
+    /// # Panics
+    /// Panics if there is no such row; use [`YAD::get_row`] to handle that case.
+    fn index(&self, name: &str) -> &Row {
+        self.get_row(name).unwrap_or_else(|| panic!("no row named `{}` in the document", name))
+    }
+}
+
+impl IndexMut<&str> for YAD {
+    /// Returns the row named `name` mutably.
+    ///
+    /// # Panics
+    /// Panics if there is no such row; use [`YAD::get_row_mut`] to handle that case.
+    fn index_mut(&mut self, name: &str) -> &mut Row {
+        self.get_row_mut(name).unwrap_or_else(|| panic!("no row named `{}` in the document", name))
     }
 }
 
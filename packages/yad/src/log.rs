@@ -0,0 +1,502 @@
+use std::collections::BTreeMap;
+use std::fs::{File, OpenOptions};
+use std::io::{Read, Write};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+use yad_core::constants::error::ErrorMessage;
+use yad_core::Reader;
+use crate::constants::{MAGIC, ROW_NAME_HEADER, ROW_START_HEADER, TOMBSTONE_HEADER};
+use crate::error::{LOG_IO_FAILED, MALFORMED_LOG, SNAPSHOT_UNAVAILABLE};
+use crate::header::Header;
+use crate::options::DuplicatePolicy;
+use crate::projection::Projection;
+use crate::row::Row;
+use crate::save::write_atomic;
+use crate::store::RowStore;
+use crate::{decode_name, encode_name_into, Version, CURRENT_VERSION, YAD};
+
+/// Garbage statistics of a [`YadLog`], as returned by [`YadLog::stats`].
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct LogStats {
+    /// Records in the file: row records and tombstones.
+    pub records: usize,
+    /// Rows currently stored.
+    pub live_rows: usize,
+    /// Bytes of the records holding the stored rows.
+    pub live_bytes: u64,
+    /// Bytes of the records superseded by a later record, and of tombstones;
+    /// [`YadLog::compact`] reclaims them.
+    pub dead_bytes: u64,
+}
+
+impl LogStats {
+    /// The size of the records relative to the size of the live data: `1.0`
+    /// right after a compaction, growing as rows are replaced or removed.
+    ///
+    /// A log holding only dead records has an infinite amplification.
+    pub fn amplification(&self) -> f64 {
+        match (self.live_bytes, self.dead_bytes) {
+            (_, 0) => 1.0,
+            (0, _) => f64::INFINITY,
+            (live, dead) => (live + dead) as f64 / live as f64,
+        }
+    }
+}
+
+/// A row store kept as an append-only file: every [`YadLog::put`] appends the
+/// whole row and every [`YadLog::remove`] appends a tombstone, so writes never
+/// rewrite the file and a crash loses at most the record being written.
+///
+/// Opening a log replays its records, the last record of a name winning.
+/// Superseded records stay in the file until [`YadLog::compact`] rewrites it
+/// with the live rows only; [`YadLog::stats`] tells when that is worth doing.
+///
+/// Every record gets the next sequence number ([`YadLog::seq`]). Since
+/// records are never rewritten in place, the state as of any sequence number
+/// can be read back from the file with [`YadLog::snapshot_at`], or from other
+/// threads through a [`LogReader`], while the writer keeps appending.
+///
+/// # Binary Layout
+/// The magic number and the version header, followed by records back to back:
+/// a row record is the row as [`YAD::serialize`] writes it, and a tombstone is
+/// `TOMBSTONE_HEADER` followed by the row name. A log without tombstones is a
+/// valid document.
+///
+/// # Example
+/// ```text
+/// let mut log = YadLog::open("events.yadlog")?;
+/// log.put(Row::new("johan", vec![Key::new("age", 18u8)]))?;
+/// log.sync()?;
+///
+/// if log.stats().amplification() > 2.0 {
+///     log.compact()?;
+/// }
+/// ```
+pub struct YadLog {
+    shared: Arc<Shared>,
+    file: File,
+    version: Version,
+    rows: BTreeMap<String, Row>,
+    live: BTreeMap<String, u64>,
+    records: usize,
+    len: u64,
+}
+
+impl YadLog {
+    /// Creates (or truncates) the log at `path` and writes its header.
+    ///
+    /// # Errors
+    /// Returns `LOG_IO_FAILED` if the file cannot be created or written.
+    pub fn create<P: AsRef<Path>>(path: P) -> Result<Self, ErrorMessage> {
+        let path = path.as_ref().to_path_buf();
+        let mut file = File::create(&path).map_err(|_| ErrorMessage(LOG_IO_FAILED))?;
+
+        let mut header = MAGIC.to_vec();
+        header.extend_from_slice(&CURRENT_VERSION.serialize());
+        file.write_all(&header).map_err(|_| ErrorMessage(LOG_IO_FAILED))?;
+
+        Ok(Self::new(path, file, CURRENT_VERSION))
+    }
+
+    /// Opens the log at `path` and replays its records, creating it if it
+    /// does not exist.
+    ///
+    /// Replay stops at the first record that does not decode, such as one cut
+    /// short by a crash: the file is truncated after the last whole record,
+    /// so the records appended next follow it.
+    ///
+    /// # Errors
+    /// Returns `LOG_IO_FAILED` if the file cannot be read, opened for
+    /// appending or truncated, or `MALFORMED_LOG` if its header is not the
+    /// header of a log.
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<Self, ErrorMessage> {
+        let path = path.as_ref();
+        if !path.exists() {
+            return Self::create(path);
+        }
+
+        let bytes = std::fs::read(path).map_err(|_| ErrorMessage(LOG_IO_FAILED))?;
+        let (version, records) = read_header(&bytes)?;
+
+        let file = OpenOptions::new().append(true).open(path).map_err(|_| ErrorMessage(LOG_IO_FAILED))?;
+        let mut log = Self::new(path.to_path_buf(), file, version);
+
+        let whole = replay(records, usize::MAX, |record, len| log.apply(record, len));
+        if whole < records.len() {
+            let len = bytes.len() - records.len() + whole;
+            log.file.set_len(len as u64).map_err(|_| ErrorMessage(LOG_IO_FAILED))?;
+        }
+        log.published().seq = log.records as u64;
+
+        Ok(log)
+    }
+
+    /// A log over `file` holding no records yet.
+    fn new(path: PathBuf, file: File, version: Version) -> Self {
+        let shared = Arc::new(Shared { path, published: Mutex::new(Published::default()) });
+        Self { shared, file, version, rows: BTreeMap::new(), live: BTreeMap::new(), records: 0, len: 0 }
+    }
+
+    /// The path of the log file.
+    pub fn path(&self) -> &Path {
+        &self.shared.path
+    }
+
+    /// The sequence number of the last record: the number of records
+    /// appended since the log was created, or since it was opened counting
+    /// the records it replayed. `0` before the first record.
+    ///
+    /// Sequence numbers are only meaningful for this handle and its readers;
+    /// they are not stored in the file.
+    pub fn seq(&self) -> u64 {
+        self.published().seq
+    }
+
+    /// Reads the rows as they were right after the record `seq` was appended
+    /// (see [`LogReader::snapshot_at`]).
+    pub fn snapshot_at(&self, seq: u64) -> Result<LogSnapshot, ErrorMessage> {
+        self.reader().snapshot_at(seq)
+    }
+
+    /// Returns a handle that reads snapshots of the log from other threads
+    /// while this one appends.
+    pub fn reader(&self) -> LogReader {
+        LogReader { shared: self.shared.clone() }
+    }
+
+    /// The format version of the records.
+    pub fn version(&self) -> &Version {
+        &self.version
+    }
+
+    /// Returns the row named `name`, if stored.
+    pub fn get(&self, name: &str) -> Option<&Row> {
+        self.rows.get(name)
+    }
+
+    /// The stored rows, by name.
+    pub fn rows(&self) -> &BTreeMap<String, Row> {
+        &self.rows
+    }
+
+    /// Appends `row`, replacing any row with the same name.
+    ///
+    /// # Errors
+    /// Returns the error of a row that fails to encode, or `LOG_IO_FAILED` if
+    /// it cannot be appended.
+    pub fn put(&mut self, row: Row) -> Result<(), ErrorMessage> {
+        let mut bytes = Vec::with_capacity(row.encoded_len());
+        row.write_into(&mut bytes)?;
+        self.append(&bytes)?;
+
+        self.apply(Record::Put(row), bytes.len() as u64);
+        self.published().seq += 1;
+        Ok(())
+    }
+
+    /// Removes the row named `name` by appending a tombstone, returning the
+    /// row if it was stored. Nothing is appended for rows that are not stored.
+    ///
+    /// # Errors
+    /// Returns `LOG_IO_FAILED` if the tombstone cannot be appended.
+    pub fn remove(&mut self, name: &str) -> Result<Option<Row>, ErrorMessage> {
+        if !self.rows.contains_key(name) {
+            return Ok(None);
+        }
+
+        let mut bytes = vec![TOMBSTONE_HEADER];
+        encode_name_into(name, ROW_NAME_HEADER, &mut bytes)?;
+        self.append(&bytes)?;
+
+        let row = self.rows.get(name).cloned();
+        self.apply(Record::Remove(name.to_string()), bytes.len() as u64);
+        self.published().seq += 1;
+        Ok(row)
+    }
+
+    /// Flushes the appended records to disk, so they survive a power loss.
+    ///
+    /// # Errors
+    /// Returns `LOG_IO_FAILED` if the flush fails.
+    pub fn sync(&self) -> Result<(), ErrorMessage> {
+        self.file.sync_data().map_err(|_| ErrorMessage(LOG_IO_FAILED))
+    }
+
+    /// Counts the live and dead records of the log.
+    pub fn stats(&self) -> LogStats {
+        let live_bytes = self.live.values().sum();
+        LogStats { records: self.records, live_rows: self.rows.len(), live_bytes, dead_bytes: self.len - live_bytes }
+    }
+
+    /// Rewrites the log with one record per stored row, dropping superseded
+    /// records and tombstones, and returns the number of bytes reclaimed.
+    ///
+    /// The new file replaces the old one like [`YAD::save_atomic`] does, so a
+    /// crash leaves either of them whole. Snapshots older than the compaction
+    /// can no longer be read, but those already read are kept.
+    ///
+    /// # Errors
+    /// Returns the error of a row that fails to encode, `SAVE_IO_FAILED` if
+    /// the new file cannot be written, or `LOG_IO_FAILED` if it cannot be
+    /// reopened for appending.
+    pub fn compact(&mut self) -> Result<u64, ErrorMessage> {
+        let stats = self.stats();
+
+        let mut bytes = MAGIC.to_vec();
+        bytes.extend_from_slice(&self.version.serialize());
+        for row in self.rows.values() {
+            row.write_into(&mut bytes)?;
+        }
+
+        // Readers open the file while holding the lock, so none of them pairs
+        // the new file with the old record numbering.
+        let shared = self.shared.clone();
+        let mut published = shared.lock();
+        write_atomic(&shared.path, &bytes)?;
+        self.file = OpenOptions::new().append(true).open(&shared.path).map_err(|_| ErrorMessage(LOG_IO_FAILED))?;
+
+        published.base = published.seq;
+        published.compacted = self.rows.len();
+        self.records = self.rows.len();
+        self.len = stats.live_bytes;
+        Ok(stats.dead_bytes)
+    }
+
+    /// Copies the stored rows into a document.
+    pub fn to_yad(&self) -> YAD {
+        YAD::new(self.version.clone(), self.rows.values().cloned().collect())
+    }
+
+    /// Locks the sequence numbers published to readers.
+    fn published(&self) -> MutexGuard<'_, Published> {
+        self.shared.lock()
+    }
+
+    /// Writes `bytes` at the end of the file.
+    fn append(&mut self, bytes: &[u8]) -> Result<(), ErrorMessage> {
+        self.file.write_all(bytes).map_err(|_| ErrorMessage(LOG_IO_FAILED))
+    }
+
+    /// Applies a record of `len` bytes to the stored rows and the counters.
+    fn apply(&mut self, record: Record, len: u64) {
+        self.records += 1;
+        self.len += len;
+
+        match record {
+            Record::Put(row) => {
+                self.live.insert(row.name.clone(), len);
+                self.rows.insert(row.name.clone(), row);
+            }
+            Record::Remove(name) => {
+                self.live.remove(&name);
+                self.rows.remove(&name);
+            }
+        }
+    }
+}
+
+/// What a [`YadLog`] shares with its readers.
+struct Shared {
+    path: PathBuf,
+    published: Mutex<Published>,
+}
+
+impl Shared {
+    /// Locks the published sequence numbers, recovering from a panicked writer.
+    fn lock(&self) -> MutexGuard<'_, Published> {
+        self.published.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+/// How sequence numbers map to the records in the file.
+#[derive(Default)]
+struct Published {
+    /// The sequence number of the last complete record.
+    seq: u64,
+    /// The sequence number at the last compaction.
+    base: u64,
+    /// The records written by the last compaction, which stand for the state at `base`.
+    compacted: usize,
+}
+
+/// Reads snapshots of a [`YadLog`] from any thread, while the log appends.
+///
+/// Snapshots are read from the file: the records up to the requested
+/// sequence number are replayed and those appended after it are ignored, so
+/// readers never wait for the writer, nor the writer for them.
+///
+/// # Example
+/// ```text
+/// let reader = log.reader();
+/// thread::spawn(move || {
+///     let snapshot = reader.snapshot()?;
+///     report(snapshot.seq(), snapshot.rows())
+/// });
+/// log.put(row)?;   // not seen by the report
+/// ```
+#[derive(Clone)]
+pub struct LogReader {
+    shared: Arc<Shared>,
+}
+
+impl LogReader {
+    /// The sequence number of the last record appended to the log (see [`YadLog::seq`]).
+    pub fn seq(&self) -> u64 {
+        self.shared.lock().seq
+    }
+
+    /// Reads the rows as of the last record appended to the log.
+    ///
+    /// # Errors
+    /// Returns the errors of [`LogReader::snapshot_at`].
+    pub fn snapshot(&self) -> Result<LogSnapshot, ErrorMessage> {
+        self.read(None)
+    }
+
+    /// Reads the rows as they were right after the record `seq` was
+    /// appended; `0` is the state before the first record.
+    ///
+    /// # Errors
+    /// Returns `SNAPSHOT_UNAVAILABLE` if `seq` is older than the last
+    /// compaction or newer than the last record, `LOG_IO_FAILED` if the file
+    /// cannot be read, or `MALFORMED_LOG` if it does not decode.
+    pub fn snapshot_at(&self, seq: u64) -> Result<LogSnapshot, ErrorMessage> {
+        self.read(Some(seq))
+    }
+
+    /// Reads the snapshot at `seq`, or at the last record.
+    fn read(&self, seq: Option<u64>) -> Result<LogSnapshot, ErrorMessage> {
+        let (mut file, seq, count) = {
+            let published = self.shared.lock();
+            let seq = seq.unwrap_or(published.seq);
+            if seq < published.base || seq > published.seq {
+                return Err(ErrorMessage(SNAPSHOT_UNAVAILABLE));
+            }
+
+            let file = File::open(&self.shared.path).map_err(|_| ErrorMessage(LOG_IO_FAILED))?;
+            (file, seq, published.compacted + (seq - published.base) as usize)
+        };
+
+        let mut bytes = vec![];
+        file.read_to_end(&mut bytes).map_err(|_| ErrorMessage(LOG_IO_FAILED))?;
+        let (version, records) = read_header(&bytes)?;
+
+        let mut rows = BTreeMap::new();
+        let mut replayed = 0;
+        replay(records, count, |record, _| {
+            replayed += 1;
+            match record {
+                Record::Put(row) => rows.insert(row.name.clone(), row),
+                Record::Remove(name) => rows.remove(&name),
+            };
+        });
+
+        if replayed < count {
+            return Err(ErrorMessage(MALFORMED_LOG));
+        }
+
+        Ok(LogSnapshot { seq, version, rows })
+    }
+}
+
+/// The rows of a [`YadLog`] as of a sequence number, read by
+/// [`YadLog::snapshot_at`] or a [`LogReader`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LogSnapshot {
+    seq: u64,
+    version: Version,
+    rows: BTreeMap<String, Row>,
+}
+
+impl LogSnapshot {
+    /// The sequence number of the last record the snapshot holds.
+    pub fn seq(&self) -> u64 {
+        self.seq
+    }
+
+    /// Returns the row named `name`, if stored at the snapshot.
+    pub fn get(&self, name: &str) -> Option<&Row> {
+        self.rows.get(name)
+    }
+
+    /// The rows stored at the snapshot, by name.
+    pub fn rows(&self) -> &BTreeMap<String, Row> {
+        &self.rows
+    }
+
+    /// Copies the rows into a document.
+    pub fn to_yad(&self) -> YAD {
+        YAD::new(self.version.clone(), self.rows.values().cloned().collect())
+    }
+}
+
+/// Reads the header of a log and returns its version and the bytes of its records.
+fn read_header(bytes: &[u8]) -> Result<(Version, &[u8]), ErrorMessage> {
+    let (Header { version, flags }, pos) = Header::read(bytes).map_err(|_| ErrorMessage(MALFORMED_LOG))?;
+    if !flags.is_empty() {
+        return Err(ErrorMessage(MALFORMED_LOG));
+    }
+
+    Ok((version, &bytes[pos..]))
+}
+
+/// Reads at most `limit` records from `bytes`, handing each to `apply` with
+/// its length, and returns the number of bytes read.
+///
+/// Reading stops at the first record that does not decode, so a record cut
+/// short by a crash is dropped with whatever follows it.
+fn replay(bytes: &[u8], limit: usize, mut apply: impl FnMut(Record, u64)) -> usize {
+    let mut reader = Reader::new(bytes);
+    for _ in 0..limit {
+        if reader.peek().is_none() {
+            break;
+        }
+
+        let start = reader.position();
+        let Ok(record) = read_record(&mut reader) else { return start };
+        apply(record, (reader.position() - start) as u64);
+    }
+
+    reader.position()
+}
+
+/// A record of a log.
+enum Record {
+    Put(Row),
+    Remove(String),
+}
+
+/// Reads the record at the reader's position.
+fn read_record(reader: &mut Reader) -> Result<Record, ErrorMessage> {
+    match reader.peek() {
+        Some(ROW_START_HEADER) => {
+            let row = Row::read(reader, DuplicatePolicy::KeepLast, &Projection::all())?;
+            row.map(Record::Put).ok_or(ErrorMessage(MALFORMED_LOG))
+        }
+        Some(TOMBSTONE_HEADER) => {
+            reader.advance(1)?;
+            let (name, consumed) = decode_name(reader.remaining(), ROW_NAME_HEADER).ok_or(ErrorMessage(MALFORMED_LOG))?;
+            reader.advance(consumed)?;
+            Ok(Record::Remove(name))
+        }
+        _ => Err(ErrorMessage(MALFORMED_LOG)),
+    }
+}
+
+impl RowStore for YadLog {
+    fn get(&self, name: &str) -> Result<Option<Row>, ErrorMessage> {
+        Ok(YadLog::get(self, name).cloned())
+    }
+
+    fn put(&mut self, row: Row) -> Result<(), ErrorMessage> {
+        YadLog::put(self, row)
+    }
+
+    fn remove(&mut self, name: &str) -> Result<Option<Row>, ErrorMessage> {
+        YadLog::remove(self, name)
+    }
+
+    fn iter(&self) -> Box<dyn Iterator<Item = Result<Row, ErrorMessage>> + '_> {
+        Box::new(self.rows.values().cloned().map(Ok))
+    }
+}
@@ -0,0 +1,216 @@
+use std::collections::BTreeMap;
+use yad_core::constants::error::ErrorMessage;
+use crate::constants::META_HEADER;
+use crate::error::{MALFORMED_META, META_VALUE_TOO_LONG};
+use crate::YAD;
+
+/// Tag of the last-modified timestamp: a big-endian `u64` (e.g. Unix milliseconds).
+pub const META_MODIFIED: u8 = 0x01;
+/// Tag of the user-defined tag byte.
+pub const META_TAG: u8 = 0x02;
+/// Tag of the expiry timestamp: a big-endian `u64`, in the unit of [`META_MODIFIED`].
+pub const META_EXPIRES: u8 = 0x03;
+/// Tag marking a key as sensitive, with an empty value (see [`crate::redact`]).
+pub const META_SENSITIVE: u8 = 0x04;
+
+/// Optional metadata attached to a [`crate::row::Row`] or a [`crate::key::Key`].
+///
+/// Metadata is a set of tagged byte strings. A few tags have typed accessors
+/// ([`META_MODIFIED`], [`META_TAG`], [`META_EXPIRES`], [`META_SENSITIVE`]); any other tag can be used through
+/// [`Meta::get`] / [`Meta::set`] and is preserved by readers that do not know it.
+///
+/// # Binary Layout
+/// Written between the name and the payload of a row or key, only when not empty:
+/// ```text
+/// +-------------+--------------------+-------------------------------------+
+/// | META_HEADER | Entries length u32 | (Tag u8 | Length u8 | Value...)...  |
+/// +-------------+--------------------+-------------------------------------+
+/// ```
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Meta {
+    /// Tag → raw value, at most 255 bytes each.
+    pub entries: BTreeMap<u8, Vec<u8>>,
+}
+
+impl Meta {
+    /// Returns `true` if no metadata is set; nothing is serialized then.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Returns the raw value of `tag`.
+    pub fn get(&self, tag: u8) -> Option<&[u8]> {
+        self.entries.get(&tag).map(Vec::as_slice)
+    }
+
+    /// Sets the raw value of `tag`, replacing any previous value.
+    ///
+    /// # Errors
+    /// Returns `META_VALUE_TOO_LONG` if `value` is longer than 255 bytes.
+    pub fn set<V: Into<Vec<u8>>>(&mut self, tag: u8, value: V) -> Result<(), ErrorMessage> {
+        let value = value.into();
+        if value.len() > u8::MAX as usize {
+            return Err(ErrorMessage(META_VALUE_TOO_LONG));
+        }
+        self.entries.insert(tag, value);
+        Ok(())
+    }
+
+    /// Removes `tag`, returning its raw value if it was set.
+    pub fn remove(&mut self, tag: u8) -> Option<Vec<u8>> {
+        self.entries.remove(&tag)
+    }
+
+    /// Returns the last-modified timestamp ([`META_MODIFIED`]), if set and well-formed.
+    pub fn modified(&self) -> Option<u64> {
+        Some(u64::from_be_bytes(self.get(META_MODIFIED)?.try_into().ok()?))
+    }
+
+    /// Sets the last-modified timestamp ([`META_MODIFIED`]).
+    pub fn set_modified(&mut self, timestamp: u64) {
+        self.entries.insert(META_MODIFIED, timestamp.to_be_bytes().to_vec());
+    }
+
+    /// Returns the user-defined tag byte ([`META_TAG`]), if set and well-formed.
+    pub fn tag(&self) -> Option<u8> {
+        match self.get(META_TAG)? {
+            [tag] => Some(*tag),
+            _ => None,
+        }
+    }
+
+    /// Sets the user-defined tag byte ([`META_TAG`]).
+    pub fn set_tag(&mut self, tag: u8) {
+        self.entries.insert(META_TAG, vec![tag]);
+    }
+
+    /// Returns the expiry timestamp ([`META_EXPIRES`]), if set and well-formed.
+    pub fn expires(&self) -> Option<u64> {
+        Some(u64::from_be_bytes(self.get(META_EXPIRES)?.try_into().ok()?))
+    }
+
+    /// Sets the expiry timestamp ([`META_EXPIRES`]).
+    pub fn set_expires(&mut self, timestamp: u64) {
+        self.entries.insert(META_EXPIRES, timestamp.to_be_bytes().to_vec());
+    }
+
+    /// Returns `true` if the expiry timestamp is set and not after `now`.
+    pub fn is_expired(&self, now: u64) -> bool {
+        self.expires().is_some_and(|expires| expires <= now)
+    }
+
+    /// Returns `true` if the sensitive mark ([`META_SENSITIVE`]) is set.
+    pub fn is_sensitive(&self) -> bool {
+        self.entries.contains_key(&META_SENSITIVE)
+    }
+
+    /// Sets or clears the sensitive mark ([`META_SENSITIVE`]).
+    pub fn set_sensitive(&mut self, sensitive: bool) {
+        match sensitive {
+            true => self.entries.insert(META_SENSITIVE, vec![]),
+            false => self.entries.remove(&META_SENSITIVE),
+        };
+    }
+
+    /// Returns the number of bytes of the serialized metadata block, `0` when empty.
+    pub fn encoded_len(&self) -> usize {
+        if self.is_empty() {
+            return 0;
+        }
+
+        5 + self.entries_len()
+    }
+
+    /// Returns the length of the block's entries, without its header.
+    fn entries_len(&self) -> usize {
+        self.entries.values().map(|value| 2 + value.len()).sum()
+    }
+
+    /// Appends the serialized metadata block to `bytes`, or nothing if it is empty.
+    pub(crate) fn write_into(&self, bytes: &mut Vec<u8>) {
+        if self.is_empty() {
+            return;
+        }
+
+        bytes.push(META_HEADER);
+        bytes.extend_from_slice(&(self.entries_len() as u32).to_be_bytes());
+
+        for (tag, value) in &self.entries {
+            bytes.push(*tag);
+            bytes.push(value.len() as u8);
+            bytes.extend_from_slice(value);
+        }
+    }
+
+    /// Reads the metadata block at the start of `bytes`, if there is one.
+    ///
+    /// # Returns
+    /// - `Ok((Meta, usize))`: The metadata (empty when `bytes` does not start
+    ///   with [`META_HEADER`]) and the number of bytes consumed.
+    ///
+    /// # Errors
+    /// Returns `MALFORMED_META` if the block is truncated or its entries overrun it.
+    pub(crate) fn decode(bytes: &[u8]) -> Result<(Self, usize), ErrorMessage> {
+        if bytes.first() != Some(&META_HEADER) {
+            return Ok((Self::default(), 0));
+        }
+
+        let length: [u8; 4] = bytes.get(1..5)
+            .and_then(|s| s.try_into().ok())
+            .ok_or(ErrorMessage(MALFORMED_META))?;
+        let end = 5usize.checked_add(u32::from_be_bytes(length) as usize)
+            .ok_or(ErrorMessage(MALFORMED_META))?;
+        let mut entries = bytes.get(5..end).ok_or(ErrorMessage(MALFORMED_META))?;
+
+        let mut meta = Self::default();
+        while let [tag, len, rest @ ..] = entries {
+            let value = rest.get(..*len as usize).ok_or(ErrorMessage(MALFORMED_META))?;
+            meta.entries.insert(*tag, value.to_vec());
+            entries = &rest[*len as usize..];
+        }
+
+        if !entries.is_empty() {
+            return Err(ErrorMessage(MALFORMED_META));
+        }
+
+        Ok((meta, end))
+    }
+}
+
+impl YAD {
+    /// Removes the rows, and the keys of the remaining rows, whose expiry
+    /// timestamp ([`Meta::expires`]) is not after `now`, notifying observers
+    /// of each removal. Returns the number of rows and keys removed.
+    ///
+    /// # Example
+    /// ```text
+    /// let mut row = Row::new("session", vec![Key::new("user", 42u32)]);
+    /// row.meta.set_expires(now + 60_000);
+    /// yad.adopt_row(row);
+    ///
+    /// yad.purge_expired(now + 60_000);   // removes the row
+    /// ```
+    pub fn purge_expired(&mut self, now: u64) -> usize {
+        let rows: Vec<String> = self.rows.values()
+            .filter(|row| row.meta.is_expired(now))
+            .map(|row| row.name.clone())
+            .collect();
+        let keys: Vec<(String, String)> = self.rows.values()
+            .filter(|row| !row.meta.is_expired(now))
+            .flat_map(|row| row.keys.values()
+                .filter(|key| key.meta.is_expired(now))
+                .map(|key| (row.name.clone(), key.name.clone())))
+            .collect();
+
+        for name in &rows {
+            self.remove_row(name);
+        }
+
+        for (row, key) in &keys {
+            self.remove_key(row, key);
+        }
+
+        rows.len() + keys.len()
+    }
+}
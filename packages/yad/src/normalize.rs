@@ -0,0 +1,150 @@
+use unicode_normalization::UnicodeNormalization;
+use crate::key::Key;
+use crate::observe::ChangeEvent;
+use crate::row::Row;
+use crate::YAD;
+
+/// How row and key names are normalized before they are stored or compared.
+///
+/// Names are always brought to Unicode NFC, so `"é"` written as one code
+/// point or as `e` plus a combining accent are the same name. With
+/// `case_fold` set they are lowercased as well.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct Normalization {
+    /// Lowercases names (with [`str::to_lowercase`]) after bringing them to NFC.
+    pub case_fold: bool,
+}
+
+impl Normalization {
+    /// NFC only; names differing in case stay distinct.
+    pub fn nfc() -> Self {
+        Self { case_fold: false }
+    }
+
+    /// NFC and lowercasing, for case-insensitive names.
+    pub fn case_insensitive() -> Self {
+        Self { case_fold: true }
+    }
+
+    /// Returns `name` normalized.
+    pub fn apply(&self, name: &str) -> String {
+        if self.case_fold {
+            name.to_lowercase().nfc().collect()
+        } else {
+            name.nfc().collect()
+        }
+    }
+}
+
+impl Row {
+    /// Returns the key whose name matches `name` once both are normalized.
+    /// An exact match is preferred; otherwise the first match in name order.
+    pub fn get_key_normalized(&self, name: &str, normalization: Normalization) -> Option<&Key> {
+        if let Some(key) = self.keys.get(name) {
+            return Some(key);
+        }
+
+        let name = normalization.apply(name);
+        self.keys.values().find(|key| normalization.apply(&key.name) == name)
+    }
+
+    /// Case-insensitive [`Row::get_key`] (see [`Normalization::case_insensitive`]).
+    pub fn get_key_ci(&self, name: &str) -> Option<&Key> {
+        self.get_key_normalized(name, Normalization::case_insensitive())
+    }
+
+    /// Renames every key to its normalized name. Keys whose names collide
+    /// are merged, the one last in the original name order winning.
+    pub fn normalize_names(&mut self, normalization: Normalization) {
+        let keys = std::mem::take(&mut self.keys);
+
+        for (_, mut key) in keys {
+            key.name = normalization.apply(&key.name);
+            self.keys.insert(key.name.clone(), key);
+        }
+    }
+}
+
+impl YAD {
+    /// Returns the row whose name matches `name` once both are normalized.
+    /// An exact match is preferred; otherwise the first match in name order.
+    pub fn get_row_normalized(&self, name: &str, normalization: Normalization) -> Option<&Row> {
+        if let Some(row) = self.rows.get(name) {
+            return Some(row);
+        }
+
+        let name = normalization.apply(name);
+        self.rows.values().find(|row| normalization.apply(&row.name) == name)
+    }
+
+    /// Case-insensitive [`YAD::get_row`] (see [`Normalization::case_insensitive`]).
+    pub fn get_row_ci(&self, name: &str) -> Option<&Row> {
+        self.get_row_normalized(name, Normalization::case_insensitive())
+    }
+
+    /// Like [`YAD::insert_row`], but normalizes the row name and its key
+    /// names first, replacing any row whose name normalizes to the same one.
+    ///
+    /// Inserting only through this method keeps names from differing just
+    /// by case or composition, so repeated ingestion does not create
+    /// duplicate rows.
+    pub fn insert_row_normalized<S: ToString>(&mut self, name: S, keys: Vec<Key>, normalization: Normalization) {
+        let name = normalization.apply(&name.to_string());
+
+        let clashes: Vec<String> = self.rows.keys()
+            .filter(|existing| normalization.apply(existing) == name)
+            .cloned()
+            .collect();
+        for existing in clashes {
+            self.remove_row(existing);
+        }
+
+        let mut row = Row::new(&name, keys);
+        row.normalize_names(normalization);
+        self.insert_row(name, row.keys.into_values().collect());
+    }
+
+    /// Renames every row and key to its normalized name, e.g. to clean up a
+    /// document written before names were normalized. Rows whose names
+    /// collide are merged key by key, and colliding keys keep the one last
+    /// in the original name order. Secondary indexes are rebuilt, keeping
+    /// the key names they were built for.
+    ///
+    /// Observers see a `RowRenamed` event for every renamed row, a
+    /// `RowRemoved` and a `RowUpdated` event for every row merged into
+    /// another, and a `RowUpdated` event for every row whose keys were renamed.
+    pub fn normalize_names(&mut self, normalization: Normalization) {
+        // Rows already holding their normalized name go first, so the rows
+        // merged into them are the renamed ones.
+        let (mut rows, renamed): (Vec<Row>, Vec<Row>) = std::mem::take(&mut self.rows)
+            .into_values()
+            .partition(|row| normalization.apply(&row.name) == row.name);
+        rows.extend(renamed);
+
+        for mut row in rows {
+            let name = normalization.apply(&row.name);
+            let old = std::mem::replace(&mut row.name, name.clone());
+            let keys_renamed = row.keys.keys().any(|name| normalization.apply(name) != *name);
+            row.normalize_names(normalization);
+
+            match self.rows.get_mut(&name) {
+                Some(existing) => {
+                    existing.keys.append(&mut row.keys);
+                    self.notify(|| ChangeEvent::RowRemoved { row: old });
+                    self.notify(|| ChangeEvent::RowUpdated { row: name });
+                }
+                None => {
+                    self.rows.insert(name.clone(), row);
+                    if old != name {
+                        self.notify(|| ChangeEvent::RowRenamed { old, new: name.clone() });
+                    }
+                    if keys_renamed {
+                        self.notify(|| ChangeEvent::RowUpdated { row: name });
+                    }
+                }
+            }
+        }
+
+        self.rebuild_indexes();
+    }
+}
@@ -0,0 +1,146 @@
+use std::fmt::{Debug, Formatter};
+use std::sync::Arc;
+use yad_core::constants::error::ErrorMessage;
+use yad_core::Value;
+use crate::error::ROW_NOT_FOUND;
+use crate::key::Key;
+use crate::YAD;
+
+/// A mutation made through the public methods of a [`YAD`], passed to the
+/// observers registered with [`YAD::on_change`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ChangeEvent {
+    /// A row was added under a name that was free.
+    RowInserted { row: String },
+    /// A row replaced, or was edited in place of, a row of the same name.
+    RowUpdated { row: String },
+    /// A row was removed.
+    RowRemoved { row: String },
+    /// A row was renamed.
+    RowRenamed { old: String, new: String },
+    /// A key was added to a row under a name that was free.
+    KeyInserted { row: String, key: String },
+    /// A key replaced a key of the same name.
+    KeyUpdated { row: String, key: String },
+    /// A key was removed from a row.
+    KeyRemoved { row: String, key: String },
+}
+
+/// Identifies an observer registered with [`YAD::on_change`].
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct ObserverId(u64);
+
+type Observer = Arc<dyn Fn(&ChangeEvent) + Send + Sync>;
+
+/// The observers of one document. Clones of a document start without any.
+#[derive(Default)]
+pub(crate) struct Observers {
+    next: u64,
+    list: Vec<(ObserverId, Observer)>,
+}
+
+impl Observers {
+    /// Copies the observer list, for a clone that replaces this document
+    /// rather than living beside it.
+    pub(crate) fn share(&self) -> Self {
+        Self { next: self.next, list: self.list.clone() }
+    }
+}
+
+impl Clone for Observers {
+    fn clone(&self) -> Self {
+        Self::default()
+    }
+}
+
+impl Debug for Observers {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} observers", self.list.len())
+    }
+}
+
+impl YAD {
+    /// Registers `observer`, called after every mutation made through the
+    /// public methods of the document: inserting, replacing, renaming,
+    /// moving and removing rows ([`YAD::insert_row`], [`YAD::adopt_row`],
+    /// [`YAD::rename_row`], [`YAD::remove_row`], ...), [`YAD::insert_key`]
+    /// and [`YAD::remove_key`], transactions, patches, [`YAD::merge`],
+    /// [`YAD::transform`] and `YAD::normalize_names`.
+    ///
+    /// Edits made directly through [`YAD::rows`], [`YAD::get_rows_mut`] or
+    /// [`YAD::get_row_mut`] are not seen.
+    ///
+    /// # Example
+    /// ```text
+    /// let id = yad.on_change(|event| println!("{:?}", event));
+    /// yad.insert_row("johan", vec![]);   // prints RowInserted { row: "johan" }
+    /// yad.remove_observer(id);
+    /// ```
+    pub fn on_change<F: Fn(&ChangeEvent) + Send + Sync + 'static>(&mut self, observer: F) -> ObserverId {
+        let id = ObserverId(self.observers.next);
+        self.observers.next += 1;
+        self.observers.list.push((id, Arc::new(observer)));
+        id
+    }
+
+    /// Unregisters an observer, returning `true` if it was registered.
+    pub fn remove_observer(&mut self, id: ObserverId) -> bool {
+        let before = self.observers.list.len();
+        self.observers.list.retain(|(observer, _)| *observer != id);
+        self.observers.list.len() != before
+    }
+
+    /// Calls every observer with the event built by `event`, which is only
+    /// built when there are observers.
+    pub(crate) fn notify(&self, event: impl FnOnce() -> ChangeEvent) {
+        if self.observers.list.is_empty() {
+            return;
+        }
+
+        let event = event();
+        for (_, observer) in &self.observers.list {
+            observer(&event);
+        }
+    }
+
+    /// Inserts (or replaces) a key in an existing row, keeping secondary
+    /// indexes in step.
+    ///
+    /// `value` is a [`Value`] or anything convertible to one (see [`crate::row::Row::insert_key`]).
+    ///
+    /// # Errors
+    /// Returns `ROW_NOT_FOUND` if the row does not exist, or the conversion
+    /// error; the document is then left unchanged.
+    pub fn insert_key<S: ToString, V>(&mut self, row: &str, name: S, value: V) -> Result<(), ErrorMessage>
+    where
+        V: TryInto<Value>,
+        V::Error: Into<ErrorMessage>,
+    {
+        let key = Key::try_new(name, value)?;
+        let mut target = self.detach_row(row).ok_or(ErrorMessage(ROW_NOT_FOUND))?;
+
+        let name = key.name.clone();
+        let replaced = target.keys.insert(name.clone(), key).is_some();
+        self.attach_row(target);
+
+        self.notify(|| match replaced {
+            true => ChangeEvent::KeyUpdated { row: row.to_string(), key: name },
+            false => ChangeEvent::KeyInserted { row: row.to_string(), key: name },
+        });
+
+        Ok(())
+    }
+
+    /// Removes a key from a row, keeping secondary indexes in step, and
+    /// returns it if it existed.
+    pub fn remove_key(&mut self, row: &str, name: &str) -> Option<Key> {
+        self.rows.get(row)?.keys.get(name)?;
+
+        let mut target = self.detach_row(row)?;
+        let key = target.keys.remove(name);
+        self.attach_row(target);
+
+        self.notify(|| ChangeEvent::KeyRemoved { row: row.to_string(), key: name.to_string() });
+        key
+    }
+}
@@ -0,0 +1,451 @@
+use std::collections::btree_map::Entry;
+use std::collections::BTreeMap;
+use yad_core::constants::error::{ErrorMessage, ALLOCATION_LIMIT_EXCEEDED};
+use yad_core::byte_order::ByteOrder;
+use yad_core::float::NonFinitePolicy;
+use yad_core::{Reader, Value};
+use crate::bloom::BloomFilter;
+use crate::checksum::{checksum_section, CHECKSUM_SECTION_LENGTH};
+use crate::compression::deflate;
+use crate::constants::MAGIC;
+use crate::error::{DUPLICATE_NAME, MISSING_MAGIC};
+use crate::header::{has_magic, HeaderFlags};
+use crate::projection::Projection;
+use crate::row::Row;
+use crate::schema::Schema;
+use crate::delta::body_to_delta;
+use crate::dictionary::body_to_dictionary;
+use crate::varint::body_to_varint;
+use crate::YAD;
+
+/// Options controlling how a [`YAD`] document is serialized, passed to
+/// [`YAD::serialize_with_options`].
+///
+/// The default options produce exactly the same bytes as [`YAD::serialize`].
+/// There is no `sort_keys` option: rows and keys are stored in name order, so
+/// they are always written in ascending name order, which canonical
+/// serialization and signatures rely on. The byte order of numbers is set
+/// with `byte_order`.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct SerializeOptions {
+    /// Writes the inferred [`Schema`] into a reserved section right after the
+    /// version header, so readers can validate and present typed views of the
+    /// document without receiving the schema separately.
+    ///
+    /// Nothing is written when the document has no keys.
+    pub embed_schema: bool,
+    /// How NaN and infinite floats are written (see [`NonFinitePolicy`]).
+    ///
+    /// `Reject` makes serialization fail on the first non-finite float;
+    /// `Canonicalize` rewrites them in the output without touching the document.
+    pub non_finite: NonFinitePolicy,
+    /// Writes a header flags section right after the version header, so tools
+    /// can sniff the file's properties with [`crate::header::Header::read`].
+    ///
+    /// The flags are written as given; they should describe the output.
+    /// [`HeaderFlags::LITTLE_ENDIAN`], [`HeaderFlags::VARINT_LENGTHS`],
+    /// [`HeaderFlags::DICTIONARY`], [`HeaderFlags::DELTA_ARRAYS`],
+    /// [`HeaderFlags::CHECKSUMMED`] and [`HeaderFlags::COMPRESSED`] are set or
+    /// cleared from `byte_order`, `length_encoding`, `dictionary`,
+    /// `delta_arrays`, `include_checksum` and `compression`.
+    pub header_flags: Option<HeaderFlags>,
+    /// The byte order of number payloads (see [`ByteOrder`]).
+    ///
+    /// `Little` writes a header flags section with [`HeaderFlags::LITTLE_ENDIAN`]
+    /// set, so every reader decodes the numbers back; readers from before the
+    /// flag existed would misread them.
+    pub byte_order: ByteOrder,
+    /// How the length descriptors of names, strings and arrays are written
+    /// (see [`LengthEncoding`]).
+    pub length_encoding: LengthEncoding,
+    /// Writes every key name used more than once into a dictionary section
+    /// right before the rows and replaces it with an index into that section,
+    /// so wide tables spell each column name once.
+    ///
+    /// Sets [`HeaderFlags::DICTIONARY`] when a name repeats; readers from
+    /// before the flag existed cannot read the rows.
+    pub dictionary: bool,
+    /// Writes arrays of unsigned integers of one width, such as those built by
+    /// [`yad_core::Value::from_sorted_u64s`], as their first value followed by
+    /// zig-zag varint deltas whenever that is shorter; time series and sorted
+    /// ids shrink the most.
+    ///
+    /// Sets [`HeaderFlags::DELTA_ARRAYS`]; readers from before the flag
+    /// existed cannot read the rows.
+    pub delta_arrays: bool,
+    /// Writes a [`BloomFilter`] over the row names right after the schema
+    /// section, so [`crate::borrowed::YadRef::row`] can answer for missing
+    /// rows without walking the document.
+    ///
+    /// Nothing is written when the document has no rows.
+    pub bloom_filter: bool,
+    /// Re-encodes every value with the smallest length descriptor that fits,
+    /// like [`YAD::serialize_canonical`] does, for documents holding values
+    /// decoded from other writers or built with wide descriptors.
+    pub minimal_lengths: bool,
+    /// Writes a checksum section holding the CRC-32 of the rest of the file
+    /// right after the bloom filter section, and sets
+    /// [`HeaderFlags::CHECKSUMMED`]. [`YAD::deserialize`] rejects a document
+    /// that does not match it with `CHECKSUM_MISMATCH`; the borrowed and
+    /// streaming readers skip it.
+    pub include_checksum: bool,
+    /// How the dictionary section and the rows are compressed (see [`Compression`]).
+    pub compression: Compression,
+}
+
+/// How the body of a document, its dictionary section and rows, is compressed.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum Compression {
+    /// The body is written as is.
+    #[default]
+    None,
+    /// The body is written as one raw deflate stream. Sets
+    /// [`HeaderFlags::COMPRESSED`]; writing and reading such documents needs
+    /// the `compression` feature, and [`crate::borrowed::YadRef`] cannot read them.
+    Deflate,
+}
+
+/// How the length descriptors of row names, key names, strings and arrays
+/// are written.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum LengthEncoding {
+    /// A big-endian descriptor of 1, 2, 4 or 8 bytes, its width given by the
+    /// low nibble of the header byte.
+    #[default]
+    Fixed,
+    /// An LEB128 varint, so lengths under 128 take one byte and any length
+    /// fits. The header byte keeps the nibble of the fixed width. Sets
+    /// [`HeaderFlags::VARINT_LENGTHS`]; readers from before the flag existed
+    /// cannot read the rows. Metadata blocks are unchanged.
+    Varint,
+}
+
+/// Options controlling how a [`YAD`] document is decoded, passed to
+/// [`YAD::deserialize_with_options`]: the safety limits and policies of the decoder.
+///
+/// The default options decode exactly like [`YAD::deserialize`].
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct DecodeOptions {
+    /// How NaN and infinite floats read from the input are handled (see [`NonFinitePolicy`]).
+    pub non_finite: NonFinitePolicy,
+    /// Rejects input that does not start with the magic number (`MISSING_MAGIC`)
+    /// instead of accepting it as a file written before the magic number existed.
+    pub require_magic: bool,
+    /// What happens when two rows, or two keys of a row, share a name (see [`DuplicatePolicy`]).
+    pub duplicates: DuplicatePolicy,
+    /// Rows and keys to decode; the others are skipped by their length
+    /// descriptors without decoding their values (see [`Projection`]).
+    pub projection: Projection,
+    /// Largest document, in bytes, the decoder may allocate: both the input
+    /// and its body once varint lengths, dictionary references and delta
+    /// arrays are expanded must fit, or decoding stops with
+    /// `ALLOCATION_LIMIT_EXCEEDED`. `None` sets no limit.
+    pub max_size: Option<usize>,
+    /// Deepest nesting of arrays a value may have, or decoding stops with
+    /// `NESTING_TOO_DEEP`: `Some(0)` rejects arrays and `Some(1)` allows
+    /// arrays of scalars only. `None` keeps the decoder's own bound.
+    pub max_depth: Option<usize>,
+    /// Rejects bytes outside of any row, between rows or after the last one,
+    /// with `STRAY_BYTES` instead of skipping them.
+    pub strict: bool,
+}
+
+/// The name [`DecodeOptions`] goes by next to [`SerializeOptions`].
+pub type DeserializeOptions = DecodeOptions;
+
+impl DecodeOptions {
+    /// Options that decode only the keys named in `keys`, in every row.
+    ///
+    /// # Example
+    /// ```text
+    /// let yad = YAD::deserialize_with_options(bytes, &DecodeOptions::project(&["name", "age"]))?;
+    /// ```
+    pub fn project<S: ToString>(keys: &[S]) -> Self {
+        Self { projection: Projection::all().with_keys(keys), ..Self::default() }
+    }
+
+    /// Options that reject documents larger than `max_size` bytes (see [`DecodeOptions::max_size`]).
+    pub fn limited(max_size: usize) -> Self {
+        Self { max_size: Some(max_size), ..Self::default() }
+    }
+
+    /// Options that reject anything the default options tolerate: stray
+    /// bytes, a missing magic number and names read twice.
+    pub fn strict() -> Self {
+        Self { strict: true, require_magic: true, duplicates: DuplicatePolicy::Error, ..Self::default() }
+    }
+
+    /// Returns a reader over `bytes` that stops decoding at [`DecodeOptions::max_depth`].
+    pub(crate) fn reader<'a>(&self, bytes: &'a [u8]) -> Reader<'a> {
+        match self.max_depth {
+            Some(max_depth) => Reader::new(bytes).with_max_depth(max_depth),
+            None => Reader::new(bytes),
+        }
+    }
+
+    /// Applies [`DecodeOptions::non_finite`] to a decoded row.
+    fn check_row(&self, row: Row) -> Result<Row, ErrorMessage> {
+        match self.non_finite {
+            NonFinitePolicy::Allow => Ok(row),
+            policy => row_with_policy(&row, policy),
+        }
+    }
+
+    /// Returns `ALLOCATION_LIMIT_EXCEEDED` if `len` bytes exceed [`DecodeOptions::max_size`].
+    pub(crate) fn check_size(&self, len: usize) -> Result<(), ErrorMessage> {
+        match self.max_size {
+            Some(max) if len > max => Err(ErrorMessage(ALLOCATION_LIMIT_EXCEEDED)),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// What decoding does when two rows of a document, or two keys of a row,
+/// share a name.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum DuplicatePolicy {
+    /// Rejects the input with `DUPLICATE_NAME`.
+    Error,
+    /// Keeps the first one read and drops the later ones.
+    KeepFirst,
+    /// Keeps the last one read, overwriting the earlier ones. This is what
+    /// [`YAD::deserialize`] does.
+    #[default]
+    KeepLast,
+    /// Keeps every one, renaming the second `name#2`, the third `name#3` and
+    /// so on, skipping suffixes that are already taken.
+    KeepAllWithSuffix,
+}
+
+impl DuplicatePolicy {
+    /// Inserts `item` into `map` under `name`, resolving a clash with an
+    /// existing entry according to the policy. `rename` is called with the
+    /// new name when [`DuplicatePolicy::KeepAllWithSuffix`] renames the item.
+    pub(crate) fn insert<T>(self, map: &mut BTreeMap<String, T>, name: String, mut item: T, rename: impl FnOnce(&mut T, &str)) -> Result<(), ErrorMessage> {
+        let base = match map.entry(name) {
+            Entry::Vacant(entry) => {
+                entry.insert(item);
+                return Ok(());
+            }
+            Entry::Occupied(mut entry) => match self {
+                DuplicatePolicy::Error => return Err(ErrorMessage(DUPLICATE_NAME)),
+                DuplicatePolicy::KeepFirst => return Ok(()),
+                DuplicatePolicy::KeepLast => {
+                    entry.insert(item);
+                    return Ok(());
+                }
+                DuplicatePolicy::KeepAllWithSuffix => entry.key().clone(),
+            },
+        };
+
+        let name = (2..)
+            .map(|n| format!("{}#{}", base, n))
+            .find(|candidate| !map.contains_key(candidate))
+            .expect("a free suffix exists");
+
+        rename(&mut item, &name);
+        map.insert(name, item);
+
+        Ok(())
+    }
+}
+
+/// Applies `policy` to every value of `row`.
+fn row_with_policy(row: &Row, policy: NonFinitePolicy) -> Result<Row, ErrorMessage> {
+    let mut row = row.clone();
+    for key in row.keys.values_mut() {
+        key.try_map_values(|value| value.with_non_finite_policy(policy))?;
+    }
+    Ok(row)
+}
+
+/// Converts the number payloads of every value of `row` to or from `order`.
+pub(crate) fn convert_byte_order(row: &mut Row, order: ByteOrder) -> Result<(), ErrorMessage> {
+    if order == ByteOrder::Big {
+        return Ok(());
+    }
+
+    for key in row.keys.values_mut() {
+        key.try_map_values(|value| value.with_byte_order(order))?;
+    }
+    Ok(())
+}
+
+impl YAD {
+    /// Serializes the YAD document to bytes using the given options.
+    ///
+    /// # Returns
+    /// - `Ok(Vec<u8>)`: The serialized document.
+    /// - `Err(ErrorMessage)`: If a row, key or the schema cannot be encoded, or
+    ///   `COMPRESSION_NOT_SUPPORTED` if compression is requested without the `compression` feature.
+    ///
+    /// # Example
+    /// ```text
+    /// let bytes = yad.serialize_with_options(&SerializeOptions {
+    ///     minimal_lengths: true,
+    ///     include_checksum: true,
+    ///     compression: Compression::Deflate,
+    ///     byte_order: ByteOrder::Little,
+    ///     ..SerializeOptions::default()
+    /// })?;
+    /// ```
+    pub fn serialize_with_options(&self, options: &SerializeOptions) -> Result<Vec<u8>, ErrorMessage> {
+        let mut body: Vec<u8> = Vec::with_capacity(self.encoded_len());
+
+        for row in self.rows.values() {
+            if options.non_finite == NonFinitePolicy::Allow && options.byte_order == ByteOrder::Big && !options.minimal_lengths {
+                row.write_into(&mut body)?
+            } else {
+                let mut row = row_with_policy(row, options.non_finite)?;
+                if options.minimal_lengths {
+                    for key in row.keys.values_mut() {
+                        key.try_map_values(|value| Value::decode_in_place(&value.bytes))?;
+                    }
+                }
+                convert_byte_order(&mut row, options.byte_order)?;
+                row.write_into(&mut body)?
+            }
+        }
+
+        let mut flags = options.header_flags.unwrap_or_default();
+        flags.remove(HeaderFlags::LITTLE_ENDIAN.with(HeaderFlags::VARINT_LENGTHS).with(HeaderFlags::DICTIONARY).with(HeaderFlags::DELTA_ARRAYS));
+        flags.remove(HeaderFlags::CHECKSUMMED.with(HeaderFlags::COMPRESSED));
+        if options.include_checksum {
+            flags.insert(HeaderFlags::CHECKSUMMED);
+        }
+        if options.compression == Compression::Deflate {
+            flags.insert(HeaderFlags::COMPRESSED);
+        }
+        if options.byte_order == ByteOrder::Little {
+            flags.insert(HeaderFlags::LITTLE_ENDIAN);
+        }
+        if options.length_encoding == LengthEncoding::Varint {
+            flags.insert(HeaderFlags::VARINT_LENGTHS);
+        }
+
+        // The dictionary section stays in fixed encoding; only the rows after it are transcoded.
+        let section = if options.dictionary { body_to_dictionary(&mut body)? } else { None };
+        if section.is_some() {
+            flags.insert(HeaderFlags::DICTIONARY);
+        }
+        let section = section.unwrap_or_default();
+
+        if options.delta_arrays {
+            body = body_to_delta(&body, options.byte_order)?;
+            flags.insert(HeaderFlags::DELTA_ARRAYS);
+        }
+
+        if options.length_encoding == LengthEncoding::Varint {
+            body = body_to_varint(&body)?;
+        }
+
+        let mut payload = section;
+        payload.extend_from_slice(&body);
+        if options.compression == Compression::Deflate {
+            payload = deflate(&payload)?;
+        }
+
+        let mut bytes: Vec<u8> = Vec::with_capacity(MAGIC.len() + 5 + CHECKSUM_SECTION_LENGTH + payload.len());
+
+        bytes.extend_from_slice(&MAGIC);
+        bytes.extend_from_slice(&self.version.serialize());
+
+        if options.header_flags.is_some() || !flags.is_empty() {
+            bytes.extend_from_slice(flags.serialize().as_slice());
+        }
+
+        if options.embed_schema {
+            let schema = Schema::infer(self);
+            if !schema.is_empty() {
+                bytes.extend_from_slice(schema.serialize()?.as_slice());
+            }
+        }
+
+        if options.bloom_filter && !self.rows.is_empty() {
+            let filter = BloomFilter::from_names(self.rows.keys().map(String::as_str));
+            bytes.extend_from_slice(filter.serialize()?.as_slice());
+        }
+
+        if options.include_checksum {
+            bytes.extend_from_slice(&checksum_section(&payload));
+        }
+
+        bytes.extend_from_slice(&payload);
+
+        Ok(bytes)
+    }
+
+    /// Serializes the document with repeated key names written once in a
+    /// dictionary section (see [`SerializeOptions::dictionary`]). Every
+    /// reader that copies the input resolves the references transparently.
+    ///
+    /// # Errors
+    /// Returns `ErrorMessage` if a row or key cannot be encoded.
+    pub fn serialize_with_dictionary(&self) -> Result<Vec<u8>, ErrorMessage> {
+        self.serialize_with_options(&SerializeOptions { dictionary: true, ..SerializeOptions::default() })
+    }
+
+    /// Deserializes a YAD document using the given options.
+    ///
+    /// # Returns
+    /// - `Ok(YAD)`: The decoded document.
+    /// - `Err(ErrorMessage)`: If [`YAD::deserialize`] fails, the magic number is
+    ///   missing while [`DecodeOptions::require_magic`] is set (`MISSING_MAGIC`), or a
+    ///   value is rejected by [`DecodeOptions::non_finite`] (`NAN_NOT_ALLOWED`, `INFINITY_NOT_ALLOWED`),
+    ///   or two rows or keys share a name under [`DuplicatePolicy::Error`] (`DUPLICATE_NAME`),
+    ///   or the document is larger than [`DecodeOptions::max_size`] (`ALLOCATION_LIMIT_EXCEEDED`),
+    ///   or a value nests arrays deeper than [`DecodeOptions::max_depth`] (`NESTING_TOO_DEEP`),
+    ///   or bytes lie outside of any row while [`DecodeOptions::strict`] is set (`STRAY_BYTES`).
+    ///
+    /// # Example
+    /// ```text
+    /// let yad = YAD::deserialize_with_options(bytes, &DeserializeOptions {
+    ///     max_size: Some(16 << 20),
+    ///     max_depth: Some(4),
+    ///     duplicates: DuplicatePolicy::Error,
+    ///     strict: true,
+    ///     projection: Projection::all().with_keys(&["name", "age"]),
+    ///     ..DeserializeOptions::default()
+    /// })?;
+    /// ```
+    pub fn deserialize_with_options(bytes: Vec<u8>, options: &DecodeOptions) -> Result<Self, ErrorMessage> {
+        if options.require_magic && !has_magic(&bytes) {
+            return Err(ErrorMessage(MISSING_MAGIC));
+        }
+
+        let mut yad = Self::decode_with(bytes, options)?;
+
+        if options.non_finite != NonFinitePolicy::Allow {
+            let rows = std::mem::take(&mut yad.rows);
+            for (name, row) in rows {
+                yad.rows.insert(name, options.check_row(row)?);
+            }
+        }
+
+        Ok(yad)
+    }
+}
+
+impl Row {
+    /// Deserializes a [`Row`] like [`Row::deserialize`], resolving keys that
+    /// share a name according to `duplicates`.
+    ///
+    /// # Errors
+    /// Returns `DUPLICATE_NAME` if two keys share a name under
+    /// [`DuplicatePolicy::Error`], or any error [`Row::deserialize`] returns.
+    pub fn deserialize_with_policy(bytes: Vec<u8>, duplicates: DuplicatePolicy) -> Result<Self, ErrorMessage> {
+        Self::deserialize_with(bytes, &DecodeOptions { duplicates, ..DecodeOptions::default() })
+    }
+
+    /// Deserializes a [`Row`] using the given options. Keys outside
+    /// [`DecodeOptions::projection`] are skipped without being decoded.
+    ///
+    /// # Errors
+    /// Returns `ROW_NOT_FOUND` if the projection does not select the row, or
+    /// any error [`Row::deserialize_with_policy`], [`DecodeOptions::non_finite`]
+    /// or [`DecodeOptions::max_depth`] returns.
+    pub fn deserialize_with_options(bytes: Vec<u8>, options: &DecodeOptions) -> Result<Self, ErrorMessage> {
+        let row = Self::deserialize_with(bytes, options)?;
+        options.check_row(row)
+    }
+}
@@ -0,0 +1,273 @@
+use yad_core::constants::error::ErrorMessage;
+use yad_core::Value;
+use crate::constants::{
+    KEY_NAME_HEADER,
+    PATCH_ADD_ROW,
+    PATCH_CHANGE_ROW,
+    PATCH_HEADER,
+    PATCH_REMOVE_KEY,
+    PATCH_REMOVE_ROW,
+    PATCH_ROW_END,
+    PATCH_SET_KEY,
+    PATCH_SET_ROW_META,
+    ROW_NAME_HEADER,
+};
+use crate::diff::YadDiff;
+use crate::error::{MALFORMED_PATCH, PATCH_TARGET_ROW_MISSING};
+use crate::key::Key;
+use crate::meta::Meta;
+use crate::observe::ChangeEvent;
+use crate::row::Row;
+use crate::{decode_name, encode_name, YAD};
+
+/// A single key operation inside a row operation of a patch.
+enum KeyOp {
+    Set(Key),
+    Remove(String),
+}
+
+/// A single row operation of a patch.
+enum RowOp {
+    Add(String, Meta, Vec<KeyOp>),
+    Change(String, Option<Meta>, Vec<KeyOp>),
+    Remove(String),
+}
+
+/// Appends a set-key operation to `bytes`, with the key's metadata and every value.
+fn push_set_key(bytes: &mut Vec<u8>, key: &Key) -> Result<(), ErrorMessage> {
+    bytes.push(PATCH_SET_KEY);
+    bytes.extend_from_slice(encode_name(&key.name, KEY_NAME_HEADER)?.as_slice());
+    key.meta.write_into(bytes);
+    for value in key.values() {
+        bytes.extend_from_slice(value.bytes.as_slice());
+    }
+    Ok(())
+}
+
+/// Appends a remove-key operation to `bytes`.
+fn push_remove_key(bytes: &mut Vec<u8>, name: &String) -> Result<(), ErrorMessage> {
+    bytes.push(PATCH_REMOVE_KEY);
+    bytes.extend_from_slice(encode_name(name, KEY_NAME_HEADER)?.as_slice());
+    Ok(())
+}
+
+impl YadDiff {
+    /// Serializes the diff into the compact binary patch format.
+    ///
+    /// Only the information needed to go from the base document to the
+    /// compared one is written: removed rows and keys are stored by name and
+    /// added or changed keys by their new value.
+    ///
+    /// # Layout
+    /// ```text
+    /// PATCH_HEADER
+    /// PATCH_ADD_ROW    <row name> [meta] (PATCH_SET_KEY <key name> [meta] <value>+)* PATCH_ROW_END
+    /// PATCH_REMOVE_ROW <row name>
+    /// PATCH_CHANGE_ROW <row name> [PATCH_SET_ROW_META [meta]] (PATCH_SET_KEY <key name> [meta] <value>+ | PATCH_REMOVE_KEY <key name>)* PATCH_ROW_END
+    /// ```
+    ///
+    /// Set-key operations carry the whole key: its metadata block, if any, and
+    /// every value of a multi-value key. No value header starts with a patch
+    /// operation byte, so the values of a key end at the next operation.
+    ///
+    /// Added rows carry their metadata block right after the name. Changed rows
+    /// carry it behind `PATCH_SET_ROW_META` only when their metadata changed; an
+    /// absent block there clears it.
+    ///
+    /// # Returns
+    /// - `Ok(Vec<u8>)`: The encoded patch, to be applied with [`YAD::apply_patch`].
+    /// - `Err(ErrorMessage)`: If a name cannot be encoded.
+    pub fn serialize(&self) -> Result<Vec<u8>, ErrorMessage> {
+        let mut bytes: Vec<u8> = vec![PATCH_HEADER];
+
+        for row in &self.added_rows {
+            bytes.push(PATCH_ADD_ROW);
+            bytes.extend_from_slice(encode_name(&row.name, ROW_NAME_HEADER)?.as_slice());
+            row.meta.write_into(&mut bytes);
+            for key in row.keys.values() {
+                push_set_key(&mut bytes, key)?;
+            }
+            bytes.push(PATCH_ROW_END);
+        }
+
+        for row in &self.removed_rows {
+            bytes.push(PATCH_REMOVE_ROW);
+            bytes.extend_from_slice(encode_name(&row.name, ROW_NAME_HEADER)?.as_slice());
+        }
+
+        for row in &self.changed_rows {
+            bytes.push(PATCH_CHANGE_ROW);
+            bytes.extend_from_slice(encode_name(&row.name, ROW_NAME_HEADER)?.as_slice());
+            if let Some(change) = &row.changed_meta {
+                bytes.push(PATCH_SET_ROW_META);
+                change.after.write_into(&mut bytes);
+            }
+            for key in &row.added_keys {
+                push_set_key(&mut bytes, key)?;
+            }
+            for change in &row.changed_keys {
+                push_set_key(&mut bytes, &change.after)?;
+            }
+            for key in &row.removed_keys {
+                push_remove_key(&mut bytes, &key.name)?;
+            }
+            bytes.push(PATCH_ROW_END);
+        }
+
+        Ok(bytes)
+    }
+}
+
+/// Reads a name at `pos`, advancing it past the name.
+fn read_name(bytes: &[u8], pos: &mut usize, header: u8) -> Result<String, ErrorMessage> {
+    let (name, consumed) = decode_name(bytes.get(*pos..).unwrap_or(&[]), header)
+        .ok_or(ErrorMessage(MALFORMED_PATCH))?;
+    *pos += consumed;
+    Ok(name)
+}
+
+/// Reads a value at `pos`, advancing it past the value.
+fn read_value(bytes: &[u8], pos: &mut usize) -> Result<Value, ErrorMessage> {
+    let value = Value::decode_in_place(bytes.get(*pos..).unwrap_or(&[])).map_err(|_| ErrorMessage(MALFORMED_PATCH))?;
+    *pos += value.bytes.len();
+    Ok(value)
+}
+
+/// Reads a metadata block at `pos`, if there is one, advancing it past the block.
+fn read_meta(bytes: &[u8], pos: &mut usize) -> Result<Meta, ErrorMessage> {
+    let (meta, consumed) = Meta::decode(bytes.get(*pos..).unwrap_or(&[])).map_err(|_| ErrorMessage(MALFORMED_PATCH))?;
+    *pos += consumed;
+    Ok(meta)
+}
+
+/// Reads key operations at `pos` until `PATCH_ROW_END`, advancing past it.
+fn read_key_ops(bytes: &[u8], pos: &mut usize) -> Result<Vec<KeyOp>, ErrorMessage> {
+    let mut ops: Vec<KeyOp> = vec![];
+
+    loop {
+        let op = *bytes.get(*pos).ok_or(ErrorMessage(MALFORMED_PATCH))?;
+        *pos += 1;
+
+        match op {
+            PATCH_ROW_END => return Ok(ops),
+            PATCH_SET_KEY => {
+                let name = read_name(bytes, pos, KEY_NAME_HEADER)?;
+                let meta = read_meta(bytes, pos)?;
+
+                let mut key = Key::new(name, read_value(bytes, pos)?);
+                key.meta = meta;
+                while !matches!(bytes.get(*pos), None | Some(&PATCH_SET_KEY | &PATCH_REMOVE_KEY | &PATCH_ROW_END)) {
+                    key.push_value(read_value(bytes, pos)?);
+                }
+                ops.push(KeyOp::Set(key));
+            }
+            PATCH_REMOVE_KEY => ops.push(KeyOp::Remove(read_name(bytes, pos, KEY_NAME_HEADER)?)),
+            _ => return Err(ErrorMessage(MALFORMED_PATCH)),
+        }
+    }
+}
+
+/// Parses a whole patch into row operations without touching any document.
+fn parse_patch(bytes: &[u8]) -> Result<Vec<RowOp>, ErrorMessage> {
+    if bytes.first() != Some(&PATCH_HEADER) {
+        return Err(ErrorMessage(MALFORMED_PATCH));
+    }
+
+    let mut ops: Vec<RowOp> = vec![];
+    let mut pos = 1;
+
+    while pos < bytes.len() {
+        let op = bytes[pos];
+        pos += 1;
+
+        let name = read_name(bytes, &mut pos, ROW_NAME_HEADER)?;
+
+        ops.push(match op {
+            PATCH_ADD_ROW => {
+                let meta = read_meta(bytes, &mut pos)?;
+                RowOp::Add(name, meta, read_key_ops(bytes, &mut pos)?)
+            }
+            PATCH_CHANGE_ROW => {
+                let meta = match bytes.get(pos) == Some(&PATCH_SET_ROW_META) {
+                    true => {
+                        pos += 1;
+                        Some(read_meta(bytes, &mut pos)?)
+                    }
+                    false => None,
+                };
+                RowOp::Change(name, meta, read_key_ops(bytes, &mut pos)?)
+            }
+            PATCH_REMOVE_ROW => RowOp::Remove(name),
+            _ => return Err(ErrorMessage(MALFORMED_PATCH)),
+        });
+    }
+
+    Ok(ops)
+}
+
+/// Applies key operations to a row.
+fn apply_key_ops(row: &mut Row, ops: Vec<KeyOp>) {
+    for op in ops {
+        match op {
+            KeyOp::Set(key) => {
+                row.keys.insert(key.name.clone(), key);
+            }
+            KeyOp::Remove(name) => {
+                row.keys.remove(&name);
+            }
+        }
+    }
+}
+
+impl YAD {
+    /// Applies a patch produced by [`YadDiff::serialize`] to this document.
+    ///
+    /// The whole patch is validated before any change is made, so a malformed
+    /// patch leaves the document untouched.
+    ///
+    /// # Errors
+    /// - `MALFORMED_PATCH` if the bytes are not a valid patch.
+    /// - `PATCH_TARGET_ROW_MISSING` if a changed row does not exist in this document.
+    pub fn apply_patch(&mut self, patch: &[u8]) -> Result<(), ErrorMessage> {
+        let ops = parse_patch(patch)?;
+
+        for op in &ops {
+            if let RowOp::Change(name, _, _) = op && !self.rows.contains_key(name) {
+                return Err(ErrorMessage(PATCH_TARGET_ROW_MISSING));
+            }
+        }
+
+        for op in ops {
+            match op {
+                RowOp::Add(name, meta, key_ops) => {
+                    let mut row = Row::new_empty(&name);
+                    row.meta = meta;
+                    apply_key_ops(&mut row, key_ops);
+                    let replaced = self.rows.insert(name.clone(), row).is_some();
+                    self.notify(|| match replaced {
+                        true => ChangeEvent::RowUpdated { row: name },
+                        false => ChangeEvent::RowInserted { row: name },
+                    });
+                }
+                RowOp::Change(name, meta, key_ops) => {
+                    if let Some(row) = self.rows.get_mut(&name) {
+                        if let Some(meta) = meta {
+                            row.meta = meta;
+                        }
+                        apply_key_ops(row, key_ops);
+                        self.notify(|| ChangeEvent::RowUpdated { row: name });
+                    }
+                }
+                RowOp::Remove(name) => {
+                    if self.rows.remove(&name).is_some() {
+                        self.notify(|| ChangeEvent::RowRemoved { row: name });
+                    }
+                }
+            }
+        }
+
+        self.rebuild_indexes();
+
+        Ok(())
+    }
+}
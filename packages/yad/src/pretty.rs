@@ -0,0 +1,94 @@
+use std::fmt::Write;
+use yad_core::constants::types::Type;
+use yad_core::Value;
+use crate::meta::Meta;
+use crate::YAD;
+
+/// Formats a value for the pretty printer.
+///
+/// Numbers carry their width suffix (`17u8`, `1.5f32`), strings are quoted
+/// and escaped, and arrays are written inline. Values that cannot be decoded
+/// are written as `<malformed 0x..>` with their raw bytes.
+fn pretty_value(value: &Value) -> String {
+    let malformed = || {
+        let hex: String = value.bytes.iter().map(|b| format!("{:02X}", b)).collect();
+        format!("<malformed 0x{}>", hex)
+    };
+    let width = value.length.as_byte_count() * 8;
+
+    match value.r#type {
+        Type::Uint => format!("{}u{}", value, width),
+        Type::Int => format!("{}i{}", value, width),
+        Type::Float => format!("{}f{}", value, width),
+        Type::Bool | Type::True | Type::False => format!("{}", value),
+        Type::Extension if value.is_decimal() => match value.as_decimal() {
+            Ok(decimal) => format!("{}d", decimal),
+            Err(_) => malformed(),
+        },
+        Type::Extension if value.is_big_integer() => match value.as_big_integer() {
+            Ok(big) => format!("{}n", big),
+            Err(_) => malformed(),
+        },
+        Type::Extension => value.try_display().unwrap_or_else(|_| malformed()),
+        Type::String => match TryInto::<String>::try_into(value.clone()) {
+            Ok(text) => format!("{:?}", text),
+            Err(_) => malformed(),
+        },
+        Type::Array => match TryInto::<Vec<Value>>::try_into(value.clone()) {
+            Ok(items) => {
+                let items: Vec<String> = items.iter().map(pretty_value).collect();
+                format!("[{}]", items.join(", "))
+            }
+            Err(_) => malformed(),
+        },
+    }
+}
+
+/// Formats a metadata block as ` meta { tag: 0xBYTES, ... }`, or nothing when empty.
+fn pretty_meta(meta: &Meta) -> String {
+    if meta.is_empty() {
+        return String::new();
+    }
+
+    let entries: Vec<String> = meta.entries.iter()
+        .map(|(tag, value)| {
+            let hex: String = value.iter().map(|b| format!("{:02X}", b)).collect();
+            format!("{:#04x}: 0x{}", tag, hex)
+        })
+        .collect();
+
+    format!(" meta {{ {} }}", entries.join(", "))
+}
+
+impl YAD {
+    /// Renders the document as a human-readable tree, indenting each level by
+    /// `indent` spaces.
+    ///
+    /// The output is stable: rows and keys are listed in name order, and each
+    /// value is shown with its width (`17u8`, `1.5f32`, `"text"`). Metadata is
+    /// shown as raw tagged bytes.
+    ///
+    /// # Example
+    /// ```text
+    /// YAD 1.0.0-0
+    ///   row "johan"
+    ///     "age" = 17u8
+    ///     "name" = "Johan"
+    /// ```
+    pub fn to_pretty_string(&self, indent: usize) -> String {
+        let pad = " ".repeat(indent);
+        let mut out = String::new();
+
+        let _ = writeln!(out, "YAD {}", self.version);
+
+        for row in self.rows.values() {
+            let _ = writeln!(out, "{}row {:?}{}", pad, row.name, pretty_meta(&row.meta));
+
+            for key in row.keys.values() {
+                let _ = writeln!(out, "{}{}{:?} = {}{}", pad, pad, key.name, pretty_value(&key.value), pretty_meta(&key.meta));
+            }
+        }
+
+        out
+    }
+}
@@ -0,0 +1,173 @@
+use std::collections::BTreeSet;
+use yad_core::constants::error::ErrorMessage;
+use yad_core::{Reader, Value};
+use crate::constants::{KEY_END_HEADER, KEY_NAME_HEADER, KEY_START_HEADER, ROW_END_HEADER, ROW_NAME_HEADER, ROW_START_HEADER};
+use crate::borrowed::read_values;
+use crate::error::{MALFORMED_KEY_NAME_VECTOR, MALFORMED_ROW_NAME_VECTOR, MALFORMED_ROW_VECTOR};
+use crate::compression::MAX_INFLATED_SIZE;
+use crate::header::{body_start, decode_body, Header};
+use crate::key::Key;
+use crate::row::Row;
+use crate::meta::Meta;
+use crate::options::convert_byte_order;
+use crate::{decode_name, YAD};
+
+/// Selects which rows and keys [`YAD::deserialize_projected`] decodes.
+///
+/// `None` selects everything. Rows and keys outside the projection are
+/// skipped using their length descriptors: their names are read, but their
+/// values are never decoded or copied.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Projection {
+    /// Names of the rows to decode, or `None` for every row.
+    pub rows: Option<BTreeSet<String>>,
+    /// Names of the keys to decode in each selected row, or `None` for every key.
+    pub keys: Option<BTreeSet<String>>,
+}
+
+impl Projection {
+    /// A projection selecting every row and key.
+    pub fn all() -> Self {
+        Self::default()
+    }
+
+    /// Restricts the projection to the given rows.
+    pub fn with_rows<S: ToString>(mut self, rows: &[S]) -> Self {
+        self.rows = Some(rows.iter().map(|r| r.to_string()).collect());
+        self
+    }
+
+    /// Restricts the projection to the given keys.
+    pub fn with_keys<S: ToString>(mut self, keys: &[S]) -> Self {
+        self.keys = Some(keys.iter().map(|k| k.to_string()).collect());
+        self
+    }
+
+    /// Returns `true` if the row named `name` is selected.
+    pub fn includes_row(&self, name: &str) -> bool {
+        self.rows.as_ref().is_none_or(|rows| rows.contains(name))
+    }
+
+    /// Returns `true` if keys named `name` are selected.
+    pub fn includes_key(&self, name: &str) -> bool {
+        self.keys.as_ref().is_none_or(|keys| keys.contains(name))
+    }
+}
+
+/// Reads a key starting at `pos` (on its start header), decoding its value only if `decode` is set.
+///
+/// # Returns
+/// - `Ok((Option<Key>, usize))`: The key (`None` when skipped) and the position after its end header.
+fn read_key(bytes: &[u8], mut pos: usize, projection: &Projection, decode: bool) -> Result<(Option<Key>, usize), ErrorMessage> {
+    pos += 1;
+
+    let (name, consumed) = decode_name(&bytes[pos..], KEY_NAME_HEADER)
+        .ok_or(ErrorMessage(MALFORMED_KEY_NAME_VECTOR))?;
+    pos += consumed;
+
+    let (meta, consumed) = Meta::decode(&bytes[pos..])?;
+    pos += consumed;
+
+    let mut reader = Reader::at(bytes, pos);
+    let (value, more) = read_values(&mut reader, KEY_END_HEADER)?;
+    let end = reader.position();
+
+    let key = if decode && projection.includes_key(&name) {
+        let mut key = Key::new(name, Value::decode_in_place(value)?);
+        key.meta = meta;
+
+        let mut more = Reader::new(more);
+        while !more.is_empty() {
+            key.push_value(more.read_value()?);
+        }
+
+        Some(key)
+    } else {
+        None
+    };
+
+    Ok((key, end + 1))
+}
+
+/// Reads a row starting at `pos` (on its start header).
+///
+/// # Returns
+/// - `Ok((Option<Row>, usize))`: The row (`None` when skipped) and the position after its end header.
+fn read_row(bytes: &[u8], mut pos: usize, projection: &Projection) -> Result<(Option<Row>, usize), ErrorMessage> {
+    pos += 1;
+
+    let (name, consumed) = decode_name(&bytes[pos..], ROW_NAME_HEADER)
+        .ok_or(ErrorMessage(MALFORMED_ROW_NAME_VECTOR))?;
+    pos += consumed;
+
+    let (meta, consumed) = Meta::decode(&bytes[pos..])?;
+    pos += consumed;
+
+    let decode = projection.includes_row(&name);
+    let mut row = Row::new_empty(&name);
+    row.meta = meta;
+
+    loop {
+        match bytes.get(pos) {
+            Some(&ROW_END_HEADER) => break,
+            Some(&KEY_START_HEADER) => {
+                let (key, next) = read_key(bytes, pos, projection, decode)?;
+                if let Some(key) = key {
+                    row.keys.insert(key.name.clone(), key);
+                }
+                pos = next;
+            }
+            _ => return Err(ErrorMessage(MALFORMED_ROW_VECTOR)),
+        }
+    }
+
+    Ok((decode.then_some(row), pos + 1))
+}
+
+impl YAD {
+    /// Deserializes only the rows and keys selected by `projection`.
+    ///
+    /// Unlike [`YAD::deserialize`], which decodes every value before anything
+    /// can be dropped, unselected rows and keys are skipped by their length
+    /// descriptors, so wide documents can be read for a few fields at a
+    /// fraction of the cost. Rows in the projection that hold none of the
+    /// projected keys are kept, empty.
+    ///
+    /// The walk is strict: bytes that do not frame a row or a key are an error.
+    /// A signature section is skipped without being checked; encrypted
+    /// documents are rejected with `ENCRYPTED_DOCUMENT`.
+    ///
+    /// # Example
+    /// ```text
+    /// let projection = Projection::all().with_rows(&["johan"]).with_keys(&["age"]);
+    /// let yad = YAD::deserialize_projected(&bytes, &projection)?;
+    /// ```
+    ///
+    /// # Errors
+    /// Returns an error if the version header, the embedded schema section, a
+    /// row or a key is malformed, or a selected value cannot be decoded.
+    pub fn deserialize_projected(bytes: &[u8], projection: &Projection) -> Result<Self, ErrorMessage> {
+        let (Header { version, flags }, pos) = body_start(bytes)?;
+
+        let body = decode_body(bytes.get(pos..).unwrap_or_default(), flags, Some(MAX_INFLATED_SIZE))?;
+        let bytes = body.as_ref();
+        let mut pos = 0;
+
+        let mut rows: Vec<Row> = vec![];
+
+        while pos < bytes.len() {
+            if bytes[pos] != ROW_START_HEADER {
+                return Err(ErrorMessage(MALFORMED_ROW_VECTOR));
+            }
+
+            let (row, next) = read_row(bytes, pos, projection)?;
+            if let Some(mut row) = row {
+                convert_byte_order(&mut row, flags.byte_order())?;
+                rows.push(row);
+            }
+            pos = next;
+        }
+
+        Ok(Self::new(version, rows))
+    }
+}
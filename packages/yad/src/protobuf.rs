@@ -0,0 +1,87 @@
+use prost_types::value::Kind;
+use prost_types::Struct;
+use yad_core::constants::error::ErrorMessage;
+use yad_core::Value;
+use crate::error::PROTOBUF_ROW_NOT_A_STRUCT;
+use crate::key::Key;
+use crate::row::Row;
+use crate::{CURRENT_VERSION, YAD};
+
+impl Row {
+    /// Converts the row into a protobuf struct of its keys (see [`Value::to_protobuf`]).
+    ///
+    /// Multi-value keys keep their first value.
+    ///
+    /// # Errors
+    /// Returns any error of [`Value::to_protobuf`] on a value.
+    pub fn to_protobuf_struct(&self) -> Result<Struct, ErrorMessage> {
+        let fields = self.keys.values()
+            .map(|key| Ok((key.name.clone(), key.value.to_protobuf()?)))
+            .collect::<Result<_, ErrorMessage>>()?;
+
+        Ok(Struct { fields })
+    }
+
+    /// Builds the row `name` from a protobuf struct shaped like the output of
+    /// [`Row::to_protobuf_struct`].
+    ///
+    /// # Errors
+    /// Returns any error of [`Value::from_protobuf`] on a value.
+    pub fn from_protobuf_struct<S: ToString>(name: S, protobuf: &Struct) -> Result<Self, ErrorMessage> {
+        let mut row = Row::new_empty(name);
+
+        for (key_name, value) in &protobuf.fields {
+            row.keys.insert(key_name.clone(), Key::new(key_name, Value::from_protobuf(value)?));
+        }
+
+        Ok(row)
+    }
+}
+
+impl YAD {
+    /// Converts the document into a protobuf struct of rows, each a struct of
+    /// keys to values, so services can embed it in messages directly.
+    ///
+    /// The version is not written; metadata is dropped.
+    ///
+    /// # Example
+    /// ```
+    /// use serde_yad::{row::Row, key::Key, YAD, CURRENT_VERSION};
+    ///
+    /// let yad = YAD::new(CURRENT_VERSION, vec![Row::new("johan", vec![Key::new("age", 17u8)])]);
+    /// let protobuf = yad.to_protobuf_struct().unwrap();
+    /// assert_eq!(YAD::from_protobuf_struct(&protobuf), Ok(yad));
+    /// ```
+    ///
+    /// # Errors
+    /// Returns any error of [`Value::to_protobuf`] on a value.
+    pub fn to_protobuf_struct(&self) -> Result<Struct, ErrorMessage> {
+        let fields = self.rows.values()
+            .map(|row| {
+                let keys = prost_types::Value { kind: Some(Kind::StructValue(row.to_protobuf_struct()?)) };
+                Ok((row.name.clone(), keys))
+            })
+            .collect::<Result<_, ErrorMessage>>()?;
+
+        Ok(Struct { fields })
+    }
+
+    /// Builds a document of the [`CURRENT_VERSION`] from a protobuf struct
+    /// shaped like the output of [`YAD::to_protobuf_struct`].
+    ///
+    /// # Errors
+    /// - `PROTOBUF_ROW_NOT_A_STRUCT` if a row is not a struct.
+    /// - Any error of [`Value::from_protobuf`] on a value.
+    pub fn from_protobuf_struct(protobuf: &Struct) -> Result<Self, ErrorMessage> {
+        let mut yad = Self::new_empty(CURRENT_VERSION);
+
+        for (row_name, keys) in &protobuf.fields {
+            let Some(Kind::StructValue(keys)) = &keys.kind else {
+                return Err(ErrorMessage(PROTOBUF_ROW_NOT_A_STRUCT));
+            };
+            yad.rows.insert(row_name.clone(), Row::from_protobuf_struct(row_name, keys)?);
+        }
+
+        Ok(yad)
+    }
+}
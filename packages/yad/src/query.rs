@@ -0,0 +1,205 @@
+use std::borrow::Cow;
+use std::cmp::Ordering;
+use yad_core::constants::types::Type;
+use yad_core::Value;
+use crate::row::Row;
+use crate::YAD;
+
+/// A test applied to a key value by [`YAD::select`].
+///
+/// Implemented by [`Predicate`] and by any `Fn(&Value) -> bool` closure.
+pub trait ValuePredicate {
+    /// Returns `true` if `value` matches.
+    fn test(&self, value: &Value) -> bool;
+}
+
+impl<F: Fn(&Value) -> bool> ValuePredicate for F {
+    fn test(&self, value: &Value) -> bool {
+        self(value)
+    }
+}
+
+/// Common comparisons on key values.
+///
+/// Ordering comparisons use [`Value::semantic_cmp`], so `Predicate::gt(10u8)`
+/// matches a value stored as `11u32` or `10.5f64` as well.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Predicate {
+    /// Matches values semantically equal to the operand.
+    Eq(Value),
+    /// Matches values not semantically equal to the operand.
+    Ne(Value),
+    /// Matches values greater than the operand.
+    Gt(Value),
+    /// Matches values greater than or equal to the operand.
+    Ge(Value),
+    /// Matches values less than the operand.
+    Lt(Value),
+    /// Matches values less than or equal to the operand.
+    Le(Value),
+    /// Matches strings containing the operand string, or arrays containing an
+    /// element semantically equal to the operand.
+    Contains(Value),
+    /// Matches any value; used to test for the presence of a key.
+    Exists,
+}
+
+impl Predicate {
+    /// Builds [`Predicate::Eq`].
+    pub fn eq<V: Into<Value>>(value: V) -> Self {
+        Self::Eq(value.into())
+    }
+
+    /// Builds [`Predicate::Ne`].
+    pub fn ne<V: Into<Value>>(value: V) -> Self {
+        Self::Ne(value.into())
+    }
+
+    /// Builds [`Predicate::Gt`].
+    pub fn gt<V: Into<Value>>(value: V) -> Self {
+        Self::Gt(value.into())
+    }
+
+    /// Builds [`Predicate::Ge`].
+    pub fn ge<V: Into<Value>>(value: V) -> Self {
+        Self::Ge(value.into())
+    }
+
+    /// Builds [`Predicate::Lt`].
+    pub fn lt<V: Into<Value>>(value: V) -> Self {
+        Self::Lt(value.into())
+    }
+
+    /// Builds [`Predicate::Le`].
+    pub fn le<V: Into<Value>>(value: V) -> Self {
+        Self::Le(value.into())
+    }
+
+    /// Builds [`Predicate::Contains`] for a substring.
+    ///
+    /// Returns `None` if `needle` is empty, since YAD strings cannot be empty.
+    pub fn contains_str(needle: &str) -> Option<Self> {
+        Value::try_from(needle).ok().map(Self::Contains)
+    }
+
+    /// Returns `true` if both values have the same kind (boolean, number, string, array or extension).
+    pub(crate) fn same_kind(a: &Value, b: &Value) -> bool {
+        let kind = |v: &Value| match v.r#type {
+            Type::Bool | Type::True | Type::False => 0,
+            Type::Uint | Type::Int | Type::Float => 1,
+            Type::String => 2,
+            Type::Array => 3,
+            Type::Extension => 4,
+        };
+        kind(a) == kind(b)
+    }
+
+    /// Orders `value` against `operand`, or `None` if they are of different kinds.
+    fn order(value: &Value, operand: &Value) -> Option<Ordering> {
+        Self::same_kind(value, operand).then(|| value.semantic_cmp(operand))
+    }
+}
+
+impl ValuePredicate for Predicate {
+    fn test(&self, value: &Value) -> bool {
+        match self {
+            Predicate::Eq(operand) => value.semantic_eq(operand),
+            Predicate::Ne(operand) => !value.semantic_eq(operand),
+            Predicate::Gt(operand) => Self::order(value, operand).is_some_and(|o| o.is_gt()),
+            Predicate::Ge(operand) => Self::order(value, operand).is_some_and(|o| o.is_ge()),
+            Predicate::Lt(operand) => Self::order(value, operand).is_some_and(|o| o.is_lt()),
+            Predicate::Le(operand) => Self::order(value, operand).is_some_and(|o| o.is_le()),
+            Predicate::Contains(operand) => match value.r#type {
+                Type::String => {
+                    let haystack: Result<String, _> = value.clone().try_into();
+                    let needle: Result<String, _> = operand.clone().try_into();
+                    matches!((haystack, needle), (Ok(h), Ok(n)) if h.contains(&n))
+                }
+                Type::Array => {
+                    let items: Result<Vec<Value>, _> = value.clone().try_into();
+                    items.is_ok_and(|items| items.iter().any(|item| item.semantic_eq(operand)))
+                }
+                _ => false,
+            },
+            Predicate::Exists => true,
+        }
+    }
+}
+
+/// Splits a `row.key` path into the row name (`None` for `*` or no row part) and the key name.
+///
+/// A `\` makes the next character literal, so `a\.b.c` names the key `c` of
+/// the row `a.b`, `\*.c` the key `c` of the row named `*`, and `a\.b` alone
+/// the key `a.b` of every row.
+pub(crate) fn split_path(path: &str) -> (Option<Cow<'_, str>>, Cow<'_, str>) {
+    if !path.contains('\\') {
+        return match path.split_once('.') {
+            Some(("*", key)) => (None, Cow::Borrowed(key)),
+            Some((row, key)) => (Some(Cow::Borrowed(row)), Cow::Borrowed(key)),
+            None => (None, Cow::Borrowed(path)),
+        };
+    }
+
+    let mut row: Option<(String, bool)> = None;
+    let mut segment = String::new();
+    let mut escaped = false;
+    let mut chars = path.chars();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => {
+                segment.extend(chars.next());
+                escaped = true;
+            }
+            '.' if row.is_none() => row = Some((std::mem::take(&mut segment), std::mem::take(&mut escaped))),
+            _ => segment.push(c),
+        }
+    }
+
+    let row = row.filter(|(name, escaped)| *escaped || name != "*").map(|(name, _)| Cow::Owned(name));
+    (row, Cow::Owned(segment))
+}
+
+impl Row {
+    /// Returns `true` if the row has a key named `key` whose value matches `predicate`.
+    pub fn key_matches<P: ValuePredicate + ?Sized>(&self, key: &str, predicate: &P) -> bool {
+        self.keys.get(key).is_some_and(|k| predicate.test(&k.value))
+    }
+}
+
+impl YAD {
+    /// Returns an iterator over the rows for which `predicate` returns `true`, in name order.
+    ///
+    /// # Example
+    /// ```text
+    /// let adults = yad.find_rows(|row| row.key_matches("age", &Predicate::ge(18u8)));
+    /// ```
+    pub fn find_rows<'a, F>(&'a self, mut predicate: F) -> impl Iterator<Item = &'a Row> + 'a
+    where
+        F: FnMut(&Row) -> bool + 'a,
+    {
+        self.rows.values().filter(move |row| predicate(row))
+    }
+
+    /// Returns an iterator over the rows whose value at `path` matches `predicate`.
+    ///
+    /// `path` is either a key name, matched in every row, or `row.key`, where
+    /// the part before the first `.` is a row name or `*` for any row. A `\`
+    /// makes the next character literal, for row names holding a `.`.
+    ///
+    /// # Example
+    /// ```text
+    /// let rows = yad.select("age", Predicate::gt(10u8));
+    /// let rows = yad.select("johan.name", |v: &Value| v.r#type == Type::String);
+    /// ```
+    pub fn select<'a, P>(&'a self, path: &'a str, predicate: P) -> impl Iterator<Item = &'a Row> + 'a
+    where
+        P: ValuePredicate + 'a,
+    {
+        let (row_name, key) = split_path(path);
+
+        self.rows.values()
+            .filter(move |row| row_name.as_ref().is_none_or(|name| row.name == *name))
+            .filter(move |row| row.key_matches(&key, &predicate))
+    }
+}
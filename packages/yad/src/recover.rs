@@ -0,0 +1,158 @@
+use std::ops::Range;
+use yad_core::constants::error::ErrorMessage;
+use yad_core::Reader;
+use crate::constants::ROW_START_HEADER;
+use crate::error::{CHECKSUM_MISMATCH, MALFORMED_FILE};
+use crate::header::HeaderFlags;
+use crate::options::{self, DecodeOptions, DuplicatePolicy};
+use crate::projection::Projection;
+use crate::row::Row;
+use crate::{Body, Version, CURRENT_VERSION, YAD};
+
+/// A row [`scan`] could read, with the bytes it was read from.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SalvagedRow {
+    /// The row, as written.
+    pub row: Row,
+    /// Where the row was found (see [`ScanReport::offsets_in_input`]).
+    pub range: Range<usize>,
+}
+
+/// Bytes [`scan`] could not read any row from.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DamagedRegion {
+    /// Where the region is (see [`ScanReport::offsets_in_input`]).
+    pub range: Range<usize>,
+    /// The first error found in the region.
+    pub error: ErrorMessage,
+}
+
+/// What [`scan`] found in a damaged document.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ScanReport {
+    /// The version of the document, or `None` when its headers could not be read.
+    pub version: Option<Version>,
+    /// Every intact row, in file order; names may repeat.
+    pub rows: Vec<SalvagedRow>,
+    /// Every unrecoverable region, in file order.
+    pub damaged: Vec<DamagedRegion>,
+    /// Whether ranges are offsets into the input. They are offsets into the
+    /// rewritten body instead for files using a dictionary, varint length
+    /// descriptors or delta arrays.
+    pub offsets_in_input: bool,
+}
+
+impl ScanReport {
+    /// Returns `true` if nothing was damaged.
+    pub fn is_intact(&self) -> bool {
+        self.damaged.is_empty()
+    }
+
+    /// Builds a document of the salvaged rows, keeping the last of rows that
+    /// share a name. Documents whose headers were lost get [`CURRENT_VERSION`].
+    pub fn into_yad(self) -> YAD {
+        let mut yad = YAD::new_empty(self.version.unwrap_or(CURRENT_VERSION));
+        for SalvagedRow { row, .. } in self.rows {
+            yad.rows.insert(row.name.clone(), row);
+        }
+        yad
+    }
+}
+
+/// Heuristically salvages the intact rows of a corrupted document and reports
+/// the regions nothing could be read from.
+///
+/// Unlike [`YAD::deserialize_partial`], which stops at the first error, every
+/// row start marker is tried: a row is kept when its name header is valid and
+/// every key up to its end marker parses, so intact rows after a damaged one
+/// are found too. A marker byte inside damaged data can, rarely, still parse
+/// as a row; inspect [`ScanReport::damaged`] before trusting the result.
+///
+/// When the headers cannot be read, the whole input is scanned for rows and
+/// their numbers are read as big-endian. Files using a dictionary, varint
+/// length descriptors or delta arrays must have their whole body rewritten
+/// first; when that fails, the whole body is reported damaged. A body that does not match its checksum section is
+/// scanned all the same; when every row reads, the whole body is reported
+/// damaged with `CHECKSUM_MISMATCH`.
+///
+/// # Example
+/// ```
+/// use serde_yad::{row::Row, key::Key, recover, YAD, CURRENT_VERSION};
+///
+/// let yad = YAD::new(CURRENT_VERSION, vec![
+///     Row::new("a", vec![Key::new("n", 1u8)]),
+///     Row::new("b", vec![Key::new("n", 2u8)]),
+/// ]);
+/// let mut bytes = yad.serialize().unwrap();
+/// let row_a = bytes.iter().position(|b| *b == 0xF1).unwrap();
+/// bytes[row_a + 1] = 0x00; // corrupt the name header of row `a`
+///
+/// let report = recover::scan(&bytes);
+/// assert_eq!(report.damaged.len(), 1);
+/// assert!(report.into_yad().get_row("b").is_some());
+/// ```
+pub fn scan(bytes: &[u8]) -> ScanReport {
+    let body = match Body::read_unverified(bytes.to_vec(), &DecodeOptions::default()) {
+        Ok(body) => body,
+        Err(_) => {
+            let (rows, damaged) = scan_rows(bytes, 0, HeaderFlags::empty());
+            return ScanReport { version: None, rows, damaged, offsets_in_input: true };
+        }
+    };
+
+    let offset = if body.expanded { 0 } else { bytes.len() - body.bytes.len() };
+    let version = Some(body.version.clone());
+
+    let (rows, mut damaged) = scan_rows(&body.bytes, offset, body.flags);
+    if !body.checksum_matches && damaged.is_empty() {
+        damaged.push(DamagedRegion { range: offset..offset + body.bytes.len(), error: ErrorMessage(CHECKSUM_MISMATCH) });
+    }
+    ScanReport { version, rows, damaged, offsets_in_input: !body.expanded }
+}
+
+/// Tries every row start marker of `body`, whose first byte is at `offset`;
+/// `flags` give the byte order of the numbers.
+fn scan_rows(body: &[u8], offset: usize, flags: HeaderFlags) -> (Vec<SalvagedRow>, Vec<DamagedRegion>) {
+    let mut rows = Vec::new();
+    let mut damaged = Vec::new();
+    let mut damage: Option<(usize, ErrorMessage)> = None;
+    let mut pos = 0;
+
+    while pos < body.len() {
+        let Some(start) = body[pos..].iter().position(|b| *b == ROW_START_HEADER).map(|skip| pos + skip) else {
+            damage.get_or_insert((pos, ErrorMessage(MALFORMED_FILE)));
+            break;
+        };
+
+        if start > pos {
+            damage.get_or_insert((pos, ErrorMessage(MALFORMED_FILE)));
+        }
+
+        let mut reader = Reader::at(body, start);
+        let row = Row::read(&mut reader, DuplicatePolicy::KeepLast, &Projection::all()).and_then(|row| {
+            let mut row = row.ok_or(ErrorMessage(MALFORMED_FILE))?;
+            options::convert_byte_order(&mut row, flags.byte_order())?;
+            Ok(row)
+        });
+
+        match row {
+            Ok(row) => {
+                if let Some((from, error)) = damage.take() {
+                    damaged.push(DamagedRegion { range: offset + from..offset + start, error });
+                }
+                rows.push(SalvagedRow { row, range: offset + start..offset + reader.position() });
+                pos = reader.position();
+            }
+            Err(error) => {
+                damage.get_or_insert((start, error));
+                pos = start + 1;
+            }
+        }
+    }
+
+    if let Some((from, error)) = damage {
+        damaged.push(DamagedRegion { range: offset + from..offset + body.len(), error });
+    }
+
+    (rows, damaged)
+}
@@ -0,0 +1,66 @@
+use yad_core::constants::error::ErrorMessage;
+use yad_core::Value;
+use crate::key::Key;
+use crate::row::Row;
+use crate::YAD;
+
+/// What [`YAD::redacted`] does with keys marked sensitive (see [`crate::meta::Meta::set_sensitive`]).
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum RedactionPolicy {
+    /// Leaves the keys out.
+    Omit,
+    /// Keeps the keys with their metadata, their values replaced by this one.
+    Replace(Value),
+}
+
+impl RedactionPolicy {
+    /// Applies the policy to `key`, returning `None` if it is left out.
+    fn apply(&self, key: &Key) -> Option<Key> {
+        if !key.meta.is_sensitive() {
+            return Some(key.clone());
+        }
+
+        match self {
+            RedactionPolicy::Omit => None,
+            RedactionPolicy::Replace(value) => {
+                let mut redacted = Key::new(key.name.clone(), value.clone());
+                redacted.meta = key.meta.clone();
+                Some(redacted)
+            }
+        }
+    }
+}
+
+impl YAD {
+    /// Returns a copy of the document with its sensitive keys redacted by
+    /// `policy`, leaving the document untouched, so one document can produce
+    /// both an internal and a shareable export.
+    ///
+    /// Rows keep their name and metadata even when every key is left out.
+    ///
+    /// # Example
+    /// ```text
+    /// yad.get_row_mut("johan").unwrap().get_key_mut("email").unwrap().meta.set_sensitive(true);
+    ///
+    /// let internal = yad.serialize()?;
+    /// let public = yad.serialize_redacted(&RedactionPolicy::Replace(Value::try_from("<redacted>")?))?;
+    /// ```
+    pub fn redacted(&self, policy: &RedactionPolicy) -> YAD {
+        let rows = self.rows.values().map(|row| {
+            let mut redacted = Row::new(row.name.clone(), row.keys.values().filter_map(|key| policy.apply(key)).collect());
+            redacted.meta = row.meta.clone();
+            redacted
+        });
+
+        YAD::new(self.version.clone(), rows.collect())
+    }
+
+    /// Serializes the document with its sensitive keys redacted by `policy`
+    /// (see [`YAD::redacted`]).
+    ///
+    /// # Errors
+    /// Returns the errors of [`YAD::serialize`].
+    pub fn serialize_redacted(&self, policy: &RedactionPolicy) -> Result<Vec<u8>, ErrorMessage> {
+        self.redacted(policy).serialize()
+    }
+}
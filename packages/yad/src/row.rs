@@ -1,12 +1,15 @@
 use std::collections::BTreeMap;
 use std::fmt::{Debug, Display, Formatter};
+use std::ops::{Index, IndexMut};
 use yad_core::constants::error::ErrorMessage;
-use yad_core::constants::length::ByteLength;
-use yad_core::Value;
-use crate::constants::{ROW_END_HEADER, ROW_NAME_HEADER, ROW_START_HEADER};
-use crate::error::{MALFORMED_ROW_NAME_VECTOR, MALFORMED_ROW_VECTOR};
+use yad_core::{Reader, Value};
+use crate::constants::{KEY_START_HEADER, ROW_END_HEADER, ROW_NAME_HEADER, ROW_START_HEADER};
+use crate::error::{KEY_NAME_OF_LENGTH_ZERO, KEY_NOT_FOUND, MALFORMED_ROW_NAME_VECTOR, MALFORMED_ROW_VECTOR, NAME_ALREADY_TAKEN, ROW_NOT_FOUND};
 use crate::key::Key;
-use crate::{encode_name, segment_keys, usize_from_slice_bytes};
+use crate::meta::Meta;
+use crate::options::{DecodeOptions, DuplicatePolicy};
+use crate::projection::Projection;
+use crate::{decode_name, encode_name_into, encoded_name_len};
 
 /// Represents a **row structure** in the YAD binary format.
 ///
@@ -16,21 +19,29 @@ use crate::{encode_name, segment_keys, usize_from_slice_bytes};
 ///
 /// # Binary Layout
 /// ```text
-/// +---------------+---------------------+-------------------+---------------+
-/// | Start Header  | Encoded Row Name    | Encoded Keys...   | End Header    |
-/// +---------------+---------------------+-------------------+---------------+
+/// +---------------+---------------------+-----------------+-------------------+---------------+
+/// | Start Header  | Encoded Row Name    | [Metadata]      | Encoded Keys...   | End Header    |
+/// +---------------+---------------------+-----------------+-------------------+---------------+
 /// ```
 ///
 /// # Fields
 /// - `name`: A unique string identifier for the row.
 /// - `keys`: A [`BTreeMap`] mapping key names to their associated [`Key`] objects.
+/// - `meta`: Optional metadata, written only when not empty (see [`Meta`]).
 #[derive(Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Row {
-    /// The row’s unique identifier.
+    /// The row’s unique identifier. Inside a [`crate::YAD`] it must match the
+    /// row's map key; use [`crate::YAD::rename_row`] to change it.
     pub name: String,
     /// The collection of keys belonging to this row.
-    /// Keys are stored in a hashmap for fast lookup by name.
+    /// Keys are stored in a hashmap for fast lookup by name; each map key
+    /// matches [`Key::name`] (see [`Row::rename_key`]).
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_impl::by_name"))]
     pub keys: BTreeMap<String, Key>,
+    /// Optional metadata (timestamps, tags, ...).
+    #[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "Meta::is_empty"))]
+    pub meta: Meta,
 }
 
 impl Row {
@@ -53,6 +64,7 @@ impl Row {
         Self {
             name: name.to_string(),
             keys,
+            meta: Meta::default(),
         }
     }
 
@@ -70,9 +82,23 @@ impl Row {
         Self {
             name: name.to_string(),
             keys: BTreeMap::new(),
+            meta: Meta::default(),
         }
     }
 
+    /// Returns the row's metadata.
+    pub fn meta(&self) -> &Meta {
+        &self.meta
+    }
+
+    /// Sets the raw metadata value of `tag` (see [`Meta::set`]).
+    ///
+    /// # Errors
+    /// Returns `META_VALUE_TOO_LONG` if `value` is longer than 255 bytes.
+    pub fn set_meta<V: Into<Vec<u8>>>(&mut self, tag: u8, value: V) -> Result<(), ErrorMessage> {
+        self.meta.set(tag, value)
+    }
+
     /// Returns an immutable reference to the row’s key collection.
     pub fn get_keys(&self) -> &BTreeMap<String, Key> {
         &self.keys
@@ -83,16 +109,78 @@ impl Row {
         &mut self.keys
     }
 
+    /// Returns the key named `name`, if any.
+    pub fn get_key(&self, name: &str) -> Option<&Key> {
+        self.keys.get(name)
+    }
+
+    /// Returns the key named `name` mutably, if any.
+    pub fn get_key_mut(&mut self, name: &str) -> Option<&mut Key> {
+        self.keys.get_mut(name)
+    }
+
+    /// Returns the value of the key named `name`, if any. `row["name"]` is the panicking form.
+    pub fn get(&self, name: &str) -> Option<&Value> {
+        self.get_key(name).map(|key| &key.value)
+    }
+
+    /// Returns the value of the key named `name` mutably, if any.
+    pub fn get_mut(&mut self, name: &str) -> Option<&mut Value> {
+        self.get_key_mut(name).map(|key| &mut key.value)
+    }
+
     /// Inserts a new [`Key`] into the row.
     ///
     /// If a key with the same name already exists, it will be replaced.
     ///
     /// # Arguments
     /// - `name`: The unique name of the key.
-    /// - `value`: The value associated with the key.
-    pub fn insert_key<S: ToString>(&mut self, name: S, value: Value) {
-        let rows = self.get_keys_mut();
-        rows.insert(name.to_string(), Key::new(name, value));
+    /// - `value`: The value associated with the key: a [`Value`] or anything
+    ///   convertible to one (numbers, `bool`, `&str`, `String`, `Vec<Value>`).
+    ///
+    /// # Errors
+    /// Returns the conversion error, e.g. `STRING_OF_LENGTH_ZERO` for `""`;
+    /// the row is then left unchanged.
+    pub fn insert_key<S: ToString, V>(&mut self, name: S, value: V) -> Result<(), ErrorMessage>
+    where
+        V: TryInto<Value>,
+        V::Error: Into<ErrorMessage>,
+    {
+        let key = Key::try_new(name, value)?;
+        self.get_keys_mut().insert(key.name.clone(), key);
+        Ok(())
+    }
+
+    /// Renames the key `old` to `new`, updating both its map key and
+    /// [`Key::name`]. Renaming a key to its own name does nothing.
+    ///
+    /// # Errors
+    /// - `KEY_NOT_FOUND` if there is no key named `old`.
+    /// - `NAME_ALREADY_TAKEN` if another key is already named `new`.
+    /// - `KEY_NAME_OF_LENGTH_ZERO` if `new` is empty.
+    ///
+    /// The row is left unchanged on error.
+    pub fn rename_key<S: ToString>(&mut self, old: &str, new: S) -> Result<(), ErrorMessage> {
+        let new = new.to_string();
+
+        if !self.keys.contains_key(old) {
+            return Err(ErrorMessage(KEY_NOT_FOUND));
+        }
+        if new.is_empty() {
+            return Err(ErrorMessage(KEY_NAME_OF_LENGTH_ZERO));
+        }
+        if new == old {
+            return Ok(());
+        }
+        if self.keys.contains_key(&new) {
+            return Err(ErrorMessage(NAME_ALREADY_TAKEN));
+        }
+
+        let mut key = self.keys.remove(old).expect("checked above");
+        key.name = new.clone();
+        self.keys.insert(new, key);
+
+        Ok(())
     }
 
     /// Removes a [`Key`] from the row by its name.
@@ -118,11 +206,6 @@ impl Row {
         ROW_END_HEADER == byte
     }
 
-    /// Checks if a byte matches the **row name header** marker.
-    fn byte_is_row_name_header(byte: u8) -> bool {
-        ROW_NAME_HEADER == (byte & 0xF0)
-    }
-
     /// Validates that the first and last bytes of a vector
     /// correspond to valid **row boundary headers**.
     ///
@@ -143,43 +226,12 @@ impl Row {
         Self::byte_is_row_start_header(*first) && Self::byte_is_row_end_header(*last)
     }
 
-    /// Extracts and decodes the row’s name from its binary representation.
-    ///
-    /// # Arguments
-    /// - `bytes`: A byte vector containing the encoded row name and metadata.
-    ///
-    /// # Returns
-    /// - `Some(String)`: The decoded row name if successful.
-    /// - `None`: If validation fails or UTF-8 decoding fails.
-    fn find_and_decode_name_from_bytes(bytes: Vec<u8>) -> Option<String> {
-        if bytes.is_empty() {
-            return None;
-        }
-
-        let first = *bytes.get(0)?;
-        if !Self::byte_is_row_name_header(first) {
-            return None;
-        }
-
-        let byte_length = ByteLength::try_from(first).ok()?;
-        let be_length = usize_from_slice_bytes(&bytes[1..], byte_length)?;
-
-        let metadata_length = 1 + byte_length.as_byte_count() as usize;
-
-        if bytes.len() < metadata_length + be_length {
-            return None;
-        }
-
-        let string_bytes = &bytes[metadata_length..metadata_length + be_length];
-
-        String::from_utf8(string_bytes.to_vec()).ok()
-    }
-
     /// Serializes the [`Row`] into its binary representation.
     ///
     /// # Layout
     /// - Start header
     /// - Encoded row name
+    /// - Metadata block, if any
     /// - Encoded keys
     /// - End header
     ///
@@ -187,17 +239,36 @@ impl Row {
     /// - `Ok(Vec<u8>)`: Binary representation of the row.
     /// - `Err(ErrorMessage)`: If name encoding or key serialization fails.
     pub fn serialize(&self) -> Result<Vec<u8>, ErrorMessage> {
-        let mut bytes: Vec<u8> = vec![ROW_START_HEADER];
+        let mut bytes: Vec<u8> = Vec::with_capacity(self.encoded_len());
+
+        self.write_into(&mut bytes)?;
 
-        bytes.extend_from_slice(encode_name(&self.name, ROW_NAME_HEADER)?.as_slice());
+        Ok(bytes)
+    }
 
-        for (_n, value) in &self.keys {
-            bytes.extend_from_slice(value.serialize()?.as_slice());
+    /// Returns the number of bytes [`Row::serialize`] writes.
+    pub fn encoded_len(&self) -> usize {
+        2 + encoded_name_len(&self.name)
+            + self.meta.encoded_len()
+            + self.keys.values().map(Key::encoded_len).sum::<usize>()
+    }
+
+    /// Appends the serialized row to `bytes` (see [`Row::serialize`]).
+    ///
+    /// # Errors
+    /// Returns an error if a name cannot be encoded; `bytes` may then hold a partial row.
+    pub fn write_into(&self, bytes: &mut Vec<u8>) -> Result<(), ErrorMessage> {
+        bytes.push(ROW_START_HEADER);
+        encode_name_into(&self.name, ROW_NAME_HEADER, bytes)?;
+        self.meta.write_into(bytes);
+
+        for key in self.keys.values() {
+            key.write_into(bytes)?;
         }
 
         bytes.push(ROW_END_HEADER);
 
-        Ok(bytes)
+        Ok(())
     }
 
     /// Deserializes a [`Row`] from its binary representation.
@@ -209,20 +280,96 @@ impl Row {
     /// - `Ok(Row)`: A decoded row if successful.
     /// - `Err(ErrorMessage)`: If boundary headers or name decoding fail.
     pub fn deserialize(bytes: Vec<u8>) -> Result<Self, ErrorMessage> {
+        Self::deserialize_with(bytes, &DecodeOptions::default())
+    }
+
+    /// Deserializes a row, resolving keys that share a name according to
+    /// `options.duplicates`, skipping keys outside `options.projection` and
+    /// stopping at arrays nested deeper than `options.max_depth`.
+    ///
+    /// # Errors
+    /// Returns `ROW_NOT_FOUND` if `options.projection` does not select the row.
+    pub(crate) fn deserialize_with(bytes: Vec<u8>, options: &DecodeOptions) -> Result<Self, ErrorMessage> {
         if !Self::check_boundary_bytes(&bytes) {
             return Err(ErrorMessage(MALFORMED_ROW_VECTOR));
         }
 
-        let mut keys: Vec<Key> = vec![];
+        let mut reader = options.reader(&bytes);
+        let row = Self::read(&mut reader, options.duplicates, &options.projection)?.ok_or(ErrorMessage(ROW_NOT_FOUND))?;
 
-        for key_bytes in segment_keys(&bytes) {
-            keys.push(Key::deserialize(key_bytes)?)
+        if !reader.is_empty() {
+            return Err(ErrorMessage(MALFORMED_ROW_VECTOR));
         }
 
-        let name = Self::find_and_decode_name_from_bytes(bytes[1..].to_vec())
-            .ok_or_else(|| ErrorMessage(MALFORMED_ROW_NAME_VECTOR))?;
+        Ok(row)
+    }
 
-        Ok(Self::new(name, keys))
+    /// Reads the row at the reader's position (on its start header), walking
+    /// its keys by their length descriptors rather than scanning for header bytes.
+    ///
+    /// Keys that share a name are resolved according to `duplicates`. Rows
+    /// and keys outside `projection` are skipped without decoding their
+    /// values; a skipped row is read as `None`. On success the reader is left
+    /// after the row's end header.
+    ///
+    /// # Errors
+    /// Returns an error if the row or one of its keys is malformed or truncated.
+    pub(crate) fn read(reader: &mut Reader, duplicates: DuplicatePolicy, projection: &Projection) -> Result<Option<Self>, ErrorMessage> {
+        if reader.peek() != Some(ROW_START_HEADER) {
+            return Err(ErrorMessage(MALFORMED_ROW_VECTOR));
+        }
+        reader.advance(1)?;
+
+        let (name, consumed) = decode_name(reader.remaining(), ROW_NAME_HEADER)
+            .ok_or(ErrorMessage(MALFORMED_ROW_NAME_VECTOR))?;
+        reader.advance(consumed)?;
+
+        let (meta, consumed) = Meta::decode(reader.remaining())?;
+        reader.advance(consumed)?;
+
+        let selected = projection.includes_row(&name);
+        let mut row = Self::new_empty(name);
+        row.meta = meta;
+
+        loop {
+            match reader.peek() {
+                Some(ROW_END_HEADER) => {
+                    reader.advance(1)?;
+                    return Ok(selected.then_some(row));
+                }
+                Some(KEY_START_HEADER) => {
+                    if let Some(key) = Key::read(reader, |name| selected && projection.includes_key(name))? {
+                        duplicates.insert(&mut row.keys, key.name.clone(), key, |key, name| key.name = name.to_string())?;
+                    }
+                }
+                _ => return Err(ErrorMessage(MALFORMED_ROW_VECTOR)),
+            }
+        }
+    }
+}
+
+impl Index<&str> for Row {
+    type Output = Value;
+
+    /// Returns the value of the key named `name`.
+    ///
+    /// # Panics
+    /// Panics if there is no such key; use [`Row::get`] to handle that case.
+    fn index(&self, name: &str) -> &Value {
+        self.get(name).unwrap_or_else(|| panic!("no key named `{}` in row `{}`", name, self.name))
+    }
+}
+
+impl IndexMut<&str> for Row {
+    /// Returns the value of the key named `name` mutably.
+    ///
+    /// # Panics
+    /// Panics if there is no such key; use [`Row::get_mut`] to handle that case.
+    fn index_mut(&mut self, name: &str) -> &mut Value {
+        match self.keys.get_mut(name) {
+            Some(key) => &mut key.value,
+            None => panic!("no key named `{}` in row `{}`", name, self.name),
+        }
     }
 }
 
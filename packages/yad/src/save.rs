@@ -0,0 +1,87 @@
+use std::ffi::OsString;
+use std::fs::{self, File};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use yad_core::constants::error::ErrorMessage;
+use crate::error::SAVE_IO_FAILED;
+use crate::YAD;
+
+/// Returns `path` with `suffix` appended to its file name.
+fn with_suffix(path: &Path, suffix: &str) -> Result<PathBuf, ErrorMessage> {
+    let mut name: OsString = path.file_name().ok_or(ErrorMessage(SAVE_IO_FAILED))?.to_os_string();
+    name.push(suffix);
+    Ok(path.with_file_name(name))
+}
+
+/// Flushes the directory holding `path`, so a rename in it survives a crash.
+#[cfg(unix)]
+fn sync_parent(path: &Path) -> Result<(), ErrorMessage> {
+    match path.parent().filter(|dir| !dir.as_os_str().is_empty()) {
+        Some(dir) => File::open(dir).and_then(|dir| dir.sync_all()).map_err(|_| ErrorMessage(SAVE_IO_FAILED)),
+        None => Ok(()),
+    }
+}
+
+/// Directories cannot be flushed outside Unix; the rename is left to the file system.
+#[cfg(not(unix))]
+fn sync_parent(_: &Path) -> Result<(), ErrorMessage> {
+    Ok(())
+}
+
+/// Writes `bytes` to a temporary file next to `path`, flushes it to disk and
+/// renames it over `path`.
+pub(crate) fn write_atomic(path: &Path, bytes: &[u8]) -> Result<(), ErrorMessage> {
+    let io = |_| ErrorMessage(SAVE_IO_FAILED);
+    let temp = with_suffix(path, ".tmp")?;
+
+    let mut file = File::create(&temp).map_err(io)?;
+    file.write_all(bytes).and_then(|_| file.sync_all()).map_err(io)?;
+    drop(file);
+
+    fs::rename(&temp, path).map_err(io)?;
+    sync_parent(path)
+}
+
+impl YAD {
+    /// Saves the document to `path` so that a crash leaves either the
+    /// previous file or the new one, never a mix of both.
+    ///
+    /// The document is written to a temporary file next to `path` (its name
+    /// with `.tmp` appended), flushed to disk and renamed over `path`; on
+    /// Unix the directory is flushed too, so the rename itself is durable.
+    /// Use it instead of `fs::write(path, yad.serialize()?)`, which truncates
+    /// the file before writing it.
+    ///
+    /// # Errors
+    /// Returns any error of [`YAD::serialize`], or `SAVE_IO_FAILED` if a file
+    /// cannot be written, flushed or renamed.
+    pub fn save_atomic<P: AsRef<Path>>(&self, path: P) -> Result<(), ErrorMessage> {
+        write_atomic(path.as_ref(), &self.serialize()?)
+    }
+
+    /// Saves the document like [`YAD::save_atomic`], first keeping the file
+    /// it replaces as a backup next to it, its name with `.bak` appended.
+    ///
+    /// The previous backup is replaced. The backup is copied rather than
+    /// moved, so `path` holds a complete document throughout.
+    ///
+    /// # Example
+    /// ```text
+    /// yad.save_atomic_with_backup("data.yad")?;   // the old data.yad is now data.yad.bak
+    /// ```
+    ///
+    /// # Errors
+    /// Returns the errors of [`YAD::save_atomic`].
+    pub fn save_atomic_with_backup<P: AsRef<Path>>(&self, path: P) -> Result<(), ErrorMessage> {
+        let path = path.as_ref();
+        let bytes = self.serialize()?;
+
+        if path.exists() {
+            let backup = with_suffix(path, ".bak")?;
+            let previous = fs::read(path).map_err(|_| ErrorMessage(SAVE_IO_FAILED))?;
+            write_atomic(&backup, &previous)?;
+        }
+
+        write_atomic(path, &bytes)
+    }
+}
@@ -0,0 +1,196 @@
+use std::collections::BTreeMap;
+use yad_core::constants::error::ErrorMessage;
+use yad_core::constants::length::ByteLength;
+use yad_core::constants::types::Type;
+use yad_core::Value;
+use crate::constants::SCHEMA_HEADER;
+use crate::error::{MALFORMED_SCHEMA, SCHEMA_MISMATCH};
+use crate::header::Header;
+use crate::YAD;
+
+/// The type and width of a key's value as recorded in a [`Schema`].
+///
+/// Booleans are always recorded as [`Type::Bool`]. Strings, arrays and
+/// extension values are recorded with [`ByteLength::Zero`] since their length
+/// descriptor depends on the content, not on the type; the tag of extension
+/// values is not recorded.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd)]
+pub struct FieldType {
+    /// The value type.
+    pub r#type: Type,
+    /// The numeric width, or [`ByteLength::Zero`] when not applicable.
+    pub length: ByteLength,
+}
+
+impl FieldType {
+    /// Describes the type of the given value.
+    pub fn of(value: &Value) -> Self {
+        match value.r#type {
+            Type::Uint | Type::Int | Type::Float => Self { r#type: value.r#type, length: value.length },
+            Type::String | Type::Array | Type::Extension => Self { r#type: value.r#type, length: ByteLength::Zero },
+            Type::Bool | Type::True | Type::False => Self { r#type: Type::Bool, length: ByteLength::Zero },
+        }
+    }
+
+    /// Returns `true` if `value` has this type.
+    pub fn matches(&self, value: &Value) -> bool {
+        Self::of(value) == *self
+    }
+
+    /// Maps a serialized type tag back to its [`Type`].
+    fn type_from_tag(tag: u8) -> Option<Type> {
+        [Type::Uint, Type::Int, Type::Float, Type::String, Type::Array, Type::Bool, Type::Extension]
+            .into_iter()
+            .find(|t| u8::from(*t) == tag)
+    }
+}
+
+/// Describes the keys and value types of every row in a document.
+///
+/// A schema can be inferred from a document with [`Schema::infer`] and embedded
+/// in the serialized file with [`crate::options::SerializeOptions::embed_schema`],
+/// so readers can validate documents without receiving the schema separately.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Schema {
+    /// Row name → key name → value type.
+    pub rows: BTreeMap<String, BTreeMap<String, FieldType>>,
+}
+
+impl Schema {
+    /// Infers the schema of a document from the values it currently holds.
+    pub fn infer(yad: &YAD) -> Self {
+        let rows = yad.rows.iter()
+            .map(|(row_name, row)| {
+                let keys = row.keys.iter()
+                    .map(|(key_name, key)| (key_name.clone(), FieldType::of(&key.value)))
+                    .collect();
+                (row_name.clone(), keys)
+            })
+            .collect();
+
+        Self { rows }
+    }
+
+    /// Returns `true` if the schema describes no keys.
+    pub fn is_empty(&self) -> bool {
+        self.rows.values().all(|keys| keys.is_empty())
+    }
+
+    /// Checks that every key of `yad` is described by this schema with the same type.
+    ///
+    /// Keys listed in the schema but missing from the document are allowed.
+    ///
+    /// # Errors
+    /// Returns `SCHEMA_MISMATCH` if a row or key is unknown or has a different type.
+    pub fn validate(&self, yad: &YAD) -> Result<(), ErrorMessage> {
+        for (row_name, row) in &yad.rows {
+            let keys = self.rows.get(row_name).ok_or(ErrorMessage(SCHEMA_MISMATCH))?;
+
+            for (key_name, key) in &row.keys {
+                let field = keys.get(key_name).ok_or(ErrorMessage(SCHEMA_MISMATCH))?;
+                if !field.matches(&key.value) {
+                    return Err(ErrorMessage(SCHEMA_MISMATCH));
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Serializes the schema as a schema section.
+    ///
+    /// # Layout
+    /// `SCHEMA_HEADER` followed by an array value whose entries are
+    /// `[row name, key name, type tag (u8), length tag (u8)]` arrays.
+    ///
+    /// # Errors
+    /// Returns `MALFORMED_SCHEMA` if the schema is empty, since YAD arrays
+    /// cannot be empty.
+    pub fn serialize(&self) -> Result<Vec<u8>, ErrorMessage> {
+        let mut entries: Vec<Value> = vec![];
+
+        for (row_name, keys) in &self.rows {
+            for (key_name, field) in keys {
+                entries.push(Value::try_from(vec![
+                    Value::try_from(row_name.as_str())?,
+                    Value::try_from(key_name.as_str())?,
+                    Value::from(u8::from(field.r#type)),
+                    Value::from(u8::from(field.length)),
+                ])?);
+            }
+        }
+
+        if entries.is_empty() {
+            return Err(ErrorMessage(MALFORMED_SCHEMA));
+        }
+
+        let mut bytes = vec![SCHEMA_HEADER];
+        bytes.extend_from_slice(Value::try_from(entries)?.bytes.as_slice());
+
+        Ok(bytes)
+    }
+
+    /// Deserializes a schema section starting with `SCHEMA_HEADER`.
+    ///
+    /// # Returns
+    /// - `Ok((Schema, usize))`: The schema and the number of bytes consumed.
+    /// - `Err(ErrorMessage)`: If the section is malformed.
+    pub fn deserialize(bytes: &[u8]) -> Result<(Self, usize), ErrorMessage> {
+        if bytes.first() != Some(&SCHEMA_HEADER) {
+            return Err(ErrorMessage(MALFORMED_SCHEMA));
+        }
+
+        let section = Value::decode_in_place(&bytes[1..])?;
+        let consumed = 1 + section.bytes.len();
+        let entries: Vec<Value> = section.try_into().map_err(|_| ErrorMessage(MALFORMED_SCHEMA))?;
+
+        let mut schema = Self::default();
+
+        for entry in entries {
+            let fields: Vec<Value> = entry.try_into().map_err(|_| ErrorMessage(MALFORMED_SCHEMA))?;
+            let [row_name, key_name, type_tag, length_tag]: [Value; 4] = fields.try_into()
+                .map_err(|_| ErrorMessage(MALFORMED_SCHEMA))?;
+
+            let row_name: String = row_name.try_into().map_err(|_| ErrorMessage(MALFORMED_SCHEMA))?;
+            let key_name: String = key_name.try_into().map_err(|_| ErrorMessage(MALFORMED_SCHEMA))?;
+            let type_tag: u8 = type_tag.try_into().map_err(|_| ErrorMessage(MALFORMED_SCHEMA))?;
+            let length_tag: u8 = length_tag.try_into().map_err(|_| ErrorMessage(MALFORMED_SCHEMA))?;
+
+            let field = FieldType {
+                r#type: FieldType::type_from_tag(type_tag).ok_or(ErrorMessage(MALFORMED_SCHEMA))?,
+                length: ByteLength::try_from(length_tag).map_err(|_| ErrorMessage(MALFORMED_SCHEMA))?,
+            };
+
+            schema.rows.entry(row_name).or_default().insert(key_name, field);
+        }
+
+        Ok((schema, consumed))
+    }
+
+    /// Reads the schema embedded in a serialized document, if any.
+    ///
+    /// # Arguments
+    /// - `bytes`: The whole serialized document, starting with its header.
+    ///
+    /// # Returns
+    /// - `Ok(Some(Schema))`: If the document embeds a schema section.
+    /// - `Ok(None)`: If it does not.
+    pub fn read_embedded(bytes: &[u8]) -> Result<Option<Self>, ErrorMessage> {
+        let (_, pos) = Header::read(bytes)?;
+
+        match bytes.get(pos) {
+            Some(&SCHEMA_HEADER) => Ok(Some(Self::deserialize(&bytes[pos..])?.0)),
+            _ => Ok(None),
+        }
+    }
+}
+
+/// Removes a leading schema section from a document body (the bytes after the version header).
+pub(crate) fn strip_schema_section(bytes: &mut Vec<u8>) -> Result<(), ErrorMessage> {
+    if bytes.first() == Some(&SCHEMA_HEADER) {
+        let (_, consumed) = Schema::deserialize(bytes)?;
+        bytes.drain(..consumed);
+    }
+
+    Ok(())
+}
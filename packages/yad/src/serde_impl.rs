@@ -0,0 +1,37 @@
+use std::collections::BTreeMap;
+use serde::{Deserialize, Deserializer, Serializer};
+use crate::key::Key;
+use crate::row::Row;
+
+/// Something stored in a map under its own name.
+pub(crate) trait Named {
+    fn name(&self) -> &str;
+}
+
+impl Named for Key {
+    fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+impl Named for Row {
+    fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+/// Writes the keys of a row, or the rows of a document, as a sequence rather
+/// than a map, so each name is written once; reading keeps the last item of
+/// those that share a name, like [`crate::YAD::deserialize`].
+pub(crate) mod by_name {
+    use super::*;
+
+    pub(crate) fn serialize<T: Named + serde::Serialize, S: Serializer>(map: &BTreeMap<String, T>, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(map.values())
+    }
+
+    pub(crate) fn deserialize<'de, T: Named + Deserialize<'de>, D: Deserializer<'de>>(deserializer: D) -> Result<BTreeMap<String, T>, D::Error> {
+        let items = Vec::<T>::deserialize(deserializer)?;
+        Ok(items.into_iter().map(|item| (item.name().to_string(), item)).collect())
+    }
+}
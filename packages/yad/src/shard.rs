@@ -0,0 +1,139 @@
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use std::fs;
+use std::path::Path;
+use yad_core::constants::error::ErrorMessage;
+use crate::error::SHARD_IO_FAILED;
+use crate::row::Row;
+use crate::save::write_atomic;
+use crate::YAD;
+
+/// The file extension of shard files written by [`write_shards`] and read by
+/// [`YadShardSet::open`].
+pub const SHARD_EXTENSION: &str = "yad";
+
+impl YAD {
+    /// Splits the document into shards, one per distinct value `shard_of`
+    /// returns for its rows. Rows are moved, not cloned; every shard keeps
+    /// the document version. Secondary indexes are dropped.
+    ///
+    /// # Example
+    /// ```text
+    /// let shards = yad.split_by(|row| row.name.as_bytes()[0] % 4);
+    /// shard::write_shards("out", &shards)?;
+    /// ```
+    pub fn split_by<K: Ord, F: FnMut(&Row) -> K>(self, mut shard_of: F) -> BTreeMap<K, YAD> {
+        let mut shards: BTreeMap<K, YAD> = BTreeMap::new();
+
+        for (name, row) in self.rows {
+            shards.entry(shard_of(&row))
+                .or_insert_with(|| YAD::new_empty(self.version.clone()))
+                .rows
+                .insert(name, row);
+        }
+
+        shards
+    }
+}
+
+/// Writes each shard to `<dir>/<id>.yad`, creating `dir` if needed.
+///
+/// Each file is replaced atomically, like [`YAD::save_atomic`] does, so a
+/// crash never leaves a shard half written.
+///
+/// # Errors
+/// Returns `SHARD_IO_FAILED` if the directory or a file cannot be written,
+/// or the error of a shard that fails to serialize.
+pub fn write_shards<P: AsRef<Path>, K: Display>(dir: P, shards: &BTreeMap<K, YAD>) -> Result<(), ErrorMessage> {
+    let dir = dir.as_ref();
+    fs::create_dir_all(dir).map_err(|_| ErrorMessage(SHARD_IO_FAILED))?;
+
+    for (id, shard) in shards {
+        let path = dir.join(format!("{}.{}", id, SHARD_EXTENSION));
+        write_atomic(&path, &shard.serialize()?).map_err(|_| ErrorMessage(SHARD_IO_FAILED))?;
+    }
+
+    Ok(())
+}
+
+/// A set of shards presented as one document, for datasets too big to
+/// serialize as a single file.
+///
+/// Shards are expected to hold disjoint rows, as [`YAD::split_by`] produces.
+/// When a row name appears in several shards, lookups return it from the
+/// first shard in order.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct YadShardSet {
+    shards: Vec<YAD>,
+}
+
+impl YadShardSet {
+    /// Wraps already loaded shards, kept in the given order.
+    pub fn new(shards: Vec<YAD>) -> Self {
+        Self { shards }
+    }
+
+    /// Reads every `.yad` file of `dir`, in file name order. Other files and
+    /// subdirectories are ignored.
+    ///
+    /// # Errors
+    /// Returns `SHARD_IO_FAILED` if the directory or a file cannot be read,
+    /// or the error of a shard that fails to deserialize.
+    pub fn open<P: AsRef<Path>>(dir: P) -> Result<Self, ErrorMessage> {
+        let mut paths = Vec::new();
+
+        for entry in fs::read_dir(dir).map_err(|_| ErrorMessage(SHARD_IO_FAILED))? {
+            let path = entry.map_err(|_| ErrorMessage(SHARD_IO_FAILED))?.path();
+            if path.is_file() && path.extension().is_some_and(|ext| ext == SHARD_EXTENSION) {
+                paths.push(path);
+            }
+        }
+        paths.sort();
+
+        let shards = paths.iter()
+            .map(|path| YAD::deserialize(fs::read(path).map_err(|_| ErrorMessage(SHARD_IO_FAILED))?))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Self { shards })
+    }
+
+    /// Returns the shards in order.
+    pub fn shards(&self) -> &[YAD] {
+        &self.shards
+    }
+
+    /// Returns the row named `name` from the first shard holding one.
+    pub fn get_row(&self, name: &str) -> Option<&Row> {
+        self.shards.iter().find_map(|shard| shard.get_row(name))
+    }
+
+    /// Iterates over the rows of every shard, shard by shard.
+    pub fn rows(&self) -> impl Iterator<Item = &Row> {
+        self.shards.iter().flat_map(|shard| shard.rows.values())
+    }
+
+    /// Returns the total number of rows across shards.
+    pub fn len(&self) -> usize {
+        self.shards.iter().map(|shard| shard.rows.len()).sum()
+    }
+
+    /// Returns `true` if no shard holds a row.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Moves every row into one document with the version of the first
+    /// shard. When a name appears in several shards, the first one is kept.
+    pub fn merge(self) -> Option<YAD> {
+        let version = self.shards.first()?.version.clone();
+        let mut shards = self.shards.into_iter().rev();
+        let mut yad = shards.next()?;
+
+        for shard in shards {
+            yad.extend(shard);
+        }
+        yad.version = version;
+
+        Some(yad)
+    }
+}
@@ -0,0 +1,175 @@
+use std::ops::{Deref, DerefMut};
+use std::sync::{Arc, PoisonError, RwLock, RwLockReadGuard, RwLockWriteGuard};
+use yad_core::constants::error::ErrorMessage;
+use crate::observe::ChangeEvent;
+use crate::row::Row;
+use crate::YAD;
+
+/// A document shared between threads.
+///
+/// Cloning a `SharedYad` clones the handle, not the document. Reads take a
+/// shared lock and writes an exclusive one, so [`SharedYad::serialize`] and
+/// [`SharedYad::snapshot`] always see a document between two writes, never in
+/// the middle of one.
+///
+/// [`SharedYad::snapshot`] is copy-on-write: it shares the document instead
+/// of copying it, and the next write copies the document only while a
+/// snapshot is still alive. A server can serialize a snapshot at leisure
+/// while writes go on.
+///
+/// A lock poisoned by a panicking thread is recovered: the document is still
+/// handed out, in whatever state the panicking closure left it.
+#[derive(Clone, Default)]
+pub struct SharedYad {
+    inner: Arc<RwLock<Arc<YAD>>>,
+}
+
+/// An immutable view of a [`SharedYad`] document at one point in time,
+/// returned by [`SharedYad::snapshot`]. Later writes to the shared document
+/// are not seen.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Snapshot(Arc<YAD>);
+
+impl Snapshot {
+    /// Returns `true` if both snapshots are of the same version of the
+    /// document, with no write in between.
+    pub(crate) fn same_as(&self, other: &Snapshot) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+
+    /// Returns the document, copying it only if other snapshots or the
+    /// shared document still refer to it.
+    pub fn into_yad(self) -> YAD {
+        Arc::unwrap_or_clone(self.0)
+    }
+}
+
+impl Deref for Snapshot {
+    type Target = YAD;
+
+    fn deref(&self) -> &YAD {
+        &self.0
+    }
+}
+
+/// Shared access to a [`SharedYad`] document, returned by [`SharedYad::read`].
+pub struct ReadGuard<'a>(RwLockReadGuard<'a, Arc<YAD>>);
+
+impl Deref for ReadGuard<'_> {
+    type Target = YAD;
+
+    fn deref(&self) -> &YAD {
+        &self.0
+    }
+}
+
+/// Exclusive access to a [`SharedYad`] document, returned by [`SharedYad::write`].
+///
+/// The first mutable access copies the document if a [`Snapshot`] of it is
+/// still alive, keeping its observers.
+pub struct WriteGuard<'a>(RwLockWriteGuard<'a, Arc<YAD>>);
+
+impl Deref for WriteGuard<'_> {
+    type Target = YAD;
+
+    fn deref(&self) -> &YAD {
+        &self.0
+    }
+}
+
+impl DerefMut for WriteGuard<'_> {
+    fn deref_mut(&mut self) -> &mut YAD {
+        let shared: &mut Arc<YAD> = &mut self.0;
+
+        if Arc::get_mut(shared).is_none() {
+            let mut copy = YAD::clone(shared);
+            copy.observers = shared.observers.share();
+            *shared = Arc::new(copy);
+        }
+
+        Arc::get_mut(shared).expect("the document is no longer shared")
+    }
+}
+
+impl From<YAD> for SharedYad {
+    fn from(yad: YAD) -> Self {
+        Self::new(yad)
+    }
+}
+
+impl SharedYad {
+    /// Wraps a document so it can be shared between threads.
+    pub fn new(yad: YAD) -> Self {
+        Self { inner: Arc::new(RwLock::new(Arc::new(yad))) }
+    }
+
+    /// Locks the document for reading.
+    pub fn read(&self) -> ReadGuard<'_> {
+        ReadGuard(self.inner.read().unwrap_or_else(PoisonError::into_inner))
+    }
+
+    /// Locks the document for writing.
+    pub fn write(&self) -> WriteGuard<'_> {
+        WriteGuard(self.inner.write().unwrap_or_else(PoisonError::into_inner))
+    }
+
+    /// Calls `f` with the row named `name` under a read lock.
+    ///
+    /// # Returns
+    /// - `Some(R)`: The value returned by `f`.
+    /// - `None`: If the row does not exist.
+    pub fn read_row<R>(&self, name: &str, f: impl FnOnce(&Row) -> R) -> Option<R> {
+        self.read().rows.get(name).map(f)
+    }
+
+    /// Calls `f` with the row named `name` under a write lock.
+    ///
+    /// Secondary indexes are updated once `f` returns.
+    ///
+    /// # Returns
+    /// - `Some(R)`: The value returned by `f`.
+    /// - `None`: If the row does not exist.
+    pub fn with_row_mut<R>(&self, name: &str, f: impl FnOnce(&mut Row) -> R) -> Option<R> {
+        let mut yad = self.write();
+        let mut row = yad.detach_row(name)?;
+        let output = f(&mut row);
+
+        yad.attach_row(row);
+        yad.notify(|| ChangeEvent::RowUpdated { row: name.to_string() });
+
+        Some(output)
+    }
+
+    /// Returns the document as it is now, without copying it; the next
+    /// write copies the document instead while the snapshot is alive.
+    ///
+    /// # Example
+    /// ```text
+    /// let snapshot = shared.snapshot();
+    /// shared.write().insert_row("johan", vec![]);   // copies the document once
+    /// let bytes = snapshot.serialize()?;            // does not see "johan"
+    /// ```
+    pub fn snapshot(&self) -> Snapshot {
+        Snapshot(self.read().0.clone())
+    }
+
+    /// Serializes the document under a read lock.
+    ///
+    /// # Errors
+    /// Returns the same errors as [`YAD::serialize`].
+    pub fn serialize(&self) -> Result<Vec<u8>, ErrorMessage> {
+        self.read().serialize()
+    }
+
+    /// Unwraps the document if this is the last handle to it. It is copied
+    /// if snapshots of it are still alive.
+    ///
+    /// # Returns
+    /// - `Ok(YAD)`: The document.
+    /// - `Err(SharedYad)`: This handle, if other handles are still alive.
+    pub fn try_into_inner(self) -> Result<YAD, Self> {
+        Arc::try_unwrap(self.inner)
+            .map(|lock| Arc::unwrap_or_clone(lock.into_inner().unwrap_or_else(PoisonError::into_inner)))
+            .map_err(|inner| Self { inner })
+    }
+}
@@ -0,0 +1,84 @@
+use ed25519_dalek::{Signature, Signer, SigningKey, VerifyingKey};
+use yad_core::constants::error::ErrorMessage;
+use crate::constants::{SIGNATURE_HEADER, SIGNATURE_LENGTH};
+use crate::error::{INVALID_SIGNATURE, MISSING_SIGNATURE};
+use crate::header::{version_end, HeaderFlags};
+use crate::options::SerializeOptions;
+use crate::YAD;
+
+/// Returns where the signature section of `bytes` starts: right after the
+/// version and the header flags section, if any.
+fn signature_start(bytes: &[u8]) -> Result<usize, ErrorMessage> {
+    let version = version_end(bytes);
+    let (_, consumed) = HeaderFlags::decode(bytes.get(version..).unwrap_or_default())?;
+    Ok(version + consumed)
+}
+
+/// Signs `unsigned` and inserts the signature section into it.
+fn embed_signature(mut unsigned: Vec<u8>, signing_key: &SigningKey) -> Result<Vec<u8>, ErrorMessage> {
+    let signature = signing_key.sign(&unsigned);
+    let start = signature_start(&unsigned)?;
+
+    let mut section = vec![SIGNATURE_HEADER];
+    section.extend_from_slice(&signature.to_bytes());
+    unsigned.splice(start..start, section);
+
+    Ok(unsigned)
+}
+
+impl YAD {
+    /// Serializes the document canonically and embeds an Ed25519 signature over it.
+    ///
+    /// # Layout
+    /// `magic (4 bytes) | version (5 bytes) | SIGNATURE_HEADER | signature (64 bytes) | rows`
+    ///
+    /// The signature covers the output of [`YAD::serialize_canonical`], that
+    /// is the magic number and version header followed by the rows. Signed files can still be
+    /// read with [`YAD::deserialize`], which skips the signature; use
+    /// [`YAD::verify`] to check it.
+    ///
+    /// # Errors
+    /// Returns the same errors as [`YAD::serialize_canonical`].
+    pub fn sign(&self, signing_key: &SigningKey) -> Result<Vec<u8>, ErrorMessage> {
+        embed_signature(self.serialize_canonical()?, signing_key)
+    }
+
+    /// Serializes the document with `options` and embeds an Ed25519 signature
+    /// over the result, right after the header flags section.
+    ///
+    /// The signature covers every other byte of the file, the checksum
+    /// section and a compressed body included, so [`YAD::verify`] checks it
+    /// before anything is decoded.
+    ///
+    /// # Errors
+    /// Returns the same errors as [`YAD::serialize_with_options`].
+    pub fn sign_with_options(&self, signing_key: &SigningKey, options: &SerializeOptions) -> Result<Vec<u8>, ErrorMessage> {
+        embed_signature(self.serialize_with_options(options)?, signing_key)
+    }
+
+    /// Checks the signature embedded by [`YAD::sign`] or
+    /// [`YAD::sign_with_options`] and deserializes the document.
+    ///
+    /// # Errors
+    /// - `MISSING_SIGNATURE` if the file has no signature section.
+    /// - `INVALID_SIGNATURE` if the signature does not match `public_key` and the rest of the file.
+    /// - Any error of [`YAD::deserialize`] on the signed document.
+    pub fn verify(bytes: &[u8], public_key: &VerifyingKey) -> Result<Self, ErrorMessage> {
+        let start = signature_start(bytes)?;
+
+        if bytes.len() < start + 1 + SIGNATURE_LENGTH || bytes[start] != SIGNATURE_HEADER {
+            return Err(ErrorMessage(MISSING_SIGNATURE));
+        }
+
+        let signature: [u8; SIGNATURE_LENGTH] = bytes[start + 1..start + 1 + SIGNATURE_LENGTH].try_into()
+            .map_err(|_| ErrorMessage(MISSING_SIGNATURE))?;
+
+        let mut signed = bytes[..start].to_vec();
+        signed.extend_from_slice(&bytes[start + 1 + SIGNATURE_LENGTH..]);
+
+        public_key.verify_strict(&signed, &Signature::from_bytes(&signature))
+            .map_err(|_| ErrorMessage(INVALID_SIGNATURE))?;
+
+        Self::deserialize(signed)
+    }
+}
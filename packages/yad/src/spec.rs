@@ -0,0 +1,162 @@
+use yad_core::constants::length::ByteLength;
+use yad_core::constants::types::Type;
+use yad_core::delta::DELTA_ARRAY_HEADER;
+use crate::constants::*;
+use crate::header::HeaderFlags;
+
+/// Where a header byte is found.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum HeaderContext {
+    /// In front of the rows, once per document.
+    Document,
+    /// Around and at the start of a row.
+    Row,
+    /// Around and at the start of a key.
+    Key,
+    /// Inside a patch (see [`crate::patch`]).
+    Patch,
+    /// Between the records of a log (see [`crate::log`]).
+    Log,
+    /// In place of a value.
+    Value,
+}
+
+/// A header byte of the file format.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct HeaderSpec {
+    /// Name of the constant holding the byte.
+    pub name: &'static str,
+    /// The byte itself.
+    pub byte: u8,
+    /// Where it is found.
+    pub context: HeaderContext,
+    /// What it marks and what follows it.
+    pub description: &'static str,
+}
+
+/// A type nibble, the high half of a value's first byte.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct TypeSpec {
+    /// Name of the type, as printed by [`Type`]'s `Display`.
+    pub name: &'static str,
+    /// The nibble, in place (`0x10` for unsigned integers).
+    pub nibble: u8,
+    /// Whether the low nibble is a [`LengthSpec`]; booleans hold their state there.
+    pub has_length: bool,
+    /// What values of the type hold.
+    pub description: &'static str,
+}
+
+/// A length nibble, the low half of a value's first byte.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct LengthSpec {
+    /// The nibble.
+    pub nibble: u8,
+    /// Width in bytes of a number's payload, or of the length descriptor of a
+    /// string or array.
+    pub bytes: u8,
+}
+
+/// A bit of the header flags section (see [`HeaderFlags`]).
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct FlagSpec {
+    /// Name of the [`HeaderFlags`] constant.
+    pub name: &'static str,
+    /// The bit.
+    pub bit: u16,
+    /// What it declares.
+    pub description: &'static str,
+}
+
+const HEADERS: &[HeaderSpec] = &[
+    HeaderSpec { name: "VERSION_HEADER", byte: VERSION_HEADER, context: HeaderContext::Document, description: "Version of the file; followed by the major, minor and patch numbers and the beta flag, one byte each." },
+    HeaderSpec { name: "SCHEMA_HEADER", byte: SCHEMA_HEADER, context: HeaderContext::Document, description: "Embedded schema section, right after the version." },
+    HeaderSpec { name: "BLOOM_HEADER", byte: BLOOM_HEADER, context: HeaderContext::Document, description: "Bloom filter section over the row names, right after the schema section; followed by an array of the hash count (u8) and the filter bits as u64 words." },
+    HeaderSpec { name: "CHECKSUM_HEADER", byte: CHECKSUM_HEADER, context: HeaderContext::Document, description: "Checksum section, right after the bloom filter section; followed by the big-endian CRC-32 of the rest of the file." },
+    HeaderSpec { name: "ENCRYPTED_HEADER", byte: ENCRYPTED_HEADER, context: HeaderContext::Document, description: "Encrypted body, right after the version; followed by the cipher id." },
+    HeaderSpec { name: "SIGNATURE_HEADER", byte: SIGNATURE_HEADER, context: HeaderContext::Document, description: "Signature section, right after the version; followed by a 64-byte Ed25519 signature." },
+    HeaderSpec { name: "FLAGS_HEADER", byte: FLAGS_HEADER, context: HeaderContext::Document, description: "Header flags section, right after the version; followed by the big-endian u16 flags and two reserved zero bytes." },
+    HeaderSpec { name: "DICTIONARY_HEADER", byte: DICTIONARY_HEADER, context: HeaderContext::Document, description: "Dictionary section, right before the rows; followed by the key names it holds." },
+    HeaderSpec { name: "ROW_START_HEADER", byte: ROW_START_HEADER, context: HeaderContext::Row, description: "Start of a row; followed by its name." },
+    HeaderSpec { name: "ROW_NAME_HEADER", byte: ROW_NAME_HEADER, context: HeaderContext::Row, description: "Row name; the low nibble is a length nibble, followed by the length and the UTF-8 name." },
+    HeaderSpec { name: "ROW_END_HEADER", byte: ROW_END_HEADER, context: HeaderContext::Row, description: "End of a row." },
+    HeaderSpec { name: "META_HEADER", byte: META_HEADER, context: HeaderContext::Row, description: "Metadata block of a row or key, right after its name." },
+    HeaderSpec { name: "KEY_START_HEADER", byte: KEY_START_HEADER, context: HeaderContext::Key, description: "Start of a key; followed by its name and values." },
+    HeaderSpec { name: "KEY_NAME_HEADER", byte: KEY_NAME_HEADER, context: HeaderContext::Key, description: "Key name; the low nibble is a length nibble, followed by the length and the UTF-8 name." },
+    HeaderSpec { name: "KEY_NAME_REF_HEADER", byte: KEY_NAME_REF_HEADER, context: HeaderContext::Key, description: "Key name replaced by an index into the dictionary section; the low nibble is a length nibble, followed by the index." },
+    HeaderSpec { name: "KEY_END_HEADER", byte: KEY_END_HEADER, context: HeaderContext::Key, description: "End of a key." },
+    HeaderSpec { name: "DELTA_ARRAY_HEADER", byte: DELTA_ARRAY_HEADER, context: HeaderContext::Value, description: "Delta array; the low nibble is the item width, followed by the array header, the count varint and zig-zag delta varints." },
+    HeaderSpec { name: "PATCH_HEADER", byte: PATCH_HEADER, context: HeaderContext::Patch, description: "Start of a patch." },
+    HeaderSpec { name: "PATCH_ADD_ROW", byte: PATCH_ADD_ROW, context: HeaderContext::Patch, description: "Adds a row; followed by its name and key operations." },
+    HeaderSpec { name: "PATCH_REMOVE_ROW", byte: PATCH_REMOVE_ROW, context: HeaderContext::Patch, description: "Removes a row; followed by its name." },
+    HeaderSpec { name: "PATCH_CHANGE_ROW", byte: PATCH_CHANGE_ROW, context: HeaderContext::Patch, description: "Changes a row; followed by its name and key operations." },
+    HeaderSpec { name: "PATCH_SET_KEY", byte: PATCH_SET_KEY, context: HeaderContext::Patch, description: "Sets a key; followed by its name and value." },
+    HeaderSpec { name: "PATCH_REMOVE_KEY", byte: PATCH_REMOVE_KEY, context: HeaderContext::Patch, description: "Removes a key; followed by its name." },
+    HeaderSpec { name: "PATCH_ROW_END", byte: PATCH_ROW_END, context: HeaderContext::Patch, description: "Ends the key operations of a row." },
+    HeaderSpec { name: "PATCH_SET_ROW_META", byte: PATCH_SET_ROW_META, context: HeaderContext::Patch, description: "Sets a changed row's metadata; followed by its metadata block, absent when cleared." },
+    HeaderSpec { name: "TOMBSTONE_HEADER", byte: TOMBSTONE_HEADER, context: HeaderContext::Log, description: "Removed row, between the row records of a log; followed by the row name." },
+];
+
+const TYPES: &[TypeSpec] = &[
+    TypeSpec { name: "uint", nibble: Type::Uint as u8, has_length: true, description: "Unsigned integer; the payload is as wide as the length nibble says." },
+    TypeSpec { name: "int", nibble: Type::Int as u8, has_length: true, description: "Two's complement signed integer; the payload is as wide as the length nibble says." },
+    TypeSpec { name: "float", nibble: Type::Float as u8, has_length: true, description: "IEEE 754 float (F8E4M3 for one byte); the payload is as wide as the length nibble says." },
+    TypeSpec { name: "string", nibble: Type::String as u8, has_length: true, description: "UTF-8 string; followed by its byte length, as wide as the length nibble says, and the text." },
+    TypeSpec { name: "array", nibble: Type::Array as u8, has_length: true, description: "Array; followed by its element count, as wide as the length nibble says, and the elements." },
+    TypeSpec { name: "bool", nibble: Type::False as u8, has_length: false, description: "Boolean; `0x80` is false and `0x81` is true." },
+    TypeSpec { name: "extension", nibble: Type::Extension as u8, has_length: true, description: "Application-defined value; followed by its byte length, as wide as the length nibble says, a tag byte naming its kind and its data. Tags `0x00` to `0x7F` are kept for the format: `0x00` is a decimal, whose data is its scale byte and big-endian two's complement mantissa, and `0x01` a big integer, whose data is the big-endian two's complement integer." },
+];
+
+const LENGTHS: &[LengthSpec] = &[
+    LengthSpec { nibble: ByteLength::One as u8, bytes: 1 },
+    LengthSpec { nibble: ByteLength::Two as u8, bytes: 2 },
+    LengthSpec { nibble: ByteLength::Four as u8, bytes: 4 },
+    LengthSpec { nibble: ByteLength::Eight as u8, bytes: 8 },
+];
+
+const FLAGS: &[FlagSpec] = &[
+    FlagSpec { name: "COMPRESSED", bit: HeaderFlags::COMPRESSED.0, description: "The body is compressed." },
+    FlagSpec { name: "ENCRYPTED", bit: HeaderFlags::ENCRYPTED.0, description: "The body is encrypted." },
+    FlagSpec { name: "INDEXED", bit: HeaderFlags::INDEXED.0, description: "The file carries index data." },
+    FlagSpec { name: "CANONICAL", bit: HeaderFlags::CANONICAL.0, description: "The rows are in canonical order." },
+    FlagSpec { name: "CHECKSUMMED", bit: HeaderFlags::CHECKSUMMED.0, description: "The file carries a checksum." },
+    FlagSpec { name: "LITTLE_ENDIAN", bit: HeaderFlags::LITTLE_ENDIAN.0, description: "Number payloads are little-endian." },
+    FlagSpec { name: "VARINT_LENGTHS", bit: HeaderFlags::VARINT_LENGTHS.0, description: "Length descriptors are varints." },
+    FlagSpec { name: "DICTIONARY", bit: HeaderFlags::DICTIONARY.0, description: "Key names may be references into the dictionary section." },
+    FlagSpec { name: "DELTA_ARRAYS", bit: HeaderFlags::DELTA_ARRAYS.0, description: "Arrays of unsigned integers may be delta arrays." },
+];
+
+/// Returns every header byte of the file format, so tools such as hex viewers
+/// and dissectors can be generated from the crate.
+///
+/// Each byte appears once. Name headers and delta arrays keep a length nibble
+/// in their low half, so match them on the high nibble.
+pub fn headers() -> &'static [HeaderSpec] {
+    HEADERS
+}
+
+/// Returns every type nibble.
+pub fn types() -> &'static [TypeSpec] {
+    TYPES
+}
+
+/// Returns every length nibble.
+pub fn lengths() -> &'static [LengthSpec] {
+    LENGTHS
+}
+
+/// Returns every bit of the header flags section that has a meaning; the
+/// others are reserved.
+pub fn flags() -> &'static [FlagSpec] {
+    FLAGS
+}
+
+/// Returns the bytes written before the version header (see [`MAGIC`]).
+pub fn magic() -> &'static [u8] {
+    &MAGIC
+}
+
+/// Returns the header `byte` is, if any.
+pub fn header(byte: u8) -> Option<&'static HeaderSpec> {
+    HEADERS.iter().find(|spec| spec.byte == byte)
+}
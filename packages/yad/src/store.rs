@@ -0,0 +1,120 @@
+use std::collections::{BTreeMap, HashMap};
+use yad_core::constants::error::ErrorMessage;
+use crate::row::Row;
+use crate::{Version, YAD};
+
+/// A storage engine holding rows by name.
+///
+/// Rows are exchanged by value and every operation may fail, so the trait can
+/// be implemented over engines that keep rows serialized (files, key-value
+/// stores) as well as over in-memory maps. Code written against `RowStore`
+/// works unchanged over any of them, including a [`YAD`] document itself.
+pub trait RowStore {
+    /// Returns the row named `name`, if stored.
+    fn get(&self, name: &str) -> Result<Option<Row>, ErrorMessage>;
+
+    /// Stores `row` under its name, replacing any row with the same name.
+    fn put(&mut self, row: Row) -> Result<(), ErrorMessage>;
+
+    /// Removes the row named `name`, returning it if it was stored.
+    fn remove(&mut self, name: &str) -> Result<Option<Row>, ErrorMessage>;
+
+    /// Iterates over every stored row.
+    fn iter(&self) -> Box<dyn Iterator<Item = Result<Row, ErrorMessage>> + '_>;
+}
+
+impl RowStore for BTreeMap<String, Row> {
+    fn get(&self, name: &str) -> Result<Option<Row>, ErrorMessage> {
+        Ok(BTreeMap::get(self, name).cloned())
+    }
+
+    fn put(&mut self, row: Row) -> Result<(), ErrorMessage> {
+        self.insert(row.name.clone(), row);
+        Ok(())
+    }
+
+    fn remove(&mut self, name: &str) -> Result<Option<Row>, ErrorMessage> {
+        Ok(BTreeMap::remove(self, name))
+    }
+
+    fn iter(&self) -> Box<dyn Iterator<Item = Result<Row, ErrorMessage>> + '_> {
+        Box::new(self.values().cloned().map(Ok))
+    }
+}
+
+impl RowStore for HashMap<String, Row> {
+    fn get(&self, name: &str) -> Result<Option<Row>, ErrorMessage> {
+        Ok(HashMap::get(self, name).cloned())
+    }
+
+    fn put(&mut self, row: Row) -> Result<(), ErrorMessage> {
+        self.insert(row.name.clone(), row);
+        Ok(())
+    }
+
+    fn remove(&mut self, name: &str) -> Result<Option<Row>, ErrorMessage> {
+        Ok(HashMap::remove(self, name))
+    }
+
+    fn iter(&self) -> Box<dyn Iterator<Item = Result<Row, ErrorMessage>> + '_> {
+        Box::new(self.values().cloned().map(Ok))
+    }
+}
+
+impl RowStore for YAD {
+    fn get(&self, name: &str) -> Result<Option<Row>, ErrorMessage> {
+        Ok(self.rows.get(name).cloned())
+    }
+
+    fn put(&mut self, row: Row) -> Result<(), ErrorMessage> {
+        self.adopt_row(row);
+        Ok(())
+    }
+
+    fn remove(&mut self, name: &str) -> Result<Option<Row>, ErrorMessage> {
+        Ok(self.remove_row(name))
+    }
+
+    fn iter(&self) -> Box<dyn Iterator<Item = Result<Row, ErrorMessage>> + '_> {
+        Box::new(self.rows.values().cloned().map(Ok))
+    }
+}
+
+impl YAD {
+    /// Loads every row of `store` into a new document.
+    ///
+    /// # Errors
+    /// Returns the first error reported by the store.
+    pub fn from_store<S: RowStore + ?Sized>(version: Version, store: &S) -> Result<Self, ErrorMessage> {
+        let rows = store.iter().collect::<Result<Vec<Row>, ErrorMessage>>()?;
+        Ok(Self::new(version, rows))
+    }
+
+    /// Writes the rows of this document to `store`, so it holds exactly these rows.
+    ///
+    /// Rows already in the store but not in the document are removed.
+    ///
+    /// # Errors
+    /// Returns the first error reported by the store. Rows written before the
+    /// error stay written.
+    pub fn write_to_store<S: RowStore + ?Sized>(&self, store: &mut S) -> Result<(), ErrorMessage> {
+        let mut stale: Vec<String> = vec![];
+
+        for row in store.iter() {
+            let row = row?;
+            if !self.rows.contains_key(&row.name) {
+                stale.push(row.name);
+            }
+        }
+
+        for name in stale {
+            store.remove(&name)?;
+        }
+
+        for row in self.rows.values() {
+            store.put(row.clone())?;
+        }
+
+        Ok(())
+    }
+}
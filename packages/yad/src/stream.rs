@@ -0,0 +1,311 @@
+use std::fs::File;
+use std::io::{BufWriter, Read, Write};
+use std::path::Path;
+use yad_core::constants::error::ErrorMessage;
+use yad_core::Reader;
+use crate::constants::{ENCRYPTED_HEADER, MAGIC, SIGNATURE_LENGTH};
+use crate::dictionary::read_dictionary;
+use crate::error::{ENCRYPTED_DOCUMENT, MALFORMED_ROW_VECTOR, STREAM_IO_FAILED};
+use crate::header::{body_start, decode_row, Header, HeaderFlags};
+use crate::options::{convert_byte_order, DuplicatePolicy};
+use crate::projection::Projection;
+use crate::row::Row;
+use crate::{Version, YAD};
+
+/// How many bytes [`YadRowStream`] reads from its source at a time, at least.
+const CHUNK_SIZE: usize = 64 * 1024;
+
+/// The longest header without a schema section: magic number, version,
+/// flags section and signature section, plus the byte after them.
+const HEADER_PREFETCH: usize = MAGIC.len() + 5 + 5 + 1 + SIGNATURE_LENGTH + 1;
+
+/// Writes a document row by row, so exports of millions of rows never hold
+/// the whole [`crate::YAD`] in memory.
+///
+/// The magic number and version are written when the writer is created, and
+/// each row is encoded and written as it is given; [`YadWriter::close`]
+/// flushes what is left. The output is what [`crate::YAD::serialize`] writes
+/// for the same rows in the same order.
+///
+/// Rows are not checked against each other: a name written twice is resolved
+/// by the reader (see [`crate::options::DuplicatePolicy`]).
+///
+/// # Example
+/// ```text
+/// let mut writer = YadWriter::create("export.yad", CURRENT_VERSION)?;
+/// for row in source.rows() {
+///     writer.write_row(&row?)?;
+/// }
+/// writer.close()?;
+/// ```
+pub struct YadWriter<W: Write> {
+    writer: W,
+    buffer: Vec<u8>,
+    rows: usize,
+}
+
+impl YadWriter<BufWriter<File>> {
+    /// Creates (or truncates) the file at `path` and writes the header.
+    ///
+    /// # Errors
+    /// Returns `STREAM_IO_FAILED` if the file cannot be created or written.
+    pub fn create<P: AsRef<Path>>(path: P, version: Version) -> Result<Self, ErrorMessage> {
+        let file = File::create(path).map_err(|_| ErrorMessage(STREAM_IO_FAILED))?;
+        Self::new(BufWriter::new(file), version)
+    }
+}
+
+impl<W: Write> YadWriter<W> {
+    /// Writes the header to `writer` and returns a writer for the rows.
+    ///
+    /// # Errors
+    /// Returns `STREAM_IO_FAILED` if the header cannot be written.
+    pub fn new(mut writer: W, version: Version) -> Result<Self, ErrorMessage> {
+        writer.write_all(&MAGIC).map_err(|_| ErrorMessage(STREAM_IO_FAILED))?;
+        writer.write_all(&version.serialize()).map_err(|_| ErrorMessage(STREAM_IO_FAILED))?;
+
+        Ok(Self { writer, buffer: vec![], rows: 0 })
+    }
+
+    /// Encodes `row` and writes it. The encoding buffer is reused between rows.
+    ///
+    /// # Errors
+    /// Returns the error of a row that fails to encode, or `STREAM_IO_FAILED`
+    /// if it cannot be written.
+    pub fn write_row(&mut self, row: &Row) -> Result<(), ErrorMessage> {
+        self.buffer.clear();
+        row.write_into(&mut self.buffer)?;
+        self.writer.write_all(&self.buffer).map_err(|_| ErrorMessage(STREAM_IO_FAILED))?;
+        self.rows += 1;
+
+        Ok(())
+    }
+
+    /// Returns the number of rows written so far.
+    pub fn rows_written(&self) -> usize {
+        self.rows
+    }
+
+    /// Flushes the rows still buffered and returns the underlying writer.
+    ///
+    /// Dropping the writer instead also flushes a `BufWriter`, but ignores
+    /// the errors.
+    ///
+    /// # Errors
+    /// Returns `STREAM_IO_FAILED` if the flush fails.
+    pub fn close(mut self) -> Result<W, ErrorMessage> {
+        self.writer.flush().map_err(|_| ErrorMessage(STREAM_IO_FAILED))?;
+        Ok(self.writer)
+    }
+}
+
+/// Input read from a source in chunks, of which only the part not parsed yet is kept.
+struct Chunks<R: Read> {
+    source: R,
+    buffer: Vec<u8>,
+    pos: usize,
+    eof: bool,
+}
+
+impl<R: Read> Chunks<R> {
+    /// Reads the next chunk, dropping the bytes already parsed. A chunk is
+    /// at least as large as the bytes still buffered, so a row that spans
+    /// several chunks is retried a logarithmic number of times.
+    fn fill(&mut self) -> Result<(), ErrorMessage> {
+        self.buffer.drain(..self.pos);
+        self.pos = 0;
+
+        let want = CHUNK_SIZE.max(self.buffer.len());
+        let read = (&mut self.source).take(want as u64)
+            .read_to_end(&mut self.buffer)
+            .map_err(|_| ErrorMessage(STREAM_IO_FAILED))?;
+
+        self.eof = read < want;
+        Ok(())
+    }
+
+    /// Reads until at least `len` bytes are buffered or the source ends.
+    fn fill_to(&mut self, len: usize) -> Result<(), ErrorMessage> {
+        while self.buffer.len() - self.pos < len && !self.eof {
+            self.fill()?;
+        }
+        Ok(())
+    }
+
+    /// Parses the buffered input with `parse`, which returns what it read and
+    /// the number of bytes it consumed. A failure is retried with more input
+    /// until the source ends, when it is returned; malformed input is thus
+    /// reported once the rest of the source has been read.
+    fn parse<T>(&mut self, mut parse: impl FnMut(&[u8]) -> Result<(T, usize), ErrorMessage>) -> Result<T, ErrorMessage> {
+        loop {
+            match parse(&self.buffer[self.pos..]) {
+                Ok((value, consumed)) => {
+                    self.pos += consumed;
+                    return Ok(value);
+                }
+                Err(error) if self.eof => return Err(error),
+                Err(_) => self.fill()?,
+            }
+        }
+    }
+}
+
+/// Reads the rows of a document one at a time from any [`Read`] source, so
+/// arbitrarily large files can be processed as a pipeline without building
+/// a [`YAD`].
+///
+/// Only the row being decoded is buffered. Header flags are honoured like
+/// [`YAD::deserialize`] does (little-endian numbers, varint lengths, key name
+/// dictionaries and delta arrays), and the signature, schema, bloom filter
+/// and checksum sections are skipped without being checked. Beta-era and
+/// compressed files are decoded whole, then yielded row by row.
+///
+/// Rows are yielded in file order and never checked against each other, so
+/// a name written twice is yielded twice. The walk is strict: bytes that do
+/// not frame a row are an error, after which the stream ends.
+///
+/// # Example
+/// ```text
+/// for row in YadRowStream::open(File::open("export.yad")?)? {
+///     let row = row?;
+///     println!("{}", row.name);
+/// }
+/// ```
+pub struct YadRowStream<R: Read> {
+    chunks: Chunks<R>,
+    header: Header,
+    names: Vec<String>,
+    /// Rows of a compressed document, decoded as a whole.
+    decoded: Option<std::vec::IntoIter<Row>>,
+    now: Option<u64>,
+    done: bool,
+}
+
+impl<R: Read> YadRowStream<R> {
+    /// Reads the header sections from `source` and returns a stream over the rows after them.
+    ///
+    /// # Errors
+    /// Returns `STREAM_IO_FAILED` if `source` fails, `ENCRYPTED_DOCUMENT` for
+    /// encrypted bodies, or an error if a header section is malformed.
+    pub fn open(source: R) -> Result<Self, ErrorMessage> {
+        let mut chunks = Chunks { source, buffer: vec![], pos: 0, eof: false };
+        chunks.fill_to(HEADER_PREFETCH)?;
+
+        // The version and flags fit in the prefetched bytes; checking them
+        // first keeps the whole input for beta-era files and fails encrypted
+        // ones without reading on.
+        let (header, end) = Header::read(&chunks.buffer)?;
+        if chunks.buffer.get(end) == Some(&ENCRYPTED_HEADER) {
+            return Err(ErrorMessage(ENCRYPTED_DOCUMENT));
+        }
+
+        // Compressed bodies are one deflate stream, inflated whole.
+        if header.flags.contains(HeaderFlags::COMPRESSED) {
+            chunks.fill_to(usize::MAX)?;
+            let yad = YAD::deserialize(std::mem::take(&mut chunks.buffer))?;
+            let rows = yad.rows.into_values().collect::<Vec<_>>().into_iter();
+            return Ok(Self { chunks, header, names: vec![], decoded: Some(rows), now: None, done: false });
+        }
+
+        let header = chunks.parse(body_start)?;
+        let mut stream = Self { chunks, header, names: vec![], decoded: None, now: None, done: false };
+
+        if stream.header.flags.contains(HeaderFlags::DICTIONARY) {
+            stream.names = stream.chunks.parse(|bytes| {
+                read_dictionary(bytes).map(|(names, rest)| (names, bytes.len() - rest.len()))
+            })?;
+        }
+
+        Ok(stream)
+    }
+
+    /// Reads the rows as of `now`: rows and keys whose expiry timestamp
+    /// ([`crate::meta::Meta::expires`]) is not after `now` are skipped, as
+    /// if [`YAD::purge_expired`] had removed them.
+    ///
+    /// # Example
+    /// ```text
+    /// let rows = YadRowStream::open(File::open("sessions.yad")?)?.expiring_at(now);
+    /// ```
+    pub fn expiring_at(mut self, now: u64) -> Self {
+        self.now = Some(now);
+        self
+    }
+
+    /// Returns `row` without its expired keys, or `None` if the row itself
+    /// has expired; always the whole row without [`YadRowStream::expiring_at`].
+    fn unexpired(&self, mut row: Row) -> Option<Row> {
+        let Some(now) = self.now else {
+            return Some(row);
+        };
+        if row.meta.is_expired(now) {
+            return None;
+        }
+        row.keys.retain(|_, key| !key.meta.is_expired(now));
+        Some(row)
+    }
+
+    /// The header read from the source.
+    pub fn header(&self) -> &Header {
+        &self.header
+    }
+
+    /// The version of the document.
+    pub fn version(&self) -> &Version {
+        &self.header.version
+    }
+
+    /// Reads the next row, or `None` at the end of the source.
+    fn read_row(&mut self) -> Result<Option<Row>, ErrorMessage> {
+        self.chunks.fill_to(1)?;
+        if self.chunks.buffer.len() == self.chunks.pos {
+            return Ok(None);
+        }
+
+        let flags = self.header.flags;
+        let names = &self.names;
+        let encoded = flags.contains(HeaderFlags::VARINT_LENGTHS)
+            || flags.contains(HeaderFlags::DELTA_ARRAYS)
+            || flags.contains(HeaderFlags::DICTIONARY);
+
+        let all = Projection::all();
+        let row = self.chunks.parse(|bytes| {
+            let mut reader = Reader::new(bytes);
+            let row = if encoded {
+                let row = decode_row(&mut reader, flags, names)?;
+                Row::read(&mut Reader::new(&row), DuplicatePolicy::KeepLast, &all)?
+            } else {
+                Row::read(&mut reader, DuplicatePolicy::KeepLast, &all)?
+            };
+            Ok((row, reader.position()))
+        })?;
+
+        let mut row = row.ok_or(ErrorMessage(MALFORMED_ROW_VECTOR))?;
+        convert_byte_order(&mut row, flags.byte_order())?;
+        Ok(Some(row))
+    }
+}
+
+impl<R: Read> Iterator for YadRowStream<R> {
+    type Item = Result<Row, ErrorMessage>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let row = match &mut self.decoded {
+                Some(rows) => rows.next()?,
+                None if self.done => return None,
+                None => match self.read_row() {
+                    Ok(Some(row)) => row,
+                    result => {
+                        self.done = true;
+                        return result.transpose();
+                    }
+                },
+            };
+
+            if let Some(row) = self.unexpired(row) {
+                return Some(Ok(row));
+            }
+        }
+    }
+}
@@ -0,0 +1,412 @@
+use std::fmt::{Display, Formatter};
+use float16::f16;
+use float8::F8E4M3;
+use yad_core::constants::error::ErrorMessage;
+use yad_core::constants::types::Type;
+use yad_core::bigint::BigInteger;
+use yad_core::decimal::Decimal;
+use yad_core::Value;
+use crate::error::{
+    TEXT_DUPLICATE_NAME, TEXT_EXPECTED_NAME, TEXT_EXPECTED_VALUE, TEXT_INVALID_EXTENSION, TEXT_INVALID_NUMBER,
+    TEXT_INVALID_VERSION, TEXT_UNEXPECTED_CHARACTER, TEXT_UNTERMINATED_STRING,
+};
+use crate::key::Key;
+use crate::row::Row;
+use crate::{Version, CURRENT_VERSION, YAD};
+
+/// An error found while parsing YAD Text Notation, with its position.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct TextError {
+    /// What went wrong.
+    pub error: ErrorMessage,
+    /// Line of the offending character, starting at 1.
+    pub line: usize,
+    /// Column of the offending character, starting at 1.
+    pub column: usize,
+}
+
+impl Display for TextError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} (line {}, column {})", self.error.0, self.line, self.column)
+    }
+}
+
+impl From<TextError> for ErrorMessage {
+    fn from(error: TextError) -> Self {
+        error.error
+    }
+}
+
+/// Returns `true` if `c` may appear in an unquoted name.
+fn is_name_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || c == '_' || c == '-'
+}
+
+/// Returns `true` if `c` may appear in a number or keyword token.
+fn is_token_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || matches!(c, '_' | '.' | '+' | '-')
+}
+
+/// Writes `name` bare when it only holds name characters, quoted otherwise.
+fn text_name(name: &str) -> String {
+    if !name.is_empty() && name.chars().all(is_name_char) {
+        name.to_string()
+    } else {
+        format!("{:?}", name)
+    }
+}
+
+/// Writes a value in text notation; numbers always carry their width suffix.
+fn text_value(value: &Value) -> Result<String, ErrorMessage> {
+    let width = value.length.as_byte_count() * 8;
+
+    Ok(match value.r#type {
+        Type::Uint => format!("{}u{}", value, width),
+        Type::Int => format!("{}i{}", value, width),
+        Type::Float => format!("{}f{}", value, width),
+        Type::Bool | Type::True | Type::False => format!("{}", value),
+        Type::Extension if value.is_decimal() => format!("{}d", value.as_decimal()?),
+        Type::Extension if value.is_big_integer() => format!("{}n", value.as_big_integer()?),
+        Type::Extension => value.try_display()?,
+        Type::String => format!("{:?}", TryInto::<String>::try_into(value.clone())?),
+        Type::Array => {
+            let items = TryInto::<Vec<Value>>::try_into(value.clone())?
+                .iter()
+                .map(text_value)
+                .collect::<Result<Vec<String>, ErrorMessage>>()?;
+            format!("[{}]", items.join(", "))
+        }
+    })
+}
+
+/// Builds a number from its text body and width suffix (`u8` ... `f64`,
+/// `d` for decimals or `n` for big integers).
+///
+/// Without a suffix, integers are `i64` and anything with a fraction, an
+/// exponent, `inf` or `nan` is `f64`.
+fn number_value(token: &str) -> Option<Value> {
+    const SUFFIXES: [&str; 12] = ["u16", "u32", "u64", "i16", "i32", "i64", "f16", "f32", "f64", "u8", "i8", "f8"];
+
+    let token = token.replace('_', "");
+    if let Some(body) = token.strip_suffix('d') {
+        return body.parse::<Decimal>().ok().map(Value::from);
+    }
+    // `nan` also ends in `n`.
+    if let Some(big) = token.strip_suffix('n').and_then(|body| body.parse::<BigInteger>().ok()) {
+        return Some(Value::from(big));
+    }
+
+    let (body, suffix) = match SUFFIXES.iter().find(|s| token.len() > s.len() && token.ends_with(*s)) {
+        Some(suffix) => (&token[..token.len() - suffix.len()], *suffix),
+        None if token.contains(['.', 'e', 'E', 'n', 'N']) => (token.as_str(), "f64"),
+        None => (token.as_str(), "i64"),
+    };
+
+    Some(match suffix {
+        "u8" => Value::from(body.parse::<u8>().ok()?),
+        "u16" => Value::from(body.parse::<u16>().ok()?),
+        "u32" => Value::from(body.parse::<u32>().ok()?),
+        "u64" => Value::from(body.parse::<u64>().ok()?),
+        "i8" => Value::from(body.parse::<i8>().ok()?),
+        "i16" => Value::from(body.parse::<i16>().ok()?),
+        "i32" => Value::from(body.parse::<i32>().ok()?),
+        "i64" => Value::from(body.parse::<i64>().ok()?),
+        "f8" => Value::from(F8E4M3::from_f64(body.parse::<f64>().ok()?)),
+        "f16" => Value::from(f16::from_f64(body.parse::<f64>().ok()?)),
+        "f32" => Value::from(body.parse::<f32>().ok()?),
+        _ => Value::from(body.parse::<f64>().ok()?),
+    })
+}
+
+/// Builds an extension value from its `ext.<tag>.<data>` token, both in hex,
+/// as written by the `Display` implementation of [`Value`].
+pub(crate) fn extension_value(token: &str) -> Option<Value> {
+    let (tag, data) = token.strip_prefix("ext.")?.split_once('.')?;
+    if tag.len() != 2 || data.len() % 2 != 0 || !data.is_ascii() {
+        return None;
+    }
+
+    let tag = u8::from_str_radix(tag, 16).ok()?;
+    let data = (0..data.len()).step_by(2)
+        .map(|i| u8::from_str_radix(&data[i..i + 2], 16).ok())
+        .collect::<Option<Vec<u8>>>()?;
+    Value::from_extension(tag, &data).ok()
+}
+
+/// A cursor over the text being parsed, tracking line and column.
+struct Parser<'a> {
+    chars: std::iter::Peekable<std::str::Chars<'a>>,
+    line: usize,
+    column: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn new(text: &'a str) -> Self {
+        Self { chars: text.chars().peekable(), line: 1, column: 1 }
+    }
+
+    /// Builds an error at the current position.
+    fn error(&self, error: &'static str) -> TextError {
+        TextError { error: ErrorMessage(error), line: self.line, column: self.column }
+    }
+
+    fn peek(&mut self) -> Option<char> {
+        self.chars.peek().copied()
+    }
+
+    fn next(&mut self) -> Option<char> {
+        let c = self.chars.next()?;
+        if c == '\n' {
+            self.line += 1;
+            self.column = 1;
+        } else {
+            self.column += 1;
+        }
+        Some(c)
+    }
+
+    /// Skips whitespace, `#` comments and the optional `,` / `;` separators.
+    fn skip_blank(&mut self) {
+        while let Some(c) = self.peek() {
+            match c {
+                '#' => while self.peek().is_some_and(|c| c != '\n') {
+                    self.next();
+                },
+                c if c.is_whitespace() || c == ',' || c == ';' => {
+                    self.next();
+                }
+                _ => break,
+            }
+        }
+    }
+
+    /// Consumes `expected` after any blank, or fails with `TEXT_UNEXPECTED_CHARACTER`.
+    fn expect(&mut self, expected: char) -> Result<(), TextError> {
+        self.skip_blank();
+        if self.peek() != Some(expected) {
+            return Err(self.error(TEXT_UNEXPECTED_CHARACTER));
+        }
+        self.next();
+        Ok(())
+    }
+
+    /// Reads a run of characters accepted by `accept`.
+    fn token(&mut self, accept: fn(char) -> bool) -> String {
+        let mut token = String::new();
+        while let Some(c) = self.peek().filter(|c| accept(*c)) {
+            token.push(c);
+            self.next();
+        }
+        token
+    }
+
+    /// Reads a double-quoted string with Rust-style escapes.
+    fn string(&mut self) -> Result<String, TextError> {
+        self.next();
+        let mut text = String::new();
+
+        loop {
+            let c = self.next().ok_or_else(|| self.error(TEXT_UNTERMINATED_STRING))?;
+            match c {
+                '"' => return Ok(text),
+                '\\' => {
+                    let escaped = match self.next() {
+                        Some('n') => '\n',
+                        Some('r') => '\r',
+                        Some('t') => '\t',
+                        Some('0') => '\0',
+                        Some(c @ ('\\' | '"' | '\'')) => c,
+                        Some('u') => {
+                            if self.next() != Some('{') {
+                                return Err(self.error(TEXT_UNEXPECTED_CHARACTER));
+                            }
+                            let code = self.token(|c| c.is_ascii_hexdigit());
+                            if self.next() != Some('}') {
+                                return Err(self.error(TEXT_UNEXPECTED_CHARACTER));
+                            }
+                            u32::from_str_radix(&code, 16).ok()
+                                .and_then(char::from_u32)
+                                .ok_or_else(|| self.error(TEXT_UNEXPECTED_CHARACTER))?
+                        }
+                        _ => return Err(self.error(TEXT_UNEXPECTED_CHARACTER)),
+                    };
+                    text.push(escaped);
+                }
+                c => text.push(c),
+            }
+        }
+    }
+
+    /// Reads a bare or quoted row or key name.
+    fn name(&mut self) -> Result<String, TextError> {
+        self.skip_blank();
+        match self.peek() {
+            Some('"') => self.string(),
+            Some(c) if is_name_char(c) => Ok(self.token(is_name_char)),
+            _ => Err(self.error(TEXT_EXPECTED_NAME)),
+        }
+    }
+
+    /// Reads a string, boolean, number or array.
+    fn value(&mut self) -> Result<Value, TextError> {
+        self.skip_blank();
+        let (line, column) = (self.line, self.column);
+        let at = |error: &'static str| TextError { error: ErrorMessage(error), line, column };
+
+        match self.peek() {
+            Some('"') => {
+                let text = self.string()?;
+                Value::try_from(text).map_err(|e| at(e.0))
+            }
+            Some('[') => {
+                self.next();
+                let mut items = vec![];
+                loop {
+                    self.skip_blank();
+                    if self.peek() == Some(']') {
+                        self.next();
+                        break;
+                    }
+                    items.push(self.value()?);
+                }
+                Value::try_from(items).map_err(|e| at(e.0))
+            }
+            Some(c) if is_token_char(c) => match self.token(is_token_char).as_str() {
+                "true" => Ok(Value::from(true)),
+                "false" => Ok(Value::from(false)),
+                token if token.starts_with("ext.") => extension_value(token).ok_or_else(|| at(TEXT_INVALID_EXTENSION)),
+                token => number_value(token).ok_or_else(|| at(TEXT_INVALID_NUMBER)),
+            },
+            _ => Err(self.error(TEXT_EXPECTED_VALUE)),
+        }
+    }
+
+    /// Reads a `major.minor.patch[-beta]` version.
+    fn version(&mut self) -> Result<Version, TextError> {
+        self.skip_blank();
+        let (line, column) = (self.line, self.column);
+        let token = self.token(is_token_char);
+
+        let (numbers, beta) = token.split_once('-').unwrap_or((&token, "0"));
+        let parts: Vec<Option<u8>> = numbers.split('.').chain([beta]).map(|p| p.parse().ok()).collect();
+
+        match parts.as_slice() {
+            [Some(major), Some(minor), Some(patch), Some(beta)] => Ok(Version {
+                major: *major,
+                minor: *minor,
+                patch: *patch,
+                beta: *beta,
+            }),
+            _ => Err(TextError { error: ErrorMessage(TEXT_INVALID_VERSION), line, column }),
+        }
+    }
+
+    /// Reads the keys of a row up to its closing brace.
+    fn row(&mut self, name: String) -> Result<Row, TextError> {
+        self.expect('{')?;
+        let mut row = Row::new_empty(name);
+
+        loop {
+            self.skip_blank();
+            if self.peek() == Some('}') {
+                self.next();
+                return Ok(row);
+            }
+
+            let (line, column) = (self.line, self.column);
+            let key = self.name()?;
+            self.expect('=')?;
+            let value = self.value()?;
+
+            if row.keys.contains_key(&key) {
+                return Err(TextError { error: ErrorMessage(TEXT_DUPLICATE_NAME), line, column });
+            }
+            row.keys.insert(key.clone(), Key::new(key, value));
+        }
+    }
+}
+
+impl YAD {
+    /// Parses a document written in YAD Text Notation.
+    ///
+    /// # Syntax
+    /// ```text
+    /// # Comments run to the end of the line.
+    /// version 1.0.0-0          # optional, defaults to CURRENT_VERSION
+    ///
+    /// johan {
+    ///     age = 17u8
+    ///     name = "Johan"
+    ///     "favourite numbers" = [3u8, 7u8, -1i16]
+    ///     ratio = 0.5f32
+    ///     active = true
+    /// }
+    /// ```
+    /// - Rows are `name { ... }`; keys are `name = value`. Names are bare
+    ///   (`[A-Za-z0-9_-]+`) or double-quoted strings.
+    /// - Numbers take a width suffix (`u8`...`u64`, `i8`...`i64`, `f8`, `f16`,
+    ///   `f32`, `f64`). Without one, integers are `i64` and floats (including
+    ///   `inf` and `NaN`) are `f64`. `_` may separate digits.
+    /// - Decimals take the `d` suffix and keep their digits: `19.90d`. Big
+    ///   integers take the `n` suffix: `340282366920938463463374607431768211456n`.
+    /// - Strings use Rust-style escapes (`\n`, `\"`, `\u{1F600}`, ...).
+    /// - Whitespace, `,` and `;` all separate entries.
+    ///
+    /// Metadata has no text form.
+    ///
+    /// # Errors
+    /// Returns a [`TextError`] with the line and column of the first problem:
+    /// a syntax error, an invalid number or version, a duplicate row or key
+    /// name, or a value YAD cannot hold (such as an empty string or array).
+    pub fn from_text(text: &str) -> Result<Self, TextError> {
+        let mut parser = Parser::new(text);
+        let mut yad = Self::new_empty(CURRENT_VERSION);
+
+        loop {
+            parser.skip_blank();
+            if parser.peek().is_none() {
+                return Ok(yad);
+            }
+
+            let (line, column) = (parser.line, parser.column);
+            let name = parser.name()?;
+
+            parser.skip_blank();
+            if name == "version" && parser.peek() != Some('{') {
+                yad.version = parser.version()?;
+                continue;
+            }
+
+            if yad.rows.contains_key(&name) {
+                return Err(TextError { error: ErrorMessage(TEXT_DUPLICATE_NAME), line, column });
+            }
+
+            let row = parser.row(name)?;
+            yad.rows.insert(row.name.clone(), row);
+        }
+    }
+
+    /// Writes the document in YAD Text Notation (see [`YAD::from_text`]).
+    ///
+    /// Rows and keys are written in name order and every number carries its
+    /// width suffix, so `YAD::from_text(&yad.to_text()?)` gives back the same
+    /// values. Metadata is not written.
+    ///
+    /// # Errors
+    /// Returns an error if a value is malformed.
+    pub fn to_text(&self) -> Result<String, ErrorMessage> {
+        let mut text = format!("version {}\n", self.version);
+
+        for row in self.rows.values() {
+            text.push_str(&format!("\n{} {{\n", text_name(&row.name)));
+
+            for key in row.keys.values() {
+                text.push_str(&format!("    {} = {}\n", text_name(&key.name), text_value(&key.value)?));
+            }
+
+            text.push_str("}\n");
+        }
+
+        Ok(text)
+    }
+}
@@ -0,0 +1,114 @@
+use std::collections::BTreeMap;
+use yad_core::constants::error::ErrorMessage;
+use yad_core::Value;
+use crate::error::ROW_NOT_FOUND;
+use crate::key::Key;
+use crate::row::Row;
+use crate::YAD;
+
+/// Changes staged by [`YAD::transaction`].
+///
+/// Reads through the transaction see the staged changes. Rows are copied
+/// from the document the first time they are modified, so untouched rows are
+/// never cloned.
+pub struct Transaction<'a> {
+    /// The document as it was when the transaction started.
+    base: &'a YAD,
+    /// Row name → staged row, or `None` if the row is removed.
+    staged: BTreeMap<String, Option<Row>>,
+}
+
+impl<'a> Transaction<'a> {
+    /// Returns the row named `name` as seen by this transaction.
+    pub fn get_row(&self, name: &str) -> Option<&Row> {
+        match self.staged.get(name) {
+            Some(staged) => staged.as_ref(),
+            None => self.base.rows.get(name),
+        }
+    }
+
+    /// Returns a mutable reference to the row named `name`, staging a copy of it.
+    pub fn get_row_mut(&mut self, name: &str) -> Option<&mut Row> {
+        if !self.staged.contains_key(name) {
+            let row = self.base.rows.get(name)?.clone();
+            self.staged.insert(name.to_string(), Some(row));
+        }
+
+        self.staged.get_mut(name)?.as_mut()
+    }
+
+    /// Stages a new row, replacing any row with the same name.
+    pub fn insert_row<S: ToString>(&mut self, name: S, keys: Vec<Key>) {
+        let name = name.to_string();
+        let row = Row::new(&name, keys);
+        self.staged.insert(name, Some(row));
+    }
+
+    /// Stages the removal of a row, returning it if it existed.
+    pub fn remove_row(&mut self, name: &str) -> Option<Row> {
+        let row = self.get_row(name).cloned();
+        self.staged.insert(name.to_string(), None);
+        row
+    }
+
+    /// Stages a key insertion (or replacement) in an existing row.
+    ///
+    /// `value` is a [`Value`] or anything convertible to one (see [`Row::insert_key`]).
+    ///
+    /// # Errors
+    /// Returns `ROW_NOT_FOUND` if the row does not exist, or the conversion error.
+    pub fn insert_key<S: ToString, V>(&mut self, row: &str, name: S, value: V) -> Result<(), ErrorMessage>
+    where
+        V: TryInto<Value>,
+        V::Error: Into<ErrorMessage>,
+    {
+        self.get_row_mut(row).ok_or(ErrorMessage(ROW_NOT_FOUND))?.insert_key(name, value)
+    }
+
+    /// Stages the removal of a key, returning it if it existed.
+    pub fn remove_key(&mut self, row: &str, name: &str) -> Option<Key> {
+        self.get_row(row)?.keys.get(name)?;
+        self.get_row_mut(row)?.remove_key(name)
+    }
+}
+
+impl YAD {
+    /// Runs `f` against a [`Transaction`] and applies its staged changes only if it returns `Ok`.
+    ///
+    /// If `f` returns `Err`, nothing is applied and the document is left exactly
+    /// as it was. Secondary indexes are updated for the applied rows.
+    ///
+    /// # Example
+    /// ```text
+    /// yad.transaction(|tx| {
+    ///     tx.insert_key("alice", "balance", Value::from(90u8))?;
+    ///     tx.insert_key("bob", "balance", Value::from(110u8))?;
+    ///     Ok::<_, ErrorMessage>(())
+    /// })?;
+    /// ```
+    ///
+    /// # Returns
+    /// - `Ok(T)`: The value returned by `f`, after the changes are applied.
+    /// - `Err(E)`: The error returned by `f`; no change is applied.
+    pub fn transaction<T, E, F>(&mut self, f: F) -> Result<T, E>
+    where
+        F: FnOnce(&mut Transaction) -> Result<T, E>,
+    {
+        let mut tx = Transaction { base: self, staged: BTreeMap::new() };
+        let output = f(&mut tx)?;
+        let staged = tx.staged;
+
+        for (name, row) in staged {
+            match row {
+                Some(row) => {
+                    self.adopt_row(row);
+                }
+                None => {
+                    self.remove_row(&name);
+                }
+            }
+        }
+
+        Ok(output)
+    }
+}
@@ -0,0 +1,89 @@
+use yad_core::constants::error::ErrorMessage;
+use yad_core::Value;
+use crate::observe::ChangeEvent;
+use crate::query::split_path;
+use crate::YAD;
+
+/// A value rewritten (or to be rewritten) by [`YAD::transform`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ValueChange {
+    /// Name of the row holding the key.
+    pub row: String,
+    /// Name of the key.
+    pub key: String,
+    /// The current value.
+    pub before: Value,
+    /// The value returned by the transformation.
+    pub after: Value,
+}
+
+impl YAD {
+    /// Computes the changes [`YAD::transform`] would make, without modifying the document.
+    ///
+    /// `selector` uses the same syntax as [`YAD::select`]: a key name, or
+    /// `row.key` where the row part may be `*`. `f` is called once per matching
+    /// value, in row then key name order. Values that `f` returns unchanged
+    /// are not reported.
+    ///
+    /// # Errors
+    /// Returns the first error returned by `f`.
+    pub fn plan_transform<F>(&self, selector: &str, mut f: F) -> Result<Vec<ValueChange>, ErrorMessage>
+    where
+        F: FnMut(Value) -> Result<Value, ErrorMessage>,
+    {
+        let (row_name, key_name) = split_path(selector);
+        let mut changes: Vec<ValueChange> = vec![];
+
+        for row in self.rows.values().filter(|row| row_name.as_ref().is_none_or(|name| row.name == *name)) {
+            let Some(key) = row.keys.get(key_name.as_ref()) else { continue };
+            let after = f(key.value.clone())?;
+
+            if after != key.value {
+                changes.push(ValueChange {
+                    row: row.name.clone(),
+                    key: key.name.clone(),
+                    before: key.value.clone(),
+                    after,
+                });
+            }
+        }
+
+        Ok(changes)
+    }
+
+    /// Rewrites every value matching `selector` with `f`.
+    ///
+    /// Every value is transformed before any is written, so if `f` fails the
+    /// document is left untouched. Use [`YAD::plan_transform`] for a dry run.
+    /// Observers see a `KeyUpdated` event for every rewritten key.
+    ///
+    /// # Example
+    /// ```text
+    /// yad.transform("*.timeout_ms", |v| {
+    ///     let ms: u32 = v.try_into()?;
+    ///     Ok(Value::from(ms * 1000))
+    /// })?;
+    /// ```
+    ///
+    /// # Returns
+    /// - `Ok(Vec<ValueChange>)`: The changes that were applied.
+    /// - `Err(ErrorMessage)`: The first error returned by `f`.
+    pub fn transform<F>(&mut self, selector: &str, f: F) -> Result<Vec<ValueChange>, ErrorMessage>
+    where
+        F: FnMut(Value) -> Result<Value, ErrorMessage>,
+    {
+        let changes = self.plan_transform(selector, f)?;
+
+        for change in &changes {
+            let Some(mut row) = self.detach_row(&change.row) else { continue };
+            if let Some(key) = row.keys.get_mut(&change.key) {
+                key.set_value(change.after.clone());
+            }
+            self.attach_row(row);
+
+            self.notify(|| ChangeEvent::KeyUpdated { row: change.row.clone(), key: change.key.clone() });
+        }
+
+        Ok(changes)
+    }
+}
@@ -0,0 +1,132 @@
+use yad_core::constants::error::ErrorMessage;
+use yad_core::varint::{read_varint, transcode_from_varint, transcode_to_varint, write_varint};
+use yad_core::Reader;
+use crate::constants::{KEY_END_HEADER, KEY_NAME_HEADER, KEY_NAME_REF_HEADER, KEY_START_HEADER, ROW_END_HEADER, ROW_NAME_HEADER, ROW_START_HEADER};
+use crate::error::{MALFORMED_KEY_NAME_VECTOR, MALFORMED_KEY_VECTOR, MALFORMED_ROW_NAME_VECTOR, MALFORMED_ROW_VECTOR};
+use crate::dictionary::{decode_name_ref_index, encode_name_ref};
+use crate::meta::Meta;
+use crate::{decode_name_ref, encode_name_into};
+
+/// Copies a row or key name at the reader's position, rewriting its length descriptor.
+fn transcode_name(reader: &mut Reader, out: &mut Vec<u8>, header: u8, to_varint: bool, error: &'static str) -> Result<(), ErrorMessage> {
+    if header == KEY_NAME_HEADER && reader.peek().map(|b| b & 0xF0) == Some(KEY_NAME_REF_HEADER) {
+        return transcode_name_ref(reader, out, to_varint);
+    }
+
+    if to_varint {
+        let first = reader.peek().ok_or(ErrorMessage(error))?;
+        let (name, consumed) = decode_name_ref(reader.remaining(), header).ok_or(ErrorMessage(error))?;
+        reader.advance(consumed)?;
+
+        out.push(first);
+        write_varint(name.len() as u64, out);
+        out.extend_from_slice(name.as_bytes());
+    } else {
+        if reader.peek().map(|b| b & 0xF0) != Some(header) {
+            return Err(ErrorMessage(error));
+        }
+        reader.advance(1)?;
+
+        let (len, consumed) = read_varint(reader.remaining())?;
+        reader.advance(consumed)?;
+        let len = usize::try_from(len).map_err(|_| ErrorMessage(error))?;
+        let name = std::str::from_utf8(reader.take(len)?).map_err(|_| ErrorMessage(error))?;
+
+        encode_name_into(name, header, out)?;
+    }
+
+    Ok(())
+}
+
+/// Copies a dictionary reference at the reader's position, rewriting its index.
+fn transcode_name_ref(reader: &mut Reader, out: &mut Vec<u8>, to_varint: bool) -> Result<(), ErrorMessage> {
+    if to_varint {
+        let first = reader.peek().ok_or(ErrorMessage(MALFORMED_KEY_NAME_VECTOR))?;
+        let (index, consumed) = decode_name_ref_index(reader.remaining()).ok_or(ErrorMessage(MALFORMED_KEY_NAME_VECTOR))?;
+        reader.advance(consumed)?;
+
+        out.push(first);
+        write_varint(index as u64, out);
+    } else {
+        reader.advance(1)?;
+
+        let (index, consumed) = read_varint(reader.remaining())?;
+        reader.advance(consumed)?;
+
+        encode_name_ref(usize::try_from(index).map_err(|_| ErrorMessage(MALFORMED_KEY_NAME_VECTOR))?, out);
+    }
+
+    Ok(())
+}
+
+/// Copies the metadata block at the reader's position, if any; it has no
+/// nibble-based descriptor and is the same in both encodings.
+pub(crate) fn copy_meta(reader: &mut Reader, out: &mut Vec<u8>) -> Result<(), ErrorMessage> {
+    let (_, consumed) = Meta::decode(reader.remaining())?;
+    out.extend_from_slice(reader.take(consumed)?);
+    Ok(())
+}
+
+/// Rewrites every length descriptor of a body of rows (the bytes after the
+/// header sections), to varints when `to_varint` is set and back otherwise.
+fn transcode_body(body: &[u8], to_varint: bool) -> Result<Vec<u8>, ErrorMessage> {
+    let mut reader = Reader::new(body);
+    let mut out = Vec::with_capacity(body.len());
+
+    while reader.peek().is_some() {
+        transcode_row(&mut reader, &mut out, to_varint)?;
+    }
+
+    Ok(out)
+}
+
+/// Rewrites the length descriptors of the row at the reader's position, like
+/// [`transcode_body`] does for every row.
+pub(crate) fn transcode_row(reader: &mut Reader, out: &mut Vec<u8>, to_varint: bool) -> Result<(), ErrorMessage> {
+    if reader.peek() != Some(ROW_START_HEADER) {
+        return Err(ErrorMessage(MALFORMED_ROW_VECTOR));
+    }
+    out.push(ROW_START_HEADER);
+    reader.advance(1)?;
+
+    transcode_name(reader, out, ROW_NAME_HEADER, to_varint, MALFORMED_ROW_NAME_VECTOR)?;
+    copy_meta(reader, out)?;
+
+    loop {
+        match reader.peek() {
+            Some(ROW_END_HEADER) => break,
+            Some(KEY_START_HEADER) => {
+                out.push(KEY_START_HEADER);
+                reader.advance(1)?;
+
+                transcode_name(reader, out, KEY_NAME_HEADER, to_varint, MALFORMED_KEY_NAME_VECTOR)?;
+                copy_meta(reader, out)?;
+
+                loop {
+                    match reader.peek() {
+                        Some(KEY_END_HEADER) => break,
+                        Some(_) if to_varint => transcode_to_varint(reader, out)?,
+                        Some(_) => transcode_from_varint(reader, out)?,
+                        None => return Err(ErrorMessage(MALFORMED_KEY_VECTOR)),
+                    }
+                }
+                out.push(KEY_END_HEADER);
+                reader.advance(1)?;
+            }
+            _ => return Err(ErrorMessage(MALFORMED_ROW_VECTOR)),
+        }
+    }
+    out.push(ROW_END_HEADER);
+    reader.advance(1)
+}
+
+/// Rewrites the length descriptors of names, strings and arrays in a body of
+/// rows as LEB128 varints (see [`crate::options::LengthEncoding::Varint`]).
+pub(crate) fn body_to_varint(body: &[u8]) -> Result<Vec<u8>, ErrorMessage> {
+    transcode_body(body, true)
+}
+
+/// Rewrites a body written by [`body_to_varint`] with fixed length descriptors.
+pub(crate) fn body_from_varint(body: &[u8]) -> Result<Vec<u8>, ErrorMessage> {
+    transcode_body(body, false)
+}
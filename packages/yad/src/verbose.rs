@@ -0,0 +1,214 @@
+use std::borrow::Cow;
+use yad_core::constants::error::ErrorMessage;
+use yad_core::Value;
+use crate::compression::MAX_INFLATED_SIZE;
+use crate::constants::{KEY_END_HEADER, KEY_NAME_HEADER, KEY_START_HEADER, ROW_END_HEADER, ROW_NAME_HEADER, ROW_START_HEADER};
+use crate::error::{MALFORMED_KEY_NAME_VECTOR, MALFORMED_ROW_NAME_VECTOR};
+use crate::header::{body_start, decode_body, Header};
+use crate::key::Key;
+use crate::options::convert_byte_order;
+use crate::row::Row;
+use crate::meta::Meta;
+use crate::{decode_name, YAD};
+
+/// A run of bytes the parser did not recognize.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Segment {
+    /// Offset of the first byte in the input.
+    pub offset: usize,
+    /// The ignored bytes.
+    pub bytes: Vec<u8>,
+}
+
+/// A key whose value could not be decoded, kept as raw bytes.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct UnknownValue {
+    /// Offset of the value's first byte in the input.
+    pub offset: usize,
+    /// Name of the row holding the key.
+    pub row: String,
+    /// Name of the key.
+    pub key: String,
+    /// The raw value bytes, up to (not including) the key end header.
+    pub bytes: Vec<u8>,
+}
+
+/// Everything [`YAD::deserialize_verbose`] did not turn into rows or keys.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Leftovers {
+    /// Unrecognized bytes found between or inside rows.
+    pub segments: Vec<Segment>,
+    /// Bytes after the last complete row, including a truncated row.
+    pub trailing: Option<Segment>,
+    /// Keys whose values could not be decoded. These keys are not part of the document.
+    pub unknown_values: Vec<UnknownValue>,
+}
+
+impl Leftovers {
+    /// Returns `true` when the parser consumed every byte.
+    pub fn is_empty(&self) -> bool {
+        self.segments.is_empty() && self.trailing.is_none() && self.unknown_values.is_empty()
+    }
+}
+
+/// Returns the position of the first byte at or after `from` that is one of `stops`.
+fn find_any(bytes: &[u8], from: usize, stops: &[u8]) -> Option<usize> {
+    bytes[from..].iter().position(|b| stops.contains(b)).map(|p| from + p)
+}
+
+/// Reads a key starting at `pos` (on its start header).
+///
+/// # Returns
+/// - `Ok(Some((Option<Key>, usize)))`: The key (or `None` if its value was
+///   unknown) and the position after the key end header.
+/// - `Ok(None)`: If the input ends before the key is complete.
+fn read_key(bytes: &[u8], mut pos: usize, row: &str, leftovers: &mut Leftovers) -> Result<Option<(Option<Key>, usize)>, ErrorMessage> {
+    pos += 1;
+
+    let (name, consumed) = decode_name(&bytes[pos..], KEY_NAME_HEADER)
+        .ok_or(ErrorMessage(MALFORMED_KEY_NAME_VECTOR))?;
+    pos += consumed;
+
+    let (meta, consumed) = Meta::decode(&bytes[pos..])?;
+    pos += consumed;
+
+    let value_start = pos;
+
+    let key = match Value::decode_in_place(&bytes[pos..]) {
+        Ok(value) => {
+            pos += value.bytes.len();
+            let mut key = Key::new(name, value);
+            key.meta = meta;
+
+            // Further values of a multi-value key; anything undecodable is left as a segment.
+            while bytes.get(pos).is_some_and(|b| *b != KEY_END_HEADER) {
+                let Ok(value) = Value::decode_in_place(&bytes[pos..]) else { break };
+                pos += value.bytes.len();
+                key.push_value(value);
+            }
+
+            Some(key)
+        }
+        Err(_) => {
+            let Some(end) = find_any(bytes, pos, &[KEY_END_HEADER]) else { return Ok(None) };
+            leftovers.unknown_values.push(UnknownValue {
+                offset: value_start,
+                row: row.to_string(),
+                key: name,
+                bytes: bytes[value_start..end].to_vec(),
+            });
+            pos = end;
+            None
+        }
+    };
+
+    let Some(end) = find_any(bytes, pos, &[KEY_END_HEADER]) else { return Ok(None) };
+
+    if end > pos {
+        leftovers.segments.push(Segment { offset: pos, bytes: bytes[pos..end].to_vec() });
+    }
+
+    Ok(Some((key, end + 1)))
+}
+
+/// Reads a row starting at `pos` (on its start header).
+///
+/// # Returns
+/// - `Ok(Some((Row, usize)))`: The row and the position after its end header.
+/// - `Ok(None)`: If the input ends before the row is complete.
+fn read_row(bytes: &[u8], mut pos: usize, leftovers: &mut Leftovers) -> Result<Option<(Row, usize)>, ErrorMessage> {
+    pos += 1;
+
+    let (name, consumed) = decode_name(&bytes[pos..], ROW_NAME_HEADER)
+        .ok_or(ErrorMessage(MALFORMED_ROW_NAME_VECTOR))?;
+    pos += consumed;
+
+    let (meta, consumed) = Meta::decode(&bytes[pos..])?;
+    pos += consumed;
+
+    let mut row = Row::new_empty(&name);
+    row.meta = meta;
+
+    loop {
+        match bytes.get(pos) {
+            None => return Ok(None),
+            Some(&ROW_END_HEADER) => return Ok(Some((row, pos + 1))),
+            Some(&KEY_START_HEADER) => {
+                let Some((key, next)) = read_key(bytes, pos, &name, leftovers)? else { return Ok(None) };
+                if let Some(key) = key {
+                    row.keys.insert(key.name.clone(), key);
+                }
+                pos = next;
+            }
+            Some(_) => {
+                let end = find_any(bytes, pos, &[KEY_START_HEADER, ROW_END_HEADER]).unwrap_or(bytes.len());
+                leftovers.segments.push(Segment { offset: pos, bytes: bytes[pos..end].to_vec() });
+                pos = end;
+            }
+        }
+    }
+}
+
+impl YAD {
+    /// Deserializes a YAD document in a single pass, reporting everything that was ignored.
+    ///
+    /// Unlike [`YAD::deserialize`], which silently skips bytes it cannot place,
+    /// this walks the input once and returns the document together with
+    /// [`Leftovers`]: unrecognized segments, trailing bytes, and keys whose
+    /// values could not be decoded, each with its offset in `bytes`. The
+    /// signature, schema, bloom filter and checksum sections are skipped
+    /// without being checked.
+    ///
+    /// Header flags are honoured like [`YAD::deserialize`] does. Bodies that
+    /// are compressed or use a dictionary, varint lengths or delta arrays are
+    /// rewritten before the walk, so offsets then point into the rewritten body.
+    ///
+    /// # Errors
+    /// Returns an error if the version header, the sections before the rows,
+    /// an encoded body, or a row or key name is malformed.
+    pub fn deserialize_verbose(bytes: Vec<u8>) -> Result<(Self, Leftovers), ErrorMessage> {
+        let (Header { version, flags }, start) = body_start(&bytes)?;
+
+        let rewritten = match decode_body(bytes.get(start..).unwrap_or_default(), flags, Some(MAX_INFLATED_SIZE))? {
+            Cow::Borrowed(_) => None,
+            Cow::Owned(body) => Some(body),
+        };
+        let (bytes, mut pos) = match &rewritten {
+            Some(body) => (body.as_slice(), 0),
+            None => (bytes.as_slice(), start),
+        };
+
+        let mut leftovers = Leftovers::default();
+        let mut rows: Vec<Row> = vec![];
+
+        while pos < bytes.len() {
+            if bytes[pos] != ROW_START_HEADER {
+                match find_any(bytes, pos, &[ROW_START_HEADER]) {
+                    Some(end) => {
+                        leftovers.segments.push(Segment { offset: pos, bytes: bytes[pos..end].to_vec() });
+                        pos = end;
+                    }
+                    None => {
+                        leftovers.trailing = Some(Segment { offset: pos, bytes: bytes[pos..].to_vec() });
+                        break;
+                    }
+                }
+                continue;
+            }
+
+            match read_row(bytes, pos, &mut leftovers)? {
+                Some((mut row, next)) => {
+                    convert_byte_order(&mut row, flags.byte_order())?;
+                    rows.push(row);
+                    pos = next;
+                }
+                None => {
+                    leftovers.trailing = Some(Segment { offset: pos, bytes: bytes[pos..].to_vec() });
+                    break;
+                }
+            }
+        }
+
+        Ok((Self::new(version, rows), leftovers))
+    }
+}
@@ -0,0 +1,86 @@
+use yad_core::constants::error::ErrorMessage;
+use yad_core::visit::ValueVisitor;
+use crate::key::Key;
+use crate::row::Row;
+use crate::YAD;
+
+/// A [`ValueVisitor`] that is also told which row and key it is in, for
+/// [`YAD::walk`].
+///
+/// Every method does nothing by default, so value visitors only need an
+/// empty `impl DocumentVisitor for MyVisitor {}` to walk a whole document.
+pub trait DocumentVisitor: ValueVisitor {
+    /// Called before the keys of `row`. Returning `false` skips them;
+    /// [`DocumentVisitor::leave_row`] is still called.
+    fn enter_row(&mut self, _row: &Row) -> bool {
+        true
+    }
+
+    /// Called after the keys of `row`.
+    fn leave_row(&mut self, _row: &Row) {}
+
+    /// Called before the values of `key`. Returning `false` skips them;
+    /// [`DocumentVisitor::leave_key`] is still called.
+    fn enter_key(&mut self, _row: &Row, _key: &Key) -> bool {
+        true
+    }
+
+    /// Called after the values of `key`.
+    fn leave_key(&mut self, _row: &Row, _key: &Key) {}
+}
+
+impl YAD {
+    /// Traverses every value of the document, in row then key name order,
+    /// walking each one with [`yad_core::Value::walk`].
+    ///
+    /// Every value of a multi-value key is walked in turn, each with its own
+    /// [`yad_core::visit::Location`] starting at offset `0`.
+    ///
+    /// # Example
+    /// ```
+    /// use serde_yad::{CURRENT_VERSION, Value, YAD};
+    /// use serde_yad::key::Key;
+    /// use serde_yad::row::Row;
+    /// use serde_yad::walk::DocumentVisitor;
+    /// use yad_core::visit::{Location, ValueVisitor};
+    ///
+    /// #[derive(Default)]
+    /// struct Strings(Vec<String>);
+    ///
+    /// impl ValueVisitor for Strings {
+    ///     fn visit_string(&mut self, _at: Location<'_>, value: &str) {
+    ///         self.0.push(value.to_string());
+    ///     }
+    /// }
+    ///
+    /// impl DocumentVisitor for Strings {}
+    ///
+    /// let tags = Value::try_from(vec![Value::try_from("admin").unwrap(), Value::try_from("staff").unwrap()]).unwrap();
+    /// let yad = YAD::new(CURRENT_VERSION, vec![
+    ///     Row::new("johan", vec![Key::new("name", Value::try_from("Johan").unwrap()), Key::new("tags", tags)]),
+    /// ]);
+    ///
+    /// let mut strings = Strings::default();
+    /// yad.walk(&mut strings).unwrap();
+    /// assert_eq!(strings.0, ["Johan", "admin", "staff"]);
+    /// ```
+    ///
+    /// # Errors
+    /// Returns the first error returned by [`yad_core::Value::walk`].
+    pub fn walk(&self, visitor: &mut impl DocumentVisitor) -> Result<(), ErrorMessage> {
+        for row in self.rows.values() {
+            if visitor.enter_row(row) {
+                for key in row.keys.values() {
+                    if visitor.enter_key(row, key) {
+                        for value in key.values() {
+                            value.walk(visitor)?;
+                        }
+                    }
+                    visitor.leave_key(row, key);
+                }
+            }
+            visitor.leave_row(row);
+        }
+        Ok(())
+    }
+}
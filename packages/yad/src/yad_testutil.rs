@@ -0,0 +1,127 @@
+use yad_core::Value;
+use crate::key::Key;
+use crate::row::Row;
+use crate::{CURRENT_VERSION, YAD};
+
+/// Names the generator draws from half of the time, so documents repeat key
+/// names across rows the way real ones do (and dictionaries have work).
+const COMMON_NAMES: [&str; 8] = ["id", "name", "age", "tags", "at", "score", "ünïcode", "名前"];
+
+/// Characters of the names the generator makes up.
+const NAME_CHARS: [char; 12] = ['a', 'b', 'k', 'x', 'z', '0', '7', '_', '-', 'é', 'ß', '字'];
+
+/// Generates random, valid documents from a seed, so downstream pipelines can
+/// be fuzzed against arbitrary input without a property-testing crate.
+///
+/// The same seed always yields the same documents. Besides every value type,
+/// the generator produces what the optional encodings act on: key names
+/// shared between rows, sorted arrays of unsigned integers and nested arrays.
+///
+/// # Example
+/// ```text
+/// let mut generator = DocumentGenerator::new(42);
+/// for _ in 0..1_000 {
+///     let yad = generator.document();
+///     assert_eq!(YAD::deserialize(pipeline(yad.serialize()?)?)?, yad);
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct DocumentGenerator {
+    state: u64,
+    /// Most rows in a document.
+    pub max_rows: usize,
+    /// Most keys in a row.
+    pub max_keys: usize,
+    /// How deep arrays nest.
+    pub max_depth: u32,
+}
+
+impl DocumentGenerator {
+    /// Creates a generator of documents of up to 8 rows of 8 keys, with arrays nested 3 deep.
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed, max_rows: 8, max_keys: 8, max_depth: 3 }
+    }
+
+    /// Returns the next number of a SplitMix64 sequence.
+    fn next(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// Returns a number below `bound`, which must not be zero.
+    fn below(&mut self, bound: usize) -> usize {
+        (self.next() % bound as u64) as usize
+    }
+
+    /// Returns a non-empty name, a common one half of the time.
+    pub fn name(&mut self) -> String {
+        if self.below(2) == 0 {
+            return COMMON_NAMES[self.below(COMMON_NAMES.len())].to_string();
+        }
+
+        let len = 1 + self.below(12);
+        (0..len).map(|_| NAME_CHARS[self.below(NAME_CHARS.len())]).collect()
+    }
+
+    /// Returns a value of any type.
+    pub fn value(&mut self) -> Value {
+        self.value_at(self.max_depth)
+    }
+
+    fn value_at(&mut self, depth: u32) -> Value {
+        let bits = self.next();
+        let kinds = if depth == 0 { 12 } else { 14 };
+
+        match self.below(kinds) {
+            0 => Value::from(bits as u8),
+            1 => Value::from(bits as u16),
+            2 => Value::from(bits as u32),
+            3 => Value::from(bits),
+            4 => Value::from(bits as i8),
+            5 => Value::from(bits as i16),
+            6 => Value::from(bits as i32),
+            7 => Value::from(bits as i64),
+            8 => Value::from(f32::from_bits(bits as u32)),
+            9 => Value::from(f64::from_bits(bits)),
+            10 => Value::from(bits & 1 == 1),
+            11 => Value::try_from(self.name()).expect("names are not empty"),
+            12 => {
+                let len = 2 + self.below(32);
+                let start = self.next() >> 8;
+                let mut values: Vec<u64> = (0..len as u64).map(|i| start + i * (1 + (bits & 0xFF))).collect();
+                values.dedup();
+                Value::from_sorted_u64s(&values).expect("values are sorted and not empty")
+            }
+            _ => {
+                let len = 1 + self.below(6);
+                let items: Vec<Value> = (0..len).map(|_| self.value_at(depth - 1)).collect();
+                Value::try_from(items).expect("arrays are not empty")
+            }
+        }
+    }
+
+    /// Returns a key with a random name and value.
+    pub fn key(&mut self) -> Key {
+        Key::new(self.name(), self.value())
+    }
+
+    /// Returns a row of up to [`DocumentGenerator::max_keys`] keys.
+    pub fn row(&mut self) -> Row {
+        let keys = (0..self.below(self.max_keys + 1)).map(|_| self.key()).collect();
+        Row::new(self.name(), keys)
+    }
+
+    /// Returns a document of up to [`DocumentGenerator::max_rows`] rows.
+    pub fn document(&mut self) -> YAD {
+        let rows = (0..self.below(self.max_rows + 1)).map(|_| self.row()).collect();
+        YAD::new(CURRENT_VERSION, rows)
+    }
+}
+
+/// Returns the first document generated from `seed` (see [`DocumentGenerator`]).
+pub fn random_document(seed: u64) -> YAD {
+    DocumentGenerator::new(seed).document()
+}
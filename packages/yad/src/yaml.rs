@@ -0,0 +1,207 @@
+use float16::f16;
+use float8::F8E4M3;
+use serde_yaml::value::{Tag, TaggedValue};
+use serde_yaml::{Mapping, Number};
+use yad_core::bigint::BigInteger;
+use yad_core::constants::error::ErrorMessage;
+use yad_core::constants::length::ByteLength;
+use yad_core::constants::types::Type;
+use yad_core::decimal::Decimal;
+use yad_core::Value;
+use crate::error::{MALFORMED_YAML, YAML_INVALID_VALUE, YAML_NAME_NOT_TEXT, YAML_ROW_NOT_A_MAPPING};
+use crate::key::Key;
+use crate::row::Row;
+use crate::{text, CURRENT_VERSION, YAD};
+
+/// Returns the text of a YAML mapping key, which must be a row or key name.
+fn name(yaml: &serde_yaml::Value) -> Result<&str, ErrorMessage> {
+    yaml.as_str().ok_or(ErrorMessage(YAML_NAME_NOT_TEXT))
+}
+
+/// Wraps `value` in the tag `tag`, written `!tag`.
+fn tagged(tag: &str, value: serde_yaml::Value) -> serde_yaml::Value {
+    serde_yaml::Value::Tagged(Box::new(TaggedValue { tag: Tag::new(tag), value }))
+}
+
+/// Converts a value into YAML.
+///
+/// Numbers are written plain when reading them back gives the same width
+/// (smallest integers, unsigned when non-negative, and `f64` floats) and
+/// tagged with their width otherwise, such as `!u32 7`.
+fn value_to_yaml(value: &Value) -> Result<serde_yaml::Value, ErrorMessage> {
+    let v = value.clone();
+    let width = value.length.as_byte_count() * 8;
+
+    let number = match (value.r#type, value.length) {
+        (Type::Uint, ByteLength::One) => Number::from(TryInto::<u8>::try_into(v)?),
+        (Type::Uint, ByteLength::Two) => Number::from(TryInto::<u16>::try_into(v)?),
+        (Type::Uint, ByteLength::Four) => Number::from(TryInto::<u32>::try_into(v)?),
+        (Type::Uint, _) => Number::from(TryInto::<u64>::try_into(v)?),
+        (Type::Int, ByteLength::One) => Number::from(TryInto::<i8>::try_into(v)?),
+        (Type::Int, ByteLength::Two) => Number::from(TryInto::<i16>::try_into(v)?),
+        (Type::Int, ByteLength::Four) => Number::from(TryInto::<i32>::try_into(v)?),
+        (Type::Int, _) => Number::from(TryInto::<i64>::try_into(v)?),
+        (Type::Float, _) => Number::from(value.as_f64_lossy()?),
+        (Type::String, _) => return Ok(serde_yaml::Value::String(v.try_into()?)),
+        (Type::Bool | Type::True | Type::False, _) => return Ok(serde_yaml::Value::Bool(v.try_into()?)),
+        (Type::Array, _) => {
+            let items: Vec<Value> = v.try_into()?;
+            return Ok(serde_yaml::Value::Sequence(items.iter().map(value_to_yaml).collect::<Result<_, _>>()?));
+        }
+        (Type::Extension, _) if value.is_decimal() => return Ok(tagged("decimal", serde_yaml::Value::String(value.try_display()?))),
+        (Type::Extension, _) if value.is_big_integer() => return Ok(tagged("bigint", serde_yaml::Value::String(value.try_display()?))),
+        (Type::Extension, _) => return Ok(tagged("ext", serde_yaml::Value::String(value.try_display()?))),
+    };
+
+    let plain = serde_yaml::Value::Number(number);
+    if value_from_yaml(&plain).as_ref() == Ok(value) {
+        return Ok(plain);
+    }
+
+    let prefix = match value.r#type {
+        Type::Uint => "u",
+        Type::Int => "i",
+        _ => "f",
+    };
+    Ok(tagged(&format!("{}{}", prefix, width), plain))
+}
+
+/// Returns the smallest unsigned, or for negative numbers signed, `Value` holding `n`.
+fn integer_value(n: &Number) -> Option<Value> {
+    if let Some(n) = n.as_u64() {
+        return Some(match n {
+            n if n <= u8::MAX as u64 => Value::from(n as u8),
+            n if n <= u16::MAX as u64 => Value::from(n as u16),
+            n if n <= u32::MAX as u64 => Value::from(n as u32),
+            n => Value::from(n),
+        });
+    }
+
+    Some(match n.as_i64()? {
+        n if n >= i8::MIN as i64 => Value::from(n as i8),
+        n if n >= i16::MIN as i64 => Value::from(n as i16),
+        n if n >= i32::MIN as i64 => Value::from(n as i32),
+        n => Value::from(n),
+    })
+}
+
+/// Reads a value tagged `tag` by [`value_to_yaml`].
+fn tagged_value(tag: &str, yaml: &serde_yaml::Value) -> Option<Value> {
+    let integer = || yaml.as_u64().map(i128::from).or_else(|| yaml.as_i64().map(i128::from));
+
+    Some(match tag {
+        "u8" => Value::from(u8::try_from(integer()?).ok()?),
+        "u16" => Value::from(u16::try_from(integer()?).ok()?),
+        "u32" => Value::from(u32::try_from(integer()?).ok()?),
+        "u64" => Value::from(u64::try_from(integer()?).ok()?),
+        "i8" => Value::from(i8::try_from(integer()?).ok()?),
+        "i16" => Value::from(i16::try_from(integer()?).ok()?),
+        "i32" => Value::from(i32::try_from(integer()?).ok()?),
+        "i64" => Value::from(i64::try_from(integer()?).ok()?),
+        "f8" => Value::from(F8E4M3::from_f64(yaml.as_f64()?)),
+        "f16" => Value::from(f16::from_f64(yaml.as_f64()?)),
+        "f32" => Value::from(yaml.as_f64()? as f32),
+        "f64" => Value::from(yaml.as_f64()?),
+        "decimal" => Value::from(yaml.as_str()?.parse::<Decimal>().ok()?),
+        "bigint" => Value::from(yaml.as_str()?.parse::<BigInteger>().ok()?),
+        "ext" => text::extension_value(yaml.as_str()?)?,
+        _ => return None,
+    })
+}
+
+/// Converts YAML into a value, the inverse of [`value_to_yaml`].
+fn value_from_yaml(yaml: &serde_yaml::Value) -> Result<Value, ErrorMessage> {
+    let invalid = || ErrorMessage(YAML_INVALID_VALUE);
+
+    match yaml {
+        serde_yaml::Value::Bool(b) => Ok(Value::from(*b)),
+        serde_yaml::Value::Number(n) if n.is_f64() => Ok(Value::from(n.as_f64().ok_or_else(invalid)?)),
+        serde_yaml::Value::Number(n) => integer_value(n).ok_or_else(invalid),
+        serde_yaml::Value::String(s) => Value::try_from(s.as_str()),
+        serde_yaml::Value::Sequence(items) => {
+            Value::try_from(items.iter().map(value_from_yaml).collect::<Result<Vec<Value>, _>>()?)
+        }
+        serde_yaml::Value::Tagged(tagged) => {
+            let tag = tagged.tag.to_string();
+            tagged_value(tag.trim_start_matches('!'), &tagged.value).ok_or_else(invalid)
+        }
+        serde_yaml::Value::Null | serde_yaml::Value::Mapping(_) => Err(invalid()),
+    }
+}
+
+impl YAD {
+    /// Writes the document as YAML: a mapping of rows, each a mapping of keys
+    /// to values, for reviewing configurations as plain text.
+    ///
+    /// Strings, booleans and arrays map directly. Numbers are plain when
+    /// [`YAD::from_yaml_str`] reads them back with the same width, and tagged
+    /// with it otherwise (`!u32 7`, `!f32 0.5`); decimals, big integers and
+    /// other extension values are tagged strings (`!decimal '19.90'`).
+    ///
+    /// The version and metadata are not written, and multi-value keys keep
+    /// their first value.
+    ///
+    /// # Example
+    /// ```
+    /// use serde_yad::{row::Row, key::Key, YAD, CURRENT_VERSION};
+    ///
+    /// let yad = YAD::new(CURRENT_VERSION, vec![Row::new("server", vec![Key::new("port", 8080u16), Key::new("debug", true)])]);
+    /// let yaml = yad.to_yaml_string().unwrap();
+    /// assert_eq!(yaml, "server:\n  debug: true\n  port: 8080\n");
+    /// assert_eq!(YAD::from_yaml_str(&yaml).unwrap(), yad);
+    /// ```
+    ///
+    /// # Errors
+    /// Returns an error if a value is malformed, or `MALFORMED_YAML` if the
+    /// YAML cannot be written.
+    pub fn to_yaml_string(&self) -> Result<String, ErrorMessage> {
+        let mut rows = Mapping::with_capacity(self.rows.len());
+
+        for row in self.rows.values() {
+            let mut keys = Mapping::with_capacity(row.keys.len());
+            for key in row.keys.values() {
+                keys.insert(serde_yaml::Value::String(key.name.clone()), value_to_yaml(&key.value)?);
+            }
+            rows.insert(serde_yaml::Value::String(row.name.clone()), serde_yaml::Value::Mapping(keys));
+        }
+
+        serde_yaml::to_string(&rows).map_err(|_| ErrorMessage(MALFORMED_YAML))
+    }
+
+    /// Builds a document of the [`CURRENT_VERSION`] from YAML shaped like the
+    /// output of [`YAD::to_yaml_string`].
+    ///
+    /// Untagged integers take the smallest width holding them, unsigned when
+    /// non-negative, and untagged floats are `f64`. An empty document has no rows.
+    ///
+    /// # Errors
+    /// - `MALFORMED_YAML` if the text is not YAML.
+    /// - `YAML_ROW_NOT_A_MAPPING` if the document or one of its rows is not a mapping.
+    /// - `YAML_NAME_NOT_TEXT` if a row or key name is not a string.
+    /// - `YAML_INVALID_VALUE` for nulls, nested mappings, unknown tags and
+    ///   values that do not fit their tag.
+    /// - `STRING_OF_LENGTH_ZERO` / `VEC_OF_LENGTH_ZERO` for empty strings and sequences.
+    pub fn from_yaml_str(yaml: &str) -> Result<Self, ErrorMessage> {
+        let document: serde_yaml::Value = serde_yaml::from_str(yaml).map_err(|_| ErrorMessage(MALFORMED_YAML))?;
+        let mut yad = Self::new_empty(CURRENT_VERSION);
+
+        let rows = match &document {
+            serde_yaml::Value::Null => return Ok(yad),
+            document => document.as_mapping().ok_or(ErrorMessage(YAML_ROW_NOT_A_MAPPING))?,
+        };
+
+        for (row_name, keys) in rows {
+            let keys = keys.as_mapping().ok_or(ErrorMessage(YAML_ROW_NOT_A_MAPPING))?;
+            let mut row = Row::new_empty(name(row_name)?);
+
+            for (key_name, value) in keys {
+                let key = Key::new(name(key_name)?, value_from_yaml(value)?);
+                row.keys.insert(key.name.clone(), key);
+            }
+
+            yad.rows.insert(row.name.clone(), row);
+        }
+
+        Ok(yad)
+    }
+}
@@ -0,0 +1,22 @@
+use serde_yad::builder::YadBuilder;
+use serde_yad::{CURRENT_VERSION, Value};
+
+#[test]
+fn index_reads_and_writes_values() {
+    let mut yad = YadBuilder::new(CURRENT_VERSION)
+        .row("johan", |r| r.key("age", 17u8))
+        .build()
+        .unwrap();
+
+    assert_eq!(yad["johan"]["age"], Value::from(17u8));
+    yad["johan"]["age"] = Value::from(18u8);
+    assert_eq!(yad.get_row("johan").and_then(|row| row.get("age")), Some(&Value::from(18u8)));
+    assert!(yad.get_row("missing").is_none());
+}
+
+#[test]
+#[should_panic(expected = "no key named `name` in row `johan`")]
+fn index_panics_on_missing_key() {
+    let yad = YadBuilder::new(CURRENT_VERSION).row("johan", |r| r).build().unwrap();
+    let _ = &yad["johan"]["name"];
+}
@@ -0,0 +1,95 @@
+//! Tests for the Arbitrary implementations and the random document generator.
+
+use serde_yad::options::{LengthEncoding, SerializeOptions};
+use serde_yad::yad_testutil::{random_document, DocumentGenerator};
+use serde_yad::YAD;
+use yad_core::byte_order::ByteOrder;
+
+fn options() -> Vec<SerializeOptions> {
+    vec![
+        SerializeOptions::default(),
+        SerializeOptions { length_encoding: LengthEncoding::Varint, ..SerializeOptions::default() },
+        SerializeOptions { dictionary: true, delta_arrays: true, ..SerializeOptions::default() },
+        SerializeOptions { byte_order: ByteOrder::Little, dictionary: true, ..SerializeOptions::default() },
+    ]
+}
+
+#[test]
+fn generated_documents_round_trip_with_every_option() {
+    let mut generator = DocumentGenerator::new(7);
+
+    for _ in 0..200 {
+        let yad = generator.document();
+        for options in options() {
+            let bytes = yad.serialize_with_options(&options).unwrap();
+            assert_eq!(YAD::deserialize(bytes).unwrap(), yad, "{options:?}");
+        }
+    }
+}
+
+#[test]
+fn generator_is_deterministic() {
+    assert_eq!(random_document(42), random_document(42));
+    assert_eq!(DocumentGenerator::new(1).row(), DocumentGenerator::new(1).row());
+
+    let mut generator = DocumentGenerator::new(42);
+    assert_eq!(generator.document(), random_document(42));
+    assert_ne!(generator.document(), random_document(42));
+}
+
+#[test]
+fn generator_respects_its_bounds() {
+    let mut generator = DocumentGenerator::new(3);
+    generator.max_rows = 2;
+    generator.max_keys = 1;
+
+    for _ in 0..100 {
+        let yad = generator.document();
+        assert!(yad.rows.len() <= 2);
+        assert!(yad.rows.values().all(|row| row.keys.len() <= 1));
+    }
+}
+
+#[cfg(feature = "proptest")]
+mod proptest_round_trip {
+    use proptest::prelude::*;
+    use serde_yad::key::Key;
+    use serde_yad::row::Row;
+    use serde_yad::{CURRENT_VERSION, Value, YAD};
+
+    proptest! {
+        #[test]
+        fn values_round_trip(value in any::<Value>()) {
+            prop_assert_eq!(Value::decode(value.bytes.to_vec()).unwrap(), value);
+        }
+
+        #[test]
+        fn documents_round_trip(yad in any::<YAD>()) {
+            prop_assert_eq!(YAD::deserialize(yad.serialize().unwrap()).unwrap(), yad);
+        }
+
+        #[test]
+        fn rows_round_trip(row in any::<Row>(), key in any::<Key>()) {
+            let yad = YAD::new(CURRENT_VERSION, vec![row, Row::new("extra", vec![key])]);
+            prop_assert_eq!(YAD::deserialize(yad.serialize().unwrap()).unwrap(), yad);
+        }
+    }
+}
+
+#[cfg(feature = "quickcheck")]
+mod quickcheck_round_trip {
+    use quickcheck::quickcheck;
+    use serde_yad::options::SerializeOptions;
+    use serde_yad::YAD;
+
+    quickcheck! {
+        fn documents_round_trip(yad: YAD) -> bool {
+            YAD::deserialize(yad.serialize().unwrap()).unwrap() == yad
+        }
+
+        fn compact_documents_round_trip(yad: YAD) -> bool {
+            let options = SerializeOptions { dictionary: true, delta_arrays: true, ..SerializeOptions::default() };
+            YAD::deserialize(yad.serialize_with_options(&options).unwrap()).unwrap() == yad
+        }
+    }
+}
@@ -0,0 +1,44 @@
+#![cfg(feature = "arena")]
+
+use serde_yad::arena::Bump;
+use serde_yad::error::MALFORMED_FILE;
+use serde_yad::header::Header;
+use serde_yad::key::Key;
+use serde_yad::options::SerializeOptions;
+use serde_yad::row::Row;
+use serde_yad::{CURRENT_VERSION, Value, YAD};
+use yad_core::constants::error::ErrorMessage;
+
+#[test]
+fn arena_document_matches_owned_decode() {
+    let mut row = Row::new("johan", vec![
+        Key::new("age", Value::from(17u8)),
+        Key::new("tags", Value::try_from(vec![Value::try_from("a").unwrap(), Value::from(true)]).unwrap()),
+    ]);
+    row.meta.set_modified(42);
+    let yad = YAD::new(CURRENT_VERSION, vec![row, Row::new("empty", vec![])]);
+    let bytes = yad.serialize().unwrap();
+
+    let bump = Bump::new();
+    let doc = YAD::deserialize_in(&bytes, &bump).unwrap();
+
+    assert_eq!(doc.rows.len(), 2);
+    assert_eq!(doc.get("johan").unwrap().get("age").unwrap().value().unwrap(), Value::from(17u8));
+    assert!(doc.get("missing").is_none());
+    assert_eq!(doc.to_owned().unwrap(), yad);
+}
+
+#[test]
+fn sections_cut_short_are_malformed() {
+    let yad = YAD::new(CURRENT_VERSION, vec![Row::new("johan", vec![Key::new("age", 17u8)])]);
+    let bytes = yad.serialize_with_options(&SerializeOptions { include_checksum: true, ..SerializeOptions::default() }).unwrap();
+    let (_, header_end) = Header::read(&bytes).unwrap();
+
+    let bump = Bump::new();
+    let checksummed = &bytes[..header_end + 3];
+    assert_eq!(YAD::deserialize_in(checksummed, &bump).err(), Some(ErrorMessage(MALFORMED_FILE)));
+
+    let mut signed = bytes[..header_end].to_vec();
+    signed.push(0xF8);
+    assert_eq!(YAD::deserialize_in(&signed, &bump).err(), Some(ErrorMessage(MALFORMED_FILE)));
+}
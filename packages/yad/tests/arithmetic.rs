@@ -0,0 +1,65 @@
+//! Tests for arithmetic on numeric values.
+
+use float16::f16;
+use serde_yad::key::Key;
+use serde_yad::Value;
+use yad_core::constants::error::{ErrorMessage, ARITHMETIC_OVERFLOW, NOT_A_NUMBER};
+
+#[test]
+fn increments_keep_the_width_until_it_overflows() {
+    let mut counter = Value::from(254u8);
+    counter.increment(1).unwrap();
+    assert_eq!(counter, Value::from(255u8));
+
+    counter.increment(1).unwrap();
+    assert_eq!(counter, Value::from(256u16));
+
+    let mut counter = Value::from(i32::MAX);
+    counter.increment(1).unwrap();
+    assert_eq!(counter, Value::from(i32::MAX as i64 + 1));
+}
+
+#[test]
+fn unsigned_counters_become_signed_below_zero() {
+    let mut counter = Value::from(0u16);
+    counter.increment(-1).unwrap();
+    assert_eq!(counter, Value::from(-1i16));
+}
+
+#[test]
+fn overflowing_64_bits_is_an_error() {
+    let mut counter = Value::from(u64::MAX);
+    assert_eq!(counter.increment(1), Err(ErrorMessage(ARITHMETIC_OVERFLOW)));
+    assert_eq!(counter, Value::from(u64::MAX));
+
+    assert_eq!(Value::from(i64::MIN).checked_add(&Value::from(-1i8)), Err(ErrorMessage(ARITHMETIC_OVERFLOW)));
+}
+
+#[test]
+fn floats_are_promoted_when_they_overflow() {
+    let mut half = Value::from(f16::from_f32(1.5));
+    half.increment(1).unwrap();
+    assert_eq!(half, Value::from(f16::from_f32(2.5)));
+
+    half.increment(70_000).unwrap();
+    assert_eq!(half, Value::from(70_002.5f32));
+}
+
+#[test]
+fn sums_take_the_wider_operand_and_the_richer_type() {
+    assert_eq!(Value::from(200u8).checked_add(&Value::from(1u32)), Ok(Value::from(201u32)));
+    assert_eq!(Value::from(200u8).checked_add(&Value::from(100u8)), Ok(Value::from(300u16)));
+    assert_eq!(Value::from(5u8).checked_add(&Value::from(-10i8)), Ok(Value::from(-5i8)));
+    assert_eq!(Value::from(5u16).checked_add(&Value::from(0.5f32)), Ok(Value::from(5.5f32)));
+    assert_eq!(Value::from(true).checked_add(&Value::from(1u8)), Err(ErrorMessage(NOT_A_NUMBER)));
+}
+
+#[test]
+fn keys_update_their_value_in_place() {
+    let mut key = Key::new("visits", 255u8);
+    key.update_with(|value| value.checked_add(&Value::from(1u8))).unwrap();
+    assert_eq!(key.value, Value::from(256u16));
+
+    assert!(key.update_with(|_| Err(ErrorMessage(NOT_A_NUMBER))).is_err());
+    assert_eq!(key.value, Value::from(256u16));
+}
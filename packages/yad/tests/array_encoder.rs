@@ -0,0 +1,50 @@
+//! Tests for encoding arrays element by element.
+
+use serde_yad::key::Key;
+use serde_yad::row::Row;
+use serde_yad::{CURRENT_VERSION, Value, YAD};
+use yad_core::constants::error::{ErrorMessage, VEC_OF_LENGTH_ZERO};
+use yad_core::ArrayEncoder;
+
+#[test]
+fn encoder_matches_the_vec_conversion_for_every_count_width() {
+    for count in [1u32, 255, 256, 70_000] {
+        let expected = Value::try_from((0..count).collect::<Vec<u32>>()).unwrap();
+        let encoded = (0..count).collect::<ArrayEncoder>().finish().unwrap();
+
+        assert_eq!(encoded, expected);
+    }
+}
+
+#[test]
+fn encoder_accepts_mixed_and_nested_elements() {
+    let inner = Value::try_from(vec![1u8, 2]).unwrap();
+
+    let mut encoder = ArrayEncoder::with_capacity(16);
+    encoder.push(true);
+    encoder.push(Value::try_from("two").unwrap());
+    encoder.push_value(&inner);
+    encoder.extend([4u16, 5]);
+    assert_eq!(encoder.len(), 5);
+
+    let expected = Value::try_from(vec![
+        Value::from(true),
+        Value::try_from("two").unwrap(),
+        inner,
+        Value::from(4u16),
+        Value::from(5u16),
+    ]).unwrap();
+    let value = encoder.finish().unwrap();
+
+    assert_eq!(value, expected);
+    let yad = YAD::new(CURRENT_VERSION, vec![Row::new("r", vec![Key::new("k", value)])]);
+    assert_eq!(YAD::deserialize(yad.serialize().unwrap()).unwrap(), yad);
+}
+
+#[test]
+fn empty_encoder_is_rejected() {
+    let encoder = ArrayEncoder::new();
+
+    assert!(encoder.is_empty());
+    assert_eq!(encoder.finish().err(), Some(ErrorMessage(VEC_OF_LENGTH_ZERO)));
+}
@@ -0,0 +1,69 @@
+//! Tests for in-place array operations on values.
+
+use serde_yad::Value;
+use yad_core::constants::error::{ErrorMessage, INDEX_OUT_OF_BOUNDS, NOT_AN_ARRAY, VEC_OF_LENGTH_ZERO};
+
+fn array(items: &[Value]) -> Value {
+    Value::try_from(items.to_vec()).unwrap()
+}
+
+fn items() -> Vec<Value> {
+    vec![Value::from(1u8), Value::try_from("two").unwrap(), Value::try_from(vec![3u16, 4]).unwrap(), Value::from(true)]
+}
+
+#[test]
+fn elements_are_read_without_decoding_the_array() {
+    let value = array(&items());
+
+    assert_eq!(value.array_len(), Ok(4));
+    for (index, item) in items().iter().enumerate() {
+        assert_eq!(value.array_get(index).as_ref(), Ok(item));
+    }
+    assert_eq!(value.array_get(4), Err(ErrorMessage(INDEX_OUT_OF_BOUNDS)));
+    assert_eq!(Value::from(1u8).array_len(), Err(ErrorMessage(NOT_AN_ARRAY)));
+}
+
+#[test]
+fn mutations_match_arrays_built_from_scratch() {
+    let mut expected = items();
+    let mut value = array(&expected);
+
+    value.array_push(&Value::from(-5i32)).unwrap();
+    expected.push(Value::from(-5i32));
+    assert_eq!(value, array(&expected));
+
+    value.array_insert(1, &Value::from(0.5f64)).unwrap();
+    expected.insert(1, Value::from(0.5f64));
+    assert_eq!(value, array(&expected));
+
+    assert_eq!(value.array_remove(3), Ok(expected.remove(3)));
+    assert_eq!(value, array(&expected));
+
+    assert_eq!(value.array_pop(), Ok(expected.pop().unwrap()));
+    assert_eq!(value, array(&expected));
+}
+
+#[test]
+fn the_count_descriptor_widens_and_narrows() {
+    let mut expected: Vec<Value> = (0..255u8).map(Value::from).collect();
+    let mut value = array(&expected);
+    assert_eq!(value.bytes.len(), 2 + 255 * 2);
+
+    value.array_push(&Value::from(7u8)).unwrap();
+    expected.push(Value::from(7u8));
+    assert_eq!(value, array(&expected));
+    assert_eq!(value.bytes.len(), 3 + 256 * 2);
+
+    value.array_remove(0).unwrap();
+    expected.remove(0);
+    assert_eq!(value, array(&expected));
+}
+
+#[test]
+fn arrays_cannot_become_empty() {
+    let mut value = array(&[Value::from(1u8)]);
+
+    assert_eq!(value.array_pop(), Err(ErrorMessage(VEC_OF_LENGTH_ZERO)));
+    assert_eq!(value.array_insert(2, &Value::from(2u8)), Err(ErrorMessage(INDEX_OUT_OF_BOUNDS)));
+    assert_eq!(value, array(&[Value::from(1u8)]));
+}
@@ -0,0 +1,100 @@
+//! Tests for exporting documents as Arrow record batches.
+#![cfg(feature = "arrow")]
+
+use std::sync::Arc;
+use arrow_array::{Array, BooleanArray, Decimal128Array, Float64Array, StringArray, UInt16Array};
+use arrow_schema::{DataType, Field, Schema};
+use serde_yad::arrow::{ArrowError, ROW_NAME_FIELD};
+use serde_yad::error::{ARROW_MISSING_VALUE, ARROW_UNSUPPORTED_TYPE, ARROW_VALUE_MISMATCH};
+use serde_yad::key::Key;
+use serde_yad::row::Row;
+use serde_yad::{Value, CURRENT_VERSION, YAD};
+use yad_core::constants::error::ErrorMessage;
+use yad_core::decimal::Decimal;
+
+fn people() -> YAD {
+    YAD::new(CURRENT_VERSION, vec![
+        Row::new("johan", vec![
+            Key::new("age", 17u16),
+            Key::new("name", Value::try_from("Johan").unwrap()),
+            Key::new("balance", Decimal::new(1990, 2)),
+            Key::new("active", true),
+        ]),
+        Row::new("ana", vec![
+            Key::new("age", 30u16),
+            Key::new("name", Value::try_from("Ana").unwrap()),
+            Key::new("balance", Decimal::new(5, 0)),
+        ]),
+    ])
+}
+
+fn schema(fields: Vec<Field>) -> Arc<Schema> {
+    Arc::new(Schema::new(fields))
+}
+
+#[test]
+fn exports_one_record_per_row_in_name_order() {
+    let batch = people().to_arrow_recordbatch(schema(vec![
+        Field::new(ROW_NAME_FIELD, DataType::Utf8, false),
+        Field::new("age", DataType::UInt16, false),
+        Field::new("name", DataType::Utf8, false),
+        Field::new("active", DataType::Boolean, true),
+    ])).unwrap();
+
+    assert_eq!(batch.num_rows(), 2);
+    let rows = batch.column(0).as_any().downcast_ref::<StringArray>().unwrap();
+    assert_eq!(rows.value(0), "ana");
+    assert_eq!(rows.value(1), "johan");
+
+    let ages = batch.column(1).as_any().downcast_ref::<UInt16Array>().unwrap();
+    assert_eq!(ages.values().to_vec(), vec![30, 17]);
+
+    let names = batch.column(2).as_any().downcast_ref::<StringArray>().unwrap();
+    assert_eq!(names.value(0), "Ana");
+
+    let active = batch.column(3).as_any().downcast_ref::<BooleanArray>().unwrap();
+    assert!(active.is_null(0));
+    assert!(active.value(1));
+}
+
+#[test]
+fn rescales_decimals() {
+    let batch = people().to_arrow_recordbatch(schema(vec![Field::new("balance", DataType::Decimal128(10, 3), false)])).unwrap();
+    let balances = batch.column(0).as_any().downcast_ref::<Decimal128Array>().unwrap();
+    assert_eq!(balances.value(0), 5_000);
+    assert_eq!(balances.value(1), 19_900);
+    assert_eq!(balances.value_as_string(1), "19.900");
+}
+
+#[test]
+fn rejects_decimals_that_do_not_fit() {
+    let narrower = people().to_arrow_recordbatch(schema(vec![Field::new("balance", DataType::Decimal128(10, 1), false)]));
+    assert_eq!(narrower, Err(ArrowError { error: ErrorMessage(ARROW_VALUE_MISMATCH), row: Some("johan".to_string()), field: "balance".to_string() }));
+
+    let shorter = people().to_arrow_recordbatch(schema(vec![Field::new("balance", DataType::Decimal128(3, 2), false)]));
+    assert_eq!(shorter.unwrap_err().row.as_deref(), Some("johan"));
+}
+
+#[test]
+fn reports_missing_and_mismatched_values() {
+    let missing = people().to_arrow_recordbatch(schema(vec![Field::new("active", DataType::Boolean, false)])).unwrap_err();
+    assert_eq!(missing.error, ErrorMessage(ARROW_MISSING_VALUE));
+    assert_eq!(missing.to_string(), format!("{} (field `active` in row `ana`)", ARROW_MISSING_VALUE));
+
+    let wrong_width = people().to_arrow_recordbatch(schema(vec![Field::new("age", DataType::UInt32, false)])).unwrap_err();
+    assert_eq!(wrong_width.error, ErrorMessage(ARROW_VALUE_MISMATCH));
+
+    let unsupported = people().to_arrow_recordbatch(schema(vec![Field::new("age", DataType::Date32, false)])).unwrap_err();
+    assert_eq!(unsupported, ArrowError { error: ErrorMessage(ARROW_UNSUPPORTED_TYPE), row: None, field: "age".to_string() });
+}
+
+#[test]
+fn empty_schema_keeps_the_row_count() {
+    let batch = people().to_arrow_recordbatch(schema(vec![])).unwrap();
+    assert_eq!(batch.num_rows(), 2);
+
+    let empty = YAD::new_empty(CURRENT_VERSION)
+        .to_arrow_recordbatch(schema(vec![Field::new("ratio", DataType::Float64, true)]))
+        .unwrap();
+    assert_eq!(empty.column(0).as_any().downcast_ref::<Float64Array>().unwrap().len(), 0);
+}
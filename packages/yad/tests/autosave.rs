@@ -0,0 +1,88 @@
+//! Tests for saving shared documents in the background.
+
+use std::fs;
+use std::path::PathBuf;
+use std::thread::sleep;
+use std::time::{Duration, Instant};
+use serde_yad::autosave::YadAutoSaver;
+use serde_yad::error::SAVE_IO_FAILED;
+use serde_yad::key::Key;
+use serde_yad::row::Row;
+use serde_yad::shared::SharedYad;
+use serde_yad::{CURRENT_VERSION, YAD};
+use yad_core::constants::error::ErrorMessage;
+
+fn temp_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("serde_yad-autosave-{}-{}", name, std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+/// Waits up to five seconds for `done` to hold.
+fn wait_for(done: impl Fn() -> bool) {
+    let deadline = Instant::now() + Duration::from_secs(5);
+    while !done() && Instant::now() < deadline {
+        sleep(Duration::from_millis(5));
+    }
+    assert!(done());
+}
+
+fn shared() -> SharedYad {
+    SharedYad::new(YAD::new(CURRENT_VERSION, vec![Row::new("johan", vec![Key::new("age", 17u8)])]))
+}
+
+#[test]
+fn saves_periodically_and_skips_unchanged_intervals() {
+    let dir = temp_dir("periodic");
+    let path = dir.join("data.yad");
+    let shared = shared();
+
+    let saver = YadAutoSaver::spawn(shared.clone(), &path, Duration::from_millis(10));
+    wait_for(|| saver.saves() == 1);
+    assert_eq!(YAD::deserialize(fs::read(&path).unwrap()).unwrap(), *shared.read());
+
+    shared.write().insert_row("ana", vec![Key::new("age", 30u8)]);
+    wait_for(|| saver.saves() == 2);
+    sleep(Duration::from_millis(50));
+    assert_eq!(saver.saves(), 2);
+    assert!(YAD::deserialize(fs::read(&path).unwrap()).unwrap().rows.contains_key("ana"));
+
+    assert_eq!(saver.stop(), Ok(()));
+    assert!(!dir.join("data.yad.tmp").exists());
+    fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn stopping_saves_the_latest_writes() {
+    let dir = temp_dir("stop");
+    let path = dir.join("data.yad");
+    let shared = shared();
+
+    let saver = YadAutoSaver::spawn(shared.clone(), &path, Duration::from_secs(3600));
+    shared.write().insert_row("ana", vec![]);
+    assert_eq!(saver.stop(), Ok(()));
+
+    assert_eq!(YAD::deserialize(fs::read(&path).unwrap()).unwrap(), *shared.read());
+    fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn dropping_the_saver_saves_too() {
+    let dir = temp_dir("drop");
+    let path = dir.join("data.yad");
+
+    drop(YadAutoSaver::spawn(shared(), &path, Duration::from_secs(3600)));
+    assert!(path.exists());
+    fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn reports_failed_saves() {
+    let path = temp_dir("missing").join("no-such-dir").join("data.yad");
+
+    let saver = YadAutoSaver::spawn(shared(), &path, Duration::from_millis(10));
+    wait_for(|| saver.last_error() == Some(ErrorMessage(SAVE_IO_FAILED)));
+    assert_eq!(saver.saves(), 0);
+    assert_eq!(saver.stop(), Err(ErrorMessage(SAVE_IO_FAILED)));
+    fs::remove_dir_all(temp_dir("missing")).unwrap();
+}
@@ -0,0 +1,49 @@
+//! Tests for reading beta-era (`< 1.0`) files, which share the current row
+//! and key framing.
+
+use serde_yad::borrowed::YadRef;
+use serde_yad::key::Key;
+use serde_yad::row::Row;
+use serde_yad::stream::YadRowStream;
+use serde_yad::{Value, Version, CURRENT_VERSION, YAD};
+
+/// The example file of the format description, written by version `0.0.1` beta.
+const BETA_FILE: [u8; 75] = [
+    0xF0, 0x00, 0x00, 0x01, 0x01,
+    0xF1, 0x61, 0x05, 0x6A, 0x6F, 0x68, 0x61, 0x6E,
+    0xF3, 0x71, 0x04, 0x6E, 0x61, 0x6D, 0x65, 0x41, 0x05, 0x4A, 0x6F, 0x68, 0x61, 0x6E, 0xF4,
+    0xF3, 0x71, 0x03, 0x61, 0x67, 0x65, 0x11, 0x11, 0xF4,
+    0xF2,
+    0xF1, 0x61, 0x07, 0x73, 0x69, 0x6C, 0x65, 0x6E, 0x63, 0x65,
+    0xF3, 0x71, 0x04, 0x6E, 0x61, 0x6D, 0x65, 0x41, 0x07, 0x53, 0x69, 0x6C, 0x65, 0x6E, 0x63, 0x65, 0xF4,
+    0xF3, 0x71, 0x03, 0x61, 0x67, 0x65, 0x11, 0x11, 0xF4,
+    0xF2,
+];
+
+fn rows() -> Vec<Row> {
+    vec![
+        Row::new("johan", vec![Key::new("name", Value::try_from("Johan").unwrap()), Key::new("age", 17u8)]),
+        Row::new("silence", vec![Key::new("name", Value::try_from("Silence").unwrap()), Key::new("age", 17u8)]),
+    ]
+}
+
+#[test]
+fn beta_files_are_read_like_current_ones() {
+    let beta = Version { major: 0, minor: 0, patch: 1, beta: 1 };
+    let yad = YAD::deserialize(BETA_FILE.to_vec()).unwrap();
+
+    assert_eq!(yad, YAD::new(beta, rows()));
+    assert_eq!(YadRef::new(&BETA_FILE).unwrap().to_owned().unwrap(), yad);
+
+    let streamed: Vec<Row> = YadRowStream::open(&BETA_FILE[..]).unwrap().collect::<Result<_, _>>().unwrap();
+    assert_eq!(streamed, rows());
+}
+
+#[test]
+fn upgraded_beta_files_are_saved_with_the_current_version() {
+    let mut yad = YAD::deserialize(BETA_FILE.to_vec()).unwrap();
+    yad.upgrade();
+
+    let bytes = yad.serialize().unwrap();
+    assert_eq!(YAD::deserialize(bytes).unwrap(), YAD::new(CURRENT_VERSION, rows()));
+}
@@ -0,0 +1,150 @@
+//! Tests for big integer values.
+
+use serde_yad::key::Key;
+use serde_yad::row::Row;
+use serde_yad::{Value, CURRENT_VERSION, YAD};
+use yad_core::bigint::{BigInteger, BIG_INTEGER_EXTENSION_TAG};
+use yad_core::constants::error::{
+    ErrorMessage, BIG_INTEGER_OUT_OF_RANGE, INVALID_BIG_INTEGER_TEXT, MALFORMED_BIG_INTEGER, NOT_A_BIG_INTEGER,
+};
+use yad_core::kind::ValueKind;
+
+const HUGE: &str = "-340282366920938463463374607431768211457";
+
+#[test]
+fn big_integers_round_trip_through_decode() {
+    for text in ["0", "1", "-1", "127", "128", "-128", "-129", "18446744073709551616", HUGE] {
+        let big: BigInteger = text.parse().unwrap();
+        let value = Value::from(big.clone());
+        assert!(value.is_big_integer());
+        assert_eq!(value.kind(), ValueKind::BigInteger);
+        assert_eq!(value.extension_tag(), Ok(BIG_INTEGER_EXTENSION_TAG));
+        assert_eq!(Value::decode(value.bytes.to_vec()).unwrap(), value);
+        assert_eq!(TryInto::<BigInteger>::try_into(value.clone()), Ok(big.clone()));
+        assert_eq!(big.to_string(), text);
+        assert_eq!(value.to_string(), text);
+    }
+}
+
+#[test]
+fn bytes_are_minimal_twos_complement() {
+    assert_eq!(BigInteger::from(0u8).to_signed_bytes_be(), &[0x00]);
+    assert_eq!(BigInteger::from(128u8).to_signed_bytes_be(), &[0x00, 0x80]);
+    assert_eq!(BigInteger::from(-128i8).to_signed_bytes_be(), &[0x80]);
+    assert_eq!(BigInteger::from(-129i16).to_signed_bytes_be(), &[0xFF, 0x7F]);
+    assert_eq!(BigInteger::from_signed_bytes_be(&[0xFF, 0xFF, 0x80]), BigInteger::from(-128i8));
+    assert_eq!(BigInteger::from_signed_bytes_be(&[]), BigInteger::from(0u8));
+    assert_eq!(BigInteger::from(u128::MAX).to_signed_bytes_be().len(), 17);
+}
+
+#[test]
+fn native_integers_convert_both_ways() {
+    for n in [0, -1, i128::MIN, i128::MAX, i64::MIN as i128] {
+        assert_eq!(i128::try_from(&BigInteger::from(n)), Ok(n));
+    }
+    assert_eq!(u128::try_from(&BigInteger::from(u128::MAX)), Ok(u128::MAX));
+    assert_eq!(u128::try_from(&BigInteger::from(-1i8)), Err(ErrorMessage(BIG_INTEGER_OUT_OF_RANGE)));
+    assert_eq!(i128::try_from(&BigInteger::from(u128::MAX)), Err(ErrorMessage(BIG_INTEGER_OUT_OF_RANGE)));
+}
+
+#[test]
+fn big_integers_order_by_value() {
+    let mut values: Vec<BigInteger> = ["5", HUGE, "-1", "340282366920938463463374607431768211456", "0", "-200"]
+        .iter()
+        .map(|text| text.parse().unwrap())
+        .collect();
+    values.sort();
+    let sorted: Vec<String> = values.iter().map(ToString::to_string).collect();
+    assert_eq!(sorted, [HUGE, "-200", "-1", "0", "5", "340282366920938463463374607431768211456"]);
+
+    let (a, b) = (Value::from(BigInteger::from(-5i8)), Value::from(BigInteger::from(300u16)));
+    assert!(a.semantic_cmp(&b).is_lt());
+}
+
+#[test]
+fn parsing_rejects_non_integers() {
+    assert_eq!("+42".parse::<BigInteger>(), Ok(BigInteger::from(42u8)));
+    assert_eq!("-0".parse::<BigInteger>(), Ok(BigInteger::from(0u8)));
+    for text in ["", "-", "1.5", "1e3", "0x10", " 1"] {
+        assert_eq!(text.parse::<BigInteger>(), Err(ErrorMessage(INVALID_BIG_INTEGER_TEXT)), "{text}");
+    }
+
+    assert_eq!(Value::from(1u8).as_big_integer(), Err(ErrorMessage(NOT_A_BIG_INTEGER)));
+    let empty = Value::from_extension(BIG_INTEGER_EXTENSION_TAG, &[]).unwrap();
+    assert_eq!(empty.as_big_integer(), Err(ErrorMessage(MALFORMED_BIG_INTEGER)));
+}
+
+#[test]
+fn big_integers_live_in_documents_and_text() {
+    let big = Value::from(HUGE.parse::<BigInteger>().unwrap());
+    let yad = YAD::new(CURRENT_VERSION, vec![Row::new("lab", vec![
+        Key::new("count", big.clone()),
+        Key::new("all", Value::try_from(vec![big, Value::from(BigInteger::from(7u8))]).unwrap()),
+        Key::new("ratio", f64::NAN),
+    ])]);
+
+    assert_eq!(YAD::deserialize(yad.serialize().unwrap()).unwrap(), yad);
+    let text = yad.to_text().unwrap();
+    assert!(text.contains(&format!("count = {}n", HUGE)), "{text}");
+    assert!(text.contains("[-340282366920938463463374607431768211457n, 7n]"), "{text}");
+    assert_eq!(YAD::from_text(&text).unwrap().serialize().unwrap(), yad.serialize().unwrap());
+}
+
+#[cfg(feature = "bigint")]
+#[test]
+fn num_bigint_converts_both_ways() {
+    use num_bigint::{BigInt, BigUint};
+
+    let big: BigInt = HUGE.parse().unwrap();
+    let value = Value::from(big.clone());
+    assert_eq!(value.to_string(), HUGE);
+    assert_eq!(TryInto::<BigInt>::try_into(value.clone()), Ok(big));
+    assert_eq!(TryInto::<BigUint>::try_into(value), Err(ErrorMessage(BIG_INTEGER_OUT_OF_RANGE)));
+
+    let unsigned = BigUint::from(u128::MAX) * 4u8;
+    assert_eq!(TryInto::<BigUint>::try_into(Value::from(unsigned.clone())), Ok(unsigned));
+}
+
+#[cfg(feature = "cbor")]
+#[test]
+fn cbor_writes_bignums() {
+    let value = Value::from(BigInteger::from(-257i16));
+    let cbor = value.to_cbor().unwrap();
+    assert_eq!(cbor, ciborium::Value::Tag(3, Box::new(ciborium::Value::Bytes(vec![0x01, 0x00]))));
+    assert_eq!(Value::from_cbor(&cbor), Ok(value));
+
+    let huge = Value::from(HUGE.parse::<BigInteger>().unwrap());
+    assert_eq!(Value::from_cbor(&huge.to_cbor().unwrap()), Ok(huge));
+    let zero = Value::from(BigInteger::from(0u8));
+    assert_eq!(zero.to_cbor().unwrap(), ciborium::Value::Tag(2, Box::new(ciborium::Value::Bytes(vec![]))));
+    assert_eq!(Value::from_cbor(&zero.to_cbor().unwrap()), Ok(zero));
+}
+
+#[cfg(feature = "ffi")]
+#[test]
+fn ffi_builds_and_reads_big_integers() {
+    use std::ffi::{CStr, CString};
+    use yad_core::ffi::byte_buffer_free;
+    use yad_core::ffi::value::{
+        big_integer_bytes_from_value, cstring_free, cstring_from_big_integer_value, value_free,
+        value_from_big_integer_bytes, value_from_big_integer_cstring,
+    };
+
+    let input = CString::new(HUGE).unwrap();
+    let value = unsafe { value_from_big_integer_cstring(input.as_ptr()) };
+    let text = unsafe { cstring_from_big_integer_value(value) };
+    assert_eq!(unsafe { CStr::from_ptr(text) }.to_str(), Ok(HUGE));
+    cstring_free(text as *mut _);
+
+    let buffer = unsafe { big_integer_bytes_from_value(value) };
+    let bytes = unsafe { std::slice::from_raw_parts(buffer.ptr, buffer.len) }.to_vec();
+    byte_buffer_free(buffer);
+    value_free(value);
+
+    let copy = unsafe { value_from_big_integer_bytes(bytes.as_ptr(), bytes.len()) };
+    assert_eq!(unsafe { &*copy }.to_string(), HUGE);
+    value_free(copy);
+
+    let bad = CString::new("12.5").unwrap();
+    assert!(unsafe { value_from_big_integer_cstring(bad.as_ptr()) }.is_null());
+}
@@ -0,0 +1,79 @@
+//! Tests for the bloom filter section over row names.
+
+mod common;
+
+use common::numbered;
+use serde_yad::bloom::BloomFilter;
+use serde_yad::borrowed::YadRef;
+use serde_yad::options::SerializeOptions;
+use serde_yad::row::Row;
+use serde_yad::stream::YadRowStream;
+use serde_yad::{CURRENT_VERSION, YAD};
+
+fn with_bloom(yad: &YAD) -> Vec<u8> {
+    let options = SerializeOptions { bloom_filter: true, embed_schema: true, ..SerializeOptions::default() };
+    yad.serialize_with_options(&options).unwrap()
+}
+
+#[test]
+fn filter_has_no_false_negatives() {
+    let names: Vec<String> = (0..1000).map(|i| format!("name-{i}")).collect();
+    let filter = BloomFilter::from_names(names.iter().map(String::as_str));
+
+    assert!(names.iter().all(|name| filter.might_contain(name)));
+
+    let false_positives = (0..1000).filter(|i| filter.might_contain(&format!("other-{i}"))).count();
+    assert!(false_positives < 50, "{false_positives} false positives");
+}
+
+#[test]
+fn filter_round_trips() {
+    let filter = BloomFilter::from_names(["a", "b", "c"].into_iter());
+    let bytes = filter.serialize().unwrap();
+
+    assert_eq!(BloomFilter::deserialize(&bytes).unwrap(), (filter, bytes.len()));
+    assert!(BloomFilter::deserialize(&bytes[..bytes.len() - 1]).is_err());
+}
+
+#[test]
+fn documents_with_a_filter_still_decode() {
+    let yad = numbered(200);
+    let bytes = with_bloom(&yad);
+
+    assert!(BloomFilter::read_embedded(&bytes).unwrap().is_some());
+    assert_eq!(YAD::deserialize(bytes.clone()).unwrap(), yad);
+    assert_eq!(YadRef::new(&bytes).unwrap().to_owned().unwrap(), yad);
+
+    let streamed: Vec<Row> = YadRowStream::open(bytes.as_slice()).unwrap().collect::<Result<_, _>>().unwrap();
+    assert_eq!(YAD::new(CURRENT_VERSION, streamed), yad);
+
+    let (verbose, leftovers) = YAD::deserialize_verbose(bytes).unwrap();
+    assert_eq!(verbose, yad);
+    assert!(leftovers.is_empty());
+}
+
+#[test]
+fn borrowed_lookups_use_the_filter() {
+    let yad = numbered(200);
+    let bytes = with_bloom(&yad);
+    let doc = YadRef::new(&bytes).unwrap();
+
+    assert!(doc.bloom_filter().is_some());
+    assert!(doc.contains_row("row-0").unwrap());
+    assert!(doc.contains_row("row-199").unwrap());
+    assert!(!doc.contains_row("row-200").unwrap());
+    assert_eq!(doc.row("row-42").unwrap().unwrap().to_owned().unwrap(), yad.rows["row-42"]);
+    assert!(doc.row("missing").unwrap().is_none());
+}
+
+#[test]
+fn filter_is_optional() {
+    let yad = numbered(200);
+    let bytes = yad.serialize().unwrap();
+
+    assert!(BloomFilter::read_embedded(&bytes).unwrap().is_none());
+    assert!(YadRef::new(&bytes).unwrap().bloom_filter().is_none());
+
+    let empty = YAD::new(CURRENT_VERSION, vec![]);
+    assert_eq!(with_bloom(&empty), empty.serialize_with_options(&SerializeOptions { embed_schema: true, ..SerializeOptions::default() }).unwrap());
+}
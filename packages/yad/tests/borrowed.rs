@@ -0,0 +1,42 @@
+use serde_yad::borrowed::YadRef;
+use serde_yad::key::Key;
+use serde_yad::row::Row;
+use serde_yad::{CURRENT_VERSION, Value, YAD};
+
+fn with_empty_row() -> YAD {
+    YAD::new(CURRENT_VERSION, vec![
+        Row::new("johan", vec![
+            Key::new("name", Value::try_from("Johan").unwrap()),
+            Key::new("age", Value::from(17u8)),
+        ]),
+        Row::new("empty", vec![]),
+    ])
+}
+
+#[test]
+fn borrowed_document_reads_lazily_and_converts_back() {
+    let yad = with_empty_row();
+    let bytes = yad.serialize().unwrap();
+    let doc = YadRef::new(&bytes).unwrap();
+
+    let row = doc.row("johan").unwrap().unwrap();
+    let name = row.get("name").unwrap().unwrap();
+
+    assert_eq!(name.value.as_str().unwrap(), "Johan");
+    assert!(row.get("age").unwrap().unwrap().value.as_str().is_err());
+    assert_eq!(doc.rows().count(), 2);
+    assert_eq!(doc.to_owned().unwrap(), yad);
+}
+
+#[test]
+fn borrowed_rows_stop_after_malformed_bytes() {
+    let mut bytes = with_empty_row().serialize().unwrap();
+    bytes.push(0x00);
+
+    let doc = YadRef::new(&bytes).unwrap();
+    let rows: Vec<_> = doc.rows().collect();
+
+    assert_eq!(rows.len(), 3);
+    assert!(rows[2].is_err());
+    assert!(doc.to_owned().is_err());
+}
@@ -0,0 +1,147 @@
+//! Tests for mapping rows to and from Rust types through serde.
+#![cfg(feature = "serde")]
+
+use std::collections::BTreeMap;
+use serde::{Deserialize, Serialize};
+use serde_yad::bridge::BridgeError;
+use serde_yad::error::{SERDE_MISMATCH, SERDE_NOT_A_STRUCT, SERDE_UNSUPPORTED_TYPE};
+use serde_yad::key::Key;
+use serde_yad::row::Row;
+use serde_yad::Value;
+use yad_core::constants::error::{ErrorMessage, STRING_OF_LENGTH_ZERO};
+use yad_core::decimal::Decimal;
+
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+enum Role {
+    Admin,
+    Guest,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Meters(f64);
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct User {
+    age: u8,
+    score: i64,
+    name: String,
+    initial: char,
+    active: bool,
+    ratio: f32,
+    height: Meters,
+    role: Role,
+    tags: Vec<String>,
+    point: (u16, u16),
+    email: Option<String>,
+}
+
+fn user() -> User {
+    User {
+        age: 17,
+        score: -3,
+        name: "Johan".into(),
+        initial: 'J',
+        active: true,
+        ratio: 0.5,
+        height: Meters(1.8),
+        role: Role::Admin,
+        tags: vec!["a".into(), "b".into()],
+        point: (3, 4),
+        email: None,
+    }
+}
+
+#[test]
+fn structs_round_trip_through_rows() {
+    let row = Row::from_serialize("johan", &user()).unwrap();
+
+    assert_eq!(row.name, "johan");
+    assert_eq!(row.get("age"), Some(&Value::from(17u8)));
+    assert_eq!(row.get("score"), Some(&Value::from(-3i64)));
+    assert_eq!(row.get("initial"), Some(&Value::from('J')));
+    assert_eq!(row.get("role"), Some(&Value::try_from("Admin").unwrap()));
+    assert_eq!(row.get("point"), Some(&Value::try_from(vec![3u16, 4]).unwrap()));
+    assert!(row.get("email").is_none());
+
+    assert_eq!(row.deserialize_into::<User>(), Ok(user()));
+}
+
+#[test]
+fn present_options_and_maps_convert() {
+    let mut with_email = user();
+    with_email.email = Some("j@example.com".into());
+    let row = Row::from_serialize("johan", &with_email).unwrap();
+    assert_eq!(row.get_str("email"), Ok("j@example.com"));
+    assert_eq!(row.deserialize_into::<User>(), Ok(with_email));
+
+    let map = BTreeMap::from([("x".to_string(), 1u32), ("y".to_string(), 2)]);
+    let row = Row::from_serialize("point", &map).unwrap();
+    assert_eq!(row.get_u32("y"), Ok(2));
+    assert_eq!(row.deserialize_into::<BTreeMap<String, u32>>(), Ok(map));
+}
+
+#[test]
+fn multi_value_keys_and_extensions_read_as_sequences_and_strings() {
+    #[derive(Debug, PartialEq, Deserialize)]
+    struct Prices {
+        history: Vec<u8>,
+        price: String,
+    }
+
+    let mut history = Key::new("history", 1u8);
+    history.push_value(2u8);
+    let row = Row::new("shop", vec![history, Key::new("price", Decimal::new(1990, 2))]);
+
+    assert_eq!(row.deserialize_into::<Prices>(), Ok(Prices { history: vec![1, 2], price: "19.90".into() }));
+}
+
+#[test]
+fn unsupported_shapes_fail() {
+    #[derive(Serialize)]
+    struct Nested {
+        inner: BTreeMap<String, u8>,
+    }
+    #[derive(Serialize)]
+    enum Shape {
+        Circle(f32),
+    }
+    #[derive(Serialize)]
+    struct Drawing {
+        shape: Shape,
+    }
+    #[derive(Serialize)]
+    struct Named {
+        name: String,
+    }
+
+    let nested = Nested { inner: BTreeMap::from([("a".to_string(), 1)]) };
+    assert_eq!(
+        Row::from_serialize("n", &nested),
+        Err(BridgeError { error: ErrorMessage(SERDE_UNSUPPORTED_TYPE), detail: "key `inner`".into() })
+    );
+    assert_eq!(Row::from_serialize("d", &Drawing { shape: Shape::Circle(1.0) }).unwrap_err().error, ErrorMessage(SERDE_UNSUPPORTED_TYPE));
+    assert_eq!(Row::from_serialize("n", &5u8).unwrap_err().error, ErrorMessage(SERDE_NOT_A_STRUCT));
+    assert_eq!(Row::from_serialize("n", &Named { name: String::new() }).unwrap_err().error, ErrorMessage(STRING_OF_LENGTH_ZERO));
+}
+
+#[test]
+fn mismatches_name_the_key() {
+    #[derive(Debug, Deserialize)]
+    #[allow(dead_code)]
+    struct Age {
+        age: u8,
+    }
+
+    let missing = Row::new("johan", vec![Key::new("name", Value::try_from("Johan").unwrap())]);
+    let error = missing.deserialize_into::<Age>().unwrap_err();
+    assert_eq!(error.error, ErrorMessage(SERDE_MISMATCH));
+    assert_eq!(error.detail, "missing field `age`");
+
+    let wide = Row::new("johan", vec![Key::new("age", 300u16)]);
+    let error = wide.deserialize_into::<Age>().unwrap_err();
+    assert_eq!(error.error, ErrorMessage(SERDE_MISMATCH));
+    assert!(error.to_string().contains("300"), "{error}");
+
+    let error = Row::new("johan", vec![Key::new("age", Value::try_from("x").unwrap())]).deserialize_into::<Age>().unwrap_err();
+    assert_eq!(error.detail, "invalid type: string \"x\", expected u8");
+}
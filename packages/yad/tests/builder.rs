@@ -0,0 +1,47 @@
+use serde_yad::builder::YadBuilder;
+use serde_yad::key::Key;
+use serde_yad::row::Row;
+use serde_yad::{CURRENT_VERSION, Value, YAD};
+
+#[test]
+fn builder_matches_manual_construction() {
+    let built = YadBuilder::new(CURRENT_VERSION)
+        .row("johan", |r| r.key("name", "Johan").key("age", 17u8).key("admin", false))
+        .row("empty", |r| r)
+        .build()
+        .unwrap();
+
+    let manual = YAD::new(CURRENT_VERSION, vec![
+        Row::new("johan", vec![
+            Key::new("name", Value::try_from("Johan").unwrap()),
+            Key::new("age", Value::from(17u8)),
+            Key::new("admin", Value::from(false)),
+        ]),
+        Row::new("empty", vec![]),
+    ]);
+
+    assert_eq!(built, manual);
+}
+
+#[test]
+fn builder_reports_the_first_failed_conversion() {
+    let result = YadBuilder::new(CURRENT_VERSION)
+        .row("johan", |r| r.key("name", "").key("age", 17u8))
+        .build();
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn insert_key_converts_native_values() {
+    let mut row = Row::new("johan", vec![Key::new("age", 17u8)]);
+
+    row.insert_key("name", "Johan").unwrap();
+    row.insert_key("score", 9.5f64).unwrap();
+    assert!(row.insert_key("empty", "").is_err());
+
+    assert_eq!(row.keys["name"].value, Value::try_from("Johan").unwrap());
+    assert_eq!(row.keys["age"].value, Value::from(17u8));
+    assert!(!row.keys.contains_key("empty"));
+    assert!(Key::try_new("list", vec![Value::from(true)]).is_ok());
+}
@@ -0,0 +1,55 @@
+//! Tests for little-endian number payloads.
+
+use serde_yad::borrowed::YadRef;
+use serde_yad::header::{Header, HeaderFlags};
+use serde_yad::key::Key;
+use serde_yad::options::SerializeOptions;
+use serde_yad::projection::Projection;
+use serde_yad::row::Row;
+use serde_yad::{CURRENT_VERSION, Value, YAD};
+use yad_core::byte_order::ByteOrder;
+
+fn mixed_widths() -> YAD {
+    let mut key = Key::new("scores", 0x0102u16);
+    key.push_value(Value::try_from(vec![Value::from(0x01020304u32), Value::from(-2i64)]).unwrap());
+
+    YAD::new(CURRENT_VERSION, vec![Row::new("johan", vec![
+        key,
+        Key::new("pi", 3.25f64),
+        Key::try_new("name", "Johan").unwrap(),
+    ])])
+}
+
+fn little_endian() -> Vec<u8> {
+    mixed_widths().serialize_with_options(&SerializeOptions { byte_order: ByteOrder::Little, ..SerializeOptions::default() }).unwrap()
+}
+
+#[test]
+fn numbers_are_written_little_endian() {
+    let bytes = little_endian();
+    let (header, _) = Header::read(&bytes).unwrap();
+
+    assert!(header.flags.contains(HeaderFlags::LITTLE_ENDIAN));
+    assert!(bytes.windows(2).any(|pair| pair == [0x02, 0x01]));
+    assert!(!bytes.windows(2).any(|pair| pair == [0x01, 0x02]));
+}
+
+#[test]
+fn every_reader_converts_numbers_back() {
+    let bytes = little_endian();
+
+    assert_eq!(YAD::deserialize(bytes.clone()).unwrap(), mixed_widths());
+    assert_eq!(YAD::deserialize_projected(&bytes, &Projection::all()).unwrap(), mixed_widths());
+    assert_eq!(YadRef::new(&bytes).unwrap().to_owned().unwrap(), mixed_widths());
+}
+
+#[test]
+fn big_endian_stays_the_default() {
+    assert_eq!(mixed_widths().serialize_with_options(&SerializeOptions::default()).unwrap(), mixed_widths().serialize().unwrap());
+
+    let flags = HeaderFlags::empty().with(HeaderFlags::LITTLE_ENDIAN);
+    let bytes = mixed_widths().serialize_with_options(&SerializeOptions { header_flags: Some(flags), ..SerializeOptions::default() }).unwrap();
+
+    assert!(!Header::read(&bytes).unwrap().0.flags.contains(HeaderFlags::LITTLE_ENDIAN));
+    assert_eq!(YAD::deserialize(bytes).unwrap(), mixed_widths());
+}
@@ -0,0 +1,88 @@
+//! Tests for caching decoded rows of borrowed documents.
+
+use std::sync::Arc;
+use serde_yad::borrowed::YadRef;
+use serde_yad::cache::{CacheConfig, CachedYadRef};
+use serde_yad::key::Key;
+use serde_yad::row::Row;
+use serde_yad::{Value, CURRENT_VERSION, YAD};
+
+fn bytes() -> Vec<u8> {
+    YAD::new(CURRENT_VERSION, (0..10u8).map(|i| Row::new(format!("row{}", i), vec![Key::new("n", i)])).collect())
+        .serialize()
+        .unwrap()
+}
+
+fn row_len() -> usize {
+    Row::new("row0", vec![Key::new("n", 0u8)]).encoded_len()
+}
+
+#[test]
+fn repeated_reads_hit_the_cache() {
+    let bytes = bytes();
+    let cached = CachedYadRef::new(YadRef::new(&bytes).unwrap(), CacheConfig::default());
+
+    let first = cached.row("row3").unwrap().unwrap();
+    let second = cached.row("row3").unwrap().unwrap();
+    assert!(Arc::ptr_eq(&first, &second));
+    assert_eq!(second["n"], Value::from(3u8));
+    assert_eq!((cached.hits(), cached.misses()), (1, 1));
+    assert_eq!(cached.cached_rows(), 1);
+    assert_eq!(cached.cached_bytes(), row_len());
+}
+
+#[test]
+fn missing_rows_are_not_cached() {
+    let bytes = bytes();
+    let cached = CachedYadRef::new(YadRef::new(&bytes).unwrap(), CacheConfig::default());
+
+    assert_eq!(cached.row("nobody"), Ok(None));
+    assert_eq!(cached.row("nobody"), Ok(None));
+    assert_eq!((cached.hits(), cached.misses(), cached.cached_rows()), (0, 2, 0));
+}
+
+#[test]
+fn evicts_the_least_recently_used_row() {
+    let bytes = bytes();
+    let cached = CachedYadRef::new(YadRef::new(&bytes).unwrap(), CacheConfig { max_rows: 2, max_bytes: usize::MAX });
+
+    cached.row("row0").unwrap();
+    cached.row("row1").unwrap();
+    cached.row("row0").unwrap();
+    cached.row("row2").unwrap();
+    assert_eq!(cached.cached_rows(), 2);
+
+    let misses = cached.misses();
+    cached.row("row0").unwrap();
+    assert_eq!(cached.misses(), misses);
+    cached.row("row1").unwrap();
+    assert_eq!(cached.misses(), misses + 1);
+}
+
+#[test]
+fn bounds_the_cached_bytes() {
+    let bytes = bytes();
+    let config = CacheConfig { max_rows: 100, max_bytes: row_len() * 3 };
+    let cached = CachedYadRef::new(YadRef::new(&bytes).unwrap(), config);
+
+    for i in 0..10 {
+        cached.row(&format!("row{}", i)).unwrap();
+    }
+    assert_eq!(cached.cached_rows(), 3);
+    assert_eq!(cached.cached_bytes(), row_len() * 3);
+
+    let tiny = CachedYadRef::new(YadRef::new(&bytes).unwrap(), CacheConfig { max_rows: 100, max_bytes: 1 });
+    assert!(tiny.row("row0").unwrap().is_some());
+    assert_eq!(tiny.cached_rows(), 0);
+}
+
+#[test]
+fn clear_drops_cached_rows() {
+    let bytes = bytes();
+    let cached = CachedYadRef::new(YadRef::new(&bytes).unwrap(), CacheConfig::default());
+    cached.row("row0").unwrap();
+    cached.clear();
+    assert_eq!((cached.cached_rows(), cached.cached_bytes()), (0, 0));
+    cached.row("row0").unwrap();
+    assert_eq!(cached.misses(), 2);
+}
@@ -0,0 +1,117 @@
+//! Tests for framing documents and values over byte streams.
+#![cfg(feature = "tokio")]
+
+use bytes::{BufMut, BytesMut};
+use tokio_util::codec::{Decoder, Encoder};
+use serde_yad::codec::{CodecError, ValueCodec, YadCodec};
+use serde_yad::error::{FRAME_TOO_LARGE, STREAM_IO_FAILED};
+use serde_yad::key::Key;
+use serde_yad::row::Row;
+use serde_yad::{Value, CURRENT_VERSION, YAD};
+use yad_core::constants::error::{ErrorMessage, MALFORMED_VARINT};
+
+fn document(name: &str) -> YAD {
+    YAD::new(CURRENT_VERSION, vec![Row::new(name, vec![
+        Key::new("age", 17u8),
+        Key::new("bio", Value::try_from("x".repeat(300).as_str()).unwrap()),
+    ])])
+}
+
+#[test]
+fn decodes_documents_fed_a_byte_at_a_time() {
+    let mut codec = YadCodec::new();
+    let mut encoded = BytesMut::new();
+    codec.encode(&document("johan"), &mut encoded).unwrap();
+    codec.encode(document("ana"), &mut encoded).unwrap();
+
+    let mut buffer = BytesMut::new();
+    let mut decoded = vec![];
+    for byte in encoded.iter() {
+        buffer.put_u8(*byte);
+        if let Some(yad) = codec.decode(&mut buffer).unwrap() {
+            decoded.push(yad);
+        }
+    }
+
+    assert_eq!(decoded, vec![document("johan"), document("ana")]);
+    assert!(buffer.is_empty());
+}
+
+#[test]
+fn frames_are_length_prefixed() {
+    let mut buffer = BytesMut::new();
+    YadCodec::new().encode(&document("johan"), &mut buffer).unwrap();
+
+    let serialized = document("johan").serialize().unwrap();
+    let length = serialized.len();
+    assert_eq!(&buffer[..2], &[length as u8 | 0x80, (length >> 7) as u8]);
+    assert_eq!(&buffer[2..], serialized.as_slice());
+}
+
+#[test]
+fn rejects_frames_past_the_maximum_length() {
+    let mut codec = YadCodec::new().with_max_frame_length(64);
+    assert_eq!(codec.max_frame_length(), 64);
+    assert_eq!(codec.encode(&document("johan"), &mut BytesMut::new()), Err(CodecError::from(ErrorMessage(FRAME_TOO_LARGE))));
+
+    // The declared length is checked before waiting for the frame.
+    let mut declared = BytesMut::from(&[0xC8, 0x01][..]);
+    assert_eq!(codec.decode(&mut declared), Err(CodecError::from(ErrorMessage(FRAME_TOO_LARGE))));
+}
+
+#[test]
+fn rejects_malformed_lengths() {
+    let mut buffer = BytesMut::from(&[0xFF; 11][..]);
+    assert_eq!(YadCodec::new().decode(&mut buffer).unwrap_err().error, ErrorMessage(MALFORMED_VARINT));
+}
+
+#[test]
+fn truncated_streams_fail_at_the_end() {
+    let mut buffer = BytesMut::new();
+    YadCodec::new().encode(&document("johan"), &mut buffer).unwrap();
+    buffer.truncate(buffer.len() - 1);
+
+    let error = YadCodec::new().decode_eof(&mut buffer).unwrap_err();
+    assert_eq!(error.error, ErrorMessage(STREAM_IO_FAILED));
+    assert!(!error.detail.is_empty());
+}
+
+#[test]
+fn decodes_values_fed_in_pieces() {
+    let values = vec![
+        Value::from(7u8),
+        Value::try_from("hello").unwrap(),
+        Value::try_from(vec![1u16, 2, 3]).unwrap(),
+        Value::from(true),
+    ];
+
+    let mut codec = ValueCodec::new();
+    let mut encoded = BytesMut::new();
+    for value in &values {
+        codec.encode(value, &mut encoded).unwrap();
+    }
+
+    let mut buffer = BytesMut::new();
+    let mut decoded = vec![];
+    for chunk in encoded.chunks(3) {
+        buffer.put_slice(chunk);
+        while let Some(value) = codec.decode(&mut buffer).unwrap() {
+            decoded.push(value);
+        }
+    }
+
+    assert_eq!(decoded, values);
+}
+
+#[test]
+fn value_frames_are_bounded() {
+    let mut codec = ValueCodec::new().with_max_frame_length(8);
+    let long = Value::try_from("more than eight bytes").unwrap();
+    assert_eq!(codec.encode(&long, &mut BytesMut::new()).unwrap_err().error, ErrorMessage(FRAME_TOO_LARGE));
+
+    let mut buffer = BytesMut::from(&long.bytes[..]);
+    assert_eq!(codec.decode(&mut buffer).unwrap_err().error, ErrorMessage(FRAME_TOO_LARGE));
+
+    let mut partial = BytesMut::from(&long.bytes[..4]);
+    assert_eq!(codec.decode(&mut partial), Ok(None));
+}
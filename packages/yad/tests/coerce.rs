@@ -0,0 +1,42 @@
+//! Tests for coercing loosely-typed values.
+
+use serde_yad::Value;
+use yad_core::constants::error::{ErrorMessage, NOT_COERCIBLE_TO_U64};
+
+#[test]
+fn integers_of_any_width_coerce_to_u64() {
+    assert_eq!(Value::from(7u8).coerce_u64(), Ok(7));
+    assert_eq!(Value::from(u64::MAX).coerce_u64(), Ok(u64::MAX));
+    assert_eq!(Value::from(300i16).coerce_u64(), Ok(300));
+    assert_eq!(Value::from(-1i64).coerce_u64(), Err(ErrorMessage(NOT_COERCIBLE_TO_U64)));
+}
+
+#[test]
+fn numeric_strings_coerce_to_u64() {
+    assert_eq!(Value::try_from("42").unwrap().coerce_u64(), Ok(42));
+    assert_eq!(Value::try_from(" +42\n").unwrap().coerce_u64(), Ok(42));
+
+    for text in ["-1", "4.2", "forty-two", "18446744073709551616"] {
+        assert_eq!(Value::try_from(text).unwrap().coerce_u64(), Err(ErrorMessage(NOT_COERCIBLE_TO_U64)), "{text}");
+    }
+}
+
+#[test]
+fn other_types_do_not_coerce_to_u64() {
+    assert!(Value::from(4.0f64).coerce_u64().is_err());
+    assert!(Value::from(true).coerce_u64().is_err());
+    assert!(Value::try_from(vec![1u8]).unwrap().coerce_u64().is_err());
+}
+
+#[test]
+fn every_value_renders_as_a_string() {
+    assert_eq!(Value::from(-3i8).as_string_lossy(), "-3");
+    assert_eq!(Value::from(1.5f32).as_string_lossy(), "1.5");
+    assert_eq!(Value::from(false).as_string_lossy(), "false");
+    assert_eq!(Value::try_from("text").unwrap().as_string_lossy(), "text");
+    assert_eq!(Value::try_from(vec![1u8, 2]).unwrap().as_string_lossy(), "[1, 2]");
+
+    let mut invalid = Value::try_from("ab").unwrap();
+    invalid.bytes[2] = 0xFF;
+    assert_eq!(invalid.as_string_lossy(), "\u{FFFD}b");
+}
@@ -0,0 +1,23 @@
+//! Fixtures shared by the integration tests.
+#![allow(dead_code)]
+
+use serde_yad::key::Key;
+use serde_yad::row::Row;
+use serde_yad::{CURRENT_VERSION, YAD};
+
+/// A row with a name, an age and an id.
+pub fn person(name: &str, age: u8, id: u16) -> Row {
+    let mut display = name.to_string();
+    display[..1].make_ascii_uppercase();
+    Row::new(name, vec![Key::new("age", age), Key::new("id", id), Key::try_new("name", display.as_str()).unwrap()])
+}
+
+/// Three people: alice (30), bob (41) and carol (27).
+pub fn document() -> YAD {
+    YAD::new(CURRENT_VERSION, vec![person("alice", 30, 1), person("bob", 41, 2), person("carol", 27, 3)])
+}
+
+/// Rows `row-0`, `row-1`, ... each holding its number under `n`.
+pub fn numbered(count: u32) -> YAD {
+    YAD::new(CURRENT_VERSION, (0..count).map(|i| Row::new(format!("row-{i}"), vec![Key::new("n", i)])).collect())
+}
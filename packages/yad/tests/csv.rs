@@ -0,0 +1,108 @@
+//! Tests for exporting documents to CSV and importing them back.
+#![cfg(feature = "csv")]
+
+mod common;
+
+use common::document;
+use serde_yad::csv::ColumnTypes;
+use serde_yad::error::{CSV_INVALID_VALUE, CSV_NESTED_VALUE, CSV_UNKNOWN_COLUMN, MALFORMED_CSV, ROW_NAME_OF_LENGTH_ZERO};
+use serde_yad::key::Key;
+use serde_yad::row::Row;
+use serde_yad::schema::Schema;
+use serde_yad::{CURRENT_VERSION, Value, YAD};
+use yad_core::constants::error::ErrorMessage;
+
+fn to_csv(yad: &YAD) -> Result<String, ErrorMessage> {
+    let mut bytes = vec![];
+    yad.to_csv(&mut bytes)?;
+    Ok(String::from_utf8(bytes).unwrap())
+}
+
+fn round_trip(yad: &YAD) -> YAD {
+    YAD::from_csv(to_csv(yad).unwrap().as_bytes(), &Schema::infer(yad).columns()).unwrap()
+}
+
+/// A document whose strings need quoting and whose rows lack some keys.
+fn awkward() -> YAD {
+    YAD::new(CURRENT_VERSION, vec![
+        Row::new("johan", vec![
+            Key::try_new("note", "says \"hi\", then\nleaves").unwrap(),
+            Key::new("balance", -1_500i16),
+            Key::new("ratio", 0.25f32),
+            Key::new("admin", true),
+        ]),
+        Row::new("a,b", vec![Key::try_new("note", "plain").unwrap()]),
+    ])
+}
+
+#[test]
+fn one_record_per_row_and_one_column_per_key() {
+    assert_eq!(to_csv(&document()).unwrap(), "row,age,id,name\nalice,30,1,Alice\nbob,41,2,Bob\ncarol,27,3,Carol\n");
+    assert_eq!(round_trip(&document()), document());
+}
+
+#[test]
+fn commas_quotes_and_newlines_are_quoted() {
+    let csv = to_csv(&awkward()).unwrap();
+
+    assert_eq!(csv, concat!(
+        "row,admin,balance,note,ratio\n",
+        "\"a,b\",,,plain,\n",
+        "johan,true,-1500,\"says \"\"hi\"\", then\nleaves\",0.25\n",
+    ));
+    assert_eq!(round_trip(&awkward()), awkward());
+}
+
+#[test]
+fn widths_come_from_the_column_types() {
+    let yad = YAD::new(CURRENT_VERSION, vec![Row::new("n", vec![Key::new("a", 7u64), Key::new("b", 7i8), Key::new("c", 1.5f64)])]);
+    let back = round_trip(&yad);
+
+    assert_eq!(back["n"]["a"], Value::from(7u64));
+    assert_eq!(back["n"]["b"], Value::from(7i8));
+    assert_eq!(back["n"]["c"], Value::from(1.5f64));
+}
+
+#[test]
+fn cells_must_match_their_column_type() {
+    let columns = Schema::infer(&document()).columns();
+
+    for csv in ["row,age\nbob,old\n", "row,age\nbob,300\n", "row,age\nbob,-1\n", "row,age\nbob,1.5\n"] {
+        assert_eq!(YAD::from_csv(csv.as_bytes(), &columns), Err(ErrorMessage(CSV_INVALID_VALUE)), "{:?}", csv);
+    }
+    assert_eq!(YAD::from_csv("row,height\nbob,180\n".as_bytes(), &columns), Err(ErrorMessage(CSV_UNKNOWN_COLUMN)));
+    assert_eq!(YAD::from_csv("row,age\nbob,\n".as_bytes(), &columns).unwrap()["bob"].keys.len(), 0);
+}
+
+#[test]
+fn column_types_may_come_from_another_document() {
+    let mut columns = ColumnTypes::new();
+    columns.extend(Schema::infer(&document()).columns());
+    columns.extend(Schema::infer(&awkward()).columns());
+
+    let yad = YAD::from_csv("name,age,note\nzoe,9,\"x,y\"\n".as_bytes(), &columns).unwrap();
+    assert_eq!(yad["zoe"]["age"], Value::from(9u8));
+    assert_eq!(yad["zoe"]["note"], Value::try_from("x,y").unwrap());
+}
+
+#[test]
+fn only_flat_documents_are_exported() {
+    let mut arrays = document();
+    arrays.insert_key("bob", "scores", Value::try_from(vec![1u8, 2]).unwrap()).unwrap();
+    assert_eq!(to_csv(&arrays), Err(ErrorMessage(CSV_NESTED_VALUE)));
+
+    let mut tags = Key::try_new("tags", "a").unwrap();
+    tags.push_value(Value::try_from("b").unwrap());
+    let mut multi = document();
+    multi.insert_row("dave", vec![tags]);
+    assert_eq!(to_csv(&multi), Err(ErrorMessage(CSV_NESTED_VALUE)));
+}
+
+#[test]
+fn malformed_input_is_rejected() {
+    let columns = Schema::infer(&document()).columns();
+
+    assert_eq!(YAD::from_csv("row,age\nbob,41,extra\n".as_bytes(), &columns), Err(ErrorMessage(MALFORMED_CSV)));
+    assert_eq!(YAD::from_csv(&b"row,age\n\"bob\xFF\",41\n"[..], &columns), Err(ErrorMessage(MALFORMED_CSV)));
+    assert_eq!(YAD::from_csv("row,age\n,41\n".as_bytes(), &columns), Err(ErrorMessage(ROW_NAME_OF_LENGTH_ZERO)));
+}
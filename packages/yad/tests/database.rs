@@ -0,0 +1,100 @@
+//! Tests for storing named documents in a directory.
+
+use std::fs;
+use std::path::PathBuf;
+use std::sync::Arc;
+use serde_yad::database::YadDatabase;
+use serde_yad::error::{DOCUMENT_EXISTS, DOCUMENT_NOT_FOUND, INVALID_DOCUMENT_NAME};
+use serde_yad::key::Key;
+use serde_yad::{Value, YAD};
+use yad_core::constants::error::ErrorMessage;
+
+fn temp_dir(name: &str) -> PathBuf {
+    std::env::temp_dir().join(format!("serde_yad-database-{}-{}", name, std::process::id()))
+}
+
+#[test]
+fn creates_lists_and_reopens_documents() {
+    let dir = temp_dir("lifecycle");
+    let db = YadDatabase::open(&dir).unwrap();
+    assert_eq!(db.list(), Ok(vec![]));
+
+    let users = db.create("users").unwrap();
+    db.create("orders").unwrap();
+    assert_eq!(db.create("users").err(), Some(ErrorMessage(DOCUMENT_EXISTS)));
+    assert_eq!(db.list(), Ok(vec!["orders".to_string(), "users".to_string()]));
+
+    users.write().insert_row("johan", vec![Key::new("age", 17u8)]);
+    db.save("users").unwrap();
+
+    let reopened = YadDatabase::open(&dir).unwrap();
+    assert!(reopened.contains("users"));
+    assert_eq!(reopened.get("users").unwrap().read()["johan"]["age"], Value::from(17u8));
+    assert_eq!(YAD::deserialize(fs::read(dir.join("users.yad")).unwrap()).unwrap(), *users.read());
+    fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn every_caller_shares_the_cached_document() {
+    let dir = temp_dir("cache");
+    let db = Arc::new(YadDatabase::open(&dir).unwrap());
+    db.create("counters").unwrap();
+
+    let threads: Vec<_> = (0..4u8).map(|i| {
+        let db = db.clone();
+        std::thread::spawn(move || {
+            db.get("counters").unwrap().write().insert_row(format!("thread{}", i), vec![]);
+        })
+    }).collect();
+    threads.into_iter().for_each(|thread| thread.join().unwrap());
+
+    assert_eq!(db.get("counters").unwrap().read().rows.len(), 4);
+
+    // Closing saves the document before dropping it from the cache.
+    db.close("counters").unwrap();
+    assert_eq!(db.get("counters").unwrap().read().rows.len(), 4);
+    fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn save_all_writes_every_loaded_document() {
+    let dir = temp_dir("save-all");
+    let db = YadDatabase::open(&dir).unwrap();
+    db.create("a").unwrap().write().insert_row("x", vec![]);
+    db.create("b").unwrap().write().insert_row("y", vec![]);
+    db.save_all().unwrap();
+
+    let reopened = YadDatabase::open(&dir).unwrap();
+    assert!(reopened.get("a").unwrap().read().rows.contains_key("x"));
+    assert!(reopened.get("b").unwrap().read().rows.contains_key("y"));
+    fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn deletes_documents() {
+    let dir = temp_dir("delete");
+    let db = YadDatabase::open(&dir).unwrap();
+    db.create("tmp").unwrap();
+
+    db.delete("tmp").unwrap();
+    assert!(!db.contains("tmp"));
+    assert!(!dir.join("tmp.yad").exists());
+    assert_eq!(db.delete("tmp"), Err(ErrorMessage(DOCUMENT_NOT_FOUND)));
+    assert_eq!(db.get("tmp").err(), Some(ErrorMessage(DOCUMENT_NOT_FOUND)));
+    assert_eq!(db.save("tmp"), Err(ErrorMessage(DOCUMENT_NOT_FOUND)));
+    fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn rejects_names_outside_the_directory() {
+    let dir = temp_dir("names");
+    let db = YadDatabase::open(&dir).unwrap();
+
+    for name in ["", ".hidden", "../escape", "a/b", "a\\b"] {
+        assert_eq!(db.create(name).err(), Some(ErrorMessage(INVALID_DOCUMENT_NAME)), "{name:?}");
+    }
+
+    fs::write(dir.join("notes.txt"), "not a document").unwrap();
+    assert_eq!(db.list(), Ok(vec![]));
+    fs::remove_dir_all(dir).unwrap();
+}
@@ -0,0 +1,141 @@
+//! Tests for decimal values.
+
+use std::cmp::Ordering;
+use serde_yad::key::Key;
+use serde_yad::row::Row;
+use serde_yad::{Value, CURRENT_VERSION, YAD};
+use yad_core::constants::error::{ErrorMessage, DECIMAL_OUT_OF_RANGE, INVALID_DECIMAL_TEXT, MALFORMED_DECIMAL, NOT_A_DECIMAL};
+use yad_core::decimal::{Decimal, DECIMAL_EXTENSION_TAG};
+use yad_core::kind::ValueKind;
+
+#[test]
+fn decimals_round_trip_exactly() {
+    for decimal in [
+        Decimal::new(0, 0),
+        Decimal::new(1990, 2),
+        Decimal::new(-5, 3),
+        Decimal::new(150, 2),
+        Decimal::new(i128::MAX, 0),
+        Decimal::new(i128::MIN, 255),
+        Decimal::new(-128, 1),
+        Decimal::new(128, 1),
+    ] {
+        let value = Value::from(decimal);
+        assert!(value.is_decimal());
+        assert_eq!(value.kind(), ValueKind::Decimal);
+        assert_eq!(value.extension_tag(), Ok(DECIMAL_EXTENSION_TAG));
+        assert_eq!(Value::decode(value.bytes.to_vec()).unwrap(), value);
+        assert_eq!(TryInto::<Decimal>::try_into(value), Ok(decimal));
+    }
+}
+
+#[test]
+fn mantissas_take_the_fewest_bytes() {
+    assert_eq!(Value::from(Decimal::new(1990, 2)).extension_data(), Ok(&[0x02, 0x07, 0xC6][..]));
+    assert_eq!(Value::from(Decimal::new(-1, 0)).extension_data(), Ok(&[0x00, 0xFF][..]));
+    assert_eq!(Value::from(Decimal::new(128, 0)).extension_data(), Ok(&[0x00, 0x00, 0x80][..]));
+    assert_eq!(Value::from(Decimal::new(-128, 0)).extension_data(), Ok(&[0x00, 0x80][..]));
+}
+
+#[test]
+fn other_values_are_not_decimals() {
+    assert_eq!(Value::from(1u8).as_decimal(), Err(ErrorMessage(NOT_A_DECIMAL)));
+    assert_eq!(Value::from_extension(0x80, &[2, 1]).unwrap().as_decimal(), Err(ErrorMessage(NOT_A_DECIMAL)));
+    assert_eq!(Value::from_extension(DECIMAL_EXTENSION_TAG, &[2]).unwrap().as_decimal(), Err(ErrorMessage(MALFORMED_DECIMAL)));
+    assert_eq!(Value::from_extension(DECIMAL_EXTENSION_TAG, &[0; 18]).unwrap().as_decimal(), Err(ErrorMessage(MALFORMED_DECIMAL)));
+}
+
+#[test]
+fn display_and_parse_keep_the_scale() {
+    for (text, decimal) in [("19.90", Decimal::new(1990, 2)), ("-0.005", Decimal::new(-5, 3)), ("42", Decimal::new(42, 0)), ("0.0", Decimal::new(0, 1))] {
+        assert_eq!(text.parse::<Decimal>(), Ok(decimal));
+        assert_eq!(decimal.to_string(), text);
+        assert_eq!(Value::from(decimal).to_string(), text);
+    }
+
+    assert_eq!("+1.5".parse::<Decimal>(), Ok(Decimal::new(15, 1)));
+    for text in ["", "1.", ".5", "1e3", "1.2.3", "- 1", "abc"] {
+        assert_eq!(text.parse::<Decimal>(), Err(ErrorMessage(INVALID_DECIMAL_TEXT)), "{text}");
+    }
+    assert_eq!("1".repeat(40).parse::<Decimal>(), Err(ErrorMessage(DECIMAL_OUT_OF_RANGE)));
+    assert_eq!(format!("0.{}", "1".repeat(256)).parse::<Decimal>(), Err(ErrorMessage(DECIMAL_OUT_OF_RANGE)));
+}
+
+#[test]
+fn comparison_by_value_ignores_the_scale() {
+    let a = Decimal::new(150, 2);
+    let b = Decimal::new(15, 1);
+    assert_ne!(a, b);
+    assert_eq!(a.cmp_value(&b), Ordering::Equal);
+    assert_eq!(a.normalized(), b);
+    assert_eq!(Decimal::new(-1, 0).cmp_value(&Decimal::new(1, 3)), Ordering::Less);
+    assert_eq!(Decimal::new(i128::MAX, 0).cmp_value(&Decimal::new(1, 30)), Ordering::Greater);
+    assert_eq!(Decimal::new(i128::MIN, 0).cmp_value(&Decimal::new(-1, 30)), Ordering::Less);
+
+    assert_eq!(Value::from(a).semantic_cmp(&Value::from(b)), Ordering::Equal);
+}
+
+#[test]
+fn decimals_live_in_documents_and_text() {
+    let price = Value::from(Decimal::new(1990, 2));
+    let yad = YAD::new(CURRENT_VERSION, vec![Row::new("shop", vec![
+        Key::new("price", price.clone()),
+        Key::new("history", Value::try_from(vec![price, Value::from(Decimal::new(-1, 0))]).unwrap()),
+    ])]);
+
+    assert_eq!(YAD::deserialize(yad.serialize().unwrap()).unwrap(), yad);
+    let text = yad.to_text().unwrap();
+    assert!(text.contains("price = 19.90d"), "{text}");
+    assert_eq!(YAD::from_text(&text).unwrap(), yad);
+    assert_eq!(format!("{:?}", yad.get_row("shop").unwrap().get_keys()["price"]), "price = 19.90d");
+}
+
+#[cfg(feature = "decimal")]
+#[test]
+fn rust_decimal_converts_both_ways() {
+    let decimal = rust_decimal::Decimal::new(-123_450, 3);
+    let value = Value::from(decimal);
+    assert_eq!(value.as_decimal(), Ok(Decimal::new(-123_450, 3)));
+    assert_eq!(TryInto::<rust_decimal::Decimal>::try_into(value), Ok(decimal));
+
+    let wide = Value::from(Decimal::new(i128::MAX, 0));
+    assert_eq!(TryInto::<rust_decimal::Decimal>::try_into(wide), Err(ErrorMessage(DECIMAL_OUT_OF_RANGE)));
+}
+
+#[cfg(feature = "cbor")]
+#[test]
+fn cbor_writes_decimal_fractions() {
+    let value = Value::from(Decimal::new(1990, 2));
+    let cbor = value.to_cbor().unwrap();
+    assert_eq!(cbor, ciborium::Value::Tag(4, Box::new(ciborium::Value::Array(vec![(-2).into(), 1990.into()]))));
+    assert_eq!(Value::from_cbor(&cbor), Ok(value));
+
+    let wide = Value::from(Decimal::new(i128::MAX, 3));
+    assert_eq!(Value::from_cbor(&wide.to_cbor().unwrap()), Ok(wide));
+}
+
+#[cfg(feature = "ffi")]
+#[test]
+fn ffi_builds_and_reads_decimals() {
+    use std::ffi::{CStr, CString};
+    use yad_core::ffi::value::{cstring_free, cstring_from_decimal_value, decimal_from_value, value_free, value_from_decimal, value_from_decimal_cstring};
+
+    let value = value_from_decimal(-1990, 2);
+    let (mut mantissa, mut scale) = (0, 0);
+    assert!(unsafe { decimal_from_value(value, &mut mantissa, &mut scale) });
+    assert_eq!((mantissa, scale), (-1990, 2));
+
+    let text = unsafe { cstring_from_decimal_value(value) };
+    assert_eq!(unsafe { CStr::from_ptr(text) }.to_str(), Ok("-19.90"));
+    cstring_free(text as *mut _);
+    value_free(value);
+
+    let input = CString::new("12345678901234567890.5").unwrap();
+    let wide = unsafe { value_from_decimal_cstring(input.as_ptr()) };
+    assert!(!wide.is_null());
+    assert!(!unsafe { decimal_from_value(wide, &mut mantissa, &mut scale) });
+    value_free(wide);
+
+    let bad = CString::new("1e3").unwrap();
+    assert!(unsafe { value_from_decimal_cstring(bad.as_ptr()) }.is_null());
+}
@@ -0,0 +1,88 @@
+//! Tests for key projection applied while decoding.
+
+use serde_yad::error::{DUPLICATE_NAME, MALFORMED_FILE, ROW_NOT_FOUND};
+use serde_yad::header::Header;
+use serde_yad::key::Key;
+use serde_yad::options::{DecodeOptions, DuplicatePolicy, LengthEncoding, SerializeOptions};
+use serde_yad::projection::Projection;
+use serde_yad::row::Row;
+use serde_yad::{CURRENT_VERSION, Value, YAD};
+use yad_core::constants::error::ErrorMessage;
+
+fn johan() -> Row {
+    Row::new("johan", vec![
+        Key::new("name", Value::try_from("Johan").unwrap()),
+        Key::new("age", 31u8),
+        Key::new("tags", Value::try_from(vec![1u16, 2, 3]).unwrap()),
+    ])
+}
+
+fn people() -> YAD {
+    YAD::new(CURRENT_VERSION, vec![johan(), Row::new("maria", vec![Key::new("age", 28u8), Key::new("city", Value::try_from("Lima").unwrap())])])
+}
+
+#[test]
+fn projection_keeps_only_the_named_keys() {
+    let bytes = people().serialize().unwrap();
+    let yad = YAD::deserialize_with_options(bytes, &DecodeOptions::project(&["name", "age"])).unwrap();
+
+    assert_eq!(yad["johan"].keys.keys().collect::<Vec<_>>(), ["age", "name"]);
+    assert_eq!(yad["johan"]["age"], Value::from(31u8));
+    assert_eq!(yad["maria"].keys.keys().collect::<Vec<_>>(), ["age"]);
+}
+
+#[test]
+fn projection_applies_to_encoded_bodies() {
+    let options = SerializeOptions { length_encoding: LengthEncoding::Varint, dictionary: true, ..SerializeOptions::default() };
+    let bytes = people().serialize_with_options(&options).unwrap();
+    let yad = YAD::deserialize_with_options(bytes, &DecodeOptions::project(&["city"])).unwrap();
+
+    assert!(yad["johan"].keys.is_empty());
+    assert_eq!(yad["maria"]["city"], Value::try_from("Lima").unwrap());
+}
+
+#[test]
+fn row_projection_drops_unselected_rows() {
+    let options = DecodeOptions { projection: Projection::all().with_rows(&["maria"]), ..DecodeOptions::default() };
+    let yad = YAD::deserialize_with_options(people().serialize().unwrap(), &options).unwrap();
+    assert_eq!(yad.rows.keys().collect::<Vec<_>>(), ["maria"]);
+
+    let row = Row::deserialize_with_options(johan().serialize().unwrap(), &options);
+    assert_eq!(row.err(), Some(ErrorMessage(ROW_NOT_FOUND)));
+}
+
+#[test]
+fn row_projection_matches_projected_decoding() {
+    let row = Row::deserialize_with_options(johan().serialize().unwrap(), &DecodeOptions::project(&["tags"])).unwrap();
+
+    assert_eq!(row, Row::new("johan", vec![Key::new("tags", johan()["tags"].clone())]));
+}
+
+#[test]
+fn skipped_keys_are_not_checked_for_duplicates() {
+    let first = Row::new("johan", vec![Key::new("age", 1u8)]).serialize().unwrap();
+    let second = Key::new("age", 2u8).serialize().unwrap();
+    let mut bytes = first[..first.len() - 1].to_vec();
+    bytes.extend_from_slice(&second);
+    bytes.push(*first.last().unwrap());
+
+    let strict = |keys: &[&str]| DecodeOptions { duplicates: DuplicatePolicy::Error, ..DecodeOptions::project(keys) };
+
+    assert_eq!(Row::deserialize_with_options(bytes.clone(), &strict(&["age"])).err(), Some(ErrorMessage(DUPLICATE_NAME)));
+    assert!(Row::deserialize_with_options(bytes, &strict(&["name"])).unwrap().keys.is_empty());
+}
+
+#[test]
+fn sections_cut_short_are_malformed() {
+    let options = SerializeOptions { include_checksum: true, ..SerializeOptions::default() };
+    let bytes = people().serialize_with_options(&options).unwrap();
+    let (_, header_end) = Header::read(&bytes).unwrap();
+
+    let mut signed = bytes[..header_end].to_vec();
+    signed.push(0xF8);
+    let checksummed = bytes[..header_end + 3].to_vec();
+
+    for bytes in [signed, checksummed] {
+        assert_eq!(YAD::deserialize_projected(&bytes, &Projection::all()).err(), Some(ErrorMessage(MALFORMED_FILE)));
+    }
+}
@@ -0,0 +1,82 @@
+//! Tests for delta-encoded arrays.
+
+use serde_yad::borrowed::YadRef;
+use serde_yad::error::DELTA_ARRAYS_NOT_BORROWABLE;
+use serde_yad::header::{Header, HeaderFlags};
+use serde_yad::key::Key;
+use serde_yad::options::{LengthEncoding, SerializeOptions};
+use serde_yad::projection::Projection;
+use serde_yad::row::Row;
+use serde_yad::{CURRENT_VERSION, Value, YAD};
+use yad_core::byte_order::ByteOrder;
+use yad_core::constants::error::{ErrorMessage, NOT_SORTED};
+use yad_core::delta::{zigzag_decode, zigzag_encode};
+
+fn timestamps() -> Vec<u64> {
+    (0..200).map(|i| 1_700_000_000 + i * 60).collect()
+}
+
+fn sensor_rows() -> YAD {
+    let series = Value::from_sorted_u64s(&timestamps()).unwrap();
+    let unsorted = Value::try_from(vec![900u32, 3, 70_000, 5]).unwrap();
+    let mixed = Value::try_from(vec![Value::from(1u8), Value::from(2u16), Value::try_from("three").unwrap()]).unwrap();
+    let nested = Value::try_from(vec![
+        Value::from_sorted_u64s(&[10, 11, 12, 13, 14, 15]).unwrap(),
+        Value::from_sorted_u64s(&[u64::MAX - 1, u64::MAX]).unwrap(),
+    ]).unwrap();
+
+    YAD::new(CURRENT_VERSION, vec![
+        Row::new("sensor", vec![Key::new("at", series), Key::new("unsorted", unsorted), Key::new("mixed", mixed), Key::new("nested", nested)]),
+        Row::new("plain", vec![Key::new("ok", true), Key::new("n", 7u64)]),
+    ])
+}
+
+fn delta(options: SerializeOptions) -> Vec<u8> {
+    sensor_rows().serialize_with_options(&SerializeOptions { delta_arrays: true, ..options }).unwrap()
+}
+
+#[test]
+fn zigzag_round_trips() {
+    for value in [0, 1, -1, 2, -2, i64::MAX, i64::MIN] {
+        assert_eq!(zigzag_decode(zigzag_encode(value)), value);
+    }
+    assert_eq!([0, -1, 1, -2].map(zigzag_encode), [0, 1, 2, 3]);
+}
+
+#[test]
+fn from_sorted_u64s_uses_one_width_and_rejects_unsorted_input() {
+    let items: Vec<Value> = Value::from_sorted_u64s(&[1, 2, 300]).unwrap().try_into().unwrap();
+    assert!(items.iter().all(|item| item.length == items[2].length));
+
+    assert_eq!(Value::from_sorted_u64s(&[2, 1]).err(), Some(ErrorMessage(NOT_SORTED)));
+}
+
+#[test]
+fn delta_arrays_round_trip_and_shrink_series() {
+    let bytes = delta(SerializeOptions::default());
+
+    assert!(bytes.len() < sensor_rows().serialize().unwrap().len() / 2);
+    assert!(Header::read(&bytes).unwrap().0.flags.contains(HeaderFlags::DELTA_ARRAYS));
+    assert_eq!(YAD::deserialize(bytes.clone()).unwrap(), sensor_rows());
+    assert_eq!(YAD::deserialize_projected(&bytes, &Projection::all()).unwrap(), sensor_rows());
+}
+
+#[test]
+fn delta_arrays_combine_with_other_encodings() {
+    let options = SerializeOptions {
+        byte_order: ByteOrder::Little,
+        length_encoding: LengthEncoding::Varint,
+        dictionary: true,
+        ..SerializeOptions::default()
+    };
+    let bytes = delta(options);
+
+    assert_eq!(YAD::deserialize(bytes).unwrap(), sensor_rows());
+}
+
+#[test]
+fn borrowed_reader_rejects_delta_arrays() {
+    let bytes = delta(SerializeOptions::default());
+
+    assert_eq!(YadRef::new(&bytes).err(), Some(ErrorMessage(DELTA_ARRAYS_NOT_BORROWABLE)));
+}
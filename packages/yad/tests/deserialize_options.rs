@@ -0,0 +1,105 @@
+//! Tests for the deserializer options accepted by [`YAD::deserialize_with_options`].
+
+use serde_yad::error::{DUPLICATE_NAME, MISSING_MAGIC, STRAY_BYTES};
+use serde_yad::key::Key;
+use serde_yad::options::{DeserializeOptions, DuplicatePolicy};
+use serde_yad::projection::Projection;
+use serde_yad::row::Row;
+use serde_yad::{CURRENT_VERSION, Value, YAD};
+use yad_core::constants::error::{ErrorMessage, NESTING_TOO_DEEP};
+
+fn nested(depth: usize) -> Value {
+    (0..depth).fold(Value::from(1u8), |value, _| Value::try_from(vec![value]).unwrap())
+}
+
+fn document(depth: usize) -> YAD {
+    YAD::new(CURRENT_VERSION, vec![
+        Row::new("johan", vec![Key::new("name", Value::try_from("Johan").unwrap()), Key::new("tree", nested(depth))]),
+        Row::new("maria", vec![Key::new("name", Value::try_from("Maria").unwrap())]),
+    ])
+}
+
+#[test]
+fn default_options_match_deserialize() {
+    let bytes = document(3).serialize().unwrap();
+
+    assert_eq!(YAD::deserialize_with_options(bytes.clone(), &DeserializeOptions::default()).unwrap(), YAD::deserialize(bytes).unwrap());
+}
+
+#[test]
+fn max_depth_bounds_array_nesting() {
+    let bytes = document(3).serialize().unwrap();
+    let options = |max_depth| DeserializeOptions { max_depth: Some(max_depth), ..DeserializeOptions::default() };
+
+    assert_eq!(YAD::deserialize_with_options(bytes.clone(), &options(3)).unwrap(), document(3));
+    assert_eq!(YAD::deserialize_with_options(bytes.clone(), &options(2)).err(), Some(ErrorMessage(NESTING_TOO_DEEP)));
+    assert_eq!(YAD::deserialize_with_options(bytes, &options(0)).err(), Some(ErrorMessage(NESTING_TOO_DEEP)));
+
+    let flat = document(0).serialize().unwrap();
+    assert_eq!(YAD::deserialize_with_options(flat, &options(0)).unwrap(), document(0));
+
+    let row = document(2).rows["johan"].serialize().unwrap();
+    assert_eq!(Row::deserialize_with_options(row, &options(1)).err(), Some(ErrorMessage(NESTING_TOO_DEEP)));
+}
+
+#[test]
+fn max_depth_stops_decoding_at_the_limit() {
+    let mut row = document(2).rows["johan"].serialize().unwrap();
+    // The inner array now declares more items than the row holds.
+    let inner = row.windows(4).position(|w| w == [0x51, 0x01, 0x11, 0x01]).unwrap();
+    row[inner + 1] = 0x05;
+    let options = DeserializeOptions { max_depth: Some(1), ..DeserializeOptions::default() };
+
+    assert_ne!(Row::deserialize(row.clone()).err(), Some(ErrorMessage(NESTING_TOO_DEEP)));
+    assert!(Row::deserialize(row.clone()).is_err());
+    assert_eq!(Row::deserialize_with_options(row, &options).err(), Some(ErrorMessage(NESTING_TOO_DEEP)));
+}
+
+#[test]
+fn strict_rejects_stray_bytes() {
+    let bytes = document(1).serialize().unwrap();
+    let strict = DeserializeOptions { strict: true, ..DeserializeOptions::default() };
+    assert_eq!(YAD::deserialize_with_options(bytes.clone(), &strict).unwrap(), document(1));
+
+    let mut trailing = bytes.clone();
+    trailing.extend_from_slice(&[0x00, 0x01]);
+    assert_eq!(YAD::deserialize(trailing.clone()).unwrap(), document(1));
+    assert_eq!(YAD::deserialize_with_options(trailing, &strict).err(), Some(ErrorMessage(STRAY_BYTES)));
+
+    // A stray byte between the two rows.
+    let second = bytes.len() - document(1).rows["maria"].serialize().unwrap().len();
+    let mut between = bytes;
+    between.insert(second, 0x00);
+    assert_eq!(YAD::deserialize(between.clone()).unwrap(), document(1));
+    assert_eq!(YAD::deserialize_with_options(between, &strict).err(), Some(ErrorMessage(STRAY_BYTES)));
+}
+
+#[test]
+fn strict_preset_rejects_what_the_defaults_tolerate() {
+    let yad = document(1);
+    let bytes = yad.serialize().unwrap();
+    assert_eq!(YAD::deserialize_with_options(bytes.clone(), &DeserializeOptions::strict()).unwrap(), yad);
+    assert_eq!(YAD::deserialize_with_options(bytes[4..].to_vec(), &DeserializeOptions::strict()).err(), Some(ErrorMessage(MISSING_MAGIC)));
+
+    let mut twice = bytes.clone();
+    twice.extend_from_slice(&yad.rows["maria"].serialize().unwrap());
+    assert_eq!(YAD::deserialize_with_options(twice, &DeserializeOptions::strict()).err(), Some(ErrorMessage(DUPLICATE_NAME)));
+}
+
+#[test]
+fn options_combine() {
+    let bytes = document(2).serialize().unwrap();
+    let options = DeserializeOptions {
+        max_size: Some(bytes.len()),
+        max_depth: Some(2),
+        duplicates: DuplicatePolicy::Error,
+        strict: true,
+        projection: Projection::all().with_keys(&["name"]),
+        ..DeserializeOptions::default()
+    };
+
+    let yad = YAD::deserialize_with_options(bytes, &options).unwrap();
+    assert_eq!(yad.rows.len(), 2);
+    assert!(yad.rows["johan"].get("tree").is_none());
+    assert!(yad.rows["johan"].get("name").is_some());
+}
@@ -0,0 +1,76 @@
+//! Tests for the key name dictionary.
+
+use serde_yad::borrowed::YadRef;
+use serde_yad::error::{DICTIONARY_NOT_BORROWABLE, MALFORMED_DICTIONARY};
+use serde_yad::header::{Header, HeaderFlags};
+use serde_yad::key::Key;
+use serde_yad::options::{LengthEncoding, SerializeOptions};
+use serde_yad::projection::Projection;
+use serde_yad::row::Row;
+use serde_yad::{CURRENT_VERSION, YAD};
+use yad_core::byte_order::ByteOrder;
+use yad_core::constants::error::ErrorMessage;
+
+fn table(rows: usize) -> YAD {
+    YAD::new(CURRENT_VERSION, (0..rows).map(|i| Row::new(format!("user{i}"), vec![
+        Key::try_new("name", format!("User {i}")).unwrap(),
+        Key::new("age", i as u32),
+        Key::new(format!("only{i}"), true),
+    ])).collect())
+}
+
+#[test]
+fn dictionary_round_trips_and_shrinks_wide_tables() {
+    let yad = table(50);
+    let plain = yad.serialize().unwrap();
+    let bytes = yad.serialize_with_dictionary().unwrap();
+
+    assert!(bytes.len() < plain.len());
+    assert!(Header::read(&bytes).unwrap().0.flags.contains(HeaderFlags::DICTIONARY));
+    assert_eq!(YAD::deserialize(bytes).unwrap(), yad);
+}
+
+#[test]
+fn no_section_is_written_without_repeated_names() {
+    let yad = table(1);
+    let bytes = yad.serialize_with_dictionary().unwrap();
+
+    assert_eq!(bytes, yad.serialize().unwrap());
+}
+
+#[test]
+fn dictionary_combines_with_other_encodings() {
+    let yad = table(5);
+    let options = SerializeOptions {
+        dictionary: true,
+        length_encoding: LengthEncoding::Varint,
+        byte_order: ByteOrder::Little,
+        embed_schema: true,
+        ..SerializeOptions::default()
+    };
+    let bytes = yad.serialize_with_options(&options).unwrap();
+
+    assert_eq!(YAD::deserialize(bytes.clone()).unwrap(), yad);
+    assert_eq!(YAD::deserialize_projected(&bytes, &Projection::all()).unwrap(), yad);
+}
+
+#[test]
+fn borrowed_reader_rejects_dictionaries() {
+    let bytes = table(2).serialize_with_dictionary().unwrap();
+
+    assert_eq!(YadRef::new(&bytes).err(), Some(ErrorMessage(DICTIONARY_NOT_BORROWABLE)));
+}
+
+#[test]
+fn flag_without_section_is_malformed() {
+    let yad = table(2);
+    let options = SerializeOptions { header_flags: Some(HeaderFlags::DICTIONARY), ..SerializeOptions::default() };
+    let mut bytes = yad.serialize_with_options(&options).unwrap();
+    assert!(!Header::read(&bytes).unwrap().0.flags.contains(HeaderFlags::DICTIONARY));
+
+    // Set the bit by hand on a file written without a dictionary.
+    let (_, end) = Header::read(&bytes).unwrap();
+    bytes[end - 3] |= 0x80;
+
+    assert_eq!(YAD::deserialize(bytes).err(), Some(ErrorMessage(MALFORMED_DICTIONARY)));
+}
@@ -0,0 +1,167 @@
+//! Tests for comparing and merging documents.
+
+mod common;
+
+use common::{document, person};
+use serde_yad::diff::{KeyChange, MergeStrategy, MetaChange, RowDiff};
+use serde_yad::key::Key;
+use serde_yad::row::Row;
+use serde_yad::{CURRENT_VERSION, Value, YAD};
+
+/// The fixture with bob one year older, alice's id swapped for an email,
+/// carol removed and dave added.
+fn edited() -> YAD {
+    let mut yad = document();
+    yad.insert_key("bob", "age", 42u8).unwrap();
+    yad.remove_key("alice", "id");
+    yad.insert_key("alice", "email", Value::try_from("alice@example.com").unwrap()).unwrap();
+    yad.remove_row("carol");
+    yad.adopt_row(person("dave", 35, 4));
+    yad
+}
+
+#[test]
+fn identical_documents_have_no_differences() {
+    assert!(document().diff(&document()).is_empty());
+    assert!(RowDiff::between(&person("bob", 41, 2), &person("bob", 41, 2)).is_empty());
+}
+
+#[test]
+fn added_removed_and_changed_rows_and_keys() {
+    let diff = document().diff(&edited());
+
+    assert_eq!(diff.added_rows, [person("dave", 35, 4)]);
+    assert_eq!(diff.removed_rows, [person("carol", 27, 3)]);
+    assert_eq!(diff.changed_rows, [
+        RowDiff {
+            name: "alice".into(),
+            changed_meta: None,
+            added_keys: vec![Key::try_new("email", "alice@example.com").unwrap()],
+            removed_keys: vec![Key::new("id", 1u16)],
+            changed_keys: vec![],
+        },
+        RowDiff {
+            name: "bob".into(),
+            changed_meta: None,
+            added_keys: vec![],
+            removed_keys: vec![],
+            changed_keys: vec![KeyChange { name: "age".into(), before: Key::new("age", 41u8), after: Key::new("age", 42u8) }],
+        },
+    ]);
+}
+
+#[test]
+fn diffs_are_directional() {
+    let forward = document().diff(&edited());
+    let backward = edited().diff(&document());
+
+    assert_eq!(forward.added_rows, backward.removed_rows);
+    assert_eq!(forward.removed_rows, backward.added_rows);
+    assert_eq!(backward.changed_rows[1].changed_keys[0].before, Key::new("age", 42u8));
+}
+
+#[test]
+fn a_different_width_is_a_change() {
+    let mut wider = document();
+    wider.insert_key("bob", "age", 41u16).unwrap();
+
+    let diff = document().diff(&wider);
+    assert_eq!(diff.changed_rows.len(), 1);
+    assert_eq!(diff.changed_rows[0].changed_keys[0].after, Key::new("age", 41u16));
+}
+
+/// The receiving document of the merge tests.
+fn base() -> YAD {
+    YAD::new(CURRENT_VERSION, vec![
+        Row::new("johan", vec![Key::new("age", 17u8), Key::new("level", 3u8)]),
+        Row::new("maria", vec![Key::new("age", 20u8)]),
+    ])
+}
+
+/// The merged document: it changes johan's age, drops his level, adds a
+/// city and adds pedro.
+fn incoming() -> YAD {
+    YAD::new(CURRENT_VERSION, vec![
+        Row::new("johan", vec![Key::new("age", 18u8), Key::try_new("city", "Lima").unwrap()]),
+        Row::new("pedro", vec![Key::new("age", 30u8)]),
+    ])
+}
+
+fn merged(strategy: MergeStrategy) -> YAD {
+    let mut yad = base();
+    yad.merge(incoming(), strategy);
+    yad
+}
+
+#[test]
+fn merges_keep_rows_of_both_documents() {
+    for strategy in [MergeStrategy::KeepExisting, MergeStrategy::Overwrite, MergeStrategy::ReplaceRows] {
+        let yad = merged(strategy);
+
+        assert_eq!(yad.rows.keys().collect::<Vec<_>>(), ["johan", "maria", "pedro"], "{:?}", strategy);
+        assert_eq!(yad["maria"], base()["maria"], "{:?}", strategy);
+        assert_eq!(yad["pedro"], incoming()["pedro"], "{:?}", strategy);
+    }
+}
+
+#[test]
+fn keep_existing_only_adds_missing_keys() {
+    let yad = merged(MergeStrategy::KeepExisting);
+
+    assert_eq!(yad["johan"]["age"], Value::from(17u8));
+    assert_eq!(yad["johan"]["level"], Value::from(3u8));
+    assert_eq!(yad["johan"]["city"], Value::try_from("Lima").unwrap());
+}
+
+#[test]
+fn overwrite_replaces_conflicting_keys() {
+    let yad = merged(MergeStrategy::Overwrite);
+
+    assert_eq!(yad["johan"]["age"], Value::from(18u8));
+    assert_eq!(yad["johan"]["level"], Value::from(3u8));
+    assert_eq!(yad["johan"]["city"], Value::try_from("Lima").unwrap());
+}
+
+#[test]
+fn replace_rows_drops_keys_the_merged_row_lacks() {
+    let yad = merged(MergeStrategy::ReplaceRows);
+
+    assert_eq!(yad["johan"], incoming()["johan"]);
+    assert!(yad["johan"].get_key("level").is_none());
+}
+
+#[test]
+fn merges_add_and_change_but_never_remove() {
+    let mut yad = document();
+    yad.merge(edited(), MergeStrategy::Overwrite);
+
+    // Only what `edited` removed is left over: carol and alice's id.
+    let diff = yad.diff(&edited());
+    assert!(diff.added_rows.is_empty());
+    assert_eq!(diff.removed_rows, [person("carol", 27, 3)]);
+    assert_eq!(diff.changed_rows.len(), 1);
+    assert_eq!(diff.changed_rows[0].removed_keys, [Key::new("id", 1u16)]);
+    assert!(diff.changed_rows[0].added_keys.is_empty() && diff.changed_rows[0].changed_keys.is_empty());
+}
+
+#[test]
+fn row_metadata_is_compared_and_merged() {
+    let mut expiring = document();
+    expiring.get_row_mut("bob").unwrap().meta.set_expires(100);
+
+    let diff = document().diff(&expiring);
+    assert_eq!(diff.changed_rows.len(), 1);
+    assert_eq!(diff.changed_rows[0].changed_meta, Some(MetaChange { before: Default::default(), after: expiring["bob"].meta.clone() }));
+    assert!(diff.changed_rows[0].changed_keys.is_empty());
+
+    let mut yad = document();
+    yad.merge(expiring.clone(), MergeStrategy::KeepExisting);
+    assert_eq!(yad, expiring);
+
+    let mut later = document();
+    later.get_row_mut("bob").unwrap().meta.set_expires(200);
+    yad.merge(later.clone(), MergeStrategy::KeepExisting);
+    assert_eq!(yad, expiring);
+    yad.merge(later.clone(), MergeStrategy::Overwrite);
+    assert_eq!(yad, later);
+}
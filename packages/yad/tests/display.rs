@@ -0,0 +1,48 @@
+//! Tests for rendering values, types and lengths as text.
+
+use serde_yad::Value;
+use yad_core::constants::error::{ErrorMessage, NOT_A_UINT32};
+use yad_core::constants::length::ByteLength;
+use yad_core::constants::types::Type;
+
+/// A `uint32` whose payload lost its last two bytes.
+fn truncated_u32() -> Value {
+    let mut value = Value::from(70_000u32);
+    value.bytes.truncate(3);
+    value
+}
+
+#[test]
+fn valid_values_render_the_same_both_ways() {
+    let value = Value::try_from(vec![Value::from(1u8), Value::try_from("two").unwrap(), Value::from(true)]).unwrap();
+
+    assert_eq!(value.to_string(), "[1, two, true]");
+    assert_eq!(value.try_display(), Ok("[1, two, true]".to_string()));
+}
+
+#[test]
+fn invalid_payloads_render_a_placeholder() {
+    let text = truncated_u32().to_string();
+
+    assert!(text.starts_with("<invalid uint32: "), "{text}");
+    assert_eq!(truncated_u32().try_display().err(), Some(ErrorMessage(NOT_A_UINT32)));
+}
+
+#[test]
+fn invalid_strings_render_a_placeholder() {
+    let mut value = Value::try_from("text").unwrap();
+    value.bytes[2] = 0xFF;
+
+    assert!(value.to_string().starts_with("<invalid string: "));
+    assert!(value.try_display().is_err());
+}
+
+#[test]
+fn types_and_lengths_have_names() {
+    assert_eq!(Type::Uint.to_string(), "uint");
+    assert_eq!(Type::Float.to_string(), "float");
+    assert_eq!(Type::True.to_string(), "bool");
+    assert_eq!(ByteLength::Zero.to_string(), "0");
+    assert_eq!(ByteLength::Four.to_string(), "32");
+    assert_eq!(format!("{}{}", Type::Int, ByteLength::Eight), "int64");
+}
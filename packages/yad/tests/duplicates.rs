@@ -0,0 +1,69 @@
+//! Tests for the duplicate-name policy applied while decoding.
+
+use serde_yad::error::DUPLICATE_NAME;
+use serde_yad::key::Key;
+use serde_yad::options::{DecodeOptions, DuplicatePolicy};
+use serde_yad::row::Row;
+use serde_yad::{CURRENT_VERSION, Value, YAD};
+use yad_core::constants::error::ErrorMessage;
+
+/// A row with two keys named `age`, holding 1 then 2.
+fn row_with_duplicate_keys() -> Vec<u8> {
+    let first = Row::new("johan", vec![Key::new("age", 1u8)]).serialize().unwrap();
+    let second = Key::new("age", 2u8).serialize().unwrap();
+
+    let mut bytes = first[..first.len() - 1].to_vec();
+    bytes.extend_from_slice(&second);
+    bytes.push(*first.last().unwrap());
+    bytes
+}
+
+/// A document with two rows named `johan`, holding `age` 1 then 2.
+fn document_with_duplicate_rows() -> Vec<u8> {
+    let mut bytes = YAD::new(CURRENT_VERSION, vec![Row::new("johan", vec![Key::new("age", 1u8)])]).serialize().unwrap();
+    bytes.extend(Row::new("johan", vec![Key::new("age", 2u8)]).serialize().unwrap());
+    bytes
+}
+
+fn decode(bytes: Vec<u8>, duplicates: DuplicatePolicy) -> Result<YAD, ErrorMessage> {
+    YAD::deserialize_with_options(bytes, &DecodeOptions { duplicates, ..DecodeOptions::default() })
+}
+
+#[test]
+fn keep_last_is_the_default() {
+    let row = Row::deserialize(row_with_duplicate_keys()).unwrap();
+    assert_eq!(row["age"], Value::from(2u8));
+
+    let yad = YAD::deserialize(document_with_duplicate_rows()).unwrap();
+    assert_eq!(yad["johan"]["age"], Value::from(2u8));
+}
+
+#[test]
+fn error_rejects_duplicate_keys_and_rows() {
+    let error = ErrorMessage(DUPLICATE_NAME);
+
+    assert_eq!(Row::deserialize_with_policy(row_with_duplicate_keys(), DuplicatePolicy::Error), Err(error));
+    assert_eq!(decode(document_with_duplicate_rows(), DuplicatePolicy::Error), Err(error));
+}
+
+#[test]
+fn keep_first_drops_later_duplicates() {
+    let row = Row::deserialize_with_policy(row_with_duplicate_keys(), DuplicatePolicy::KeepFirst).unwrap();
+    assert_eq!(row["age"], Value::from(1u8));
+
+    let yad = decode(document_with_duplicate_rows(), DuplicatePolicy::KeepFirst).unwrap();
+    assert_eq!(yad["johan"]["age"], Value::from(1u8));
+}
+
+#[test]
+fn keep_all_with_suffix_renames_later_duplicates() {
+    let row = Row::deserialize_with_policy(row_with_duplicate_keys(), DuplicatePolicy::KeepAllWithSuffix).unwrap();
+    assert_eq!(row["age"], Value::from(1u8));
+    assert_eq!(row["age#2"], Value::from(2u8));
+    assert_eq!(row.keys["age#2"].name, "age#2");
+
+    let yad = decode(document_with_duplicate_rows(), DuplicatePolicy::KeepAllWithSuffix).unwrap();
+    assert_eq!(yad["johan"]["age"], Value::from(1u8));
+    assert_eq!(yad["johan#2"]["age"], Value::from(2u8));
+    assert_eq!(yad["johan#2"].name, "johan#2");
+}
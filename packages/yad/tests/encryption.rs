@@ -0,0 +1,98 @@
+//! Tests for encrypting and decrypting whole documents.
+#![cfg(feature = "encryption")]
+
+mod common;
+
+use std::io::Cursor;
+use common::document;
+use serde_yad::constants::MAGIC;
+use serde_yad::encryption::{Cipher, EncryptionKey};
+use serde_yad::error::{AUTHENTICATION_FAILED, ENCRYPTED_DOCUMENT, MALFORMED_ENVELOPE, UNKNOWN_CIPHER};
+use serde_yad::stream::YadRowStream;
+use serde_yad::YAD;
+use yad_core::constants::error::ErrorMessage;
+
+const CIPHERS: [Cipher; 2] = [Cipher::ChaCha20Poly1305, Cipher::Aes256Gcm];
+
+/// Offset of the cipher id, after the magic number, version and envelope header.
+const CIPHER_ID: usize = MAGIC.len() + 5 + 1;
+
+#[test]
+fn round_trips_under_both_ciphers() {
+    for cipher in CIPHERS {
+        let key = EncryptionKey::new(cipher, [7; 32]);
+        let bytes = document().serialize_encrypted(&key).unwrap();
+
+        assert_eq!(bytes[CIPHER_ID], u8::from(cipher));
+        assert_eq!(YAD::deserialize_encrypted(&key, &bytes).unwrap(), document());
+    }
+}
+
+#[test]
+fn every_call_uses_a_fresh_nonce() {
+    let key = EncryptionKey::new(Cipher::ChaCha20Poly1305, [7; 32]);
+    assert_ne!(document().serialize_encrypted(&key).unwrap(), document().serialize_encrypted(&key).unwrap());
+}
+
+#[test]
+fn the_body_is_not_readable() {
+    let plain = document().serialize().unwrap();
+    let bytes = document().serialize_encrypted(&EncryptionKey::new(Cipher::Aes256Gcm, [7; 32])).unwrap();
+
+    assert!(!bytes.windows(5).any(|window| window == b"alice"));
+    assert!(plain.windows(5).any(|window| window == b"alice"));
+}
+
+#[test]
+fn a_wrong_key_fails_authentication() {
+    for cipher in CIPHERS {
+        let bytes = document().serialize_encrypted(&EncryptionKey::new(cipher, [7; 32])).unwrap();
+        let wrong = EncryptionKey::new(cipher, [8; 32]);
+
+        assert_eq!(YAD::deserialize_encrypted(&wrong, &bytes), Err(ErrorMessage(AUTHENTICATION_FAILED)));
+    }
+}
+
+#[test]
+fn flipped_bytes_fail_authentication() {
+    let key = EncryptionKey::new(Cipher::ChaCha20Poly1305, [7; 32]);
+    let bytes = document().serialize_encrypted(&key).unwrap();
+
+    // The last byte of the ciphertext, then the patch number of the version,
+    // which is only authenticated as associated data.
+    for offset in [bytes.len() - 1, MAGIC.len() + 3] {
+        let mut tampered = bytes.clone();
+        tampered[offset] ^= 0x01;
+
+        assert_eq!(YAD::deserialize_encrypted(&key, &tampered), Err(ErrorMessage(AUTHENTICATION_FAILED)), "at {}", offset);
+    }
+}
+
+#[test]
+fn the_cipher_must_match_the_key() {
+    let bytes = document().serialize_encrypted(&EncryptionKey::new(Cipher::ChaCha20Poly1305, [7; 32])).unwrap();
+    let other = EncryptionKey::new(Cipher::Aes256Gcm, [7; 32]);
+    assert_eq!(YAD::deserialize_encrypted(&other, &bytes), Err(ErrorMessage(UNKNOWN_CIPHER)));
+
+    let mut unknown = bytes.clone();
+    unknown[CIPHER_ID] = 0x7F;
+    let key = EncryptionKey::new(Cipher::ChaCha20Poly1305, [7; 32]);
+    assert_eq!(YAD::deserialize_encrypted(&key, &unknown), Err(ErrorMessage(UNKNOWN_CIPHER)));
+}
+
+#[test]
+fn plain_or_truncated_bytes_are_not_an_envelope() {
+    let key = EncryptionKey::new(Cipher::ChaCha20Poly1305, [7; 32]);
+    let bytes = document().serialize_encrypted(&key).unwrap();
+
+    assert_eq!(YAD::deserialize_encrypted(&key, &document().serialize().unwrap()), Err(ErrorMessage(MALFORMED_ENVELOPE)));
+    assert_eq!(YAD::deserialize_encrypted(&key, &bytes[..CIPHER_ID + 5]), Err(ErrorMessage(MALFORMED_ENVELOPE)));
+}
+
+#[test]
+fn plain_readers_reject_encrypted_documents() {
+    let bytes = document().serialize_encrypted(&EncryptionKey::new(Cipher::Aes256Gcm, [7; 32])).unwrap();
+
+    assert_eq!(YAD::deserialize(bytes.clone()), Err(ErrorMessage(ENCRYPTED_DOCUMENT)));
+    assert_eq!(YadRowStream::open(Cursor::new(bytes)).err(), Some(ErrorMessage(ENCRYPTED_DOCUMENT)));
+}
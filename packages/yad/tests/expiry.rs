@@ -0,0 +1,100 @@
+//! Tests for row and key expiry timestamps.
+
+use std::sync::{Arc, Mutex};
+use serde_yad::borrowed::YadRef;
+use serde_yad::key::Key;
+use serde_yad::observe::ChangeEvent;
+use serde_yad::row::Row;
+use serde_yad::stream::YadRowStream;
+use serde_yad::{CURRENT_VERSION, Value, YAD};
+
+fn expiring_row(name: &str, expires: u64) -> Row {
+    let mut row = Row::new(name, vec![Key::new("v", Value::from(1u8))]);
+    row.meta.set_expires(expires);
+    row
+}
+
+fn expiring() -> YAD {
+    let mut token = Key::new("token", Value::from(7u8));
+    token.meta.set_expires(150);
+
+    YAD::new(CURRENT_VERSION, vec![
+        expiring_row("short", 100),
+        expiring_row("long", 200),
+        Row::new("forever", vec![Key::new("name", Value::try_from("Johan").unwrap()), token]),
+    ])
+}
+
+#[test]
+fn expiry_round_trips() {
+    let yad = expiring();
+    let read = YAD::deserialize(yad.serialize().unwrap()).unwrap();
+
+    assert_eq!(read.rows["short"].meta().expires(), Some(100));
+    assert!(read.rows["short"].meta().is_expired(100));
+    assert!(!read.rows["short"].meta().is_expired(99));
+    assert!(!read.rows["forever"].meta().is_expired(u64::MAX));
+}
+
+#[test]
+fn purge_removes_expired_rows_and_keys() {
+    let mut yad = expiring();
+    let events = Arc::new(Mutex::new(vec![]));
+    let sink = events.clone();
+    yad.on_change(move |event| sink.lock().unwrap().push(event.clone()));
+
+    assert_eq!(yad.purge_expired(99), 0);
+    assert_eq!(yad.purge_expired(150), 2);
+
+    assert_eq!(yad.rows.keys().collect::<Vec<_>>(), ["forever", "long"]);
+    assert!(yad.rows["forever"].get("token").is_none());
+    assert!(yad.rows["forever"].get("name").is_some());
+    assert_eq!(*events.lock().unwrap(), [
+        ChangeEvent::RowRemoved { row: "short".to_string() },
+        ChangeEvent::KeyRemoved { row: "forever".to_string(), key: "token".to_string() },
+    ]);
+
+    assert_eq!(yad.purge_expired(u64::MAX), 1);
+    assert_eq!(yad.rows.len(), 1);
+}
+
+#[test]
+fn borrowed_reads_skip_expired_entries() {
+    let yad = expiring();
+    let bytes = yad.serialize().unwrap();
+
+    let all = YadRef::new(&bytes).unwrap();
+    assert_eq!(all.rows().count(), 3);
+    assert!(all.row("short").unwrap().is_some());
+
+    let doc = YadRef::new(&bytes).unwrap().expiring_at(150);
+    let names: Vec<&str> = doc.rows().map(|row| row.unwrap().name).collect();
+    assert_eq!(names, ["forever", "long"]);
+    assert!(doc.row("short").unwrap().is_none());
+    assert!(!doc.contains_row("short").unwrap());
+
+    let forever = doc.row("forever").unwrap().unwrap();
+    assert!(forever.get("token").unwrap().is_none());
+    assert_eq!(forever.keys().count(), 1);
+
+    let mut purged = yad.clone();
+    purged.purge_expired(150);
+    assert_eq!(doc.to_owned().unwrap(), purged);
+}
+
+#[test]
+fn streamed_reads_skip_expired_entries() {
+    let yad = expiring();
+    let bytes = yad.serialize().unwrap();
+
+    assert_eq!(YadRowStream::open(bytes.as_slice()).unwrap().count(), 3);
+
+    let rows: Vec<Row> = YadRowStream::open(bytes.as_slice()).unwrap()
+        .expiring_at(150)
+        .collect::<Result<_, _>>()
+        .unwrap();
+
+    let mut purged = yad.clone();
+    purged.purge_expired(150);
+    assert_eq!(YAD::new(CURRENT_VERSION, rows), purged);
+}
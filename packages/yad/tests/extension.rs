@@ -0,0 +1,167 @@
+//! Tests for extension values and the codec registry.
+
+use serde_yad::key::Key;
+use serde_yad::row::Row;
+use serde_yad::{Value, CURRENT_VERSION, YAD};
+use yad_core::constants::error::{
+    ErrorMessage, EXTENSION_OF_LENGTH_ZERO, EXTENSION_TAG_RESERVED, EXTENSION_TAG_TAKEN, EXTENSION_TARGET_MISMATCH,
+    EXTENSION_TARGET_TAKEN, NOT_AN_EXTENSION, UNKNOWN_EXTENSION_TAG, UNKNOWN_EXTENSION_TARGET,
+};
+use yad_core::constants::types::Type;
+use yad_core::extension::{ValueCodec, ValueCodecRegistry};
+use yad_core::kind::ValueKind;
+
+const BAD_POINT: &str = "A geo-point takes 8 bytes.";
+
+#[derive(Debug, PartialEq)]
+struct Point {
+    lat: f32,
+    lon: f32,
+}
+
+struct PointCodec;
+
+impl ValueCodec for PointCodec {
+    type Target = Point;
+
+    fn name(&self) -> &str {
+        "geo-point"
+    }
+
+    fn encode(&self, point: &Point) -> Result<Vec<u8>, ErrorMessage> {
+        Ok([point.lat.to_be_bytes(), point.lon.to_be_bytes()].concat())
+    }
+
+    fn decode(&self, data: &[u8]) -> Result<Point, ErrorMessage> {
+        let data: [u8; 8] = data.try_into().map_err(|_| ErrorMessage(BAD_POINT))?;
+        Ok(Point {
+            lat: f32::from_be_bytes(data[..4].try_into().unwrap()),
+            lon: f32::from_be_bytes(data[4..].try_into().unwrap()),
+        })
+    }
+}
+
+struct Cents;
+
+impl ValueCodec for Cents {
+    type Target = i64;
+
+    fn name(&self) -> &str {
+        "cents"
+    }
+
+    fn encode(&self, value: &i64) -> Result<Vec<u8>, ErrorMessage> {
+        Ok(value.to_be_bytes().to_vec())
+    }
+
+    fn decode(&self, data: &[u8]) -> Result<i64, ErrorMessage> {
+        Ok(i64::from_be_bytes(data.try_into().map_err(|_| ErrorMessage("Cents take 8 bytes."))?))
+    }
+}
+
+fn registry() -> ValueCodecRegistry {
+    let mut registry = ValueCodecRegistry::new();
+    registry.register(0x80, PointCodec).unwrap();
+    registry.register(0x81, Cents).unwrap();
+    registry
+}
+
+#[test]
+fn extension_values_round_trip_through_decode() {
+    for data in [&[][..], &[1, 2, 3], &[7; 300]] {
+        let value = Value::from_extension(0x90, data).unwrap();
+        assert_eq!(value.r#type, Type::Extension);
+        assert_eq!(value.kind(), ValueKind::Extension);
+        assert!(value.is_extension());
+        assert_eq!(Value::decode(value.bytes.to_vec()).unwrap(), value);
+        assert_eq!(Value::encoded_len(&value.bytes).unwrap(), value.bytes.len());
+        assert_eq!(value.extension_tag(), Ok(0x90));
+        assert_eq!(value.extension_data(), Ok(data));
+    }
+
+    assert_eq!(Value::from(1u8).extension_tag(), Err(ErrorMessage(NOT_AN_EXTENSION)));
+    assert_eq!(Value::decode(vec![0xA1, 0x00]), Err(ErrorMessage(EXTENSION_OF_LENGTH_ZERO)));
+    assert_eq!(Value::decode(vec![0xA0]), Err(ErrorMessage(EXTENSION_OF_LENGTH_ZERO)));
+}
+
+#[test]
+fn extension_values_live_in_arrays_and_documents() {
+    let point = Value::from_extension(0x80, &[0xAB; 8]).unwrap();
+    let array = Value::try_from(vec![Value::from(1u8), point.clone(), Value::from(true)]).unwrap();
+    assert_eq!(TryInto::<Vec<Value>>::try_into(array.clone()).unwrap()[1], point);
+
+    let yad = YAD::new(CURRENT_VERSION, vec![Row::new("shop", vec![Key::new("at", point), Key::new("all", array)])]);
+    assert_eq!(YAD::deserialize(yad.serialize().unwrap()).unwrap(), yad);
+    assert_eq!(YAD::from_text(&yad.to_text().unwrap()).unwrap(), yad);
+}
+
+#[test]
+fn display_writes_tag_and_data_in_hex() {
+    assert_eq!(Value::from_extension(0x81, &[0x0A, 0xFF]).unwrap().to_string(), "ext.81.0AFF");
+    assert_eq!(Value::from_extension(0x05, &[]).unwrap().to_string(), "ext.05.");
+}
+
+#[test]
+fn registry_encodes_and_decodes_by_type() {
+    let registry = registry();
+    let point = Point { lat: 40.4, lon: -3.7 };
+
+    let value = registry.encode(&point).unwrap();
+    assert_eq!(value.extension_tag(), Ok(0x80));
+    assert_eq!(registry.decode::<Point>(&value), Ok(point));
+    assert_eq!(registry.decode::<i64>(&registry.encode(&-1250i64).unwrap()), Ok(-1250));
+
+    assert_eq!(registry.decode::<i64>(&value), Err(ErrorMessage(EXTENSION_TARGET_MISMATCH)));
+    assert_eq!(registry.encode(&1u8), Err(ErrorMessage(UNKNOWN_EXTENSION_TARGET)));
+    assert_eq!(registry.decode::<Point>(&Value::from(1u8)), Err(ErrorMessage(NOT_AN_EXTENSION)));
+
+    let unknown = Value::from_extension(0xEE, &[]).unwrap();
+    assert_eq!(registry.decode::<Point>(&unknown), Err(ErrorMessage(UNKNOWN_EXTENSION_TAG)));
+}
+
+#[test]
+fn registration_rejects_reserved_and_taken_tags() {
+    let mut registry = registry();
+
+    assert_eq!(registry.register(0x10, Cents), Err(ErrorMessage(EXTENSION_TAG_RESERVED)));
+    assert_eq!(registry.register(0x80, Cents), Err(ErrorMessage(EXTENSION_TAG_TAKEN)));
+    assert_eq!(registry.register(0x82, Cents), Err(ErrorMessage(EXTENSION_TARGET_TAKEN)));
+    assert_eq!(registry.tags().collect::<Vec<_>>(), vec![0x80, 0x81]);
+    assert_eq!(registry.name(0x80), Some("geo-point"));
+    assert_eq!(registry.name(0x82), None);
+}
+
+#[test]
+fn validate_checks_nested_extension_values() {
+    let registry = registry();
+    let good = registry.encode(&Point { lat: 1.0, lon: 2.0 }).unwrap();
+    let short = Value::from_extension(0x80, &[1, 2]).unwrap();
+    let unknown = Value::from_extension(0xEE, &[]).unwrap();
+
+    assert_eq!(registry.validate(&Value::try_from(vec![good.clone(), Value::from(3u8)]).unwrap()), Ok(()));
+    assert_eq!(registry.validate(&Value::try_from(vec![good.clone(), short]).unwrap()), Err(ErrorMessage(BAD_POINT)));
+    assert_eq!(registry.validate(&Value::try_from(vec![good, unknown]).unwrap()), Err(ErrorMessage(UNKNOWN_EXTENSION_TAG)));
+}
+
+#[cfg(feature = "ffi")]
+#[test]
+fn ffi_builds_and_reads_extension_values() {
+    use yad_core::ffi::{byte_buffer_free, value::{extension_data_from_value, extension_tag_from_value, value_free, value_from_extension}};
+
+    let data = [1u8, 2, 3];
+    let value = unsafe { value_from_extension(0x80, data.as_ptr(), data.len()) };
+    assert!(!value.is_null());
+
+    let mut tag = 0;
+    assert!(unsafe { extension_tag_from_value(value, &mut tag) });
+    assert_eq!(tag, 0x80);
+
+    let buffer = unsafe { extension_data_from_value(value) };
+    assert_eq!(unsafe { std::slice::from_raw_parts(buffer.ptr, buffer.len) }, data);
+    byte_buffer_free(buffer);
+    value_free(value);
+
+    let number = Box::into_raw(Box::new(Value::from(1u8)));
+    assert!(!unsafe { extension_tag_from_value(number, &mut tag) });
+    value_free(number);
+}
@@ -0,0 +1,72 @@
+//! Tests for the C ABI version.
+#![cfg(feature = "ffi")]
+
+use std::fs;
+use std::path::Path;
+use yad_core::ffi::{yad_abi_version, YAD_ABI_VERSION};
+
+#[test]
+fn header_and_library_agree_on_the_abi_version() {
+    let header = include_str!("../include/yad.h");
+
+    assert_eq!(yad_abi_version(), YAD_ABI_VERSION);
+    assert!(header.contains(&format!("#define YAD_ABI_VERSION {YAD_ABI_VERSION}\n")));
+}
+
+/// Collects the names of the `extern "C"` functions under `dir`, each with
+/// the symbol it is exported as under the `prefixed-symbols` feature.
+fn exported_functions(dir: &Path, names: &mut Vec<(String, String)>) {
+    for entry in fs::read_dir(dir).unwrap() {
+        let path = entry.unwrap().path();
+        if path.is_dir() {
+            exported_functions(&path, names);
+            continue;
+        }
+
+        let mut symbol = None;
+        for line in fs::read_to_string(&path).unwrap().lines() {
+            if let Some((_, rest)) = line.split_once("export_name = \"") {
+                symbol = rest.split('"').next().map(str::to_string);
+            }
+            if let Some((_, rest)) = line.split_once("extern \"C\" fn ") && line.trim_start().starts_with("pub ") {
+                let name = rest.split('(').next().unwrap().to_string();
+                names.push((name.clone(), symbol.take().unwrap_or(name)));
+            }
+        }
+    }
+}
+
+fn all_exported_functions() -> Vec<(String, String)> {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+
+    let mut names = vec![];
+    exported_functions(&root.join("src"), &mut names);
+    exported_functions(&root.join("../core/src/ffi"), &mut names);
+    assert!(names.len() > 100);
+    names
+}
+
+#[test]
+fn header_declares_every_exported_function() {
+    let header = include_str!("../include/yad.h");
+
+    let declared = |name: &String| [" ", "*"].iter().any(|before| header.contains(&format!("{}{}(", before, name)));
+    let missing: Vec<String> = all_exported_functions().into_iter().map(|(name, _)| name).filter(|name| !declared(name)).collect();
+    assert!(missing.is_empty(), "regenerate include/yad.h with cbindgen, it lacks {:?}", missing);
+}
+
+#[test]
+fn prefixed_header_maps_every_renamed_function() {
+    let header = include_str!("../include/yad_prefixed.h");
+    let defines: Vec<Vec<&str>> = header
+        .lines()
+        .filter(|line| line.starts_with("#define "))
+        .map(|line| line.split_whitespace().skip(1).collect())
+        .collect();
+
+    for (name, symbol) in all_exported_functions() {
+        let mapped = defines.iter().any(|define| *define == [name.as_str(), symbol.as_str()]);
+        assert_eq!(mapped, name != symbol, "include/yad_prefixed.h must map {} to {} only when they differ", name, symbol);
+        assert!(symbol.starts_with("yad_"), "{} is exported as {}", name, symbol);
+    }
+}
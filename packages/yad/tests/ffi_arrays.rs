@@ -0,0 +1,36 @@
+//! Tests for building arrays from primitive buffers over FFI.
+#![cfg(feature = "ffi")]
+
+use serde_yad::Value;
+use yad_core::ffi::error::{yad_clear_last_error, yad_last_error, YadErrorCode};
+use yad_core::ffi::value::{value_free, value_from_bool_array, value_from_double_array, value_from_int16_array, value_from_uint8_array};
+
+fn take(value: *mut Value) -> Value {
+    assert!(!value.is_null());
+    let copy = unsafe { (*value).clone() };
+    value_free(value);
+    copy
+}
+
+#[test]
+fn buffers_become_the_same_arrays_as_vec_conversions() {
+    let bytes = [1u8, 2, 255];
+    let doubles = [0.5f64, -1.25, f64::MAX];
+    let shorts = [-300i16, 0, 300];
+    let flags = [true, false];
+
+    assert_eq!(take(unsafe { value_from_uint8_array(bytes.as_ptr(), bytes.len()) }), Value::try_from(bytes.to_vec()).unwrap());
+    assert_eq!(take(unsafe { value_from_double_array(doubles.as_ptr(), doubles.len()) }), Value::try_from(doubles.to_vec()).unwrap());
+    assert_eq!(take(unsafe { value_from_int16_array(shorts.as_ptr(), shorts.len()) }), Value::try_from(shorts.to_vec()).unwrap());
+    assert_eq!(take(unsafe { value_from_bool_array(flags.as_ptr(), flags.len()) }), Value::try_from(flags.to_vec()).unwrap());
+}
+
+#[test]
+fn null_and_empty_buffers_are_rejected() {
+    yad_clear_last_error();
+    assert!(unsafe { value_from_uint8_array(std::ptr::null(), 4) }.is_null());
+    assert_eq!(yad_last_error().code, YadErrorCode::NullPointer);
+
+    assert!(unsafe { value_from_uint8_array(std::ptr::null(), 0) }.is_null());
+    assert_eq!(yad_last_error().code, YadErrorCode::Encode);
+}
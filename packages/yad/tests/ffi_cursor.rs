@@ -0,0 +1,138 @@
+//! Tests for the FFI cursors and indexed access.
+#![cfg(feature = "ffi")]
+
+mod common;
+
+use common::{document, person};
+use serde_yad::ffi::cursor::{
+    row_key_cursor_free, row_key_cursor_new, row_key_cursor_next, yad_row_cursor_free, yad_row_cursor_new,
+    yad_row_cursor_next, YadRowCursor,
+};
+use serde_yad::ffi::key::key_free;
+use serde_yad::ffi::row::{row_free, row_key_at};
+use serde_yad::ffi::yad_row_at;
+use serde_yad::row::Row;
+use std::ptr;
+use yad_core::ffi::error::{yad_clear_last_error, yad_last_error, YadErrorCode};
+
+fn next_row_name(cursor: *mut YadRowCursor) -> Option<String> {
+    let row = yad_row_cursor_next(cursor);
+    if row.is_null() {
+        return None;
+    }
+    let name = unsafe { (*row).name.clone() };
+    row_free(row);
+    Some(name)
+}
+
+#[test]
+fn row_cursor_visits_every_row_then_stays_exhausted() {
+    let yad = document();
+    let cursor = yad_row_cursor_new(&yad);
+
+    let names: Vec<String> = std::iter::from_fn(|| next_row_name(cursor)).collect();
+    assert_eq!(names, ["alice", "bob", "carol"]);
+
+    yad_clear_last_error();
+    assert!(yad_row_cursor_next(cursor).is_null());
+    assert_eq!(yad_last_error().code, YadErrorCode::Ok);
+    yad_row_cursor_free(cursor);
+}
+
+#[test]
+fn row_cursor_resumes_after_rows_change_between_calls() {
+    let yad = Box::into_raw(Box::new(document()));
+    let cursor = yad_row_cursor_new(yad);
+
+    assert_eq!(next_row_name(cursor).as_deref(), Some("alice"));
+    unsafe {
+        (*yad).remove_row("bob");
+        (*yad).rows.insert("aaron".to_string(), person("aaron", 22, 4));
+        (*yad).rows.insert("dave".to_string(), person("dave", 35, 5));
+    }
+
+    assert_eq!(next_row_name(cursor).as_deref(), Some("carol"));
+    assert_eq!(next_row_name(cursor).as_deref(), Some("dave"));
+    assert_eq!(next_row_name(cursor), None);
+    yad_row_cursor_free(cursor);
+    drop(unsafe { Box::from_raw(yad) });
+}
+
+#[test]
+fn key_cursor_visits_every_key_then_stays_exhausted() {
+    let row = person("alice", 30, 1);
+    let cursor = row_key_cursor_new(&row);
+
+    let mut names = Vec::new();
+    loop {
+        let key = row_key_cursor_next(cursor);
+        if key.is_null() {
+            break;
+        }
+        names.push(unsafe { (*key).name.clone() });
+        key_free(key);
+    }
+    assert_eq!(names, ["age", "id", "name"]);
+
+    yad_clear_last_error();
+    assert!(row_key_cursor_next(cursor).is_null());
+    assert_eq!(yad_last_error().code, YadErrorCode::Ok);
+    row_key_cursor_free(cursor);
+}
+
+#[test]
+fn cursors_reject_null_pointers() {
+    yad_clear_last_error();
+    assert!(yad_row_cursor_new(ptr::null()).is_null());
+    assert_eq!(yad_last_error().code, YadErrorCode::NullPointer);
+
+    yad_clear_last_error();
+    assert!(yad_row_cursor_next(ptr::null_mut()).is_null());
+    assert_eq!(yad_last_error().code, YadErrorCode::NullPointer);
+
+    yad_clear_last_error();
+    assert!(row_key_cursor_new(ptr::null()).is_null());
+    assert_eq!(yad_last_error().code, YadErrorCode::NullPointer);
+
+    yad_clear_last_error();
+    assert!(row_key_cursor_next(ptr::null_mut()).is_null());
+    assert_eq!(yad_last_error().code, YadErrorCode::NullPointer);
+
+    yad_row_cursor_free(ptr::null_mut());
+    row_key_cursor_free(ptr::null_mut());
+}
+
+#[test]
+fn indexed_access_returns_items_in_name_order() {
+    let yad = document();
+
+    let row = yad_row_at(&yad, 1);
+    assert_eq!(unsafe { &(*row).name }, "bob");
+
+    let key = row_key_at(row, 2);
+    assert_eq!(unsafe { &(*key).name }, "name");
+    key_free(key);
+    row_free(row);
+}
+
+#[test]
+fn indexed_access_out_of_range_is_an_out_of_bounds_error() {
+    let yad = document();
+    let row = Row::new("empty", vec![]);
+
+    yad_clear_last_error();
+    assert!(yad_row_at(&yad, 3).is_null());
+    assert_eq!(yad_last_error().code, YadErrorCode::OutOfBounds);
+
+    yad_clear_last_error();
+    assert!(row_key_at(&row, 0).is_null());
+    assert_eq!(yad_last_error().code, YadErrorCode::OutOfBounds);
+
+    yad_clear_last_error();
+    assert!(yad_row_at(ptr::null(), 0).is_null());
+    assert_eq!(yad_last_error().code, YadErrorCode::NullPointer);
+
+    yad_clear_last_error();
+    assert!(row_key_at(ptr::null(), 0).is_null());
+    assert_eq!(yad_last_error().code, YadErrorCode::NullPointer);
+}
@@ -0,0 +1,48 @@
+//! Tests for the FFI name getters.
+#![cfg(feature = "ffi")]
+
+use serde_yad::ffi::key::{key_free, key_get_name, key_get_name_cstr, key_get_name_len};
+use serde_yad::ffi::row::{row_free, row_get_name_cstr, row_get_name_len};
+use serde_yad::ffi::yad_string_free;
+use serde_yad::key::Key;
+use serde_yad::row::Row;
+use std::ffi::CStr;
+use yad_core::ffi::error::{yad_clear_last_error, yad_last_error, YadErrorCode};
+
+#[test]
+fn key_names_are_returned_with_their_length_or_terminated() {
+    let key = Box::into_raw(Box::new(Key::new("née", 1u8)));
+
+    let len = key_get_name_len(key);
+    assert_eq!(len, "née".len());
+    let bytes = unsafe { std::slice::from_raw_parts(key_get_name(key) as *const u8, len) };
+    assert_eq!(bytes, "née".as_bytes());
+
+    let name = key_get_name_cstr(key);
+    assert_eq!(unsafe { CStr::from_ptr(name) }.to_str(), Ok("née"));
+    yad_string_free(name);
+    key_free(key);
+}
+
+#[test]
+fn row_names_are_returned_as_owned_c_strings() {
+    let row = Box::into_raw(Box::new(Row::new("johan", vec![])));
+
+    assert_eq!(row_get_name_len(row), 5);
+    let name = row_get_name_cstr(row);
+    assert_eq!(unsafe { CStr::from_ptr(name) }.to_str(), Ok("johan"));
+    yad_string_free(name);
+    row_free(row);
+}
+
+#[test]
+fn names_with_a_null_byte_are_a_conversion_error() {
+    let row = Box::into_raw(Box::new(Row::new("jo\0han", vec![])));
+
+    yad_clear_last_error();
+    assert!(row_get_name_cstr(row).is_null());
+    assert_eq!(yad_last_error().code, YadErrorCode::Conversion);
+    assert_eq!(row_get_name_len(row), 6);
+    row_free(row);
+    yad_string_free(std::ptr::null_mut());
+}
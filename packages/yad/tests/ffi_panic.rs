@@ -0,0 +1,49 @@
+//! Tests for panics caught at the FFI boundary.
+#![cfg(feature = "ffi")]
+
+use yad_core::ffi::error::{catch_panic, yad_clear_last_error, yad_last_error, YadErrorCode};
+use yad_core::ffi::ByteBuffer;
+use std::ffi::CStr;
+
+fn last_message() -> String {
+    let error = yad_last_error();
+    assert_eq!(error.code, YadErrorCode::Panic);
+    unsafe { CStr::from_ptr(error.message) }.to_str().unwrap().to_string()
+}
+
+#[test]
+fn panics_become_failure_values_and_errors() {
+    yad_clear_last_error();
+    assert!(catch_panic("ptr", || -> *mut u8 { panic!("boom") }).is_null());
+    assert_eq!(last_message(), "ptr: panicked: boom");
+
+    assert!(!catch_panic("flag", || -> bool { panic!("{} items", 3) }));
+    assert_eq!(last_message(), "flag: panicked: 3 items");
+
+    assert_eq!(catch_panic("count", || -> usize { panic!() }), 0);
+    assert!(catch_panic("buffer", || -> ByteBuffer { panic!() }).ptr.is_null());
+    catch_panic::<()>("unit", || panic!());
+    assert!(last_message().starts_with("unit: panicked"));
+}
+
+#[test]
+fn bodies_that_return_are_untouched() {
+    yad_clear_last_error();
+    assert_eq!(catch_panic("count", || 7usize), 7);
+    assert_eq!(yad_last_error().code, YadErrorCode::Ok);
+}
+
+#[test]
+#[cfg(debug_assertions)]
+fn exported_functions_do_not_unwind() {
+    use yad_core::ffi::{c_array_get, CArray};
+    use yad_core::Value;
+
+    // A corrupted array fails the debug assertions of `c_array_get`.
+    let mut values = vec![Value::from(1u8)];
+    let mut array = CArray { ptr: values.as_mut_ptr(), len: 2, cap: 1 };
+
+    yad_clear_last_error();
+    assert!(c_array_get(&mut array, 0).is_null());
+    assert!(last_message().starts_with("c_array_get: panicked"));
+}
@@ -0,0 +1,95 @@
+//! Tests for choosing float widths with `Value::from_float_auto` and widening
+//! them back with `Value::as_f64_lossy`.
+
+use float16::f16;
+use float8::F8E4M3;
+use serde_yad::Value;
+use yad_core::constants::error::{ErrorMessage, NOT_A_FLOAT};
+use yad_core::constants::length::ByteLength;
+
+fn width(value: f64, tolerance: f64) -> ByteLength {
+    Value::from_float_auto(value, tolerance).length
+}
+
+#[test]
+fn exact_values_take_the_narrowest_width() {
+    assert_eq!(width(0.5, 0.0), ByteLength::One);
+    assert_eq!(width(448.0, 0.0), ByteLength::One);
+    assert_eq!(width(-0.0, 0.0), ByteLength::One);
+    assert_eq!(width(1000.5, 0.0), ByteLength::Two);
+    assert_eq!(width(65504.0, 0.0), ByteLength::Two);
+    assert_eq!(width(1e6, 0.0), ByteLength::Four);
+    assert_eq!(width(0.1, 0.0), ByteLength::Eight);
+}
+
+#[test]
+fn each_width_is_chosen_up_to_its_error() {
+    let value = 21.37;
+    let f8_error = (F8E4M3::from_f64(value).to_f64() - value).abs();
+    let f16_error = (f16::from_f64(value).to_f64() - value).abs();
+    let f32_error = ((value as f32) as f64 - value).abs();
+    assert!(f32_error < f16_error && f16_error < f8_error);
+
+    assert_eq!(width(value, f8_error), ByteLength::One);
+    assert_eq!(width(value, f8_error * 0.99), ByteLength::Two);
+    assert_eq!(width(value, f16_error), ByteLength::Two);
+    assert_eq!(width(value, f16_error * 0.99), ByteLength::Four);
+    assert_eq!(width(value, f32_error), ByteLength::Four);
+    assert_eq!(width(value, f32_error * 0.99), ByteLength::Eight);
+}
+
+#[test]
+fn negative_or_nan_tolerances_demand_exact_values() {
+    for tolerance in [0.0, -1.0, f64::NAN] {
+        assert_eq!(width(21.37, tolerance), ByteLength::Eight);
+        assert_eq!(width(0.5, tolerance), ByteLength::One);
+    }
+}
+
+#[test]
+fn values_above_the_f8_range_saturate_only_within_tolerance() {
+    // F8E4M3 tops out at 448 and has no infinity.
+    assert_eq!(F8E4M3::from_f64(500.0).to_f64(), 448.0);
+
+    assert_eq!(width(500.0, 0.0), ByteLength::Two);
+    assert_eq!(width(500.0, 51.0), ByteLength::Two);
+    assert_eq!(width(500.0, 52.0), ByteLength::One);
+    assert_eq!(width(-500.0, 0.0), ByteLength::Two);
+    assert_eq!(width(1e6, 1e3), ByteLength::Four);
+}
+
+#[test]
+fn nan_and_infinities_keep_a_width_that_holds_them() {
+    let nan = Value::from_float_auto(f64::NAN, 0.0);
+    assert_eq!(nan.length, ByteLength::One);
+    assert!(nan.as_f64_lossy().unwrap().is_nan());
+
+    for infinity in [f64::INFINITY, f64::NEG_INFINITY] {
+        for tolerance in [0.0, 1e300] {
+            let value = Value::from_float_auto(infinity, tolerance);
+            assert_eq!(value.length, ByteLength::Two, "{} within {}", infinity, tolerance);
+            assert_eq!(value.as_f64_lossy(), Ok(infinity));
+        }
+    }
+}
+
+#[test]
+fn widening_returns_the_stored_value() {
+    for (value, tolerance) in [(0.5, 0.0), (21.37, 1.0), (21.37, 0.01), (21.37, 1e-6), (21.37, 0.0), (-500.0, 0.0)] {
+        let stored = Value::from_float_auto(value, tolerance);
+        let widened = stored.as_f64_lossy().unwrap();
+
+        assert!((widened - value).abs() <= tolerance, "{} within {}: {}", value, tolerance, widened);
+        assert_eq!(Value::from_float_auto(widened, 0.0).length, stored.length, "{} within {}", value, tolerance);
+    }
+
+    assert_eq!(Value::from(f16::from_f32(1.5)).as_f64_lossy(), Ok(1.5));
+    assert_eq!(Value::from(1.25f32).as_f64_lossy(), Ok(1.25));
+    assert_eq!(Value::from(0.1f64).as_f64_lossy(), Ok(0.1));
+}
+
+#[test]
+fn only_floats_widen() {
+    assert_eq!(Value::from(1u8).as_f64_lossy(), Err(ErrorMessage(NOT_A_FLOAT)));
+    assert_eq!(Value::try_from("1.5").unwrap().as_f64_lossy(), Err(ErrorMessage(NOT_A_FLOAT)));
+}
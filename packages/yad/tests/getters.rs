@@ -0,0 +1,60 @@
+//! Tests for the typed getters of rows.
+
+use serde_yad::getters::KeyError;
+use serde_yad::key::Key;
+use serde_yad::row::Row;
+use serde_yad::error::KEY_NOT_FOUND;
+use serde_yad::Value;
+use yad_core::constants::error::{ErrorMessage, NOT_A_STRING, NOT_A_UINT8};
+
+fn row() -> Row {
+    Row::new("johan", vec![
+        Key::new("age", 17u8),
+        Key::new("score", 9_001u64),
+        Key::new("name", Value::try_from("Johan").unwrap()),
+        Key::new("ratio", 0.5f32),
+        Key::new("active", true),
+        Key::new("grade", 'B'),
+        Key::new("ids", Value::try_from(vec![1u8, 2]).unwrap()),
+    ])
+}
+
+#[test]
+fn getters_read_present_keys() {
+    let row = row();
+    assert_eq!(row.get_u8("age"), Ok(17));
+    assert_eq!(row.get_u64("score"), Ok(9_001));
+    assert_eq!(row.get_str("name"), Ok("Johan"));
+    assert_eq!(row.get_string("name"), Ok(String::from("Johan")));
+    assert_eq!(row.get_f32("ratio"), Ok(0.5));
+    assert_eq!(row.get_bool("active"), Ok(true));
+    assert_eq!(row.get_char("grade"), Ok('B'));
+    assert_eq!(row.get_array("ids"), Ok(vec![Value::from(1u8), Value::from(2u8)]));
+    assert_eq!(row.get_as::<u8>("age"), Ok(17));
+}
+
+#[test]
+fn optional_getters_turn_missing_keys_into_none() {
+    let row = row();
+    assert_eq!(row.get_opt_u64("score"), Ok(Some(9_001)));
+    assert_eq!(row.get_opt_u64("missing"), Ok(None));
+    assert_eq!(row.get_opt_str("missing"), Ok(None));
+    assert!(row.get_opt_u8("name").is_err());
+}
+
+#[test]
+fn errors_name_the_key_and_row() {
+    let row = row();
+    let missing = KeyError { error: ErrorMessage(KEY_NOT_FOUND), row: "johan".into(), key: "email".into() };
+    assert_eq!(row.get_str("email"), Err(missing.clone()));
+    assert_eq!(row.get_u8("email"), Err(missing));
+
+    let error = row.get_u8("name").unwrap_err();
+    assert_eq!((error.error, error.key.as_str(), error.row.as_str()), (ErrorMessage(NOT_A_UINT8), "name", "johan"));
+    assert_eq!(row.get_str("age").unwrap_err().error, ErrorMessage(NOT_A_STRING));
+    assert_eq!(
+        row.get_str("email").unwrap_err().to_string(),
+        format!("{} (key `email` in row `johan`)", KEY_NOT_FOUND)
+    );
+    assert_eq!(ErrorMessage::from(row.get_u8("email").unwrap_err()), ErrorMessage(KEY_NOT_FOUND));
+}
@@ -0,0 +1,115 @@
+//! Golden tests for [`YAD::serialize_canonical`].
+//!
+//! The expected bytes below are part of the format's stability guarantee: if
+//! one of these tests fails, the canonical encoding changed.
+
+use serde_yad::key::Key;
+use serde_yad::row::Row;
+use serde_yad::{CURRENT_VERSION, Value, YAD};
+use yad_core::constants::length::ByteLength;
+use yad_core::constants::types::Type;
+
+fn numbers() -> Row {
+    Row::new("numbers", vec![
+        Key::new("u8", Value::from(200u8)),
+        Key::new("u16", Value::from(0x1234u16)),
+        Key::new("u32", Value::from(0xDEADBEEFu32)),
+        Key::new("u64", Value::from(u64::MAX)),
+        Key::new("i8", Value::from(-2i8)),
+        Key::new("i16", Value::from(-300i16)),
+        Key::new("i32", Value::from(-70000i32)),
+        Key::new("i64", Value::from(i64::MIN)),
+        Key::new("f32", Value::from(1.5f32)),
+        Key::new("f64", Value::from(-0.25f64)),
+    ])
+}
+
+fn misc() -> Row {
+    Row::new("misc", vec![
+        Key::new("yes", Value::from(true)),
+        Key::new("no", Value::from(false)),
+        Key::new("text", Value::try_from("héllo").unwrap()),
+        Key::new("list", Value::try_from(vec![
+            Value::from(1u8),
+            Value::try_from("a").unwrap(),
+            Value::try_from(vec![Value::from(false)]).unwrap(),
+        ]).unwrap()),
+    ])
+}
+
+const GOLDEN: &[u8] = &[
+    // magic "YAD\0"
+    0x59, 0x41, 0x44, 0x00,
+    // version 1.0.0-0
+    0xF0, 0x01, 0x00, 0x00, 0x00,
+    // row "misc"
+    0xF1, 0x61, 0x04, 0x6D, 0x69, 0x73, 0x63,
+    0xF3, 0x71, 0x04, 0x6C, 0x69, 0x73, 0x74, 0x51, 0x03, 0x11, 0x01, 0x41, 0x01, 0x61, 0x51, 0x01, 0x80, 0xF4,
+    0xF3, 0x71, 0x02, 0x6E, 0x6F, 0x80, 0xF4,
+    0xF3, 0x71, 0x04, 0x74, 0x65, 0x78, 0x74, 0x41, 0x06, 0x68, 0xC3, 0xA9, 0x6C, 0x6C, 0x6F, 0xF4,
+    0xF3, 0x71, 0x03, 0x79, 0x65, 0x73, 0x81, 0xF4,
+    0xF2,
+    // row "numbers"
+    0xF1, 0x61, 0x07, 0x6E, 0x75, 0x6D, 0x62, 0x65, 0x72, 0x73,
+    0xF3, 0x71, 0x03, 0x66, 0x33, 0x32, 0x33, 0x3F, 0xC0, 0x00, 0x00, 0xF4,
+    0xF3, 0x71, 0x03, 0x66, 0x36, 0x34, 0x34, 0xBF, 0xD0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xF4,
+    0xF3, 0x71, 0x03, 0x69, 0x31, 0x36, 0x22, 0xFE, 0xD4, 0xF4,
+    0xF3, 0x71, 0x03, 0x69, 0x33, 0x32, 0x23, 0xFF, 0xFE, 0xEE, 0x90, 0xF4,
+    0xF3, 0x71, 0x03, 0x69, 0x36, 0x34, 0x24, 0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xF4,
+    0xF3, 0x71, 0x02, 0x69, 0x38, 0x21, 0xFE, 0xF4,
+    0xF3, 0x71, 0x03, 0x75, 0x31, 0x36, 0x12, 0x12, 0x34, 0xF4,
+    0xF3, 0x71, 0x03, 0x75, 0x33, 0x32, 0x13, 0xDE, 0xAD, 0xBE, 0xEF, 0xF4,
+    0xF3, 0x71, 0x03, 0x75, 0x36, 0x34, 0x14, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xF4,
+    0xF3, 0x71, 0x02, 0x75, 0x38, 0x11, 0xC8, 0xF4,
+    0xF2,
+];
+
+#[test]
+fn canonical_output_matches_golden_bytes() {
+    let yad = YAD::new(CURRENT_VERSION, vec![numbers(), misc()]);
+
+    assert_eq!(yad.serialize_canonical().unwrap(), GOLDEN);
+}
+
+#[test]
+fn canonical_output_ignores_insertion_order() {
+    let mut yad = YAD::new_empty(CURRENT_VERSION);
+    yad.insert_row("misc", vec![]);
+    yad.insert_row("numbers", vec![]);
+
+    for row in [misc(), numbers()] {
+        for key in row.keys.into_values().rev() {
+            yad.rows.get_mut(&row.name).unwrap().insert_key(&key.name, key.value).unwrap();
+        }
+    }
+
+    assert_eq!(yad.serialize_canonical().unwrap(), GOLDEN);
+}
+
+#[test]
+fn canonical_output_round_trips() {
+    let yad = YAD::deserialize(GOLDEN.to_vec()).unwrap();
+
+    assert_eq!(yad.serialize_canonical().unwrap(), GOLDEN);
+}
+
+#[test]
+fn canonical_output_uses_smallest_length_descriptor() {
+    let wide = Value { r#type: Type::String, length: ByteLength::Two, bytes: vec![0x42, 0x00, 0x01, 0x61].into() };
+    let yad = YAD::new(CURRENT_VERSION, vec![Row::new("r", vec![Key::new("k", wide)])]);
+
+    assert_eq!(yad.serialize_canonical().unwrap(), [
+        0x59, 0x41, 0x44, 0x00,
+        0xF0, 0x01, 0x00, 0x00, 0x00,
+        0xF1, 0x61, 0x01, 0x72,
+        0xF3, 0x71, 0x01, 0x6B, 0x41, 0x01, 0x61, 0xF4,
+        0xF2,
+    ]);
+}
+
+#[test]
+fn files_without_magic_are_still_read() {
+    let yad = YAD::deserialize(GOLDEN[4..].to_vec()).unwrap();
+
+    assert_eq!(yad.serialize_canonical().unwrap(), GOLDEN);
+}
@@ -0,0 +1,59 @@
+//! Tests for hashing values and keys.
+
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashSet;
+use std::hash::{Hash, Hasher};
+use float16::f16;
+use serde_yad::index::IndexKey;
+use serde_yad::key::Key;
+use serde_yad::Value;
+use yad_core::compare::SemanticValue;
+
+fn hash<T: Hash>(value: &T) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    value.hash(&mut hasher);
+    hasher.finish()
+}
+
+#[test]
+fn semantically_equal_values_hash_the_same() {
+    let fives = [Value::from(5u8), Value::from(5u64), Value::from(5i16), Value::from(5.0f32), Value::from(f16::from_f32(5.0))];
+    for five in &fives {
+        assert!(five.semantic_eq(&fives[0]));
+        assert_eq!(hash(five), hash(&fives[0]));
+    }
+
+    assert_eq!(hash(&Value::from(0.0f64)), hash(&Value::from(-0.0f32)));
+    assert_eq!(
+        hash(&Value::try_from(vec![1u8, 2]).unwrap()),
+        hash(&Value::try_from(vec![Value::from(1u32), Value::from(2i64)]).unwrap()),
+    );
+    assert_ne!(hash(&Value::from(5u8)), hash(&Value::from(6u8)));
+    assert_ne!(hash(&Value::from(true)), hash(&Value::from(1u8)));
+}
+
+#[test]
+fn hash_sets_deduplicate_by_bytes_or_by_meaning() {
+    let values = [Value::from(5u8), Value::from(5u8), Value::from(5u16), Value::try_from("5").unwrap()];
+
+    let by_bytes: HashSet<Value> = values.iter().cloned().collect();
+    assert_eq!(by_bytes.len(), 3);
+
+    let by_meaning: HashSet<IndexKey> = values.iter().cloned().map(IndexKey).collect();
+    assert_eq!(by_meaning.len(), 2);
+}
+
+#[test]
+fn semantic_values_merge_widths_in_a_hash_set() {
+    let values = [Value::from(5u8), Value::from(5u16), Value::from(5i32), Value::from(5.0f64), Value::from(6u8)];
+
+    let set: HashSet<SemanticValue> = values.into_iter().map(SemanticValue).collect();
+    assert_eq!(set.len(), 2);
+    assert!(set.contains(&SemanticValue(Value::from(5u64))));
+}
+
+#[test]
+fn keys_can_be_set_members() {
+    let keys: HashSet<Key> = [Key::new("a", 1u8), Key::new("a", 1u8), Key::new("b", 1u8)].into_iter().collect();
+    assert_eq!(keys.len(), 2);
+}
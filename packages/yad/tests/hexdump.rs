@@ -0,0 +1,47 @@
+//! Golden tests for [`yad_core::hexdump`].
+
+use serde_yad::Value;
+use yad_core::hexdump;
+
+#[test]
+fn nested_array_is_annotated_per_segment() {
+    let value = Value::try_from(vec![Value::from(200u8), Value::try_from("hi").unwrap()]).unwrap();
+
+    let expected = "\
+00000000  51                                header: array, 1-byte length
+00000001  02                                length: 2 item(s)
+00000002    11                              header: uint8
+00000003    C8                              payload: 200
+00000004    41                              header: string, 1-byte length
+00000005    02                              length: 2 byte(s)
+00000006    68 69                           payload: \"hi\"
+";
+
+    assert_eq!(hexdump(&value), expected);
+}
+
+#[test]
+fn long_payloads_wrap_onto_continuation_lines() {
+    let value = Value::try_from("abcdefghij").unwrap();
+
+    let expected = "\
+00000000  41                                header: string, 1-byte length
+00000001  0A                                length: 10 byte(s)
+00000002  61 62 63 64 65 66 67 68           payload: \"abcdefghij\"
+0000000A  69 6A                             ...
+";
+
+    assert_eq!(hexdump(&value), expected);
+}
+
+#[test]
+fn malformed_bytes_are_dumped_as_invalid() {
+    let mut value = Value::from(0x1234u16);
+    value.bytes.truncate(2);
+
+    let expected = "\
+00000000  12 12                             invalid: truncated number
+";
+
+    assert_eq!(hexdump(&value), expected);
+}
@@ -0,0 +1,106 @@
+//! Tests for converting JSON values with [`Value::from_json_value`].
+#![cfg(feature = "json")]
+
+use serde_json::json;
+use serde_yad::Value;
+use yad_core::constants::error::{ErrorMessage, JSON_NULL_NOT_SUPPORTED, JSON_NUMBER_NOT_REPRESENTABLE, JSON_OBJECT_NOT_SUPPORTED};
+use yad_core::json::{FloatWidth, IntegerWidth, NumberPolicy};
+
+fn convert(json: serde_json::Value, policy: NumberPolicy) -> Result<Value, ErrorMessage> {
+    Value::from_json_value(&json, policy)
+}
+
+fn signed() -> NumberPolicy {
+    NumberPolicy { prefer_unsigned: false, ..NumberPolicy::default() }
+}
+
+#[test]
+fn integers_take_the_smallest_width_by_default() {
+    let policy = NumberPolicy::default();
+
+    assert_eq!(convert(json!(0), policy), Ok(Value::from(0u8)));
+    assert_eq!(convert(json!(255), policy), Ok(Value::from(255u8)));
+    assert_eq!(convert(json!(256), policy), Ok(Value::from(256u16)));
+    assert_eq!(convert(json!(65_536), policy), Ok(Value::from(65_536u32)));
+    assert_eq!(convert(json!(4_294_967_296u64), policy), Ok(Value::from(4_294_967_296u64)));
+    assert_eq!(convert(json!(u64::MAX), policy), Ok(Value::from(u64::MAX)));
+
+    assert_eq!(convert(json!(-128), policy), Ok(Value::from(-128i8)));
+    assert_eq!(convert(json!(-129), policy), Ok(Value::from(-129i16)));
+    assert_eq!(convert(json!(-32_769), policy), Ok(Value::from(-32_769i32)));
+    assert_eq!(convert(json!(-2_147_483_649i64), policy), Ok(Value::from(-2_147_483_649i64)));
+    assert_eq!(convert(json!(i64::MIN), policy), Ok(Value::from(i64::MIN)));
+}
+
+#[test]
+fn fixed_widths_are_always_64_bits() {
+    let policy = NumberPolicy { integer_width: IntegerWidth::Fixed64, ..NumberPolicy::default() };
+
+    assert_eq!(convert(json!(5), policy), Ok(Value::from(5u64)));
+    assert_eq!(convert(json!(-5), policy), Ok(Value::from(-5i64)));
+    assert_eq!(convert(json!(5), NumberPolicy { prefer_unsigned: false, ..policy }), Ok(Value::from(5i64)));
+}
+
+#[test]
+fn signed_policies_stop_at_the_i64_boundary() {
+    assert_eq!(convert(json!(127), signed()), Ok(Value::from(127i8)));
+    assert_eq!(convert(json!(128), signed()), Ok(Value::from(128i16)));
+    assert_eq!(convert(json!(i64::MAX as u64), signed()), Ok(Value::from(i64::MAX)));
+    assert_eq!(convert(json!(i64::MAX as u64 + 1), signed()), Err(ErrorMessage(JSON_NUMBER_NOT_REPRESENTABLE)));
+
+    // Unsigned policies take the whole u64 range instead.
+    assert_eq!(convert(json!(i64::MAX as u64 + 1), NumberPolicy::default()), Ok(Value::from(i64::MAX as u64 + 1)));
+}
+
+#[test]
+fn floats_narrow_only_when_exact() {
+    let policy = NumberPolicy { float_width: FloatWidth::Smallest, ..NumberPolicy::default() };
+
+    assert_eq!(convert(json!(1.5), NumberPolicy::default()), Ok(Value::from(1.5f64)));
+    assert_eq!(convert(json!(1.5), policy), Ok(Value::from(1.5f32)));
+    assert_eq!(convert(json!(0.1), policy), Ok(Value::from(0.1f64)));
+    assert_eq!(convert(json!(3.0), policy), Ok(Value::from(3.0f32)));
+}
+
+#[test]
+fn integral_floats_become_integers_when_asked() {
+    let policy = NumberPolicy { integral_floats_as_integers: true, ..NumberPolicy::default() };
+
+    assert_eq!(convert(json!(3.0), NumberPolicy::default()), Ok(Value::from(3.0f64)));
+    assert_eq!(convert(json!(3.0), policy), Ok(Value::from(3u8)));
+    assert_eq!(convert(json!(-3.0), policy), Ok(Value::from(-3i8)));
+    assert_eq!(convert(json!(3.0), NumberPolicy { prefer_unsigned: false, ..policy }), Ok(Value::from(3i8)));
+    assert_eq!(convert(json!(3.5), policy), Ok(Value::from(3.5f64)));
+
+    // Out of every integer range, the float is kept.
+    assert_eq!(convert(json!(1e20), policy), Ok(Value::from(1e20f64)));
+    assert_eq!(convert(json!(-1e20), policy), Ok(Value::from(-1e20f64)));
+
+    // 2^64 and 2^63 are one past the largest integers; they do not saturate.
+    let signed = NumberPolicy { prefer_unsigned: false, ..policy };
+    assert_eq!(convert(json!(18446744073709551616.0), policy), Ok(Value::from(18446744073709551616.0f64)));
+    assert_eq!(convert(json!(9223372036854775808.0), policy), Ok(Value::from(9223372036854775808u64)));
+    assert_eq!(convert(json!(9223372036854775808.0), signed), Ok(Value::from(9223372036854775808.0f64)));
+    assert_eq!(convert(json!(-9223372036854775808.0), signed), Ok(Value::from(i64::MIN)));
+}
+
+#[test]
+fn arrays_apply_the_policy_to_every_element() {
+    let value = convert(json!([1, 300, -1, 1.5, "x", true]), NumberPolicy::default()).unwrap();
+
+    assert_eq!(value, Value::try_from(vec![
+        Value::from(1u8),
+        Value::from(300u16),
+        Value::from(-1i8),
+        Value::from(1.5f64),
+        Value::try_from("x").unwrap(),
+        Value::from(true),
+    ]).unwrap());
+}
+
+#[test]
+fn null_and_objects_have_no_value() {
+    assert_eq!(convert(json!(null), NumberPolicy::default()), Err(ErrorMessage(JSON_NULL_NOT_SUPPORTED)));
+    assert_eq!(convert(json!({ "a": 1 }), NumberPolicy::default()), Err(ErrorMessage(JSON_OBJECT_NOT_SUPPORTED)));
+    assert_eq!(convert(json!([1, null]), NumberPolicy::default()), Err(ErrorMessage(JSON_NULL_NOT_SUPPORTED)));
+}
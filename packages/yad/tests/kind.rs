@@ -0,0 +1,42 @@
+//! Tests for introspecting the kind of values.
+
+use float16::f16;
+use serde_yad::Value;
+use yad_core::kind::ValueKind;
+
+#[test]
+fn kinds_merge_widths_and_boolean_states() {
+    assert_eq!(Value::from(1u8).kind(), ValueKind::Uint);
+    assert_eq!(Value::from(1u64).kind(), ValueKind::Uint);
+    assert_eq!(Value::from(-1i16).kind(), ValueKind::Int);
+    assert_eq!(Value::from(f16::from_f32(1.0)).kind(), ValueKind::Float);
+    assert_eq!(Value::try_from("a").unwrap().kind(), ValueKind::String);
+    assert_eq!(Value::try_from(vec![1u8]).unwrap().kind(), ValueKind::Array);
+    assert_eq!(Value::from(true).kind(), ValueKind::Bool);
+    assert_eq!(Value::from(false).kind(), ValueKind::Bool);
+}
+
+#[test]
+fn predicates_match_the_kind() {
+    let number = Value::from(2.5f64);
+    assert!(number.is_float() && number.is_number());
+    assert!(!number.is_uint() && !number.is_int() && !number.is_container());
+
+    let array = Value::try_from(vec![1u8, 2, 3]).unwrap();
+    assert!(array.is_container() && !array.is_number());
+    assert!(Value::from(true).is_bool());
+    assert!(Value::try_from("a").unwrap().is_string());
+}
+
+#[test]
+fn widths_and_counts() {
+    assert_eq!(Value::from(1u8).numeric_bit_width(), Some(8));
+    assert_eq!(Value::from(-1i32).numeric_bit_width(), Some(32));
+    assert_eq!(Value::from(f16::from_f32(1.0)).numeric_bit_width(), Some(16));
+    assert_eq!(Value::from(1.0f64).numeric_bit_width(), Some(64));
+    assert_eq!(Value::try_from("a").unwrap().numeric_bit_width(), None);
+    assert_eq!(Value::from(true).numeric_bit_width(), None);
+
+    assert_eq!(Value::try_from(vec![1u8, 2, 3]).unwrap().element_count(), Some(3));
+    assert_eq!(Value::from(3u8).element_count(), None);
+}
@@ -0,0 +1,72 @@
+//! Tests for the allocation limit applied to untrusted input.
+
+use serde_yad::key::Key;
+use serde_yad::options::{DecodeOptions, SerializeOptions};
+use serde_yad::row::Row;
+use serde_yad::{CURRENT_VERSION, Value, YAD};
+use yad_core::constants::error::{ErrorMessage, ALLOCATION_LIMIT_EXCEEDED, NOT_ENOUGH_BYTES};
+
+fn long_series() -> YAD {
+    let series = Value::from_sorted_u64s(&(0..500).collect::<Vec<u64>>()).unwrap();
+    YAD::new(CURRENT_VERSION, vec![Row::new("sensor", vec![Key::new("at", series)])])
+}
+
+#[test]
+fn value_limit_is_checked_before_decoding() {
+    let bytes = Value::try_from("a string of some length").unwrap().bytes.to_vec();
+
+    assert!(Value::decode_limited(&bytes, bytes.len()).is_ok());
+    assert_eq!(Value::decode_limited(&bytes, bytes.len() - 1).err(), Some(ErrorMessage(ALLOCATION_LIMIT_EXCEEDED)));
+}
+
+#[test]
+fn huge_declared_counts_do_not_preallocate() {
+    // An array claiming u64::MAX elements followed by a single one.
+    let bytes = [0x54, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x11, 0x01];
+
+    assert_eq!(Value::decode(bytes.to_vec()).err(), Some(ErrorMessage(NOT_ENOUGH_BYTES)));
+}
+
+#[test]
+fn document_limit_covers_the_input_and_the_expanded_body() {
+    let plain = long_series().serialize().unwrap();
+    let delta = long_series().serialize_with_options(&SerializeOptions { delta_arrays: true, ..SerializeOptions::default() }).unwrap();
+    assert!(delta.len() < plain.len() / 2);
+
+    let decode = |bytes: &[u8], max| YAD::deserialize_with_options(bytes.to_vec(), &DecodeOptions::limited(max));
+
+    assert_eq!(decode(&plain, plain.len()).unwrap(), long_series());
+    assert_eq!(decode(&plain, plain.len() - 1).err(), Some(ErrorMessage(ALLOCATION_LIMIT_EXCEEDED)));
+    assert_eq!(decode(&delta, delta.len()).err(), Some(ErrorMessage(ALLOCATION_LIMIT_EXCEEDED)));
+}
+
+#[test]
+#[cfg(feature = "ffi")]
+fn ffi_entry_points_report_a_dedicated_error_code() {
+    use serde_yad::ffi::{yad_free, yad_from_buffer_limited};
+    use yad_core::ffi::error::{yad_clear_last_error, yad_last_error, YadErrorCode};
+    use yad_core::ffi::value::{value_free, value_from_buffer_limited};
+
+    let bytes = long_series().serialize().unwrap();
+
+    let yad = yad_from_buffer_limited(bytes.as_ptr(), bytes.len(), bytes.len());
+    assert!(!yad.is_null());
+    yad_free(yad);
+
+    yad_clear_last_error();
+    assert!(yad_from_buffer_limited(bytes.as_ptr(), bytes.len(), 16).is_null());
+    assert_eq!(yad_last_error().code, YadErrorCode::LimitExceeded);
+
+    let value = Value::from(7u32).bytes.to_vec();
+    let decoded = unsafe { value_from_buffer_limited(value.as_ptr(), value.len(), 5) };
+    assert!(!decoded.is_null());
+    value_free(decoded);
+
+    yad_clear_last_error();
+    assert!(unsafe { value_from_buffer_limited(value.as_ptr(), value.len(), 4) }.is_null());
+    assert_eq!(yad_last_error().code, YadErrorCode::LimitExceeded);
+
+    yad_clear_last_error();
+    assert!(yad_from_buffer_limited(value.as_ptr(), value.len(), 1024).is_null());
+    assert_eq!(yad_last_error().code, YadErrorCode::Decode);
+}
@@ -0,0 +1,202 @@
+//! Tests for append-only logs, their statistics, compaction and snapshots.
+
+use std::fs;
+use std::path::PathBuf;
+use std::thread;
+use serde_yad::error::{MALFORMED_LOG, SNAPSHOT_UNAVAILABLE};
+use serde_yad::key::Key;
+use serde_yad::log::{LogStats, YadLog};
+use serde_yad::row::Row;
+use serde_yad::store::RowStore;
+use serde_yad::{CURRENT_VERSION, YAD};
+use yad_core::constants::error::ErrorMessage;
+
+fn temp_path(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("serde_yad-log-{}-{}", name, std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    dir.join("data.yadlog")
+}
+
+fn person(name: &str, age: u8) -> Row {
+    Row::new(name, vec![Key::new("age", age)])
+}
+
+#[test]
+fn records_replay_on_open() {
+    let path = temp_path("replay");
+    let mut log = YadLog::create(&path).unwrap();
+    log.put(person("johan", 17)).unwrap();
+    log.put(person("maria", 30)).unwrap();
+    log.put(person("johan", 18)).unwrap();
+    assert_eq!(log.remove("maria").unwrap(), Some(person("maria", 30)));
+    assert_eq!(log.remove("maria").unwrap(), None);
+    log.sync().unwrap();
+    drop(log);
+
+    let log = YadLog::open(&path).unwrap();
+    assert_eq!(log.get("johan"), Some(&person("johan", 18)));
+    assert_eq!(log.get("maria"), None);
+    assert_eq!(log.to_yad(), YAD::new(CURRENT_VERSION, vec![person("johan", 18)]));
+    assert_eq!(log.stats().records, 4);
+}
+
+#[test]
+fn stats_count_dead_bytes() {
+    let path = temp_path("stats");
+    let mut log = YadLog::create(&path).unwrap();
+    assert_eq!(log.stats(), LogStats::default());
+    assert_eq!(log.stats().amplification(), 1.0);
+
+    let record = person("johan", 17).serialize().unwrap().len() as u64;
+    log.put(person("johan", 17)).unwrap();
+    log.put(person("johan", 18)).unwrap();
+    log.put(person("maria", 30)).unwrap();
+
+    let stats = log.stats();
+    assert_eq!(stats.records, 3);
+    assert_eq!(stats.live_rows, 2);
+    assert_eq!(stats.live_bytes, 2 * record);
+    assert_eq!(stats.dead_bytes, record);
+    assert_eq!(stats.amplification(), 1.5);
+    assert_eq!(YadLog::open(&path).unwrap().stats(), stats);
+
+    log.remove("johan").unwrap();
+    log.remove("maria").unwrap();
+    assert_eq!(log.stats().live_rows, 0);
+    assert_eq!(log.stats().amplification(), f64::INFINITY);
+}
+
+#[test]
+fn compact_keeps_only_live_rows() {
+    let path = temp_path("compact");
+    let mut log = YadLog::create(&path).unwrap();
+    for age in 0..50 {
+        log.put(person("johan", age)).unwrap();
+    }
+    log.put(person("maria", 30)).unwrap();
+    log.remove("maria").unwrap();
+
+    let before = fs::metadata(&path).unwrap().len();
+    let reclaimed = log.compact().unwrap();
+    assert_eq!(fs::metadata(&path).unwrap().len(), before - reclaimed);
+    assert_eq!(log.stats().dead_bytes, 0);
+    assert_eq!(log.stats().records, 1);
+
+    log.put(person("ana", 20)).unwrap();
+    let reopened = YadLog::open(&path).unwrap();
+    assert_eq!(reopened.rows(), log.rows());
+    assert_eq!(reopened.stats(), log.stats());
+
+    // A compacted log without tombstones is a plain document.
+    assert_eq!(YAD::deserialize(fs::read(&path).unwrap()).unwrap(), log.to_yad());
+}
+
+#[test]
+fn log_is_a_row_store() {
+    let mut log = YadLog::create(temp_path("store")).unwrap();
+    RowStore::put(&mut log, person("johan", 17)).unwrap();
+
+    assert_eq!(RowStore::get(&log, "johan").unwrap(), Some(person("johan", 17)));
+    assert_eq!(RowStore::iter(&log).count(), 1);
+    assert_eq!(RowStore::remove(&mut log, "johan").unwrap(), Some(person("johan", 17)));
+}
+
+#[test]
+fn open_drops_a_torn_last_record() {
+    let path = temp_path("torn");
+    let mut log = YadLog::create(&path).unwrap();
+    for age in 1..=5 {
+        log.put(person(&format!("row{age}"), age)).unwrap();
+    }
+    drop(log);
+
+    let bytes = fs::read(&path).unwrap();
+    fs::write(&path, &bytes[..bytes.len() - 1]).unwrap();
+
+    let mut log = YadLog::open(&path).unwrap();
+    assert_eq!(log.stats().records, 4);
+    assert_eq!(log.rows().keys().collect::<Vec<_>>(), ["row1", "row2", "row3", "row4"]);
+
+    log.put(person("row6", 6)).unwrap();
+    drop(log);
+
+    let log = YadLog::open(&path).unwrap();
+    assert_eq!(log.stats().records, 5);
+    assert_eq!(log.get("row6"), Some(&person("row6", 6)));
+    assert_eq!(log.get("row5"), None);
+}
+
+#[test]
+fn open_rejects_files_that_are_not_logs() {
+    let path = temp_path("not-a-log");
+    fs::write(&path, b"not a log").unwrap();
+    assert_eq!(YadLog::open(&path).err(), Some(ErrorMessage(MALFORMED_LOG)));
+}
+
+#[test]
+fn snapshots_read_past_states() {
+    let path = temp_path("snapshot");
+    let mut log = YadLog::create(&path).unwrap();
+    assert_eq!(log.seq(), 0);
+
+    log.put(person("johan", 17)).unwrap();
+    log.put(person("maria", 30)).unwrap();
+    log.put(person("johan", 18)).unwrap();
+    log.remove("maria").unwrap();
+    assert_eq!(log.seq(), 4);
+
+    assert!(log.snapshot_at(0).unwrap().rows().is_empty());
+    assert_eq!(log.snapshot_at(1).unwrap().to_yad(), YAD::new(CURRENT_VERSION, vec![person("johan", 17)]));
+    assert_eq!(log.snapshot_at(3).unwrap().rows().len(), 2);
+    assert_eq!(log.snapshot_at(3).unwrap().get("johan"), Some(&person("johan", 18)));
+    assert_eq!(log.snapshot_at(4).unwrap().to_yad(), log.to_yad());
+    assert_eq!(log.snapshot_at(5).err(), Some(ErrorMessage(SNAPSHOT_UNAVAILABLE)));
+
+    assert_eq!(YadLog::open(&path).unwrap().seq(), 4);
+}
+
+#[test]
+fn compaction_keeps_sequence_numbers() {
+    let mut log = YadLog::create(temp_path("snapshot-compact")).unwrap();
+    log.put(person("johan", 17)).unwrap();
+    log.put(person("johan", 18)).unwrap();
+    let before = log.reader().snapshot().unwrap();
+
+    log.compact().unwrap();
+    assert_eq!(log.seq(), 2);
+    assert_eq!(log.snapshot_at(1).err(), Some(ErrorMessage(SNAPSHOT_UNAVAILABLE)));
+    assert_eq!(log.snapshot_at(2).unwrap(), before);
+
+    log.put(person("maria", 30)).unwrap();
+    assert_eq!(log.snapshot_at(2).unwrap(), before);
+    assert_eq!(log.snapshot_at(3).unwrap().rows().len(), 2);
+}
+
+#[test]
+fn readers_see_consistent_snapshots_while_the_writer_appends() {
+    let mut log = YadLog::create(temp_path("snapshot-threads")).unwrap();
+    let reader = log.reader();
+
+    let readers: Vec<_> = (0..4).map(|_| {
+        let reader = reader.clone();
+        thread::spawn(move || {
+            for _ in 0..50 {
+                let snapshot = reader.snapshot().unwrap();
+                // Record 2i + 1 puts `row-i` and record 2i + 2 sets `counter` to i.
+                let seq = snapshot.seq();
+                let counter = snapshot.get("counter").map(|row| row.get_key("age").unwrap().value.clone());
+                assert_eq!(snapshot.rows().len() as u64, seq.div_ceil(2) + u64::from(seq >= 2));
+                assert_eq!(counter, (seq >= 2).then(|| (seq as u8 / 2 - 1).into()));
+            }
+        })
+    }).collect();
+
+    for i in 0..100u8 {
+        log.put(person(&format!("row-{i}"), i)).unwrap();
+        log.put(person("counter", i)).unwrap();
+    }
+
+    for reader in readers {
+        reader.join().unwrap();
+    }
+}
@@ -0,0 +1,41 @@
+//! Round-trip tests for row and key metadata blocks.
+
+use serde_yad::key::Key;
+use serde_yad::row::Row;
+use serde_yad::{CURRENT_VERSION, Value, YAD};
+
+#[test]
+fn metadata_round_trips() {
+    let mut key = Key::new("age", Value::from(17u8));
+    key.set_meta(0x80, vec![1, 2, 3]).unwrap();
+    key.meta.set_tag(7);
+
+    let mut row = Row::new("johan", vec![key]);
+    row.meta.set_modified(1_700_000_000_000);
+
+    let yad = YAD::new(CURRENT_VERSION, vec![row]);
+    let read = YAD::deserialize(yad.serialize().unwrap()).unwrap();
+
+    assert_eq!(read, yad);
+    assert_eq!(read.rows["johan"].meta().modified(), Some(1_700_000_000_000));
+    assert_eq!(read.rows["johan"].keys["age"].meta().tag(), Some(7));
+    assert_eq!(read.rows["johan"].keys["age"].meta().get(0x80), Some(&[1, 2, 3][..]));
+}
+
+#[test]
+fn empty_metadata_is_not_written() {
+    let yad = YAD::new(CURRENT_VERSION, vec![Row::new("r", vec![Key::new("k", Value::from(1u8))])]);
+
+    assert_eq!(yad.serialize().unwrap(), yad.serialize_canonical().unwrap());
+}
+
+#[test]
+fn encoded_len_matches_serialized_output() {
+    let mut row = Row::new("r", vec![Key::new("k", Value::try_from("x".repeat(300)).unwrap())]);
+    row.meta.set_modified(1);
+
+    let yad = YAD::new(CURRENT_VERSION, vec![row.clone()]);
+
+    assert_eq!(row.encoded_len(), row.serialize().unwrap().len());
+    assert_eq!(yad.encoded_len(), yad.serialize().unwrap().len());
+}
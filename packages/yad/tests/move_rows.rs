@@ -0,0 +1,44 @@
+//! Tests for moving rows between documents.
+
+use serde_yad::key::Key;
+use serde_yad::row::Row;
+use serde_yad::{CURRENT_VERSION, Value, YAD};
+
+fn document(rows: &[(&str, u8)]) -> YAD {
+    YAD::new(CURRENT_VERSION, rows.iter().map(|(name, age)| Row::new(name, vec![Key::new("age", *age)])).collect())
+}
+
+#[test]
+fn take_and_adopt_move_a_row() {
+    let mut source = document(&[("johan", 17), ("maria", 20)]);
+    let mut target = document(&[]);
+    target.build_index("age");
+
+    let row = source.take_row("johan").unwrap();
+    assert!(target.adopt_row(row).is_none());
+
+    assert!(source.get_row("johan").is_none());
+    assert_eq!(target["johan"]["age"], Value::from(17u8));
+    assert_eq!(target.rows_where("age", 17u8..=17u8).len(), 1);
+    assert!(source.take_row("johan").is_none());
+}
+
+#[test]
+fn adopt_returns_the_replaced_row() {
+    let mut yad = document(&[("johan", 17)]);
+
+    let replaced = yad.adopt_row(Row::new("johan", vec![Key::new("age", 18u8)])).unwrap();
+
+    assert_eq!(replaced["age"], Value::from(17u8));
+    assert_eq!(yad["johan"]["age"], Value::from(18u8));
+}
+
+#[test]
+fn extend_moves_every_row_and_replaces_clashes() {
+    let mut yad = document(&[("johan", 17), ("maria", 20)]);
+
+    yad.extend(document(&[("maria", 21), ("pedro", 30)]));
+
+    assert_eq!(yad.rows.keys().collect::<Vec<_>>(), ["johan", "maria", "pedro"]);
+    assert_eq!(yad["maria"]["age"], Value::from(21u8));
+}
@@ -0,0 +1,53 @@
+//! Round-trip tests for keys holding more than one value.
+
+use serde_yad::borrowed::YadRef;
+use serde_yad::key::Key;
+use serde_yad::projection::Projection;
+use serde_yad::row::Row;
+use serde_yad::{CURRENT_VERSION, Value, YAD};
+
+#[test]
+fn multi_value_keys_round_trip() {
+    let mut key = Key::new("history", 1u8);
+    key.push_value(2u8);
+    key.push_value(Value::try_from("three").unwrap());
+
+    let single = Key::new("single", 1u8);
+    let yad = YAD::new(CURRENT_VERSION, vec![Row::new("r", vec![key.clone(), single.clone()])]);
+    let bytes = yad.serialize().unwrap();
+    let decoded = YAD::deserialize(bytes.clone()).unwrap();
+
+    let values: Vec<&Value> = decoded.rows["r"].keys["history"].values().collect();
+    assert_eq!(values, vec![&Value::from(1u8), &Value::from(2u8), &Value::try_from("three").unwrap()]);
+    assert!(!decoded.rows["r"].keys["single"].is_multi_valued());
+    assert_eq!(single.serialize().unwrap().len(), single.encoded_len());
+    assert_eq!(key.serialize().unwrap().len(), key.encoded_len());
+    assert_eq!(decoded, yad);
+    assert_eq!(YadRef::new(&bytes).unwrap().to_owned().unwrap(), yad);
+    assert_eq!(YAD::deserialize_projected(&bytes, &Projection::all()).unwrap(), yad);
+}
+
+#[test]
+fn diff_and_patch_carry_every_value() {
+    let mut history = Key::new("history", 1u8);
+    history.push_value(2u8);
+    let before = YAD::new(CURRENT_VERSION, vec![Row::new("r", vec![history.clone()])]);
+
+    history.push_value(3u8);
+    let mut tagged = Key::new("tagged", 4u8);
+    tagged.push_value(5u8);
+    tagged.meta.set_modified(10);
+    let after = YAD::new(CURRENT_VERSION, vec![
+        Row::new("r", vec![history.clone()]),
+        Row::new("s", vec![tagged]),
+    ]);
+
+    let diff = before.diff(&after);
+    assert!(!diff.is_empty());
+    assert_eq!(diff.changed_rows[0].changed_keys[0].after, history);
+
+    let mut patched = before.clone();
+    patched.apply_patch(&diff.serialize().unwrap()).unwrap();
+    assert_eq!(patched, after);
+    assert!(patched.diff(&after).is_empty());
+}
@@ -0,0 +1,108 @@
+//! Tests for the handling of NaN and infinite floats when serializing and decoding.
+
+use float16::f16;
+use float8::F8E4M3;
+use serde_yad::key::Key;
+use serde_yad::options::{DecodeOptions, SerializeOptions};
+use serde_yad::row::Row;
+use serde_yad::{CURRENT_VERSION, Value, YAD};
+use yad_core::constants::error::{ErrorMessage, INFINITY_NOT_ALLOWED, NAN_NOT_ALLOWED};
+use yad_core::float::NonFinitePolicy;
+
+/// A NaN with a payload and the sign bit set, unlike the canonical quiet NaN.
+fn odd_nan() -> f64 {
+    f64::from_bits(0xFFF8_0000_0000_0001)
+}
+
+fn document(value: Value) -> YAD {
+    YAD::new(CURRENT_VERSION, vec![
+        Row::new("sensor", vec![Key::new("reading", value), Key::new("unit", Value::try_from("C").unwrap())]),
+    ])
+}
+
+fn nan() -> YAD {
+    document(Value::from(odd_nan()))
+}
+
+fn infinite() -> YAD {
+    document(Value::try_from(vec![Value::from(1.5f32), Value::from(f32::NEG_INFINITY)]).unwrap())
+}
+
+fn serialized(yad: &YAD, policy: NonFinitePolicy) -> Result<Vec<u8>, ErrorMessage> {
+    yad.serialize_with_options(&SerializeOptions { non_finite: policy, ..SerializeOptions::default() })
+}
+
+fn decoded(yad: &YAD, policy: NonFinitePolicy) -> Result<YAD, ErrorMessage> {
+    YAD::deserialize_with_options(yad.serialize().unwrap(), &DecodeOptions { non_finite: policy, ..DecodeOptions::default() })
+}
+
+#[test]
+fn allow_keeps_every_bit() {
+    for yad in [nan(), infinite()] {
+        assert_eq!(serialized(&yad, NonFinitePolicy::Allow), yad.serialize());
+        assert_eq!(decoded(&yad, NonFinitePolicy::Allow), Ok(yad.clone()));
+    }
+
+    let reading = decoded(&nan(), NonFinitePolicy::Allow).unwrap()["sensor"]["reading"].clone();
+    assert_eq!(f64::try_from(&reading).unwrap().to_bits(), odd_nan().to_bits());
+}
+
+#[test]
+fn reject_fails_on_serialize_and_decode() {
+    for apply in [serialized, |yad: &YAD, policy| decoded(yad, policy).map(|_| vec![])] {
+        assert_eq!(apply(&nan(), NonFinitePolicy::Reject), Err(ErrorMessage(NAN_NOT_ALLOWED)));
+        assert_eq!(apply(&infinite(), NonFinitePolicy::Reject), Err(ErrorMessage(INFINITY_NOT_ALLOWED)));
+    }
+
+    let finite = document(Value::from(21.5f64));
+    assert_eq!(serialized(&finite, NonFinitePolicy::Reject), finite.serialize());
+    assert_eq!(decoded(&finite, NonFinitePolicy::Reject), Ok(finite));
+}
+
+#[test]
+fn canonicalize_rewrites_nan_and_saturates_infinities() {
+    let canonical_nan = document(Value::from(f64::NAN));
+    let saturated = document(Value::try_from(vec![Value::from(1.5f32), Value::from(f32::MIN)]).unwrap());
+
+    assert_eq!(YAD::deserialize(serialized(&nan(), NonFinitePolicy::Canonicalize).unwrap()), Ok(canonical_nan.clone()));
+    assert_eq!(YAD::deserialize(serialized(&infinite(), NonFinitePolicy::Canonicalize).unwrap()), Ok(saturated.clone()));
+
+    assert_eq!(decoded(&nan(), NonFinitePolicy::Canonicalize), Ok(canonical_nan));
+    assert_eq!(decoded(&infinite(), NonFinitePolicy::Canonicalize), Ok(saturated));
+}
+
+#[test]
+fn serializing_never_changes_the_document() {
+    let yad = infinite();
+    serialized(&yad, NonFinitePolicy::Canonicalize).unwrap();
+    assert!(serialized(&yad, NonFinitePolicy::Reject).is_err());
+
+    assert_eq!(yad, infinite());
+}
+
+#[test]
+fn canonicalize_keeps_the_float_width() {
+    let cases = [
+        (Value::from(F8E4M3::from_f64(f64::NAN)), Value::from(F8E4M3::NAN)),
+        (Value::from(f16::NEG_INFINITY), Value::from(f16::MIN)),
+        (Value::from(f16::INFINITY), Value::from(f16::MAX)),
+        (Value::from(f32::INFINITY), Value::from(f32::MAX)),
+        (Value::from(f64::NEG_INFINITY), Value::from(f64::MIN)),
+        (Value::from(-f32::NAN), Value::from(f32::NAN)),
+    ];
+
+    for (value, expected) in cases {
+        assert_eq!(value.clone().with_non_finite_policy(NonFinitePolicy::Canonicalize), Ok(expected), "{:?}", value);
+    }
+}
+
+#[test]
+fn nested_arrays_are_checked_at_any_depth() {
+    let deep = Value::try_from(vec![Value::try_from(vec![Value::from(1u8), Value::from(f64::INFINITY)]).unwrap()]).unwrap();
+
+    assert_eq!(deep.clone().with_non_finite_policy(NonFinitePolicy::Reject), Err(ErrorMessage(INFINITY_NOT_ALLOWED)));
+    assert_eq!(
+        deep.with_non_finite_policy(NonFinitePolicy::Canonicalize),
+        Ok(Value::try_from(vec![Value::try_from(vec![Value::from(1u8), Value::from(f64::MAX)]).unwrap()]).unwrap()),
+    );
+}
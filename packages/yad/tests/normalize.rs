@@ -0,0 +1,49 @@
+//! Tests for normalized (NFC and case-insensitive) name lookup.
+#![cfg(feature = "normalize")]
+
+use serde_yad::key::Key;
+use serde_yad::normalize::Normalization;
+use serde_yad::row::Row;
+use serde_yad::{CURRENT_VERSION, Value, YAD};
+
+/// `é` as one code point and as `e` plus a combining acute accent.
+const COMPOSED: &str = "caf\u{e9}";
+const DECOMPOSED: &str = "cafe\u{301}";
+
+#[test]
+fn lookup_matches_across_composition_and_case() {
+    let yad = YAD::new(CURRENT_VERSION, vec![Row::new("Johan", vec![Key::new(COMPOSED, 1u8)])]);
+
+    assert!(yad.get_row("johan").is_none());
+    let row = yad.get_row_ci("JOHAN").unwrap();
+
+    assert!(row.get_key(DECOMPOSED).is_none());
+    assert_eq!(row.get_key_normalized(DECOMPOSED, Normalization::nfc()).unwrap().value, Value::from(1u8));
+    assert!(row.get_key_normalized("CAFÉ", Normalization::nfc()).is_none());
+    assert!(row.get_key_ci("CAFÉ").is_some());
+}
+
+#[test]
+fn normalized_insert_replaces_instead_of_duplicating() {
+    let mut yad = YAD::new_empty(CURRENT_VERSION);
+
+    yad.insert_row_normalized("Johan", vec![Key::new("Age", 17u8)], Normalization::case_insensitive());
+    yad.insert_row_normalized("JOHAN", vec![Key::new("AGE", 18u8)], Normalization::case_insensitive());
+
+    assert_eq!(yad.rows.len(), 1);
+    assert_eq!(yad["johan"]["age"], Value::from(18u8));
+}
+
+#[test]
+fn normalize_names_merges_existing_duplicates() {
+    let mut yad = YAD::new(CURRENT_VERSION, vec![
+        Row::new("Johan", vec![Key::new("age", 17u8)]),
+        Row::new("johan", vec![Key::new(DECOMPOSED, 1u8)]),
+    ]);
+
+    yad.normalize_names(Normalization::case_insensitive());
+
+    assert_eq!(yad.rows.len(), 1);
+    assert_eq!(yad["johan"]["age"], Value::from(17u8));
+    assert_eq!(yad["johan"][COMPOSED], Value::from(1u8));
+}
@@ -0,0 +1,171 @@
+//! Tests for change observers.
+
+use std::sync::{Arc, Mutex};
+use std::ops::Bound;
+use serde_yad::diff::MergeStrategy;
+use serde_yad::key::Key;
+use serde_yad::observe::ChangeEvent;
+use serde_yad::row::Row;
+use serde_yad::{CURRENT_VERSION, Value, YAD};
+
+/// Registers an observer recording every event into the returned list.
+fn record(yad: &mut YAD) -> Arc<Mutex<Vec<ChangeEvent>>> {
+    let events = Arc::new(Mutex::new(Vec::new()));
+    let sink = events.clone();
+    yad.on_change(move |event| sink.lock().unwrap().push(event.clone()));
+    events
+}
+
+fn row(name: &str) -> String {
+    name.to_string()
+}
+
+#[test]
+fn row_and_key_mutations_fire_events() {
+    let mut yad = YAD::new_empty(CURRENT_VERSION);
+    let events = record(&mut yad);
+
+    yad.insert_row("johan", vec![Key::new("age", 17u8)]);
+    yad.insert_row("johan", vec![Key::new("age", 18u8)]);
+    yad.insert_key("johan", "level", 3u8).unwrap();
+    yad.insert_key("johan", "level", 4u8).unwrap();
+    yad.remove_key("johan", "level");
+    yad.rename_row("johan", "juan").unwrap();
+    yad.remove_row("juan");
+
+    assert_eq!(*events.lock().unwrap(), [
+        ChangeEvent::RowInserted { row: row("johan") },
+        ChangeEvent::RowUpdated { row: row("johan") },
+        ChangeEvent::KeyInserted { row: row("johan"), key: "level".into() },
+        ChangeEvent::KeyUpdated { row: row("johan"), key: "level".into() },
+        ChangeEvent::KeyRemoved { row: row("johan"), key: "level".into() },
+        ChangeEvent::RowRenamed { old: row("johan"), new: row("juan") },
+        ChangeEvent::RowRemoved { row: row("juan") },
+    ]);
+}
+
+#[test]
+fn failed_mutations_and_direct_edits_fire_nothing() {
+    let mut yad = YAD::new_empty(CURRENT_VERSION);
+    yad.insert_row("johan", vec![]);
+    let events = record(&mut yad);
+
+    assert!(yad.insert_key("nobody", "age", 1u8).is_err());
+    assert!(yad.remove_key("johan", "age").is_none());
+    assert!(yad.remove_row("nobody").is_none());
+    yad.get_row_mut("johan").unwrap().insert_key("age", 1u8).unwrap();
+
+    assert!(events.lock().unwrap().is_empty());
+    assert_eq!(yad["johan"]["age"], Value::from(1u8));
+}
+
+#[test]
+fn transactions_notify_and_observers_can_be_removed() {
+    let mut yad = YAD::new_empty(CURRENT_VERSION);
+    let events = Arc::new(Mutex::new(Vec::new()));
+    let sink = events.clone();
+    let id = yad.on_change(move |event| sink.lock().unwrap().push(event.clone()));
+
+    yad.transaction(|tx| {
+        tx.insert_row("johan", vec![]);
+        Ok::<_, ()>(())
+    }).unwrap();
+
+    assert!(yad.remove_observer(id));
+    assert!(!yad.remove_observer(id));
+    yad.remove_row("johan");
+
+    assert_eq!(*events.lock().unwrap(), [ChangeEvent::RowInserted { row: row("johan") }]);
+}
+
+#[test]
+fn merges_notify_added_and_changed_rows() {
+    for (strategy, changed) in [(MergeStrategy::KeepExisting, false), (MergeStrategy::Overwrite, true), (MergeStrategy::ReplaceRows, true)] {
+        let mut yad = YAD::new(CURRENT_VERSION, vec![Row::new("johan", vec![Key::new("age", 17u8)])]);
+        yad.build_index("age");
+        let events = record(&mut yad);
+
+        yad.merge(YAD::new(CURRENT_VERSION, vec![
+            Row::new("johan", vec![Key::new("age", 18u8)]),
+            Row::new("maria", vec![Key::new("age", 20u8)]),
+        ]), strategy);
+
+        let mut expected = vec![];
+        if changed {
+            expected.push(ChangeEvent::RowUpdated { row: row("johan") });
+        }
+        expected.push(ChangeEvent::RowInserted { row: row("maria") });
+        assert_eq!(*events.lock().unwrap(), expected, "{:?}", strategy);
+
+        let index = yad.get_index("age").unwrap();
+        assert_eq!(index.range((Bound::Unbounded, Bound::Unbounded)).count(), 2, "{:?}", strategy);
+    }
+}
+
+#[test]
+fn transforms_notify_every_rewritten_key() {
+    let mut yad = YAD::new(CURRENT_VERSION, vec![
+        Row::new("johan", vec![Key::new("age", 17u8), Key::new("level", 3u8)]),
+        Row::new("maria", vec![Key::new("age", 20u8)]),
+    ]);
+    yad.build_index("age");
+    let events = record(&mut yad);
+
+    yad.transform("*.age", |value| Ok(Value::from(u8::try_from(&value)? + 1))).unwrap();
+
+    assert_eq!(*events.lock().unwrap(), [
+        ChangeEvent::KeyUpdated { row: row("johan"), key: "age".into() },
+        ChangeEvent::KeyUpdated { row: row("maria"), key: "age".into() },
+    ]);
+    assert_eq!(yad.rows_where("age", 18u8..=18u8).len(), 1);
+
+    events.lock().unwrap().clear();
+    assert!(yad.transform("*.age", |_| Err(yad_core::constants::error::ErrorMessage("no"))).is_err());
+    assert!(events.lock().unwrap().is_empty());
+}
+
+#[test]
+#[cfg(feature = "normalize")]
+fn normalizing_names_notifies_renames_and_merges() {
+    use serde_yad::normalize::Normalization;
+
+    let mut yad = YAD::new(CURRENT_VERSION, vec![
+        Row::new("Johan", vec![Key::new("Level", 3u8)]),
+        Row::new("johan", vec![Key::new("age", 17u8)]),
+        Row::new("Maria", vec![Key::new("age", 20u8)]),
+        Row::new("pedro", vec![Key::new("Age", 30u8)]),
+    ]);
+    let events = record(&mut yad);
+
+    yad.normalize_names(Normalization::case_insensitive());
+
+    assert_eq!(*events.lock().unwrap(), [
+        ChangeEvent::RowUpdated { row: row("pedro") },
+        ChangeEvent::RowRemoved { row: row("Johan") },
+        ChangeEvent::RowUpdated { row: row("johan") },
+        ChangeEvent::RowRenamed { old: row("Maria"), new: row("maria") },
+    ]);
+    assert_eq!(yad.rows.keys().collect::<Vec<_>>(), ["johan", "maria", "pedro"]);
+}
+
+#[test]
+#[cfg(feature = "ffi")]
+fn rows_inserted_through_the_ffi_are_seen() {
+    use serde_yad::ffi::yad_insert_row;
+
+    let mut yad = YAD::new(CURRENT_VERSION, vec![Row::new("johan", vec![Key::new("age", 17u8)])]);
+    yad.build_index("age");
+    let events = record(&mut yad);
+
+    let mut johan = Row::new("johan", vec![Key::new("age", 18u8)]);
+    let mut maria = Row::new("maria", vec![Key::new("age", 20u8)]);
+    yad_insert_row(&mut yad, &mut johan);
+    yad_insert_row(&mut yad, &mut maria);
+
+    assert_eq!(*events.lock().unwrap(), [
+        ChangeEvent::RowUpdated { row: row("johan") },
+        ChangeEvent::RowInserted { row: row("maria") },
+    ]);
+    assert_eq!(yad.rows_where("age", 17u8..=17u8).len(), 0);
+    assert_eq!(yad.get_index("age").unwrap().range((Bound::Unbounded, Bound::Unbounded)).count(), 2);
+}
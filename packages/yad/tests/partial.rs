@@ -0,0 +1,78 @@
+//! Tests for recovering the rows of damaged documents.
+
+mod common;
+
+use common::{document, person};
+use serde_yad::error::{CHECKSUM_MISMATCH, MALFORMED_VERSION_HEADER};
+use serde_yad::options::SerializeOptions;
+use serde_yad::{DecodeError, YAD};
+use yad_core::constants::error::ErrorMessage;
+
+#[test]
+fn intact_documents_decode_without_error() {
+    let (yad, error) = YAD::deserialize_partial(document().serialize().unwrap());
+
+    assert_eq!(yad, document());
+    assert_eq!(error, None);
+}
+
+#[test]
+fn truncated_documents_keep_the_rows_before_the_cut() {
+    let bytes = document().serialize().unwrap();
+    let carol = person("carol", 27, 3).serialize().unwrap();
+    let carol_offset = bytes.len() - carol.len();
+
+    for cut in carol_offset + 1..bytes.len() {
+        let (yad, error) = YAD::deserialize_partial(bytes[..cut].to_vec());
+
+        assert_eq!(yad.rows.keys().collect::<Vec<_>>(), ["alice", "bob"], "cut at {cut}");
+        let error = error.expect("the last row is truncated");
+        assert_eq!((error.offset, error.rows), (carol_offset, 2));
+        assert!(YAD::deserialize(bytes[..cut].to_vec()).is_err());
+    }
+}
+
+#[test]
+fn checksummed_documents_are_recovered_despite_the_mismatch() {
+    let options = SerializeOptions { include_checksum: true, ..SerializeOptions::default() };
+    let bytes = document().serialize_with_options(&options).unwrap();
+    let carol = person("carol", 27, 3).serialize().unwrap();
+    let carol_offset = bytes.len() - carol.len();
+
+    assert_eq!(YAD::deserialize_partial(bytes.clone()), (document(), None));
+
+    // Truncated: the rows before the cut are kept and the cut row is reported.
+    let (yad, error) = YAD::deserialize_partial(bytes[..bytes.len() - 3].to_vec());
+    assert_eq!(yad.rows.keys().collect::<Vec<_>>(), ["alice", "bob"]);
+    assert_eq!(error.map(|error| (error.offset, error.rows)), Some((carol_offset, 2)));
+    assert_eq!(YAD::deserialize(bytes[..bytes.len() - 3].to_vec()).err(), Some(ErrorMessage(CHECKSUM_MISMATCH)));
+
+    // Corrupted value: every row reads, so the mismatch itself is reported.
+    let mut corrupted = bytes.clone();
+    // Carol's age is encoded as a uint8 header followed by 27.
+    let age = carol_offset + carol.windows(2).position(|w| w == [0x11, 27]).unwrap() + 1;
+    corrupted[age] = 28;
+    let (yad, error) = YAD::deserialize_partial(corrupted);
+    assert_eq!(yad.rows.len(), 3);
+    assert_eq!(error, Some(DecodeError { error: ErrorMessage(CHECKSUM_MISMATCH), offset: 0, rows: 3 }));
+}
+
+#[test]
+fn unreadable_headers_return_an_empty_document() {
+    let (yad, error) = YAD::deserialize_partial(vec![0xF0, 0x01]);
+
+    assert!(yad.rows.is_empty());
+    assert_eq!(error, Some(DecodeError { error: ErrorMessage(MALFORMED_VERSION_HEADER), offset: 0, rows: 0 }));
+}
+
+#[test]
+fn rewritten_bodies_are_recovered_only_when_they_can_be_rewritten() {
+    let options = SerializeOptions { dictionary: true, ..SerializeOptions::default() };
+    let bytes = document().serialize_with_options(&options).unwrap();
+
+    assert_eq!(YAD::deserialize_partial(bytes.clone()), (document(), None));
+
+    let (yad, error) = YAD::deserialize_partial(bytes[..bytes.len() - 2].to_vec());
+    assert!(yad.rows.is_empty());
+    assert_eq!(error.map(|error| (error.offset, error.rows)), Some((0, 0)));
+}
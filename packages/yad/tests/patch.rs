@@ -0,0 +1,138 @@
+//! Tests for serializing diffs as patches and applying them.
+
+mod common;
+
+use common::{document, person};
+use serde_yad::constants::{PATCH_HEADER, PATCH_REMOVE_ROW, PATCH_ROW_END};
+use serde_yad::error::{MALFORMED_PATCH, PATCH_TARGET_ROW_MISSING};
+use serde_yad::key::Key;
+use serde_yad::meta::META_TAG;
+use serde_yad::{Value, YAD};
+use yad_core::constants::error::ErrorMessage;
+
+/// The fixture with bob one year older and an email, carol removed and dave added.
+fn edited() -> YAD {
+    let mut yad = document();
+    yad.insert_key("bob", "age", 42u8).unwrap();
+    yad.insert_key("bob", "email", Value::try_from("bob@example.com").unwrap()).unwrap();
+    yad.remove_key("bob", "id");
+    yad.remove_row("carol");
+    yad.adopt_row(person("dave", 35, 4));
+    yad
+}
+
+fn patch() -> Vec<u8> {
+    document().diff(&edited()).serialize().unwrap()
+}
+
+#[test]
+fn patches_round_trip() {
+    let mut yad = document();
+    yad.apply_patch(&patch()).unwrap();
+
+    assert_eq!(yad, edited());
+    assert!(yad.diff(&edited()).is_empty());
+}
+
+#[test]
+fn patches_go_both_ways() {
+    let mut yad = edited();
+    yad.apply_patch(&edited().diff(&document()).serialize().unwrap()).unwrap();
+
+    assert_eq!(yad, document());
+}
+
+#[test]
+fn an_empty_diff_is_a_bare_header() {
+    let empty = document().diff(&document()).serialize().unwrap();
+    assert_eq!(empty, [PATCH_HEADER]);
+
+    let mut yad = document();
+    yad.apply_patch(&empty).unwrap();
+    assert_eq!(yad, document());
+}
+
+#[test]
+fn a_stale_base_is_rejected_without_partial_changes() {
+    // The patch adds dave before it changes bob, who is gone from this base.
+    let mut stale = document();
+    stale.remove_row("bob");
+    let before = stale.clone();
+
+    assert_eq!(stale.apply_patch(&patch()), Err(ErrorMessage(PATCH_TARGET_ROW_MISSING)));
+    assert_eq!(stale, before);
+    assert!(stale.get_row("dave").is_none());
+}
+
+#[test]
+fn removing_a_missing_row_is_not_an_error() {
+    let mut yad = document();
+    yad.remove_row("carol");
+    yad.insert_key("bob", "age", 41u8).unwrap();
+
+    yad.apply_patch(&patch()).unwrap();
+    assert_eq!(yad, edited());
+}
+
+#[test]
+fn malformed_patches_are_rejected() {
+    let mut unknown_op = patch();
+    unknown_op[1] = 0x00;
+
+    let mut bad_key_op = patch();
+    let row_end = bad_key_op.iter().position(|byte| *byte == PATCH_ROW_END).unwrap();
+    bad_key_op[row_end] = 0x00;
+
+    for bytes in [vec![], vec![PATCH_REMOVE_ROW], unknown_op, bad_key_op] {
+        let mut yad = document();
+        assert_eq!(yad.apply_patch(&bytes), Err(ErrorMessage(MALFORMED_PATCH)), "{:02X?}", bytes);
+        assert_eq!(yad, document());
+    }
+}
+
+#[test]
+fn truncated_patches_fail_cleanly_or_apply_whole_operations() {
+    let patch = patch();
+
+    for len in 1..patch.len() {
+        let mut yad = document();
+        match yad.apply_patch(&patch[..len]) {
+            Ok(()) => assert!(len == 1 || yad.get_row("dave").is_some(), "at {}", len),
+            Err(error) => {
+                assert_eq!(error, ErrorMessage(MALFORMED_PATCH), "at {}", len);
+                assert_eq!(yad, document(), "at {}", len);
+            }
+        }
+    }
+}
+
+#[test]
+fn set_keys_carry_every_value() {
+    let mut tags = Key::try_new("tags", "admin").unwrap();
+    tags.push_value(Value::try_from("staff").unwrap());
+
+    let mut target = document();
+    target.insert_row("erin", vec![tags]);
+
+    let mut yad = document();
+    yad.apply_patch(&document().diff(&target).serialize().unwrap()).unwrap();
+    assert_eq!(yad, target);
+}
+
+#[test]
+fn row_metadata_round_trips() {
+    let mut base = document();
+    base.rows.get_mut("carol").unwrap().meta.set_tag(7);
+
+    let mut target = base.clone();
+    target.rows.get_mut("bob").unwrap().meta.set_expires(1_000);
+    target.rows.get_mut("carol").unwrap().meta.remove(META_TAG);
+    let mut dave = person("dave", 35, 4);
+    dave.meta.set_modified(42);
+    target.adopt_row(dave);
+
+    let mut yad = base.clone();
+    yad.apply_patch(&base.diff(&target).serialize().unwrap()).unwrap();
+    assert_eq!(yad, target);
+    assert!(yad.diff(&target).is_empty());
+}
@@ -0,0 +1,54 @@
+//! Golden tests for [`YAD::to_pretty_string`].
+
+mod common;
+
+use common::document;
+use serde_yad::key::Key;
+use serde_yad::row::Row;
+use serde_yad::{CURRENT_VERSION, Value, YAD};
+
+#[test]
+fn document_is_rendered_in_name_order() {
+    let expected = format!(
+        "YAD {}\n\
+         \x20 row \"alice\"\n\
+         \x20   \"age\" = 30u8\n\
+         \x20   \"id\" = 1u16\n\
+         \x20   \"name\" = \"Alice\"\n\
+         \x20 row \"bob\"\n\
+         \x20   \"age\" = 41u8\n\
+         \x20   \"id\" = 2u16\n\
+         \x20   \"name\" = \"Bob\"\n\
+         \x20 row \"carol\"\n\
+         \x20   \"age\" = 27u8\n\
+         \x20   \"id\" = 3u16\n\
+         \x20   \"name\" = \"Carol\"\n",
+        CURRENT_VERSION
+    );
+
+    assert_eq!(document().to_pretty_string(2), expected);
+}
+
+#[test]
+fn values_and_metadata_are_rendered_with_their_widths() {
+    let mut row = Row::new("misc", vec![
+        Key::new("f", Value::from(1.5f32)),
+        Key::new("i", Value::from(-300i16)),
+        Key::new("list", Value::try_from(vec![Value::from(1u8), Value::try_from("a\"b").unwrap()]).unwrap()),
+        Key::new("yes", Value::from(true)),
+    ]);
+    row.set_meta(0x01, vec![0xAB, 0xCD]).unwrap();
+    let yad = YAD::new(CURRENT_VERSION, vec![row]);
+
+    let expected = format!(
+        "YAD {}\n\
+         row \"misc\" meta {{ 0x01: 0xABCD }}\n\
+         \"f\" = 1.5f32\n\
+         \"i\" = -300i16\n\
+         \"list\" = [1u8, \"a\\\"b\"]\n\
+         \"yes\" = true\n",
+        CURRENT_VERSION
+    );
+
+    assert_eq!(yad.to_pretty_string(0), expected);
+}
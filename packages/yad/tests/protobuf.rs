@@ -0,0 +1,85 @@
+//! Tests for converting values, rows and documents to and from protobuf.
+#![cfg(feature = "protobuf")]
+
+use std::collections::BTreeMap;
+use prost_types::value::Kind;
+use prost_types::{ListValue, Struct};
+use serde_yad::error::PROTOBUF_ROW_NOT_A_STRUCT;
+use serde_yad::key::Key;
+use serde_yad::row::Row;
+use serde_yad::{Value, CURRENT_VERSION, YAD};
+use yad_core::bigint::BigInteger;
+use yad_core::constants::error::{
+    ErrorMessage, PROTOBUF_KIND_MISSING, PROTOBUF_NULL_NOT_SUPPORTED, PROTOBUF_NUMBER_NOT_REPRESENTABLE,
+    PROTOBUF_STRUCT_NOT_SUPPORTED,
+};
+use yad_core::decimal::Decimal;
+use yad_core::protobuf::MAX_EXACT_PROTOBUF_INTEGER;
+
+fn protobuf(kind: Kind) -> prost_types::Value {
+    prost_types::Value { kind: Some(kind) }
+}
+
+#[test]
+fn values_map_to_protobuf_kinds() {
+    assert_eq!(Value::from(300u16).to_protobuf(), Ok(protobuf(Kind::NumberValue(300.0))));
+    assert_eq!(Value::from(-7i64).to_protobuf(), Ok(protobuf(Kind::NumberValue(-7.0))));
+    assert_eq!(Value::from(0.5f32).to_protobuf(), Ok(protobuf(Kind::NumberValue(0.5))));
+    assert_eq!(Value::from(true).to_protobuf(), Ok(protobuf(Kind::BoolValue(true))));
+    assert_eq!(Value::try_from("hi").unwrap().to_protobuf(), Ok(protobuf(Kind::StringValue("hi".to_string()))));
+    assert_eq!(
+        Value::try_from(vec![1u8, 2]).unwrap().to_protobuf(),
+        Ok(protobuf(Kind::ListValue(ListValue { values: vec![protobuf(Kind::NumberValue(1.0)), protobuf(Kind::NumberValue(2.0))] }))),
+    );
+}
+
+#[test]
+fn extension_values_become_their_text() {
+    assert_eq!(Value::from(Decimal::new(1990, 2)).to_protobuf(), Ok(protobuf(Kind::StringValue("19.90".to_string()))));
+    let big: BigInteger = "123456789012345678901234567890".parse().unwrap();
+    assert_eq!(Value::from(big).to_protobuf(), Ok(protobuf(Kind::StringValue("123456789012345678901234567890".to_string()))));
+}
+
+#[test]
+fn integers_must_be_exact_as_f64() {
+    assert!(Value::from(MAX_EXACT_PROTOBUF_INTEGER).to_protobuf().is_ok());
+    assert_eq!(Value::from(MAX_EXACT_PROTOBUF_INTEGER + 1).to_protobuf(), Err(ErrorMessage(PROTOBUF_NUMBER_NOT_REPRESENTABLE)));
+    assert_eq!(Value::from(i64::MIN).to_protobuf(), Err(ErrorMessage(PROTOBUF_NUMBER_NOT_REPRESENTABLE)));
+}
+
+#[test]
+fn numbers_take_the_smallest_width() {
+    assert_eq!(Value::from_protobuf(&protobuf(Kind::NumberValue(200.0))), Ok(Value::from(200u8)));
+    assert_eq!(Value::from_protobuf(&protobuf(Kind::NumberValue(-200.0))), Ok(Value::from(-200i16)));
+    assert_eq!(Value::from_protobuf(&protobuf(Kind::NumberValue(1.5))), Ok(Value::from(1.5f64)));
+    assert_eq!(Value::from_protobuf(&protobuf(Kind::NumberValue(1e300))), Ok(Value::from(1e300f64)));
+}
+
+#[test]
+fn rejects_kinds_without_a_value() {
+    assert_eq!(Value::from_protobuf(&protobuf(Kind::NullValue(0))), Err(ErrorMessage(PROTOBUF_NULL_NOT_SUPPORTED)));
+    assert_eq!(Value::from_protobuf(&protobuf(Kind::StructValue(Struct::default()))), Err(ErrorMessage(PROTOBUF_STRUCT_NOT_SUPPORTED)));
+    assert_eq!(Value::from_protobuf(&prost_types::Value { kind: None }), Err(ErrorMessage(PROTOBUF_KIND_MISSING)));
+}
+
+#[test]
+fn documents_round_trip_through_structs() {
+    let yad = YAD::new(CURRENT_VERSION, vec![
+        Row::new("johan", vec![Key::new("age", 17u8), Key::new("name", Value::try_from("Johan").unwrap())]),
+        Row::new("server", vec![Key::new("debug", false), Key::new("ratio", 0.25f64)]),
+    ]);
+
+    let protobuf_struct = yad.to_protobuf_struct().unwrap();
+    assert_eq!(protobuf_struct.fields.len(), 2);
+    assert_eq!(YAD::from_protobuf_struct(&protobuf_struct), Ok(yad.clone()));
+
+    let row = yad.rows["johan"].to_protobuf_struct().unwrap();
+    assert_eq!(row.fields["age"], protobuf(Kind::NumberValue(17.0)));
+    assert_eq!(Row::from_protobuf_struct("johan", &row), Ok(yad.rows["johan"].clone()));
+}
+
+#[test]
+fn rows_must_be_structs() {
+    let document = Struct { fields: BTreeMap::from([("johan".to_string(), protobuf(Kind::NumberValue(1.0)))]) };
+    assert_eq!(YAD::from_protobuf_struct(&document), Err(ErrorMessage(PROTOBUF_ROW_NOT_A_STRUCT)));
+}
@@ -0,0 +1,111 @@
+//! Tests for finding and selecting rows.
+
+mod common;
+
+use common::{document, person};
+use serde_yad::key::Key;
+use serde_yad::query::{Predicate, ValuePredicate};
+use serde_yad::row::Row;
+use serde_yad::{Value, YAD};
+use yad_core::constants::types::Type;
+
+fn names<'a>(rows: impl Iterator<Item = &'a Row>) -> Vec<&'a str> {
+    rows.map(|row| row.name.as_str()).collect()
+}
+
+/// The fixture with a row whose name holds a `.`, one named `*` and a key
+/// whose name holds a `.`.
+fn dotted() -> YAD {
+    let mut yad = document();
+    yad.adopt_row(person("a.b", 50, 9));
+    yad.adopt_row(person("*", 60, 10));
+    yad.insert_key("bob", "x.y", 1u8).unwrap();
+    yad
+}
+
+#[test]
+fn find_rows_filters_in_name_order() {
+    let yad = document();
+
+    assert_eq!(names(yad.find_rows(|row| row.key_matches("age", &Predicate::ge(30u8)))), ["alice", "bob"]);
+    assert_eq!(names(yad.find_rows(|_| true)), ["alice", "bob", "carol"]);
+    assert!(yad.find_rows(|row| row.key_matches("missing", &Predicate::Exists)).next().is_none());
+}
+
+#[test]
+fn predicates_compare_by_meaning() {
+    let yad = document();
+
+    assert_eq!(names(yad.select("age", Predicate::eq(41u64))), ["bob"]);
+    assert_eq!(names(yad.select("age", Predicate::ne(41u64))), ["alice", "carol"]);
+    assert_eq!(names(yad.select("age", Predicate::gt(29.5f64))), ["alice", "bob"]);
+    assert_eq!(names(yad.select("age", Predicate::lt(30i32))), ["carol"]);
+    assert_eq!(names(yad.select("age", Predicate::le(30u16))), ["alice", "carol"]);
+    assert_eq!(names(yad.select("name", Predicate::contains_str("ro").unwrap())), ["carol"]);
+
+    // Values of another kind never order against the operand.
+    assert!(yad.select("name", Predicate::gt(0u8)).next().is_none());
+    assert!(Predicate::contains_str("").is_none());
+}
+
+#[test]
+fn contains_looks_into_arrays() {
+    let mut yad = document();
+    yad.insert_key("bob", "scores", Value::try_from(vec![3u8, 7]).unwrap()).unwrap();
+
+    assert_eq!(names(yad.select("scores", Predicate::Contains(Value::from(7u32)))), ["bob"]);
+    assert!(yad.select("scores", Predicate::Contains(Value::from(8u8))).next().is_none());
+}
+
+#[test]
+fn closures_are_predicates() {
+    let yad = document();
+    let is_string = |value: &Value| value.r#type == Type::String;
+
+    assert!(is_string.test(&Value::try_from("x").unwrap()));
+    assert_eq!(names(yad.select("name", is_string)), ["alice", "bob", "carol"]);
+    assert!(yad.select("age", is_string).next().is_none());
+}
+
+#[test]
+fn paths_name_a_key_a_row_or_any_row() {
+    let yad = document();
+
+    assert_eq!(names(yad.select("age", Predicate::Exists)), ["alice", "bob", "carol"]);
+    assert_eq!(names(yad.select("*.age", Predicate::Exists)), ["alice", "bob", "carol"]);
+    assert_eq!(names(yad.select("bob.age", Predicate::Exists)), ["bob"]);
+}
+
+#[test]
+fn missing_segments_match_nothing() {
+    let yad = document();
+
+    for path in ["", "bob.", ".age", "*.", "dave.age", "bob.height", "age.bob"] {
+        assert!(yad.select(path, Predicate::Exists).next().is_none(), "{:?}", path);
+    }
+}
+
+#[test]
+fn key_names_may_hold_dots() {
+    let yad = dotted();
+
+    assert_eq!(names(yad.select("bob.x.y", Predicate::Exists)), ["bob"]);
+    assert_eq!(names(yad.select("*.x.y", Predicate::Exists)), ["bob"]);
+    assert_eq!(names(yad.select("x\\.y", Predicate::Exists)), ["bob"]);
+}
+
+#[test]
+fn escaped_segments_are_literal() {
+    let yad = dotted();
+
+    assert_eq!(names(yad.select("a\\.b.age", Predicate::Exists)), ["a.b"]);
+    assert!(yad.select("a.b.age", Predicate::Exists).next().is_none());
+
+    assert_eq!(names(yad.select("\\*.age", Predicate::Exists)), ["*"]);
+    assert_eq!(yad.select("*.age", Predicate::Exists).count(), 5);
+
+    assert_eq!(names(yad.select("a\\\\.age", Predicate::Exists)), Vec::<&str>::new());
+    let mut backslash = dotted();
+    backslash.adopt_row(Row::new("a\\", vec![Key::new("age", 1u8)]));
+    assert_eq!(names(backslash.select("a\\\\.age", Predicate::Exists)), ["a\\"]);
+}
@@ -0,0 +1,100 @@
+//! Tests for scanning corrupted documents for intact rows.
+
+mod common;
+
+use common::document;
+use serde_yad::error::{CHECKSUM_MISMATCH, MALFORMED_FILE, MALFORMED_ROW_NAME_VECTOR};
+use serde_yad::options::SerializeOptions;
+use serde_yad::recover::scan;
+use serde_yad::CURRENT_VERSION;
+use yad_core::byte_order::ByteOrder;
+use yad_core::constants::error::ErrorMessage;
+
+/// The offset of every row of `bytes`, which must be a serialized [`document`].
+fn row_offsets(bytes: &[u8]) -> Vec<usize> {
+    let lengths: Vec<usize> = document().rows.values().map(|row| row.serialize().unwrap().len()).collect();
+    let mut offset = bytes.len() - lengths.iter().sum::<usize>();
+    lengths.iter().map(|len| { let start = offset; offset += len; start }).collect()
+}
+
+#[test]
+fn intact_documents_are_fully_salvaged() {
+    let bytes = document().serialize().unwrap();
+    let report = scan(&bytes);
+
+    assert!(report.is_intact());
+    assert!(report.offsets_in_input);
+    assert_eq!(report.rows.iter().map(|row| row.range.start).collect::<Vec<_>>(), row_offsets(&bytes));
+    assert_eq!(report.into_yad(), document());
+}
+
+#[test]
+fn rows_after_a_damaged_one_are_salvaged() {
+    let mut bytes = document().serialize().unwrap();
+    let offsets = row_offsets(&bytes);
+    bytes[offsets[1] + 1] = 0x00;
+
+    let report = scan(&bytes);
+    assert_eq!(report.damaged.len(), 1);
+    assert_eq!(report.damaged[0].range, offsets[1]..offsets[2]);
+    assert_eq!(report.damaged[0].error, ErrorMessage(MALFORMED_ROW_NAME_VECTOR));
+
+    let yad = report.into_yad();
+    assert_eq!(yad.rows.keys().collect::<Vec<_>>(), ["alice", "carol"]);
+    assert_eq!(yad.get_row("carol"), document().get_row("carol"));
+}
+
+#[test]
+fn checksum_mismatches_are_reported_with_the_rows() {
+    let options = SerializeOptions { include_checksum: true, ..SerializeOptions::default() };
+    let mut bytes = document().serialize_with_options(&options).unwrap();
+    let offsets = row_offsets(&bytes);
+    // Carol's age is encoded as a uint8 header followed by 27.
+    let age = offsets[2] + bytes[offsets[2]..].windows(2).position(|w| w == [0x11, 27]).unwrap() + 1;
+    bytes[age] = 28;
+
+    let report = scan(&bytes);
+    assert_eq!(report.version, Some(CURRENT_VERSION));
+    assert_eq!(report.rows.len(), 3);
+    assert_eq!(report.damaged.len(), 1);
+    assert_eq!(report.damaged[0].range, offsets[0]..bytes.len());
+    assert_eq!(report.damaged[0].error, ErrorMessage(CHECKSUM_MISMATCH));
+}
+
+#[test]
+fn truncated_tails_and_garbage_are_reported() {
+    let bytes = document().serialize().unwrap();
+    let offsets = row_offsets(&bytes);
+
+    let report = scan(&bytes[..bytes.len() - 2]);
+    assert_eq!(report.rows.len(), 2);
+    assert_eq!(report.damaged[0].range, offsets[2]..bytes.len() - 2);
+
+    let mut garbled = bytes.clone();
+    garbled.splice(offsets[1]..offsets[1], [0x13, 0x37]);
+    let report = scan(&garbled);
+    assert_eq!(report.rows.len(), 3);
+    assert_eq!(report.damaged[0].range, offsets[1]..offsets[1] + 2);
+    assert_eq!(report.damaged[0].error, ErrorMessage(MALFORMED_FILE));
+}
+
+#[test]
+fn documents_without_headers_are_scanned_whole() {
+    let bytes = document().serialize().unwrap();
+    let offsets = row_offsets(&bytes);
+
+    let report = scan(&bytes[offsets[0]..]);
+    assert_eq!(report.version, None);
+    assert!(report.is_intact());
+    assert_eq!(report.into_yad(), document());
+}
+
+#[test]
+fn rewritten_bodies_are_scanned_after_being_rewritten() {
+    let options = SerializeOptions { byte_order: ByteOrder::Little, dictionary: true, ..SerializeOptions::default() };
+    let report = scan(&document().serialize_with_options(&options).unwrap());
+
+    assert!(report.is_intact());
+    assert!(!report.offsets_in_input);
+    assert_eq!(report.into_yad(), document());
+}
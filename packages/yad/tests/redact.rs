@@ -0,0 +1,54 @@
+//! Tests for sensitive keys and redacted serialization.
+
+use serde_yad::key::Key;
+use serde_yad::redact::RedactionPolicy;
+use serde_yad::row::Row;
+use serde_yad::{CURRENT_VERSION, Value, YAD};
+
+fn with_sensitive_key() -> YAD {
+    let mut email = Key::new("email", Value::try_from("johan@example.com").unwrap());
+    email.meta.set_sensitive(true);
+
+    let mut row = Row::new("johan", vec![Key::new("age", Value::from(17u8)), email]);
+    row.meta.set_modified(1);
+
+    YAD::new(CURRENT_VERSION, vec![row])
+}
+
+#[test]
+fn sensitive_mark_round_trips() {
+    let yad = with_sensitive_key();
+    let read = YAD::deserialize(yad.serialize().unwrap()).unwrap();
+
+    assert!(read.rows["johan"].keys["email"].meta().is_sensitive());
+    assert!(!read.rows["johan"].keys["age"].meta().is_sensitive());
+
+    let mut key = read.rows["johan"].keys["email"].clone();
+    key.meta.set_sensitive(false);
+    assert!(key.meta.is_empty());
+}
+
+#[test]
+fn omit_leaves_sensitive_keys_out() {
+    let yad = with_sensitive_key();
+    let public = YAD::deserialize(yad.serialize_redacted(&RedactionPolicy::Omit).unwrap()).unwrap();
+
+    assert!(public.rows["johan"].get("email").is_none());
+    assert_eq!(public.rows["johan"].get("age"), Some(&Value::from(17u8)));
+    assert_eq!(public.rows["johan"].meta().modified(), Some(1));
+    assert_eq!(yad, with_sensitive_key());
+}
+
+#[test]
+fn replace_keeps_the_key_with_another_value() {
+    let mut yad = with_sensitive_key();
+    yad.get_row_mut("johan").unwrap().get_key_mut("email").unwrap().push_value(Value::try_from("j@example.org").unwrap());
+
+    let mask = Value::try_from("<redacted>").unwrap();
+    let public = yad.redacted(&RedactionPolicy::Replace(mask.clone()));
+
+    let email = public.rows["johan"].get_key("email").unwrap();
+    assert_eq!(email.values().collect::<Vec<_>>(), [&mask]);
+    assert!(email.meta().is_sensitive());
+    assert_eq!(public.rows["johan"].get_key("age"), yad.rows["johan"].get_key("age"));
+}
@@ -0,0 +1,49 @@
+//! Tests for renaming rows and keys.
+
+mod common;
+
+use common::document;
+use serde_yad::error::{KEY_NOT_FOUND, NAME_ALREADY_TAKEN, ROW_NOT_FOUND};
+use serde_yad::{Value, YAD};
+use yad_core::constants::error::ErrorMessage;
+
+#[test]
+fn rename_row_updates_map_key_name_and_indexes() {
+    let mut yad = document();
+    yad.build_index("age");
+
+    yad.rename_row("alice", "ann").unwrap();
+
+    assert!(yad.get_row("alice").is_none());
+    assert_eq!(yad["ann"].name, "ann");
+    assert_eq!(yad.rows_where("age", 30u8..=30u8).iter().map(|row| row.name.as_str()).collect::<Vec<_>>(), ["ann"]);
+
+    let read = YAD::deserialize(yad.serialize().unwrap()).unwrap();
+    assert_eq!(read, yad);
+}
+
+#[test]
+fn rename_key_updates_map_key_and_name() {
+    let mut row = document()["alice"].clone();
+
+    row.rename_key("age", "years").unwrap();
+
+    assert!(row.get_key("age").is_none());
+    assert_eq!(row.keys["years"].name, "years");
+    assert_eq!(row["years"], Value::from(30u8));
+}
+
+#[test]
+fn renames_fail_without_changing_anything() {
+    let mut yad = document();
+
+    assert_eq!(yad.rename_row("nobody", "x"), Err(ErrorMessage(ROW_NOT_FOUND)));
+    assert_eq!(yad.rename_row("alice", "bob"), Err(ErrorMessage(NAME_ALREADY_TAKEN)));
+    assert_eq!(yad, document());
+
+    let row = yad.get_row_mut("alice").unwrap();
+    assert_eq!(row.rename_key("nope", "x"), Err(ErrorMessage(KEY_NOT_FOUND)));
+    assert_eq!(row.rename_key("age", "id"), Err(ErrorMessage(NAME_ALREADY_TAKEN)));
+    assert!(row.rename_key("age", "").is_err());
+    assert_eq!(yad, document());
+}
@@ -0,0 +1,68 @@
+//! Tests for saving documents atomically.
+
+use std::fs;
+use std::path::PathBuf;
+use serde_yad::error::SAVE_IO_FAILED;
+use serde_yad::key::Key;
+use serde_yad::row::Row;
+use serde_yad::{CURRENT_VERSION, YAD};
+use yad_core::constants::error::ErrorMessage;
+
+fn temp_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("serde_yad-save-{}-{}", name, std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+fn document(age: u8) -> YAD {
+    YAD::new(CURRENT_VERSION, vec![Row::new("johan", vec![Key::new("age", age)])])
+}
+
+fn read(path: &PathBuf) -> YAD {
+    YAD::deserialize(fs::read(path).unwrap()).unwrap()
+}
+
+#[test]
+fn save_atomic_replaces_the_file_without_leaving_a_temporary() {
+    let dir = temp_dir("atomic");
+    let path = dir.join("data.yad");
+
+    document(17).save_atomic(&path).unwrap();
+    assert_eq!(read(&path), document(17));
+
+    document(18).save_atomic(&path).unwrap();
+    assert_eq!(read(&path), document(18));
+    assert_eq!(fs::read_dir(&dir).unwrap().count(), 1);
+    fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn backups_keep_the_replaced_version() {
+    let dir = temp_dir("backup");
+    let path = dir.join("data.yad");
+    let backup = dir.join("data.yad.bak");
+
+    document(17).save_atomic_with_backup(&path).unwrap();
+    assert!(!backup.exists());
+
+    document(18).save_atomic_with_backup(&path).unwrap();
+    document(19).save_atomic_with_backup(&path).unwrap();
+    assert_eq!(read(&path), document(19));
+    assert_eq!(read(&backup), document(18));
+    assert_eq!(fs::read_dir(&dir).unwrap().count(), 2);
+    fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn failures_leave_the_file_untouched() {
+    let dir = temp_dir("failure");
+    let missing = dir.join("no-such-dir").join("data.yad");
+    assert_eq!(document(17).save_atomic(&missing), Err(ErrorMessage(SAVE_IO_FAILED)));
+
+    let path = dir.join("data.yad");
+    document(17).save_atomic(&path).unwrap();
+    fs::create_dir(dir.join("data.yad.tmp")).unwrap();
+    assert_eq!(document(18).save_atomic(&path), Err(ErrorMessage(SAVE_IO_FAILED)));
+    assert_eq!(read(&path), document(17));
+    fs::remove_dir_all(dir).unwrap();
+}
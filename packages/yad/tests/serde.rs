@@ -0,0 +1,58 @@
+//! Tests for the serde implementations of the model types.
+#![cfg(feature = "serde")]
+
+use serde_json::json;
+use serde_yad::key::Key;
+use serde_yad::row::Row;
+use serde_yad::{Value, Version, CURRENT_VERSION, YAD};
+use float16::f16;
+
+fn mixed_values() -> YAD {
+    let mut tags = Key::new("tags", Value::try_from("a").unwrap());
+    tags.push_value(Value::try_from("b").unwrap());
+    let mut row = Row::new("alice", vec![Key::new("age", 30u8), tags]);
+    row.meta.set(1, vec![7]).unwrap();
+
+    YAD::new(CURRENT_VERSION, vec![
+        row,
+        Row::new("bob", vec![Key::new("half", f16::from_f32(1.5)), Key::new("ids", Value::try_from(vec![1i64, -2]).unwrap())]),
+    ])
+}
+
+#[test]
+fn values_keep_their_type_and_width() {
+    assert_eq!(serde_json::to_value(Value::from(7u32)).unwrap(), json!({ "uint32": 7 }));
+    assert_eq!(
+        serde_json::to_value(Value::try_from(vec![Value::from(true), Value::try_from("x").unwrap()]).unwrap()).unwrap(),
+        json!({ "array": [{ "bool": true }, { "string": "x" }] }),
+    );
+
+    for value in [Value::from(7u32), Value::from(-1i8), Value::from(f16::from_f32(0.25)), Value::from(2.5f64)] {
+        let json = serde_json::to_string(&value).unwrap();
+        assert_eq!(serde_json::from_str::<Value>(&json).unwrap(), value, "{json}");
+    }
+}
+
+#[test]
+fn invalid_values_are_rejected() {
+    assert!(serde_json::from_value::<Value>(json!({ "string": "" })).is_err());
+    assert!(serde_json::from_value::<Value>(json!({ "uint8": 256 })).is_err());
+    assert!(serde_json::from_value::<Value>(json!({ "uint128": 1 })).is_err());
+}
+
+#[test]
+fn documents_round_trip_through_json() {
+    let json = serde_json::to_value(mixed_values()).unwrap();
+
+    assert_eq!(json["rows"][0]["name"], "alice");
+    assert_eq!(json["rows"][0]["keys"][1]["more"], json!([{ "string": "b" }]));
+    assert!(json["rows"][1].get("meta").is_none());
+
+    assert_eq!(serde_json::from_value::<YAD>(json).unwrap(), mixed_values());
+}
+
+#[test]
+fn versions_are_plain_structs() {
+    let version = Version { major: 2, minor: 1, patch: 0, beta: 3 };
+    assert_eq!(serde_json::to_value(&version).unwrap(), json!({ "major": 2, "minor": 1, "patch": 0, "beta": 3 }));
+}
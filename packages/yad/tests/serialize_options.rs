@@ -0,0 +1,137 @@
+//! Tests for the serializer options accepted by [`YAD::serialize_with_options`].
+
+use serde_yad::borrowed::YadRef;
+use serde_yad::error::CHECKSUM_MISMATCH;
+use serde_yad::header::{Header, HeaderFlags};
+use serde_yad::key::Key;
+use serde_yad::options::{Compression, SerializeOptions};
+use serde_yad::row::Row;
+use serde_yad::stream::YadRowStream;
+use serde_yad::{CURRENT_VERSION, Value, YAD};
+use yad_core::constants::error::ErrorMessage;
+use yad_core::constants::length::ByteLength;
+use yad_core::constants::types::Type;
+
+fn hundred_rows() -> YAD {
+    YAD::new(CURRENT_VERSION, (0..100u32).map(|i| Row::new(format!("row-{i}"), vec![
+        Key::new("name", Value::try_from("Johan").unwrap()),
+        Key::new("n", Value::from(i)),
+    ])).collect())
+}
+
+fn streamed(bytes: &[u8]) -> YAD {
+    let rows = YadRowStream::open(bytes).unwrap().collect::<Result<Vec<_>, _>>().unwrap();
+    YAD::new(CURRENT_VERSION, rows)
+}
+
+#[test]
+fn default_options_match_serialize() {
+    let yad = hundred_rows();
+
+    assert_eq!(yad.serialize_with_options(&SerializeOptions::default()).unwrap(), yad.serialize().unwrap());
+}
+
+#[test]
+fn minimal_lengths_shrink_wide_descriptors() {
+    let wide = Value { r#type: Type::String, length: ByteLength::Two, bytes: vec![0x42, 0x00, 0x01, 0x61].into() };
+    let yad = YAD::new(CURRENT_VERSION, vec![Row::new("r", vec![Key::new("k", wide)])]);
+
+    let bytes = yad.serialize_with_options(&SerializeOptions { minimal_lengths: true, ..SerializeOptions::default() }).unwrap();
+    assert_eq!(bytes, yad.serialize_canonical().unwrap());
+    assert_eq!(bytes.len() + 1, yad.serialize().unwrap().len());
+}
+
+#[test]
+fn checksum_detects_corruption() {
+    let yad = hundred_rows();
+    let options = SerializeOptions { include_checksum: true, bloom_filter: true, ..SerializeOptions::default() };
+    let mut bytes = yad.serialize_with_options(&options).unwrap();
+
+    assert!(Header::read(&bytes).unwrap().0.flags.contains(HeaderFlags::CHECKSUMMED));
+    assert_eq!(YAD::deserialize(bytes.clone()).unwrap(), yad);
+    assert_eq!(YadRef::new(&bytes).unwrap().to_owned().unwrap(), yad);
+    assert_eq!(streamed(&bytes), yad);
+    assert_eq!(YAD::deserialize_verbose(bytes.clone()).unwrap().0, yad);
+
+    let last = bytes.len() - 3;
+    bytes[last] ^= 0x01;
+    assert_eq!(YAD::deserialize(bytes).err(), Some(ErrorMessage(CHECKSUM_MISMATCH)));
+}
+
+#[test]
+fn verbose_reads_every_encoding() {
+    use serde_yad::options::LengthEncoding;
+    use yad_core::byte_order::ByteOrder;
+
+    let mut yad = hundred_rows();
+    yad.insert_row("series", vec![Key::new("at", Value::from_sorted_u64s(&[10, 11, 300, 70_000]).unwrap())]);
+
+    let encodings = [
+        SerializeOptions { byte_order: ByteOrder::Little, ..SerializeOptions::default() },
+        SerializeOptions { length_encoding: LengthEncoding::Varint, ..SerializeOptions::default() },
+        SerializeOptions { dictionary: true, ..SerializeOptions::default() },
+        SerializeOptions { delta_arrays: true, ..SerializeOptions::default() },
+        SerializeOptions {
+            byte_order: ByteOrder::Little,
+            length_encoding: LengthEncoding::Varint,
+            dictionary: true,
+            delta_arrays: true,
+            ..SerializeOptions::default()
+        },
+    ];
+
+    for options in encodings {
+        let (read, leftovers) = YAD::deserialize_verbose(yad.serialize_with_options(&options).unwrap()).unwrap();
+        assert_eq!(read, yad, "{options:?}");
+        assert!(leftovers.is_empty(), "{options:?}");
+    }
+}
+
+#[test]
+fn checksum_flag_follows_the_option() {
+    let options = SerializeOptions { header_flags: Some(HeaderFlags::CHECKSUMMED.with(HeaderFlags::COMPRESSED)), ..SerializeOptions::default() };
+    let bytes = hundred_rows().serialize_with_options(&options).unwrap();
+
+    assert!(Header::read(&bytes).unwrap().0.flags.is_empty());
+    assert_eq!(YAD::deserialize(bytes).unwrap(), hundred_rows());
+}
+
+#[cfg(feature = "compression")]
+#[test]
+fn compression_round_trips_with_every_encoding() {
+    use serde_yad::error::COMPRESSED_NOT_BORROWABLE;
+    use serde_yad::options::{DecodeOptions, LengthEncoding};
+    use yad_core::byte_order::ByteOrder;
+    use yad_core::constants::error::ALLOCATION_LIMIT_EXCEEDED;
+
+    let yad = hundred_rows();
+    let options = SerializeOptions {
+        compression: Compression::Deflate,
+        include_checksum: true,
+        byte_order: ByteOrder::Little,
+        length_encoding: LengthEncoding::Varint,
+        dictionary: true,
+        minimal_lengths: true,
+        ..SerializeOptions::default()
+    };
+    let bytes = yad.serialize_with_options(&options).unwrap();
+
+    assert!(Header::read(&bytes).unwrap().0.flags.contains(HeaderFlags::COMPRESSED));
+    assert!(bytes.len() < yad.serialize().unwrap().len() / 2);
+    assert_eq!(YAD::deserialize(bytes.clone()).unwrap(), yad);
+    assert_eq!(streamed(&bytes), yad);
+    assert_eq!(YAD::deserialize_verbose(bytes.clone()).unwrap().0, yad);
+    assert_eq!(YAD::deserialize_with_options(bytes, &DecodeOptions::limited(1024)).err(), Some(ErrorMessage(ALLOCATION_LIMIT_EXCEEDED)));
+
+    let compressed = yad.serialize_with_options(&SerializeOptions { compression: Compression::Deflate, ..SerializeOptions::default() }).unwrap();
+    assert_eq!(YadRef::new(&compressed).err(), Some(ErrorMessage(COMPRESSED_NOT_BORROWABLE)));
+}
+
+#[cfg(not(feature = "compression"))]
+#[test]
+fn compression_needs_the_feature() {
+    use serde_yad::error::COMPRESSION_NOT_SUPPORTED;
+
+    let options = SerializeOptions { compression: Compression::Deflate, ..SerializeOptions::default() };
+    assert_eq!(hundred_rows().serialize_with_options(&options).err(), Some(ErrorMessage(COMPRESSION_NOT_SUPPORTED)));
+}
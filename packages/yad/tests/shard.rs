@@ -0,0 +1,36 @@
+//! Tests for splitting documents into shards and reading them back.
+
+mod common;
+
+use std::fs;
+use common::numbered;
+use serde_yad::shard::{write_shards, YadShardSet};
+use serde_yad::{CURRENT_VERSION, Value};
+
+#[test]
+fn split_by_groups_rows_per_shard() {
+    let shards = numbered(10).split_by(|row| row["n"].clone().try_into().map(|n: u32| n % 3).unwrap());
+
+    assert_eq!(shards.keys().copied().collect::<Vec<_>>(), [0, 1, 2]);
+    assert_eq!(shards[&0].rows.len(), 4);
+    assert!(shards.values().all(|shard| shard.version == CURRENT_VERSION));
+    assert_eq!(shards[&1]["row-4"]["n"], Value::from(4u32));
+}
+
+#[test]
+fn shard_set_reads_a_directory_as_one_view() {
+    let dir = std::env::temp_dir().join(format!("serde_yad-shards-{}", std::process::id()));
+    let shards = numbered(10).split_by(|row| row.name.as_bytes()[4] % 2);
+
+    write_shards(&dir, &shards).unwrap();
+    fs::write(dir.join("notes.txt"), "not a shard").unwrap();
+
+    let set = YadShardSet::open(&dir).unwrap();
+    fs::remove_dir_all(&dir).unwrap();
+
+    assert_eq!(set.shards().len(), 2);
+    assert_eq!(set.len(), 10);
+    assert_eq!(set.get_row("row-7").unwrap()["n"], Value::from(7u32));
+    assert!(set.get_row("row-10").is_none());
+    assert_eq!(set.merge().unwrap(), numbered(10));
+}
@@ -0,0 +1,99 @@
+//! Tests for signing documents and verifying their signatures.
+#![cfg(feature = "signing")]
+
+mod common;
+
+use common::document;
+use ed25519_dalek::SigningKey;
+use serde_yad::constants::{MAGIC, SIGNATURE_HEADER, SIGNATURE_LENGTH};
+use serde_yad::error::{CHECKSUM_MISMATCH, INVALID_SIGNATURE, MISSING_SIGNATURE};
+use serde_yad::header::{Header, HeaderFlags};
+use serde_yad::options::SerializeOptions;
+use serde_yad::YAD;
+use yad_core::constants::error::ErrorMessage;
+
+fn signing_key() -> SigningKey {
+    SigningKey::from_bytes(&[7; 32])
+}
+
+/// Offset of the first byte after the signature section of a file without flags.
+const ROWS: usize = MAGIC.len() + 5 + 1 + SIGNATURE_LENGTH;
+
+#[test]
+fn a_valid_signature_verifies() {
+    let bytes = document().sign(&signing_key()).unwrap();
+
+    assert_eq!(bytes[MAGIC.len() + 5], SIGNATURE_HEADER);
+    assert_eq!(YAD::verify(&bytes, &signing_key().verifying_key()).unwrap(), document());
+    assert_eq!(YAD::deserialize(bytes).unwrap(), document());
+}
+
+#[test]
+fn a_tampered_body_is_rejected() {
+    let mut bytes = document().sign(&signing_key()).unwrap();
+    let age = bytes.iter().rposition(|byte| *byte == 27).unwrap();
+    bytes[age] = 28;
+
+    assert!(age >= ROWS);
+    assert_eq!(YAD::verify(&bytes, &signing_key().verifying_key()), Err(ErrorMessage(INVALID_SIGNATURE)));
+}
+
+#[test]
+fn a_tampered_signature_is_rejected() {
+    let mut bytes = document().sign(&signing_key()).unwrap();
+    bytes[ROWS - 1] ^= 0x01;
+
+    assert_eq!(YAD::verify(&bytes, &signing_key().verifying_key()), Err(ErrorMessage(INVALID_SIGNATURE)));
+}
+
+#[test]
+fn a_wrong_public_key_is_rejected() {
+    let bytes = document().sign(&signing_key()).unwrap();
+    let other = SigningKey::from_bytes(&[8; 32]).verifying_key();
+
+    assert_eq!(YAD::verify(&bytes, &other), Err(ErrorMessage(INVALID_SIGNATURE)));
+}
+
+#[test]
+fn unsigned_or_truncated_files_have_no_signature() {
+    let public_key = signing_key().verifying_key();
+    let bytes = document().sign(&signing_key()).unwrap();
+
+    assert_eq!(YAD::verify(&document().serialize().unwrap(), &public_key), Err(ErrorMessage(MISSING_SIGNATURE)));
+    assert_eq!(YAD::verify(&bytes[..ROWS - 1], &public_key), Err(ErrorMessage(MISSING_SIGNATURE)));
+}
+
+#[test]
+fn signatures_follow_the_flags_and_cover_the_checksum() {
+    let options = SerializeOptions { include_checksum: true, ..SerializeOptions::default() };
+    let bytes = document().sign_with_options(&signing_key(), &options).unwrap();
+
+    let (header, start) = Header::read(&bytes).unwrap();
+    assert!(header.flags.contains(HeaderFlags::CHECKSUMMED));
+    assert_eq!(bytes[start], SIGNATURE_HEADER);
+    assert_eq!(YAD::verify(&bytes, &signing_key().verifying_key()).unwrap(), document());
+    assert_eq!(YAD::deserialize(bytes.clone()).unwrap(), document());
+
+    // The checksum would also catch the change, but the signature is checked first.
+    let mut tampered = bytes.clone();
+    let age = tampered.iter().rposition(|byte| *byte == 27).unwrap();
+    tampered[age] = 28;
+    assert_eq!(YAD::verify(&tampered, &signing_key().verifying_key()), Err(ErrorMessage(INVALID_SIGNATURE)));
+    assert_eq!(YAD::deserialize(tampered), Err(ErrorMessage(CHECKSUM_MISMATCH)));
+}
+
+#[test]
+#[cfg(feature = "compression")]
+fn signatures_cover_compressed_bodies() {
+    use serde_yad::options::Compression;
+
+    let options = SerializeOptions { include_checksum: true, compression: Compression::Deflate, ..SerializeOptions::default() };
+    let bytes = document().sign_with_options(&signing_key(), &options).unwrap();
+
+    assert_eq!(YAD::verify(&bytes, &signing_key().verifying_key()).unwrap(), document());
+
+    let mut tampered = bytes.clone();
+    let last = tampered.len() - 1;
+    tampered[last] ^= 0x01;
+    assert_eq!(YAD::verify(&tampered, &signing_key().verifying_key()), Err(ErrorMessage(INVALID_SIGNATURE)));
+}
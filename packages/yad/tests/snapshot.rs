@@ -0,0 +1,52 @@
+//! Tests for copy-on-write snapshots of shared documents.
+
+mod common;
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use common::document;
+use serde_yad::shared::SharedYad;
+use serde_yad::Value;
+
+#[test]
+fn snapshot_does_not_see_later_writes() {
+    let shared = SharedYad::new(document());
+    let snapshot = shared.snapshot();
+
+    shared.write().insert_row("dave", vec![]);
+    shared.with_row_mut("alice", |row| row.insert_key("age", 31u8).unwrap());
+
+    assert_eq!(*snapshot, document());
+    assert_eq!(snapshot.serialize().unwrap(), document().serialize().unwrap());
+    assert_eq!(shared.read()["alice"]["age"], Value::from(31u8));
+    assert!(shared.read().get_row("dave").is_some());
+}
+
+#[test]
+fn snapshots_share_the_document_until_a_write() {
+    let shared = SharedYad::new(document());
+
+    let first = shared.snapshot();
+    let second = shared.snapshot();
+    assert!(std::ptr::eq(&*first, &*second));
+
+    shared.write().remove_row("alice");
+    assert!(!std::ptr::eq(&*first, &*shared.snapshot()));
+    assert_eq!(first.into_yad(), document());
+}
+
+#[test]
+fn observers_survive_the_copy_on_write() {
+    let shared = SharedYad::new(document());
+    let count = Arc::new(AtomicUsize::new(0));
+    let seen = count.clone();
+    shared.write().on_change(move |_| {
+        seen.fetch_add(1, Ordering::SeqCst);
+    });
+
+    let _snapshot = shared.snapshot();
+    shared.write().remove_row("alice");
+
+    assert_eq!(count.load(Ordering::SeqCst), 1);
+    assert!(shared.try_into_inner().is_ok_and(|yad| yad.get_row("alice").is_none()));
+}
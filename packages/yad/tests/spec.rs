@@ -0,0 +1,74 @@
+//! Tests for the description of the wire format.
+
+use std::collections::HashSet;
+use serde_yad::constants::*;
+use serde_yad::header::HeaderFlags;
+use serde_yad::spec::{self, HeaderContext};
+use yad_core::constants::length::ByteLength;
+use yad_core::constants::types::Type;
+
+#[test]
+fn headers_are_unique() {
+    let bytes: HashSet<u8> = spec::headers().iter().map(|header| header.byte).collect();
+    let names: HashSet<&str> = spec::headers().iter().map(|header| header.name).collect();
+    assert_eq!(bytes.len(), spec::headers().len());
+    assert_eq!(names.len(), spec::headers().len());
+}
+
+#[test]
+fn headers_match_the_constants() {
+    for (name, byte) in [
+        ("VERSION_HEADER", VERSION_HEADER),
+        ("ROW_START_HEADER", ROW_START_HEADER),
+        ("ROW_NAME_HEADER", ROW_NAME_HEADER),
+        ("ROW_END_HEADER", ROW_END_HEADER),
+        ("KEY_START_HEADER", KEY_START_HEADER),
+        ("KEY_NAME_HEADER", KEY_NAME_HEADER),
+        ("KEY_NAME_REF_HEADER", KEY_NAME_REF_HEADER),
+        ("KEY_END_HEADER", KEY_END_HEADER),
+        ("SCHEMA_HEADER", SCHEMA_HEADER),
+        ("BLOOM_HEADER", BLOOM_HEADER),
+        ("CHECKSUM_HEADER", CHECKSUM_HEADER),
+        ("TOMBSTONE_HEADER", TOMBSTONE_HEADER),
+        ("PATCH_HEADER", PATCH_HEADER),
+        ("PATCH_SET_KEY", PATCH_SET_KEY),
+        ("ENCRYPTED_HEADER", ENCRYPTED_HEADER),
+        ("SIGNATURE_HEADER", SIGNATURE_HEADER),
+        ("META_HEADER", META_HEADER),
+        ("FLAGS_HEADER", FLAGS_HEADER),
+        ("DICTIONARY_HEADER", DICTIONARY_HEADER),
+    ] {
+        let header = spec::header(byte).unwrap();
+        assert_eq!(header.name, name);
+    }
+    assert_eq!(spec::header(PATCH_ADD_ROW).unwrap().context, HeaderContext::Patch);
+    assert_eq!(spec::header(0x00), None);
+    assert_eq!(spec::magic(), MAGIC);
+}
+
+#[test]
+fn type_nibbles_decode_to_their_type() {
+    for r#type in spec::types() {
+        assert_eq!(r#type.nibble & 0x0F, 0);
+        assert_eq!(Type::try_from(r#type.nibble).unwrap().to_string(), r#type.name);
+    }
+}
+
+#[test]
+fn length_nibbles_decode_to_their_width() {
+    for length in spec::lengths() {
+        let byte_length = ByteLength::try_from(length.nibble).unwrap();
+        assert_eq!(byte_length as u8, length.nibble);
+        assert_eq!(u32::from(length.bytes) * 8, byte_length.to_string().trim_end_matches(" bits").parse::<u32>().unwrap());
+    }
+}
+
+#[test]
+fn flags_cover_every_known_bit() {
+    let bits = spec::flags().iter().fold(0, |bits, flag| {
+        assert_eq!(flag.bit.count_ones(), 1);
+        assert_eq!(bits & flag.bit, 0);
+        bits | flag.bit
+    });
+    assert_eq!(bits, HeaderFlags::DELTA_ARRAYS.0 * 2 - 1);
+}
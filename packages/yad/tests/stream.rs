@@ -0,0 +1,126 @@
+//! Tests for streaming documents row by row.
+
+use std::fs;
+use std::io::Read;
+use serde_yad::header::HeaderFlags;
+use serde_yad::key::Key;
+use serde_yad::options::{LengthEncoding, SerializeOptions};
+use serde_yad::row::Row;
+use serde_yad::stream::{YadRowStream, YadWriter};
+use serde_yad::{CURRENT_VERSION, Value, YAD};
+use yad_core::byte_order::ByteOrder;
+
+/// A source handing out at most `step` bytes per read.
+struct Trickle<'a> {
+    bytes: &'a [u8],
+    step: usize,
+}
+
+impl Read for Trickle<'_> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let n = self.step.min(buf.len()).min(self.bytes.len());
+        buf[..n].copy_from_slice(&self.bytes[..n]);
+        self.bytes = &self.bytes[n..];
+        Ok(n)
+    }
+}
+
+fn streamed(bytes: &[u8], step: usize) -> Vec<Row> {
+    YadRowStream::open(Trickle { bytes, step }).unwrap().collect::<Result<_, _>>().unwrap()
+}
+
+fn rows() -> Vec<Row> {
+    (0..100).map(|i| Row::new(format!("row{i:03}"), vec![Key::new("n", i as u32), Key::new("even", i % 2 == 0)])).collect()
+}
+
+#[test]
+fn writer_output_matches_serialize() {
+    let mut writer = YadWriter::new(vec![], CURRENT_VERSION).unwrap();
+    for row in rows() {
+        writer.write_row(&row).unwrap();
+    }
+    assert_eq!(writer.rows_written(), 100);
+
+    let bytes = writer.close().unwrap();
+    assert_eq!(bytes, YAD::new(CURRENT_VERSION, rows()).serialize().unwrap());
+}
+
+#[test]
+fn writer_creates_files() {
+    let path = std::env::temp_dir().join(format!("serde_yad-stream-{}.yad", std::process::id()));
+
+    let mut writer = YadWriter::create(&path, CURRENT_VERSION).unwrap();
+    for row in rows() {
+        writer.write_row(&row).unwrap();
+    }
+    writer.close().unwrap();
+
+    let yad = YAD::deserialize(fs::read(&path).unwrap()).unwrap();
+    fs::remove_file(&path).unwrap();
+    assert_eq!(yad, YAD::new(CURRENT_VERSION, rows()));
+}
+
+#[test]
+fn stream_yields_rows_in_file_order() {
+    let bytes = YAD::new(CURRENT_VERSION, rows()).serialize().unwrap();
+
+    for step in [1, 7, 1 << 20] {
+        assert_eq!(streamed(&bytes, step), rows());
+    }
+}
+
+#[test]
+fn stream_decodes_every_body_encoding() {
+    let mut rows = rows();
+    rows.push(Row::new("series", vec![Key::new("at", Value::from_sorted_u64s(&[10, 20, 30, 40, 50, 60]).unwrap())]));
+    let yad = YAD::new(CURRENT_VERSION, rows.clone());
+    let options = SerializeOptions {
+        embed_schema: true,
+        header_flags: Some(HeaderFlags::INDEXED),
+        byte_order: ByteOrder::Little,
+        length_encoding: LengthEncoding::Varint,
+        dictionary: true,
+        delta_arrays: true,
+        ..SerializeOptions::default()
+    };
+    let bytes = yad.serialize_with_options(&options).unwrap();
+
+    let stream = YadRowStream::open(Trickle { bytes: &bytes, step: 5 }).unwrap();
+    assert!(stream.header().flags.contains(HeaderFlags::DICTIONARY));
+    let streamed: Vec<Row> = stream.collect::<Result<_, _>>().unwrap();
+
+    assert_eq!(YAD::new(CURRENT_VERSION, streamed), yad);
+}
+
+#[test]
+fn stream_decodes_each_body_encoding_on_its_own() {
+    let mut rows = rows();
+    rows.push(Row::new("series", vec![Key::new("at", Value::from_sorted_u64s(&[10, 20, 30, 40, 50, 60]).unwrap())]));
+    let yad = YAD::new(CURRENT_VERSION, rows);
+
+    let encodings = [
+        SerializeOptions { length_encoding: LengthEncoding::Varint, ..SerializeOptions::default() },
+        SerializeOptions { dictionary: true, ..SerializeOptions::default() },
+        SerializeOptions { delta_arrays: true, ..SerializeOptions::default() },
+        SerializeOptions { delta_arrays: true, byte_order: ByteOrder::Little, ..SerializeOptions::default() },
+        SerializeOptions { delta_arrays: true, dictionary: true, ..SerializeOptions::default() },
+    ];
+
+    for (index, options) in encodings.iter().enumerate() {
+        let bytes = yad.serialize_with_options(options).unwrap();
+        assert_eq!(YAD::deserialize(bytes.clone()).unwrap(), yad);
+
+        let streamed: Vec<Row> = YadRowStream::open(Trickle { bytes: &bytes, step: 7 }).unwrap().collect::<Result<_, _>>().unwrap();
+        assert_eq!(YAD::new(CURRENT_VERSION, streamed), yad, "encoding {}", index);
+    }
+}
+
+#[test]
+fn truncated_input_ends_the_stream_with_an_error() {
+    let bytes = YAD::new(CURRENT_VERSION, rows()).serialize().unwrap();
+    let results: Vec<_> = YadRowStream::open(&bytes[..bytes.len() - 3]).unwrap().collect();
+
+    assert_eq!(results.len(), 100);
+    assert!(results[..99].iter().all(Result::is_ok));
+    assert!(results[99].is_err());
+}
@@ -0,0 +1,71 @@
+//! Tests for string operations on values.
+
+use serde_yad::Value;
+use serde_yad::key::Key;
+use serde_yad::row::Row;
+use serde_yad::{CURRENT_VERSION, YAD};
+use yad_core::constants::error::{
+    ErrorMessage, INVALID_STRING_RANGE, NOT_ASCII, NOT_A_SINGLE_CHAR, NOT_A_STRING, STRING_OF_LENGTH_ZERO,
+};
+
+fn string(text: &str) -> Value {
+    Value::try_from(text).unwrap()
+}
+
+#[test]
+fn lengths_count_bytes() {
+    assert_eq!(string("héllo").string_len(), Ok(6));
+    assert_eq!(string("héllo").char_len(), Ok(5));
+    assert_eq!(Value::from(1u8).string_len(), Err(ErrorMessage(NOT_A_STRING)));
+}
+
+#[test]
+fn appending_widens_the_length_descriptor() {
+    let mut value = string("a");
+    value.string_append("b").unwrap();
+    assert_eq!(value, string("ab"));
+
+    let long = "x".repeat(300);
+    value.string_append(&long).unwrap();
+    assert_eq!(value, string(&format!("ab{long}")));
+    assert_eq!(value.bytes.len(), 3 + 302);
+
+    value.string_append("").unwrap();
+    assert_eq!(value.string_len(), Ok(302));
+}
+
+#[test]
+fn slices_respect_character_boundaries() {
+    let value = string("héllo");
+
+    assert_eq!(value.string_slice(3..), Ok(string("llo")));
+    assert_eq!(value.string_slice(..=2), Ok(string("hé")));
+    assert_eq!(value.string_slice(..), Ok(value.clone()));
+    assert_eq!(value.string_slice(1..2), Err(ErrorMessage(INVALID_STRING_RANGE)));
+    assert_eq!(value.string_slice(4..9), Err(ErrorMessage(INVALID_STRING_RANGE)));
+    assert_eq!(value.string_slice(3..3), Err(ErrorMessage(STRING_OF_LENGTH_ZERO)));
+}
+
+#[test]
+fn characters_are_strings_of_their_utf8_width() {
+    for (c, width) in [('a', 1), ('é', 2), ('€', 3), ('😀', 4)] {
+        let value = Value::from(c);
+        assert_eq!(value, string(&c.to_string()));
+        assert_eq!(value.bytes.len(), 2 + width);
+        assert_eq!(TryInto::<char>::try_into(value), Ok(c));
+    }
+
+    assert_eq!(string("ab").as_char(), Err(ErrorMessage(NOT_A_SINGLE_CHAR)));
+    assert_eq!(Value::from(97u8).as_char(), Err(ErrorMessage(NOT_A_STRING)));
+
+    let yad = YAD::new(CURRENT_VERSION, vec![Row::new("student", vec![Key::new("grade", 'B')])]);
+    let decoded = YAD::deserialize(yad.serialize().unwrap()).unwrap();
+    assert_eq!(decoded.get_row("student").unwrap().get("grade").unwrap().as_char(), Ok('B'));
+}
+
+#[test]
+fn bytes_are_numbers_unless_read_as_ascii() {
+    assert_eq!(Value::from_byte(b'a'), Value::from(97u8));
+    assert_eq!(Value::from_ascii(b'a'), Ok(Value::from('a')));
+    assert_eq!(Value::from_ascii(0xE9), Err(ErrorMessage(NOT_ASCII)));
+}
@@ -0,0 +1,44 @@
+//! Round-trip tests for YAD Text Notation.
+
+use serde_yad::key::Key;
+use serde_yad::row::Row;
+use serde_yad::{CURRENT_VERSION, Value, YAD};
+use yad_core::constants::error::{ErrorMessage, VEC_OF_LENGTH_ZERO};
+
+#[test]
+fn text_round_trips() {
+    let yad = YAD::new(CURRENT_VERSION, vec![
+        Row::new("johan", vec![
+            Key::new("age", Value::from(17u8)),
+            Key::new("name", Value::try_from("Jo \"han\"\n").unwrap()),
+            Key::new("ratio", Value::from(0.1f32)),
+            Key::new("list", Value::try_from(vec![Value::from(-1i16), Value::from(true), Value::from(f64::INFINITY)]).unwrap()),
+        ]),
+        Row::new("with space", vec![Key::new("version", Value::from(u64::MAX))]),
+    ]);
+
+    let text = yad.to_text().unwrap();
+
+    assert_eq!(YAD::from_text(&text).unwrap(), yad);
+}
+
+#[test]
+fn text_parses_hand_written_documents() {
+    let yad = YAD::from_text(r#"
+        # settings
+        version 1.0.0
+        server { port = 8080u16, "host name" = "local\u{68}ost"; retries = 3 }
+    "#).unwrap();
+
+    let server = &yad.rows["server"];
+    assert_eq!(server.keys["port"].value, Value::from(8080u16));
+    assert_eq!(server.keys["host name"].value, Value::try_from("localhost").unwrap());
+    assert_eq!(server.keys["retries"].value, Value::from(3i64));
+}
+
+#[test]
+fn text_errors_carry_their_position() {
+    let error = YAD::from_text("row {\n  empty = []\n}").unwrap_err();
+
+    assert_eq!((error.error, error.line, error.column), (ErrorMessage(VEC_OF_LENGTH_ZERO), 2, 11));
+}
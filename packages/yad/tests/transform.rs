@@ -0,0 +1,86 @@
+//! Tests for rewriting values with `YAD::transform` and planning it.
+
+mod common;
+
+use common::document;
+use serde_yad::transform::ValueChange;
+use serde_yad::Value;
+use yad_core::constants::error::ErrorMessage;
+
+/// Doubles an unsigned age, failing for values that do not fit a `u8`.
+fn double(value: Value) -> Result<Value, ErrorMessage> {
+    let age = u8::try_from(&value)?;
+    age.checked_mul(2).map(Value::from).ok_or(ErrorMessage("age out of range"))
+}
+
+fn change(row: &str, before: u8, after: u8) -> ValueChange {
+    ValueChange { row: row.into(), key: "age".into(), before: Value::from(before), after: Value::from(after) }
+}
+
+#[test]
+fn plans_do_not_touch_the_document() {
+    let yad = document();
+    let plan = yad.plan_transform("age", double).unwrap();
+
+    assert_eq!(plan, [change("alice", 30, 60), change("bob", 41, 82), change("carol", 27, 54)]);
+    assert_eq!(yad, document());
+}
+
+#[test]
+fn transforms_apply_the_planned_changes() {
+    let mut yad = document();
+    let plan = yad.plan_transform("age", double).unwrap();
+
+    assert_eq!(yad.transform("age", double).unwrap(), plan);
+    assert_eq!(yad["alice"]["age"], Value::from(60u8));
+    assert_eq!(yad["bob"]["age"], Value::from(82u8));
+    assert_eq!(yad["carol"]["age"], Value::from(54u8));
+}
+
+#[test]
+fn selectors_limit_the_rows() {
+    let mut yad = document();
+
+    assert_eq!(yad.transform("bob.age", double).unwrap(), [change("bob", 41, 82)]);
+    assert_eq!(yad["alice"]["age"], Value::from(30u8));
+    assert_eq!(yad.transform("*.age", double).unwrap().len(), 3);
+    assert!(yad.transform("dave.age", double).unwrap().is_empty());
+    assert!(yad.transform("height", double).unwrap().is_empty());
+}
+
+#[test]
+fn unchanged_values_are_not_reported() {
+    let mut yad = document();
+
+    assert!(yad.transform("age", Ok).unwrap().is_empty());
+    assert_eq!(yad, document());
+}
+
+#[test]
+fn a_conversion_error_leaves_the_document_unchanged() {
+    let mut yad = document();
+    yad.insert_key("bob", "age", 200u8).unwrap();
+    let before = yad.clone();
+
+    // alice is doubled before bob fails; nothing may be written.
+    assert_eq!(yad.transform("age", double), Err(ErrorMessage("age out of range")));
+    assert_eq!(yad, before);
+    assert_eq!(yad.plan_transform("age", double), Err(ErrorMessage("age out of range")));
+
+    // Values of another type fail the conversion itself.
+    assert!(yad.transform("name", double).is_err());
+    assert_eq!(yad, before);
+}
+
+#[test]
+fn the_function_sees_values_in_row_order() {
+    let yad = document();
+    let mut seen = vec![];
+
+    yad.plan_transform("name", |value| {
+        seen.push(value.as_str()?.to_string());
+        Ok(value)
+    }).unwrap();
+
+    assert_eq!(seen, ["Alice", "Bob", "Carol"]);
+}
@@ -0,0 +1,59 @@
+//! Round-trip tests for non-ASCII row names, key names and strings.
+
+use serde_yad::borrowed::YadRef;
+use serde_yad::key::Key;
+use serde_yad::row::Row;
+use serde_yad::{CURRENT_VERSION, Value, YAD};
+
+/// A document whose names and values mix one- to four-byte UTF-8 characters.
+fn multi_byte() -> YAD {
+    let long = "é".repeat(200);
+
+    YAD::new(CURRENT_VERSION, vec![
+        Row::new("jöhan", vec![
+            Key::try_new("名前", "ヨハン").unwrap(),
+            Key::try_new("emoji", "🦀🦀").unwrap(),
+        ]),
+        Row::new(long.as_str(), vec![Key::try_new(long.as_str(), long.as_str()).unwrap()]),
+    ])
+}
+
+#[test]
+fn multi_byte_names_round_trip() {
+    let yad = multi_byte();
+    let read = YAD::deserialize(yad.serialize().unwrap()).unwrap();
+
+    assert_eq!(read, yad);
+    assert_eq!(read["jöhan"]["名前"], Value::try_from("ヨハン").unwrap());
+    assert!(read.get_row(&"é".repeat(200)).is_some());
+}
+
+#[test]
+fn borrowed_names_match_owned_names() {
+    let bytes = multi_byte().serialize().unwrap();
+    let doc = YadRef::new(&bytes).unwrap();
+
+    let row = doc.row("jöhan").unwrap().unwrap();
+    let key = row.get("名前").unwrap().unwrap();
+
+    assert_eq!(key.value.as_str().unwrap(), "ヨハン");
+}
+
+#[test]
+fn string_lengths_count_bytes_and_characters() {
+    let value = Value::from_str_bytes("🦀🦀").unwrap();
+
+    assert_eq!(value.isolate_value_bytes().len(), 8);
+    assert_eq!(value.char_len().unwrap(), 2);
+    assert_eq!(Value::from_str_chars("🦀🦀".chars()).unwrap(), value);
+    assert!(Value::from(1u8).char_len().is_err());
+}
+
+#[test]
+fn names_cut_mid_character_are_rejected() {
+    let mut bytes = Row::new("é", vec![Key::new("k", 1u8)]).serialize().unwrap();
+    // Claim a one-byte name, splitting the two-byte `é`.
+    bytes[2] = 1;
+
+    assert!(Row::deserialize(bytes).is_err());
+}
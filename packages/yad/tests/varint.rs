@@ -0,0 +1,75 @@
+//! Tests for varint length descriptors.
+
+use serde_yad::borrowed::YadRef;
+use serde_yad::error::VARINT_LENGTHS_NOT_BORROWABLE;
+use serde_yad::header::{Header, HeaderFlags};
+use serde_yad::key::Key;
+use serde_yad::options::{LengthEncoding, SerializeOptions};
+use serde_yad::projection::Projection;
+use serde_yad::row::Row;
+use serde_yad::{CURRENT_VERSION, Value, YAD};
+use yad_core::byte_order::ByteOrder;
+use yad_core::constants::error::ErrorMessage;
+use yad_core::varint::{read_varint, write_varint};
+
+fn long_names() -> YAD {
+    let long = "x".repeat(300);
+    let nested = Value::try_from(vec![
+        Value::try_from("short").unwrap(),
+        Value::try_from(vec![Value::try_from(long.as_str()).unwrap(), Value::from(7u32)]).unwrap(),
+    ]).unwrap();
+
+    let mut key = Key::try_new("name", "Johan").unwrap();
+    key.push_value(Value::try_from(long.as_str()).unwrap());
+
+    YAD::new(CURRENT_VERSION, vec![
+        Row::new("johan", vec![key, Key::new("nested", nested), Key::new("ok", true)]),
+        Row::new(long.as_str(), vec![Key::new(long.as_str(), 1.5f32)]),
+    ])
+}
+
+fn varint(byte_order: ByteOrder) -> Vec<u8> {
+    let options = SerializeOptions { length_encoding: LengthEncoding::Varint, byte_order, ..SerializeOptions::default() };
+    long_names().serialize_with_options(&options).unwrap()
+}
+
+#[test]
+fn varints_round_trip() {
+    for value in [0, 1, 127, 128, 300, u32::MAX as u64, u64::MAX] {
+        let mut bytes = Vec::new();
+        write_varint(value, &mut bytes);
+        assert_eq!(read_varint(&bytes), Ok((value, bytes.len())));
+    }
+
+    assert!(read_varint(&[0x80]).is_err());
+    assert!(read_varint(&[0xFF; 10]).is_err());
+}
+
+#[test]
+fn varint_documents_round_trip() {
+    let bytes = varint(ByteOrder::Big);
+
+    assert!(Header::read(&bytes).unwrap().0.flags.contains(HeaderFlags::VARINT_LENGTHS));
+    assert_eq!(YAD::deserialize(bytes.clone()).unwrap(), long_names());
+    assert_eq!(YAD::deserialize_projected(&bytes, &Projection::all()).unwrap(), long_names());
+    assert_eq!(YAD::deserialize(varint(ByteOrder::Little)).unwrap(), long_names());
+}
+
+#[test]
+fn descriptor_sizes_follow_the_varint_width() {
+    let size = |len: usize, length_encoding| {
+        let yad = YAD::new(CURRENT_VERSION, vec![Row::new("r", vec![Key::try_new("k", "x".repeat(len)).unwrap()])]);
+        yad.serialize_with_options(&SerializeOptions { length_encoding, ..SerializeOptions::default() }).unwrap().len()
+    };
+    // The flags section written in varint mode takes 5 bytes.
+    let flags = 5;
+
+    assert_eq!(size(100, LengthEncoding::Varint), size(100, LengthEncoding::Fixed) + flags);
+    assert_eq!(size(200, LengthEncoding::Varint), size(200, LengthEncoding::Fixed) + flags + 1);
+    assert_eq!(size(70_000, LengthEncoding::Varint), size(70_000, LengthEncoding::Fixed) + flags - 1);
+}
+
+#[test]
+fn borrowed_reader_rejects_varint_lengths() {
+    assert_eq!(YadRef::new(&varint(ByteOrder::Big)), Err(ErrorMessage(VARINT_LENGTHS_NOT_BORROWABLE)));
+}
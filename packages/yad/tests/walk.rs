@@ -0,0 +1,232 @@
+//! Tests for [`Value::walk`], [`YAD::walk`] and their visitors.
+
+use serde_yad::key::Key;
+use serde_yad::row::Row;
+use serde_yad::walk::DocumentVisitor;
+use serde_yad::{Value, CURRENT_VERSION, YAD};
+use yad_core::bigint::BigInteger;
+use yad_core::constants::error::{ErrorMessage, NOT_ENOUGH_BYTES};
+use yad_core::decimal::Decimal;
+use yad_core::visit::{Location, ValueVisitor};
+
+/// Records every callback as text, along with where it was made.
+#[derive(Default)]
+struct Recorder {
+    events: Vec<(Vec<usize>, usize, String)>,
+    skip_arrays_at_depth: Option<usize>,
+}
+
+impl Recorder {
+    fn record(&mut self, at: Location<'_>, event: String) {
+        self.events.push((at.path.to_vec(), at.offset, event));
+    }
+
+    fn names(&self) -> Vec<&str> {
+        self.events.iter().map(|(_, _, event)| event.as_str()).collect()
+    }
+}
+
+impl ValueVisitor for Recorder {
+    fn visit_uint(&mut self, at: Location<'_>, value: u64) {
+        self.record(at, format!("uint {}", value));
+    }
+
+    fn visit_int(&mut self, at: Location<'_>, value: i64) {
+        self.record(at, format!("int {}", value));
+    }
+
+    fn visit_float(&mut self, at: Location<'_>, value: f64) {
+        self.record(at, format!("float {}", value));
+    }
+
+    fn visit_string(&mut self, at: Location<'_>, value: &str) {
+        self.record(at, format!("string {}", value));
+    }
+
+    fn visit_bool(&mut self, at: Location<'_>, value: bool) {
+        self.record(at, format!("bool {}", value));
+    }
+
+    fn visit_decimal(&mut self, at: Location<'_>, value: Decimal) {
+        self.record(at, format!("decimal {}", value));
+    }
+
+    fn visit_big_integer(&mut self, at: Location<'_>, value: BigInteger) {
+        self.record(at, format!("big {}", value));
+    }
+
+    fn visit_extension(&mut self, at: Location<'_>, tag: u8, data: &[u8]) {
+        self.record(at, format!("ext {:02X} {:?}", tag, data));
+    }
+
+    fn enter_array(&mut self, at: Location<'_>, len: usize) -> bool {
+        self.record(at, format!("enter {}", len));
+        self.skip_arrays_at_depth != Some(at.depth())
+    }
+
+    fn leave_array(&mut self, at: Location<'_>, len: usize) {
+        self.record(at, format!("leave {}", len));
+    }
+}
+
+impl DocumentVisitor for Recorder {
+    fn enter_row(&mut self, row: &Row) -> bool {
+        self.events.push((vec![], 0, format!("row {}", row.name)));
+        row.name != "skipped"
+    }
+
+    fn leave_row(&mut self, row: &Row) {
+        self.events.push((vec![], 0, format!("end row {}", row.name)));
+    }
+
+    fn enter_key(&mut self, _row: &Row, key: &Key) -> bool {
+        self.events.push((vec![], 0, format!("key {}", key.name)));
+        key.name != "hidden"
+    }
+}
+
+fn array(items: Vec<Value>) -> Value {
+    Value::try_from(items).unwrap()
+}
+
+fn string(text: &str) -> Value {
+    Value::try_from(text).unwrap()
+}
+
+fn nested() -> Value {
+    array(vec![
+        Value::from(1u8),
+        array(vec![Value::from(-2i8), string("hi"), array(vec![Value::from(300u16)])]),
+        Value::from(true),
+        Value::from(1.5f64),
+    ])
+}
+
+#[test]
+fn walks_nested_arrays_depth_first() {
+    let mut recorder = Recorder::default();
+    nested().walk(&mut recorder).unwrap();
+
+    assert_eq!(recorder.names(), [
+        "enter 4",
+        "uint 1",
+        "enter 3",
+        "int -2",
+        "string hi",
+        "enter 1",
+        "uint 300",
+        "leave 1",
+        "leave 3",
+        "bool true",
+        "float 1.5",
+        "leave 4",
+    ]);
+
+    let paths: Vec<&[usize]> = recorder.events.iter().map(|(path, _, _)| path.as_slice()).collect();
+    assert_eq!(paths, [
+        &[][..], &[0], &[1], &[1, 0], &[1, 1], &[1, 2], &[1, 2, 0], &[1, 2], &[1], &[2], &[3], &[],
+    ]);
+}
+
+#[test]
+fn offsets_point_at_the_visited_values() {
+    let value = nested();
+    let mut recorder = Recorder::default();
+    value.walk(&mut recorder).unwrap();
+
+    for (path, offset, _) in &recorder.events {
+        let expected = path.iter().fold(value.clone(), |value, index| value.array_get(*index).unwrap());
+        assert_eq!(Value::decode_in_place(&value.bytes[*offset..]).unwrap(), expected, "at {:?}", path);
+    }
+}
+
+#[test]
+fn skipped_arrays_are_still_left() {
+    let mut recorder = Recorder { skip_arrays_at_depth: Some(1), ..Recorder::default() };
+    nested().walk(&mut recorder).unwrap();
+
+    assert_eq!(recorder.names(), [
+        "enter 4",
+        "uint 1",
+        "enter 3",
+        "leave 3",
+        "bool true",
+        "float 1.5",
+        "leave 4",
+    ]);
+}
+
+#[test]
+fn scalars_are_walked_on_their_own() {
+    let mut recorder = Recorder::default();
+    Value::from(7u32).walk(&mut recorder).unwrap();
+    string("solo").walk(&mut recorder).unwrap();
+
+    assert_eq!(recorder.events, [
+        (vec![], 0, "uint 7".to_string()),
+        (vec![], 0, "string solo".to_string()),
+    ]);
+}
+
+#[test]
+fn extension_kinds_have_their_own_callbacks() {
+    let value = array(vec![
+        Value::from(Decimal::new(1990, 2)),
+        Value::from(BigInteger::from(u128::MAX)),
+        Value::from_extension(0x81, &[0x0A, 0x0B]).unwrap(),
+    ]);
+
+    let mut recorder = Recorder::default();
+    value.walk(&mut recorder).unwrap();
+
+    assert_eq!(recorder.names(), [
+        "enter 3",
+        "decimal 19.90",
+        "big 340282366920938463463374607431768211455",
+        "ext 81 [10, 11]",
+        "leave 3",
+    ]);
+}
+
+#[test]
+fn truncated_values_fail() {
+    let mut value = nested();
+    let len = value.bytes.len();
+    value.bytes.truncate(len - 4);
+
+    assert_eq!(value.walk(&mut Recorder::default()), Err(ErrorMessage(NOT_ENOUGH_BYTES)));
+}
+
+#[test]
+fn walks_a_document_in_row_then_key_order() {
+    let mut tags = Key::new("tags", array(vec![string("admin")]));
+    tags.push_value(string("staff"));
+
+    let yad = YAD::new(CURRENT_VERSION, vec![
+        Row::new("maria", vec![Key::new("age", Value::from(30u8))]),
+        Row::new("johan", vec![tags, Key::new("hidden", string("secret")), Key::new("age", Value::from(25u8))]),
+        Row::new("skipped", vec![Key::new("age", Value::from(99u8))]),
+    ]);
+
+    let mut recorder = Recorder::default();
+    yad.walk(&mut recorder).unwrap();
+
+    assert_eq!(recorder.names(), [
+        "row johan",
+        "key age",
+        "uint 25",
+        "key hidden",
+        "key tags",
+        "enter 1",
+        "string admin",
+        "leave 1",
+        "string staff",
+        "end row johan",
+        "row maria",
+        "key age",
+        "uint 30",
+        "end row maria",
+        "row skipped",
+        "end row skipped",
+    ]);
+}
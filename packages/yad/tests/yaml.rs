@@ -0,0 +1,96 @@
+//! Tests for YAML interop.
+#![cfg(feature = "yaml")]
+
+use serde_yad::error::{MALFORMED_YAML, YAML_INVALID_VALUE, YAML_NAME_NOT_TEXT, YAML_ROW_NOT_A_MAPPING};
+use serde_yad::key::Key;
+use serde_yad::row::Row;
+use serde_yad::{Value, CURRENT_VERSION, YAD};
+use yad_core::bigint::BigInteger;
+use yad_core::constants::error::ErrorMessage;
+use yad_core::decimal::Decimal;
+
+fn plain_values() -> YAD {
+    YAD::new(CURRENT_VERSION, vec![
+        Row::new("johan", vec![
+            Key::new("age", 21u8),
+            Key::new("balance", -1_500i16),
+            Key::new("name", Value::try_from("Johan").unwrap()),
+            Key::new("tags", Value::try_from(vec![Value::try_from("a").unwrap(), Value::try_from("b").unwrap()]).unwrap()),
+            Key::new("admin", false),
+        ]),
+        Row::new("server", vec![
+            Key::new("port", 8080u16),
+            Key::new("ratio", 0.5f64),
+        ]),
+    ])
+}
+
+#[test]
+fn round_trips_plain_values() {
+    let yad = plain_values();
+    let yaml = yad.to_yaml_string().unwrap();
+    assert!(yaml.contains("port: 8080"));
+    assert!(yaml.contains("balance: -1500"));
+    assert!(!yaml.contains('!'));
+    assert_eq!(YAD::from_yaml_str(&yaml), Ok(yad));
+}
+
+#[test]
+fn tags_numbers_wider_than_needed() {
+    let yad = YAD::new(CURRENT_VERSION, vec![Row::new("row", vec![
+        Key::new("small", 7u32),
+        Key::new("positive", 3i16),
+        Key::new("single", 0.5f32),
+    ])]);
+
+    let yaml = yad.to_yaml_string().unwrap();
+    assert!(yaml.contains("small: !u32 7"));
+    assert!(yaml.contains("positive: !i16 3"));
+    assert!(yaml.contains("single: !f32 0.5"));
+    assert_eq!(YAD::from_yaml_str(&yaml), Ok(yad));
+}
+
+#[test]
+fn round_trips_extension_values() {
+    let big: BigInteger = "-340282366920938463463374607431768211456".parse().unwrap();
+    let yad = YAD::new(CURRENT_VERSION, vec![Row::new("row", vec![
+        Key::new("price", Value::from(Decimal::new(1990, 2))),
+        Key::new("huge", Value::from(big)),
+        Key::new("raw", Value::from_extension(0x40, &[0xDE, 0xAD]).unwrap()),
+    ])]);
+
+    let yaml = yad.to_yaml_string().unwrap();
+    assert!(yaml.contains("price: !decimal '19.90'"));
+    assert!(yaml.contains("huge: !bigint '-340282366920938463463374607431768211456'"));
+    assert!(yaml.contains("raw: !ext"));
+    assert_eq!(YAD::from_yaml_str(&yaml), Ok(yad));
+}
+
+#[test]
+fn reads_handwritten_yaml() {
+    let yad = YAD::from_yaml_str("app:\n  name: demo\n  workers: 300\n  offset: -2\n  scale: 1.5\n  limit: !u64 10\n").unwrap();
+    let app = &yad.rows["app"];
+    assert_eq!(yad.version, CURRENT_VERSION);
+    assert_eq!(app.keys["name"].value, Value::try_from("demo").unwrap());
+    assert_eq!(app.keys["workers"].value, Value::from(300u16));
+    assert_eq!(app.keys["offset"].value, Value::from(-2i8));
+    assert_eq!(app.keys["scale"].value, Value::from(1.5f64));
+    assert_eq!(app.keys["limit"].value, Value::from(10u64));
+}
+
+#[test]
+fn empty_document_has_no_rows() {
+    assert_eq!(YAD::from_yaml_str("").unwrap().rows.len(), 0);
+}
+
+#[test]
+fn rejects_invalid_yaml() {
+    assert_eq!(YAD::from_yaml_str("a: [b"), Err(ErrorMessage(MALFORMED_YAML)));
+    assert_eq!(YAD::from_yaml_str("- a"), Err(ErrorMessage(YAML_ROW_NOT_A_MAPPING)));
+    assert_eq!(YAD::from_yaml_str("row: 1"), Err(ErrorMessage(YAML_ROW_NOT_A_MAPPING)));
+    assert_eq!(YAD::from_yaml_str("1:\n  a: 1"), Err(ErrorMessage(YAML_NAME_NOT_TEXT)));
+    assert_eq!(YAD::from_yaml_str("row:\n  a: ~"), Err(ErrorMessage(YAML_INVALID_VALUE)));
+    assert_eq!(YAD::from_yaml_str("row:\n  a:\n    b: 1"), Err(ErrorMessage(YAML_INVALID_VALUE)));
+    assert_eq!(YAD::from_yaml_str("row:\n  a: !u8 300"), Err(ErrorMessage(YAML_INVALID_VALUE)));
+    assert_eq!(YAD::from_yaml_str("row:\n  a: !when 1"), Err(ErrorMessage(YAML_INVALID_VALUE)));
+}